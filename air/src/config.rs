@@ -20,7 +20,7 @@ use miden_crypto::{
         GenericStarkConfig,
         challenger::{CanObserve, DuplexChallenger, HashChallenger, SerializingChallenger64},
         dft::Radix2DitParallel,
-        hasher::{ChainingHasher, SerializingStatefulSponge, StatefulSponge},
+        hasher::{ChainingHasher, SerializingStatefulSponge, StatefulSponge, TruncatingHasher},
         lmcs::config::LmcsConfig,
         pcs::PcsParams,
         symmetric::{
@@ -306,6 +306,45 @@ pub fn blake3_256_config(params: PcsParams, relation_digest: RelationDigest) ->
     GenericStarkConfig::new(params, lmcs, Radix2DitParallel::default(), challenger)
 }
 
+// BLAKE3-192
+// ================================================================================================
+
+/// Digest size in bytes for Blake3_192.
+const BLAKE_192_DIGEST_SIZE: usize = 24;
+
+/// Blake3 hasher with its output truncated to the first 24 bytes.
+type Blake3_192Hasher = TruncatingHasher<Blake3Hasher, BLAKE_DIGEST_SIZE, BLAKE_192_DIGEST_SIZE>;
+
+/// Blake3_192 LMCS.
+type Blake192Lmcs = LmcsConfig<
+    Felt,
+    u8,
+    ChainingHasher<Blake3_192Hasher>,
+    CompressionFunctionFromHasher<Blake3_192Hasher, COMPRESSION_INPUTS, BLAKE_192_DIGEST_SIZE>,
+    BLAKE_192_DIGEST_SIZE,
+    BLAKE_192_DIGEST_SIZE,
+>;
+
+/// Blake3_192 challenger.
+type Blake192Challenger =
+    SerializingChallenger64<Felt, HashChallenger<u8, Blake3_192Hasher, BLAKE_192_DIGEST_SIZE>>;
+
+/// Concrete STARK configuration type for Blake3_192.
+pub type Blake3_192Config = MidenStarkConfig<Blake192Lmcs, Blake192Challenger>;
+
+/// Creates a Blake3_192-based STARK configuration bound to `relation_digest`.
+///
+/// Commitments and Fiat-Shamir challenges use the first 24 bytes of the Blake3 output, giving
+/// 96 bits of collision resistance.
+pub fn blake3_192_config(params: PcsParams, relation_digest: RelationDigest) -> Blake3_192Config {
+    let hasher = Blake3_192Hasher::new(Blake3Hasher);
+    let lmcs =
+        LmcsConfig::new(ChainingHasher::new(hasher), CompressionFunctionFromHasher::new(hasher));
+    let mut challenger = SerializingChallenger64::new(HashChallenger::new(vec![], hasher));
+    challenger.observe_slice(&relation_digest);
+    GenericStarkConfig::new(params, lmcs, Radix2DitParallel::default(), challenger)
+}
+
 // KECCAK
 // ================================================================================================
 
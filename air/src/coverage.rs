@@ -0,0 +1,332 @@
+use alloc::{sync::Arc, vec::Vec};
+use core::fmt;
+
+use miden_core::{
+    Felt,
+    field::{PrimeCharacteristicRing, QuadFelt},
+    utils::RowMajorMatrix,
+};
+use miden_crypto::stark::air::{
+    BaseAir, LiftedAir,
+    symbolic::{BaseEntry, BaseLeaf, SymbolicAirBuilder, SymbolicExpression, SymbolicVariable},
+};
+
+use crate::{AIRS, MIDEN_AIR_COUNT, MidenAir};
+
+// CONSTRAINT COVERAGE
+// ================================================================================================
+
+/// Identifies a single base-field constraint of one of the Miden VM AIRs.
+///
+/// `index` follows the order in which the AIR asserts its constraints, which is also the order
+/// used by [`MidenAir::periodic_column_info`] and by ACE codegen.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ConstraintId {
+    pub air: MidenAir,
+    pub index: usize,
+}
+
+impl fmt::Display for ConstraintId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}]", self.air.name(), self.index)
+    }
+}
+
+/// Tracks, across a corpus of execution traces, on how many rows each base-field constraint of
+/// the Miden VM AIRs was active.
+///
+/// A constraint is active on a row when all of its selectors are nonzero there. Selectors are the
+/// left operands of the products along the outermost multiplication chain of the constraint; this
+/// is the shape produced by `builder.when(s).assert_zero(x)` and by the `flag * expr` idiom used
+/// throughout the constraint modules. Constraints without selectors are active on every row. A
+/// constraint which is never active is trivially satisfied by every trace of the corpus, meaning
+/// the corpus does not exercise it.
+///
+/// Only base-field constraints are tracked; the LogUp constraints over the auxiliary trace are
+/// not.
+pub struct ConstraintCoverage {
+    airs: [AirCoverage; MIDEN_AIR_COUNT],
+    num_traces: usize,
+}
+
+impl ConstraintCoverage {
+    /// Returns a new coverage tracker which has not seen any traces yet.
+    pub fn new() -> Self {
+        Self {
+            airs: AIRS.map(AirCoverage::new),
+            num_traces: 0,
+        }
+    }
+
+    /// Records the constraint activity of a single execution trace.
+    ///
+    /// `main_traces` contains the main trace of each AIR in [`AIRS`] order (as returned by
+    /// `ExecutionTrace::to_air_matrices`), and `public_values` are the AIR public values of the
+    /// trace (see [`PublicInputs::to_air_inputs`](crate::PublicInputs::to_air_inputs)).
+    ///
+    /// # Panics
+    ///
+    /// Panics if a main trace is empty or its width does not match the width of its AIR.
+    pub fn record(&mut self, main_traces: &[RowMajorMatrix<Felt>], public_values: &[Felt]) {
+        assert_eq!(main_traces.len(), MIDEN_AIR_COUNT, "one main trace is required per AIR");
+        for (coverage, main) in self.airs.iter_mut().zip(main_traces) {
+            coverage.record(main, public_values);
+        }
+        self.num_traces += 1;
+    }
+
+    /// Returns the number of traces recorded so far.
+    pub fn num_traces(&self) -> usize {
+        self.num_traces
+    }
+
+    /// Returns the number of tracked constraints of the specified AIR.
+    pub fn num_constraints(&self, air: MidenAir) -> usize {
+        self.airs[air.instance_index()].constraints.len()
+    }
+
+    /// Returns the number of rows, summed across all recorded traces, on which the specified
+    /// constraint was active.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` does not identify a base-field constraint of its AIR.
+    pub fn active_rows(&self, id: ConstraintId) -> usize {
+        let coverage = &self.airs[id.air.instance_index()];
+        coverage
+            .constraints
+            .iter()
+            .find(|constraint| constraint.index == id.index)
+            .unwrap_or_else(|| panic!("{id} is not a base-field constraint"))
+            .active_rows
+    }
+
+    /// Returns the constraints which were not active on any row of the recorded traces.
+    pub fn never_active(&self) -> Vec<ConstraintId> {
+        self.airs
+            .iter()
+            .flat_map(|coverage| {
+                coverage
+                    .constraints
+                    .iter()
+                    .filter(|constraint| constraint.active_rows == 0)
+                    .map(|constraint| ConstraintId {
+                        air: coverage.air,
+                        index: constraint.index,
+                    })
+            })
+            .collect()
+    }
+}
+
+impl Default for ConstraintCoverage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for ConstraintCoverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "constraint coverage over {} trace(s):", self.num_traces)?;
+        for coverage in &self.airs {
+            let never_active: Vec<usize> = coverage
+                .constraints
+                .iter()
+                .filter(|constraint| constraint.active_rows == 0)
+                .map(|constraint| constraint.index)
+                .collect();
+            writeln!(
+                f,
+                "  {}: {} of {} constraints active",
+                coverage.air.name(),
+                coverage.constraints.len() - never_active.len(),
+                coverage.constraints.len()
+            )?;
+            if !never_active.is_empty() {
+                writeln!(f, "    never active: {never_active:?}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// AIR COVERAGE
+// ================================================================================================
+
+/// Coverage of the base-field constraints of a single AIR.
+struct AirCoverage {
+    air: MidenAir,
+    constraints: Vec<TrackedConstraint>,
+    periodic_columns: Vec<Vec<Felt>>,
+}
+
+struct TrackedConstraint {
+    index: usize,
+    selectors: Vec<Arc<SymbolicExpression<Felt>>>,
+    active_rows: usize,
+}
+
+impl AirCoverage {
+    fn new(air: MidenAir) -> Self {
+        let air_layout = <MidenAir as LiftedAir<Felt, QuadFelt>>::air_layout(&air);
+        let mut builder = SymbolicAirBuilder::<Felt, QuadFelt>::new(air_layout);
+        <MidenAir as LiftedAir<Felt, QuadFelt>>::eval(&air, &mut builder);
+        let layout = builder.constraint_layout();
+
+        let constraints = builder
+            .base_constraints()
+            .iter()
+            .zip(layout.base_indices)
+            .map(|(expr, index)| TrackedConstraint {
+                index,
+                selectors: selectors(expr),
+                active_rows: 0,
+            })
+            .collect();
+
+        Self {
+            air,
+            constraints,
+            periodic_columns: BaseAir::periodic_columns(&air),
+        }
+    }
+
+    fn record(&mut self, main: &RowMajorMatrix<Felt>, public_values: &[Felt]) {
+        let width = main.width;
+        assert_eq!(
+            width,
+            BaseAir::width(&self.air),
+            "{} main trace width mismatch",
+            self.air.name()
+        );
+        let height = main.values.len() / width;
+        assert!(height > 0, "{} main trace is empty", self.air.name());
+
+        for row in 0..height {
+            let next_row = (row + 1) % height;
+            let periodic: Vec<Felt> =
+                self.periodic_columns.iter().map(|column| column[row % column.len()]).collect();
+            let valuation = RowValuation {
+                current: &main.values[row * width..(row + 1) * width],
+                next: &main.values[next_row * width..(next_row + 1) * width],
+                periodic: &periodic,
+                public_values,
+                is_first_row: row == 0,
+                is_last_row: row == height - 1,
+            };
+
+            for constraint in self.constraints.iter_mut() {
+                if constraint.selectors.iter().all(|s| valuation.eval(s) != Felt::ZERO) {
+                    constraint.active_rows += 1;
+                }
+            }
+        }
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Values of the leaves of a symbolic expression on a single trace row.
+struct RowValuation<'a> {
+    current: &'a [Felt],
+    next: &'a [Felt],
+    periodic: &'a [Felt],
+    public_values: &'a [Felt],
+    is_first_row: bool,
+    is_last_row: bool,
+}
+
+impl RowValuation<'_> {
+    fn eval(&self, expr: &SymbolicExpression<Felt>) -> Felt {
+        match expr {
+            SymbolicExpression::Leaf(leaf) => self.eval_leaf(leaf),
+            SymbolicExpression::Add { x, y, .. } => self.eval(x) + self.eval(y),
+            SymbolicExpression::Sub { x, y, .. } => self.eval(x) - self.eval(y),
+            SymbolicExpression::Neg { x, .. } => -self.eval(x),
+            SymbolicExpression::Mul { x, y, .. } => self.eval(x) * self.eval(y),
+        }
+    }
+
+    fn eval_leaf(&self, leaf: &BaseLeaf<Felt>) -> Felt {
+        match leaf {
+            BaseLeaf::Constant(c) => *c,
+            BaseLeaf::Variable(SymbolicVariable { entry, index, .. }) => match entry {
+                BaseEntry::Main { offset: 0 } => self.current[*index],
+                BaseEntry::Main { offset: 1 } => self.next[*index],
+                BaseEntry::Periodic => self.periodic[*index],
+                BaseEntry::Public => self.public_values[*index],
+                BaseEntry::Main { offset } => panic!("unexpected main offset {offset}"),
+                BaseEntry::Preprocessed { .. } => {
+                    panic!("Miden VM AIRs do not have preprocessed columns")
+                },
+            },
+            BaseLeaf::IsFirstRow => Felt::from_bool(self.is_first_row),
+            BaseLeaf::IsLastRow => Felt::from_bool(self.is_last_row),
+            BaseLeaf::IsTransition => Felt::from_bool(!self.is_last_row),
+        }
+    }
+}
+
+/// Returns the selectors of `expr`: the left operands of the products along its outermost
+/// multiplication chain.
+fn selectors(expr: &SymbolicExpression<Felt>) -> Vec<Arc<SymbolicExpression<Felt>>> {
+    let mut selectors = Vec::new();
+    let mut expr = expr;
+    while let SymbolicExpression::Mul { x, y, .. } = expr {
+        selectors.push(x.clone());
+        expr = y;
+    }
+    selectors
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NUM_PUBLIC_VALUES;
+
+    #[test]
+    fn constraint_coverage_accumulates_across_traces() {
+        let main_traces: Vec<RowMajorMatrix<Felt>> = AIRS
+            .iter()
+            .map(|air| {
+                let width = BaseAir::width(air);
+                RowMajorMatrix::new(vec![Felt::ZERO; width * 8], width)
+            })
+            .collect();
+        let public_values = [Felt::ZERO; NUM_PUBLIC_VALUES];
+
+        let mut coverage = ConstraintCoverage::new();
+        coverage.record(&main_traces, &public_values);
+        let never_active = coverage.never_active();
+        for air in AIRS {
+            let num_constraints = coverage.num_constraints(air);
+            assert!(num_constraints > 0, "{} has no base-field constraints", air.name());
+
+            // boundary constraints are active on the first or last row of any trace
+            let num_never_active = never_active.iter().filter(|id| id.air == air).count();
+            assert!(num_never_active < num_constraints, "{}", air.name());
+        }
+
+        // recording the same trace again doubles the activity but covers nothing new
+        let active_rows: Vec<usize> = coverage
+            .airs
+            .iter()
+            .flat_map(|air| &air.constraints)
+            .map(|c| c.active_rows)
+            .collect();
+        coverage.record(&main_traces, &public_values);
+        assert_eq!(coverage.num_traces(), 2);
+        assert_eq!(coverage.never_active(), never_active);
+        let doubled: Vec<usize> = coverage
+            .airs
+            .iter()
+            .flat_map(|air| &air.constraints)
+            .map(|c| c.active_rows)
+            .collect();
+        assert!(active_rows.iter().zip(doubled).all(|(&once, twice)| twice == 2 * once));
+    }
+}
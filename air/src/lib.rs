@@ -25,7 +25,10 @@ use proptest::prelude::*;
 pub mod ace;
 pub mod config;
 mod constraints;
+#[cfg(any(test, feature = "testing"))]
+mod coverage;
 pub mod lookup;
+mod periodic;
 mod proof_order;
 pub mod trace;
 
@@ -88,7 +91,10 @@ mod export {
     };
 }
 
+#[cfg(any(test, feature = "testing"))]
+pub use coverage::{ConstraintCoverage, ConstraintId};
 pub use export::*;
+pub use periodic::PeriodicColumnInfo;
 pub use proof_order::{
     AIRS, MIDEN_AIR_COUNT, PROOF_ORDER_COUNT, PROOF_ORDER_REGISTRY_DEPTH, ProofOrder,
 };
@@ -0,0 +1,138 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use miden_core::{Felt, field::QuadFelt};
+use miden_crypto::stark::air::{
+    BaseAir, LiftedAir,
+    symbolic::{
+        BaseEntry, BaseLeaf, ExtLeaf, SymbolicAirBuilder, SymbolicExpression, SymbolicExpressionExt,
+    },
+};
+
+use crate::MidenAir;
+
+// PERIODIC COLUMN INFO
+// ================================================================================================
+
+/// Describes a periodic column of an AIR and the constraints which read it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PeriodicColumnInfo {
+    /// Index of the column among the periodic columns of the AIR.
+    pub index: usize,
+    /// Number of rows after which the values of the column repeat.
+    pub period: usize,
+    /// Indexes of the constraints reading the column, in the order in which the AIR asserts them.
+    pub constraints: Vec<usize>,
+}
+
+impl MidenAir {
+    /// Returns the period of each periodic column of this AIR, together with the constraints
+    /// which reference it.
+    ///
+    /// Constraint indexes follow the order in which constraints are asserted by the AIR, which is
+    /// also the order in which ACE codegen folds them.
+    pub fn periodic_column_info(self) -> Vec<PeriodicColumnInfo> {
+        let air_layout = <Self as LiftedAir<Felt, QuadFelt>>::air_layout(&self);
+        let mut builder = SymbolicAirBuilder::<Felt, QuadFelt>::new(air_layout);
+        <Self as LiftedAir<Felt, QuadFelt>>::eval(&self, &mut builder);
+        let layout = builder.constraint_layout();
+
+        let mut columns: Vec<PeriodicColumnInfo> = BaseAir::periodic_columns(&self)
+            .iter()
+            .enumerate()
+            .map(|(index, values)| PeriodicColumnInfo {
+                index,
+                period: values.len(),
+                constraints: Vec::new(),
+            })
+            .collect();
+
+        let mut record = |constraint: usize, used: BTreeSet<usize>| {
+            for column in used {
+                columns[column].constraints.push(constraint);
+            }
+        };
+        for (expr, &constraint) in builder.base_constraints().iter().zip(&layout.base_indices) {
+            let mut used = BTreeSet::new();
+            collect_base(expr, &mut used);
+            record(constraint, used);
+        }
+        for (expr, &constraint) in builder.extension_constraints().iter().zip(&layout.ext_indices) {
+            let mut used = BTreeSet::new();
+            collect_ext(expr, &mut used);
+            record(constraint, used);
+        }
+
+        for column in columns.iter_mut() {
+            column.constraints.sort_unstable();
+        }
+        columns
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Adds the indexes of the periodic columns read by `expr` to `used`.
+fn collect_base(expr: &SymbolicExpression<Felt>, used: &mut BTreeSet<usize>) {
+    match expr {
+        SymbolicExpression::Leaf(BaseLeaf::Variable(v)) => {
+            if let BaseEntry::Periodic = v.entry {
+                used.insert(v.index);
+            }
+        },
+        SymbolicExpression::Leaf(_) => (),
+        SymbolicExpression::Add { x, y, .. }
+        | SymbolicExpression::Sub { x, y, .. }
+        | SymbolicExpression::Mul { x, y, .. } => {
+            collect_base(x, used);
+            collect_base(y, used);
+        },
+        SymbolicExpression::Neg { x, .. } => collect_base(x, used),
+    }
+}
+
+/// Adds the indexes of the periodic columns read by `expr` to `used`.
+fn collect_ext(expr: &SymbolicExpressionExt<Felt, QuadFelt>, used: &mut BTreeSet<usize>) {
+    match expr {
+        SymbolicExpressionExt::Leaf(ExtLeaf::Base(base)) => collect_base(base, used),
+        SymbolicExpressionExt::Leaf(_) => (),
+        SymbolicExpressionExt::Add { x, y, .. }
+        | SymbolicExpressionExt::Sub { x, y, .. }
+        | SymbolicExpressionExt::Mul { x, y, .. } => {
+            collect_ext(x, used);
+            collect_ext(y, used);
+        },
+        SymbolicExpressionExt::Neg { x, .. } => collect_ext(x, used),
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AIRS;
+
+    #[test]
+    fn periodic_column_info_matches_air_periodic_columns() {
+        for air in AIRS {
+            let periodic_columns = BaseAir::periodic_columns(&air);
+            let info = air.periodic_column_info();
+
+            assert_eq!(info.len(), periodic_columns.len(), "{}", air.name());
+            for (column, values) in info.iter().zip(&periodic_columns) {
+                assert_eq!(column.period, values.len(), "{} column {}", air.name(), column.index);
+                assert!(column.period.is_power_of_two());
+                assert!(
+                    !column.constraints.is_empty(),
+                    "{} periodic column {} is not used by any constraint",
+                    air.name(),
+                    column.index
+                );
+            }
+        }
+
+        assert!(MidenAir::Core.periodic_column_info().is_empty());
+    }
+}
@@ -40,6 +40,7 @@ use miden_vm_synthetic_bench::{
     snapshot::{TraceShape, TraceSnapshot},
     snippets::{SNIPPETS, memory_max_iters, u32arith_max_iters},
     solver::{Plan, emit, solve},
+    validator::validate_generated,
     verifier::VerificationReport,
 };
 
@@ -264,7 +265,9 @@ fn bench_one_scenario(
         println!("\n=== wrote MASM dump to {}", out.display());
     }
 
-    let actual = measure_program(&source).expect("measure emitted program");
+    let actual = validate_generated(&source).expect("emitted program failed validation");
+    println!("\n=== validation: {} cycles", actual.totals.core_rows);
+
     let report = VerificationReport::new(target_shape, actual);
     println!("\n=== verification\n{report}");
     assert!(
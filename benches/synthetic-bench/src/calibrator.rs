@@ -5,8 +5,9 @@
 
 use std::collections::BTreeMap;
 
+use miden_core_lib::CoreLibrary;
 use miden_processor::{DefaultHost, FastProcessor, StackInputs, trace::build_trace};
-use miden_vm::Assembler;
+use miden_vm::{Assembler, assembly::Linkage};
 
 use crate::{
     snapshot::{TraceBreakdown, TraceShape, TraceTotals},
@@ -19,14 +20,22 @@ pub const CALIBRATION_ITERS: u64 = 1000;
 // ------------------------------------------------------------------------
 
 /// Assemble and execute `source`, returning the shape of the resulting execution trace. Wraps
-/// assembler + fast processor + trace builder.
+/// assembler + fast processor + trace builder, with the core library linked in.
 pub fn measure_program(source: &str) -> Result<TraceShape, MeasurementError> {
-    let program = Assembler::default()
+    let core_lib = CoreLibrary::default();
+    let mut assembler = Assembler::default();
+    assembler
+        .link_package(core_lib.package(), Linkage::Dynamic)
+        .map_err(|e| MeasurementError::Assembly(format!("{e}")))?;
+    let program = assembler
         .assemble_program("program", source)
         .map_err(|e| MeasurementError::Assembly(format!("{e}")))?
-        .unwrap_program();
+        .try_into_program()
+        .map_err(|e| MeasurementError::Assembly(format!("{e}")))?;
 
-    let mut host = DefaultHost::default();
+    let mut host = DefaultHost::default()
+        .with_library(&core_lib)
+        .map_err(|e| MeasurementError::Execution(format!("{e}")))?;
     let processor = FastProcessor::new(StackInputs::default());
     let trace_inputs = processor
         .execute_trace_inputs_sync(&program, &mut host)
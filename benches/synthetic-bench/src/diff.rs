@@ -0,0 +1,197 @@
+//! Regression detection between two trace snapshots.
+//!
+//! Compares a candidate snapshot against a baseline (e.g. the committed `bench-tx.json` against a
+//! freshly produced one) and reports per-component row deltas plus the overall change in trace
+//! rows. [`SnapshotDiff::regressions`] filters the components that grew by more than a threshold,
+//! so CI can flag workloads that got more expensive to prove.
+//!
+//! All percentages follow the verifier's convention: they are expressed as fractions, i.e. `0.05`
+//! means 5%.
+
+use std::fmt::{self, Display};
+
+use crate::snapshot::{TraceShape, TraceSnapshot};
+
+/// Result of comparing a snapshot against a baseline.
+#[derive(Debug, Clone)]
+pub struct SnapshotDiff {
+    /// Per-component row deltas, covering both the hard totals and the advisory breakdown.
+    pub components: Vec<RowDelta>,
+    /// Delta of the overall number of AIR trace rows (core side + chiplets + Poseidon2).
+    pub total: RowDelta,
+}
+
+/// Row-count change of a single component between a baseline and the current snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RowDelta {
+    pub name: &'static str,
+    pub baseline: u64,
+    pub current: u64,
+}
+
+/// A component whose row count grew beyond the requested threshold.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Regression {
+    pub name: &'static str,
+    pub baseline: u64,
+    pub current: u64,
+    pub delta_pct: f64,
+}
+
+impl RowDelta {
+    fn new(name: &'static str, baseline: u64, current: u64) -> Self {
+        Self { name, baseline, current }
+    }
+
+    /// Signed row-count change (`current - baseline`).
+    pub fn delta(&self) -> i64 {
+        self.current as i64 - self.baseline as i64
+    }
+
+    /// Relative change against the baseline. A component that appears out of nothing reports
+    /// `+inf`; a component that is zero on both sides reports `0`.
+    pub fn delta_pct(&self) -> f64 {
+        if self.baseline == 0 {
+            if self.current == 0 { 0.0 } else { f64::INFINITY }
+        } else {
+            self.delta() as f64 / self.baseline as f64
+        }
+    }
+}
+
+impl SnapshotDiff {
+    /// Compare `current` against `baseline`.
+    pub fn new(current: &TraceShape, baseline: &TraceShape) -> Self {
+        let components = vec![
+            RowDelta::new("core_rows", baseline.totals.core_rows, current.totals.core_rows),
+            RowDelta::new(
+                "chiplets_rows",
+                baseline.totals.chiplets_rows,
+                current.totals.chiplets_rows,
+            ),
+            RowDelta::new(
+                "poseidon2_rows",
+                baseline.totals.poseidon2_permutation_rows,
+                current.totals.poseidon2_permutation_rows,
+            ),
+            RowDelta::new("range_rows", baseline.totals.range_rows, current.totals.range_rows),
+            RowDelta::new("hasher", baseline.hasher_work_rows(), current.hasher_work_rows()),
+            RowDelta::new(
+                "bitwise",
+                baseline.breakdown.bitwise_rows,
+                current.breakdown.bitwise_rows,
+            ),
+            RowDelta::new(
+                "memory",
+                baseline.breakdown.memory_target(),
+                current.breakdown.memory_target(),
+            ),
+        ];
+        let total = RowDelta::new("total_rows", total_rows(baseline), total_rows(current));
+        Self { components, total }
+    }
+
+    /// Components whose row count grew by strictly more than `threshold_pct` (as a fraction of
+    /// the baseline), in the order they appear in [`Self::components`].
+    pub fn regressions(&self, threshold_pct: f64) -> Vec<Regression> {
+        self.components
+            .iter()
+            .filter(|d| d.delta_pct() > threshold_pct)
+            .map(|d| Regression {
+                name: d.name,
+                baseline: d.baseline,
+                current: d.current,
+                delta_pct: d.delta_pct(),
+            })
+            .collect()
+    }
+}
+
+impl TraceSnapshot {
+    /// Compare this snapshot against `baseline`.
+    pub fn diff(&self, baseline: &TraceSnapshot) -> SnapshotDiff {
+        SnapshotDiff::new(&self.shape(), &baseline.shape())
+    }
+}
+
+/// Total AIR trace rows: the core side (whose height also covers the range checker), plus the
+/// chiplets and Poseidon2 permutation traces.
+fn total_rows(shape: &TraceShape) -> u64 {
+    shape.totals.core_rows.max(shape.totals.range_rows)
+        + shape.totals.chiplets_rows
+        + shape.totals.poseidon2_permutation_rows
+}
+
+impl Display for SnapshotDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<16} {:>12} {:>12} {:>10}", "component", "baseline", "current", "delta")?;
+        for d in self.components.iter().chain(core::iter::once(&self.total)) {
+            let delta_str = if d.delta_pct().is_finite() {
+                format!("{:+6.2}%", d.delta_pct() * 100.0)
+            } else {
+                "+∞".to_string()
+            };
+            writeln!(f, "{:<16} {:>12} {:>12} {:>10}", d.name, d.baseline, d.current, delta_str)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snapshot::{TraceBreakdown, TraceTotals};
+
+    fn shape(core: u64, hasher: u64, memory: u64) -> TraceShape {
+        let breakdown = TraceBreakdown {
+            hasher_rows: hasher,
+            bitwise_rows: 0,
+            memory_rows: memory,
+            kernel_rom_rows: 0,
+            ace_rows: 0,
+        };
+        let totals = TraceTotals {
+            core_rows: core,
+            chiplets_rows: breakdown.chiplets_sum(),
+            poseidon2_permutation_rows: hasher,
+            range_rows: 0,
+        };
+        TraceShape::new(totals, breakdown)
+    }
+
+    #[test]
+    fn identical_snapshots_have_no_regressions() {
+        let s = shape(68000, 8000, 12000);
+        let diff = SnapshotDiff::new(&s, &s);
+        assert!(diff.components.iter().all(|d| d.delta() == 0));
+        assert_eq!(diff.total.delta_pct(), 0.0);
+        assert!(diff.regressions(0.0).is_empty());
+    }
+
+    #[test]
+    fn core_regression_is_detected() {
+        let baseline = shape(68000, 8000, 12000);
+        // core grows by 10%, memory shrinks by 50%
+        let current = shape(74800, 8000, 6000);
+        let diff = SnapshotDiff::new(&current, &baseline);
+
+        let regressions = diff.regressions(0.05);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].name, "core_rows");
+        assert_eq!(regressions[0].baseline, 68000);
+        assert_eq!(regressions[0].current, 74800);
+        assert!((regressions[0].delta_pct - 0.1).abs() < 1e-9);
+
+        // a looser threshold tolerates the same change
+        assert!(diff.regressions(0.2).is_empty());
+    }
+
+    #[test]
+    fn new_component_is_an_infinite_regression() {
+        let baseline = shape(1000, 0, 0);
+        let current = shape(1000, 16, 0);
+        let diff = SnapshotDiff::new(&current, &baseline);
+        let regressions = diff.regressions(1.0);
+        assert!(regressions.iter().any(|r| r.name == "hasher" && r.delta_pct.is_infinite()));
+    }
+}
@@ -10,10 +10,15 @@
 //!   `range_rows`)
 //! - `shape`: advisory per-chiplet breakdown used by the solver
 //!
+//! [`validator`] assembles and dry-runs emitted MASM before it is benchmarked; [`diff`] compares
+//! two snapshots to detect row-count regressions between them.
+//!
 //! See `README.md` for design rationale.
 
 pub mod calibrator;
+pub mod diff;
 pub mod snapshot;
 pub mod snippets;
 pub mod solver;
+pub mod validator;
 pub mod verifier;
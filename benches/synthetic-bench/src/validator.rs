@@ -0,0 +1,74 @@
+//! Pre-flight validation of generated benchmark MASM.
+//!
+//! Before a synthetic program is handed to Criterion, it is assembled against the core library
+//! and dry-run on the fast processor with default (empty) stack inputs. This catches emitter bugs
+//! early, rather than benchmarking a program that fails to assemble or traps at runtime.
+
+use crate::{
+    calibrator::{MeasurementError, measure_program},
+    snapshot::TraceShape,
+};
+
+/// Assemble `masm` with the core library linked in, execute it under the fast processor, and
+/// return the shape of the execution trace it produces.
+///
+/// This is [`measure_program`], with its failures sorted into whether the program is at fault
+/// or the trace shape formulas of the benchmark drifted from the processor.
+pub fn validate_generated(masm: &str) -> Result<TraceShape, ValidationError> {
+    measure_program(masm).map_err(|err| match err {
+        MeasurementError::Assembly(err) => ValidationError::Assembly(err),
+        MeasurementError::Execution(err) | MeasurementError::TraceBuild(err) => {
+            ValidationError::Execution(err)
+        },
+        err @ MeasurementError::InvariantDrift { .. } => ValidationError::Measurement(err),
+    })
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ValidationError {
+    #[error("generated program failed to assemble: {0}")]
+    Assembly(String),
+    #[error("generated program failed to execute: {0}")]
+    Execution(String),
+    #[error(transparent)]
+    Measurement(MeasurementError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::{Plan, emit};
+
+    #[test]
+    fn generated_benchmark_is_valid() {
+        let mut plan = Plan::new();
+        plan.set("hasher", 4);
+        plan.set("bitwise", 4);
+        plan.set("memory", 4);
+        plan.set("decoder_pad", 16);
+        let source = emit(&plan);
+
+        let shape = validate_generated(&source).expect("generated program should validate");
+        assert!(shape.totals.core_rows > 0);
+        assert!(shape.totals.padded_total() >= shape.totals.core_rows);
+    }
+
+    #[test]
+    fn core_library_procedures_are_available() {
+        let source =
+            "use miden::core::math::u64\nbegin push.1.0 push.2.0 exec.u64::wrapping_add dropw end";
+        validate_generated(source).expect("core library should be linked");
+    }
+
+    #[test]
+    fn invalid_masm_is_reported_as_assembly_error() {
+        let err = validate_generated("begin not_an_instruction end").unwrap_err();
+        assert!(matches!(err, ValidationError::Assembly(_)));
+    }
+
+    #[test]
+    fn trapping_program_is_reported_as_execution_error() {
+        let err = validate_generated("begin push.0 assert end").unwrap_err();
+        assert!(matches!(err, ValidationError::Execution(_)));
+    }
+}
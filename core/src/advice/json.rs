@@ -0,0 +1,184 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use serde::Deserialize;
+
+use super::AdviceInputs;
+use crate::{
+    Felt, Word,
+    crypto::merkle::{MerkleStore, MerkleTree},
+    field::QuotientMap,
+    program::InputError,
+};
+
+// JSON ADVICE INPUTS
+// ================================================================================================
+
+/// The JSON representation of [`AdviceInputs`] accepted by [`AdviceInputs::from_json`].
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct JsonAdviceInputs {
+    stack: Vec<u64>,
+    map: BTreeMap<String, Vec<u64>>,
+    merkle: Vec<Vec<String>>,
+}
+
+impl AdviceInputs {
+    /// Parses advice inputs from a JSON string.
+    ///
+    /// The JSON document is an object with the following optional sections:
+    /// - `stack`: an array of integers, the first of which is at the top of the advice stack.
+    /// - `map`: an object mapping hex-encoded words (e.g., `"0x0100...00"`) to arrays of integers.
+    /// - `merkle`: an array of Merkle trees, each given as an array of hex-encoded leaf words. The
+    ///   nodes of every tree are added to the Merkle store.
+    ///
+    /// For example:
+    ///
+    /// ```json
+    /// {
+    ///     "stack": [1, 2, 3],
+    ///     "map": {
+    ///         "0x0100000000000000000000000000000000000000000000000000000000000000": [4, 5]
+    ///     },
+    ///     "merkle": [
+    ///         [
+    ///             "0x0100000000000000000000000000000000000000000000000000000000000000",
+    ///             "0x0200000000000000000000000000000000000000000000000000000000000000"
+    ///         ]
+    ///     ]
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the document is not valid JSON or does not follow the schema above.
+    /// - any of the advice stack or advice map values is not a valid field element.
+    /// - any of the advice map keys or Merkle tree leaves is not a valid hex-encoded word.
+    /// - any of the Merkle trees does not have a power-of-two number of leaves.
+    pub fn from_json(json: &str) -> Result<Self, InputError> {
+        let inputs: JsonAdviceInputs =
+            serde_json::from_str(json).map_err(|err| InputError::InvalidJson(err.to_string()))?;
+
+        let map = inputs
+            .map
+            .into_iter()
+            .map(|(key, values)| {
+                let word = match Word::try_from(key.as_str()) {
+                    Ok(word) => word,
+                    Err(err) => {
+                        return Err(InputError::InvalidAdviceMapKey {
+                            key,
+                            reason: err.to_string(),
+                        });
+                    },
+                };
+                let values = values
+                    .into_iter()
+                    .map(|value| {
+                        Felt::from_canonical_checked(value).ok_or_else(|| {
+                            InputError::InvalidAdviceMapValue { key: key.clone(), value }
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok((word, values))
+            })
+            .collect::<Result<Vec<_>, InputError>>()?;
+
+        let mut store = MerkleStore::default();
+        for leaves in inputs.merkle {
+            let leaves = leaves
+                .iter()
+                .map(|leaf| {
+                    Word::try_from(leaf.as_str()).map_err(|err| {
+                        InputError::InvalidMerkleTree(alloc::format!(
+                            "invalid leaf '{leaf}': {err}"
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let tree = MerkleTree::new(leaves)
+                .map_err(|err| InputError::InvalidMerkleTree(err.to_string()))?;
+            store.extend(tree.inner_nodes());
+        }
+
+        Ok(Self::default()
+            .with_stack(parse_stack(inputs.stack)?)
+            .with_map(map)
+            .with_merkle_store(store))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn parse_stack(values: Vec<u64>) -> Result<Vec<Felt>, InputError> {
+    values
+        .into_iter()
+        .map(|v| Felt::from_canonical_checked(v).ok_or(InputError::InvalidStackElement(v)))
+        .collect()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::AdviceInputs;
+    use crate::{
+        Felt, ONE, Word, ZERO,
+        crypto::merkle::{MerkleTree, NodeIndex},
+        program::InputError,
+    };
+
+    #[test]
+    fn from_json_loads_merkle_trees_into_store() {
+        let inputs = AdviceInputs::from_json(
+            r#"{"merkle": [[
+                "0x0100000000000000000000000000000000000000000000000000000000000000",
+                "0x0200000000000000000000000000000000000000000000000000000000000000"
+            ]]}"#,
+        )
+        .unwrap();
+        assert!(inputs.stack.is_empty());
+
+        let leaves = [
+            Word::from([ONE, ZERO, ZERO, ZERO]),
+            Word::from([Felt::from_u32(2), ZERO, ZERO, ZERO]),
+        ];
+        let tree = MerkleTree::new(leaves).unwrap();
+        let index = NodeIndex::new(1, 1).unwrap();
+        assert_eq!(inputs.store.get_node(tree.root(), index).unwrap(), leaves[1]);
+    }
+
+    #[test]
+    fn from_json_rejects_malformed_fixtures() {
+        assert!(matches!(
+            AdviceInputs::from_json(r#"{"stack": [18446744073709551615]}"#),
+            Err(InputError::InvalidStackElement(_))
+        ));
+        assert!(matches!(
+            AdviceInputs::from_json(
+                r#"{"map": {"0x0100000000000000000000000000000000000000000000000000000000000000": [18446744073709551615]}}"#
+            ),
+            Err(InputError::InvalidAdviceMapValue { key, value: u64::MAX })
+                if key == "0x0100000000000000000000000000000000000000000000000000000000000000"
+        ));
+        assert!(matches!(
+            AdviceInputs::from_json(r#"{"map": {"0x01": [1]}}"#),
+            Err(InputError::InvalidAdviceMapKey { .. })
+        ));
+        assert!(matches!(
+            AdviceInputs::from_json(
+                r#"{"merkle": [["0x0100000000000000000000000000000000000000000000000000000000000000"]]}"#
+            ),
+            Err(InputError::InvalidMerkleTree(_))
+        ));
+        assert!(matches!(
+            AdviceInputs::from_json(r#"{"advice": []}"#),
+            Err(InputError::InvalidJson(_))
+        ));
+    }
+}
@@ -14,6 +14,9 @@ pub use map::AdviceMap;
 mod stack;
 pub use stack::AdviceStackBuilder;
 
+#[cfg(all(feature = "std", feature = "json"))]
+mod json;
+
 // ADVICE INPUTS
 // ================================================================================================
 
@@ -39,6 +42,16 @@ impl AdviceInputs {
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns advice inputs which initialize an advice provider to the state captured in the
+    /// provided snapshot.
+    ///
+    /// Advice inputs describe advice data only, so the number of advice map reads recorded in the
+    /// snapshot is dropped.
+    pub fn from_provider_snapshot(snapshot: AdviceProviderSnapshot) -> Self {
+        let AdviceProviderSnapshot { stack, map, store, .. } = snapshot;
+        Self { stack, map, store }
+    }
+
     /// Attempts to extend the stack values with the given sequence of integers, returning an error
     /// if any of the numbers fails while converting to an element `[Felt]`.
     pub fn with_stack_values<I>(mut self, iter: I) -> Result<Self, InputError>
@@ -107,6 +120,47 @@ impl Deserializable for AdviceInputs {
     }
 }
 
+// ADVICE PROVIDER SNAPSHOT
+// ================================================================================================
+
+/// The full state of an advice provider at some point of execution.
+///
+/// Unlike [`AdviceInputs`], which describe the advice available at the start of a program
+/// execution, a snapshot captures advice as it was left by previous executions (e.g., values
+/// pushed onto the advice stack, inserted into the advice map, or Merkle nodes added to the store
+/// by the program and its host). This allows long-lived services to checkpoint advice data
+/// between program executions, and to reload it via [`AdviceInputs::from_provider_snapshot()`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AdviceProviderSnapshot {
+    /// The advice stack, ordered from top (index 0) to bottom.
+    pub stack: Vec<Felt>,
+    pub map: AdviceMap,
+    pub store: MerkleStore,
+    /// The number of advice map reads performed by the executions so far, which counts towards
+    /// the advice map read limit of the executions the snapshot is reloaded into.
+    pub map_read_count: usize,
+}
+
+impl Serializable for AdviceProviderSnapshot {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let Self { stack, map, store, map_read_count } = self;
+        stack.write_into(target);
+        map.write_into(target);
+        store.write_into(target);
+        target.write_usize(*map_read_count);
+    }
+}
+
+impl Deserializable for AdviceProviderSnapshot {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let stack = Vec::<Felt>::read_from(source)?;
+        let map = AdviceMap::read_from(source)?;
+        let store = MerkleStore::read_from(source)?;
+        let map_read_count = source.read_usize()?;
+        Ok(Self { stack, map, store, map_read_count })
+    }
+}
+
 // TESTS
 // ================================================================================================
 
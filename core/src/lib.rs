@@ -88,7 +88,7 @@ pub mod crypto {
 
     pub mod hash {
         pub use miden_crypto::hash::{
-            blake::{Blake3_256, Blake3Digest},
+            blake::{Blake3_192, Blake3_256, Blake3Digest},
             keccak::Keccak256,
             poseidon2::Poseidon2,
             rpo::Rpo256,
@@ -115,6 +115,10 @@ pub mod prettier {
 
 /// The initial value for the frame pointer, corresponding to the start address for procedure
 /// locals.
+///
+/// This value is part of the VM's execution semantics rather than an execution parameter: the
+/// assembler writes it to [`FMP_ADDR`] in the program prologue, and the AIR constrains the write
+/// performed when `CALL`/`DYNCALL` enter a new context to use this value.
 pub const FMP_INIT_VALUE: Felt = Felt::new_unchecked(2_u64.pow(31));
 
 /// The address where the frame pointer is stored in memory.
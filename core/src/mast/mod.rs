@@ -370,7 +370,10 @@ fn final_dense_node_order(
         {
             return Err(MastForestError::InvalidNodeOrder {
                 node_id,
-                reason: "external node digests must be strictly increasing".into(),
+                reason: format!(
+                    "external node digests must be strictly increasing (at {})",
+                    nodes[node_id].label()
+                ),
             });
         }
         previous_external_digest = Some(digest);
@@ -439,7 +442,10 @@ fn final_dense_node_order(
             .expect("internal cycle must contain a pending node");
         return Err(MastForestError::InvalidNodeOrder {
             node_id,
-            reason: "internal nodes must form an acyclic child-before-parent graph".into(),
+            reason: format!(
+                "internal nodes must form an acyclic child-before-parent graph (at {})",
+                nodes[node_id].label()
+            ),
         });
     }
 
@@ -466,7 +472,10 @@ fn validate_dense_node_order(
         if node_class < previous_class {
             return Err(MastForestError::InvalidNodeOrder {
                 node_id,
-                reason: format!("node class {node_class:?} appears after {previous_class:?}"),
+                reason: format!(
+                    "node class {node_class:?} appears after {previous_class:?} (at {})",
+                    node.label()
+                ),
             });
         }
         previous_class = node_class;
@@ -478,7 +487,10 @@ fn validate_dense_node_order(
             {
                 return Err(MastForestError::InvalidNodeOrder {
                     node_id,
-                    reason: "external node digests must be strictly increasing".into(),
+                    reason: format!(
+                        "external node digests must be strictly increasing (at {})",
+                        node.label()
+                    ),
                 });
             }
             previous_external_digest = Some(digest);
@@ -775,6 +787,30 @@ impl MastForest {
         self.nodes.get(node_id)
     }
 
+    /// Returns a short, deterministic label for the specified node, e.g. `block@1a2b3c4d`.
+    ///
+    /// See [`MastNode::label`] for details.
+    ///
+    /// # Panics
+    /// Panics if the specified node is not present in this forest.
+    pub fn node_label(&self, node_id: MastNodeId) -> String {
+        self[node_id].label()
+    }
+
+    /// Returns a listing of the nodes in this forest, one node per line, in node ID order.
+    ///
+    /// Each line contains the node index and [label](Self::node_label), followed by the labels
+    /// of the node's children, if any; procedure roots are marked as such, e.g.:
+    ///
+    /// ```text
+    /// 0: block@1a2b3c4d
+    /// 1: block@5e6f7a8b
+    /// 2: join@9c0d1e2f (root) <- block@1a2b3c4d, block@5e6f7a8b
+    /// ```
+    pub fn listing(&self) -> impl fmt::Display + '_ {
+        MastForestListing { mast_forest: self }
+    }
+
     /// Returns the [`MastNodeId`] of the procedure associated with a given digest, if any.
     #[inline(always)]
     pub fn find_procedure_root(&self, digest: Word) -> Option<MastNodeId> {
@@ -1066,6 +1102,36 @@ impl Index<MastNodeId> for MastForest {
     }
 }
 
+// MAST FOREST LISTING
+// ------------------------------------------------------------------------------------------------
+
+struct MastForestListing<'a> {
+    mast_forest: &'a MastForest,
+}
+
+impl fmt::Display for MastForestListing<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, node) in self.mast_forest.nodes().iter().enumerate() {
+            write!(f, "{index}: {}", node.label())?;
+            if self.mast_forest.is_procedure_root(MastNodeId::new_unchecked(index as u32)) {
+                f.write_str(" (root)")?;
+            }
+
+            let mut separator = " <- ";
+            let mut result = Ok(());
+            node.for_each_child(|child_id| {
+                if result.is_ok() {
+                    result = write!(f, "{separator}{}", self.mast_forest.node_label(child_id));
+                    separator = ", ";
+                }
+            });
+            result?;
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
 // EXECUTABLE MAST FOREST
 // ================================================================================================
 
@@ -1,5 +1,5 @@
 mod basic_block_node;
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, format, string::String, vec::Vec};
 use core::fmt;
 
 pub(crate) use basic_block_node::collect_immediate_placements;
@@ -199,6 +199,27 @@ impl MastNode {
             other => unwrap_failed(other, "external"),
         }
     }
+
+    /// Returns a short label identifying this node, e.g. `block@1a2b3c4d`.
+    ///
+    /// The label consists of the node kind followed by the first 8 hex characters of the node's
+    /// digest, and thus does not depend on where the node is located in a MAST forest.
+    pub fn label(&self) -> String {
+        let kind = match self {
+            MastNode::Block(_) => "block",
+            MastNode::Join(_) => "join",
+            MastNode::Split(_) => "split",
+            MastNode::Loop(_) => "loop",
+            MastNode::Call(call_node) if call_node.is_syscall() => "syscall",
+            MastNode::Call(_) => "call",
+            MastNode::Dyn(dyn_node) if dyn_node.is_dyncall() => "dyncall",
+            MastNode::Dyn(_) => "dyn",
+            MastNode::External(_) => "external",
+        };
+        let digest = self.digest().to_hex();
+        let digest = digest.strip_prefix("0x").unwrap_or(&digest);
+        format!("{kind}@{}", &digest[..8])
+    }
 }
 
 // HELPERS
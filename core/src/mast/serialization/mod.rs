@@ -98,6 +98,7 @@ use super::{MastForest, MastNode, MastNodeId};
 use crate::{
     Word,
     advice::AdviceMap,
+    crypto::hash::Poseidon2,
     mast::node::MastNodeExt,
     serde::{
         BudgetedReader, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
@@ -231,6 +232,12 @@ impl MastForest {
     /// Both forms use the finalized dense node order already stored in the `MastForest`; writers
     /// validate that order but do not sort nodes while writing.
     fn write_into_with_options<W: ByteWriter>(&self, target: &mut W, hashless: bool) {
+        self.write_code_into(target, hashless);
+        self.advice_map.write_into(target);
+    }
+
+    /// Writes all sections of the serialized forest except for the trailing advice map.
+    fn write_code_into<W: ByteWriter>(&self, target: &mut W, hashless: bool) {
         self.validate_dense_node_order()
             .expect("dense MAST forest must be in final dense order before serialization");
 
@@ -290,8 +297,25 @@ impl MastForest {
                 digest.write_into(target);
             }
         }
+    }
 
-        self.advice_map.write_into(target);
+    /// Serializes the code of this forest, i.e. its nodes and procedure roots, but not its advice
+    /// map.
+    ///
+    /// The output is identical to [`Serializable::write_into`] with the trailing advice map section
+    /// omitted, so two forests with the same code but different advice data produce the same
+    /// bytes. The output is intended for comparison only and cannot be deserialized.
+    pub fn serialize_code_only<W: ByteWriter>(&self, target: &mut W) {
+        self.write_code_into(target, false);
+    }
+
+    /// Returns a hash of the output of [`Self::serialize_code_only`].
+    ///
+    /// Unlike [`MastForest::commitment`], this hash does not bind the forest's advice map.
+    pub fn code_only_hash(&self) -> Word {
+        let mut bytes = Vec::new();
+        self.serialize_code_only(&mut bytes);
+        Poseidon2::hash(&bytes)
     }
 }
 
@@ -501,6 +501,45 @@ fn test_mast_forest_read_view_modes_match() {
     );
 }
 
+#[test]
+fn test_code_only_hash_ignores_advice_map() {
+    let mut forest = MastForest::new();
+    let block = BasicBlockNodeBuilder::new(vec![Operation::Push(Felt::new_unchecked(3))])
+        .add_to_forest(&mut forest)
+        .unwrap();
+    forest.make_root(block);
+
+    let advice_key = Word::new([
+        Felt::new_unchecked(31),
+        Felt::new_unchecked(32),
+        Felt::new_unchecked(33),
+        Felt::new_unchecked(34),
+    ]);
+    let forest_a = forest
+        .clone()
+        .with_advice_map(AdviceMap::from_iter([(advice_key, vec![Felt::new_unchecked(1)])]));
+    let forest_b = forest
+        .clone()
+        .with_advice_map(AdviceMap::from_iter([(advice_key, vec![Felt::new_unchecked(2)])]));
+    assert_ne!(forest_a.commitment(), forest_b.commitment());
+
+    let mut code_a = Vec::new();
+    forest_a.serialize_code_only(&mut code_a);
+    let mut code_b = Vec::new();
+    forest_b.serialize_code_only(&mut code_b);
+    assert_eq!(code_a, code_b);
+    assert!(forest_a.to_bytes().starts_with(&code_a));
+    assert_eq!(forest_a.code_only_hash(), forest_b.code_only_hash());
+
+    // Different code still produces a different hash.
+    let mut other = MastForest::new();
+    let block = BasicBlockNodeBuilder::new(vec![Operation::Push(Felt::new_unchecked(4))])
+        .add_to_forest(&mut other)
+        .unwrap();
+    other.make_root(block);
+    assert_ne!(forest.code_only_hash(), other.code_only_hash());
+}
+
 #[test]
 fn test_mast_forest_wire_view_random_access_all_node_types() {
     let mut forest = MastForest::new();
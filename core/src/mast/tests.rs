@@ -269,3 +269,56 @@ fn digest_from_seed(seed: [u8; 32]) -> Word {
     });
     digest.into()
 }
+
+#[test]
+fn node_label_is_stable_and_distinct_for_distinct_nodes() {
+    fn build_forest(add_first: bool) -> (MastForest, [MastNodeId; 3]) {
+        let mut forest = MastForest::new();
+        if add_first {
+            BasicBlockNodeBuilder::new(vec![Operation::Mul])
+                .add_to_forest(&mut forest)
+                .unwrap();
+        }
+        let add = BasicBlockNodeBuilder::new(vec![Operation::Add])
+            .add_to_forest(&mut forest)
+            .unwrap();
+        let swap = BasicBlockNodeBuilder::new(vec![Operation::Swap])
+            .add_to_forest(&mut forest)
+            .unwrap();
+        let join = JoinNodeBuilder::new([add, swap]).add_to_forest(&mut forest).unwrap();
+        (forest, [add, swap, join])
+    }
+
+    let (forest, [add, swap, join]) = build_forest(false);
+    let add_label = forest.node_label(add);
+    let digest = forest[add].digest().to_hex();
+    assert_eq!(add_label, format!("block@{}", &digest[2..10]));
+    assert!(forest.node_label(join).starts_with("join@"));
+
+    // labels are derived from the node content only, so they do not depend on node IDs
+    let (other_forest, [other_add, other_swap, other_join]) = build_forest(true);
+    assert_ne!(add, other_add);
+    assert_eq!(add_label, other_forest.node_label(other_add));
+    assert_eq!(forest.node_label(swap), other_forest.node_label(other_swap));
+    assert_eq!(forest.node_label(join), other_forest.node_label(other_join));
+
+    let labels: BTreeSet<_> = [add, swap, join].map(|id| forest.node_label(id)).into();
+    assert_eq!(labels.len(), 3);
+}
+
+#[test]
+fn listing_uses_node_labels() {
+    let mut forest = MastForest::new();
+    let add = BasicBlockNodeBuilder::new(vec![Operation::Add])
+        .add_to_forest(&mut forest)
+        .unwrap();
+    let swap = BasicBlockNodeBuilder::new(vec![Operation::Swap])
+        .add_to_forest(&mut forest)
+        .unwrap();
+    let join = JoinNodeBuilder::new([add, swap]).add_to_forest(&mut forest).unwrap();
+    forest.make_root(join);
+
+    let [add, swap, join] = [add, swap, join].map(|id| forest.node_label(id));
+    let expected = format!("0: {add}\n1: {swap}\n2: {join} (root) <- {add}, {swap}\n");
+    assert_eq!(format!("{}", forest.listing()), expected);
+}
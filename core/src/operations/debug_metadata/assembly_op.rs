@@ -1,4 +1,4 @@
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 use core::fmt;
 
 use miden_debug_types::Location;
@@ -18,6 +18,8 @@ pub struct AssemblyOp {
     context_name: String,
     op: String,
     num_cycles: u8,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    call_sites: Vec<Location>,
 }
 
 impl AssemblyOp {
@@ -29,7 +31,13 @@ impl AssemblyOp {
         num_cycles: u8,
         op: String,
     ) -> Self {
-        Self { location, context_name, op, num_cycles }
+        Self {
+            location,
+            context_name,
+            op,
+            num_cycles,
+            call_sites: Vec::new(),
+        }
     }
 
     /// Returns the [Location] for this operation, if known
@@ -52,6 +60,15 @@ impl AssemblyOp {
         &self.op
     }
 
+    /// Returns the locations of the `exec` instructions through which the operations of this
+    /// assembly instruction were merged into the code of their callers, innermost first.
+    ///
+    /// Together with [`Self::location`], these form the chain of source spans that led to the
+    /// operations being executed at their position.
+    pub fn call_sites(&self) -> &[Location] {
+        &self.call_sites
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -64,6 +81,17 @@ impl AssemblyOp {
     pub fn set_location(&mut self, location: Location) {
         self.location = Some(location);
     }
+
+    /// Appends the location of an `exec` instruction through which the operations of this
+    /// [AssemblyOp] were merged into the code of its caller.
+    pub fn push_call_site(&mut self, location: Location) {
+        self.call_sites.push(location);
+    }
+
+    /// Replaces the call sites of this [AssemblyOp], see [Self::call_sites].
+    pub fn set_call_sites(&mut self, call_sites: Vec<Location>) {
+        self.call_sites = call_sites;
+    }
 }
 
 impl fmt::Display for AssemblyOp {
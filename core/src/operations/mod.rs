@@ -602,6 +602,10 @@ impl Operation {
     pub const OP_BITS: usize = 7;
 
     /// Returns the opcode of this operation.
+    ///
+    /// Opcodes are the values defined in [`opcodes`] and are stable, i.e. they are the same as
+    /// those used by the serialized format and can be relied on by external tooling. Use
+    /// [`Self::from_opcode()`] to get the operation back from its opcode.
     #[rustfmt::skip]
     pub fn op_code(&self) -> u8 {
         // SAFETY: This is safe because we have given this enum a primitive representation with
@@ -612,6 +616,136 @@ impl Operation {
         unsafe { *<*const _>::from(self).cast::<u8>() }
     }
 
+    /// Returns the operation with the given opcode.
+    ///
+    /// `imm` must contain the payload of operations which carry one, i.e. the value pushed by
+    /// `Push` and the error code of `Assert`, `MpVerify` and `U32assert2`, and must be `None` for
+    /// all other operations. See [`opcodes`] for the opcode of each operation.
+    ///
+    /// # Errors
+    /// Returns an error if `op_code` is not the opcode of a basic block operation (this includes
+    /// control flow opcodes), or if `imm` does not match the operation.
+    pub fn from_opcode(op_code: u8, imm: Option<Felt>) -> Result<Self, DeserializationError> {
+        match (Self::has_immediate(op_code), imm.is_some()) {
+            (true, false) => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "opcode '{op_code}' requires an immediate value"
+                )));
+            },
+            (false, true) => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "opcode '{op_code}' does not take an immediate value"
+                )));
+            },
+            _ => (),
+        }
+
+        let operation = match op_code {
+            opcodes::NOOP => Self::Noop,
+            opcodes::EQZ => Self::Eqz,
+            opcodes::NEG => Self::Neg,
+            opcodes::INV => Self::Inv,
+            opcodes::INCR => Self::Incr,
+            opcodes::NOT => Self::Not,
+            opcodes::MLOAD => Self::MLoad,
+            opcodes::SWAP => Self::Swap,
+            opcodes::CALLER => Self::Caller,
+            opcodes::MOVUP2 => Self::MovUp2,
+            opcodes::MOVDN2 => Self::MovDn2,
+            opcodes::MOVUP3 => Self::MovUp3,
+            opcodes::MOVDN3 => Self::MovDn3,
+            opcodes::ADVPOPW => Self::AdvPopW,
+            opcodes::EXPACC => Self::Expacc,
+
+            opcodes::MOVUP4 => Self::MovUp4,
+            opcodes::MOVDN4 => Self::MovDn4,
+            opcodes::MOVUP5 => Self::MovUp5,
+            opcodes::MOVDN5 => Self::MovDn5,
+            opcodes::MOVUP6 => Self::MovUp6,
+            opcodes::MOVDN6 => Self::MovDn6,
+            opcodes::MOVUP7 => Self::MovUp7,
+            opcodes::MOVDN7 => Self::MovDn7,
+            opcodes::SWAPW => Self::SwapW,
+            opcodes::EXT2MUL => Self::Ext2Mul,
+            opcodes::MOVUP8 => Self::MovUp8,
+            opcodes::MOVDN8 => Self::MovDn8,
+            opcodes::SWAPW2 => Self::SwapW2,
+            opcodes::SWAPW3 => Self::SwapW3,
+            opcodes::SWAPDW => Self::SwapDW,
+            opcodes::EMIT => Self::Emit,
+
+            opcodes::ASSERT if let Some(imm) = imm => Self::Assert(imm),
+            opcodes::EQ => Self::Eq,
+            opcodes::ADD => Self::Add,
+            opcodes::MUL => Self::Mul,
+            opcodes::AND => Self::And,
+            opcodes::OR => Self::Or,
+            opcodes::U32AND => Self::U32and,
+            opcodes::U32XOR => Self::U32xor,
+            opcodes::FRIE2F4 => Self::FriE2F4,
+            opcodes::DROP => Self::Drop,
+            opcodes::CSWAP => Self::CSwap,
+            opcodes::CSWAPW => Self::CSwapW,
+            opcodes::MLOADW => Self::MLoadW,
+            opcodes::MSTORE => Self::MStore,
+            opcodes::MSTOREW => Self::MStoreW,
+
+            opcodes::PAD => Self::Pad,
+            opcodes::DUP0 => Self::Dup0,
+            opcodes::DUP1 => Self::Dup1,
+            opcodes::DUP2 => Self::Dup2,
+            opcodes::DUP3 => Self::Dup3,
+            opcodes::DUP4 => Self::Dup4,
+            opcodes::DUP5 => Self::Dup5,
+            opcodes::DUP6 => Self::Dup6,
+            opcodes::DUP7 => Self::Dup7,
+            opcodes::DUP9 => Self::Dup9,
+            opcodes::DUP11 => Self::Dup11,
+            opcodes::DUP13 => Self::Dup13,
+            opcodes::DUP15 => Self::Dup15,
+            opcodes::ADVPOP => Self::AdvPop,
+            opcodes::SDEPTH => Self::SDepth,
+            opcodes::CLK => Self::Clk,
+
+            opcodes::U32ADD => Self::U32add,
+            opcodes::U32SUB => Self::U32sub,
+            opcodes::U32MUL => Self::U32mul,
+            opcodes::U32DIV => Self::U32div,
+            opcodes::U32SPLIT => Self::U32split,
+            opcodes::U32ASSERT2 if let Some(imm) = imm => Self::U32assert2(imm),
+            opcodes::U32ADD3 => Self::U32add3,
+            opcodes::U32MADD => Self::U32madd,
+
+            opcodes::HPERM => Self::HPerm,
+            opcodes::MPVERIFY if let Some(imm) = imm => Self::MpVerify(imm),
+            opcodes::PIPE => Self::Pipe,
+            opcodes::MSTREAM => Self::MStream,
+            opcodes::CRYPTOSTREAM => Self::CryptoStream,
+            opcodes::HORNERBASE => Self::HornerBase,
+            opcodes::HORNEREXT => Self::HornerExt,
+            opcodes::LOGDEFERRED => Self::LogDeferred,
+            opcodes::EVALCIRCUIT => Self::EvalCircuit,
+
+            opcodes::MRUPDATE => Self::MrUpdate,
+            opcodes::PUSH if let Some(imm) = imm => Self::Push(imm),
+            _ => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "Invalid opcode '{op_code}'"
+                )));
+            },
+        };
+
+        Ok(operation)
+    }
+
+    /// Returns true if the operation with the given opcode carries a field element payload.
+    fn has_immediate(op_code: u8) -> bool {
+        matches!(
+            op_code,
+            opcodes::ASSERT | opcodes::MPVERIFY | opcodes::U32ASSERT2 | opcodes::PUSH
+        )
+    }
+
     /// Returns an immediate value carried by this operation.
     // Proptest generators for operations in crate::mast::node::basic_block_node::tests discriminate
     // on this flag, please update them when you modify the semantics of this method.
@@ -678,129 +812,140 @@ impl Operation {
                 | Self::FriE2F4
         )
     }
-}
 
-impl crate::prettier::PrettyPrint for Operation {
-    fn render(&self) -> crate::prettier::Document {
-        crate::prettier::display(self)
-    }
-}
-
-impl fmt::Display for Operation {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// Returns the mnemonic of this operation, without any immediate value it carries.
+    pub fn mnemonic(&self) -> &'static str {
         match self {
             // ----- system operations ------------------------------------------------------------
-            Self::Noop => write!(f, "noop"),
-            Self::Assert(err_code) => write!(f, "assert({err_code})"),
+            Self::Noop => "noop",
+            Self::Assert(_) => "assert",
 
-            Self::SDepth => write!(f, "sdepth"),
-            Self::Caller => write!(f, "caller"),
+            Self::SDepth => "sdepth",
+            Self::Caller => "caller",
 
-            Self::Clk => write!(f, "clk"),
+            Self::Clk => "clk",
 
             // ----- field operations -------------------------------------------------------------
-            Self::Add => write!(f, "add"),
-            Self::Neg => write!(f, "neg"),
-            Self::Mul => write!(f, "mul"),
-            Self::Inv => write!(f, "inv"),
-            Self::Incr => write!(f, "incr"),
+            Self::Add => "add",
+            Self::Neg => "neg",
+            Self::Mul => "mul",
+            Self::Inv => "inv",
+            Self::Incr => "incr",
 
-            Self::And => write!(f, "and"),
-            Self::Or => write!(f, "or"),
-            Self::Not => write!(f, "not"),
+            Self::And => "and",
+            Self::Or => "or",
+            Self::Not => "not",
 
-            Self::Eq => write!(f, "eq"),
-            Self::Eqz => write!(f, "eqz"),
+            Self::Eq => "eq",
+            Self::Eqz => "eqz",
 
-            Self::Expacc => write!(f, "expacc"),
+            Self::Expacc => "expacc",
 
             // ----- ext2 operations --------------------------------------------------------------
-            Self::Ext2Mul => write!(f, "ext2mul"),
+            Self::Ext2Mul => "ext2mul",
 
             // ----- u32 operations ---------------------------------------------------------------
-            Self::U32assert2(err_code) => write!(f, "u32assert2({err_code})"),
-            Self::U32split => write!(f, "u32split"),
-            Self::U32add => write!(f, "u32add"),
-            Self::U32add3 => write!(f, "u32add3"),
-            Self::U32sub => write!(f, "u32sub"),
-            Self::U32mul => write!(f, "u32mul"),
-            Self::U32madd => write!(f, "u32madd"),
-            Self::U32div => write!(f, "u32div"),
-
-            Self::U32and => write!(f, "u32and"),
-            Self::U32xor => write!(f, "u32xor"),
+            Self::U32assert2(_) => "u32assert2",
+            Self::U32split => "u32split",
+            Self::U32add => "u32add",
+            Self::U32add3 => "u32add3",
+            Self::U32sub => "u32sub",
+            Self::U32mul => "u32mul",
+            Self::U32madd => "u32madd",
+            Self::U32div => "u32div",
+
+            Self::U32and => "u32and",
+            Self::U32xor => "u32xor",
 
             // ----- stack manipulation -----------------------------------------------------------
-            Self::Drop => write!(f, "drop"),
-            Self::Pad => write!(f, "pad"),
-
-            Self::Dup0 => write!(f, "dup0"),
-            Self::Dup1 => write!(f, "dup1"),
-            Self::Dup2 => write!(f, "dup2"),
-            Self::Dup3 => write!(f, "dup3"),
-            Self::Dup4 => write!(f, "dup4"),
-            Self::Dup5 => write!(f, "dup5"),
-            Self::Dup6 => write!(f, "dup6"),
-            Self::Dup7 => write!(f, "dup7"),
-            Self::Dup9 => write!(f, "dup9"),
-            Self::Dup11 => write!(f, "dup11"),
-            Self::Dup13 => write!(f, "dup13"),
-            Self::Dup15 => write!(f, "dup15"),
-
-            Self::Swap => write!(f, "swap"),
-            Self::SwapW => write!(f, "swapw"),
-            Self::SwapW2 => write!(f, "swapw2"),
-            Self::SwapW3 => write!(f, "swapw3"),
-            Self::SwapDW => write!(f, "swapdw"),
-
-            Self::MovUp2 => write!(f, "movup2"),
-            Self::MovUp3 => write!(f, "movup3"),
-            Self::MovUp4 => write!(f, "movup4"),
-            Self::MovUp5 => write!(f, "movup5"),
-            Self::MovUp6 => write!(f, "movup6"),
-            Self::MovUp7 => write!(f, "movup7"),
-            Self::MovUp8 => write!(f, "movup8"),
-
-            Self::MovDn2 => write!(f, "movdn2"),
-            Self::MovDn3 => write!(f, "movdn3"),
-            Self::MovDn4 => write!(f, "movdn4"),
-            Self::MovDn5 => write!(f, "movdn5"),
-            Self::MovDn6 => write!(f, "movdn6"),
-            Self::MovDn7 => write!(f, "movdn7"),
-            Self::MovDn8 => write!(f, "movdn8"),
-
-            Self::CSwap => write!(f, "cswap"),
-            Self::CSwapW => write!(f, "cswapw"),
+            Self::Drop => "drop",
+            Self::Pad => "pad",
+
+            Self::Dup0 => "dup0",
+            Self::Dup1 => "dup1",
+            Self::Dup2 => "dup2",
+            Self::Dup3 => "dup3",
+            Self::Dup4 => "dup4",
+            Self::Dup5 => "dup5",
+            Self::Dup6 => "dup6",
+            Self::Dup7 => "dup7",
+            Self::Dup9 => "dup9",
+            Self::Dup11 => "dup11",
+            Self::Dup13 => "dup13",
+            Self::Dup15 => "dup15",
+
+            Self::Swap => "swap",
+            Self::SwapW => "swapw",
+            Self::SwapW2 => "swapw2",
+            Self::SwapW3 => "swapw3",
+            Self::SwapDW => "swapdw",
+
+            Self::MovUp2 => "movup2",
+            Self::MovUp3 => "movup3",
+            Self::MovUp4 => "movup4",
+            Self::MovUp5 => "movup5",
+            Self::MovUp6 => "movup6",
+            Self::MovUp7 => "movup7",
+            Self::MovUp8 => "movup8",
+
+            Self::MovDn2 => "movdn2",
+            Self::MovDn3 => "movdn3",
+            Self::MovDn4 => "movdn4",
+            Self::MovDn5 => "movdn5",
+            Self::MovDn6 => "movdn6",
+            Self::MovDn7 => "movdn7",
+            Self::MovDn8 => "movdn8",
+
+            Self::CSwap => "cswap",
+            Self::CSwapW => "cswapw",
 
             // ----- input / output ---------------------------------------------------------------
-            Self::Push(value) => write!(f, "push({value})"),
+            Self::Push(_) => "push",
 
-            Self::AdvPop => write!(f, "advpop"),
-            Self::AdvPopW => write!(f, "advpopw"),
+            Self::AdvPop => "advpop",
+            Self::AdvPopW => "advpopw",
 
-            Self::MLoadW => write!(f, "mloadw"),
-            Self::MStoreW => write!(f, "mstorew"),
+            Self::MLoadW => "mloadw",
+            Self::MStoreW => "mstorew",
 
-            Self::MLoad => write!(f, "mload"),
-            Self::MStore => write!(f, "mstore"),
+            Self::MLoad => "mload",
+            Self::MStore => "mstore",
 
-            Self::MStream => write!(f, "mstream"),
-            Self::Pipe => write!(f, "pipe"),
-            Self::CryptoStream => write!(f, "crypto_stream"),
+            Self::MStream => "mstream",
+            Self::Pipe => "pipe",
+            Self::CryptoStream => "crypto_stream",
 
-            Self::Emit => write!(f, "emit"),
+            Self::Emit => "emit",
 
             // ----- cryptographic operations -----------------------------------------------------
-            Self::HPerm => write!(f, "hperm"),
-            Self::MpVerify(err_code) => write!(f, "mpverify({err_code})"),
-            Self::MrUpdate => write!(f, "mrupdate"),
+            Self::HPerm => "hperm",
+            Self::MpVerify(_) => "mpverify",
+            Self::MrUpdate => "mrupdate",
 
             // ----- STARK proof verification -----------------------------------------------------
-            Self::FriE2F4 => write!(f, "frie2f4"),
-            Self::HornerBase => write!(f, "horner_eval_base"),
-            Self::HornerExt => write!(f, "horner_eval_ext"),
-            Self::EvalCircuit => write!(f, "eval_circuit"),
-            Self::LogDeferred => write!(f, "log_deferred"),
+            Self::FriE2F4 => "frie2f4",
+            Self::HornerBase => "horner_eval_base",
+            Self::HornerExt => "horner_eval_ext",
+            Self::EvalCircuit => "eval_circuit",
+            Self::LogDeferred => "log_deferred",
+        }
+    }
+}
+
+impl crate::prettier::PrettyPrint for Operation {
+    fn render(&self) -> crate::prettier::Document {
+        crate::prettier::display(self)
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Assert(err_code) | Self::U32assert2(err_code) | Self::MpVerify(err_code) => {
+                write!(f, "{}({err_code})", self.mnemonic())
+            },
+            Self::Push(value) => write!(f, "{}({value})", self.mnemonic()),
+            _ => f.write_str(self.mnemonic()),
         }
     }
 }
@@ -1000,103 +1145,13 @@ impl Operation {
 impl Deserializable for Operation {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let op_code = source.read_u8()?;
-
-        let operation = match op_code {
-            opcodes::NOOP => Self::Noop,
-            opcodes::EQZ => Self::Eqz,
-            opcodes::NEG => Self::Neg,
-            opcodes::INV => Self::Inv,
-            opcodes::INCR => Self::Incr,
-            opcodes::NOT => Self::Not,
-            opcodes::MLOAD => Self::MLoad,
-            opcodes::SWAP => Self::Swap,
-            opcodes::CALLER => Self::Caller,
-            opcodes::MOVUP2 => Self::MovUp2,
-            opcodes::MOVDN2 => Self::MovDn2,
-            opcodes::MOVUP3 => Self::MovUp3,
-            opcodes::MOVDN3 => Self::MovDn3,
-            opcodes::ADVPOPW => Self::AdvPopW,
-            opcodes::EXPACC => Self::Expacc,
-
-            opcodes::MOVUP4 => Self::MovUp4,
-            opcodes::MOVDN4 => Self::MovDn4,
-            opcodes::MOVUP5 => Self::MovUp5,
-            opcodes::MOVDN5 => Self::MovDn5,
-            opcodes::MOVUP6 => Self::MovUp6,
-            opcodes::MOVDN6 => Self::MovDn6,
-            opcodes::MOVUP7 => Self::MovUp7,
-            opcodes::MOVDN7 => Self::MovDn7,
-            opcodes::SWAPW => Self::SwapW,
-            opcodes::EXT2MUL => Self::Ext2Mul,
-            opcodes::MOVUP8 => Self::MovUp8,
-            opcodes::MOVDN8 => Self::MovDn8,
-            opcodes::SWAPW2 => Self::SwapW2,
-            opcodes::SWAPW3 => Self::SwapW3,
-            opcodes::SWAPDW => Self::SwapDW,
-            opcodes::EMIT => Self::Emit,
-
-            opcodes::ASSERT => Self::Assert(Felt::read_from(source)?),
-            opcodes::EQ => Self::Eq,
-            opcodes::ADD => Self::Add,
-            opcodes::MUL => Self::Mul,
-            opcodes::AND => Self::And,
-            opcodes::OR => Self::Or,
-            opcodes::U32AND => Self::U32and,
-            opcodes::U32XOR => Self::U32xor,
-            opcodes::FRIE2F4 => Self::FriE2F4,
-            opcodes::DROP => Self::Drop,
-            opcodes::CSWAP => Self::CSwap,
-            opcodes::CSWAPW => Self::CSwapW,
-            opcodes::MLOADW => Self::MLoadW,
-            opcodes::MSTORE => Self::MStore,
-            opcodes::MSTOREW => Self::MStoreW,
-
-            opcodes::PAD => Self::Pad,
-            opcodes::DUP0 => Self::Dup0,
-            opcodes::DUP1 => Self::Dup1,
-            opcodes::DUP2 => Self::Dup2,
-            opcodes::DUP3 => Self::Dup3,
-            opcodes::DUP4 => Self::Dup4,
-            opcodes::DUP5 => Self::Dup5,
-            opcodes::DUP6 => Self::Dup6,
-            opcodes::DUP7 => Self::Dup7,
-            opcodes::DUP9 => Self::Dup9,
-            opcodes::DUP11 => Self::Dup11,
-            opcodes::DUP13 => Self::Dup13,
-            opcodes::DUP15 => Self::Dup15,
-            opcodes::ADVPOP => Self::AdvPop,
-            opcodes::SDEPTH => Self::SDepth,
-            opcodes::CLK => Self::Clk,
-
-            opcodes::U32ADD => Self::U32add,
-            opcodes::U32SUB => Self::U32sub,
-            opcodes::U32MUL => Self::U32mul,
-            opcodes::U32DIV => Self::U32div,
-            opcodes::U32SPLIT => Self::U32split,
-            opcodes::U32ASSERT2 => Self::U32assert2(Felt::read_from(source)?),
-            opcodes::U32ADD3 => Self::U32add3,
-            opcodes::U32MADD => Self::U32madd,
-
-            opcodes::HPERM => Self::HPerm,
-            opcodes::MPVERIFY => Self::MpVerify(Felt::read_from(source)?),
-            opcodes::PIPE => Self::Pipe,
-            opcodes::MSTREAM => Self::MStream,
-            opcodes::CRYPTOSTREAM => Self::CryptoStream,
-            opcodes::HORNERBASE => Self::HornerBase,
-            opcodes::HORNEREXT => Self::HornerExt,
-            opcodes::LOGDEFERRED => Self::LogDeferred,
-            opcodes::EVALCIRCUIT => Self::EvalCircuit,
-
-            opcodes::MRUPDATE => Self::MrUpdate,
-            opcodes::PUSH => Self::Push(Felt::read_from(source)?),
-            _ => {
-                return Err(DeserializationError::InvalidValue(format!(
-                    "Invalid opcode '{op_code}'"
-                )));
-            },
+        let imm = if Self::has_immediate(op_code) {
+            Some(Felt::read_from(source)?)
+        } else {
+            None
         };
 
-        Ok(operation)
+        Self::from_opcode(op_code, imm)
     }
 
     /// Returns the minimum serialized size: 1 byte opcode.
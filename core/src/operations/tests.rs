@@ -3,6 +3,7 @@ use alloc::vec::Vec;
 use proptest::prelude::*;
 
 use crate::{
+    Felt,
     mast::arbitrary::op_non_control_strategy,
     operations::{Operation, opcodes},
     serde::{Deserializable, DeserializationError, Serializable, SliceReader},
@@ -61,3 +62,44 @@ proptest! {
         }
     }
 }
+
+#[test]
+fn opcodes_round_trip_through_from_opcode() {
+    let imm = Felt::new_unchecked(7);
+
+    let mut num_ops = 0;
+    for op_code in 0..=u8::MAX {
+        let op = Operation::from_opcode(op_code, Some(imm))
+            .or_else(|_| Operation::from_opcode(op_code, None));
+        let Ok(op) = op else { continue };
+        num_ops += 1;
+
+        assert_eq!(op.op_code(), op_code, "{op} does not map back to its opcode");
+
+        // from_opcode() must agree with the serialized format
+        let mut bytes = Vec::new();
+        op.write_into(&mut bytes);
+        assert_eq!(bytes[0], op_code);
+        assert_eq!(Operation::read_from(&mut SliceReader::new(&bytes)).unwrap(), op);
+    }
+    // every basic block operation variant must be reachable from its opcode
+    assert_eq!(num_ops, 81);
+
+    // opcodes are part of the public interface and must not change
+    assert_eq!(Operation::Noop.op_code(), 0b0000_0000);
+    assert_eq!(Operation::Assert(imm).op_code(), 0b0010_0000);
+    assert_eq!(Operation::Push(imm).op_code(), 0b0101_1011);
+    assert_eq!(Operation::LogDeferred.op_code(), 0b0101_1110);
+    assert_eq!(Operation::MrUpdate.op_code(), 0b0110_0000);
+}
+
+#[test]
+fn from_opcode_checks_immediate_values() {
+    let imm = Felt::new_unchecked(7);
+
+    assert_eq!(Operation::from_opcode(opcodes::PUSH, Some(imm)).unwrap(), Operation::Push(imm));
+    assert!(Operation::from_opcode(opcodes::PUSH, None).is_err());
+    assert!(Operation::from_opcode(opcodes::ASSERT, None).is_err());
+    assert!(Operation::from_opcode(opcodes::ADD, Some(imm)).is_err());
+    assert!(Operation::from_opcode(opcodes::JOIN, None).is_err());
+}
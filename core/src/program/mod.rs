@@ -1,13 +1,15 @@
-use alloc::{sync::Arc, vec::Vec};
+use alloc::{sync::Arc, vec, vec::Vec};
 use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    Felt, WORD_SIZE, Word,
+    Felt, ONE, WORD_SIZE, Word, ZERO,
     advice::AdviceMap,
-    mast::{MastForest, MastNode, MastNodeExt, MastNodeId},
+    events::{EventId, SystemEvent},
+    mast::{BasicBlockNode, MastForest, MastNode, MastNodeExt, MastNodeId},
+    operations::Operation,
     serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     utils::ToElements,
 };
@@ -126,6 +128,78 @@ impl Program {
     pub fn to_info(&self) -> ProgramInfo {
         ProgramInfo::new(self.hash(), self.kernel().clone())
     }
+
+    /// Returns a lower bound on the number of cycles needed to execute this program, or `None` if
+    /// the program contains data-dependent control flow.
+    ///
+    /// The bound is computed for programs consisting only of basic blocks, `JOIN` and `CALL`
+    /// nodes, and is the sum of:
+    /// - the number of operations in each basic block, excluding the `NOOP`s inserted to pad
+    ///   operation groups and batches,
+    /// - the `SPAN`, `RESPAN` and `END` rows of each basic block,
+    /// - the `JOIN`/`CALL` and `END` rows of each control flow node.
+    ///
+    /// `None` is returned if the program contains `SPLIT`, `LOOP` or `DYN` nodes, or references
+    /// procedures not present in its MAST forest.
+    pub fn min_cycles(&self) -> Option<usize> {
+        let mut num_cycles = 0;
+        let mut nodes = vec![self.entrypoint];
+        while let Some(node_id) = nodes.pop() {
+            match self.mast_forest.get_node_by_id(node_id)? {
+                MastNode::Block(basic_block) => {
+                    // SPAN and END, plus one RESPAN for every batch after the first one
+                    num_cycles += basic_block.num_op_batches() + 1;
+                    num_cycles += basic_block.raw_operations().count();
+                },
+                MastNode::Join(join_node) => {
+                    num_cycles += 2;
+                    nodes.extend([join_node.first(), join_node.second()]);
+                },
+                MastNode::Call(call_node) => {
+                    num_cycles += 2;
+                    nodes.push(call_node.callee());
+                },
+                MastNode::Split(_)
+                | MastNode::Loop(_)
+                | MastNode::Dyn(_)
+                | MastNode::External(_) => return None,
+            }
+        }
+
+        Some(num_cycles)
+    }
+
+    /// Checks that the advice map embedded in this program contains every key which the program
+    /// is statically known to look up.
+    ///
+    /// A lookup is statically known when a basic block pushes a constant word immediately before
+    /// emitting one of the system events which read a value from the advice map (e.g., as produced
+    /// by `push.KEY adv.push_mapval`). Lookups of keys computed at runtime are skipped.
+    ///
+    /// All basic blocks in the program's MAST forest are scanned, regardless of whether they are
+    /// reachable from the entrypoint.
+    ///
+    /// # Errors
+    /// Returns the keys missing from the advice map, in the order in which they were first found.
+    pub fn check_advice_map_coverage(&self) -> Result<(), Vec<Word>> {
+        let advice_map = self.mast_forest.advice_map();
+        let mut missing_keys = Vec::new();
+        for node in self.mast_forest.nodes() {
+            if let MastNode::Block(basic_block) = node {
+                for key in static_advice_map_keys(basic_block) {
+                    if !advice_map.contains_key(&key) && !missing_keys.contains(&key) {
+                        missing_keys.push(key);
+                    }
+                }
+            }
+        }
+
+        if missing_keys.is_empty() {
+            Ok(())
+        } else {
+            Err(missing_keys)
+        }
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -308,8 +382,129 @@ impl ToElements for ProgramInfo {
 // HELPER
 // ===============================================================================================
 
+/// Returns the advice map keys read by the system events emitted in the provided basic block,
+/// for which the key is pushed onto the stack as a constant within the same basic block.
+///
+/// The operand stack is tracked from the start of the block using only the operations emitted by
+/// the assembler for constant pushes; any other operation invalidates everything known about the
+/// stack.
+fn static_advice_map_keys(basic_block: &BasicBlockNode) -> Vec<Word> {
+    // known stack values, with the top of the stack at the end
+    let mut stack: Vec<Option<Felt>> = Vec::new();
+    let mut keys = Vec::new();
+
+    for op in basic_block.operations() {
+        match op {
+            Operation::Noop => (),
+            Operation::Pad => stack.push(Some(ZERO)),
+            Operation::Push(value) => stack.push(Some(*value)),
+            Operation::Incr => {
+                if let Some(top) = stack.last_mut() {
+                    *top = top.map(|value| value + ONE);
+                }
+            },
+            Operation::Drop => {
+                stack.pop();
+            },
+            Operation::Emit => {
+                let reads_advice_map = stack
+                    .last()
+                    .copied()
+                    .flatten()
+                    .and_then(|event_id| SystemEvent::from_event_id(EventId::from_felt(event_id)))
+                    .is_some_and(|event| {
+                        matches!(
+                            event,
+                            SystemEvent::MapValueToStack
+                                | SystemEvent::MapValueCountToStack
+                                | SystemEvent::MapValueToStackN0
+                                | SystemEvent::MapValueToStackN4
+                                | SystemEvent::MapValueToStackN8
+                        )
+                    });
+
+                // the key is the word right below the event ID, with its first element on top
+                if reads_advice_map && stack.len() > WORD_SIZE {
+                    let key_start = stack.len() - 1 - WORD_SIZE;
+                    let key: Option<Vec<Felt>> =
+                        stack[key_start..stack.len() - 1].iter().rev().copied().collect();
+                    if let Some(key) = key {
+                        let key: [Felt; WORD_SIZE] =
+                            key.try_into().expect("key should contain exactly one word");
+                        keys.push(Word::from(key));
+                    }
+                }
+            },
+            _ => stack.clear(),
+        }
+    }
+
+    keys
+}
+
 /// Pads a vector of field elements using zeros to the next multiple of 8.
 fn pad_next_mul_8(input: &mut Vec<Felt>) {
     let output_len = input.len().next_multiple_of(8);
     input.resize(output_len, Felt::ZERO);
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::{sync::Arc, vec, vec::Vec};
+
+    use super::Program;
+    use crate::{
+        Felt, ONE, Word, ZERO,
+        advice::AdviceMap,
+        events::SystemEvent,
+        mast::{BasicBlockNodeBuilder, MastForest, SplitNodeBuilder},
+        operations::Operation,
+    };
+
+    fn push_mapval_program(key: Word) -> Program {
+        // equivalent to `push.KEY adv.push_mapval`
+        let mut ops: Vec<Operation> =
+            key.as_elements().iter().rev().map(|&value| Operation::Push(value)).collect();
+        ops.extend([
+            Operation::Push(SystemEvent::MapValueToStack.event_id().as_felt()),
+            Operation::Emit,
+            Operation::Drop,
+        ]);
+
+        let mut mast_forest = MastForest::new();
+        let basic_block_id =
+            BasicBlockNodeBuilder::new(ops).add_to_forest(&mut mast_forest).unwrap();
+        mast_forest.make_root(basic_block_id);
+        Program::new(Arc::new(mast_forest), basic_block_id)
+    }
+
+    #[test]
+    fn check_advice_map_coverage_reports_missing_constant_keys() {
+        let key = Word::from([Felt::new_unchecked(2), Felt::new_unchecked(3), ONE, ZERO]);
+        let program = push_mapval_program(key);
+        assert_eq!(program.check_advice_map_coverage(), Err(vec![key]));
+
+        let program = program.with_advice_map(AdviceMap::from_iter([(key, vec![ONE])]));
+        assert_eq!(program.check_advice_map_coverage(), Ok(()));
+    }
+
+    #[test]
+    fn min_cycles_is_none_for_data_dependent_control_flow() {
+        let mut mast_forest = MastForest::new();
+        let on_true = BasicBlockNodeBuilder::new(vec![Operation::Add])
+            .add_to_forest(&mut mast_forest)
+            .unwrap();
+        let on_false = BasicBlockNodeBuilder::new(vec![Operation::Mul])
+            .add_to_forest(&mut mast_forest)
+            .unwrap();
+        let split = SplitNodeBuilder::new([on_true, on_false])
+            .add_to_forest(&mut mast_forest)
+            .unwrap();
+        mast_forest.make_root(on_true);
+        mast_forest.make_root(split);
+
+        // SPAN, ADD, END
+        assert_eq!(Program::new(Arc::new(mast_forest.clone()), on_true).min_cycles(), Some(3));
+        assert_eq!(Program::new(Arc::new(mast_forest), split).min_cycles(), None);
+    }
+}
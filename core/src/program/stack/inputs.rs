@@ -1,4 +1,4 @@
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 use core::{ops::Deref, slice};
 
 use super::{MIN_STACK_DEPTH, get_num_stack_values};
@@ -128,4 +128,12 @@ pub enum InputError {
     InvalidStackElement(u64),
     #[error("number of input values on the stack cannot exceed {0}, but was {1}")]
     InputStackTooBig(usize, usize),
+    #[error("failed to parse advice inputs from JSON: {0}")]
+    InvalidJson(String),
+    #[error("invalid advice map key '{key}': {reason}")]
+    InvalidAdviceMapKey { key: String, reason: String },
+    #[error("value {value} of advice map entry '{key}' exceeds field modulus")]
+    InvalidAdviceMapValue { key: String, value: u64 },
+    #[error("invalid Merkle tree: {0}")]
+    InvalidMerkleTree(String),
 }
@@ -9,7 +9,7 @@ use proptest::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    crypto::hash::{Blake3_256, Poseidon2, Rpo256, Rpx256},
+    crypto::hash::{Blake3_192, Blake3_256, Poseidon2, Rpo256, Rpx256},
     deferred::{DeferredRoot, DeferredStateWire},
     serde::{
         BudgetedReader, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
@@ -29,6 +29,8 @@ use crate::{
 )]
 #[repr(u8)]
 pub enum HashFunction {
+    /// BLAKE3 hash function with 192-bit output.
+    Blake3_192 = 0x00,
     /// BLAKE3 hash function with 256-bit output.
     Blake3_256 = 0x01,
     /// RPO hash function with 256-bit output.
@@ -45,6 +47,7 @@ impl HashFunction {
     /// Returns the collision resistance level (in bits) of this hash function.
     pub const fn collision_resistance(&self) -> u32 {
         match self {
+            HashFunction::Blake3_192 => Blake3_192::COLLISION_RESISTANCE,
             HashFunction::Blake3_256 => Blake3_256::COLLISION_RESISTANCE,
             HashFunction::Rpo256 => Rpo256::COLLISION_RESISTANCE,
             HashFunction::Rpx256 => Rpx256::COLLISION_RESISTANCE,
@@ -57,7 +60,7 @@ impl HashFunction {
 /// Error type for invalid hash function strings.
 #[derive(Debug, thiserror::Error)]
 #[error(
-    "invalid hash function '{hash_function}'. Valid options are: blake3-256, rpo, rpx, poseidon2, keccak"
+    "invalid hash function '{hash_function}'. Valid options are: blake3-192, blake3-256, rpo, rpx, poseidon2, keccak"
 )]
 pub struct InvalidHashFunctionError {
     pub hash_function: String,
@@ -68,6 +71,7 @@ impl TryFrom<u8> for HashFunction {
 
     fn try_from(repr: u8) -> Result<Self, Self::Error> {
         match repr {
+            0x00 => Ok(Self::Blake3_192),
             0x01 => Ok(Self::Blake3_256),
             0x02 => Ok(Self::Rpo256),
             0x03 => Ok(Self::Rpx256),
@@ -85,6 +89,7 @@ impl TryFrom<&str> for HashFunction {
 
     fn try_from(hash_fn_str: &str) -> Result<Self, Self::Error> {
         match hash_fn_str {
+            "blake3-192" => Ok(Self::Blake3_192),
             "blake3-256" => Ok(Self::Blake3_256),
             "rpo" => Ok(Self::Rpo256),
             "rpx" => Ok(Self::Rpx256),
@@ -102,11 +107,12 @@ impl Arbitrary for HashFunction {
 
     fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
         any::<u8>()
-            .prop_map(|tag| match tag % 5 {
-                0 => Self::Blake3_256,
-                1 => Self::Rpo256,
-                2 => Self::Rpx256,
-                3 => Self::Poseidon2,
+            .prop_map(|tag| match tag % 6 {
+                0 => Self::Blake3_192,
+                1 => Self::Blake3_256,
+                2 => Self::Rpo256,
+                3 => Self::Rpx256,
+                4 => Self::Poseidon2,
                 _ => Self::Keccak,
             })
             .boxed()
@@ -96,78 +96,91 @@ use super::{
     ir::{AceDag, NodeId, PeriodicColumn, PeriodicColumnData, SparseTerm},
 };
 use crate::{
+    AceError,
     layout::{InputKey, InputLayout},
     quotient::build_quotient_recomposition_dag,
     randomness,
 };
 
 /// Lower a base-field symbolic expression into DAG nodes.
+///
+/// Returns a description of the offending construct if the expression cannot be lowered.
 fn lower_base_expr<F, EF>(
     expr: &SymbolicExpression<F>,
     builder: &mut DagBuilder<EF>,
     periodic_nodes: &[NodeId],
-) -> NodeId
+) -> Result<NodeId, String>
 where
     F: Field,
     EF: ExtensionField<F>,
 {
     match expr {
-        SymbolicExpression::Leaf(leaf) => match leaf {
+        SymbolicExpression::Leaf(leaf) => Ok(match leaf {
             BaseLeaf::Variable(v) => match v.entry {
                 BaseEntry::Main { offset } => {
                     builder.input(InputKey::Main { offset, index: v.index })
                 },
                 BaseEntry::Public => builder.input(InputKey::Public(v.index)),
-                BaseEntry::Periodic => periodic_nodes
-                    .get(v.index)
-                    .copied()
-                    .unwrap_or_else(|| panic!("periodic column index {} is out of range", v.index)),
-                BaseEntry::Preprocessed { .. } => {
-                    panic!("preprocessed trace entries are not supported")
+                BaseEntry::Periodic => periodic_nodes.get(v.index).copied().ok_or_else(|| {
+                    format!(
+                        "periodic column {} is referenced, but the AIR declares only {} periodic \
+                         columns",
+                        v.index,
+                        periodic_nodes.len()
+                    )
+                })?,
+                BaseEntry::Preprocessed { offset } => {
+                    return Err(format!(
+                        "preprocessed trace column {} (row offset {offset}) is referenced, but \
+                         preprocessed traces are not supported",
+                        v.index
+                    ));
                 },
             },
             BaseLeaf::IsFirstRow => builder.input(InputKey::IsFirst),
             BaseLeaf::IsLastRow => builder.input(InputKey::IsLast),
             BaseLeaf::IsTransition => builder.input(InputKey::IsTransition),
             BaseLeaf::Constant(c) => builder.constant(EF::from(*c)),
-        },
+        }),
         SymbolicExpression::Add { x, y, .. } => {
-            let lx = lower_base_expr::<F, EF>(x, builder, periodic_nodes);
-            let ly = lower_base_expr::<F, EF>(y, builder, periodic_nodes);
-            builder.add(lx, ly)
+            let lx = lower_base_expr::<F, EF>(x, builder, periodic_nodes)?;
+            let ly = lower_base_expr::<F, EF>(y, builder, periodic_nodes)?;
+            Ok(builder.add(lx, ly))
         },
         SymbolicExpression::Sub { x, y, .. } => {
-            let lx = lower_base_expr::<F, EF>(x, builder, periodic_nodes);
-            let ly = lower_base_expr::<F, EF>(y, builder, periodic_nodes);
-            builder.sub(lx, ly)
+            let lx = lower_base_expr::<F, EF>(x, builder, periodic_nodes)?;
+            let ly = lower_base_expr::<F, EF>(y, builder, periodic_nodes)?;
+            Ok(builder.sub(lx, ly))
         },
         SymbolicExpression::Mul { x, y, .. } => {
-            let lx = lower_base_expr::<F, EF>(x, builder, periodic_nodes);
-            let ly = lower_base_expr::<F, EF>(y, builder, periodic_nodes);
-            builder.mul(lx, ly)
+            let lx = lower_base_expr::<F, EF>(x, builder, periodic_nodes)?;
+            let ly = lower_base_expr::<F, EF>(y, builder, periodic_nodes)?;
+            Ok(builder.mul(lx, ly))
         },
         SymbolicExpression::Neg { x, .. } => {
-            let lx = lower_base_expr::<F, EF>(x, builder, periodic_nodes);
-            builder.neg(lx)
+            let lx = lower_base_expr::<F, EF>(x, builder, periodic_nodes)?;
+            Ok(builder.neg(lx))
         },
     }
 }
 
 /// Lower an extension-field symbolic expression into DAG nodes.
+///
+/// Returns a description of the offending construct if the expression cannot be lowered.
 fn lower_ext_expr<F, EF>(
     expr: &SymbolicExpressionExt<F, EF>,
     builder: &mut DagBuilder<EF>,
     layout: &InputLayout,
     periodic_nodes: &[NodeId],
-) -> NodeId
+) -> Result<NodeId, String>
 where
     F: Field,
     EF: ExtensionField<F>,
 {
     match expr {
-        SymbolicExpressionExt::Leaf(leaf) => match leaf {
+        SymbolicExpressionExt::Leaf(leaf) => Ok(match leaf {
             ExtLeaf::Base(base_expr) => {
-                lower_base_expr::<F, EF>(base_expr, builder, periodic_nodes)
+                lower_base_expr::<F, EF>(base_expr, builder, periodic_nodes)?
             },
             ExtLeaf::ExtVariable(v) => match v.entry {
                 ExtEntry::Permutation { offset } => {
@@ -187,25 +200,25 @@ where
                 ExtEntry::PermutationValue => builder.input(InputKey::AuxBusBoundary(v.index)),
             },
             ExtLeaf::ExtConstant(c) => builder.constant(*c),
-        },
+        }),
         SymbolicExpressionExt::Add { x, y, .. } => {
-            let lx = lower_ext_expr::<F, EF>(x, builder, layout, periodic_nodes);
-            let ly = lower_ext_expr::<F, EF>(y, builder, layout, periodic_nodes);
-            builder.add(lx, ly)
+            let lx = lower_ext_expr::<F, EF>(x, builder, layout, periodic_nodes)?;
+            let ly = lower_ext_expr::<F, EF>(y, builder, layout, periodic_nodes)?;
+            Ok(builder.add(lx, ly))
         },
         SymbolicExpressionExt::Sub { x, y, .. } => {
-            let lx = lower_ext_expr::<F, EF>(x, builder, layout, periodic_nodes);
-            let ly = lower_ext_expr::<F, EF>(y, builder, layout, periodic_nodes);
-            builder.sub(lx, ly)
+            let lx = lower_ext_expr::<F, EF>(x, builder, layout, periodic_nodes)?;
+            let ly = lower_ext_expr::<F, EF>(y, builder, layout, periodic_nodes)?;
+            Ok(builder.sub(lx, ly))
         },
         SymbolicExpressionExt::Mul { x, y, .. } => {
-            let lx = lower_ext_expr::<F, EF>(x, builder, layout, periodic_nodes);
-            let ly = lower_ext_expr::<F, EF>(y, builder, layout, periodic_nodes);
-            builder.mul(lx, ly)
+            let lx = lower_ext_expr::<F, EF>(x, builder, layout, periodic_nodes)?;
+            let ly = lower_ext_expr::<F, EF>(y, builder, layout, periodic_nodes)?;
+            Ok(builder.mul(lx, ly))
         },
         SymbolicExpressionExt::Neg { x, .. } => {
-            let lx = lower_ext_expr::<F, EF>(x, builder, layout, periodic_nodes);
-            builder.neg(lx)
+            let lx = lower_ext_expr::<F, EF>(x, builder, layout, periodic_nodes)?;
+            Ok(builder.neg(lx))
         },
     }
 }
@@ -215,13 +228,16 @@ where
 /// This constructs the folded constraint accumulator, divides by the vanishing
 /// polynomial, recomposes the quotient, and subtracts both sides to yield the
 /// root expression evaluated by the ACE circuit.
+///
+/// Returns [`AceError::UnsupportedEntry`] identifying the first constraint (in evaluation order)
+/// which uses a construct that cannot be lowered.
 pub fn build_verifier_dag<F, EF>(
     base_constraints: &[SymbolicExpression<F>],
     ext_constraints: &[SymbolicExpressionExt<F, EF>],
     constraint_layout: &ConstraintLayout,
     layout: &InputLayout,
     periodic: Option<&PeriodicColumnData<EF>>,
-) -> AceDag<EF>
+) -> Result<AceDag<EF>, AceError>
 where
     F: Field,
     EF: ExtensionField<F>,
@@ -245,12 +261,13 @@ where
     ordered.sort_by_key(|(pos, ..)| *pos);
 
     let mut acc = builder.constant(EF::ZERO);
-    for &(_, is_ext, idx) in &ordered {
+    for &(constraint, is_ext, idx) in &ordered {
         let node = if is_ext {
             lower_ext_expr::<F, EF>(&ext_constraints[idx], &mut builder, layout, &periodic_nodes)
         } else {
             lower_base_expr::<F, EF>(&base_constraints[idx], &mut builder, &periodic_nodes)
-        };
+        }
+        .map_err(|description| AceError::UnsupportedEntry { constraint, description })?;
         let acc_mul = builder.mul(acc, alpha);
         acc = builder.add(acc_mul, node);
     }
@@ -264,7 +281,7 @@ where
 
     let mut dag = builder.build(root);
     dag.compact();
-    dag
+    Ok(dag)
 }
 
 fn build_periodic_nodes<EF>(
@@ -18,7 +18,7 @@ impl InputRegion {
 }
 
 /// Counts needed to build the ACE input layout.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InputCounts {
     /// Width of the main trace.
     pub width: usize,
@@ -98,7 +98,7 @@ pub(crate) struct MultiAirIndices {
 }
 
 /// ACE input layout for circuit evaluation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct InputLayout {
     /// Grouped regions for the ACE input layout.
     pub(crate) regions: LayoutRegions,
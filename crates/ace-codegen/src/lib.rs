@@ -57,6 +57,11 @@ pub enum AceError {
     InvalidInputLength { expected: usize, got: usize },
     #[error("invalid input layout: {message}")]
     InvalidInputLayout { message: String },
+    /// A constraint uses a construct which cannot be lowered to an ACE circuit.
+    ///
+    /// `constraint` is the index of the constraint in the order in which the AIR asserts it.
+    #[error("constraint {constraint} cannot be lowered to an ACE circuit: {description}")]
+    UnsupportedEntry { constraint: usize, description: String },
 }
 
 #[cfg(any(test, feature = "testing"))]
@@ -68,6 +73,7 @@ pub use crate::{
     encode::EncodedCircuit,
     layout::{InputCounts, InputKey, InputLayout},
     pipeline::{
-        AceArtifacts, AceConfig, LayoutKind, build_ace_circuit_for_air, build_ace_dag_for_air,
+        AceArtifacts, AceConfig, LayoutKind, build_ace_circuit_for_air,
+        build_ace_circuit_with_layout, build_ace_dag_for_air, build_layout_for_air,
     },
 };
@@ -67,6 +67,32 @@ where
     emit_circuit(&artifacts.dag, artifacts.layout)
 }
 
+/// Build a verifier-equivalent ACE circuit for the provided AIR, reusing a layout previously
+/// computed by [`build_layout_for_air`].
+///
+/// Returns an error if `layout` is not the layout derived from `air` and `config`, i.e. if its
+/// kind, number of AIRs or input counts differ.
+pub fn build_ace_circuit_with_layout<A, F, EF>(
+    air: &A,
+    config: AceConfig,
+    layout: InputLayout,
+) -> Result<AceCircuit<EF>, AceError>
+where
+    A: LiftedAir<F, EF>,
+    F: TwoAdicField,
+    EF: ExtensionField<F>,
+    SymbolicExpressionExt<F, EF>: Algebra<EF>,
+{
+    if layout != build_layout_for_air::<A, F, EF>(air, config)? {
+        return Err(AceError::InvalidInputLayout {
+            message: "precomputed layout does not match the AIR and config".into(),
+        });
+    }
+
+    let dag = build_dag_with_layout::<A, F, EF>(air, &layout)?;
+    emit_circuit(&dag, layout)
+}
+
 /// Build a verifier-equivalent DAG and layout for the provided AIR.
 pub fn build_ace_dag_for_air<A, F, EF>(
     air: &A,
@@ -77,6 +103,19 @@ where
     F: TwoAdicField,
     EF: ExtensionField<F>,
     SymbolicExpressionExt<F, EF>: Algebra<EF>,
+{
+    let layout = build_layout_for_air::<A, F, EF>(air, config)?;
+    let dag = build_dag_with_layout::<A, F, EF>(air, &layout)?;
+
+    Ok(AceArtifacts { layout, dag })
+}
+
+/// Build the ACE input layout for the provided AIR.
+pub fn build_layout_for_air<A, F, EF>(air: &A, config: AceConfig) -> Result<InputLayout, AceError>
+where
+    A: LiftedAir<F, EF>,
+    F: Field,
+    EF: ExtensionField<F>,
 {
     if config.num_airs == 0 {
         return Err(AceError::InvalidInputLayout {
@@ -84,7 +123,6 @@ where
         });
     }
 
-    let periodic_columns = air.periodic_columns();
     let counts = input_counts_for_air::<A, F, EF>(air, config)?;
     let layout = match (config.layout, config.num_airs >= 2) {
         (LayoutKind::Native, false) => InputLayout::new(counts),
@@ -94,8 +132,23 @@ where
     };
     layout.validate();
 
+    Ok(layout)
+}
+
+fn build_dag_with_layout<A, F, EF>(air: &A, layout: &InputLayout) -> Result<AceDag<EF>, AceError>
+where
+    A: LiftedAir<F, EF>,
+    F: TwoAdicField,
+    EF: ExtensionField<F>,
+    SymbolicExpressionExt<F, EF>: Algebra<EF>,
+{
+    let periodic_columns = air.periodic_columns();
+    let counts = layout.counts;
+
     let air_layout = AirLayout {
-        preprocessed_width: 0,
+        // constraints reading preprocessed columns are rejected during lowering, with an error
+        // identifying the offending constraint
+        preprocessed_width: air.preprocessed_width(),
         main_width: counts.width,
         num_public_values: counts.num_public,
         permutation_width: counts.aux_width,
@@ -111,15 +164,13 @@ where
 
     let periodic_data = (!periodic_columns.is_empty())
         .then(|| PeriodicColumnData::from_periodic_columns::<F>(periodic_columns.to_vec()));
-    let dag = build_verifier_dag::<F, EF>(
+    build_verifier_dag::<F, EF>(
         &base_constraints,
         &ext_constraints,
         &constraint_layout,
-        &layout,
+        layout,
         periodic_data.as_ref(),
-    );
-
-    Ok(AceArtifacts { layout, dag })
+    )
 }
 
 fn input_counts_for_air<A, F, EF>(air: &A, config: AceConfig) -> Result<InputCounts, AceError>
@@ -133,11 +184,6 @@ where
             message: "num_quotient_chunks must be > 0".into(),
         });
     }
-    if air.preprocessed_trace().is_some() {
-        return Err(AceError::InvalidInputLayout {
-            message: "preprocessed trace inputs are not supported".into(),
-        });
-    }
 
     let num_randomness = air.num_randomness();
     if num_randomness != 2 {
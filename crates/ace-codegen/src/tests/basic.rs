@@ -12,8 +12,12 @@ use miden_crypto::{
 
 use super::common::{eval_dag, eval_folded_constraints, eval_periodic_values, eval_quotient};
 use crate::{
-    AceConfig, InputKey, InputLayout, LayoutKind, circuit::emit_circuit,
-    pipeline::build_ace_dag_for_air,
+    AceConfig, InputKey, InputLayout, LayoutKind,
+    circuit::emit_circuit,
+    pipeline::{
+        build_ace_circuit_for_air, build_ace_circuit_with_layout, build_ace_dag_for_air,
+        build_layout_for_air,
+    },
 };
 
 // Base and extension field types for tests.
@@ -329,6 +333,118 @@ fn pipeline_rejects_zero_quotient_chunks() {
     );
 }
 
+#[test]
+fn circuits_built_from_shared_layout_match_full_pipeline() {
+    let config = AceConfig {
+        num_quotient_chunks: 2,
+        layout: LayoutKind::Masm,
+        num_airs: 1,
+    };
+    let expected = build_ace_circuit_for_air::<_, F, EF>(&MockAir, config)
+        .unwrap()
+        .to_ace()
+        .unwrap()
+        .circuit_hash();
+
+    let layout = build_layout_for_air::<_, F, EF>(&MockAir, config).unwrap();
+    for _ in 0..2 {
+        let circuit =
+            build_ace_circuit_with_layout::<_, F, EF>(&MockAir, config, layout.clone()).unwrap();
+        assert_eq!(circuit.layout(), &layout);
+        assert_eq!(circuit.to_ace().unwrap().circuit_hash(), expected);
+    }
+}
+
+#[test]
+fn circuit_with_layout_rejects_mismatched_layout() {
+    let config = AceConfig {
+        num_quotient_chunks: 2,
+        layout: LayoutKind::Masm,
+        num_airs: 1,
+    };
+    let mismatched_configs = [
+        AceConfig { layout: LayoutKind::Native, ..config },
+        AceConfig { num_airs: 2, ..config },
+        AceConfig { num_quotient_chunks: 4, ..config },
+    ];
+
+    for other_config in mismatched_configs {
+        let other_layout = build_layout_for_air::<_, F, EF>(&MockAir, other_config).unwrap();
+        let err =
+            build_ace_circuit_with_layout::<_, F, EF>(&MockAir, config, other_layout).unwrap_err();
+        assert!(
+            matches!(err, crate::AceError::InvalidInputLayout { .. }),
+            "expected InvalidInputLayout, got {err:?}"
+        );
+    }
+}
+
+/// AIR whose second constraint reads a preprocessed column, which ACE codegen cannot lower.
+struct MockPreprocessedAir;
+
+impl BaseAir<F> for MockPreprocessedAir {
+    fn width(&self) -> usize {
+        1
+    }
+
+    fn preprocessed_trace(&self) -> Option<RowMajorMatrix<F>> {
+        Some(RowMajorMatrix::new(vec![F::ZERO; 2], 1))
+    }
+
+    fn preprocessed_width(&self) -> usize {
+        1
+    }
+}
+
+impl LiftedAir<F, EF> for MockPreprocessedAir {
+    fn num_randomness(&self) -> usize {
+        2
+    }
+
+    fn aux_width(&self) -> usize {
+        1
+    }
+
+    fn num_aux_values(&self) -> usize {
+        1
+    }
+
+    fn build_aux_trace(
+        &self,
+        main: &RowMajorMatrix<F>,
+        _air_inputs: &[F],
+        _aux_inputs: &[F],
+        _challenges: &[EF],
+    ) -> (RowMajorMatrix<EF>, Vec<EF>) {
+        (RowMajorMatrix::new(vec![EF::ZERO; main.height()], 1), vec![EF::ZERO])
+    }
+
+    fn eval<AB: LiftedAirBuilder<F = F>>(&self, builder: &mut AB) {
+        let a = builder.main().current_slice()[0];
+        let fixed = builder.preprocessed().current_slice()[0];
+
+        builder.assert_zero(a);
+        builder.assert_zero(a.into() - fixed.into());
+    }
+}
+
+#[test]
+fn pipeline_reports_constraint_with_unsupported_entry() {
+    let config = AceConfig {
+        num_quotient_chunks: 2,
+        layout: LayoutKind::Native,
+        num_airs: 1,
+    };
+
+    let err = build_ace_dag_for_air::<_, F, EF>(&MockPreprocessedAir, config).unwrap_err();
+    let crate::AceError::UnsupportedEntry { constraint, description } = &err else {
+        panic!("expected UnsupportedEntry, got {err:?}");
+    };
+    assert_eq!(*constraint, 1);
+    assert!(description.contains("preprocessed trace column 0"), "{description}");
+    assert!(err.to_string().starts_with("constraint 1 "), "{err}");
+}
+
 #[test]
 fn test_encoded_circuit_structure() {
     let air = MockAir;
@@ -135,6 +135,13 @@ ast_node!(
     "advice map entry declaration",
     recursive = true
 );
+ast_node!(
+    #[doc = "A `data` item."]
+    DataSegment,
+    SyntaxKind::DataSegment,
+    "data segment declaration",
+    recursive = true
+);
 ast_node!(
     #[doc = "A top-level `begin` block."]
     BeginBlock,
@@ -147,6 +154,12 @@ ast_node!(
     SyntaxKind::Procedure,
     "procedure declaration"
 );
+ast_node!(
+    #[doc = "A `macro` item together with its parameters and body."]
+    Macro,
+    SyntaxKind::Macro,
+    "macro declaration"
+);
 ast_node!(
     #[doc = "An attribute attached to a procedure."]
     Attribute,
@@ -231,8 +244,10 @@ pub enum Item {
     Constant(Constant),
     TypeDecl(TypeDecl),
     AdviceMap(AdviceMap),
+    DataSegment(DataSegment),
     BeginBlock(BeginBlock),
     Procedure(Procedure),
+    Macro(Macro),
 }
 
 impl Item {
@@ -247,8 +262,10 @@ impl Item {
             SyntaxKind::Constant => Constant::cast(node).map(Self::Constant),
             SyntaxKind::TypeDecl => TypeDecl::cast(node).map(Self::TypeDecl),
             SyntaxKind::AdviceMap => AdviceMap::cast(node).map(Self::AdviceMap),
+            SyntaxKind::DataSegment => DataSegment::cast(node).map(Self::DataSegment),
             SyntaxKind::BeginBlock => BeginBlock::cast(node).map(Self::BeginBlock),
             SyntaxKind::Procedure => Procedure::cast(node).map(Self::Procedure),
+            SyntaxKind::Macro => Macro::cast(node).map(Self::Macro),
             _ => None,
         }
     }
@@ -285,6 +302,8 @@ pub enum ImportKind {
     Module,
     /// A braced item import such as `use {foo, bar as baz} from some::module`.
     Items,
+    /// A wildcard import of all items exported by a module, such as `use some::module::*`.
+    Glob,
 }
 
 impl SourceFile {
@@ -326,10 +345,12 @@ impl Import {
         support::child(&self.syntax)
     }
 
-    /// Returns whether this is a module import or a braced item import.
+    /// Returns whether this is a module import, a braced item import, or a wildcard import.
     pub fn kind(&self) -> ImportKind {
         if self.import_list().is_some() {
             ImportKind::Items
+        } else if self.syntax.children_with_tokens().any(|child| child.kind() == SyntaxKind::Star) {
+            ImportKind::Glob
         } else {
             ImportKind::Module
         }
@@ -448,7 +469,24 @@ impl AdviceMap {
     }
 }
 
+impl DataSegment {
+    /// Returns the data segment name token.
+    pub fn name_token(&self) -> Option<SyntaxToken> {
+        token_after_keyword(&self.syntax, "data")
+    }
+
+    /// Returns the data segment value expression.
+    pub fn value_expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+
 impl BeginBlock {
+    /// Returns the attributes attached to this `begin` item in source order.
+    pub fn attributes(&self) -> impl Iterator<Item = Attribute> + '_ {
+        support::children(&self.syntax)
+    }
+
     /// Returns the block body for this top-level `begin` item.
     pub fn block(&self) -> Option<Block> {
         support::child(&self.syntax)
@@ -482,6 +520,28 @@ impl Procedure {
     }
 }
 
+impl Macro {
+    /// Returns the macro name token following `macro.`.
+    pub fn name_token(&self) -> Option<SyntaxToken> {
+        let dot = token_after_keyword(&self.syntax, "macro")?;
+        (dot.kind() == SyntaxKind::Dot).then(|| next_significant_token(&self.syntax, &dot))?
+    }
+
+    /// Returns the parameter name tokens in declaration order.
+    pub fn param_tokens(&self) -> impl Iterator<Item = SyntaxToken> + '_ {
+        significant_tokens(&self.syntax)
+            .skip_while(|token| token.kind() != SyntaxKind::LParen)
+            .skip(1)
+            .take_while(|token| token.kind() != SyntaxKind::RParen)
+            .filter(|token| is_name_like_token(token.kind()))
+    }
+
+    /// Returns the body block for this macro.
+    pub fn block(&self) -> Option<Block> {
+        support::child(&self.syntax)
+    }
+}
+
 impl Block {
     /// Returns the operations contained in this block.
     pub fn operations(&self) -> impl Iterator<Item = Operation> + '_ {
@@ -521,6 +581,11 @@ impl DoWhileOp {
 }
 
 impl RepeatOp {
+    /// Returns the attributes attached to this `repeat` operation in source order.
+    pub fn attributes(&self) -> impl Iterator<Item = Attribute> + '_ {
+        support::children(&self.syntax)
+    }
+
     /// Returns the repeated body.
     pub fn body(&self) -> Option<Block> {
         support::child(&self.syntax)
@@ -319,6 +319,22 @@ impl<'input> Iterator for Lexer<'input> {
                 self.advance_char();
                 self.token(SyntaxKind::Star, start, self.offset)
             },
+            '%' => {
+                self.advance_char();
+                self.token(SyntaxKind::Percent, start, self.offset)
+            },
+            '&' => {
+                self.advance_char();
+                self.token(SyntaxKind::Amp, start, self.offset)
+            },
+            '|' => {
+                self.advance_char();
+                self.token(SyntaxKind::Pipe, start, self.offset)
+            },
+            '^' => {
+                self.advance_char();
+                self.token(SyntaxKind::Caret, start, self.offset)
+            },
             ';' => {
                 self.advance_char();
                 self.token(SyntaxKind::Semicolon, start, self.offset)
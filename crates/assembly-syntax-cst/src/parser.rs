@@ -175,6 +175,7 @@ impl Nesting {
 enum BlockOwner {
     Begin,
     Procedure,
+    Macro,
     If,
     While,
     DoWhileBody,
@@ -187,6 +188,7 @@ impl BlockOwner {
         match self {
             Self::Begin => "expected `end` to close `begin` block",
             Self::Procedure => "expected `end` to close procedure",
+            Self::Macro => "expected `end` to close macro",
             Self::If => "expected `end` to close `if`",
             Self::While => "expected `end` to close `while`",
             Self::DoWhileBody => "expected `while` to close `do` block",
@@ -300,6 +302,11 @@ impl<'input> Parser<'input> {
             return;
         }
 
+        if self.at_kind(SyntaxKind::At) && self.at_attributed_keyword("begin") {
+            self.parse_begin_block();
+            return;
+        }
+
         if self.at_kind(SyntaxKind::At)
             || self.at_keyword("proc")
             || self.at_prefixed_keyword("pub", "proc")
@@ -308,6 +315,11 @@ impl<'input> Parser<'input> {
             return;
         }
 
+        if self.at_keyword("macro") {
+            self.parse_macro();
+            return;
+        }
+
         if self.at_keyword("begin") {
             self.parse_begin_block();
             return;
@@ -337,6 +349,11 @@ impl<'input> Parser<'input> {
             return;
         }
 
+        if self.at_keyword("data") {
+            self.parse_data_segment();
+            return;
+        }
+
         self.start_node(SyntaxKind::Error);
         self.error_here("unexpected top-level token");
         self.bump();
@@ -431,6 +448,16 @@ impl<'input> Parser<'input> {
 
         self.parse_path_with_message("expected an import path");
 
+        // A wildcard import of all items exported by the module, i.e. `use some::module::*`
+        if self.at_glob_suffix() {
+            self.bump_non_comment_trivia();
+            self.bump();
+            self.bump_non_comment_trivia();
+            self.bump();
+            self.parse_rejected_old_import_alias();
+            return;
+        }
+
         if is_public {
             self.error_at_span(path_start, "`pub use` is only supported for braced item imports");
         }
@@ -503,7 +530,7 @@ impl<'input> Parser<'input> {
         self.bump_regular_trivia();
 
         if self.at_kind(SyntaxKind::Star) {
-            self.error_here("wildcard imports are not supported");
+            self.error_here("wildcard imports are written as `use <module>::*`");
             self.bump();
             self.finish_node();
             return;
@@ -586,7 +613,7 @@ impl<'input> Parser<'input> {
 
     fn parse_rejected_wildcard_import(&mut self) {
         self.start_node(SyntaxKind::Error);
-        self.error_here("wildcard imports are not supported");
+        self.error_here("wildcard imports are written as `use <module>::*`");
         self.bump();
 
         if self.peek_contextual_keyword_after_non_comment_trivia("from") {
@@ -678,6 +705,23 @@ impl<'input> Parser<'input> {
         self.finish_node();
     }
 
+    fn parse_data_segment(&mut self) {
+        self.start_node(SyntaxKind::DataSegment);
+        self.expect_keyword("data", "expected `data`");
+        self.bump_regular_trivia();
+        if self.at_name_like() {
+            self.bump();
+        } else {
+            self.error_here("expected a data segment name");
+        }
+
+        self.bump_inline_whitespace();
+        self.expect_kind(SyntaxKind::Equal, "expected `=` in data segment declaration");
+        self.parse_expr_until_line_end();
+        self.parse_line_tail();
+        self.finish_node();
+    }
+
     fn parse_path_with_message(&mut self, message: &'static str) {
         self.start_node(SyntaxKind::Path);
         if self.at_kind(SyntaxKind::ColonColon) {
@@ -694,7 +738,9 @@ impl<'input> Parser<'input> {
         }
 
         loop {
-            if self.peek_after_non_comment_trivia() != Some(SyntaxKind::ColonColon) {
+            if self.peek_after_non_comment_trivia() != Some(SyntaxKind::ColonColon)
+                || self.at_glob_suffix()
+            {
                 break;
             }
 
@@ -779,6 +825,7 @@ impl<'input> Parser<'input> {
 
     fn parse_begin_block(&mut self) {
         self.start_node(SyntaxKind::BeginBlock);
+        self.parse_leading_attributes();
         self.expect_keyword("begin", "expected `begin`");
         self.parse_line_tail();
         if self.parse_block(BlockOwner::Begin, &["end"]) == BlockParseOutcome::FoundTerminator {
@@ -789,16 +836,7 @@ impl<'input> Parser<'input> {
 
     fn parse_procedure(&mut self) {
         self.start_node(SyntaxKind::Procedure);
-
-        loop {
-            self.bump_regular_trivia();
-            if !self.at_kind(SyntaxKind::At) {
-                break;
-            }
-            self.parse_attribute();
-        }
-
-        self.bump_regular_trivia();
+        self.parse_leading_attributes();
         if self.at_keyword("pub") {
             self.parse_visibility();
         }
@@ -828,6 +866,50 @@ impl<'input> Parser<'input> {
         self.finish_node();
     }
 
+    fn parse_macro(&mut self) {
+        self.start_node(SyntaxKind::Macro);
+        self.expect_keyword("macro", "expected `macro` in macro declaration");
+        if !self.at_kind(SyntaxKind::Dot) {
+            self.error_here("expected `.` after `macro`");
+            self.finish_node();
+            return;
+        }
+        self.bump();
+
+        if self.at_name_like() {
+            self.bump();
+        } else {
+            self.error_here("expected a macro name");
+        }
+
+        self.bump_inline_whitespace();
+        if self.at_kind(SyntaxKind::LParen) {
+            self.parse_balanced_group(
+                SyntaxKind::LParen,
+                SyntaxKind::RParen,
+                "expected `)` to close macro parameters",
+            );
+        }
+
+        self.parse_line_tail();
+        if self.parse_block(BlockOwner::Macro, &["end"]) == BlockParseOutcome::FoundTerminator {
+            self.expect_keyword("end", BlockOwner::Macro.missing_end_message());
+        }
+        self.finish_node();
+    }
+
+    /// Parses the attributes preceding an item, along with the trivia around them.
+    fn parse_leading_attributes(&mut self) {
+        loop {
+            self.bump_regular_trivia();
+            if !self.at_kind(SyntaxKind::At) {
+                break;
+            }
+            self.parse_attribute();
+        }
+        self.bump_regular_trivia();
+    }
+
     fn parse_attribute(&mut self) {
         self.start_node(SyntaxKind::Attribute);
         let _ = self.expect_kind(SyntaxKind::At, "expected `@`");
@@ -932,7 +1014,7 @@ impl<'input> Parser<'input> {
                     self.finish_node();
                     return BlockParseOutcome::ReachedEof;
                 }
-            } else if self.at_keyword("repeat") {
+            } else if self.at_keyword("repeat") || self.at_attributed_repeat() {
                 if self.parse_repeat() {
                     self.finish_node();
                     return BlockParseOutcome::ReachedEof;
@@ -995,7 +1077,7 @@ impl<'input> Parser<'input> {
                     self.finish_node();
                     return BlockParseOutcome::ReachedEof;
                 }
-            } else if self.at_keyword("repeat") {
+            } else if self.at_keyword("repeat") || self.at_attributed_repeat() {
                 if self.parse_repeat() {
                     self.finish_node();
                     return BlockParseOutcome::ReachedEof;
@@ -1090,6 +1172,9 @@ impl<'input> Parser<'input> {
 
     fn parse_repeat(&mut self) -> bool {
         self.start_node(SyntaxKind::RepeatOp);
+        if self.at_kind(SyntaxKind::At) {
+            self.parse_leading_attributes();
+        }
         self.expect_keyword("repeat", "expected `repeat`");
         self.parse_structured_header_suffixes();
         self.parse_line_tail();
@@ -1229,6 +1314,10 @@ impl<'input> Parser<'input> {
                     | SyntaxKind::Star
                     | SyntaxKind::Slash
                     | SyntaxKind::SlashSlash
+                    | SyntaxKind::Percent
+                    | SyntaxKind::Amp
+                    | SyntaxKind::Pipe
+                    | SyntaxKind::Caret
                     | SyntaxKind::RBracket
                     | SyntaxKind::RParen
                     | SyntaxKind::RBrace
@@ -1318,6 +1407,17 @@ impl<'input> Parser<'input> {
         None
     }
 
+    /// Returns true if the next tokens are the `::*` suffix of a wildcard import path.
+    fn at_glob_suffix(&self) -> bool {
+        let mut significant = self.tokens[self.pos.min(self.tokens.len())..]
+            .iter()
+            .filter(|token| !matches!(token.kind(), SyntaxKind::Whitespace | SyntaxKind::Newline));
+        matches!(
+            (significant.next().map(Token::kind), significant.next().map(Token::kind)),
+            (Some(SyntaxKind::ColonColon), Some(SyntaxKind::Star))
+        )
+    }
+
     fn peek_contextual_keyword_after_non_comment_trivia(&self, keyword: &str) -> bool {
         matches!(
             self.peek_token_after_non_comment_trivia(),
@@ -1334,8 +1434,8 @@ impl<'input> Parser<'input> {
             || token.kind() == SyntaxKind::At
             || (token.kind() == SyntaxKind::Ident
                 && match token.text() {
-                    "adv_map" | "begin" | "const" | "enum" | "mod" | "namespace" | "proc"
-                    | "type" | "use" => true,
+                    "adv_map" | "begin" | "const" | "data" | "enum" | "macro" | "mod"
+                    | "namespace" | "proc" | "type" | "use" => true,
                     "extern" => matches!(
                         self.next_relevant_top_level_token(index + 1)
                             .and_then(|next| self.tokens.get(next)),
@@ -1393,6 +1493,9 @@ impl<'input> Parser<'input> {
     }
 
     fn at_top_level_form_starter_in_block(&self) -> bool {
+        if self.at_attributed_repeat() {
+            return false;
+        }
         match self.current() {
             Some(token) if token.kind() == SyntaxKind::DocComment => self
                 .next_relevant_block_token(self.pos + 1)
@@ -1448,6 +1551,50 @@ impl<'input> Parser<'input> {
         matches!(self.current(), Some(token) if token.kind() == SyntaxKind::Ident && token.text() == keyword)
     }
 
+    /// Returns true if the parser is at a `repeat` block preceded by attributes, e.g. `@no_unroll`.
+    fn at_attributed_repeat(&self) -> bool {
+        self.at_kind(SyntaxKind::At) && self.at_attributed_keyword("repeat")
+    }
+
+    /// Returns true if the parser is at a sequence of attributes followed by `keyword`.
+    fn at_attributed_keyword(&self, keyword: &str) -> bool {
+        let mut index = self.pos;
+        loop {
+            let Some(next) = self.next_relevant_top_level_token(index) else {
+                return false;
+            };
+            let token = &self.tokens[next];
+            if token.kind() != SyntaxKind::At {
+                return token.kind() == SyntaxKind::Ident && token.text() == keyword;
+            }
+
+            // Skip the attribute name, and its balanced argument list, if any
+            let Some(name) = self.next_relevant_top_level_token(next + 1) else {
+                return false;
+            };
+            index = name + 1;
+            while self.tokens.get(index).map(Token::kind) == Some(SyntaxKind::Whitespace) {
+                index += 1;
+            }
+            if self.tokens.get(index).map(Token::kind) == Some(SyntaxKind::LParen) {
+                let mut depth = 0usize;
+                while let Some(token) = self.tokens.get(index) {
+                    index += 1;
+                    match token.kind() {
+                        SyntaxKind::LParen => depth += 1,
+                        SyntaxKind::RParen => {
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                        },
+                        _ => {},
+                    }
+                }
+            }
+        }
+    }
+
     fn at_prefixed_keyword(&self, prefix: &str, keyword: &str) -> bool {
         if !self.at_keyword(prefix) {
             return false;
@@ -1639,6 +1786,10 @@ fn expects_continuation_operand(kind: SyntaxKind) -> bool {
             | SyntaxKind::Star
             | SyntaxKind::Slash
             | SyntaxKind::SlashSlash
+            | SyntaxKind::Percent
+            | SyntaxKind::Amp
+            | SyntaxKind::Pipe
+            | SyntaxKind::Caret
             | SyntaxKind::LBracket
             | SyntaxKind::LParen
             | SyntaxKind::LBrace
@@ -1660,6 +1811,10 @@ fn punctuation_continues_instruction(kind: SyntaxKind) -> bool {
             | SyntaxKind::Star
             | SyntaxKind::Slash
             | SyntaxKind::SlashSlash
+            | SyntaxKind::Percent
+            | SyntaxKind::Amp
+            | SyntaxKind::Pipe
+            | SyntaxKind::Caret
             | SyntaxKind::RBracket
             | SyntaxKind::RParen
             | SyntaxKind::RBrace
@@ -1672,12 +1827,14 @@ fn is_reserved_block_keyword(text: &str) -> bool {
         "adv_map"
             | "begin"
             | "const"
+            | "data"
             | "do"
             | "else"
             | "end"
             | "enum"
             | "extern"
             | "if"
+            | "macro"
             | "mod"
             | "namespace"
             | "proc"
@@ -1705,7 +1862,7 @@ mod tests {
     use rowan::ast::AstNode;
 
     use crate::{
-        ast::{ImportKind, Item, SourceFile as AstSourceFile},
+        ast::{ImportKind, Item, Operation, SourceFile as AstSourceFile},
         parse_source_file, parse_text,
         parser::parse_inline_masm_text,
         syntax::SyntaxKind,
@@ -1787,6 +1944,15 @@ adv_map TABLE = [
     [1, 2],
     event(foo(bar, baz)),
 ]
+",
+            "\
+macro.push_pair(a, b) # pushes two values
+    push.a push.b
+end
+
+begin
+    macro_exec.push_pair(1, 2)
+end
 ",
         ]
     }
@@ -1952,6 +2118,10 @@ pub use {bar as baz} from foo
 pub const X = 1
 pub type FeltAlias = felt
 adv_map TABLE = [0x01, 0x02]
+data LOOKUP = [
+    [1, 2, 3, 4],
+    [5, 6, 7, 8],
+]
 begin
     if.true
         repeat.4
@@ -1985,6 +2155,7 @@ end
                 SyntaxKind::Constant,
                 SyntaxKind::TypeDecl,
                 SyntaxKind::AdviceMap,
+                SyntaxKind::DataSegment,
                 SyntaxKind::BeginBlock,
                 SyntaxKind::Procedure,
             ]
@@ -2102,6 +2273,91 @@ adv_map TABLE(0x0200000000000000020000000000000002000000000000000200000000000000
         );
     }
 
+    #[test]
+    fn parses_macro_declarations_and_invocations() {
+        let source = "\
+macro.push_word(a, b, c, d)
+    push.d push.c push.b push.a
+end
+
+proc foo
+    macro_exec.push_word(1, 2, 3, 4)
+end
+";
+
+        let parse = parse_text(source);
+        assert!(!parse.has_errors(), "{:?}", parse.diagnostics());
+
+        let source_file = AstSourceFile::cast(parse.syntax()).expect("source file");
+        let items = source_file.items().collect::<Vec<_>>();
+        assert_eq!(items.len(), 2);
+
+        let Item::Macro(macro_decl) = &items[0] else {
+            panic!("expected macro, got {:?}", items[0]);
+        };
+        assert_eq!(macro_decl.name_token().expect("macro name").text(), "push_word");
+        assert_eq!(
+            macro_decl
+                .param_tokens()
+                .map(|token| token.text().to_string())
+                .collect::<Vec<_>>(),
+            vec!["a", "b", "c", "d"]
+        );
+        assert_eq!(macro_decl.block().expect("macro body").operations().count(), 4);
+
+        let Item::Procedure(procedure) = &items[1] else {
+            panic!("expected procedure, got {:?}", items[1]);
+        };
+        let operations =
+            procedure.block().expect("procedure body").operations().collect::<Vec<_>>();
+        assert_eq!(operations.len(), 1);
+        let Operation::Instruction(instruction) = &operations[0] else {
+            panic!("expected instruction, got {:?}", operations[0]);
+        };
+        assert_eq!(
+            instruction
+                .significant_tokens()
+                .map(|token| token.text().to_string())
+                .collect::<Vec<_>>(),
+            vec!["macro_exec", ".", "push_word", "(", "1", ",", "2", ",", "3", ",", "4", ")"]
+        );
+    }
+
+    #[test]
+    fn parses_attributes_on_begin_blocks() {
+        let source = "\
+@cfg(feature = \"debug\")
+proc foo
+    nop
+end
+
+@cfg(not(feature = \"debug\"))
+begin
+    exec.foo
+end
+";
+
+        let parse = parse_text(source);
+        assert!(!parse.has_errors(), "{:?}", parse.diagnostics());
+
+        let source_file = AstSourceFile::cast(parse.syntax()).expect("source file");
+        let items = source_file.items().collect::<Vec<_>>();
+        assert_eq!(items.len(), 2);
+
+        let Item::Procedure(procedure) = &items[0] else {
+            panic!("expected procedure, got {:?}", items[0]);
+        };
+        assert_eq!(procedure.attributes().count(), 1);
+
+        let Item::BeginBlock(begin) = &items[1] else {
+            panic!("expected begin block, got {:?}", items[1]);
+        };
+        let attributes = begin.attributes().collect::<Vec<_>>();
+        assert_eq!(attributes.len(), 1);
+        assert_eq!(attributes[0].syntax().text().to_string(), "@cfg(not(feature = \"debug\"))");
+        assert_eq!(begin.block().expect("begin body").operations().count(), 1);
+    }
+
     #[test]
     fn parses_unparenthesized_procedure_result_types() {
         let source = "\
@@ -2453,8 +2709,40 @@ use {as, from as as, \"as\" as \"from\", $kernel} from \"from\"::\"as\"
     #[test]
     fn cst_import_rejects_wildcard_imports() {
         for source in ["use * from m\n", "use {*} from m\n", "use {foo, *} from m\n"] {
-            assert_import_rejected(source, "wildcard imports are not supported");
+            assert_import_rejected(source, "wildcard imports are written as `use <module>::*`");
+        }
+        assert_import_rejected("use m::* as n\n", "unexpected top-level token");
+    }
+
+    #[test]
+    fn cst_import_parses_glob_imports() {
+        let source = "\
+use miden::core::math::*
+pub use ::some::module :: *
+";
+
+        let parse = parse_text(source);
+        assert!(!parse.has_errors(), "{:?}", parse.diagnostics());
+
+        let source_file = AstSourceFile::cast(parse.syntax()).expect("source file");
+        let imports = source_file
+            .items()
+            .map(|item| match item {
+                Item::Import(import) => import,
+                other => panic!("expected import, got {other:?}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(imports.len(), 2);
+
+        for (import, path) in imports.iter().zip(["miden::core::math", "::some::module"]) {
+            assert_eq!(import.kind(), ImportKind::Glob);
+            assert_eq!(
+                import.module_path().expect("path").syntax().text().to_string().trim(),
+                path
+            );
+            assert!(import.module_alias_token().is_none());
         }
+        assert!(imports[1].visibility().is_some());
     }
 
     #[test]
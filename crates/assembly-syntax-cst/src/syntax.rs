@@ -17,8 +17,10 @@ pub enum SyntaxKind {
     Constant,
     TypeDecl,
     AdviceMap,
+    DataSegment,
     BeginBlock,
     Procedure,
+    Macro,
     Attribute,
     Visibility,
     Signature,
@@ -40,8 +42,10 @@ pub enum SyntaxKind {
     Number,
     QuotedIdent,
     QuotedString,
+    Amp,
     At,
     Bang,
+    Caret,
     Colon,
     ColonColon,
     Comma,
@@ -53,6 +57,8 @@ pub enum SyntaxKind {
     LBracket,
     LParen,
     Minus,
+    Percent,
+    Pipe,
     Plus,
     RAngle,
     RArrow,
@@ -93,8 +99,10 @@ const ALL_KINDS: [SyntaxKind; SyntaxKind::Star as usize + 1] = [
     SyntaxKind::Constant,
     SyntaxKind::TypeDecl,
     SyntaxKind::AdviceMap,
+    SyntaxKind::DataSegment,
     SyntaxKind::BeginBlock,
     SyntaxKind::Procedure,
+    SyntaxKind::Macro,
     SyntaxKind::Attribute,
     SyntaxKind::Visibility,
     SyntaxKind::Signature,
@@ -116,8 +124,10 @@ const ALL_KINDS: [SyntaxKind; SyntaxKind::Star as usize + 1] = [
     SyntaxKind::Number,
     SyntaxKind::QuotedIdent,
     SyntaxKind::QuotedString,
+    SyntaxKind::Amp,
     SyntaxKind::At,
     SyntaxKind::Bang,
+    SyntaxKind::Caret,
     SyntaxKind::Colon,
     SyntaxKind::ColonColon,
     SyntaxKind::Comma,
@@ -129,6 +139,8 @@ const ALL_KINDS: [SyntaxKind; SyntaxKind::Star as usize + 1] = [
     SyntaxKind::LBracket,
     SyntaxKind::LParen,
     SyntaxKind::Minus,
+    SyntaxKind::Percent,
+    SyntaxKind::Pipe,
     SyntaxKind::Plus,
     SyntaxKind::RAngle,
     SyntaxKind::RArrow,
@@ -1,7 +1,7 @@
 // Allow unused assignments - required by miette::Diagnostic derive macro
 #![allow(unused_assignments)]
 
-use alloc::{sync::Arc, vec::Vec};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
 use smallvec::SmallVec;
 
@@ -10,7 +10,7 @@ use crate::{
     ast::*,
     debuginfo::{SourceFile, SourceSpan, Span, Spanned},
     diagnostics::{Diagnostic, RelatedLabel, miette},
-    parser::IntValue,
+    parser::{IntValue, WordValue},
 };
 
 /// An error raised during evaluation of a constant expression
@@ -296,6 +296,9 @@ where
         /// We have finished evaluating the operands of a constant op, and must now apply the
         /// operation to them, pushing the result on the operand stack.
         Apply(Span<ConstantOp>),
+        /// We have finished evaluating the elements of a `word(..)` expression, and must now
+        /// construct the word from them, pushing the result on the operand stack.
+        MakeWord(SourceSpan),
         /// We have finished evaluating a reference to another constant and are returning
         /// its value on the operand stack
         Return(Span<Arc<Path>>),
@@ -361,6 +364,37 @@ where
                 continuations.push(Cont::Eval(*lhs));
                 continuations.push(Cont::Eval(*rhs));
             },
+            Cont::Eval(ConstantExpr::MakeWord { span, elements }) => {
+                continuations.push(Cont::MakeWord(span));
+                continuations.extend(elements.into_iter().rev().map(Cont::Eval));
+            },
+            Cont::MakeWord(span) => {
+                let elements = stack.split_off(stack.len() - 4);
+                let elements: [ConstantExpr; 4] = elements.try_into().unwrap();
+                if let Some(invalid) = elements
+                    .iter()
+                    .find(|elem| !matches!(elem, ConstantExpr::Int(_) | ConstantExpr::Var(_)))
+                {
+                    let operand = invalid.span();
+                    return Err(ConstEvalError::InvalidConstExprOperand {
+                        span,
+                        operand,
+                        source_file: env.get_source_file_for(operand),
+                    }
+                    .into());
+                }
+                if elements.iter().all(|elem| matches!(elem, ConstantExpr::Int(_))) {
+                    let word = elements.map(|elem| match elem {
+                        ConstantExpr::Int(value) => {
+                            Felt::new_unchecked(value.into_inner().as_int())
+                        },
+                        _ => unreachable!(),
+                    });
+                    stack.push(ConstantExpr::Word(Span::new(span, WordValue(word))));
+                } else {
+                    stack.push(ConstantExpr::MakeWord { span, elements: Box::new(elements) });
+                }
+            },
             Cont::Apply(op) => {
                 let lhs = stack.pop().unwrap();
                 let rhs = stack.pop().unwrap();
@@ -394,6 +428,29 @@ where
                                     source_file: env.get_source_file_for(span),
                                 }
                             })?,
+                            ConstantOp::Mod => lhs.checked_rem(rhs).ok_or_else(|| {
+                                ConstEvalError::DivisionByZero {
+                                    span,
+                                    source_file: env.get_source_file_for(span),
+                                }
+                            })?,
+                            ConstantOp::Shl
+                            | ConstantOp::Shr
+                            | ConstantOp::BitAnd
+                            | ConstantOp::BitOr
+                            | ConstantOp::BitXor => {
+                                let result = match op {
+                                    ConstantOp::Shl => lhs.checked_shl(rhs),
+                                    ConstantOp::Shr => lhs.checked_shr(rhs),
+                                    ConstantOp::BitAnd => lhs.checked_bitand(rhs),
+                                    ConstantOp::BitOr => lhs.checked_bitor(rhs),
+                                    _ => lhs.checked_bitxor(rhs),
+                                };
+                                result.ok_or_else(|| ConstEvalError::ConstExprOverflow {
+                                    span,
+                                    source_file: env.get_source_file_for(span),
+                                })?
+                            },
                             ConstantOp::Div => {
                                 if rhs.as_int() == 0 {
                                     return Err(ConstEvalError::DivisionByZero {
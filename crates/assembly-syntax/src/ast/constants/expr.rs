@@ -39,6 +39,11 @@ pub enum ConstantExpr {
     /// A spanned string with a [`HashKind`] showing to which type of value the given string should
     /// be hashed.
     Hash(HashKind, Ident),
+    /// A word constructed from four constant expressions, i.e. `word(a, b, c, d)`.
+    MakeWord {
+        span: SourceSpan,
+        elements: Box<[ConstantExpr; 4]>,
+    },
 }
 
 impl ConstantExpr {
@@ -102,7 +107,7 @@ impl ConstantExpr {
             Self::String(value) => Ok(ConstantValue::String(value)),
             Self::Word(value) => Ok(ConstantValue::Word(value)),
             Self::Hash(kind, value) => Ok(ConstantValue::Hash(kind, value)),
-            expr @ (Self::BinaryOp { .. } | Self::Var(_)) => Err(expr),
+            expr @ (Self::BinaryOp { .. } | Self::Var(_) | Self::MakeWord { .. }) => Err(expr),
         }
     }
 
@@ -115,7 +120,7 @@ impl ConstantExpr {
             Self::String(value) => Some(ConstantValue::String(value.clone())),
             Self::Word(value) => Some(ConstantValue::Word(*value)),
             Self::Hash(kind, value) => Some(ConstantValue::Hash(*kind, value.clone())),
-            Self::BinaryOp { .. } | Self::Var(_) => None,
+            Self::BinaryOp { .. } | Self::Var(_) | Self::MakeWord { .. } => None,
         }
     }
 
@@ -136,6 +141,9 @@ impl ConstantExpr {
                     worklist.push(lhs);
                     worklist.push(rhs);
                 },
+                Self::MakeWord { elements, .. } => {
+                    worklist.extend(elements.iter());
+                },
             }
         }
 
@@ -163,6 +171,8 @@ impl PartialEq for ConstantExpr {
                 Self::BinaryOp { op: rop, lhs: rlhs, rhs: rrhs, .. },
             ) => lop == rop && llhs == rlhs && lrhs == rrhs,
             (Self::BinaryOp { .. }, _) => false,
+            (Self::MakeWord { elements: x, .. }, Self::MakeWord { elements: y, .. }) => x == y,
+            (Self::MakeWord { .. }, _) => false,
         }
     }
 }
@@ -184,6 +194,7 @@ impl core::hash::Hash for ConstantExpr {
                 lhs.hash(state);
                 rhs.hash(state);
             },
+            Self::MakeWord { elements, .. } => elements.hash(state),
         }
     }
 }
@@ -201,6 +212,9 @@ impl fmt::Debug for ConstantExpr {
             Self::BinaryOp { op, lhs, rhs, .. } => {
                 f.debug_tuple(op.name()).field(lhs).field(rhs).finish()
             },
+            Self::MakeWord { elements, .. } => {
+                f.debug_tuple("MakeWord").field(&elements.as_slice()).finish()
+            },
         }
     }
 }
@@ -221,10 +235,27 @@ impl crate::prettier::PrettyPrint for ConstantExpr {
                     + const_text(")"),
             ),
             Self::BinaryOp { op, lhs, rhs, .. } => {
-                let single_line = lhs.render() + display(op) + rhs.render();
-                let multi_line = lhs.render() + nl() + (display(op)) + rhs.render();
+                // Operands which bind less tightly than `op` must be parenthesized to round-trip
+                let operand = |expr: &ConstantExpr, min_precedence: u8| match expr {
+                    Self::BinaryOp { op, .. } if op.precedence() < min_precedence => {
+                        const_text("(") + expr.render() + const_text(")")
+                    },
+                    _ => expr.render(),
+                };
+                let lhs = operand(lhs, op.precedence());
+                let rhs = operand(rhs, op.precedence() + 1);
+                let single_line = lhs.clone() + display(op) + rhs.clone();
+                let multi_line = lhs + nl() + (display(op)) + rhs;
                 single_line | multi_line
             },
+            Self::MakeWord { elements, .. } => {
+                let elements = elements
+                    .iter()
+                    .map(PrettyPrint::render)
+                    .reduce(|acc, element| acc + const_text(",") + element)
+                    .unwrap_or(Document::Empty);
+                flatten(const_text("word(") + elements + const_text(")"))
+            },
         }
     }
 }
@@ -237,7 +268,7 @@ impl Spanned for ConstantExpr {
             Self::Hash(_, spanned) => spanned.span(),
             Self::Var(spanned) => spanned.span(),
             Self::String(spanned) => spanned.span(),
-            Self::BinaryOp { span, .. } => *span,
+            Self::BinaryOp { span, .. } | Self::MakeWord { span, .. } => *span,
         }
     }
 }
@@ -286,9 +317,31 @@ pub enum ConstantOp {
     Mul,
     Div,
     IntDiv,
+    Mod,
+    Shl,
+    Shr,
+    BitAnd,
+    BitOr,
+    BitXor,
 }
 
 impl ConstantOp {
+    /// Returns the binding power of this operator, where operators with a higher precedence bind
+    /// more tightly than operators with a lower one.
+    ///
+    /// From loosest to tightest, the operators are: `|`, `^`, `&`, `<<` and `>>`, `+` and `-`,
+    /// and finally `*`, `/`, `//` and `%`. All operators are left-associative.
+    pub const fn precedence(self) -> u8 {
+        match self {
+            Self::BitOr => 1,
+            Self::BitXor => 2,
+            Self::BitAnd => 3,
+            Self::Shl | Self::Shr => 4,
+            Self::Add | Self::Sub => 5,
+            Self::Mul | Self::Div | Self::IntDiv | Self::Mod => 6,
+        }
+    }
+
     const fn name(self) -> &'static str {
         match self {
             Self::Add => "Add",
@@ -296,6 +349,12 @@ impl ConstantOp {
             Self::Mul => "Mul",
             Self::Div => "Div",
             Self::IntDiv => "IntDiv",
+            Self::Mod => "Mod",
+            Self::Shl => "Shl",
+            Self::Shr => "Shr",
+            Self::BitAnd => "BitAnd",
+            Self::BitOr => "BitOr",
+            Self::BitXor => "BitXor",
         }
     }
 }
@@ -308,6 +367,12 @@ impl fmt::Display for ConstantOp {
             Self::Mul => f.write_str("*"),
             Self::Div => f.write_str("/"),
             Self::IntDiv => f.write_str("//"),
+            Self::Mod => f.write_str("%"),
+            Self::Shl => f.write_str("<<"),
+            Self::Shr => f.write_str(">>"),
+            Self::BitAnd => f.write_str("&"),
+            Self::BitOr => f.write_str("|"),
+            Self::BitXor => f.write_str("^"),
         }
     }
 }
@@ -337,6 +402,12 @@ impl Deserializable for ConstantOp {
         const MUL: u8 = ConstantOp::Mul.tag();
         const DIV: u8 = ConstantOp::Div.tag();
         const INT_DIV: u8 = ConstantOp::IntDiv.tag();
+        const MOD: u8 = ConstantOp::Mod.tag();
+        const SHL: u8 = ConstantOp::Shl.tag();
+        const SHR: u8 = ConstantOp::Shr.tag();
+        const BIT_AND: u8 = ConstantOp::BitAnd.tag();
+        const BIT_OR: u8 = ConstantOp::BitOr.tag();
+        const BIT_XOR: u8 = ConstantOp::BitXor.tag();
 
         match source.read_u8()? {
             ADD => Ok(Self::Add),
@@ -344,6 +415,12 @@ impl Deserializable for ConstantOp {
             MUL => Ok(Self::Mul),
             DIV => Ok(Self::Div),
             INT_DIV => Ok(Self::IntDiv),
+            MOD => Ok(Self::Mod),
+            SHL => Ok(Self::Shl),
+            SHR => Ok(Self::Shr),
+            BIT_AND => Ok(Self::BitAnd),
+            BIT_OR => Ok(Self::BitOr),
+            BIT_XOR => Ok(Self::BitXor),
             invalid => Err(DeserializationError::InvalidValue(format!(
                 "unexpected ConstantOp tag: '{invalid}'"
             ))),
@@ -367,6 +444,12 @@ impl proptest::arbitrary::Arbitrary for ConstantOp {
             Just(Self::Mul),
             Just(Self::Div),
             Just(Self::IntDiv),
+            Just(Self::Mod),
+            Just(Self::Shl),
+            Just(Self::Shr),
+            Just(Self::BitAnd),
+            Just(Self::BitOr),
+            Just(Self::BitXor),
         ]
         .boxed()
     }
@@ -0,0 +1,42 @@
+use alloc::{format, string::String, vec::Vec};
+
+use miden_debug_types::{SourceSpan, Span, Spanned};
+
+use super::DocString;
+use crate::{ast::Ident, parser::WordValue};
+
+// Read-only data placed in the Advice Map, which the host populates before the VM starts.
+// ============================================================
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct DataSegment {
+    /// The source span of the definition.
+    pub span: SourceSpan,
+    /// The documentation string attached to this definition.
+    pub docs: Option<DocString>,
+    /// The name of the data segment.
+    pub name: Ident,
+    /// The words of the data segment, in order.
+    pub words: Vec<Span<WordValue>>,
+}
+
+impl DataSegment {
+    pub fn new(span: SourceSpan, name: Ident, words: Vec<Span<WordValue>>) -> Self {
+        Self { span, docs: None, name, words }
+    }
+
+    /// Adds documentation to this data segment declaration.
+    pub fn with_docs(mut self, docs: Option<Span<String>>) -> Self {
+        self.docs = docs.map(DocString::new);
+        self
+    }
+
+    /// Returns the name of the constant holding the number of words in this data segment, i.e.
+    /// `<NAME>_LEN`.
+    pub fn len_constant_name(&self) -> Ident {
+        Ident::from_raw_parts(Span::new(
+            self.name.span(),
+            format!("{}_LEN", self.name.as_str()).into(),
+        ))
+    }
+}
@@ -3,8 +3,8 @@ use alloc::{string::String, sync::Arc};
 use miden_debug_types::{SourceSpan, Span, Spanned};
 
 use super::{
-    AdviceMapEntry, Block, Constant, EnumType, Ident, ImportDecl, Item, Path, Procedure,
-    SubmoduleDecl, TypeAlias, TypeDecl,
+    AdviceMapEntry, Block, Constant, DataSegment, EnumType, Ident, ImportDecl, Item, Path,
+    Procedure, SubmoduleDecl, TypeAlias, TypeDecl,
 };
 
 /// This type represents the top-level forms of a Miden Assembly module
@@ -38,6 +38,8 @@ pub enum Form {
     Import(ImportDecl),
     /// An entry into the Advice Map
     AdviceMapEntry(AdviceMapEntry),
+    /// A read-only data segment, placed into the Advice Map
+    DataSegment(DataSegment),
 }
 
 impl From<Span<String>> for Form {
@@ -103,7 +105,8 @@ impl Spanned for Form {
             Self::Type(spanned) => spanned.span(),
             Self::Enum(spanned) => spanned.span(),
             Self::Constant(Constant { span, .. })
-            | Self::AdviceMapEntry(AdviceMapEntry { span, .. }) => *span,
+            | Self::AdviceMapEntry(AdviceMapEntry { span, .. })
+            | Self::DataSegment(DataSegment { span, .. }) => *span,
             Self::Begin(spanned) => spanned.span(),
             Self::Procedure(spanned) => spanned.span(),
             Self::Import(spanned) => spanned.span(),
@@ -11,6 +11,8 @@ pub enum ImportKind {
     Module,
     /// An item import such as `use {foo, bar as baz} from some::module`.
     Item,
+    /// A wildcard import such as `use some::module::*`.
+    Glob,
 }
 
 /// A source-level import declaration.
@@ -18,6 +20,7 @@ pub enum ImportKind {
 pub enum ImportDecl {
     Module(ModuleImport),
     Items(ItemImportGroup),
+    Glob(GlobImport),
 }
 
 /// A concrete import recorded in a semantically-analyzed module.
@@ -47,6 +50,18 @@ pub struct ItemImportGroup {
     specs: Vec<ImportSpec>,
 }
 
+/// Imports all items exported by a foreign module into the local scope.
+///
+/// The set of imported items is only known once the foreign module is available to the linker,
+/// which expands a wildcard import into one [ItemImport] per item that is not otherwise declared by
+/// the importing module.
+#[derive(Debug, Clone)]
+pub struct GlobImport {
+    span: SourceSpan,
+    visibility: Visibility,
+    module_path: Span<Arc<Path>>,
+}
+
 /// A single item import within an item import group.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ImportSpec {
@@ -71,6 +86,7 @@ impl ImportDecl {
         match self {
             Self::Module(_) => ImportKind::Module,
             Self::Items(_) => ImportKind::Item,
+            Self::Glob(_) => ImportKind::Glob,
         }
     }
 
@@ -78,6 +94,7 @@ impl ImportDecl {
         match self {
             Self::Module(import) => import.visibility(),
             Self::Items(import) => import.visibility(),
+            Self::Glob(import) => import.visibility(),
         }
     }
 
@@ -85,6 +102,7 @@ impl ImportDecl {
         match self {
             Self::Module(import) => import.module_path(),
             Self::Items(import) => import.module_path(),
+            Self::Glob(import) => import.module_path(),
         }
     }
 }
@@ -196,6 +214,33 @@ impl ItemImportGroup {
     }
 }
 
+impl GlobImport {
+    pub fn new(span: SourceSpan, visibility: Visibility, module_path: Span<Arc<Path>>) -> Self {
+        Self { span, visibility, module_path }
+    }
+
+    pub fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    pub fn module_path(&self) -> Span<&Path> {
+        self.module_path.as_deref()
+    }
+
+    pub fn set_module_path(&mut self, path: Span<Arc<Path>>) {
+        self.module_path = path;
+    }
+
+    /// Returns the [ItemImport] of the item `name` of the imported module, as imported by this
+    /// wildcard import.
+    ///
+    /// The returned import is attributed to the source span of this wildcard import.
+    pub fn item_import(&self, name: Arc<str>) -> ItemImport {
+        let name = Ident::from_raw_parts(Span::new(self.span, name));
+        ItemImport::new(self.span, self.visibility, self.module_path.clone(), name.clone(), name)
+    }
+}
+
 impl ImportSpec {
     pub fn new(source_name: Ident, local_name: Ident) -> Self {
         Self { source_name, local_name }
@@ -272,10 +317,17 @@ impl Spanned for ImportDecl {
         match self {
             Self::Module(import) => import.span(),
             Self::Items(import) => import.span(),
+            Self::Glob(import) => import.span(),
         }
     }
 }
 
+impl Spanned for GlobImport {
+    fn span(&self) -> SourceSpan {
+        self.span
+    }
+}
+
 impl Spanned for ModuleImport {
     fn span(&self) -> SourceSpan {
         self.span
@@ -320,6 +372,14 @@ impl PartialEq for ItemImportGroup {
     }
 }
 
+impl Eq for GlobImport {}
+
+impl PartialEq for GlobImport {
+    fn eq(&self, other: &Self) -> bool {
+        self.visibility == other.visibility && self.module_path.inner() == other.module_path.inner()
+    }
+}
+
 impl Eq for ItemImport {}
 
 impl PartialEq for ItemImport {
@@ -336,6 +396,7 @@ impl crate::prettier::PrettyPrint for ImportDecl {
         match self {
             Self::Module(import) => import.render(),
             Self::Items(import) => import.render(),
+            Self::Glob(import) => import.render(),
         }
     }
 }
@@ -382,6 +443,18 @@ impl crate::prettier::PrettyPrint for ItemImportGroup {
     }
 }
 
+impl crate::prettier::PrettyPrint for GlobImport {
+    fn render(&self) -> crate::prettier::Document {
+        use crate::prettier::*;
+
+        let mut doc = Document::Empty;
+        if self.visibility.is_public() {
+            doc += display(self.visibility) + const_text(" ");
+        }
+        doc + const_text("use ") + display(self.module_path.inner()) + const_text("::*")
+    }
+}
+
 impl crate::prettier::PrettyPrint for ImportSpec {
     fn render(&self) -> crate::prettier::Document {
         use crate::prettier::*;
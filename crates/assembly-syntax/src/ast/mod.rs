@@ -4,6 +4,7 @@ mod advice_map_entry;
 mod attribute;
 mod block;
 pub mod constants;
+mod data_segment;
 mod docstring;
 mod form;
 pub(crate) mod ident;
@@ -30,12 +31,14 @@ pub use self::{
     },
     block::Block,
     constants::{Constant, ConstantExpr, ConstantOp, ConstantValue, HashKind},
+    data_segment::DataSegment,
     docstring::DocString,
     form::Form,
     ident::{CaseKindError, Ident, IdentError},
     immediate::{ErrorMsg, ImmFelt, ImmU8, ImmU16, ImmU32, Immediate},
     import::{
-        Import, ImportDecl, ImportKind, ImportSpec, ItemImport, ItemImportGroup, ModuleImport,
+        GlobImport, Import, ImportDecl, ImportKind, ImportSpec, ItemImport, ItemImportGroup,
+        ModuleImport,
     },
     instruction::{Instruction, SystemEventNode},
     invocation_target::{InvocationTarget, Invoke, InvokeKind},
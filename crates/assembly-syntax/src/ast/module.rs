@@ -18,9 +18,9 @@ use proptest::prelude::*;
 use smallvec::SmallVec;
 
 use super::{
-    Constant, Declaration, DocString, EnumType, FunctionType, Import, Item, ItemIndex, Path,
-    Procedure, ProcedureName, QualifiedProcedureName, SubmoduleDecl, TypeAlias, TypeDecl, Variant,
-    Visibility,
+    Constant, Declaration, DocString, EnumType, FunctionType, GlobImport, Import, Item, ItemIndex,
+    Path, Procedure, ProcedureName, QualifiedProcedureName, SubmoduleDecl, TypeAlias, TypeDecl,
+    Variant, Visibility,
 };
 use crate::{
     PathBuf,
@@ -38,6 +38,7 @@ use crate::{
 /// what operations can be performed in the body of procedures defined in the module. See the
 /// documentation for each variant for a summary of these differences.
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
     all(feature = "arbitrary", test),
     miden_test_serde_macros::serde_test(binary_serde(true), serde_test(false))
@@ -176,6 +177,9 @@ pub struct Module {
     pub(crate) submodules: Vec<SubmoduleDecl>,
     /// The imports declared by this module.
     pub(crate) imports: Vec<Import>,
+    /// The wildcard imports declared by this module, i.e. `use some::module::*`, which are
+    /// expanded into [Import]s by the linker.
+    pub(crate) glob_imports: Vec<GlobImport>,
     /// The items (defined or re-exported) in the module body.
     pub(crate) items: Vec<Item>,
     /// Maps export name to its position in `items`, for O(log n) conflict checks.
@@ -214,6 +218,7 @@ impl Module {
             extern_packages: Default::default(),
             submodules: Default::default(),
             imports: Default::default(),
+            glob_imports: Default::default(),
             items: Default::default(),
             name_map: BTreeMap::new(),
             name_map_dirty: false,
@@ -515,6 +520,18 @@ impl Module {
         self.imports.push(import);
         Ok(())
     }
+
+    /// Defines a wildcard import.
+    ///
+    /// Unlike other imports, wildcard imports cannot conflict with other declarations, as they
+    /// only import the items whose names are not otherwise declared by this module.
+    pub fn define_glob_import(&mut self, import: GlobImport) -> Result<(), SemanticAnalysisError> {
+        if self.is_kernel() && import.visibility().is_public() {
+            return Err(SemanticAnalysisError::ReexportFromKernel { span: import.span() });
+        }
+        self.glob_imports.push(import);
+        Ok(())
+    }
 }
 
 /// Parsing
@@ -701,6 +718,21 @@ impl Module {
         core::mem::take(&mut self.imports)
     }
 
+    /// Get an iterator over the wildcard imports in this module.
+    pub fn glob_imports(&self) -> impl Iterator<Item = &GlobImport> + '_ {
+        self.glob_imports.iter()
+    }
+
+    /// Returns true if this module declares any wildcard imports.
+    pub fn has_glob_imports(&self) -> bool {
+        !self.glob_imports.is_empty()
+    }
+
+    /// Takes all wildcard imports from this module.
+    pub fn take_glob_imports(&mut self) -> Vec<GlobImport> {
+        core::mem::take(&mut self.glob_imports)
+    }
+
     /// Get a reference to the set of package identifiers that this module declares a dependency on
     ///
     /// This is only reflects explicit `extern package` declarations of the root project module,
@@ -806,6 +838,7 @@ impl PartialEq for Module {
             && self.path == other.path
             && self.docs == other.docs
             && self.imports == other.imports
+            && self.glob_imports == other.glob_imports
             && self.items == other.items
     }
 }
@@ -821,6 +854,7 @@ impl fmt::Debug for Module {
             .field("extern_packages", &self.extern_packages)
             .field("submodules", &self.submodules)
             .field("imports", &self.imports)
+            .field("glob_imports", &self.glob_imports)
             .field("items", &self.items)
             .finish()
     }
@@ -893,7 +927,14 @@ impl crate::prettier::PrettyPrint for Module {
             doc += import.render();
         }
 
-        if !self.imports.is_empty() {
+        for (import_index, import) in self.glob_imports.iter().enumerate() {
+            if import_index > 0 || !self.imports.is_empty() {
+                doc += nl();
+            }
+            doc += import.render();
+        }
+
+        if !self.imports.is_empty() || !self.glob_imports.is_empty() {
             doc += nl();
         }
 
@@ -38,10 +38,14 @@ pub enum Op {
     /// NOTE: The iteration count must be known at compile-time, so this is _not_ used for general
     /// `for`-style loops where the iteration count is dynamic.
     /// Also, it may be either a literal value or constant name (e.g. `add.1` or `add.CONST`)
+    ///
+    /// `no_unroll` is set when the loop is annotated with `@no_unroll`, in which case the assembler
+    /// does not unroll it.
     Repeat {
         span: SourceSpan,
         count: Immediate<u32>,
         body: Block,
+        no_unroll: bool,
     } = 2,
     /// A primitive operation, e.g. `add`
     Inst(Span<Instruction>) = 3,
@@ -59,8 +63,13 @@ impl crate::prettier::PrettyPrint for Op {
             Self::DoWhile { body, condition, .. } => {
                 text("do") + body.render() + text("while") + condition.render() + text("end")
             },
-            Self::Repeat { count, body, .. } => {
-                display(format!("repeat.{count}")) + body.render() + text("end")
+            Self::Repeat { count, body, no_unroll, .. } => {
+                let attrs = if *no_unroll {
+                    const_text("@no_unroll") + nl()
+                } else {
+                    Document::Empty
+                };
+                attrs + display(format!("repeat.{count}")) + body.render() + text("end")
             },
             Self::Inst(inst) => inst.render(),
         }
@@ -79,9 +88,12 @@ impl fmt::Debug for Op {
                 .field("body", body)
                 .field("condition", condition)
                 .finish(),
-            Self::Repeat { count, body, .. } => {
-                f.debug_struct("Repeat").field("count", count).field("body", body).finish()
-            },
+            Self::Repeat { count, body, no_unroll, .. } => f
+                .debug_struct("Repeat")
+                .field("count", count)
+                .field("body", body)
+                .field("no_unroll", no_unroll)
+                .finish(),
             Self::Inst(inst) => fmt::Debug::fmt(&**inst, f),
         }
     }
@@ -102,9 +114,19 @@ impl PartialEq for Op {
                 Self::DoWhile { body: rbody, condition: rcond, .. },
             ) => lbody == rbody && lcond == rcond,
             (
-                Self::Repeat { count: lcount, body: lbody, .. },
-                Self::Repeat { count: rcount, body: rbody, .. },
-            ) => lcount == rcount && lbody == rbody,
+                Self::Repeat {
+                    count: lcount,
+                    body: lbody,
+                    no_unroll: lno_unroll,
+                    ..
+                },
+                Self::Repeat {
+                    count: rcount,
+                    body: rbody,
+                    no_unroll: rno_unroll,
+                    ..
+                },
+            ) => lcount == rcount && lbody == rbody && lno_unroll == rno_unroll,
             (Self::Inst(l), Self::Inst(r)) => l == r,
             _ => false,
         }
@@ -0,0 +1,128 @@
+use alloc::{string::ToString, vec::Vec};
+
+use miden_core::events::EventName;
+use miden_debug_types::{SourceSpan, Spanned};
+
+use crate::{
+    ast::{Attribute, AttributeSet, MetaExpr},
+    parser::{IntValue, ParsingError},
+};
+
+/// The machine-readable interface of a procedure, declared by its `@abi` and `@emits` attributes.
+///
+/// For example, the following declares a procedure which consumes 2 words from the operand stack
+/// and 1 word from the advice stack, leaves 1 word on the operand stack, and emits the
+/// `miden::account::updated` event:
+///
+/// ```masm,ignore
+/// @abi(inputs = 2, outputs = 1, advice = 1)
+/// @emits("miden::account::updated")
+/// pub proc update
+///     ...
+/// end
+/// ```
+///
+/// All counts are in words, and default to zero when omitted. The attributes are validated when
+/// the procedure is parsed, and are carried along with the procedure into the exports of the
+/// assembled package, so that tooling can generate bindings without parsing doc comments.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ProcedureAbi {
+    /// The number of words consumed from the operand stack.
+    pub inputs: u16,
+    /// The number of words left on the operand stack.
+    pub outputs: u16,
+    /// The number of words consumed from the advice stack.
+    pub advice: u16,
+    /// The events the procedure may emit.
+    pub events: Vec<EventName>,
+}
+
+impl ProcedureAbi {
+    /// The name of the attribute declaring the stack and advice requirements of a procedure.
+    pub const ABI_ATTRIBUTE: &'static str = "abi";
+
+    /// The name of the attribute declaring the events a procedure may emit.
+    pub const EMITS_ATTRIBUTE: &'static str = "emits";
+
+    /// Returns the interface declared by `attributes`, if it has an `@abi` or `@emits` attribute.
+    ///
+    /// Returns an error if either attribute is malformed.
+    pub fn from_attributes(attributes: &AttributeSet) -> Result<Option<Self>, ParsingError> {
+        let abi = attributes.get(Self::ABI_ATTRIBUTE);
+        let emits = attributes.get(Self::EMITS_ATTRIBUTE);
+        if abi.is_none() && emits.is_none() {
+            return Ok(None);
+        }
+
+        let mut interface = Self::default();
+        match abi {
+            None => (),
+            Some(Attribute::KeyValue(kv)) => {
+                for (key, value) in kv.iter() {
+                    let count = match key.as_str() {
+                        "inputs" => &mut interface.inputs,
+                        "outputs" => &mut interface.outputs,
+                        "advice" => &mut interface.advice,
+                        _ => {
+                            return Err(invalid_abi_attr(
+                                key.span(),
+                                "expected one of `inputs`, `outputs`, or `advice`",
+                            ));
+                        },
+                    };
+                    *count = match value {
+                        MetaExpr::Int(value) => match value.inner() {
+                            IntValue::U8(n) => u16::from(*n),
+                            IntValue::U16(n) => *n,
+                            _ => {
+                                return Err(invalid_abi_attr(
+                                    value.span(),
+                                    "word count exceeds the maximum of 65535",
+                                ));
+                            },
+                        },
+                        other => {
+                            return Err(invalid_abi_attr(other.span(), "expected a word count"));
+                        },
+                    };
+                }
+            },
+            Some(other) => {
+                return Err(invalid_abi_attr(
+                    other.span(),
+                    "expected key-value pairs, e.g. `@abi(inputs = 1, outputs = 1)`",
+                ));
+            },
+        }
+
+        match emits {
+            None => (),
+            Some(Attribute::List(list)) => {
+                for item in list.as_slice() {
+                    match item {
+                        MetaExpr::Ident(name) | MetaExpr::String(name) => {
+                            interface
+                                .events
+                                .push(EventName::from_string(name.as_str().to_string()));
+                        },
+                        other => {
+                            return Err(invalid_abi_attr(other.span(), "expected an event name"));
+                        },
+                    }
+                }
+            },
+            Some(other) => {
+                return Err(invalid_abi_attr(
+                    other.span(),
+                    "expected a list of event names, e.g. `@emits(\"miden::event\")`",
+                ));
+            },
+        }
+
+        Ok(Some(interface))
+    }
+}
+
+fn invalid_abi_attr(span: SourceSpan, message: &str) -> ParsingError {
+    ParsingError::InvalidAbiAttr { span, message: message.to_string() }
+}
@@ -1,9 +1,11 @@
+mod abi;
 mod name;
 #[expect(clippy::module_inception)]
 mod procedure;
 
 pub(crate) use self::procedure::InvokedIter;
 pub use self::{
+    abi::ProcedureAbi,
     name::{ProcedureName, QualifiedProcedureName},
     procedure::Procedure,
 };
@@ -1762,3 +1762,309 @@ end
     assert_eq!(context.parse_forms(source)?, forms);
     Ok(())
 }
+
+// MACROS
+// ================================================================================================
+
+#[test]
+fn test_macro_expansion() -> Result<(), Report> {
+    let context = SyntaxTestContext::new();
+    let source = source_file!(
+        &context,
+        r#"
+#! Pushes `a`, then `b`.
+macro.push_pair(a, b)
+    push.a
+    macro_exec.push_one(b)
+end
+
+macro.push_one(a)
+    push.a
+end
+
+macro.add_n_times(n)
+    repeat.n
+        add
+    end
+end
+
+proc foo
+    macro_exec.push_pair(1, N)
+    macro_exec.push_one([0, 1, 2, 3])
+    macro_exec.add_n_times(2 + 1)
+end
+"#
+    );
+
+    let forms = module!(proc!(
+        foo,
+        0,
+        block!(
+            inst!(Push(Immediate::Value(Span::unknown(1u8.into())))),
+            inst!(Push(Immediate::Constant(id!(N)))),
+            inst!(Push(Immediate::Value(Span::unknown(
+                WordValue([
+                    Felt::new_unchecked(0),
+                    Felt::new_unchecked(1),
+                    Felt::new_unchecked(2),
+                    Felt::new_unchecked(3)
+                ])
+                .into()
+            )))),
+            Op::Repeat {
+                span: Default::default(),
+                count: Immediate::Value(Span::unknown(3)),
+                body: block!(inst!(Add)),
+                no_unroll: false,
+            }
+        )
+    ));
+    assert_eq!(context.parse_forms(source)?, forms);
+    Ok(())
+}
+
+#[test]
+fn test_macro_expansion_is_hygienic() {
+    let context = SyntaxTestContext::default();
+    let source = source_file!(
+        &context,
+        "\
+macro.outer(a)
+    macro_exec.inner
+end
+
+macro.inner
+    push.a
+end
+
+begin
+    macro_exec.outer(1)
+end"
+    );
+    assert_parse_diagnostic!(source, "only uppercase characters or underscores are allowed");
+}
+
+#[test]
+fn test_macro_expansion_errors() {
+    let context = SyntaxTestContext::default();
+
+    let source = source_file!(&context, "begin macro_exec.missing(1) end");
+    assert_parse_diagnostic!(source, "undefined macro 'missing'");
+
+    let source = source_file!(
+        &context,
+        "\
+macro.pair(a, b)
+    push.a push.b
+end
+
+begin
+    macro_exec.pair(1)
+end"
+    );
+    assert_parse_diagnostic!(source, "invalid macro expansion: expected 2 argument(s), but got 1");
+
+    let source = source_file!(
+        &context,
+        "\
+macro.ping
+    macro_exec.pong
+end
+
+macro.pong
+    macro_exec.ping
+end
+
+begin
+    macro_exec.ping
+end"
+    );
+    assert_parse_diagnostic!(source, "invalid macro expansion: macro 'ping' expands to itself");
+
+    let source = source_file!(
+        &context,
+        "\
+macro.one
+    push.1
+end
+
+macro.one
+    push.2
+end
+
+begin
+    macro_exec.one
+end"
+    );
+    assert_parse_diagnostic!(source, "duplicate macro definition");
+}
+
+// CONDITIONAL COMPILATION
+// ================================================================================================
+
+#[test]
+fn test_cfg_attributes() -> Result<(), Report> {
+    use crate::ParseOptions;
+
+    let context = SyntaxTestContext::new();
+    let source = r#"
+@cfg(feature = "debug")
+proc log
+    push.1
+    drop
+end
+
+#! Logs nothing in release builds.
+@cfg(not(feature = "debug"))
+proc log
+    nop
+end
+
+@cfg(all(feature = "debug", any(feature = "a", feature = "b")))
+@locals(1)
+proc extra
+    nop
+end
+
+@cfg(feature = "debug")
+begin
+    exec.log
+end
+
+@cfg(not(feature = "debug"))
+begin
+    exec.log
+    exec.log
+end
+"#;
+    let parse = |features: &[&str]| {
+        let options = ParseOptions::default().with_features(features.iter().copied());
+        let mut parser = Module::parser(Some(ModuleKind::Executable));
+        parser.set_options(&options);
+        parser.parse_str(Some(Path::EXEC), source, context.source_manager())
+    };
+    let procedure = |module: &Module, name: &str| {
+        module.procedures().find(|procedure| procedure.name().as_str() == name).cloned()
+    };
+    let entrypoint =
+        |module: &Module| module.procedures().find(|procedure| procedure.is_entrypoint()).cloned();
+
+    let module = parse(&[])?;
+    let log = procedure(&module, "log").expect("missing `log`");
+    assert_eq!(log.body(), &block!(inst!(Nop)));
+    assert_eq!(log.docs().map(Span::into_inner), Some("Logs nothing in release builds.\n"));
+    assert!(procedure(&module, "extra").is_none());
+    assert_eq!(entrypoint(&module).expect("missing entrypoint").body().len(), 2);
+
+    let module = parse(&["debug"])?;
+    let log = procedure(&module, "log").expect("missing `log`");
+    assert_eq!(
+        log.body(),
+        &block!(inst!(Push(Immediate::Value(Span::unknown(1u8.into())))), inst!(Drop))
+    );
+    assert!(log.docs().is_none());
+    assert!(procedure(&module, "extra").is_none());
+    assert_eq!(entrypoint(&module).expect("missing entrypoint").body().len(), 1);
+
+    let module = parse(&["debug", "b"])?;
+    let extra = procedure(&module, "extra").expect("missing `extra`");
+    assert_eq!(extra.num_locals(), 1);
+    assert!(extra.attributes().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_cfg_attribute_errors() {
+    let context = SyntaxTestContext::default();
+
+    let source = source_file!(&context, "@cfg(debug)\nproc foo\n    nop\nend");
+    assert_parse_diagnostic!(source, "expected a `cfg` predicate");
+
+    let source = source_file!(&context, "@cfg(feature = debug)\nproc foo\n    nop\nend");
+    assert_parse_diagnostic!(source, "expected a quoted feature name");
+
+    let source = source_file!(&context, "@inline\nbegin\n    nop\nend");
+    assert_parse_diagnostic!(source, "only `@cfg(...)` attributes are allowed on `begin` blocks");
+}
+
+#[test]
+fn doc_model_extracts_module_procedure_and_constant_docs() -> Result<(), Report> {
+    use crate::docs::DocModel;
+
+    let context = SyntaxTestContext::new();
+    let math = context.parse_module(
+        r#"#! Field "math" helpers.
+
+namespace lib::math
+
+#! The answer.
+pub const ANSWER = 6 * 7
+
+const SECRET = 1
+
+#! Adds two felts.
+pub proc add(a: felt, b: felt) -> felt
+    add
+end
+
+proc helper
+    nop
+end
+"#,
+    )?;
+    let util = context.parse_module("namespace lib::util\n\npub proc noop\n    nop\nend\n")?;
+
+    let model = DocModel::from_modules([&*util, &*math]);
+    assert_eq!(model.modules.len(), 2);
+    let module = model.get_module(Path::new("::lib::math")).expect("missing module");
+    assert_eq!(module.docs.as_deref(), Some("Field \"math\" helpers.\n"));
+    assert_eq!(module.procedures.len(), 2);
+    assert_eq!(module.procedures[0].docs.as_deref(), Some("Adds two felts.\n"));
+    assert!(module.procedures[0].signature.is_some());
+    assert_eq!(module.constants[0].value, "42");
+
+    let public = model.public_only();
+    assert_eq!(public.modules[0].procedures.len(), 1);
+    assert_eq!(public.modules[0].constants.len(), 1);
+
+    #[cfg(feature = "serde")]
+    assert_eq!(
+        serde_json::to_value(&public).unwrap(),
+        serde_json::json!({
+            "modules": [
+                {
+                    "path": "::lib::math",
+                    "kind": "Library",
+                    "docs": "Field \"math\" helpers.\n",
+                    "procedures": [{
+                        "name": "add",
+                        "visibility": "Public",
+                        "signature": "(felt, felt) -> felt",
+                        "docs": "Adds two felts.\n",
+                    }],
+                    "constants": [{
+                        "name": "ANSWER",
+                        "visibility": "Public",
+                        "value": "42",
+                        "docs": "The answer.\n",
+                    }],
+                },
+                {
+                    "path": "::lib::util",
+                    "kind": "Library",
+                    "docs": null,
+                    "procedures": [{
+                        "name": "noop",
+                        "visibility": "Public",
+                        "signature": null,
+                        "docs": null,
+                    }],
+                    "constants": [],
+                },
+            ]
+        })
+    );
+
+    Ok(())
+}
@@ -326,6 +326,12 @@ where
             visitor.visit_constant_expr(lhs)?;
             visitor.visit_constant_expr(rhs)
         },
+        ConstantExpr::MakeWord { elements, .. } => {
+            for element in elements.iter() {
+                visitor.visit_constant_expr(element)?;
+            }
+            ControlFlow::Continue(())
+        },
         ConstantExpr::Hash(..)
         | ConstantExpr::Int(_)
         | ConstantExpr::String(_)
@@ -886,6 +892,12 @@ where
             visitor.visit_mut_constant_expr(lhs)?;
             visitor.visit_mut_constant_expr(rhs)
         },
+        ConstantExpr::MakeWord { elements, .. } => {
+            for element in elements.iter_mut() {
+                visitor.visit_mut_constant_expr(element)?;
+            }
+            ControlFlow::Continue(())
+        },
         ConstantExpr::Hash(..)
         | ConstantExpr::Int(_)
         | ConstantExpr::String(_)
@@ -0,0 +1,165 @@
+//! Extraction of the documentation of Miden Assembly modules into a structured [`DocModel`].
+//!
+//! The model contains the doc comments (`#!`) of modules, procedures and constants, along with the
+//! information needed to present them, e.g. visibility and signatures, so that documentation for a
+//! library can be generated without parsing its sources again. With the `serde` feature enabled,
+//! the model can be serialized, e.g. to JSON.
+
+use alloc::{
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Path,
+    ast::{Constant, Module, ModuleKind, Procedure, Visibility},
+    prettier::PrettyPrint,
+};
+
+// DOC MODEL
+// ================================================================================================
+
+/// The documentation of a set of modules, e.g. those of a library.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DocModel {
+    /// The documentation of each module, in ascending order of path.
+    pub modules: Vec<ModuleDoc>,
+}
+
+impl DocModel {
+    /// Extracts the documentation of `modules`.
+    pub fn from_modules<'a>(modules: impl IntoIterator<Item = &'a Module>) -> Self {
+        let mut modules: Vec<ModuleDoc> = modules.into_iter().map(ModuleDoc::from_module).collect();
+        modules.sort_by(|a, b| a.path.cmp(&b.path));
+        Self { modules }
+    }
+
+    /// Returns the documentation of the module at `path`, if present.
+    pub fn get_module(&self, path: &Path) -> Option<&ModuleDoc> {
+        self.modules.iter().find(|module| module.path.as_ref() == path)
+    }
+
+    /// Returns a copy of this model without its private procedures and constants.
+    pub fn public_only(&self) -> Self {
+        let modules = self
+            .modules
+            .iter()
+            .map(|module| ModuleDoc {
+                procedures: module
+                    .procedures
+                    .iter()
+                    .filter(|procedure| procedure.visibility.is_public())
+                    .cloned()
+                    .collect(),
+                constants: module
+                    .constants
+                    .iter()
+                    .filter(|constant| constant.visibility.is_public())
+                    .cloned()
+                    .collect(),
+                ..module.clone()
+            })
+            .collect();
+        Self { modules }
+    }
+}
+
+// MODULE DOC
+// ================================================================================================
+
+/// The documentation of a module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ModuleDoc {
+    /// The fully-qualified path of the module.
+    #[cfg_attr(feature = "serde", serde(with = "crate::ast::path"))]
+    pub path: Arc<Path>,
+    /// The kind of module.
+    pub kind: ModuleKind,
+    /// The module documentation, if present.
+    pub docs: Option<String>,
+    /// The documentation of the procedures of the module, in the order they are defined.
+    pub procedures: Vec<ProcedureDoc>,
+    /// The documentation of the constants of the module, in the order they are defined.
+    pub constants: Vec<ConstantDoc>,
+}
+
+impl ModuleDoc {
+    /// Extracts the documentation of `module`.
+    pub fn from_module(module: &Module) -> Self {
+        Self {
+            path: Arc::from(module.path()),
+            kind: module.kind(),
+            docs: module.docs().map(|docs| docs.into_inner().to_string()),
+            procedures: module
+                .procedures()
+                .filter(|procedure| !procedure.is_entrypoint())
+                .map(ProcedureDoc::from_procedure)
+                .collect(),
+            constants: module.constants().map(ConstantDoc::from_constant).collect(),
+        }
+    }
+}
+
+// PROCEDURE DOC
+// ================================================================================================
+
+/// The documentation of a procedure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ProcedureDoc {
+    /// The name of the procedure.
+    pub name: String,
+    /// The visibility of the procedure.
+    pub visibility: Visibility,
+    /// The type signature of the procedure, as written in source, if known.
+    pub signature: Option<String>,
+    /// The procedure documentation, if present.
+    pub docs: Option<String>,
+}
+
+impl ProcedureDoc {
+    /// Extracts the documentation of `procedure`.
+    pub fn from_procedure(procedure: &Procedure) -> Self {
+        Self {
+            name: procedure.name().to_string(),
+            visibility: procedure.visibility(),
+            signature: procedure.signature().map(PrettyPrint::to_pretty_string),
+            docs: procedure.docs().map(|docs| docs.into_inner().to_string()),
+        }
+    }
+}
+
+// CONSTANT DOC
+// ================================================================================================
+
+/// The documentation of a constant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConstantDoc {
+    /// The name of the constant.
+    pub name: String,
+    /// The visibility of the constant.
+    pub visibility: Visibility,
+    /// The value of the constant, as evaluated during semantic analysis.
+    pub value: String,
+    /// The constant documentation, if present.
+    pub docs: Option<String>,
+}
+
+impl ConstantDoc {
+    /// Extracts the documentation of `constant`.
+    pub fn from_constant(constant: &Constant) -> Self {
+        Self {
+            name: constant.name().to_string(),
+            visibility: constant.visibility,
+            value: constant.value.to_pretty_string(),
+            docs: constant.docs().map(|docs| docs.into_inner().to_string()),
+        }
+    }
+}
@@ -19,6 +19,7 @@ pub use semver::{self, Error as VersionError, Version};
 #[cfg(feature = "arbitrary")]
 pub mod arbitrary;
 pub mod ast;
+pub mod docs;
 pub mod module;
 mod parse;
 pub mod parser;
@@ -31,8 +32,8 @@ pub use self::{
     parser::{ModuleParser, ParsingError},
 };
 pub use self::{
-    parse::Parse,
-    sema::{ExportedTypeUse, SemanticAnalysisError},
+    parse::{Parse, ParseOptions},
+    sema::{ExportedTypeUse, Lint, LintLevel, SemanticAnalysisError},
 };
 
 /// Maximum allowed iteration count for `repeat.<count>` blocks.
@@ -1,8 +1,64 @@
-use alloc::{borrow::Cow, boxed::Box, string::String, sync::Arc};
+use alloc::{borrow::Cow, boxed::Box, collections::BTreeSet, string::String, sync::Arc};
 
 use miden_debug_types::{SourceFile, SourceManager};
 
-use crate::{ast::Module, diagnostics::Report};
+use crate::{
+    ast::Module,
+    diagnostics::Report,
+    sema::{Lint, LintLevel, Lints},
+};
+
+// PARSE OPTIONS
+// ================================================================================================
+
+/// Options which control how Miden Assembly source code is parsed into a [Module].
+#[derive(Debug, Default, Clone)]
+pub struct ParseOptions {
+    /// Whether to treat warnings as errors.
+    pub warnings_as_errors: bool,
+    /// The set of features enabled for conditional compilation.
+    ///
+    /// Items annotated with `@cfg(...)` are only included in the parsed [Module] when their
+    /// predicate holds with respect to this set.
+    pub features: BTreeSet<Arc<str>>,
+    /// The levels at which lints are reported.
+    pub lints: Lints,
+}
+
+impl ParseOptions {
+    /// Sets whether warnings should be treated as errors.
+    pub fn with_warnings_as_errors(mut self, yes: bool) -> Self {
+        self.warnings_as_errors = yes;
+        self
+    }
+
+    /// Enables the feature `name` for conditional compilation.
+    pub fn with_feature(mut self, name: impl Into<Arc<str>>) -> Self {
+        self.features.insert(name.into());
+        self
+    }
+
+    /// Enables all of the given features for conditional compilation.
+    pub fn with_features<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Arc<str>>,
+    {
+        self.features.extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets the level at which `lint` is reported.
+    pub fn with_lint_level(mut self, lint: Lint, level: LintLevel) -> Self {
+        self.lints.set_level(lint, level);
+        self
+    }
+
+    /// Returns true if the feature `name` is enabled.
+    pub fn is_feature_enabled(&self, name: &str) -> bool {
+        self.features.contains(name)
+    }
+}
 
 // PARSE TRAIT
 // ================================================================================================
@@ -21,6 +77,18 @@ pub trait Parse: Sized {
         warnings_as_errors: bool,
         source_manager: Arc<dyn SourceManager>,
     ) -> Result<Box<Module>, Report>;
+
+    /// Parse (or convert) `self` into an executable [Module], using the given [ParseOptions].
+    ///
+    /// The default implementation only honors [ParseOptions::warnings_as_errors]; implementations
+    /// which parse source code should override it to respect the remaining options.
+    fn parse_with_options(
+        self,
+        options: &ParseOptions,
+        source_manager: Arc<dyn SourceManager>,
+    ) -> Result<Box<Module>, Report> {
+        self.parse(options.warnings_as_errors, source_manager)
+    }
 }
 
 // PARSE IMPLEMENTATIONS FOR MODULES
@@ -66,9 +134,18 @@ impl Parse for Arc<SourceFile> {
         self,
         warnings_as_errors: bool,
         source_manager: Arc<dyn SourceManager>,
+    ) -> Result<Box<Module>, Report> {
+        let options = ParseOptions::default().with_warnings_as_errors(warnings_as_errors);
+        self.parse_with_options(&options, source_manager)
+    }
+
+    fn parse_with_options(
+        self,
+        options: &ParseOptions,
+        source_manager: Arc<dyn SourceManager>,
     ) -> Result<Box<Module>, Report> {
         let mut parser = Module::parser(None);
-        parser.set_warnings_as_errors(warnings_as_errors);
+        parser.set_options(options);
         parser.parse(None, self, source_manager)
     }
 }
@@ -78,21 +155,39 @@ impl Parse for &str {
         self,
         warnings_as_errors: bool,
         source_manager: Arc<dyn SourceManager>,
+    ) -> Result<Box<Module>, Report> {
+        let options = ParseOptions::default().with_warnings_as_errors(warnings_as_errors);
+        self.parse_with_options(&options, source_manager)
+    }
+
+    fn parse_with_options(
+        self,
+        options: &ParseOptions,
+        source_manager: Arc<dyn SourceManager>,
     ) -> Result<Box<Module>, Report> {
         let mut parser = Module::parser(None);
-        parser.set_warnings_as_errors(warnings_as_errors);
+        parser.set_options(options);
         parser.parse_str(None, self, source_manager)
     }
 }
 
 impl Parse for &String {
-    #[inline]
     fn parse(
         self,
         warnings_as_errors: bool,
         source_manager: Arc<dyn SourceManager>,
     ) -> Result<Box<Module>, Report> {
-        Parse::parse(self.as_str(), warnings_as_errors, source_manager)
+        let options = ParseOptions::default().with_warnings_as_errors(warnings_as_errors);
+        self.parse_with_options(&options, source_manager)
+    }
+
+    #[inline]
+    fn parse_with_options(
+        self,
+        options: &ParseOptions,
+        source_manager: Arc<dyn SourceManager>,
+    ) -> Result<Box<Module>, Report> {
+        Parse::parse_with_options(self.as_str(), options, source_manager)
     }
 }
 
@@ -102,7 +197,16 @@ impl Parse for String {
         warnings_as_errors: bool,
         source_manager: Arc<dyn SourceManager>,
     ) -> Result<Box<Module>, Report> {
-        Parse::parse(self.as_str(), warnings_as_errors, source_manager)
+        let options = ParseOptions::default().with_warnings_as_errors(warnings_as_errors);
+        self.parse_with_options(&options, source_manager)
+    }
+
+    fn parse_with_options(
+        self,
+        options: &ParseOptions,
+        source_manager: Arc<dyn SourceManager>,
+    ) -> Result<Box<Module>, Report> {
+        Parse::parse_with_options(self.as_str(), options, source_manager)
     }
 }
 
@@ -112,7 +216,16 @@ impl Parse for Box<str> {
         warnings_as_errors: bool,
         source_manager: Arc<dyn SourceManager>,
     ) -> Result<Box<Module>, Report> {
-        Parse::parse(self.as_ref(), warnings_as_errors, source_manager)
+        let options = ParseOptions::default().with_warnings_as_errors(warnings_as_errors);
+        self.parse_with_options(&options, source_manager)
+    }
+
+    fn parse_with_options(
+        self,
+        options: &ParseOptions,
+        source_manager: Arc<dyn SourceManager>,
+    ) -> Result<Box<Module>, Report> {
+        Parse::parse_with_options(self.as_ref(), options, source_manager)
     }
 }
 
@@ -122,7 +235,16 @@ impl Parse for Cow<'_, str> {
         warnings_as_errors: bool,
         source_manager: Arc<dyn SourceManager>,
     ) -> Result<Box<Module>, Report> {
-        Parse::parse(self.as_ref(), warnings_as_errors, source_manager)
+        let options = ParseOptions::default().with_warnings_as_errors(warnings_as_errors);
+        self.parse_with_options(&options, source_manager)
+    }
+
+    fn parse_with_options(
+        self,
+        options: &ParseOptions,
+        source_manager: Arc<dyn SourceManager>,
+    ) -> Result<Box<Module>, Report> {
+        Parse::parse_with_options(self.as_ref(), options, source_manager)
     }
 }
 
@@ -135,9 +257,18 @@ impl Parse for &std::path::Path {
         self,
         warnings_as_errors: bool,
         source_manager: Arc<dyn SourceManager>,
+    ) -> Result<Box<Module>, Report> {
+        let options = ParseOptions::default().with_warnings_as_errors(warnings_as_errors);
+        self.parse_with_options(&options, source_manager)
+    }
+
+    fn parse_with_options(
+        self,
+        options: &ParseOptions,
+        source_manager: Arc<dyn SourceManager>,
     ) -> Result<Box<Module>, Report> {
         let mut parser = Module::parser(None);
-        parser.set_warnings_as_errors(warnings_as_errors);
+        parser.set_options(options);
         parser.parse_file(None, self, source_manager)
     }
 }
@@ -149,6 +280,15 @@ impl Parse for std::path::PathBuf {
         warnings_as_errors: bool,
         source_manager: Arc<dyn SourceManager>,
     ) -> Result<Box<Module>, Report> {
-        self.as_path().parse(warnings_as_errors, source_manager)
+        let options = ParseOptions::default().with_warnings_as_errors(warnings_as_errors);
+        self.parse_with_options(&options, source_manager)
+    }
+
+    fn parse_with_options(
+        self,
+        options: &ParseOptions,
+        source_manager: Arc<dyn SourceManager>,
+    ) -> Result<Box<Module>, Report> {
+        self.as_path().parse_with_options(options, source_manager)
     }
 }
@@ -12,8 +12,10 @@ use miden_assembly_syntax_cst::{
 use miden_debug_types::SourceSpan;
 
 use super::{
-    context::LoweringContext, fragments::lower_u32_immediate_token,
+    context::LoweringContext,
+    fragments::{lower_attribute, lower_u32_immediate_token},
     instructions::try_lower_instruction,
+    macros::{substitute_macro_argument_u32, substitute_macro_arguments, try_expand_macro},
 };
 use crate::{ast, parser::ParsingError};
 
@@ -156,28 +158,50 @@ fn lower_do_while_op(
 }
 
 /// Lowers a `repeat.<count>` operation and validates the repeat-count immediate.
+///
+/// The only attribute allowed on a `repeat` operation is `@no_unroll`.
 fn lower_repeat_op(
     context: &mut LoweringContext<'_>,
     op: &CstRepeatOp,
 ) -> Result<ast::Op, ParsingError> {
     let span = context.parse().span_for_node(op.syntax());
-    let count = parse_repeat_count(context, op)?;
+    let mut no_unroll = false;
+    for attribute in op.attributes() {
+        match lower_attribute(context, &attribute)? {
+            ast::Attribute::Marker(name) if name.as_str() == "no_unroll" => no_unroll = true,
+            _ => {
+                return Err(ParsingError::InvalidSyntax {
+                    span: context.parse().span_for_node(attribute.syntax()),
+                    message: "only `@no_unroll` attributes are allowed on `repeat` blocks"
+                        .to_string(),
+                });
+            },
+        }
+    }
+    let mut count = parse_repeat_count(context, op)?;
+    substitute_macro_argument_u32(context, &mut count)?;
     let body = op.body().ok_or_else(|| ParsingError::InvalidSyntax {
         span,
         message: "expected a block body for `repeat`".to_string(),
     })?;
     let body = lower_required_block(context, &body, "expected a non-empty `repeat` block")?;
-    Ok(ast::Op::Repeat { span, count, body })
+    Ok(ast::Op::Repeat { span, count, body, no_unroll })
 }
 
 /// Lowers a single instruction node, delegating operand decoding to `instructions.rs`.
 ///
-/// Any instruction spelling that the direct lowerer does not recognize is reported as malformed.
+/// Macro invocations are expanded in place, and inside a macro body, references to the macro's
+/// parameters are replaced with the arguments of the expansion. Any instruction spelling that the
+/// direct lowerer does not recognize is reported as malformed.
 fn lower_instruction(
     context: &mut LoweringContext<'_>,
     instruction: &CstInstruction,
 ) -> Result<Vec<ast::Op>, ParsingError> {
-    if let Some(ops) = try_lower_instruction(context, instruction)? {
+    if let Some(ops) = try_expand_macro(context, instruction)? {
+        return Ok(ops);
+    }
+    if let Some(mut ops) = try_lower_instruction(context, instruction)? {
+        substitute_macro_arguments(context, &mut ops)?;
         return Ok(ops);
     }
     Err(invalid_instruction_error(context, instruction))
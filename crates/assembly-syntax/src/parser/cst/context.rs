@@ -1,7 +1,8 @@
 use alloc::{
-    collections::BTreeSet,
+    collections::{BTreeMap, BTreeSet},
     string::{String, ToString},
     sync::Arc,
+    vec::Vec,
 };
 
 use miden_assembly_syntax_cst::{
@@ -9,8 +10,9 @@ use miden_assembly_syntax_cst::{
     ast::{AstNode, Path as CstPath, Visibility as CstVisibility},
     rowan,
 };
-use miden_debug_types::{SourceFile, SourceSpan, Span};
+use miden_debug_types::{SourceFile, SourceSpan, Span, Spanned};
 
+use super::macros::{MacroDef, MacroFrame};
 use crate::{Path, ast, parser::ParsingError};
 
 /// Shared lowering state for a single CST-to-AST pass.
@@ -20,12 +22,32 @@ use crate::{Path, ast, parser::ParsingError};
 pub(super) struct LoweringContext<'a> {
     parse: CstParse,
     interned: &'a mut BTreeSet<Arc<str>>,
+    macros: BTreeMap<ast::Ident, MacroDef>,
+    macro_frames: Vec<MacroFrame>,
+    features: BTreeSet<Arc<str>>,
 }
 
 impl<'a> LoweringContext<'a> {
     /// Creates a new lowering context for `parse`.
     pub(super) fn new(parse: CstParse, interned: &'a mut BTreeSet<Arc<str>>) -> Self {
-        Self { parse, interned }
+        Self {
+            parse,
+            interned,
+            macros: BTreeMap::new(),
+            macro_frames: Vec::new(),
+            features: BTreeSet::new(),
+        }
+    }
+
+    /// Sets the features enabled for conditional compilation while lowering.
+    pub(super) fn with_features(mut self, features: BTreeSet<Arc<str>>) -> Self {
+        self.features = features;
+        self
+    }
+
+    /// Returns true if the feature `name` is enabled for conditional compilation.
+    pub(super) fn is_feature_enabled(&self, name: &str) -> bool {
+        self.features.contains(name)
     }
 
     /// Returns the underlying CST parse being lowered.
@@ -47,6 +69,48 @@ impl<'a> LoweringContext<'a> {
             .expect("cst spans should always refer to valid source slices")
     }
 
+    /// Registers a macro definition, rejecting duplicate macro names.
+    pub(super) fn define_macro(&mut self, def: MacroDef) -> Result<(), ParsingError> {
+        if let Some(prev) = self.macros.get(def.name()) {
+            return Err(ParsingError::DuplicateMacro {
+                span: def.name().span(),
+                prev: prev.name().span(),
+            });
+        }
+        self.macros.insert(def.name().clone(), def);
+        Ok(())
+    }
+
+    /// Returns the macro definition named `name`, if one was declared in the current source file.
+    pub(super) fn get_macro(&self, name: &ast::Ident) -> Option<&MacroDef> {
+        self.macros.get(name)
+    }
+
+    /// Returns the names of the parameters of all macros declared in the current source file.
+    pub(super) fn macro_params(&self) -> Vec<ast::Ident> {
+        self.macros.values().flat_map(|def| def.params().iter().cloned()).collect()
+    }
+
+    /// Returns the frame of the innermost macro expansion in progress, if any.
+    pub(super) fn macro_frame(&self) -> Option<&MacroFrame> {
+        self.macro_frames.last()
+    }
+
+    /// Returns true if the macro named `name` is currently being expanded.
+    pub(super) fn is_expanding_macro(&self, name: &ast::Ident) -> bool {
+        self.macro_frames.iter().any(|frame| frame.name() == name)
+    }
+
+    /// Enters the expansion of a macro, binding its parameters to the arguments in `frame`.
+    pub(super) fn push_macro_frame(&mut self, frame: MacroFrame) {
+        self.macro_frames.push(frame);
+    }
+
+    /// Leaves the innermost macro expansion.
+    pub(super) fn pop_macro_frame(&mut self) {
+        self.macro_frames.pop();
+    }
+
     /// Lowers optional CST visibility into the AST visibility enum.
     pub(super) fn lower_visibility(&self, visibility: Option<CstVisibility>) -> ast::Visibility {
         if visibility.is_some() {
@@ -108,12 +172,19 @@ impl<'a> LoweringContext<'a> {
     /// Lowers an identifier token that must satisfy MASM constant naming rules.
     ///
     /// This is stricter than [`Self::lower_ident_token`] because constants must be screaming-case
-    /// bare identifiers; quoted identifiers and non-constant casing are rejected.
+    /// bare identifiers; quoted identifiers and non-constant casing are rejected. The parameters of
+    /// the macro being expanded are the exception, as they may be referenced in place of constants
+    /// regardless of their casing.
     pub(super) fn lower_constant_ident_token(
         &mut self,
         token: &SyntaxToken,
     ) -> Result<ast::Ident, ParsingError> {
         let span = self.parse.span_for_token(token);
+        if token.kind() == SyntaxKind::Ident
+            && self.macro_frame().is_some_and(|frame| frame.has_param(token.text()))
+        {
+            return self.lower_ident_token(token);
+        }
         if token.kind() != SyntaxKind::Ident {
             return Err(ParsingError::InvalidIdentifier {
                 error: ast::IdentError::Casing(ast::CaseKindError::Screaming),
@@ -6,9 +6,9 @@ use alloc::{
 
 use miden_assembly_syntax_cst::ast::{
     AdviceMap as CstAdviceMap, AstNode, BeginBlock as CstBeginBlock, Constant as CstConstant,
-    ExternPackage as CstExternPackage, Import as CstImport, ImportKind as CstImportKind,
-    Item as CstItem, Namespace as CstNamespace, Procedure as CstProcedure,
-    Submodule as CstSubmodule, TypeDecl as CstTypeDecl,
+    DataSegment as CstDataSegment, ExternPackage as CstExternPackage, Import as CstImport,
+    ImportKind as CstImportKind, Item as CstItem, Namespace as CstNamespace,
+    Procedure as CstProcedure, Submodule as CstSubmodule, TypeDecl as CstTypeDecl,
 };
 use miden_debug_types::{SourceSpan, Span, Spanned};
 
@@ -16,9 +16,11 @@ use super::{
     blocks::lower_required_block,
     context::LoweringContext,
     fragments::{
-        lower_advice_map_decl, lower_attribute, lower_constant_expr, lower_enum_decl_from_body,
+        evaluate_cfg_attribute, is_cfg_attribute, lower_advice_map_decl, lower_attribute,
+        lower_constant_expr, lower_data_segment_decl, lower_enum_decl_from_body,
         lower_function_type_from_signature, lower_type_expr_from_alias_body,
     },
+    macros::declare_macro,
 };
 use crate::{Report, ast, parser::ParsingError};
 
@@ -34,14 +36,35 @@ pub(super) fn lower_source_file(
     let mut forms = Vec::with_capacity(items.len());
     let mut index = 0usize;
 
+    // Macros may be expanded before the point at which they are declared, so all declarations are
+    // registered up front
+    for item in items.iter() {
+        if let CstItem::Macro(decl) = item {
+            declare_macro(context, decl)?;
+        }
+    }
+
+    let elided = items
+        .iter()
+        .map(|item| is_elided(context, item))
+        .collect::<Result<Vec<_>, _>>()?;
+
     while index < items.len() {
         if let Some(is_module_doc) = doc_group_kind(context, &items, index) {
             let end = extend_doc_group(context, &items, index);
-            forms.push(lower_doc_group(context, &items[index..end], is_module_doc)?);
+            // The documentation of an elided item has nothing to attach to
+            if is_module_doc || !elided.get(end).copied().unwrap_or(false) {
+                forms.push(lower_doc_group(context, &items[index..end], is_module_doc)?);
+            }
             index = end;
             continue;
         }
 
+        if elided[index] {
+            index += 1;
+            continue;
+        }
+
         match &items[index] {
             CstItem::Doc(_) => unreachable!("doc items handled above"),
             CstItem::Namespace(namespace) => {
@@ -72,6 +95,10 @@ pub(super) fn lower_source_file(
                 forms.push(lower_advice_map(context, advice_map)?);
                 index += 1;
             },
+            CstItem::DataSegment(data_segment) => {
+                forms.push(lower_data_segment(context, data_segment)?);
+                index += 1;
+            },
             CstItem::BeginBlock(begin) => {
                 forms.push(lower_begin_block(context, begin)?);
                 index += 1;
@@ -80,12 +107,33 @@ pub(super) fn lower_source_file(
                 forms.push(lower_procedure(context, procedure)?);
                 index += 1;
             },
+            CstItem::Macro(_) => unreachable!("macros are always elided"),
         }
     }
 
     Ok(forms)
 }
 
+/// Returns true if `item` does not produce a form of its own.
+///
+/// Macros are expanded at their invocation sites, and items annotated with a `@cfg(...)` attribute
+/// whose predicate does not hold for the enabled features are dropped entirely.
+fn is_elided(context: &mut LoweringContext<'_>, item: &CstItem) -> Result<bool, ParsingError> {
+    let attributes = match item {
+        CstItem::Macro(_) => return Ok(true),
+        CstItem::Procedure(procedure) => procedure.attributes().collect::<Vec<_>>(),
+        CstItem::BeginBlock(begin) => begin.attributes().collect::<Vec<_>>(),
+        _ => return Ok(false),
+    };
+
+    for attribute in attributes {
+        if evaluate_cfg_attribute(context, &attribute)? == Some(false) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
 /// Returns `Some(true/false)` when `items[index]` starts a doc-comment group.
 ///
 /// The boolean indicates whether the group should become `Form::ModuleDoc` (`true`) or ordinary
@@ -302,6 +350,16 @@ fn lower_import(
                 span, visibility, path, specs,
             ))))
         },
+        CstImportKind::Glob => {
+            let module_path = import.module_path().ok_or_else(|| ParsingError::InvalidSyntax {
+                span,
+                message: "expected an import path".to_string(),
+            })?;
+            let path = context.lower_path(&module_path)?;
+            Ok(ast::Form::Import(ast::ImportDecl::Glob(ast::GlobImport::new(
+                span, visibility, path,
+            ))))
+        },
     }
 }
 
@@ -417,12 +475,27 @@ fn lower_advice_map(
     Ok(ast::Form::AdviceMapEntry(lower_advice_map_decl(context, advice_map)?))
 }
 
+/// Lowers a `data` declaration into the corresponding top-level form.
+fn lower_data_segment(
+    context: &mut LoweringContext<'_>,
+    data_segment: &CstDataSegment,
+) -> Result<ast::Form, ParsingError> {
+    Ok(ast::Form::DataSegment(lower_data_segment_decl(context, data_segment)?))
+}
+
 /// Lowers the entry `begin ... end` block for a program/module source file.
 fn lower_begin_block(
     context: &mut LoweringContext<'_>,
     begin: &CstBeginBlock,
 ) -> Result<ast::Form, ParsingError> {
     let span = context.parse().span_for_node(begin.syntax());
+    if let Some(attribute) = begin.attributes().find(|attribute| !is_cfg_attribute(attribute)) {
+        return Err(ParsingError::InvalidSyntax {
+            span: context.parse().span_for_node(attribute.syntax()),
+            message: "only `@cfg(...)` attributes are allowed on `begin` blocks".to_string(),
+        });
+    }
+
     let block = match begin.block() {
         Some(block) => lower_required_block(context, &block, "expected a non-empty entry block")?,
         None => {
@@ -461,8 +534,10 @@ fn lower_procedure(
         proc = proc.with_signature(signature);
     }
 
+    // `@cfg(...)` attributes have already been evaluated, and are not retained
     let attrs = procedure
         .attributes()
+        .filter(|attribute| !is_cfg_attribute(attribute))
         .map(|attribute| lower_attribute(context, &attribute))
         .collect::<Result<Vec<_>, _>>()?;
     apply_procedure_attributes(&mut proc, attrs)?;
@@ -472,9 +547,9 @@ fn lower_procedure(
 
 /// Applies lowered attributes to a procedure while preserving legacy attribute semantics.
 ///
-/// This is responsible for duplicate detection, `@callconv` validation, `@locals` validation, and
-/// the historical rule that some validated attributes are reflected into dedicated procedure
-/// fields rather than staying in the generic attribute set.
+/// This is responsible for duplicate detection, `@callconv`, `@locals`, and `@abi`/`@emits`
+/// validation, and the historical rule that some validated attributes are reflected into dedicated
+/// procedure fields rather than staying in the generic attribute set.
 fn apply_procedure_attributes(
     procedure: &mut ast::Procedure,
     annotations: Vec<ast::Attribute>,
@@ -610,6 +685,8 @@ fn apply_procedure_attributes(
             }
         }
 
+        ast::ProcedureAbi::from_attributes(attributes)?;
+
         if num_locals.is_some() {
             attributes.remove("locals");
         }
@@ -651,7 +728,9 @@ fn item_span(context: &LoweringContext<'_>, item: &CstItem) -> SourceSpan {
         CstItem::Constant(node) => context.parse().span_for_node(node.syntax()),
         CstItem::TypeDecl(node) => context.parse().span_for_node(node.syntax()),
         CstItem::AdviceMap(node) => context.parse().span_for_node(node.syntax()),
+        CstItem::DataSegment(node) => context.parse().span_for_node(node.syntax()),
         CstItem::BeginBlock(node) => context.parse().span_for_node(node.syntax()),
         CstItem::Procedure(node) => context.parse().span_for_node(node.syntax()),
+        CstItem::Macro(node) => context.parse().span_for_node(node.syntax()),
     }
 }
@@ -10,7 +10,8 @@ use alloc::{
 use miden_assembly_syntax_cst::{
     SyntaxKind, SyntaxToken,
     ast::{
-        AdviceMap as CstAdviceMap, AstNode, Attribute as CstAttribute, Expr as CstExpr,
+        AdviceMap as CstAdviceMap, AstNode, Attribute as CstAttribute,
+        DataSegment as CstDataSegment, Expr as CstExpr, Instruction as CstInstruction,
         Signature as CstSignature, TypeBody as CstTypeBody,
     },
     rowan,
@@ -95,6 +96,36 @@ pub(super) fn lower_attribute(
     FragmentParser::parse(context, attribute, FragmentParser::parse_attribute)
 }
 
+/// Returns true if `attribute` is a `@cfg(...)` conditional compilation attribute.
+pub(super) fn is_cfg_attribute(attribute: &CstAttribute) -> bool {
+    attribute
+        .significant_tokens()
+        .nth(1)
+        .is_some_and(|token| token.kind() == SyntaxKind::Ident && token.text() == "cfg")
+}
+
+/// Evaluates the predicate of a `@cfg(<predicate>)` attribute against the features enabled in
+/// `context`.
+///
+/// Returns `None` if `attribute` is not a `cfg` attribute.
+pub(super) fn evaluate_cfg_attribute(
+    context: &mut LoweringContext<'_>,
+    attribute: &CstAttribute,
+) -> Result<Option<bool>, ParsingError> {
+    if !is_cfg_attribute(attribute) {
+        return Ok(None);
+    }
+
+    FragmentParser::parse(context, attribute, |parser| {
+        parser.expect_kind(SyntaxKind::At, "expected `@` to start an attribute")?;
+        parser.expect_keyword("cfg", "expected `cfg`")?;
+        parser.expect_kind(SyntaxKind::LParen, "expected `(` after `cfg`")?;
+        let enabled = parser.parse_cfg_predicate()?;
+        parser.expect_kind(SyntaxKind::RParen, "expected `)` to close `cfg` predicate")?;
+        Ok(Some(enabled))
+    })
+}
+
 /// Lowers an `adv_map` declaration fragment into the legacy advice-map entry AST.
 pub(super) fn lower_advice_map_decl(
     context: &mut LoweringContext<'_>,
@@ -104,6 +135,49 @@ pub(super) fn lower_advice_map_decl(
     FragmentParser::parse(context, advice_map, |parser| parser.parse_advice_map_decl(span))
 }
 
+/// Lowers a `data` declaration fragment into the corresponding data segment AST.
+pub(super) fn lower_data_segment_decl(
+    context: &mut LoweringContext<'_>,
+    data_segment: &CstDataSegment,
+) -> Result<ast::DataSegment, ParsingError> {
+    let span = context.parse().span_for_node(data_segment.syntax());
+    FragmentParser::parse(context, data_segment, |parser| parser.parse_data_segment_decl(span))
+}
+
+/// Lowers a `macro_exec.<name>(<args>)` instruction into the macro name and its argument
+/// expressions.
+///
+/// Returns `None` if `instruction` is not a macro invocation.
+pub(super) fn lower_macro_invocation(
+    context: &mut LoweringContext<'_>,
+    instruction: &CstInstruction,
+) -> Result<Option<(ast::Ident, Vec<ast::ConstantExpr>)>, ParsingError> {
+    let is_invocation = instruction
+        .significant_tokens()
+        .next()
+        .is_some_and(|token| token.kind() == SyntaxKind::Ident && token.text() == "macro_exec");
+    if !is_invocation {
+        return Ok(None);
+    }
+
+    FragmentParser::parse(context, instruction, |parser| {
+        parser.expect_keyword("macro_exec", "expected `macro_exec`")?;
+        parser.expect_kind(SyntaxKind::Dot, "expected `.` after `macro_exec`")?;
+        let name = parser.expect_ident("expected a macro name")?;
+        let name = parser.context.lower_ident_token(&name)?;
+        let mut args = Vec::new();
+        if parser.at_kind(SyntaxKind::LParen) {
+            parser.bump();
+            args = parser.parse_comma_delimited_allow_trailing(
+                SyntaxKind::RParen,
+                FragmentParser::parse_constant_expr,
+            )?;
+            parser.expect_kind(SyntaxKind::RParen, "expected `)` to close macro arguments")?;
+        }
+        Ok(Some((name, args)))
+    })
+}
+
 /// Small recursive-descent/Pratt parser used to re-parse fragment-local CST token streams.
 struct FragmentParser<'a, 'b> {
     context: &'a mut LoweringContext<'b>,
@@ -153,7 +227,7 @@ impl<'a, 'b> FragmentParser<'a, 'b> {
                 break;
             }
 
-            self.bump();
+            self.bump_constant_operator(op);
             let rhs = self.parse_constant_expr_bp(precedence + 1)?;
             let span = join_spans(lhs.span(), rhs.span());
             lhs = ast::ConstantExpr::BinaryOp {
@@ -184,7 +258,7 @@ impl<'a, 'b> FragmentParser<'a, 'b> {
                 break;
             }
 
-            self.bump();
+            self.bump_constant_operator(op);
             let rhs = self.parse_constant_arithmetic_expr_bp(precedence + 1)?;
             let span = join_spans(lhs.span(), rhs.span());
             lhs = ast::ConstantExpr::BinaryOp {
@@ -206,6 +280,16 @@ impl<'a, 'b> FragmentParser<'a, 'b> {
             return Ok(expr);
         }
 
+        if self.at_keyword("word")
+            && self.peek_kind(1) == Some(SyntaxKind::LParen)
+            && !matches!(
+                self.peek_kind(2),
+                Some(SyntaxKind::QuotedString | SyntaxKind::QuotedIdent)
+            )
+        {
+            return self.parse_make_word();
+        }
+
         if (self.at_keyword("word") || self.at_keyword("event"))
             && self.peek_kind(1) == Some(SyntaxKind::LParen)
         {
@@ -315,6 +399,24 @@ impl<'a, 'b> FragmentParser<'a, 'b> {
         }
     }
 
+    /// Parses a word constructed from four constant expressions, of the form `word(a, b, c, d)`.
+    fn parse_make_word(&mut self) -> Result<ast::ConstantExpr, ParsingError> {
+        let word = self.expect_keyword("word", "expected `word`")?;
+        self.expect_kind(SyntaxKind::LParen, "expected `(` after `word`")?;
+        let mut elements = Vec::with_capacity(4);
+        for index in 0..4 {
+            elements.push(self.parse_constant_expr()?);
+            if index < 3 {
+                self.expect_kind(SyntaxKind::Comma, "expected `,` between word elements")?;
+            }
+        }
+        let rparen = self.expect_kind(SyntaxKind::RParen, "expected `)` to close `word(...)`")?;
+        let span = join_spans(self.token_span(&word), self.token_span(&rparen));
+        let elements: [ast::ConstantExpr; 4] =
+            elements.try_into().expect("expected exactly four word elements");
+        Ok(ast::ConstantExpr::MakeWord { span, elements: Box::new(elements) })
+    }
+
     /// Parses a four-element word literal of the form `[a, b, c, d]`.
     fn parse_word_literal(&mut self) -> Result<ast::ConstantExpr, ParsingError> {
         let lbracket = self.expect_kind(SyntaxKind::LBracket, "expected `[` to start word")?;
@@ -481,6 +583,63 @@ impl<'a, 'b> FragmentParser<'a, 'b> {
         Ok(attribute.with_span(self.span))
     }
 
+    /// Parses and evaluates a `cfg` predicate, i.e. one of `feature = "<name>"`,
+    /// `not(<predicate>)`, `all(<predicates>)` or `any(<predicates>)`.
+    fn parse_cfg_predicate(&mut self) -> Result<bool, ParsingError> {
+        const EXPECTED_PREDICATE: &str =
+            "expected a `cfg` predicate: `feature = \"...\"`, `not(..)`, `all(..)` or `any(..)`";
+
+        let Some(token) = self.current().filter(|token| token.kind() == SyntaxKind::Ident) else {
+            return Err(self.invalid_syntax(EXPECTED_PREDICATE));
+        };
+        match token.text() {
+            "feature" => {
+                self.bump();
+                self.expect_kind(SyntaxKind::Equal, "expected `=` after `feature`")?;
+                let name = match self.current() {
+                    Some(token)
+                        if matches!(
+                            token.kind(),
+                            SyntaxKind::QuotedString | SyntaxKind::QuotedIdent
+                        ) =>
+                    {
+                        self.bump();
+                        self.lower_string_token(&token)?
+                    },
+                    _ => {
+                        return Err(self.invalid_syntax(
+                            "expected a quoted feature name, e.g. `feature = \"std\"`",
+                        ));
+                    },
+                };
+                Ok(self.context.is_feature_enabled(name.as_str()))
+            },
+            "not" => {
+                self.bump();
+                self.expect_kind(SyntaxKind::LParen, "expected `(` after `not`")?;
+                let enabled = self.parse_cfg_predicate()?;
+                self.expect_kind(SyntaxKind::RParen, "expected `)` to close `not` predicate")?;
+                Ok(!enabled)
+            },
+            combinator @ ("all" | "any") => {
+                let is_all = combinator == "all";
+                self.bump();
+                self.expect_kind(SyntaxKind::LParen, "expected `(` to open predicate list")?;
+                let predicates = self.parse_comma_delimited_allow_trailing(
+                    SyntaxKind::RParen,
+                    Self::parse_cfg_predicate,
+                )?;
+                self.expect_kind(SyntaxKind::RParen, "expected `)` to close predicate list")?;
+                Ok(if is_all {
+                    predicates.into_iter().all(|enabled| enabled)
+                } else {
+                    predicates.into_iter().any(|enabled| enabled)
+                })
+            },
+            _ => Err(self.invalid_syntax(EXPECTED_PREDICATE)),
+        }
+    }
+
     fn parse_meta_kv(&mut self) -> Result<(SourceSpan, ast::Ident, ast::MetaExpr), ParsingError> {
         let key_token = self.expect_ident("expected an attribute key")?;
         let key = self.context.lower_ident_token(&key_token)?;
@@ -548,6 +707,31 @@ impl<'a, 'b> FragmentParser<'a, 'b> {
         Ok(ast::AdviceMapEntry::new(span, name, key, value))
     }
 
+    /// Parses the right-hand side of a `data` declaration.
+    fn parse_data_segment_decl(
+        &mut self,
+        span: SourceSpan,
+    ) -> Result<ast::DataSegment, ParsingError> {
+        self.expect_keyword("data", "expected `data` in data segment declaration")?;
+        let name_token = self.expect_ident("expected a data segment name")?;
+        let name = self.context.lower_constant_ident_token(&name_token)?;
+        self.expect_kind(SyntaxKind::Equal, "expected `=` in data segment declaration")?;
+        self.expect_kind(SyntaxKind::LBracket, "expected `[` to start data segment words")?;
+        if self.at_kind(SyntaxKind::RBracket) {
+            return Err(self.invalid_syntax("expected at least one word in data segment"));
+        }
+        let words = self.parse_comma_delimited_allow_trailing(SyntaxKind::RBracket, |parser| {
+            if parser.at_kind(SyntaxKind::LBracket) {
+                return parser.parse_word_value_literal();
+            }
+            let span = parser.current_span();
+            parser.parse_word_value().map(|value| Span::new(span, value))
+        })?;
+        self.expect_kind(SyntaxKind::RBracket, "expected `]` to close data segment words")?;
+
+        Ok(ast::DataSegment::new(span, name, words))
+    }
+
     fn parse_word_value(&mut self) -> Result<WordValue, ParsingError> {
         if self.at_kind(SyntaxKind::LBracket) {
             return self.parse_word_value_literal().map(Span::into_inner);
@@ -941,13 +1125,41 @@ impl<'a, 'b> FragmentParser<'a, 'b> {
     /// with its binding power.
     fn current_constant_operator(&self) -> Option<(u8, ast::ConstantOp)> {
         let token = self.current()?;
-        match token.kind() {
-            SyntaxKind::Plus => Some((1, ast::ConstantOp::Add)),
-            SyntaxKind::Minus => Some((1, ast::ConstantOp::Sub)),
-            SyntaxKind::Star => Some((2, ast::ConstantOp::Mul)),
-            SyntaxKind::Slash => Some((2, ast::ConstantOp::Div)),
-            SyntaxKind::SlashSlash => Some((2, ast::ConstantOp::IntDiv)),
-            _ => None,
+        let op = match token.kind() {
+            SyntaxKind::Plus => ast::ConstantOp::Add,
+            SyntaxKind::Minus => ast::ConstantOp::Sub,
+            SyntaxKind::Star => ast::ConstantOp::Mul,
+            SyntaxKind::Slash => ast::ConstantOp::Div,
+            SyntaxKind::SlashSlash => ast::ConstantOp::IntDiv,
+            SyntaxKind::Percent => ast::ConstantOp::Mod,
+            SyntaxKind::Amp => ast::ConstantOp::BitAnd,
+            SyntaxKind::Pipe => ast::ConstantOp::BitOr,
+            SyntaxKind::Caret => ast::ConstantOp::BitXor,
+            SyntaxKind::LAngle if self.at_adjacent_pair(SyntaxKind::LAngle) => ast::ConstantOp::Shl,
+            SyntaxKind::RAngle if self.at_adjacent_pair(SyntaxKind::RAngle) => ast::ConstantOp::Shr,
+            _ => return None,
+        };
+        Some((op.precedence(), op))
+    }
+
+    /// Consumes the token(s) making up the constant operator `op`.
+    fn bump_constant_operator(&mut self, op: ast::ConstantOp) {
+        // Shifts are lexed as a pair of angle brackets, since `>>` also closes nested types
+        if matches!(op, ast::ConstantOp::Shl | ast::ConstantOp::Shr) {
+            self.bump();
+        }
+        self.bump();
+    }
+
+    /// Returns true if the current and next tokens are both `kind`, with nothing between them.
+    fn at_adjacent_pair(&self, kind: SyntaxKind) -> bool {
+        match (self.tokens.get(self.pos), self.tokens.get(self.pos + 1)) {
+            (Some(first), Some(second)) => {
+                first.kind() == kind
+                    && second.kind() == kind
+                    && first.text_range().end() == second.text_range().start()
+            },
+            _ => false,
         }
     }
 
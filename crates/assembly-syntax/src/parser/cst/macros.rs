@@ -0,0 +1,417 @@
+use alloc::{boxed::Box, collections::BTreeMap, string::ToString, sync::Arc, vec::Vec};
+use core::ops::ControlFlow;
+
+use miden_assembly_syntax_cst::{
+    SyntaxKind,
+    ast::{AstNode, Block as CstBlock, Instruction as CstInstruction, Macro as CstMacro},
+};
+use miden_debug_types::{SourceFile, SourceSpan, Span, Spanned};
+
+use super::{blocks::lower_block, context::LoweringContext, fragments::lower_macro_invocation};
+use crate::{
+    Felt, Path,
+    ast::{
+        self, Immediate, VisitMut,
+        constants::{
+            ConstEnvironment, ConstEvalError,
+            eval::{self, CachedConstantValue},
+        },
+    },
+    parser::{ParsingError, PushValue, WordValue},
+    sema::ConstEvalVisitor,
+};
+
+// MACRO DEFINITIONS
+// ================================================================================================
+
+/// A macro declared in the source file being lowered.
+///
+/// The body is kept in CST form, and is lowered anew every time the macro is expanded, with its
+/// parameters bound to the arguments of that expansion.
+pub(super) struct MacroDef {
+    name: ast::Ident,
+    params: Vec<ast::Ident>,
+    body: CstBlock,
+}
+
+impl MacroDef {
+    /// Returns the name of this macro.
+    pub(super) fn name(&self) -> &ast::Ident {
+        &self.name
+    }
+
+    /// Returns the names of the parameters of this macro.
+    pub(super) fn params(&self) -> &[ast::Ident] {
+        &self.params
+    }
+}
+
+/// The parameter bindings of a macro expansion in progress.
+pub(super) struct MacroFrame {
+    name: ast::Ident,
+    span: SourceSpan,
+    args: BTreeMap<ast::Ident, MacroArgument>,
+    source_file: Arc<SourceFile>,
+}
+
+impl MacroFrame {
+    /// Returns the name of the macro being expanded.
+    pub(super) fn name(&self) -> &ast::Ident {
+        &self.name
+    }
+
+    /// Returns true if the macro being expanded has a parameter named `name`.
+    pub(super) fn has_param(&self, name: &str) -> bool {
+        self.args.keys().any(|param| param.as_str() == name)
+    }
+
+    /// Wraps an error raised while substituting the arguments of this expansion.
+    fn invalid_argument(&self, error: ConstEvalError) -> ParsingError {
+        ParsingError::InvalidMacroArgument {
+            name: self.name.clone(),
+            span: self.span,
+            error: Box::new(error),
+        }
+    }
+}
+
+/// The argument bound to a macro parameter.
+#[derive(Clone)]
+enum MacroArgument {
+    /// The parameter is bound to a constant value, e.g. `1`, `[1, 2, 3, 4]` or `"error"`.
+    Value(ast::ConstantExpr),
+    /// The parameter is bound to a reference to a constant of the module.
+    Constant(ast::Ident),
+}
+
+// DECLARATION
+// ================================================================================================
+
+/// Validates the macro declaration `decl`, and registers it with `context` for later expansion.
+pub(super) fn declare_macro(
+    context: &mut LoweringContext<'_>,
+    decl: &CstMacro,
+) -> Result<(), ParsingError> {
+    let span = context.parse().span_for_node(decl.syntax());
+    validate_macro_header(context, decl)?;
+
+    let name = match decl.name_token() {
+        Some(token) if token.kind() == SyntaxKind::Ident => context.lower_ident_token(&token)?,
+        _ => {
+            return Err(ParsingError::InvalidSyntax {
+                span,
+                message: "expected a macro name".to_string(),
+            });
+        },
+    };
+
+    let mut params = Vec::<ast::Ident>::new();
+    for token in decl.param_tokens() {
+        let param_span = context.parse().span_for_token(&token);
+        if token.kind() != SyntaxKind::Ident {
+            return Err(ParsingError::InvalidSyntax {
+                span: param_span,
+                message: "expected a macro parameter name".to_string(),
+            });
+        }
+        let param = context.lower_ident_token(&token)?;
+        if params.contains(&param) {
+            return Err(ParsingError::InvalidSyntax {
+                span: param_span,
+                message: format!("duplicate macro parameter '{param}'"),
+            });
+        }
+        params.push(param);
+    }
+
+    let body =
+        decl.block()
+            .filter(|block| block.operations().next().is_some())
+            .ok_or_else(|| ParsingError::InvalidSyntax {
+                span,
+                message: "expected a non-empty macro body".to_string(),
+            })?;
+
+    context.define_macro(MacroDef { name, params, body })
+}
+
+/// Checks that the header of `decl` has the form `macro.<name>` or `macro.<name>(<params>)`,
+/// where `<params>` is a comma-delimited list of parameter names.
+fn validate_macro_header(
+    context: &LoweringContext<'_>,
+    decl: &CstMacro,
+) -> Result<(), ParsingError> {
+    let span = context.parse().span_for_node(decl.syntax());
+    let tokens = decl
+        .significant_tokens()
+        .take_while(|token| !(token.kind() == SyntaxKind::Ident && token.text() == "end"))
+        .collect::<Vec<_>>();
+    let header_len = tokens
+        .iter()
+        .position(|token| token.kind() == SyntaxKind::RParen)
+        .map(|index| index + 1)
+        .unwrap_or(3.min(tokens.len()));
+    let header = &tokens[..header_len];
+
+    let well_formed = match header {
+        [keyword, dot, _name] => keyword.text() == "macro" && dot.kind() == SyntaxKind::Dot,
+        [keyword, dot, _name, lparen, params @ .., rparen] => {
+            keyword.text() == "macro"
+                && dot.kind() == SyntaxKind::Dot
+                && lparen.kind() == SyntaxKind::LParen
+                && rparen.kind() == SyntaxKind::RParen
+                && params
+                    .iter()
+                    .enumerate()
+                    .all(|(index, token)| (index % 2 == 1) == (token.kind() == SyntaxKind::Comma))
+                && params.last().is_none_or(|token| token.kind() != SyntaxKind::Comma)
+        },
+        _ => false,
+    };
+
+    if well_formed {
+        Ok(())
+    } else {
+        Err(ParsingError::InvalidSyntax {
+            span,
+            message: "expected `macro.<name>` or `macro.<name>(<params>)`".to_string(),
+        })
+    }
+}
+
+// EXPANSION
+// ================================================================================================
+
+/// Expands `instruction` if it is a macro invocation, i.e. `macro_exec.<name>(<args>)`.
+///
+/// The body of the macro is lowered with its parameters bound to the given arguments, and the
+/// resulting ops are returned in place of the invocation. Returns `None` if `instruction` is not a
+/// macro invocation.
+pub(super) fn try_expand_macro(
+    context: &mut LoweringContext<'_>,
+    instruction: &CstInstruction,
+) -> Result<Option<Vec<ast::Op>>, ParsingError> {
+    let Some((name, args)) = lower_macro_invocation(context, instruction)? else {
+        return Ok(None);
+    };
+    let span = context.parse().span_for_node(instruction.syntax());
+
+    let Some(def) = context.get_macro(&name) else {
+        return Err(ParsingError::UndefinedMacro { span: name.span(), name });
+    };
+    let params = def.params.clone();
+    let body = def.body.clone();
+
+    if params.len() != args.len() {
+        return Err(ParsingError::MacroArityMismatch {
+            span,
+            expected: params.len(),
+            actual: args.len(),
+        });
+    }
+    if context.is_expanding_macro(&name) {
+        return Err(ParsingError::RecursiveMacro { name, span });
+    }
+
+    let mut bindings = BTreeMap::new();
+    for (param, arg) in params.into_iter().zip(args) {
+        let arg = resolve_macro_argument(context, &name, span, arg)?;
+        bindings.insert(param, arg);
+    }
+
+    let source_file = context.parse().source_file();
+    context.push_macro_frame(MacroFrame { name, span, args: bindings, source_file });
+    let expanded = lower_block(context, &body);
+    context.pop_macro_frame();
+
+    Ok(Some(expanded?.iter().cloned().collect()))
+}
+
+/// Evaluates the argument `arg` of an expansion of the macro `name`.
+///
+/// When expanding a macro from the body of another, references to the parameters of the enclosing
+/// macro are resolved here, so that the body of the expanded macro only ever sees its own
+/// parameters.
+fn resolve_macro_argument(
+    context: &LoweringContext<'_>,
+    name: &ast::Ident,
+    span: SourceSpan,
+    arg: ast::ConstantExpr,
+) -> Result<MacroArgument, ParsingError> {
+    let frame = context.macro_frame();
+    if let ast::ConstantExpr::Var(path) = &arg
+        && let Some(ident) = path.as_ident()
+        && let Some(bound) = frame.and_then(|frame| frame.args.get(&ident))
+    {
+        return Ok(match bound {
+            MacroArgument::Value(value) => MacroArgument::Value(value.clone()),
+            MacroArgument::Constant(constant) => {
+                MacroArgument::Constant(constant.clone().with_span(path.span()))
+            },
+        });
+    }
+
+    let mut env = MacroArguments {
+        args: frame.map(|frame| &frame.args),
+        source_file: context.parse().source_file(),
+    };
+    let arg_span = arg.span();
+    let evaluated =
+        eval::expr(&arg, &mut env).map_err(|error| ParsingError::InvalidMacroArgument {
+            name: name.clone(),
+            span,
+            error: Box::new(error),
+        })?;
+    if evaluated.is_value() {
+        return Ok(MacroArgument::Value(evaluated));
+    }
+    match &evaluated {
+        ast::ConstantExpr::Var(path) if let Some(ident) = path.as_ident() => {
+            Ok(MacroArgument::Constant(ident.with_span(path.span())))
+        },
+        _ => Err(ParsingError::InvalidSyntax {
+            span: arg_span,
+            message: "macro arguments must be constant values, or the name of a constant defined \
+                      in the current module"
+                .to_string(),
+        }),
+    }
+}
+
+/// Substitutes the arguments of the macro being expanded for references to its parameters in the
+/// immediates of `ops`.
+///
+/// This must only be applied to the ops lowered from the body of the macro itself, and not to the
+/// ops produced by nested expansions, whose parameters have already been substituted.
+pub(super) fn substitute_macro_arguments(
+    context: &LoweringContext<'_>,
+    ops: &mut [ast::Op],
+) -> Result<(), ParsingError> {
+    let Some(frame) = context.macro_frame() else {
+        return Ok(());
+    };
+
+    let mut env = MacroArguments {
+        args: Some(&frame.args),
+        source_file: Arc::clone(&frame.source_file),
+    };
+    let mut visitor = ConstEvalVisitor::new(&mut env);
+    for op in ops.iter_mut() {
+        let _ = visitor.visit_mut_op(op);
+    }
+    visitor
+        .into_result()
+        .map_err(|mut errors| frame.invalid_argument(errors.swap_remove(0)))?;
+
+    let mut renamer = RenameConstantArguments { args: &frame.args };
+    for op in ops.iter_mut() {
+        let _ = renamer.visit_mut_op(op);
+    }
+
+    Ok(())
+}
+
+/// Like [`substitute_macro_arguments`], but for a single `u32` immediate, such as the count of a
+/// `repeat`.
+pub(super) fn substitute_macro_argument_u32(
+    context: &LoweringContext<'_>,
+    imm: &mut ast::ImmU32,
+) -> Result<(), ParsingError> {
+    let Some(frame) = context.macro_frame() else {
+        return Ok(());
+    };
+
+    let mut env = MacroArguments {
+        args: Some(&frame.args),
+        source_file: Arc::clone(&frame.source_file),
+    };
+    let mut visitor = ConstEvalVisitor::new(&mut env);
+    let _ = visitor.visit_mut_immediate_u32(imm);
+    visitor
+        .into_result()
+        .map_err(|mut errors| frame.invalid_argument(errors.swap_remove(0)))?;
+
+    let _ = RenameConstantArguments { args: &frame.args }.visit_mut_immediate_u32(imm);
+    Ok(())
+}
+
+// HELPERS
+// ================================================================================================
+
+/// The constant-evaluation environment of a macro expansion, in which the parameters bound to
+/// constant values are the only symbols that are defined.
+struct MacroArguments<'a> {
+    args: Option<&'a BTreeMap<ast::Ident, MacroArgument>>,
+    source_file: Arc<SourceFile>,
+}
+
+impl ConstEnvironment for MacroArguments<'_> {
+    type Error = ConstEvalError;
+
+    fn get_source_file_for(&self, _span: SourceSpan) -> Option<Arc<SourceFile>> {
+        Some(Arc::clone(&self.source_file))
+    }
+
+    fn get(&mut self, name: &ast::Ident) -> Result<Option<CachedConstantValue<'_>>, Self::Error> {
+        Ok(self.args.and_then(|args| match args.get(name) {
+            Some(MacroArgument::Value(value)) => Some(CachedConstantValue::Miss(value)),
+            Some(MacroArgument::Constant(_)) | None => None,
+        }))
+    }
+
+    fn get_by_path(
+        &mut self,
+        _path: Span<&Path>,
+    ) -> Result<Option<CachedConstantValue<'_>>, Self::Error> {
+        Ok(None)
+    }
+}
+
+/// Renames references to macro parameters which are bound to module constants.
+struct RenameConstantArguments<'a> {
+    args: &'a BTreeMap<ast::Ident, MacroArgument>,
+}
+
+impl RenameConstantArguments<'_> {
+    fn rename<T>(&self, imm: &mut Immediate<T>) -> ControlFlow<()> {
+        if let Immediate::Constant(name) = imm
+            && let Some(MacroArgument::Constant(constant)) = self.args.get(name)
+        {
+            *imm = Immediate::Constant(constant.clone().with_span(name.span()));
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+impl VisitMut for RenameConstantArguments<'_> {
+    fn visit_mut_immediate_u8(&mut self, imm: &mut Immediate<u8>) -> ControlFlow<()> {
+        self.rename(imm)
+    }
+    fn visit_mut_immediate_u16(&mut self, imm: &mut Immediate<u16>) -> ControlFlow<()> {
+        self.rename(imm)
+    }
+    fn visit_mut_immediate_u32(&mut self, imm: &mut Immediate<u32>) -> ControlFlow<()> {
+        self.rename(imm)
+    }
+    fn visit_mut_immediate_felt(&mut self, imm: &mut Immediate<Felt>) -> ControlFlow<()> {
+        self.rename(imm)
+    }
+    fn visit_mut_immediate_word_value(
+        &mut self,
+        imm: &mut Immediate<WordValue>,
+    ) -> ControlFlow<()> {
+        self.rename(imm)
+    }
+    fn visit_mut_immediate_push_value(
+        &mut self,
+        imm: &mut Immediate<PushValue>,
+    ) -> ControlFlow<()> {
+        self.rename(imm)
+    }
+    fn visit_mut_immediate_error_message(
+        &mut self,
+        imm: &mut Immediate<Arc<str>>,
+    ) -> ControlFlow<()> {
+        self.rename(imm)
+    }
+}
@@ -3,6 +3,7 @@ mod context;
 mod forms;
 mod fragments;
 mod instructions;
+mod macros;
 
 use alloc::{collections::BTreeSet, string::String, sync::Arc, vec::Vec};
 
@@ -62,15 +63,25 @@ pub enum SyntaxError {
 /// This function is the public entry point for the CST backend. It first runs the lossless CST
 /// parser, converts any CST diagnostics into the existing parser-facing report surface, and only
 /// then lowers the recovered CST into the historic `Vec<Form>` boundary used by semantic analysis.
+///
+/// Items annotated with `@cfg(...)` are only lowered when their predicate holds with respect to
+/// `features`.
+///
+/// Macros are expanded while lowering, so their parameters are not represented in the resulting
+/// forms. The names of the parameters of all declared macros are returned alongside the forms, so
+/// that semantic analysis can check them against the constants of the module.
 pub fn parse_forms(
     source: Arc<SourceFile>,
     interned: &mut BTreeSet<Arc<str>>,
-) -> Result<Vec<ast::Form>, Report> {
+    features: &BTreeSet<Arc<str>>,
+) -> Result<(Vec<ast::Form>, Vec<ast::Ident>), Report> {
     let mut parse = miden_assembly_syntax_cst::parse_source_file(source.clone());
     let diagnostics = parse.take_diagnostics();
     if diagnostics.is_empty() {
-        let mut context = LoweringContext::new(parse, interned);
-        lower_source_file(&mut context).map_err(move |err| err.with_source_code(source))
+        let mut context = LoweringContext::new(parse, interned).with_features(features.clone());
+        let forms =
+            lower_source_file(&mut context).map_err(move |err| err.with_source_code(source))?;
+        Ok((forms, context.macro_params()))
     } else {
         Err(Report::from(SyntaxError::from(diagnostics)).with_source_code(source))
     }
@@ -330,6 +330,13 @@ pub enum ParsingError {
         instruction: String,
         replacement: String,
     },
+    #[error("invalid procedure @abi attribute")]
+    #[diagnostic()]
+    InvalidAbiAttr {
+        #[label("{message}")]
+        span: SourceSpan,
+        message: String,
+    },
     #[error("invalid procedure @locals attribute")]
     #[diagnostic()]
     InvalidLocalsAttr {
@@ -394,6 +401,46 @@ pub enum ParsingError {
         #[source_code]
         source_file: Option<Arc<miden_debug_types::SourceFile>>,
     },
+    #[error("undefined macro '{name}'")]
+    #[diagnostic(help("macros must be defined in the same module in which they are expanded"))]
+    UndefinedMacro {
+        name: crate::ast::Ident,
+        #[label("no macro with this name is defined in this module")]
+        span: SourceSpan,
+    },
+    #[error("duplicate macro definition")]
+    #[diagnostic()]
+    DuplicateMacro {
+        #[label("a macro with this name has already been defined")]
+        span: SourceSpan,
+        #[label("previously defined here")]
+        prev: SourceSpan,
+    },
+    #[error("invalid macro expansion: expected {expected} argument(s), but got {actual}")]
+    #[diagnostic()]
+    MacroArityMismatch {
+        #[label]
+        span: SourceSpan,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("invalid macro expansion: macro '{name}' expands to itself")]
+    #[diagnostic(help("macros cannot be expanded recursively"))]
+    RecursiveMacro {
+        name: crate::ast::Ident,
+        #[label("this expansion is recursive")]
+        span: SourceSpan,
+    },
+    #[error("invalid argument in expansion of macro '{name}'")]
+    #[diagnostic()]
+    InvalidMacroArgument {
+        name: crate::ast::Ident,
+        #[label("this expansion is invalid")]
+        span: SourceSpan,
+        #[source]
+        #[diagnostic(source)]
+        error: alloc::boxed::Box<crate::ast::constants::ConstEvalError>,
+    },
 }
 
 impl ParsingError {
@@ -14,7 +14,7 @@ pub use self::{
     error::{BinErrorKind, HexErrorKind, LiteralErrorKind, ParsingError},
     value::{IntValue, PushValue, WordValue},
 };
-use crate::{Path, ast, sema};
+use crate::{ParseOptions, Path, ast, sema};
 
 // MODULE PARSER
 // ================================================================================================
@@ -47,6 +47,10 @@ pub struct ModuleParser {
     interned: BTreeSet<Arc<str>>,
     /// When true, all warning diagnostics are promoted to error severity
     warnings_as_errors: bool,
+    /// The set of features enabled for conditional compilation via `@cfg(...)`
+    features: BTreeSet<Arc<str>>,
+    /// The levels at which lints are reported
+    lints: sema::Lints,
 }
 
 impl ModuleParser {
@@ -56,6 +60,8 @@ impl ModuleParser {
             kind,
             interned: Default::default(),
             warnings_as_errors: false,
+            features: Default::default(),
+            lints: Default::default(),
         }
     }
 
@@ -64,6 +70,13 @@ impl ModuleParser {
         self.warnings_as_errors = yes;
     }
 
+    /// Configure this parser according to `options`.
+    pub fn set_options(&mut self, options: &ParseOptions) {
+        self.warnings_as_errors = options.warnings_as_errors;
+        self.features = options.features.clone();
+        self.lints = options.lints.clone();
+    }
+
     /// Parse a [ast::Module] from `source`, and give it the provided `path`.
     ///
     /// If `path` is unset, then it must be derivable in one of two ways:
@@ -89,13 +102,16 @@ impl ModuleParser {
             )),
             None => None,
         };
-        let forms = parse_forms_internal(source.clone(), &mut self.interned)?;
-        sema::analyze(
+        let (forms, macro_params) =
+            parse_forms_internal(source.clone(), &mut self.interned, &self.features)?;
+        sema::analyze_with_lints(
             source,
             self.kind,
             path.as_deref(),
             forms,
             self.warnings_as_errors,
+            &self.lints,
+            macro_params,
             source_manager,
         )
         .map_err(Report::new)
@@ -153,18 +169,20 @@ impl ModuleParser {
 #[cfg(any(test, feature = "testing"))]
 pub fn parse_forms(source: Arc<SourceFile>) -> Result<Vec<ast::Form>, Report> {
     let mut interned = BTreeSet::default();
-    parse_forms_internal(source, &mut interned)
+    parse_forms_internal(source, &mut interned, &BTreeSet::default()).map(|(forms, _)| forms)
 }
 
-/// Parse `source` as a set of [ast::Form]s
+/// Parse `source` as a set of [ast::Form]s, along with the names of the parameters of the macros it
+/// declares
 ///
 /// Aside from catching syntax errors, this does little validation of the resulting forms, that is
 /// handled by semantic analysis, which the caller is expected to perform next.
 fn parse_forms_internal(
     source: Arc<SourceFile>,
     interned: &mut BTreeSet<Arc<str>>,
-) -> Result<Vec<ast::Form>, Report> {
-    cst::parse_forms(source, interned)
+    features: &BTreeSet<Arc<str>>,
+) -> Result<(Vec<ast::Form>, Vec<ast::Ident>), Report> {
+    cst::parse_forms(source, interned, features)
 }
 
 // DIRECTORY PARSER
@@ -183,7 +201,7 @@ pub fn read_modules_from_root(
     namespace: Option<Arc<Path>>,
     kind: Option<ast::ModuleKind>,
     source_manager: Arc<dyn SourceManager>,
-    warnings_as_errors: bool,
+    options: &ParseOptions,
 ) -> Result<(Box<ast::Module>, Vec<Box<ast::Module>>), Report> {
     use miden_utils_diagnostics::report;
 
@@ -230,28 +248,20 @@ pub fn read_modules_from_root(
     let mut modules = Vec::new();
 
     let mut parser = ModuleParser::new(kind);
-    parser.set_warnings_as_errors(warnings_as_errors);
+    parser.set_options(options);
     let root_ast = parser.parse_file(namespace.as_deref(), &root, source_manager.clone())?;
 
     let namespace = Arc::<Path>::from(root_ast.path().to_path_buf().into_boxed_path());
     let submodules = root_ast.submodules().to_vec();
     seen.insert(namespace.clone());
-    walk_module_tree(
-        namespace,
-        root,
-        root_dir,
-        submodules,
-        source_manager,
-        warnings_as_errors,
-        |module| {
-            if !seen.insert(module.path().into()) {
-                Err(report!("duplicate module '{0}'", module.path()))
-            } else {
-                modules.push(module);
-                Ok(())
-            }
-        },
-    )?;
+    walk_module_tree(namespace, root, root_dir, submodules, source_manager, options, |module| {
+        if !seen.insert(module.path().into()) {
+            Err(report!("duplicate module '{0}'", module.path()))
+        } else {
+            modules.push(module);
+            Ok(())
+        }
+    })?;
 
     Ok((root_ast, modules))
 }
@@ -263,7 +273,7 @@ pub fn walk_module_tree<F>(
     current_dir: std::path::PathBuf,
     submodules: Vec<ast::SubmoduleDecl>,
     source_manager: Arc<dyn SourceManager>,
-    warnings_as_errors: bool,
+    options: &ParseOptions,
     mut callback: F,
 ) -> Result<(), Report>
 where
@@ -356,7 +366,7 @@ where
         }
 
         let mut parser = ModuleParser::new(Some(ast::ModuleKind::Library));
-        parser.set_warnings_as_errors(warnings_as_errors);
+        parser.set_options(options);
         let module_path = Arc::<Path>::from(entry.namespace.join(&entry.name).into_boxed_path());
         let ast = parser.parse_file(Some(&module_path), &actual_path, source_manager.clone())?;
 
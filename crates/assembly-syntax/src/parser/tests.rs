@@ -4,7 +4,10 @@ use core::assert_matches;
 use miden_debug_types::{SourceFile, SourceId, SourceLanguage, Uri};
 
 use super::*;
-use crate::ast::{Form, Immediate, Instruction, Op, Visibility};
+use crate::{
+    ast::{Form, Immediate, Instruction, Op, Visibility},
+    prettier::PrettyPrint,
+};
 
 fn test_source_file(source: &str) -> Arc<SourceFile> {
     Arc::new(SourceFile::new(
@@ -153,8 +156,9 @@ end
     .unwrap_or_else(|error| panic!("failed to write {}: {error}", child_path.display()));
 
     let source_manager = Arc::new(DefaultSourceManager::default());
-    let (root, support) = read_modules_from_root(&root_path, None, None, source_manager, false)
-        .expect("valid root module with one declared submodule should parse without panicking");
+    let (root, support) =
+        read_modules_from_root(&root_path, None, None, source_manager, &ParseOptions::default())
+            .expect("valid root module with one declared submodule should parse without panicking");
 
     assert_eq!(root.path(), Path::new("::parser::root"));
     assert_eq!(support.len(), 1);
@@ -433,6 +437,45 @@ fn parser_preserves_literal_constant_expr_tree() {
         if *value.inner() == IntValue::U8(3)));
 }
 
+#[test]
+fn parser_respects_constant_operator_precedence() {
+    let source = test_source_file("const VALUE = A | B << 2 + 1\n");
+    let forms = parse_forms(source).expect("parser should succeed");
+    let [Form::Constant(constant)] = forms.as_slice() else {
+        panic!("expected one constant form, got {forms:?}");
+    };
+
+    let ast::ConstantExpr::BinaryOp { op, rhs, .. } = &constant.value else {
+        panic!("expected bitwise-or expression, got {:?}", constant.value);
+    };
+    assert_eq!(*op, ast::ConstantOp::BitOr);
+    let ast::ConstantExpr::BinaryOp { op, rhs, .. } = rhs.as_ref() else {
+        panic!("expected shift expression, got {rhs:?}");
+    };
+    assert_eq!(*op, ast::ConstantOp::Shl);
+    assert!(matches!(
+        rhs.as_ref(),
+        ast::ConstantExpr::BinaryOp { op: ast::ConstantOp::Add, .. }
+    ));
+    assert_eq!(constant.value.to_pretty_string(), "A|B<<2+1");
+}
+
+#[test]
+fn parse_word_constructor_constant() {
+    let source = test_source_file("const VALUE = word(A, A * 2, (A + 1) % 3, 0)\n");
+    let forms = parse_forms(source).expect("parser should succeed");
+    let [Form::Constant(constant)] = forms.as_slice() else {
+        panic!("expected one constant form, got {forms:?}");
+    };
+
+    let ast::ConstantExpr::MakeWord { elements, .. } = &constant.value else {
+        panic!("expected word constructor, got {:?}", constant.value);
+    };
+    assert!(matches!(&elements[0], ast::ConstantExpr::Var(_)));
+    assert!(matches!(&elements[3], ast::ConstantExpr::Int(_)));
+    assert_eq!(constant.value.to_pretty_string(), "word(A,A*2,(A+1)%3,0)");
+}
+
 #[test]
 fn parse_string_constant_forms() {
     let source = test_source_file("const ERR = \"failed to load the circuit description\"\n");
@@ -509,6 +552,25 @@ end
     assert_parses(source);
 }
 
+#[test]
+fn parse_data_segments() {
+    let source = test_source_file(
+        "\
+data LOOKUP = [
+    [1, 2, 3, 4],
+    0x0500000000000000060000000000000007000000000000000800000000000000,
+]
+
+begin
+    push.LOOKUP_LEN
+    push.LOOKUP
+end
+",
+    );
+
+    assert_parses(source);
+}
+
 #[test]
 fn parse_procedure_attributes() {
     let source = test_source_file(
@@ -850,6 +912,30 @@ fn parser_reports_invalid_advice_map_keys() {
     assert_matches!(render_diagnostic(err), diag if diag.contains("invalid Advice Map key"));
 }
 
+#[test]
+fn parser_reports_empty_data_segments() {
+    let source = test_source_file("data LOOKUP = []\n");
+
+    let err = parse_forms(source).expect_err("parser should reject empty data segments");
+
+    assert_matches!(render_diagnostic(err), diag if diag.contains("expected at least one word"));
+}
+
+#[test]
+fn parser_reports_malformed_abi_attributes() {
+    let source = test_source_file("@abi(inputs = 1, stack = 2)\nproc foo\n    nop\nend\n");
+    let err = parse_forms(source).expect_err("parser should reject unknown abi keys");
+    assert_matches!(render_diagnostic(err), diag if diag.contains("expected one of `inputs`"));
+
+    let source = test_source_file("@abi(inputs = \"one\")\nproc foo\n    nop\nend\n");
+    let err = parse_forms(source).expect_err("parser should reject non-integer word counts");
+    assert_matches!(render_diagnostic(err), diag if diag.contains("expected a word count"));
+
+    let source = test_source_file("@emits\nproc foo\n    nop\nend\n");
+    let err = parse_forms(source).expect_err("parser should reject @emits without events");
+    assert_matches!(render_diagnostic(err), diag if diag.contains("expected a list of event names"));
+}
+
 #[test]
 fn parser_preserves_immediate_spellings_without_rewrites() {
     let source = test_source_file(
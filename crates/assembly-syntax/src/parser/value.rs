@@ -268,6 +268,49 @@ impl IntValue {
         }
         Some(shrink_u64_hex(value))
     }
+
+    pub fn checked_rem(&self, rhs: Self) -> Option<Self> {
+        let value = self.as_int().checked_rem(rhs.as_int())?;
+        Some(shrink_u64_hex(value))
+    }
+
+    /// Shifts `self` left by `rhs` bits, returning `None` if any set bit is shifted out, or the
+    /// result is not a valid field element.
+    pub fn checked_shl(&self, rhs: Self) -> Option<Self> {
+        let shift = u32::try_from(rhs.as_int()).ok()?;
+        let value = self.as_int().checked_shl(shift)?;
+        if value >> shift != self.as_int() || value >= crate::FIELD_MODULUS {
+            return None;
+        }
+        Some(shrink_u64_hex(value))
+    }
+
+    /// Shifts `self` right by `rhs` bits, returning `None` if `rhs` is not less than 64.
+    pub fn checked_shr(&self, rhs: Self) -> Option<Self> {
+        let shift = u32::try_from(rhs.as_int()).ok()?;
+        let value = self.as_int().checked_shr(shift)?;
+        Some(shrink_u64_hex(value))
+    }
+
+    pub fn checked_bitand(&self, rhs: Self) -> Option<Self> {
+        Some(shrink_u64_hex(self.as_int() & rhs.as_int()))
+    }
+
+    pub fn checked_bitor(&self, rhs: Self) -> Option<Self> {
+        let value = self.as_int() | rhs.as_int();
+        if value >= crate::FIELD_MODULUS {
+            return None;
+        }
+        Some(shrink_u64_hex(value))
+    }
+
+    pub fn checked_bitxor(&self, rhs: Self) -> Option<Self> {
+        let value = self.as_int() ^ rhs.as_int();
+        if value >= crate::FIELD_MODULUS {
+            return None;
+        }
+        Some(shrink_u64_hex(value))
+    }
 }
 
 impl core::ops::Add<IntValue> for IntValue {
@@ -8,7 +8,7 @@ use alloc::{
 use miden_debug_types::{SourceFile, SourceManager, SourceSpan, Span, Spanned};
 use miden_utils_diagnostics::{Diagnostic, Severity};
 
-use super::{SemanticAnalysisError, SyntaxError};
+use super::{Lint, LintLevel, Lints, SemanticAnalysisError, SyntaxError};
 use crate::ast::{
     constants::{ConstEvalError, eval::CachedConstantValue},
     *,
@@ -19,11 +19,16 @@ pub struct AnalysisContext {
     constants: BTreeMap<Ident, Constant>,
     cached_constant_values: BTreeMap<Ident, ConstantValue>,
     imported: BTreeSet<Ident>,
+    /// Whether the module declares wildcard imports, which may import any undefined name.
+    has_glob_imports: bool,
     procedures: BTreeSet<ProcedureName>,
     errors: Vec<SemanticAnalysisError>,
     source_file: Arc<SourceFile>,
     source_manager: Arc<dyn SourceManager>,
     warnings_as_errors: bool,
+    lints: Lints,
+    /// Set when a finding of a denied lint is reported
+    denied: bool,
 }
 
 impl constants::ConstEnvironment for AnalysisContext {
@@ -42,7 +47,7 @@ impl constants::ConstEnvironment for AnalysisContext {
             Ok(Some(CachedConstantValue::Hit(value)))
         } else if let Some(constant) = self.constants.get(name) {
             Ok(Some(CachedConstantValue::Miss(&constant.value)))
-        } else if self.imported.contains(name) {
+        } else if self.imported.contains(name) || self.has_glob_imports {
             // We don't have the definition available yet
             Ok(None)
         } else {
@@ -84,11 +89,14 @@ impl AnalysisContext {
             constants: Default::default(),
             cached_constant_values: Default::default(),
             imported: Default::default(),
+            has_glob_imports: false,
             procedures: Default::default(),
             errors: Default::default(),
             source_file,
             source_manager,
             warnings_as_errors: false,
+            lints: Default::default(),
+            denied: false,
         }
     }
 
@@ -101,6 +109,17 @@ impl AnalysisContext {
         self.warnings_as_errors
     }
 
+    /// Sets the levels at which lints are reported.
+    pub fn set_lints(&mut self, lints: Lints) {
+        self.lints = lints;
+    }
+
+    /// Returns true if `lint` is checked, i.e. if it is not allowed.
+    #[inline]
+    pub fn is_lint_enabled(&self, lint: Lint) -> bool {
+        self.lints.is_enabled(lint)
+    }
+
     #[inline(always)]
     pub fn source_manager(&self) -> Arc<dyn SourceManager> {
         self.source_manager.clone()
@@ -114,6 +133,14 @@ impl AnalysisContext {
         self.imported.insert(name);
     }
 
+    /// Registers that the module declares a wildcard import.
+    ///
+    /// The names imported by wildcard imports are only known to the linker, so names which are not
+    /// defined by the module are assumed to be imported, rather than reported as undefined.
+    pub fn register_glob_import(&mut self) {
+        self.has_glob_imports = true;
+    }
+
     /// Define a new constant `constant`
     ///
     /// Returns `Err` if a constant with the same name is already defined
@@ -185,12 +212,29 @@ impl AnalysisContext {
         }
     }
 
+    /// Get the definition of the constant named `name`, if it is defined by the current module
+    pub fn get_constant_definition(&self, name: &Ident) -> Option<&Constant> {
+        self.constants.get(name)
+    }
+
     pub fn error(&mut self, diagnostic: SemanticAnalysisError) {
         self.errors.push(diagnostic);
     }
 
+    /// Reports `diagnostic`, a finding of `lint`, according to the level configured for `lint`.
+    pub fn lint(&mut self, lint: Lint, diagnostic: SemanticAnalysisError) {
+        match self.lints.level(lint) {
+            LintLevel::Allow => (),
+            LintLevel::Warn => self.errors.push(diagnostic),
+            LintLevel::Deny => {
+                self.denied = true;
+                self.errors.push(diagnostic);
+            },
+        }
+    }
+
     pub fn has_errors(&self) -> bool {
-        if self.warnings_as_errors() {
+        if self.warnings_as_errors() || self.denied {
             return !self.errors.is_empty();
         }
         self.errors
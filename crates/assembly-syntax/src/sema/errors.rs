@@ -305,6 +305,70 @@ pub enum SemanticAnalysisError {
         #[label]
         span: SourceSpan,
     },
+    #[error("unused procedure")]
+    #[diagnostic(
+        severity(Warning),
+        help(
+            "this private procedure is never invoked or referenced, and can be safely removed, \
+            use `@allow(unused_procedure)` if this is intentional"
+        )
+    )]
+    UnusedProcedure {
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("macro parameter shadows a constant")]
+    #[diagnostic(
+        severity(Warning),
+        help("references to this name in the body of the macro refer to the parameter instead")
+    )]
+    ShadowedConstant {
+        #[label("this parameter...")]
+        span: SourceSpan,
+        #[label("...shadows this constant")]
+        constant: SourceSpan,
+    },
+    #[error("advice value is dropped without being used")]
+    #[diagnostic(
+        severity(Warning),
+        help(
+            "values pushed from the advice stack are usually consumed, \
+            use `@allow(dropped_advice_value)` if this is intentional"
+        )
+    )]
+    DroppedAdviceValue {
+        #[label("this drops the value...")]
+        span: SourceSpan,
+        #[label("...pushed from the advice stack here")]
+        push: SourceSpan,
+    },
+    #[error("u32 arithmetic on an unchecked advice value")]
+    #[diagnostic(
+        severity(Warning),
+        help(
+            "values pushed from the advice stack are not known to be valid u32 values, \
+            use `u32assert` to check them first, or `@allow(unchecked_u32_arithmetic)` if this is \
+            intentional"
+        )
+    )]
+    UncheckedU32Arithmetic {
+        #[label("this operand of a u32 instruction...")]
+        span: SourceSpan,
+        #[label("...was pushed from the advice stack without being checked")]
+        push: SourceSpan,
+    },
+    #[error("unknown lint")]
+    #[diagnostic(
+        severity(Warning),
+        help(
+            "valid lints are: unused_import, unused_procedure, shadowed_constant, \
+            dropped_advice_value, and unchecked_u32_arithmetic"
+        )
+    )]
+    UnknownLint {
+        #[label]
+        span: SourceSpan,
+    },
     #[error("advmap key already defined")]
     #[diagnostic()]
     AdvMapKeyAlreadyDefined {
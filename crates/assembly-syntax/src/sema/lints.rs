@@ -0,0 +1,126 @@
+use alloc::{collections::BTreeMap, string::String};
+use core::{fmt, str::FromStr};
+
+// LINT
+// ================================================================================================
+
+/// A lint is a check for code which is valid, but likely to be a mistake.
+///
+/// The findings of a lint are reported as warning diagnostics, unless the lint is configured to be
+/// allowed (in which case nothing is reported), or denied (in which case the findings cause
+/// semantic analysis to fail). See [Lints] for details.
+///
+/// The findings of a lint within a procedure can be suppressed by annotating the procedure with
+/// `@allow(<lint>, ..)`, where `<lint>` is the name of the lint, e.g. `@allow(unused_procedure)`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Lint {
+    /// An import which is never used.
+    UnusedImport,
+    /// A private procedure which is never invoked, nor referenced, by the module defining it.
+    UnusedProcedure,
+    /// A macro parameter with the same name as a constant of the module, which the parameter
+    /// shadows in the body of the macro.
+    ShadowedConstant,
+    /// A value pushed from the advice stack, which is immediately dropped.
+    DroppedAdviceValue,
+    /// A u32 arithmetic instruction applied directly to a value pushed from the advice stack, i.e.
+    /// to a value which is not known to be a valid u32.
+    UncheckedU32Arithmetic,
+}
+
+impl Lint {
+    /// All of the lints known to the assembler.
+    pub const ALL: [Self; 5] = [
+        Self::UnusedImport,
+        Self::UnusedProcedure,
+        Self::ShadowedConstant,
+        Self::DroppedAdviceValue,
+        Self::UncheckedU32Arithmetic,
+    ];
+
+    /// Returns the name of this lint, as used in `@allow(..)` attributes.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::UnusedImport => "unused_import",
+            Self::UnusedProcedure => "unused_procedure",
+            Self::ShadowedConstant => "shadowed_constant",
+            Self::DroppedAdviceValue => "dropped_advice_value",
+            Self::UncheckedU32Arithmetic => "unchecked_u32_arithmetic",
+        }
+    }
+
+    /// Returns the level of this lint, when not configured otherwise.
+    ///
+    /// The lints on values pushed from the advice stack are heuristics, which are prone to false
+    /// positives, so they default to [LintLevel::Allow], i.e. checking them is opt-in.
+    pub const fn default_level(&self) -> LintLevel {
+        match self {
+            Self::UnusedImport | Self::UnusedProcedure | Self::ShadowedConstant => LintLevel::Warn,
+            Self::DroppedAdviceValue | Self::UncheckedU32Arithmetic => LintLevel::Allow,
+        }
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Lint {
+    type Err = UnknownLintError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|lint| lint.name() == name)
+            .ok_or_else(|| UnknownLintError(name.into()))
+    }
+}
+
+/// The error returned when parsing the name of a [Lint] which does not exist.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("unknown lint '{0}'")]
+pub struct UnknownLintError(String);
+
+// LINT LEVEL
+// ================================================================================================
+
+/// Determines how the findings of a [Lint] are reported.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintLevel {
+    /// The lint is not checked.
+    Allow,
+    /// The findings of the lint are reported as warnings.
+    Warn,
+    /// The findings of the lint are reported as warnings, and cause semantic analysis to fail.
+    Deny,
+}
+
+// LINTS
+// ================================================================================================
+
+/// The levels at which each [Lint] is reported.
+///
+/// Lints whose level is not set explicitly are reported at their [Lint::default_level].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Lints {
+    levels: BTreeMap<Lint, LintLevel>,
+}
+
+impl Lints {
+    /// Sets the level at which `lint` is reported.
+    pub fn set_level(&mut self, lint: Lint, level: LintLevel) {
+        self.levels.insert(lint, level);
+    }
+
+    /// Returns the level at which `lint` is reported.
+    pub fn level(&self, lint: Lint) -> LintLevel {
+        self.levels.get(&lint).copied().unwrap_or_else(|| lint.default_level())
+    }
+
+    /// Returns true if `lint` is checked, i.e. if it is not allowed.
+    pub fn is_enabled(&self, lint: Lint) -> bool {
+        self.level(lint) != LintLevel::Allow
+    }
+}
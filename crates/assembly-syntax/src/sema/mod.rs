@@ -1,5 +1,6 @@
 mod context;
 mod errors;
+mod lints;
 mod passes;
 #[cfg(test)]
 mod tests;
@@ -16,13 +17,17 @@ use miden_core::{Word, crypto::hash::Poseidon2};
 use miden_debug_types::{SourceFile, SourceManager, SourceSpan, Span, Spanned};
 use smallvec::SmallVec;
 
-use self::passes::{LocalInvokeTarget, VerifyInvokeTargets};
+use self::passes::{LintAdviceValues, LocalInvokeTarget, VerifyInvokeTargets};
 pub use self::{
     context::AnalysisContext,
     errors::{ExportedTypeUse, LimitKind, SemanticAnalysisError, SyntaxError},
+    lints::{Lint, LintLevel, Lints, UnknownLintError},
     passes::{ConstEvalVisitor, VerifyRepeatCounts},
 };
-use crate::{ast::*, parser::WordValue};
+use crate::{
+    ast::*,
+    parser::{IntValue, WordValue},
+};
 
 /// Constructs and validates a [Module], given the forms constituting the module body.
 ///
@@ -34,6 +39,8 @@ use crate::{ast::*, parser::WordValue};
 ///   * Constants referenced by name are replaced with the value of that constant.
 ///   * Calls to imported procedures are resolved concretely
 /// * Semantic analysis is performed on the module to validate it
+///
+/// Lints are reported at their default levels, see [analyze_with_lints] for configuring them.
 pub fn analyze(
     source: Arc<SourceFile>,
     kind: Option<ModuleKind>,
@@ -41,10 +48,37 @@ pub fn analyze(
     forms: Vec<Form>,
     warnings_as_errors: bool,
     source_manager: Arc<dyn SourceManager>,
+) -> Result<Box<Module>, SyntaxError> {
+    analyze_with_lints(
+        source,
+        kind,
+        path,
+        forms,
+        warnings_as_errors,
+        &Lints::default(),
+        Vec::new(),
+        source_manager,
+    )
+}
+
+/// This is like [analyze], but reports lints at the levels configured in `lints`.
+///
+/// Macros are expanded during parsing, so `macro_params` provides the names of the parameters of
+/// the macros declared by the module, which are checked for shadowing constants of the module.
+pub fn analyze_with_lints(
+    source: Arc<SourceFile>,
+    kind: Option<ModuleKind>,
+    path: Option<&Path>,
+    forms: Vec<Form>,
+    warnings_as_errors: bool,
+    lints: &Lints,
+    macro_params: Vec<Ident>,
+    source_manager: Arc<dyn SourceManager>,
 ) -> Result<Box<Module>, SyntaxError> {
     log::debug!(target: "sema", "starting semantic analysis for '{}' (kind = {kind:?})", path.map(Path::as_str).unwrap_or("None"));
     let mut analyzer = AnalysisContext::new(source.clone(), source_manager);
     analyzer.set_warnings_as_errors(warnings_as_errors);
+    analyzer.set_lints(lints.clone());
 
     let expected_path = match path {
         Some(path) => Some(normalize_namespace_path(path).map_err(|err| SyntaxError {
@@ -184,6 +218,10 @@ pub fn analyze(
                 namespace_allowed = false;
                 add_advice_map_entry(&mut module, entry.with_docs(docs.take()), &mut analyzer);
             },
+            Form::DataSegment(data_segment) => {
+                namespace_allowed = false;
+                add_data_segment(&mut module, data_segment.with_docs(docs.take()), &mut analyzer);
+            },
         }
     }
 
@@ -224,6 +262,18 @@ pub fn analyze(
                 _ => (),
             }
         }
+        for import in module.glob_imports().filter(|import| import.visibility().is_public()) {
+            match actual_kind {
+                ModuleKind::Executable => {
+                    analyzer.error(SemanticAnalysisError::UnexpectedExport { span: import.span() });
+                },
+                ModuleKind::Kernel => {
+                    analyzer
+                        .error(SemanticAnalysisError::ReexportFromKernel { span: import.span() });
+                },
+                ModuleKind::Library => (),
+            }
+        }
         for import in module.imports() {
             match import {
                 Import::Module(import) if import.visibility().is_public() => {
@@ -257,6 +307,19 @@ pub fn analyze(
             .clone();
     }
 
+    // Check for macro parameters shadowing constants now that all constants have been discovered
+    if analyzer.is_lint_enabled(Lint::ShadowedConstant) {
+        for param in macro_params {
+            if let Some(constant) = analyzer.get_constant_definition(&param) {
+                let constant = constant.span;
+                analyzer.lint(
+                    Lint::ShadowedConstant,
+                    SemanticAnalysisError::ShadowedConstant { span: param.span(), constant },
+                );
+            }
+        }
+    }
+
     // Define enums now that all constant declarations have been discovered
     for mut ty in enums {
         for variant in ty.variants_mut() {
@@ -282,7 +345,10 @@ pub fn analyze(
     // Check unused imports
     for import in module.imports() {
         if !import.is_used() {
-            analyzer.error(SemanticAnalysisError::UnusedImport { span: import.unused_span() });
+            analyzer.lint(
+                Lint::UnusedImport,
+                SemanticAnalysisError::UnusedImport { span: import.unused_span() },
+            );
         }
     }
 
@@ -447,6 +513,7 @@ fn visit_items(module: &mut Module, analyzer: &mut AnalysisContext) {
         }),
     );
     let mut used_aliases = BTreeSet::default();
+    let mut maybe_unused = Vec::new();
     let mut items = VecDeque::from(module.take_items());
     while let Some(item) = items.pop_front() {
         match item {
@@ -456,6 +523,14 @@ fn visit_items(module: &mut Module, analyzer: &mut AnalysisContext) {
                     procedure.set_syscall(true);
                 }
 
+                let allowed = allowed_lints(&procedure, analyzer);
+                if !procedure.visibility().is_public()
+                    && !procedure.is_entrypoint()
+                    && !allowed.contains(&Lint::UnusedProcedure)
+                {
+                    maybe_unused.push(procedure.name().clone());
+                }
+
                 // Evaluate all named immediates to their concrete values
                 log::debug!(target: "const-eval", "visiting procedure {}", procedure.name());
                 {
@@ -492,6 +567,15 @@ fn visit_items(module: &mut Module, analyzer: &mut AnalysisContext) {
                     );
                     let _ = visitor.visit_mut_procedure(&mut procedure);
                 }
+
+                // Check how values pushed from the advice stack are used, unless allowed
+                if analyzer.is_lint_enabled(Lint::DroppedAdviceValue)
+                    || analyzer.is_lint_enabled(Lint::UncheckedU32Arithmetic)
+                {
+                    let mut visitor = LintAdviceValues::new(analyzer, &allowed);
+                    let _ = visitor.visit_procedure(&procedure);
+                }
+
                 if let Err(err) = module.push_export(Item::Procedure(procedure)) {
                     analyzer.error(err);
                 }
@@ -531,6 +615,10 @@ fn visit_items(module: &mut Module, analyzer: &mut AnalysisContext) {
         }
     }
 
+    if analyzer.is_lint_enabled(Lint::UnusedProcedure) {
+        lint_unused_procedures(module, maybe_unused, analyzer);
+    }
+
     for import in module.imports_mut() {
         if import.is_used() || !used_aliases.contains(import.local_name().as_str()) {
             continue;
@@ -542,6 +630,68 @@ fn visit_items(module: &mut Module, analyzer: &mut AnalysisContext) {
     }
 }
 
+/// Returns the lints whose findings are suppressed within `procedure` by an `@allow(..)` attribute.
+fn allowed_lints(procedure: &Procedure, analyzer: &mut AnalysisContext) -> BTreeSet<Lint> {
+    let mut allowed = BTreeSet::default();
+    let Some(Attribute::List(list)) = procedure.get_attribute("allow") else {
+        return allowed;
+    };
+    for item in list.items.iter() {
+        let lint = match item {
+            MetaExpr::Ident(name) | MetaExpr::String(name) => name.as_str().parse::<Lint>().ok(),
+            MetaExpr::Int(_) | MetaExpr::Word(_) => None,
+        };
+        match lint {
+            Some(lint) => {
+                allowed.insert(lint);
+            },
+            None => analyzer.error(SemanticAnalysisError::UnknownLint { span: item.span() }),
+        }
+    }
+    allowed
+}
+
+/// Reports the procedures in `candidates` which are never invoked, nor referenced, by `module`.
+fn lint_unused_procedures(
+    module: &Module,
+    candidates: Vec<ProcedureName>,
+    analyzer: &mut AnalysisContext,
+) {
+    let mut referenced = BTreeSet::<&str>::default();
+    for procedure in module.procedures() {
+        for invoke in procedure.invoked() {
+            let name = match &invoke.target {
+                InvocationTarget::Symbol(name) => name.as_str(),
+                InvocationTarget::Path(path)
+                    if path.parent().is_some_and(|parent| parent == module.path()) =>
+                {
+                    path.last().unwrap_or_default()
+                },
+                InvocationTarget::Path(_) | InvocationTarget::MastRoot(_) => continue,
+            };
+            referenced.insert(name);
+        }
+    }
+    // Procedures imported from the module itself are referenced through the import
+    for import in module.imports() {
+        if let Import::Item(import) = import {
+            let path = import.module_path();
+            if path.as_str() == "self" || *path == module.path() {
+                referenced.insert(import.source_name().as_str());
+            }
+        }
+    }
+
+    for name in candidates {
+        if !referenced.contains(name.as_str()) {
+            analyzer.lint(
+                Lint::UnusedProcedure,
+                SemanticAnalysisError::UnusedProcedure { span: name.span() },
+            );
+        }
+    }
+}
+
 fn define_import(
     import: ImportDecl,
     module: &mut Module,
@@ -590,6 +740,13 @@ fn define_import(
                 context.register_imported_name(name);
             }
         },
+        ImportDecl::Glob(import) => {
+            if let Err(err) = module.define_glob_import(import) {
+                context.error(err);
+                context.has_failed()?;
+            }
+            context.register_glob_import();
+        },
     }
 
     Ok(())
@@ -653,6 +810,25 @@ fn define_procedure(
     Ok(())
 }
 
+/// Inserts the words of a data segment in the Advice Map, keyed by their hash, and defines the
+/// constants holding the key and the number of words of the segment.
+fn add_data_segment(module: &mut Module, data_segment: DataSegment, context: &mut AnalysisContext) {
+    let len_name = data_segment.len_constant_name();
+    let len = data_segment.words.len() as u32;
+    let value = data_segment.words.iter().flat_map(|word| word.inner().0).collect::<Vec<_>>();
+    let mut entry = AdviceMapEntry::new(data_segment.span, data_segment.name, None, value);
+    entry.docs = data_segment.docs;
+    add_advice_map_entry(module, entry, context);
+
+    let cst = Constant::new(
+        data_segment.span,
+        Visibility::Private,
+        len_name,
+        ConstantExpr::Int(Span::new(data_segment.span, IntValue::from(len))),
+    );
+    context.define_constant(module, cst);
+}
+
 /// Inserts a new entry in the Advice Map and defines a constant corresposnding to the entry's
 /// key.
 fn add_advice_map_entry(module: &mut Module, entry: AdviceMapEntry, context: &mut AnalysisContext) {
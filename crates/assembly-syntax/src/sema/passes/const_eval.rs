@@ -57,14 +57,20 @@ where
                         | CachedConstantValue::Miss(ConstantExpr::Int(value)),
                     )) => *value,
                     Ok(Some(CachedConstantValue::Miss(
-                        expr @ (ConstantExpr::Var(_) | ConstantExpr::BinaryOp { .. }),
+                        expr @ (ConstantExpr::Var(_)
+                        | ConstantExpr::BinaryOp { .. }
+                        | ConstantExpr::MakeWord { .. }),
                     ))) => {
                         // A reference to another constant was used, try to evaluate the expression
                         let expr = expr.clone();
                         match constants::eval::expr(&expr, self.env) {
                             Ok(ConstantExpr::Int(value)) => value,
                             // Unable to evaluate in the current context
-                            Ok(ConstantExpr::Var(_) | ConstantExpr::BinaryOp { .. }) => {
+                            Ok(
+                                ConstantExpr::Var(_)
+                                | ConstantExpr::BinaryOp { .. }
+                                | ConstantExpr::MakeWord { .. },
+                            ) => {
                                 return ControlFlow::Continue(());
                             },
                             Ok(_) => {
@@ -263,7 +269,9 @@ where
                         *imm = Immediate::Value(Span::new(span, event_id));
                     },
                     Ok(Some(CachedConstantValue::Miss(
-                        expr @ (ConstantExpr::Var(_) | ConstantExpr::BinaryOp { .. }),
+                        expr @ (ConstantExpr::Var(_)
+                        | ConstantExpr::BinaryOp { .. }
+                        | ConstantExpr::MakeWord { .. }),
                     ))) => {
                         // A reference to another constant was used, try to evaluate the expression
                         let expr = expr.clone();
@@ -283,7 +291,11 @@ where
                                 *imm = Immediate::Value(Span::new(span, event_id));
                             },
                             // Unable to evaluate in the current context
-                            Ok(ConstantExpr::Var(_) | ConstantExpr::BinaryOp { .. }) => (),
+                            Ok(
+                                ConstantExpr::Var(_)
+                                | ConstantExpr::BinaryOp { .. }
+                                | ConstantExpr::MakeWord { .. },
+                            ) => (),
                             Ok(_) => {
                                 self.errors.push(
                                     ConstEvalError::InvalidConstant {
@@ -367,7 +379,9 @@ where
                         },
                     },
                     Ok(Some(CachedConstantValue::Miss(
-                        expr @ (ConstantExpr::Var(_) | ConstantExpr::BinaryOp { .. }),
+                        expr @ (ConstantExpr::Var(_)
+                        | ConstantExpr::BinaryOp { .. }
+                        | ConstantExpr::MakeWord { .. }),
                     ))) => {
                         // A reference to another constant was used, try to evaluate the expression
                         let expr = expr.clone();
@@ -405,7 +419,11 @@ where
                                 ));
                             },
                             // Unable to evaluate in the current context
-                            Ok(ConstantExpr::Var(_) | ConstantExpr::BinaryOp { .. }) => (),
+                            Ok(
+                                ConstantExpr::Var(_)
+                                | ConstantExpr::BinaryOp { .. }
+                                | ConstantExpr::MakeWord { .. },
+                            ) => (),
                             Ok(_) => {
                                 self.errors.push(
                                     ConstEvalError::InvalidConstant {
@@ -0,0 +1,106 @@
+use alloc::collections::BTreeSet;
+use core::ops::ControlFlow;
+
+use miden_debug_types::SourceSpan;
+
+use crate::{
+    ast::{Block, Instruction, Op, Visit, visit},
+    sema::{AnalysisContext, Lint, SemanticAnalysisError},
+};
+
+/// This visitor checks how the values pushed from the advice stack are used by the instruction
+/// which immediately follows the push, reporting the findings of the following lints:
+///
+/// * [Lint::DroppedAdviceValue], when the values are dropped.
+/// * [Lint::UncheckedU32Arithmetic], when the values are operands of u32 arithmetic.
+///
+/// Only instructions in the same block as the push are considered, as the values may be consumed
+/// in different ways on different control flow paths.
+pub(crate) struct LintAdviceValues<'a> {
+    analyzer: &'a mut AnalysisContext,
+    allowed: &'a BTreeSet<Lint>,
+}
+
+impl<'a> LintAdviceValues<'a> {
+    pub(crate) fn new(analyzer: &'a mut AnalysisContext, allowed: &'a BTreeSet<Lint>) -> Self {
+        Self { analyzer, allowed }
+    }
+
+    fn lint(&mut self, lint: Lint, diagnostic: SemanticAnalysisError) {
+        if !self.allowed.contains(&lint) {
+            self.analyzer.lint(lint, diagnostic);
+        }
+    }
+}
+
+impl Visit for LintAdviceValues<'_> {
+    fn visit_block(&mut self, block: &Block) -> ControlFlow<()> {
+        let mut advice_push: Option<SourceSpan> = None;
+        for op in block.iter() {
+            let Op::Inst(inst) = op else {
+                advice_push = None;
+                continue;
+            };
+            if let Some(push) = advice_push {
+                let span = inst.span();
+                match inst.inner() {
+                    Instruction::Drop | Instruction::DropW => {
+                        self.lint(
+                            Lint::DroppedAdviceValue,
+                            SemanticAnalysisError::DroppedAdviceValue { span, push },
+                        );
+                    },
+                    inst if is_u32_arithmetic(inst) => {
+                        self.lint(
+                            Lint::UncheckedU32Arithmetic,
+                            SemanticAnalysisError::UncheckedU32Arithmetic { span, push },
+                        );
+                    },
+                    _ => (),
+                }
+            }
+            advice_push = matches!(
+                inst.inner(),
+                Instruction::AdvPush | Instruction::AdvPushW | Instruction::AdvLoadW
+            )
+            .then(|| inst.span());
+        }
+
+        visit::visit_block(self, block)
+    }
+}
+
+/// Returns true if `inst` is a u32 arithmetic instruction, i.e. one whose result is undefined
+/// rather than an error when its operands are not valid u32 values.
+fn is_u32_arithmetic(inst: &Instruction) -> bool {
+    use Instruction::*;
+
+    matches!(
+        inst,
+        U32WrappingAdd
+            | U32WrappingAddImm(_)
+            | U32OverflowingAdd
+            | U32OverflowingAddImm(_)
+            | U32WideningAdd
+            | U32WideningAddImm(_)
+            | U32OverflowingAdd3
+            | U32WideningAdd3
+            | U32WrappingAdd3
+            | U32WrappingSub
+            | U32WrappingSubImm(_)
+            | U32OverflowingSub
+            | U32OverflowingSubImm(_)
+            | U32WrappingMul
+            | U32WrappingMulImm(_)
+            | U32WideningMul
+            | U32WideningMulImm(_)
+            | U32WideningMadd
+            | U32WrappingMadd
+            | U32Div
+            | U32DivImm(_)
+            | U32Mod
+            | U32ModImm(_)
+            | U32DivMod
+            | U32DivModImm(_)
+    )
+}
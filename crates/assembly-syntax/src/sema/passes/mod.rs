@@ -1,6 +1,10 @@
 mod const_eval;
+mod lint_advice;
 mod verify_invoke;
 mod verify_repeat;
 
-pub(super) use self::verify_invoke::{LocalInvokeTarget, VerifyInvokeTargets};
 pub use self::{const_eval::ConstEvalVisitor, verify_repeat::VerifyRepeatCounts};
+pub(super) use self::{
+    lint_advice::LintAdviceValues,
+    verify_invoke::{LocalInvokeTarget, VerifyInvokeTargets},
+};
@@ -123,9 +123,12 @@ impl VerifyInvokeTargets<'_> {
         }
 
         let Some(item) = self.locals.get(name).cloned() else {
-            self.analyzer.error(SemanticAnalysisError::SymbolResolutionError(Box::new(
-                SymbolResolutionError::undefined(span, &self.analyzer.source_manager()),
-            )));
+            // The name may be imported by a wildcard import, which only the linker can tell
+            if !self.module.has_glob_imports() {
+                self.analyzer.error(SemanticAnalysisError::SymbolResolutionError(Box::new(
+                    SymbolResolutionError::undefined(span, &self.analyzer.source_manager()),
+                )));
+            }
             return ControlFlow::Continue(());
         };
 
@@ -4,16 +4,16 @@ use alloc::{
     vec::Vec,
 };
 
-use miden_debug_types::{Span, Spanned};
+use miden_debug_types::{SourceSpan, Span, Spanned};
 
 use crate::{
-    MAX_REPEAT_COUNT, Path,
+    MAX_REPEAT_COUNT, ParseOptions, Path,
     ast::{
         Constant, ConstantExpr, ImportKind, Item, Module, ModuleKind, SymbolResolutionError,
         TypeAlias, TypeExpr, Visibility, constants::ConstEvalError, types,
     },
     diagnostics::reporting::PrintDiagnostic,
-    sema::{SemanticAnalysisError, SyntaxError},
+    sema::{Lint, LintLevel, SemanticAnalysisError, SyntaxError},
     testing::SyntaxTestContext,
 };
 
@@ -105,6 +105,35 @@ fn unused_import_slices(error: &miden_utils_diagnostics::Report) -> Vec<String>
         .collect()
 }
 
+fn parse_module_with_options(
+    context: &SyntaxTestContext,
+    source: &str,
+    options: &ParseOptions,
+) -> Result<alloc::boxed::Box<Module>, miden_utils_diagnostics::Report> {
+    let mut parser = Module::parser(None);
+    parser.set_options(options);
+    parser.parse_str(None, source, context.source_manager())
+}
+
+fn diagnostic_slices(
+    error: &miden_utils_diagnostics::Report,
+    span_of: impl Fn(&SemanticAnalysisError) -> Option<SourceSpan>,
+) -> Vec<String> {
+    let syntax_error = syntax_error(error);
+    syntax_error
+        .errors
+        .iter()
+        .filter_map(span_of)
+        .map(|span| {
+            syntax_error
+                .source_file
+                .source_slice(span)
+                .expect("diagnostic span should be valid")
+                .to_string()
+        })
+        .collect()
+}
+
 fn assert_import(module: &Module, name: &str, kind: ImportKind, used: bool) {
     let import = module
         .imports()
@@ -946,3 +975,152 @@ enum DUP : u8 {
         .expect_err("expected symbol conflict when enum variant matches enum name");
     assert_symbol_conflict(&error, "DUP");
 }
+
+#[test]
+fn sema_lint_unused_private_procedure_warns_unless_allowed() {
+    let context = SyntaxTestContext::new().with_warnings_as_errors(true);
+    let error = context
+        .parse_module(
+            "
+namespace test
+
+proc unused
+    nop
+end
+
+@allow(unused_procedure)
+proc allowed
+    nop
+end
+
+proc used
+    nop
+end
+
+proc reexported
+    nop
+end
+
+pub use {reexported as exposed} from self
+
+pub proc entry
+    exec.used
+end
+",
+        )
+        .expect_err("unused private procedure should warn");
+
+    let unused = diagnostic_slices(&error, |err| match err {
+        SemanticAnalysisError::UnusedProcedure { span } => Some(*span),
+        _ => None,
+    });
+    assert_eq!(unused, vec!["unused"]);
+}
+
+#[test]
+fn sema_lint_macro_parameter_shadowing_constant_warns() {
+    let context = SyntaxTestContext::new().with_warnings_as_errors(true);
+    let error = context
+        .parse_module(
+            "
+namespace test
+
+const N = 1
+
+macro.push_n(N)
+    push.N
+end
+
+pub proc entry
+    macro_exec.push_n(2)
+end
+",
+        )
+        .expect_err("macro parameter shadowing a constant should warn");
+
+    let shadowed = diagnostic_slices(&error, |err| match err {
+        SemanticAnalysisError::ShadowedConstant { span, .. } => Some(*span),
+        _ => None,
+    });
+    assert_eq!(shadowed, vec!["N"]);
+}
+
+#[test]
+fn sema_lint_advice_value_lints_are_opt_in() {
+    let context = SyntaxTestContext::new().with_warnings_as_errors(true);
+    let source = "
+namespace test
+
+pub proc entry
+    adv_push drop
+    adv_push u32wrapping_add
+    adv_push u32assert u32wrapping_add
+end
+
+@allow(dropped_advice_value, unchecked_u32_arithmetic)
+pub proc allowed
+    adv_push drop
+    adv_push u32wrapping_add
+end
+";
+    context
+        .parse_module(source)
+        .expect("advice value lints should be allowed by default");
+
+    let options = ParseOptions::default()
+        .with_lint_level(Lint::DroppedAdviceValue, LintLevel::Deny)
+        .with_lint_level(Lint::UncheckedU32Arithmetic, LintLevel::Deny);
+    let error = parse_module_with_options(&context, source, &options)
+        .expect_err("denied advice value lints should fail analysis");
+
+    let dropped = diagnostic_slices(&error, |err| match err {
+        SemanticAnalysisError::DroppedAdviceValue { span, .. } => Some(*span),
+        _ => None,
+    });
+    let unchecked = diagnostic_slices(&error, |err| match err {
+        SemanticAnalysisError::UncheckedU32Arithmetic { span, .. } => Some(*span),
+        _ => None,
+    });
+    assert_eq!(dropped, vec!["drop"]);
+    assert_eq!(unchecked, vec!["u32wrapping_add"]);
+}
+
+#[test]
+fn sema_lint_allowed_lint_is_not_reported() {
+    let context = SyntaxTestContext::new().with_warnings_as_errors(true);
+    let options = ParseOptions::default()
+        .with_warnings_as_errors(true)
+        .with_lint_level(Lint::UnusedImport, LintLevel::Allow);
+    parse_module_with_options(
+        &context,
+        "
+namespace test
+use some::module as sm
+",
+        &options,
+    )
+    .expect("allowed lint should not be reported");
+}
+
+#[test]
+fn sema_lint_unknown_lint_in_allow_attribute_warns() {
+    let context = SyntaxTestContext::new().with_warnings_as_errors(true);
+    let error = context
+        .parse_module(
+            "
+namespace test
+
+@allow(unused_procedure, no_such_lint)
+proc unused
+    nop
+end
+",
+        )
+        .expect_err("unknown lint should warn");
+
+    let unknown = diagnostic_slices(&error, |err| match err {
+        SemanticAnalysisError::UnknownLint { span } => Some(*span),
+        _ => None,
+    });
+    assert_eq!(unknown, vec!["no_such_lint"]);
+}
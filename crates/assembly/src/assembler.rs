@@ -1,6 +1,9 @@
+mod compile_cache;
+mod dead_code;
 pub(super) mod debuginfo;
 pub(crate) mod error;
 mod product;
+mod program_builder;
 
 use alloc::{
     boxed::Box,
@@ -12,43 +15,53 @@ use alloc::{
 
 use debuginfo::DebugInfoSections;
 use miden_assembly_syntax::{
-    ExportedTypeUse, MAX_REPEAT_COUNT, Parse, SemanticAnalysisError,
+    ExportedTypeUse, Lint, LintLevel, MAX_REPEAT_COUNT, Parse, ParseOptions, SemanticAnalysisError,
     ast::{
         self, AttributeSet, Ident, InvocationTarget, InvokeKind, ItemIndex, ModuleKind,
         SymbolResolution, Visibility, types::FunctionType,
     },
-    debuginfo::{DefaultSourceManager, SourceManager, SourceSpan, Spanned},
-    diagnostics::{IntoDiagnostic, RelatedLabel, Report},
+    debuginfo::{DefaultSourceManager, Location, SourceManager, SourceSpan, Spanned},
+    diagnostics::{IntoDiagnostic, RelatedError, RelatedLabel, Report},
     module::ItemInfo,
 };
 use miden_core::{
     WORD_SIZE, Word,
     mast::{MastNodeExt, MastNodeId},
     operations::{AssemblyOp, Operation},
-    program::KernelDescriptor,
+    program::{KernelDescriptor, Program},
     serde::Serializable,
 };
 use miden_mast_package::{
-    ConstantExport, Package, PackageDebugInfoError, PackageExport, PackageId, PackageModule,
-    PackageSubmodule, ProcedureExport, Section, SectionId, TypeExport,
-    debug_info::DebugSourceNodeId,
+    ConstantExport, Dependency, ErrorTable, Package, PackageDebugInfoError, PackageExport,
+    PackageId, PackageModule, PackageSubmodule, ProcedureExport, Section, SectionId, SourceMap,
+    TypeExport, Version, debug_info::DebugSourceNodeId,
 };
 use miden_project::{Linkage, TargetType};
 
-use self::{error::AssemblerError, product::AssemblyProduct};
+pub use self::{
+    compile_cache::CompileCache,
+    dead_code::DeadCodeReport,
+    program_builder::{NodeHandle, ProgramBuilder, ProgramBuilderError},
+};
+use self::{
+    compile_cache::PendingCacheEntry,
+    error::AssemblerError,
+    product::{AssemblyProduct, build_error_table},
+};
 use crate::{
     GlobalItemIndex, ModuleIndex, Procedure, ProcedureContext,
     ast::Path,
     basic_block_builder::BasicBlockBuilder,
     fmp::{fmp_end_frame_sequence, fmp_initialization_sequence, fmp_start_frame_sequence},
     linker::{
-        Import, LinkLibrary, Linker, LinkerError, SymbolItem, SymbolResolutionContext,
-        SymbolResolver,
+        DeduplicatedForest, Import, LinkLibrary, Linker, LinkerError, SymbolItem,
+        SymbolResolutionContext, SymbolResolver,
     },
     mast_forest_builder::{
         MastForestBuilder, MastNodeRef, SourceDebugGraph, SourceNodeId, SourceNodeRef,
         StaticLibrary,
     },
+    peephole::OptimizationLevel,
 };
 
 /// Maximum allowed nesting of control-flow blocks during compilation.
@@ -150,9 +163,13 @@ impl PendingProcedureExport {
 /// need to specify a kernel. You will need to do so if any of your code needs to call into the
 /// kernel directly.
 ///
-/// * If a kernel is needed, you should construct an `Assembler` using [`Assembler::with_kernel`]
+/// * If a kernel is needed, you should construct an `Assembler` using [`Assembler::with_kernel`],
+///   or add it to an existing one using [`Assembler::with_kernel_from_package`]
 /// * Otherwise, you should construct an `Assembler` using [`Assembler::new`]
 ///
+/// The same program can also be assembled against several kernels at once using
+/// [`Assembler::assemble_program_for_kernels`].
+///
 /// <div class="warning">
 /// Programs compiled with an empty kernel cannot use the `syscall` instruction.
 /// </div>
@@ -176,12 +193,31 @@ pub struct Assembler {
     linker: Box<Linker>,
     /// The debug information gathered during assembly
     pub(super) debug_info: DebugInfoSections,
-    /// Whether to treat warning diagnostics as errors
-    warnings_as_errors: bool,
+    /// The options used when parsing modules from source, e.g. whether to treat warning
+    /// diagnostics as errors, and the features enabled for conditional compilation
+    parse_options: ParseOptions,
     /// Whether to preserve debug information in the assembled artifact.
     pub(super) emit_debug_info: bool,
     /// Whether to trim source file paths in debug information.
     pub(super) trim_paths: bool,
+    /// The cache of compiled procedures shared with other assemblers, if any.
+    compile_cache: Option<Arc<CompileCache>>,
+    /// The procedures compiled by the current assembly, to be added to the compile cache.
+    compile_cache_entries: Vec<PendingCacheEntry>,
+    /// Whether to remove the procedures which are unreachable from the assembled artifact.
+    dead_code_elimination: bool,
+    /// The optimizations applied to the assembled code.
+    optimization_level: OptimizationLevel,
+    /// The number of operation batches which disqualify a procedure from being inlined into its
+    /// callers, or `None` to use the default threshold.
+    inlining_threshold: Option<usize>,
+    /// The number of operations up to which `repeat` blocks are unrolled, or `None` if they are
+    /// not unrolled.
+    unroll_budget: Option<usize>,
+    /// Whether to produce a [`SourceMap`] of the assembled artifact.
+    emit_source_map: bool,
+    /// Whether to produce an [`ErrorTable`] of the assembled artifact.
+    emit_error_table: bool,
 }
 
 impl Default for Assembler {
@@ -192,9 +228,17 @@ impl Default for Assembler {
             source_manager,
             linker,
             debug_info: Default::default(),
-            warnings_as_errors: false,
+            parse_options: ParseOptions::default(),
             emit_debug_info: true,
             trim_paths: false,
+            compile_cache: None,
+            compile_cache_entries: Vec::new(),
+            dead_code_elimination: false,
+            optimization_level: OptimizationLevel::None,
+            inlining_threshold: None,
+            unroll_budget: None,
+            emit_source_map: false,
+            emit_error_table: false,
         }
     }
 }
@@ -209,9 +253,17 @@ impl Assembler {
             source_manager,
             linker,
             debug_info: Default::default(),
-            warnings_as_errors: false,
+            parse_options: ParseOptions::default(),
             emit_debug_info: true,
             trim_paths: false,
+            compile_cache: None,
+            compile_cache_entries: Vec::new(),
+            dead_code_elimination: false,
+            optimization_level: OptimizationLevel::None,
+            inlining_threshold: None,
+            unroll_budget: None,
+            emit_source_map: false,
+            emit_error_table: false,
         }
     }
 
@@ -232,7 +284,40 @@ impl Assembler {
     ///
     /// When true, any warning diagnostics that are emitted will be promoted to errors.
     pub fn with_warnings_as_errors(mut self, yes: bool) -> Self {
-        self.warnings_as_errors = yes;
+        self.parse_options.warnings_as_errors = yes;
+        self
+    }
+
+    /// Enables the feature `name` for conditional compilation of the modules parsed by this
+    /// assembler.
+    ///
+    /// Procedures and `begin` blocks annotated with `@cfg(...)` are only assembled when their
+    /// predicate holds with respect to the set of enabled features.
+    pub fn with_feature(mut self, name: impl Into<Arc<str>>) -> Self {
+        self.parse_options.features.insert(name.into());
+        self
+    }
+
+    /// Enables all of the given features for conditional compilation of the modules parsed by this
+    /// assembler.
+    ///
+    /// See [`Self::with_feature`] for details.
+    pub fn with_features<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Arc<str>>,
+    {
+        self.parse_options.features.extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets the level at which `lint` is reported for the modules parsed by this assembler.
+    ///
+    /// Findings of lints at [`LintLevel::Warn`] are reported as warnings, which are promoted to
+    /// errors when [`Self::with_warnings_as_errors`] is set, while findings of lints at
+    /// [`LintLevel::Deny`] are always errors.
+    pub fn with_lint_level(mut self, lint: Lint, level: LintLevel) -> Self {
+        self.parse_options.lints.set_level(lint, level);
         self
     }
 
@@ -242,6 +327,63 @@ impl Assembler {
         self.trim_paths = profile.should_trim_paths();
         self
     }
+
+    /// Sets the cache of compiled procedures used by this assembler.
+    ///
+    /// Procedures found in the cache are copied from previously assembled artifacts instead of
+    /// being compiled, and procedures compiled by this assembler are added to the cache. The same
+    /// cache can be shared by any number of assemblers; see [`CompileCache`] for details.
+    pub fn with_compile_cache(mut self, cache: Arc<CompileCache>) -> Self {
+        self.compile_cache = Some(cache);
+        self
+    }
+
+    /// Enables dead-code elimination.
+    ///
+    /// When enabled, procedures which are unreachable from the exports of an assembled library, or
+    /// from the entrypoint of an assembled program, are not included in the assembled artifact.
+    /// See [`DeadCodeReport`] for how reachability is determined, and
+    /// [`Self::assemble_library_with_dead_code_report`] to find out which procedures were removed.
+    pub fn with_dead_code_elimination(mut self) -> Self {
+        self.dead_code_elimination = true;
+        self
+    }
+
+    /// Sets the level of optimization applied to the code assembled by this assembler.
+    ///
+    /// See [`OptimizationLevel`] for the optimizations enabled at each level. Code is not
+    /// optimized by default.
+    pub fn with_optimization_level(mut self, level: OptimizationLevel) -> Self {
+        self.optimization_level = level;
+        self
+    }
+
+    /// Sets the size, in operation batches, from which procedures are no longer inlined into their
+    /// callers automatically.
+    ///
+    /// A procedure consisting of a single basic block smaller than this threshold is merged into
+    /// the basic blocks surrounding each `exec` of it, rather than being executed as a separate
+    /// MAST node. The default threshold is 32 batches; a threshold of 0 disables automatic
+    /// inlining. Procedures annotated with `@inline` are inlined regardless of their size.
+    pub fn with_inlining_threshold(mut self, num_op_batches: usize) -> Self {
+        self.inlining_threshold = Some(num_op_batches);
+        self
+    }
+
+    /// Enables unrolling of `repeat` blocks whose unrolled body is at most `num_ops` operations.
+    ///
+    /// The body of a `repeat.N` block is always emitted `N` times, but by default each iteration
+    /// is executed as a separate MAST subtree whenever the body contains control flow. Unrolling
+    /// splices the iterations into the enclosing body instead, so that the straight-line code
+    /// ending one iteration is merged with the code starting the next one, reducing the number of
+    /// control flow nodes the decoder executes. The size of an unrolled block is `N` times the
+    /// number of operations in the straight-line code at the top level of its body.
+    ///
+    /// Unrolling can be disabled for a `repeat` block by annotating it with `@no_unroll`.
+    pub fn with_unroll_budget(mut self, num_ops: usize) -> Self {
+        self.unroll_budget = Some(num_ops);
+        self
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -265,7 +407,9 @@ impl Assembler {
     ) -> Result<&mut Self, Report> {
         let modules = modules
             .into_iter()
-            .map(|module| module.parse(self.warnings_as_errors, self.source_manager.clone()))
+            .map(|module| {
+                module.parse_with_options(&self.parse_options, self.source_manager.clone())
+            })
             .collect::<Result<Vec<_>, Report>>()?;
 
         self.linker.link_modules(modules)?;
@@ -334,12 +478,32 @@ impl Assembler {
             namespace.map(Into::into),
             None,
             self.source_manager.clone(),
-            self.warnings_as_errors,
+            &self.parse_options,
         )?;
         self.linker.link_modules(core::iter::once(root).chain(modules))?;
         Ok(())
     }
 
+    /// Use the kernel defined by `kernel` when assembling programs and libraries with this
+    /// assembler.
+    ///
+    /// This is equivalent to [`Self::with_kernel`], but can be applied to an already configured
+    /// assembler, e.g. one that other packages have been linked with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `kernel` is not a kernel package, or if this assembler already has a
+    /// kernel.
+    pub fn with_kernel_from_package(self, kernel: &Package) -> Result<Self, Report> {
+        if kernel.kind != TargetType::Kernel {
+            return Err(Report::msg(format!(
+                "invalid kernel package: '{}@{}' is not a kernel",
+                kernel.name, kernel.version
+            )));
+        }
+        self.with_package(Arc::new(kernel.clone()), Linkage::Dynamic)
+    }
+
     /// Link against `package` with the specified linkage mode during assembly.
     pub fn with_package(mut self, package: Arc<Package>, linkage: Linkage) -> Result<Self, Report> {
         self.link_package(package, linkage)?;
@@ -377,7 +541,22 @@ impl Assembler {
 impl Assembler {
     /// Returns true if this assembler promotes warning diagnostics as errors by default.
     pub fn warnings_as_errors(&self) -> bool {
-        self.warnings_as_errors
+        self.parse_options.warnings_as_errors
+    }
+
+    /// Returns the set of features enabled for conditional compilation.
+    pub fn features(&self) -> &BTreeSet<Arc<str>> {
+        &self.parse_options.features
+    }
+
+    /// Returns the options used by this assembler when parsing modules from source.
+    pub fn parse_options(&self) -> &ParseOptions {
+        &self.parse_options
+    }
+
+    /// Returns the cache of compiled procedures used by this assembler, if any.
+    pub fn compile_cache(&self) -> Option<&Arc<CompileCache>> {
+        self.compile_cache.as_ref()
     }
 
     /// Returns a reference to the kernel for this assembler.
@@ -387,6 +566,14 @@ impl Assembler {
         self.linker.kernel()
     }
 
+    /// Merges the MAST forests of the kernel and of all packages linked with this assembler into a
+    /// single forest, in which the procedures shared by several of them are stored only once.
+    ///
+    /// See [Linker::deduplicate_libraries] for details.
+    pub fn deduplicate_libraries(&self) -> Result<DeduplicatedForest, Report> {
+        self.linker.deduplicate_libraries().map_err(Report::from)
+    }
+
     #[cfg(any(feature = "std", all(test, feature = "std")))]
     pub(crate) fn source_manager(&self) -> Arc<dyn SourceManager> {
         self.source_manager.clone()
@@ -413,16 +600,114 @@ impl Assembler {
         root: impl Parse,
         support: impl IntoIterator<Item = impl Parse>,
     ) -> Result<Box<Package>, Report> {
-        let root = root.parse(self.warnings_as_errors, self.source_manager.clone())?;
+        let root = root.parse_with_options(&self.parse_options, self.source_manager.clone())?;
         let support = support
             .into_iter()
-            .map(|module| module.parse(self.warnings_as_errors, self.source_manager.clone()))
+            .map(|module| {
+                module.parse_with_options(&self.parse_options, self.source_manager.clone())
+            })
             .collect::<Result<Vec<_>, Report>>()?;
 
         self.assemble_library_modules(name.into(), root, support, TargetType::Library)?
             .into_artifact()
     }
 
+    /// Assembles a root module, and its supporting submodules into a library [`Package`] with
+    /// dead-code elimination enabled, and returns it along with a [`DeadCodeReport`] of the
+    /// procedures which were removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing or compilation of the specified modules fails.
+    pub fn assemble_library_with_dead_code_report(
+        mut self,
+        name: impl Into<PackageId>,
+        root: impl Parse,
+        support: impl IntoIterator<Item = impl Parse>,
+    ) -> Result<(Box<Package>, DeadCodeReport), Report> {
+        self.dead_code_elimination = true;
+        let root = root.parse_with_options(&self.parse_options, self.source_manager.clone())?;
+        let support = support
+            .into_iter()
+            .map(|module| {
+                module.parse_with_options(&self.parse_options, self.source_manager.clone())
+            })
+            .collect::<Result<Vec<_>, Report>>()?;
+
+        let mut product =
+            self.assemble_library_modules(name.into(), root, support, TargetType::Library)?;
+        let report = product.take_dead_code_report().expect("dead-code elimination was requested");
+        Ok((product.into_artifact()?, report))
+    }
+
+    /// Assembles a root module, and its supporting submodules into a library [`Package`] with the
+    /// specified version and pinned dependencies.
+    ///
+    /// Each entry of `dependencies` names a package which has been linked into this assembler
+    /// (e.g. via [`Self::with_package`]), together with the version the library is pinned to. The
+    /// version and dependencies are recorded in the package manifest, and are therefore preserved
+    /// when the package is serialized. They can be read back via [`Package::version`] and
+    /// [`Package::dependencies`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - parsing or compilation of the specified modules fails.
+    /// - a dependency has not been linked into this assembler.
+    /// - the linked package of a dependency has a different version than the pinned one.
+    pub fn assemble_library_versioned(
+        self,
+        name: impl Into<PackageId>,
+        version: Version,
+        dependencies: &[(PackageId, Version)],
+        root: impl Parse,
+        support: impl IntoIterator<Item = impl Parse>,
+    ) -> Result<Box<Package>, Report> {
+        let dependencies = dependencies
+            .iter()
+            .map(|(name, version)| {
+                let package = self
+                    .linker
+                    .libraries()
+                    .map(|library| &library.package)
+                    .find(|package| &package.name == name)
+                    .ok_or_else(|| {
+                        Report::msg(format!(
+                            "unable to pin dependency '{name}': no such package has been linked"
+                        ))
+                    })?;
+                if &package.version != version {
+                    return Err(Report::msg(format!(
+                        "unable to pin dependency '{name}' to version {version}: the linked package has version {}",
+                        package.version
+                    )));
+                }
+
+                Ok(Dependency {
+                    name: name.clone(),
+                    kind: package.kind,
+                    version: version.clone(),
+                    digest: package.digest(),
+                })
+            })
+            .collect::<Result<Vec<_>, Report>>()?;
+
+        let root = root.parse_with_options(&self.parse_options, self.source_manager.clone())?;
+        let support = support
+            .into_iter()
+            .map(|module| {
+                module.parse_with_options(&self.parse_options, self.source_manager.clone())
+            })
+            .collect::<Result<Vec<_>, Report>>()?;
+
+        let mut product =
+            self.assemble_library_modules(name.into(), root, support, TargetType::Library)?;
+        product.extend_dependencies(dependencies)?;
+        let mut package = product.into_artifact()?;
+        package.version = version;
+        Ok(package)
+    }
+
     /// Assemble a library [`Package`] from the set of modules reachable from `root`.
     ///
     /// See [Assembler::compile_and_statically_link_from_root] for details on how modules are
@@ -442,7 +727,7 @@ impl Assembler {
             namespace,
             Some(ModuleKind::Library),
             self.source_manager.clone(),
-            self.warnings_as_errors,
+            &self.parse_options,
         )?;
 
         // Derive the package name from the namespace of the root module
@@ -493,7 +778,7 @@ impl Assembler {
             namespace,
             Some(ModuleKind::Kernel),
             self.source_manager.clone(),
-            self.warnings_as_errors,
+            &self.parse_options,
         )?;
 
         self.assemble_library_modules(name.into(), root, support, TargetType::Kernel)?
@@ -509,6 +794,9 @@ impl Assembler {
     ) -> Result<AssemblyProduct, Report> {
         let staticlibs = self.static_libraries_for_builder()?;
         let mut mast_forest_builder = MastForestBuilder::new_with_static_libraries(staticlibs)?;
+        if let Some(threshold) = self.inlining_threshold {
+            mast_forest_builder.set_inlining_threshold(threshold);
+        }
         let exports = {
             let mut exports = BTreeMap::new();
 
@@ -582,8 +870,20 @@ impl Assembler {
             exports
         };
 
+        let export_root_refs = exports.values().filter_map(|export| match export {
+            PendingPackageExport::Procedure(export) => Some(export.node_ref),
+            PendingPackageExport::Constant(_) | PendingPackageExport::Type(_) => None,
+        });
+        let dead_code_report = self.eliminate_dead_code(export_root_refs, &mut mast_forest_builder);
+
         let (mast_forest, node_id_by_ref, source_graph, source_id_by_ref) =
             mast_forest_builder.build()?.into_parts_with_source_graph();
+        self.populate_compile_cache(
+            &mast_forest,
+            &node_id_by_ref,
+            &source_graph,
+            &source_id_by_ref,
+        )?;
         let exports = exports
             .into_iter()
             .map(|(path, export)| {
@@ -595,6 +895,7 @@ impl Assembler {
 
         let modules = self.package_modules(module_indices);
         self.finish_library_product(name, mast_forest, source_graph, exports, modules, kind)
+            .map(|product| product.with_dead_code_report(dead_code_report))
     }
 
     fn package_modules(&self, module_indices: &[ModuleIndex]) -> Vec<PackageModule> {
@@ -786,14 +1087,164 @@ impl Assembler {
         name: impl Into<PackageId>,
         source: impl Parse,
     ) -> Result<Box<Package>, Report> {
-        let program = source.parse(self.warnings_as_errors, self.source_manager.clone())?;
+        self.assemble_program_product(name.into(), source)?.into_artifact()
+    }
+
+    /// Compiles the provided module into an executable package once for each of `kernels`,
+    /// returning the packages in the order of the kernels they were assembled against.
+    ///
+    /// Each program is assembled by a copy of this assembler, which must not have a kernel of its
+    /// own, extended with the kernel as if by [`Self::with_kernel_from_package`]. As a result,
+    /// `syscall` instructions are validated against each kernel separately.
+    ///
+    /// # Errors
+    ///
+    /// If the program fails to assemble against any of the kernels, returns an error whose
+    /// related diagnostics identify each such kernel, along with the errors raised when assembling
+    /// against it, e.g. the `syscall` instructions whose targets are missing from that kernel.
+    pub fn assemble_program_for_kernels<S>(
+        &self,
+        name: impl Into<PackageId>,
+        source: S,
+        kernels: impl IntoIterator<Item = Arc<Package>>,
+    ) -> Result<Vec<Box<Package>>, Report>
+    where
+        S: Parse + Clone,
+    {
+        if !self.kernel().is_empty() {
+            return Err(Report::msg(
+                "cannot assemble a program for multiple kernels with an assembler that already has a kernel",
+            ));
+        }
+
+        let name = name.into();
+        let mut programs = Vec::new();
+        let mut errors = Vec::new();
+        let mut num_kernels = 0;
+        for kernel in kernels {
+            num_kernels += 1;
+            let kernel_id = format!("{}@{}", kernel.name, kernel.version);
+            let program = self
+                .clone()
+                .with_kernel_from_package(&kernel)
+                .and_then(|assembler| assembler.assemble_program(name.clone(), source.clone()));
+            match program {
+                Ok(program) => programs.push(program),
+                Err(report) => {
+                    errors.push(RelatedError::wrap(AssemblerError::KernelAssemblyFailed {
+                        program: name.to_string(),
+                        kernel: kernel_id,
+                        errors: Box::new([RelatedError::new(report)]),
+                    }))
+                },
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(programs)
+        } else {
+            Err(Report::new(AssemblerError::MultiKernelAssemblyFailed {
+                program: name.to_string(),
+                num_kernels,
+                errors: errors.into_boxed_slice(),
+            }))
+        }
+    }
+
+    /// Compiles the provided module into an executable package, and returns it along with a
+    /// [`SourceMap`] of the assembled program.
+    ///
+    /// The source map is produced even if this assembler does not emit debug information into the
+    /// package, e.g. when configured with a release profile, so that errors raised by the program
+    /// can still be reported against its source code. See [`SourceMap`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing or compilation of the specified program fails, or if the source
+    /// doesn't have an entrypoint.
+    pub fn assemble_program_with_source_map(
+        mut self,
+        name: impl Into<PackageId>,
+        source: impl Parse,
+    ) -> Result<(Box<Package>, SourceMap), Report> {
+        self.emit_source_map = true;
+        let mut product = self.assemble_program_product(name.into(), source)?;
+        let source_map = product.take_source_map().expect("source map emission was requested");
+        Ok((product.into_artifact()?, source_map))
+    }
+
+    /// Compiles the provided module into an executable package, and returns it along with an
+    /// [`ErrorTable`] mapping the error codes of its assertions to their messages.
+    ///
+    /// Like the [`SourceMap`], the error table is produced even if this assembler does not emit
+    /// debug information into the package, so that the messages of errors raised by the program
+    /// can be recovered by loading the table into the host which executes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing or compilation of the specified program fails, or if the source
+    /// doesn't have an entrypoint.
+    pub fn assemble_program_with_error_table(
+        mut self,
+        name: impl Into<PackageId>,
+        source: impl Parse,
+    ) -> Result<(Box<Package>, ErrorTable), Report> {
+        self.emit_error_table = true;
+        let mut product = self.assemble_program_product(name.into(), source)?;
+        let error_table = product.take_error_table().expect("error table emission was requested");
+        Ok((product.into_artifact()?, error_table))
+    }
+
+    /// Compiles the provided module into an executable package with dead-code elimination enabled,
+    /// and returns it along with a [`DeadCodeReport`] of the procedures which were removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing or compilation of the specified program fails, or if the source
+    /// doesn't have an entrypoint.
+    pub fn assemble_program_with_dead_code_report(
+        mut self,
+        name: impl Into<PackageId>,
+        source: impl Parse,
+    ) -> Result<(Box<Package>, DeadCodeReport), Report> {
+        self.dead_code_elimination = true;
+        let mut product = self.assemble_program_product(name.into(), source)?;
+        let report = product.take_dead_code_report().expect("dead-code elimination was requested");
+        Ok((product.into_artifact()?, report))
+    }
+
+    fn assemble_program_product(
+        self,
+        name: PackageId,
+        source: impl Parse,
+    ) -> Result<AssemblyProduct, Report> {
+        let program =
+            source.parse_with_options(&self.parse_options, self.source_manager.clone())?;
         if !program.is_executable() {
             return Err(Report::msg(
                 "unable to assemble program: source is not an executable module",
             ));
         }
 
-        self.assemble_executable_modules(name.into(), program, [])?.into_artifact()
+        self.assemble_executable_modules(name, program, [])
+    }
+
+    /// Compiles a bare sequence of instructions into an executable [`Program`].
+    ///
+    /// The snippet is wrapped in an implicit `begin ... end` block, so `ops` must not contain its
+    /// own entrypoint. This is primarily intended for interactive use (e.g. a REPL), where each
+    /// line is evaluated against the current operand stack.
+    ///
+    /// Any control-flow keywords used in the snippet (e.g. `if.true`, `while.true`, `repeat`)
+    /// must be balanced by a matching `end` within the snippet itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the snippet fails to parse or compile.
+    pub fn assemble_snippet(&self, ops: &str) -> Result<Program, Report> {
+        let source = format!("begin\n{ops}\nend\n");
+        let package = self.clone().assemble_program("snippet", source)?;
+        package.try_into_program()
     }
 
     pub(crate) fn assemble_library_modules(
@@ -1049,6 +1500,9 @@ impl Assembler {
         // Compile the linked module graph rooted at the entrypoint
         let staticlibs = self.static_libraries_for_builder()?;
         let mut mast_forest_builder = MastForestBuilder::new_with_static_libraries(staticlibs)?;
+        if let Some(threshold) = self.inlining_threshold {
+            mast_forest_builder.set_inlining_threshold(threshold);
+        }
 
         if let Some(advice_map) = self.linker[module_index].advice_map() {
             mast_forest_builder.merge_advice_map(advice_map)?;
@@ -1059,9 +1513,16 @@ impl Assembler {
             .get_procedure(entrypoint)
             .expect("compilation succeeded but root not found in cache")
             .body_node_ref();
+        let dead_code_report = self.eliminate_dead_code([entry_node_ref], &mut mast_forest_builder);
 
-        let (mast_forest, node_id_by_ref, source_graph, _) =
+        let (mast_forest, node_id_by_ref, source_graph, source_id_by_ref) =
             mast_forest_builder.build()?.into_parts_with_source_graph();
+        self.populate_compile_cache(
+            &mast_forest,
+            &node_id_by_ref,
+            &source_graph,
+            &source_id_by_ref,
+        )?;
         let entry_node_id = *node_id_by_ref.get(&entry_node_ref).ok_or_else(|| {
             Report::msg(format!("entrypoint ref {entry_node_ref} was not finalized"))
         })?;
@@ -1074,6 +1535,7 @@ impl Assembler {
             entry_node_id,
             self.linker.kernel_package(),
         )
+        .map(|product| product.with_dead_code_report(dead_code_report))
     }
 
     fn finish_library_product(
@@ -1089,7 +1551,7 @@ impl Assembler {
         let package = Box::new(
             Package::create_with_modules(
                 name,
-                miden_mast_package::Version::new(0, 0, 0),
+                Version::new(0, 0, 0),
                 kind,
                 mast,
                 exports.into_values(),
@@ -1098,6 +1560,13 @@ impl Assembler {
             )
             .map_err(Report::msg)?,
         );
+        let source_map = self
+            .emit_source_map
+            .then(|| self.build_source_map(package.mast_forest(), &source_graph))
+            .transpose()?;
+        let error_table = self
+            .emit_error_table
+            .then(|| build_error_table(package.mast_forest(), &source_graph));
         let debug_info = self.emit_debug_info.then(|| {
             #[cfg_attr(not(feature = "std"), expect(unused_mut))]
             let mut debug_info = self.debug_info.clone();
@@ -1111,7 +1580,8 @@ impl Assembler {
         let source_graph =
             self.emit_debug_info.then(|| self.apply_source_debug_options(source_graph));
 
-        Ok(AssemblyProduct::new(package, None, debug_info, source_graph))
+        Ok(AssemblyProduct::new(package, None, debug_info, source_graph, source_map)
+            .with_error_table(error_table))
     }
 
     fn static_libraries_for_builder(&self) -> Result<Vec<StaticLibrary<'_>>, Report> {
@@ -1155,7 +1625,7 @@ impl Assembler {
         let package = Box::new(
             Package::create(
                 name,
-                miden_mast_package::Version::new(0, 0, 0),
+                Version::new(0, 0, 0),
                 TargetType::Executable,
                 mast,
                 vec![PackageExport::Procedure(
@@ -1166,6 +1636,13 @@ impl Assembler {
             )
             .map_err(Report::msg)?,
         );
+        let source_map = self
+            .emit_source_map
+            .then(|| self.build_source_map(package.mast_forest(), &source_graph))
+            .transpose()?;
+        let error_table = self
+            .emit_error_table
+            .then(|| build_error_table(package.mast_forest(), &source_graph));
         let debug_info = self.emit_debug_info.then(|| {
             #[cfg_attr(not(feature = "std"), expect(unused_mut))]
             let mut debug_info = self.debug_info.clone();
@@ -1179,7 +1656,8 @@ impl Assembler {
         let source_graph =
             self.emit_debug_info.then(|| self.apply_source_debug_options(source_graph));
 
-        Ok(AssemblyProduct::new(package, kernel, debug_info, source_graph))
+        Ok(AssemblyProduct::new(package, kernel, debug_info, source_graph, source_map)
+            .with_error_table(error_table))
     }
 
     fn apply_source_debug_options(&self, source_graph: SourceDebugGraph) -> SourceDebugGraph {
@@ -1260,6 +1738,7 @@ impl Assembler {
                 SymbolItem::Procedure(proc) => {
                     let proc = proc.borrow();
                     let num_locals = proc.num_locals();
+                    let is_inline = proc.has_attribute("inline");
                     let path = Arc::<Path>::from(module_path.join(proc.name().as_str()));
                     let signature = self.linker.resolve_signature(procedure_gid)?;
                     let is_program_entrypoint =
@@ -1277,8 +1756,30 @@ impl Assembler {
                     .with_span(proc.span())
                     .with_num_locals(num_locals)?;
 
-                    // Compile this procedure
-                    let procedure = self.compile_procedure(pctx, mast_forest_builder)?;
+                    // Compile this procedure, unless it can be reused from the compile cache
+                    let cache_key = self.compile_cache_key(&pctx, mast_forest_builder);
+                    let cached_body_ref = match &cache_key {
+                        Some(key) => self.import_cached_procedure(key, mast_forest_builder)?,
+                        None => None,
+                    };
+                    let procedure = match cached_body_ref {
+                        Some(body_ref) => {
+                            // The MAST roots taken by a cached procedure are unknown, so assume it
+                            // takes those of all of its callees
+                            for callee in self.linker.callees(procedure_gid) {
+                                if let Some(callee) = mast_forest_builder.get_procedure(*callee) {
+                                    let callee_ref = callee.body_node_ref();
+                                    mast_forest_builder
+                                        .record_procref_target_ref(procedure_gid, callee_ref);
+                                }
+                            }
+                            let mast_root = mast_forest_builder
+                                .mast_root_for_ref(body_ref)
+                                .expect("no MAST node for cached procedure");
+                            pctx.into_procedure(mast_root, body_ref)
+                        },
+                        None => self.compile_procedure(pctx, mast_forest_builder)?,
+                    };
                     // TODO: if a re-exported procedure with the same MAST root had been previously
                     // added to the builder, this will result in unreachable nodes added to the
                     // MAST forest. This is because while we won't insert a duplicate node for the
@@ -1291,6 +1792,18 @@ impl Assembler {
 
                     // Cache the compiled procedure
                     drop(proc);
+                    if let Some(key) = cache_key
+                        && cached_body_ref.is_none()
+                    {
+                        self.record_compiled_procedure(
+                            key,
+                            procedure.body_node_ref(),
+                            mast_forest_builder,
+                        );
+                    }
+                    if is_inline {
+                        mast_forest_builder.record_inline_procedure(procedure_gid);
+                    }
                     self.linker.register_procedure_root(procedure_gid, procedure.mast_root());
                     mast_forest_builder.insert_procedure(procedure_gid, procedure)?;
                 },
@@ -1392,7 +1905,13 @@ impl Assembler {
         use ast::Op;
 
         let mut body_node_refs: Vec<MastNodeRef> = Vec::new();
-        let mut block_builder = BasicBlockBuilder::new(wrapper, mast_forest_builder);
+        // The locations of the `exec` instructions through which nodes of `body_node_refs` were
+        // reached, keyed by their position in `body_node_refs`
+        let mut exec_call_sites: BTreeMap<usize, Location> = BTreeMap::new();
+        // The positions in `body_node_refs` of the nodes spliced from `@inline` procedures
+        let mut inlined_node_idxs: BTreeSet<usize> = BTreeSet::new();
+        let mut block_builder =
+            BasicBlockBuilder::new(wrapper, self.optimization_level, mast_forest_builder);
 
         for op in body {
             match op {
@@ -1404,7 +1923,31 @@ impl Assembler {
                             body_node_refs.push(basic_block_id);
                         }
 
-                        body_node_refs.push(node_ref);
+                        // Splice the body of a procedure annotated with `@inline` into this body,
+                        // rather than executing it as a separate subtree
+                        let is_exec = matches!(inst.inner(), ast::Instruction::Exec(_));
+                        let first_node_idx = body_node_refs.len();
+                        let mast_forest_builder = block_builder.mast_forest_builder_mut();
+                        if let ast::Instruction::Exec(callee) = inst.inner()
+                            && self.is_inline_target(
+                                callee,
+                                proc_ctx.id().module,
+                                mast_forest_builder,
+                            )
+                        {
+                            body_node_refs.extend(mast_forest_builder.inline_node_refs(node_ref));
+                            inlined_node_idxs.extend(first_node_idx..body_node_refs.len());
+                        } else {
+                            body_node_refs.push(node_ref);
+                        }
+                        if is_exec
+                            && let Ok(location) = proc_ctx.source_manager().location(inst.span())
+                        {
+                            exec_call_sites.extend(
+                                (first_node_idx..body_node_refs.len())
+                                    .map(|idx| (idx, location.clone())),
+                            );
+                        }
                     }
                 },
 
@@ -1445,7 +1988,7 @@ impl Assembler {
                     body_node_refs.push(split_node_ref);
                 },
 
-                Op::Repeat { count, body, span } => {
+                Op::Repeat { count, body, span, no_unroll } => {
                     if let Some(basic_block_id) = block_builder.make_basic_block()? {
                         body_node_refs.push(basic_block_id);
                     }
@@ -1492,8 +2035,23 @@ impl Assembler {
                             .into());
                     }
 
+                    // Splice the iterations into this body if the unrolled body fits the budget
+                    let mast_forest_builder = block_builder.mast_forest_builder_mut();
+                    let unrolled_node_refs = match self.unroll_budget {
+                        Some(budget) if !no_unroll => {
+                            let node_refs = mast_forest_builder.inline_node_refs(repeat_node_ref);
+                            let num_ops =
+                                mast_forest_builder.num_basic_block_operations(&node_refs);
+                            (num_ops.saturating_mul(iteration_count as usize) <= budget)
+                                .then_some(node_refs)
+                        },
+                        _ => None,
+                    };
                     for _ in 0..iteration_count {
-                        body_node_refs.push(repeat_node_ref);
+                        match &unrolled_node_refs {
+                            Some(node_refs) => body_node_refs.extend(node_refs.iter().copied()),
+                            None => body_node_refs.push(repeat_node_ref),
+                        }
                     }
                 },
 
@@ -1591,12 +2149,36 @@ impl Assembler {
             mast_forest_builder.ensure_block_ref(vec![Operation::Noop], vec![], vec![])?
         } else {
             let asm_op = self.create_asm_op(&proc_ctx.span(), "begin", proc_ctx);
-            mast_forest_builder.join_node_refs(body_node_refs, Some(asm_op))?
+            mast_forest_builder.join_node_refs_with_call_sites(
+                body_node_refs,
+                exec_call_sites,
+                inlined_node_idxs,
+                Some(asm_op),
+            )?
         };
 
         Ok(procedure_body_ref)
     }
 
+    /// Returns true if `target`, invoked with `exec` from `caller_module`, resolves to a procedure
+    /// annotated with `@inline`.
+    fn is_inline_target(
+        &self,
+        target: &InvocationTarget,
+        caller_module: ModuleIndex,
+        mast_forest_builder: &MastForestBuilder,
+    ) -> bool {
+        let caller = SymbolResolutionContext {
+            span: target.span(),
+            module: caller_module,
+            kind: Some(InvokeKind::Exec),
+        };
+        matches!(
+            self.linker.resolve_invoke_target(&caller, target),
+            Ok(SymbolResolution::Exact { gid, .. }) if mast_forest_builder.is_inline_procedure(gid)
+        )
+    }
+
     /// Resolves the specified target to the corresponding procedure root [`MastNodeRef`].
     ///
     /// If no [`MastNodeRef`] exists for that procedure root, we wrap the root in an
@@ -0,0 +1,304 @@
+use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use miden_assembly_syntax::{debuginfo::Spanned, module::ItemInfo, prettier::PrettyPrint};
+use miden_core::{
+    crypto::hash::Blake3_256,
+    mast::{MastForest, MastNode, MastNodeId, SubtreeIterator},
+    operations::Operation,
+    serde::Serializable,
+};
+use miden_mast_package::debug_info::{DebugSourceNodeId, PackageDebugInfo};
+use miden_utils_sync::RwLock;
+
+use super::{
+    Assembler,
+    product::{source_graph_section, source_map_section},
+};
+use crate::{
+    ProcedureContext,
+    diagnostics::Report,
+    linker::SymbolItem,
+    mast_forest_builder::{
+        MastForestBuilder, MastNodeRef, SourceDebugGraph, SourceNodeId, SourceNodeRef,
+    },
+};
+
+// COMPILE CACHE
+// ================================================================================================
+
+/// A cache of compiled procedures which can be shared by multiple [`Assembler`]s.
+///
+/// Every procedure an assembler compiles from source is recorded under a key derived from the
+/// content of the module's source file, the procedure body with all constants resolved, and the
+/// MAST roots of the procedures it invokes (and whether they are inlined), as well as from the
+/// kernel, the statically linked libraries, and the optimization and debug information settings
+/// of the assembler. When a later assembly comes across a procedure with the same key, the MAST
+/// subtree built for it previously is copied into the new artifact, along with its source-level
+/// debug information, instead of compiling the procedure again.
+///
+/// Any change to a module changes the keys of all procedures it defines, and any change to the
+/// MAST root of a procedure changes the keys of its callers. Thus, when reassembling a project in
+/// which a single module changed, only the procedures of that module and the procedures whose
+/// callees changed are compiled again.
+///
+/// The cache keeps the artifacts its procedures were copied from alive; use [`Self::clear()`] to
+/// release them.
+#[derive(Default)]
+pub struct CompileCache {
+    procedures: RwLock<BTreeMap<CompileCacheKey, CachedProcedure>>,
+    num_hits: AtomicUsize,
+    num_misses: AtomicUsize,
+}
+
+impl CompileCache {
+    /// Returns a new, empty [`CompileCache`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of procedures in this cache.
+    pub fn len(&self) -> usize {
+        self.procedures.read().len()
+    }
+
+    /// Returns true if this cache contains no procedures.
+    pub fn is_empty(&self) -> bool {
+        self.procedures.read().is_empty()
+    }
+
+    /// Returns the number of times a procedure was reused from this cache instead of being
+    /// compiled.
+    pub fn num_hits(&self) -> usize {
+        self.num_hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of times a procedure was looked up in this cache, but had to be compiled.
+    pub fn num_misses(&self) -> usize {
+        self.num_misses.load(Ordering::Relaxed)
+    }
+
+    /// Removes all procedures from this cache, and resets its hit and miss counters.
+    pub fn clear(&self) {
+        self.procedures.write().clear();
+        self.num_hits.store(0, Ordering::Relaxed);
+        self.num_misses.store(0, Ordering::Relaxed);
+    }
+
+    fn get(&self, key: &CompileCacheKey) -> Option<CachedProcedure> {
+        let cached = self.procedures.read().get(key).cloned();
+        let counter = if cached.is_some() {
+            &self.num_hits
+        } else {
+            &self.num_misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        cached
+    }
+}
+
+pub(super) type CompileCacheKey = [u8; 32];
+
+/// The MAST subtree built for a procedure in a previous assembly.
+#[derive(Clone)]
+struct CachedProcedure {
+    artifact: Arc<CachedArtifact>,
+    root_id: MastNodeId,
+    source_root_id: Option<DebugSourceNodeId>,
+    /// The messages of the errors which can be raised by the procedure.
+    error_messages: Vec<Arc<str>>,
+}
+
+/// The MAST forest, and the debug information describing it, of a previous assembly.
+struct CachedArtifact {
+    mast: MastForest,
+    debug_info: PackageDebugInfo,
+}
+
+/// A procedure compiled by the current assembly, to be added to the cache once the MAST forest
+/// being built is finalized.
+#[derive(Clone)]
+pub(super) struct PendingCacheEntry {
+    key: CompileCacheKey,
+    node_ref: MastNodeRef,
+    source_ref: Option<SourceNodeRef>,
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Compile cache
+impl Assembler {
+    /// Returns the key under which the procedure described by `proc_ctx` is cached, or `None` if
+    /// this assembler does not use a compile cache, or the procedure cannot be cached.
+    ///
+    /// All procedures invoked by the procedure must have been compiled already.
+    pub(super) fn compile_cache_key(
+        &self,
+        proc_ctx: &ProcedureContext,
+        mast_forest_builder: &MastForestBuilder,
+    ) -> Option<CompileCacheKey> {
+        self.compile_cache.as_ref()?;
+
+        let gid = proc_ctx.id();
+        let SymbolItem::Procedure(proc) = self.linker[gid].item() else {
+            return None;
+        };
+        let proc = proc.borrow();
+
+        let mut input = Vec::new();
+        if let Some(kernel) = self.linker.kernel_package() {
+            kernel.digest().write_into(&mut input);
+        }
+        for library in self.linker.static_libraries() {
+            library.commitment().write_into(&mut input);
+        }
+        if let Ok(source_file) = self.source_manager.get(proc.span().source_id()) {
+            source_file.uri().as_str().write_into(&mut input);
+            source_file.as_str().write_into(&mut input);
+        }
+        proc_ctx.path().as_str().write_into(&mut input);
+        proc.to_pretty_string().write_into(&mut input);
+        input.push(proc_ctx.is_program_entrypoint() as u8);
+        proc_ctx.num_locals().write_into(&mut input);
+        input.push(self.optimization_level as u8);
+        self.inlining_threshold.write_into(&mut input);
+        self.unroll_budget.write_into(&mut input);
+        input.push(self.emit_debug_info as u8);
+        input.push(self.trim_paths as u8);
+        input.push(self.emit_source_map as u8);
+        input.push(self.emit_error_table as u8);
+        for &callee in self.linker.callees(gid) {
+            let digest = match mast_forest_builder.get_procedure(callee) {
+                Some(procedure) => {
+                    // whether the callee is spliced into the procedure at `exec` sites
+                    input.push(mast_forest_builder.is_inline_procedure(callee) as u8);
+                    procedure.mast_root()
+                },
+                None => match self.linker[callee].item() {
+                    SymbolItem::Compiled(ItemInfo::Procedure(info)) => info.digest,
+                    SymbolItem::Procedure(_) => return None,
+                    // constants and types are resolved in the procedure body
+                    SymbolItem::Compiled(_) | SymbolItem::Constant(_) | SymbolItem::Type(_) => {
+                        continue;
+                    },
+                },
+            };
+            digest.write_into(&mut input);
+        }
+
+        Some(*Blake3_256::hash(&input).as_bytes())
+    }
+
+    /// Copies the MAST subtree of the procedure cached under `key` into `mast_forest_builder`, and
+    /// returns a reference to its root, or `None` if no procedure is cached under `key`.
+    pub(super) fn import_cached_procedure(
+        &self,
+        key: &CompileCacheKey,
+        mast_forest_builder: &mut MastForestBuilder,
+    ) -> Result<Option<MastNodeRef>, Report> {
+        let Some(cached) = self.compile_cache.as_ref().and_then(|cache| cache.get(key)) else {
+            return Ok(None);
+        };
+
+        for message in cached.error_messages {
+            mast_forest_builder.register_error(message);
+        }
+        mast_forest_builder
+            .import_procedure_subtree_ref(
+                &cached.artifact.mast,
+                Some(&cached.artifact.debug_info),
+                cached.root_id,
+                cached.source_root_id,
+            )
+            .map(Some)
+    }
+
+    /// Records that the procedure whose body is `node_ref` was compiled, so that it can be added
+    /// to the compile cache under `key` once the MAST forest is finalized.
+    pub(super) fn record_compiled_procedure(
+        &mut self,
+        key: CompileCacheKey,
+        node_ref: MastNodeRef,
+        mast_forest_builder: &MastForestBuilder,
+    ) {
+        let source_ref = mast_forest_builder.latest_source_ref_for_node_ref(node_ref);
+        self.compile_cache_entries.push(PendingCacheEntry { key, node_ref, source_ref });
+    }
+
+    /// Adds the procedures compiled by this assembly to the compile cache.
+    pub(super) fn populate_compile_cache(
+        &mut self,
+        mast_forest: &MastForest,
+        node_id_by_ref: &BTreeMap<MastNodeRef, MastNodeId>,
+        source_graph: &SourceDebugGraph,
+        source_id_by_ref: &BTreeMap<SourceNodeRef, SourceNodeId>,
+    ) -> Result<(), Report> {
+        let entries = core::mem::take(&mut self.compile_cache_entries);
+        let Some(cache) = self.compile_cache.as_ref() else {
+            return Ok(());
+        };
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let artifact = Arc::new(CachedArtifact {
+            mast: mast_forest.clone(),
+            debug_info: PackageDebugInfo::with_source_debug(
+                source_graph_section(source_graph)?,
+                source_map_section(source_graph)?,
+            ),
+        });
+
+        let mut procedures = cache.procedures.write();
+        for PendingCacheEntry { key, node_ref, source_ref } in entries {
+            let Some(&root_id) = node_id_by_ref.get(&node_ref) else {
+                continue;
+            };
+            let source_root_id = source_ref
+                .and_then(|source_ref| source_id_by_ref.get(&source_ref))
+                .map(|source_id| DebugSourceNodeId::from(u32::from(*source_id)));
+            let error_messages = subtree_error_messages(mast_forest, root_id, source_graph);
+
+            procedures.insert(
+                key,
+                CachedProcedure {
+                    artifact: Arc::clone(&artifact),
+                    root_id,
+                    source_root_id,
+                    error_messages,
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the messages of the errors which can be raised by operations in the subtree rooted at
+/// `root_id`.
+fn subtree_error_messages(
+    mast_forest: &MastForest,
+    root_id: MastNodeId,
+    source_graph: &SourceDebugGraph,
+) -> Vec<Arc<str>> {
+    let mut error_messages = BTreeMap::new();
+    for node_id in SubtreeIterator::new(&root_id, mast_forest) {
+        let MastNode::Block(block) = &mast_forest[node_id] else {
+            continue;
+        };
+        for op in block.operations() {
+            if let Operation::Assert(err_code)
+            | Operation::MpVerify(err_code)
+            | Operation::U32assert2(err_code) = op
+            {
+                let err_code = err_code.as_canonical_u64();
+                if let Some(message) = source_graph.error_messages().get(&err_code) {
+                    error_messages.insert(err_code, Arc::clone(message));
+                }
+            }
+        }
+    }
+    error_messages.into_values().collect()
+}
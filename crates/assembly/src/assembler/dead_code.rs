@@ -0,0 +1,63 @@
+use alloc::{sync::Arc, vec::Vec};
+
+use miden_assembly_syntax::ast::Path;
+
+use super::Assembler;
+use crate::mast_forest_builder::{MastForestBuilder, MastNodeRef};
+
+// DEAD CODE REPORT
+// ================================================================================================
+
+/// A record of the procedures removed by the dead-code elimination pass of an [`Assembler`].
+///
+/// Dead-code elimination is enabled with [`Assembler::with_dead_code_elimination`], and the report
+/// is obtained by assembling with [`Assembler::assemble_library_with_dead_code_report`] or
+/// [`Assembler::assemble_program_with_dead_code_report`]. When enabled, procedures which are
+/// unreachable from the exports of the assembled library, or from the entrypoint of the assembled
+/// program, are dropped from the resulting MAST forest. A procedure is reachable if it is invoked
+/// by a reachable procedure, either statically, or dynamically via a MAST root taken with
+/// `procref`.
+///
+/// Procedures which are only invoked dynamically, using a MAST root obtained without `procref`,
+/// e.g. from the advice provider, are considered unreachable, and will be removed.
+///
+/// The advice map of the assembled artifact is left untouched, even if some of its entries are
+/// only read by removed procedures: advice map keys are read from the operand stack at runtime,
+/// so the procedures using an entry cannot be determined statically.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DeadCodeReport {
+    removed_procedures: Vec<Arc<Path>>,
+}
+
+impl DeadCodeReport {
+    /// Returns the fully-qualified paths of the procedures which were removed, in the order they
+    /// were removed.
+    pub fn removed_procedures(&self) -> &[Arc<Path>] {
+        &self.removed_procedures
+    }
+
+    /// Returns the number of procedures which were removed.
+    pub fn len(&self) -> usize {
+        self.removed_procedures.len()
+    }
+
+    /// Returns true if no procedures were removed.
+    pub fn is_empty(&self) -> bool {
+        self.removed_procedures.is_empty()
+    }
+}
+
+impl Assembler {
+    /// Runs dead-code elimination on `mast_forest_builder` if it is enabled for this assembler,
+    /// keeping only the procedures reachable from `root_refs`, and returns a report of the
+    /// procedures it removed.
+    pub(super) fn eliminate_dead_code(
+        &self,
+        root_refs: impl IntoIterator<Item = MastNodeRef>,
+        mast_forest_builder: &mut MastForestBuilder,
+    ) -> Option<DeadCodeReport> {
+        self.dead_code_elimination.then(|| DeadCodeReport {
+            removed_procedures: mast_forest_builder.eliminate_dead_procedures(root_refs),
+        })
+    }
+}
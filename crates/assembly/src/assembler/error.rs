@@ -1,9 +1,9 @@
-use alloc::sync::Arc;
+use alloc::{boxed::Box, string::String, sync::Arc};
 
 use miden_assembly_syntax::{
     Path,
     debuginfo::{SourceFile, SourceSpan},
-    diagnostics::{Diagnostic, miette},
+    diagnostics::{Diagnostic, RelatedError, miette},
 };
 
 #[derive(Debug, thiserror::Error, Diagnostic)]
@@ -32,4 +32,20 @@ pub(crate) enum AssemblerError {
         max_locals: u16,
         num_locals: u16,
     },
+    #[error("failed to assemble '{program}' against kernel '{kernel}'")]
+    #[diagnostic(help("see diagnostics for details"))]
+    KernelAssemblyFailed {
+        program: String,
+        kernel: String,
+        #[related]
+        errors: Box<[RelatedError]>,
+    },
+    #[error("failed to assemble '{program}' against {} of {num_kernels} kernels", errors.len())]
+    #[diagnostic(help("see diagnostics for the failures of each kernel"))]
+    MultiKernelAssemblyFailed {
+        program: String,
+        num_kernels: usize,
+        #[related]
+        errors: Box<[RelatedError]>,
+    },
 }
@@ -1,9 +1,10 @@
-use miden_mast_package::{
-    Dependency,
-    debug_info::{
-        DebugErrorMessage, DebugErrorMessagesSection, DebugSourceAsmOp, DebugSourceGraphSection,
-        DebugSourceMapSection, DebugSourceNode, DebugSourceNodeId, DebugSourceVar,
-    },
+use miden_core::{
+    mast::{MastForest, MastNode},
+    operations::Operation,
+};
+use miden_mast_package::debug_info::{
+    DebugErrorMessage, DebugErrorMessagesSection, DebugSourceAsmOp, DebugSourceGraphSection,
+    DebugSourceMapSection, DebugSourceNode, DebugSourceNodeId, DebugSourceVar,
 };
 
 use super::*;
@@ -14,6 +15,9 @@ pub struct AssemblyProduct {
     kernel_package: Option<Arc<Package>>,
     debug_info: Option<DebugInfoSections>,
     source_graph: Option<SourceDebugGraph>,
+    source_map: Option<SourceMap>,
+    error_table: Option<ErrorTable>,
+    dead_code_report: Option<DeadCodeReport>,
 }
 
 impl AssemblyProduct {
@@ -22,6 +26,7 @@ impl AssemblyProduct {
         kernel: Option<Arc<Package>>,
         debug_info: Option<DebugInfoSections>,
         source_graph: Option<SourceDebugGraph>,
+        source_map: Option<SourceMap>,
     ) -> Self {
         assert!(
             kernel.is_none() || !package.is_kernel(),
@@ -32,10 +37,39 @@ impl AssemblyProduct {
             kernel_package: kernel,
             debug_info,
             source_graph,
+            source_map,
+            error_table: None,
+            dead_code_report: None,
         }
     }
 
-    #[cfg_attr(not(feature = "std"), expect(unused))]
+    /// Attaches the [`ErrorTable`] of the assembled artifact, if one was produced.
+    pub(super) fn with_error_table(mut self, error_table: Option<ErrorTable>) -> Self {
+        self.error_table = error_table;
+        self
+    }
+
+    /// Attaches the [`DeadCodeReport`] of the assembled artifact, if dead-code elimination ran.
+    pub(super) fn with_dead_code_report(mut self, report: Option<DeadCodeReport>) -> Self {
+        self.dead_code_report = report;
+        self
+    }
+
+    /// Takes the [`SourceMap`] of the assembled artifact, if one was produced.
+    pub fn take_source_map(&mut self) -> Option<SourceMap> {
+        self.source_map.take()
+    }
+
+    /// Takes the [`ErrorTable`] of the assembled artifact, if one was produced.
+    pub fn take_error_table(&mut self) -> Option<ErrorTable> {
+        self.error_table.take()
+    }
+
+    /// Takes the [`DeadCodeReport`] of the assembled artifact, if dead-code elimination ran.
+    pub fn take_dead_code_report(&mut self) -> Option<DeadCodeReport> {
+        self.dead_code_report.take()
+    }
+
     pub fn extend_dependencies(
         &mut self,
         deps: impl IntoIterator<Item = Dependency>,
@@ -53,6 +87,9 @@ impl AssemblyProduct {
             kernel_package,
             debug_info,
             source_graph,
+            source_map: _,
+            error_table: _,
+            dead_code_report: _,
         } = self;
         // Section: embedded kernel package
         if package.is_program()
@@ -123,11 +160,54 @@ impl AssemblyProduct {
     }
 }
 
+impl Assembler {
+    /// Builds the [`SourceMap`] of `mast_forest`, whose source/debug occurrences are described by
+    /// `source_graph`.
+    ///
+    /// Source positions are resolved using the source manager of this assembler, before any paths
+    /// are trimmed from the source locations.
+    pub(super) fn build_source_map(
+        &self,
+        mast_forest: &MastForest,
+        source_graph: &SourceDebugGraph,
+    ) -> Result<SourceMap, Report> {
+        let mut positions = BTreeMap::new();
+        for location in source_map_section(source_graph)?.locations() {
+            let position = self
+                .source_manager
+                .location_to_span(location.clone())
+                .and_then(|span| self.source_manager.file_line_col(span).ok());
+            if let Some(position) = position {
+                positions.insert(location.clone(), position);
+            }
+        }
+
+        #[cfg(feature = "std")]
+        if let Some(trimmer) = self.source_path_trimmer() {
+            positions = positions
+                .into_iter()
+                .map(|(location, position)| {
+                    (trimmer.trim_location(location), trimmer.trim_file_line_col(position))
+                })
+                .collect();
+        }
+
+        let source_graph = self.apply_source_debug_options(source_graph.clone());
+        Ok(SourceMap::new(
+            mast_forest.commitment(),
+            source_graph_section(&source_graph)?,
+            source_map_section(&source_graph)?,
+            error_messages_section(&source_graph),
+            |location| positions.get(location).cloned(),
+        ))
+    }
+}
+
 fn linked_kernel_package_section(package: &Package) -> Section {
     Section::new(SectionId::KERNEL, package.to_bytes())
 }
 
-fn source_graph_section(
+pub(super) fn source_graph_section(
     source_graph: &SourceDebugGraph,
 ) -> Result<DebugSourceGraphSection, Report> {
     Ok(DebugSourceGraphSection::from_parts(
@@ -161,23 +241,28 @@ fn source_graph_section(
     ))
 }
 
-fn source_map_section(source_graph: &SourceDebugGraph) -> Result<DebugSourceMapSection, Report> {
+pub(super) fn source_map_section(
+    source_graph: &SourceDebugGraph,
+) -> Result<DebugSourceMapSection, Report> {
     let mut asm_ops = Vec::new();
     let mut debug_vars = Vec::new();
 
     for (source_index, source_node) in source_graph.nodes().as_slice().iter().enumerate() {
         let source_node_id = DebugSourceNodeId::from(source_index as u32);
         for (op_idx, asm_op) in source_node.asm_ops() {
-            asm_ops.push(DebugSourceAsmOp::new(
-                source_node_id,
-                (*op_idx)
-                    .try_into()
-                    .map_err(|_| Report::msg("source asm-op index exceeds u32"))?,
-                asm_op.location().cloned(),
-                asm_op.context_name().to_string(),
-                asm_op.op().to_string(),
-                asm_op.num_cycles(),
-            ));
+            asm_ops.push(
+                DebugSourceAsmOp::new(
+                    source_node_id,
+                    (*op_idx)
+                        .try_into()
+                        .map_err(|_| Report::msg("source asm-op index exceeds u32"))?,
+                    asm_op.location().cloned(),
+                    asm_op.context_name().to_string(),
+                    asm_op.op().to_string(),
+                    asm_op.num_cycles(),
+                )
+                .with_call_sites(asm_op.call_sites().to_vec()),
+            );
         }
         for (op_idx, debug_var) in source_node.debug_vars() {
             debug_vars.push(DebugSourceVar::new(
@@ -193,7 +278,7 @@ fn source_map_section(source_graph: &SourceDebugGraph) -> Result<DebugSourceMapS
     Ok(DebugSourceMapSection::from_parts(asm_ops, debug_vars))
 }
 
-fn error_messages_section(source_graph: &SourceDebugGraph) -> DebugErrorMessagesSection {
+pub(super) fn error_messages_section(source_graph: &SourceDebugGraph) -> DebugErrorMessagesSection {
     DebugErrorMessagesSection::from_parts(
         source_graph
             .error_messages()
@@ -202,3 +287,53 @@ fn error_messages_section(source_graph: &SourceDebugGraph) -> DebugErrorMessages
             .collect(),
     )
 }
+
+/// Builds the [`ErrorTable`] of `mast_forest`, whose source/debug occurrences are described by
+/// `source_graph`.
+///
+/// Each error code is attributed to the module of the procedure containing the first assertion
+/// found to raise it, if that assertion has assembly-op information.
+pub(super) fn build_error_table(
+    mast_forest: &MastForest,
+    source_graph: &SourceDebugGraph,
+) -> ErrorTable {
+    let mut error_table = ErrorTable::default();
+    for source_node in source_graph.nodes().as_slice() {
+        let MastNode::Block(block) = &mast_forest[source_node.exec_node()] else {
+            continue;
+        };
+        let ops = block
+            .operations()
+            .enumerate()
+            .take(source_node.op_end())
+            .skip(source_node.op_start());
+        for (op_idx, op) in ops {
+            let (Operation::Assert(err_code)
+            | Operation::MpVerify(err_code)
+            | Operation::U32assert2(err_code)) = op
+            else {
+                continue;
+            };
+            let err_code = err_code.as_canonical_u64();
+            let Some(message) = source_graph.error_messages().get(&err_code) else {
+                continue;
+            };
+            let module = source_node
+                .asm_ops()
+                .iter()
+                .filter(|(asm_op_idx, _)| *asm_op_idx <= op_idx)
+                .max_by_key(|(asm_op_idx, _)| *asm_op_idx)
+                .and_then(|(_, asm_op)| Path::new(asm_op.context_name()).parent())
+                .map(|module| Arc::from(module.as_str()));
+            error_table.insert(err_code, Arc::clone(message), module);
+        }
+    }
+
+    // Messages of errors raised by code without source/debug occurrences, e.g. statically linked
+    // libraries without debug information, are recorded without a module.
+    for (err_code, message) in source_graph.error_messages() {
+        error_table.insert(*err_code, Arc::clone(message), None);
+    }
+
+    error_table
+}
@@ -0,0 +1,363 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+
+use miden_assembly_syntax::{
+    debuginfo::{Location, SourceManager},
+    diagnostics::{Diagnostic, Report, miette},
+};
+use miden_core::{
+    Felt, Word,
+    mast::MastNodeId,
+    operations::{AssemblyOp, Operation},
+    program::{KernelDescriptor, Program},
+};
+use miden_mast_package::SourceMap;
+
+use super::product::{error_messages_section, source_graph_section, source_map_section};
+use crate::mast_forest_builder::{MastForestBuilder, MastNodeRef};
+
+// PROGRAM BUILDER
+// ================================================================================================
+
+/// A builder for constructing a [Program] node-by-node from Rust code, without going through
+/// Miden Assembly source code.
+///
+/// This is intended for compiler backends which lower their own IR directly to MAST. Nodes are
+/// added bottom-up: each `add_*` method returns a [NodeHandle] which can be used as a child of the
+/// nodes added after it, and [Self::build] turns the node chosen as entrypoint into a [Program].
+///
+/// The builder applies the same node deduplication and basic block merging as the assembler, and
+/// validates the nodes it is given:
+///
+/// * Basic blocks must contain at least one operation.
+/// * Syscalls must target a procedure of the kernel the program is built against.
+/// * Procedures which are invoked with `call` or `syscall`, as well as the entrypoint of the
+///   program, must not leave more elements on the operand stack than they received, as the VM would
+///   reject that at runtime.
+///
+/// To check the last of these, the builder tracks a lower bound on the change in operand stack
+/// depth caused by each node, see [Self::min_stack_effect]. Nodes with an unbounded effect, e.g.
+/// `dynexec` or loops whose body does not push the next condition, are never rejected.
+///
+/// Every operation added to a basic block is automatically annotated with an [AssemblyOp]
+/// decorator, naming the operation and attributing it to the location set with
+/// [Self::set_location], so that debuggers and [SourceMap]s can describe the built program.
+pub struct ProgramBuilder {
+    source_manager: Arc<dyn SourceManager>,
+    mast_forest_builder: MastForestBuilder,
+    kernel: KernelDescriptor,
+    context_name: String,
+    location: Option<Location>,
+    min_stack_effects: BTreeMap<MastNodeRef, Option<isize>>,
+}
+
+/// A handle to a node added to a [ProgramBuilder].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct NodeHandle {
+    node_ref: MastNodeRef,
+    digest: Word,
+}
+
+impl NodeHandle {
+    /// Returns the MAST root of the node.
+    pub fn digest(&self) -> Word {
+        self.digest
+    }
+}
+
+/// Errors raised while validating the nodes given to a [ProgramBuilder].
+#[derive(Debug, thiserror::Error, Diagnostic)]
+pub enum ProgramBuilderError {
+    #[error("basic blocks must contain at least one operation")]
+    #[diagnostic()]
+    EmptyBasicBlock,
+    #[error("join nodes must have at least one child")]
+    #[diagnostic()]
+    EmptyJoin,
+    #[error("syscall target {digest} is not a procedure of the kernel")]
+    #[diagnostic(help("use `ProgramBuilder::with_kernel` to build the program against a kernel"))]
+    SyscallTargetNotInKernel { digest: Word },
+    #[error("invalid callee: the callee leaves at least {growth} more elements on the stack")]
+    #[diagnostic(help(
+        "procedures invoked with `call` or `syscall` must return with the same stack depth they \
+         were invoked with"
+    ))]
+    CalleeGrowsStack { growth: isize },
+    #[error("invalid entrypoint: the program leaves at least {growth} more elements on the stack")]
+    #[diagnostic(help("programs must not return more than 16 elements on the operand stack"))]
+    EntrypointGrowsStack { growth: isize },
+}
+
+/// Construction
+impl ProgramBuilder {
+    /// Creates a new builder for a program without a kernel.
+    ///
+    /// `source_manager` is used to resolve the locations attached to operations when producing a
+    /// [SourceMap] of the program.
+    pub fn new(source_manager: Arc<dyn SourceManager>) -> Self {
+        Self {
+            source_manager,
+            mast_forest_builder: MastForestBuilder::default(),
+            kernel: KernelDescriptor::default(),
+            context_name: String::from("#exec::#main"),
+            location: None,
+            min_stack_effects: BTreeMap::new(),
+        }
+    }
+
+    /// Builds the program against `kernel`, allowing it to make syscalls to the kernel's
+    /// procedures.
+    pub fn with_kernel(mut self, kernel: KernelDescriptor) -> Self {
+        self.kernel = kernel;
+        self
+    }
+
+    /// Sets the name of the context, e.g. the procedure, operations are attributed to.
+    pub fn set_context_name(&mut self, name: impl Into<String>) {
+        self.context_name = name.into();
+    }
+
+    /// Sets the source location operations added from now on are attributed to.
+    pub fn set_location(&mut self, location: Option<Location>) {
+        self.location = location;
+    }
+
+    /// Registers the error message `msg`, returning the error code to use for it in assertions.
+    ///
+    /// Error messages registered this way are included in the [SourceMap] of the program.
+    pub fn register_error(&mut self, msg: impl Into<Arc<str>>) -> Felt {
+        self.mast_forest_builder.register_error(msg.into())
+    }
+}
+
+/// Nodes
+impl ProgramBuilder {
+    /// Adds a basic block executing `operations`.
+    pub fn add_block(
+        &mut self,
+        operations: impl IntoIterator<Item = Operation>,
+    ) -> Result<NodeHandle, Report> {
+        let operations = operations.into_iter().collect::<Vec<_>>();
+        if operations.is_empty() {
+            return Err(ProgramBuilderError::EmptyBasicBlock.into());
+        }
+
+        let min_stack_effect = operations.iter().map(operation_stack_effect).sum::<isize>();
+        let mut asm_op_refs = Vec::with_capacity(operations.len());
+        for (op_idx, op) in operations.iter().enumerate() {
+            let asm_op = AssemblyOp::new(
+                self.location.clone(),
+                self.context_name.clone(),
+                1,
+                op.to_string(),
+            );
+            asm_op_refs.push((op_idx, self.mast_forest_builder.add_asm_op_ref(asm_op)?));
+        }
+        let node_ref =
+            self.mast_forest_builder.ensure_block_ref(operations, asm_op_refs, vec![])?;
+
+        Ok(self.handle(node_ref, Some(min_stack_effect)))
+    }
+
+    /// Adds a node executing `children` in sequence.
+    ///
+    /// Contiguous basic blocks are merged, and the remaining nodes are combined into a balanced
+    /// tree of JOIN nodes. A single child is returned as is.
+    pub fn add_join(
+        &mut self,
+        children: impl IntoIterator<Item = NodeHandle>,
+    ) -> Result<NodeHandle, Report> {
+        let children = children.into_iter().collect::<Vec<_>>();
+        if children.is_empty() {
+            return Err(ProgramBuilderError::EmptyJoin.into());
+        }
+
+        let min_stack_effect = children
+            .iter()
+            .map(|child| self.min_stack_effect(*child))
+            .sum::<Option<isize>>();
+        let asm_op = self.control_flow_asm_op("join");
+        let node_ref = self.mast_forest_builder.join_node_refs(
+            children.into_iter().map(|child| child.node_ref).collect(),
+            Some(asm_op),
+        )?;
+
+        Ok(self.handle(node_ref, min_stack_effect))
+    }
+
+    /// Adds a node executing `on_true` or `on_false`, depending on the condition on top of the
+    /// stack.
+    pub fn add_split(
+        &mut self,
+        on_true: NodeHandle,
+        on_false: NodeHandle,
+    ) -> Result<NodeHandle, Report> {
+        let min_stack_effect = self
+            .min_stack_effect(on_true)
+            .zip(self.min_stack_effect(on_false))
+            .map(|(on_true, on_false)| on_true.min(on_false) - 1);
+        let asm_op = self.control_flow_asm_op("if.true");
+        let node_ref = self
+            .mast_forest_builder
+            .ensure_split_node_ref([on_true.node_ref, on_false.node_ref], asm_op)?;
+
+        Ok(self.handle(node_ref, min_stack_effect))
+    }
+
+    /// Adds a node executing `body` for as long as the condition on top of the stack is true.
+    pub fn add_loop(&mut self, body: NodeHandle) -> Result<NodeHandle, Report> {
+        // Each iteration pops the condition pushed by the body, so the loop only has a bounded
+        // effect if every iteration leaves the stack at least as deep as it found it
+        let min_stack_effect =
+            self.min_stack_effect(body).and_then(|body| (body >= 1).then_some(-1));
+        let asm_op = self.control_flow_asm_op("while.true");
+        let node_ref = self.mast_forest_builder.ensure_loop_node_ref(body.node_ref, asm_op)?;
+
+        Ok(self.handle(node_ref, min_stack_effect))
+    }
+
+    /// Adds a node invoking `callee` in a new execution context.
+    pub fn add_call(&mut self, callee: NodeHandle) -> Result<NodeHandle, Report> {
+        self.validate_callee(callee)?;
+        let asm_op = self.control_flow_asm_op("call");
+        let node_ref =
+            self.mast_forest_builder.ensure_call_node_ref(callee.node_ref, false, asm_op)?;
+
+        Ok(self.handle(node_ref, Some(0)))
+    }
+
+    /// Adds a node invoking `callee` in the context of the kernel.
+    ///
+    /// `callee` is typically an external node, see [Self::add_external], referencing a procedure
+    /// of the kernel the program is built against.
+    pub fn add_syscall(&mut self, callee: NodeHandle) -> Result<NodeHandle, Report> {
+        if !self.kernel.contains_proc(callee.digest) {
+            return Err(
+                ProgramBuilderError::SyscallTargetNotInKernel { digest: callee.digest }.into()
+            );
+        }
+        self.validate_callee(callee)?;
+        let asm_op = self.control_flow_asm_op("syscall");
+        let node_ref =
+            self.mast_forest_builder.ensure_call_node_ref(callee.node_ref, true, asm_op)?;
+
+        Ok(self.handle(node_ref, Some(0)))
+    }
+
+    /// Adds a node invoking the procedure whose MAST root is stored in memory at the address on
+    /// top of the stack.
+    ///
+    /// If `is_dyncall` is true, the procedure is invoked in a new execution context, as with
+    /// `call`, otherwise it is executed in the current context.
+    pub fn add_dyn(&mut self, is_dyncall: bool) -> Result<NodeHandle, Report> {
+        let (op, min_stack_effect) = if is_dyncall {
+            ("dyncall", Some(-1))
+        } else {
+            ("dynexec", None)
+        };
+        let asm_op = self.control_flow_asm_op(op);
+        let node_ref = self.mast_forest_builder.ensure_dyn_node_ref(is_dyncall, asm_op)?;
+
+        Ok(self.handle(node_ref, min_stack_effect))
+    }
+
+    /// Adds a reference to the procedure with MAST root `digest`, which must be provided to the
+    /// processor at runtime.
+    pub fn add_external(&mut self, digest: Word) -> Result<NodeHandle, Report> {
+        let node_ref = self
+            .mast_forest_builder
+            .ensure_external_link_with_source_ref(digest, None, None, None)?;
+
+        Ok(self.handle(node_ref, None))
+    }
+
+    /// Returns a lower bound on the change in operand stack depth caused by executing `node`, or
+    /// `None` if no bound is known.
+    pub fn min_stack_effect(&self, node: NodeHandle) -> Option<isize> {
+        self.min_stack_effects.get(&node.node_ref).copied().flatten()
+    }
+}
+
+/// Finalization
+impl ProgramBuilder {
+    /// Builds a [Program] with `entrypoint` as its entrypoint.
+    pub fn build(self, entrypoint: NodeHandle) -> Result<Program, Report> {
+        self.build_with_source_map(entrypoint).map(|(program, _)| program)
+    }
+
+    /// Builds a [Program] with `entrypoint` as its entrypoint, along with a [SourceMap] of it
+    /// built from the decorators attached to its operations.
+    pub fn build_with_source_map(
+        mut self,
+        entrypoint: NodeHandle,
+    ) -> Result<(Program, SourceMap), Report> {
+        if let Some(growth) = self.min_stack_effect(entrypoint).filter(|effect| *effect > 0) {
+            return Err(ProgramBuilderError::EntrypointGrowsStack { growth }.into());
+        }
+
+        self.mast_forest_builder.record_procedure_root_ref(entrypoint.node_ref);
+        let (mast_forest, node_id_by_ref, source_graph, _) =
+            self.mast_forest_builder.build()?.into_parts_with_source_graph();
+        let entrypoint: MastNodeId =
+            *node_id_by_ref.get(&entrypoint.node_ref).ok_or_else(|| {
+                Report::msg(format!("entrypoint ref {} was not finalized", entrypoint.node_ref))
+            })?;
+
+        let source_manager = self.source_manager;
+        let source_map = SourceMap::new(
+            mast_forest.commitment(),
+            source_graph_section(&source_graph)?,
+            source_map_section(&source_graph)?,
+            error_messages_section(&source_graph),
+            |location| {
+                source_manager
+                    .location_to_span(location.clone())
+                    .and_then(|span| source_manager.file_line_col(span).ok())
+            },
+        );
+        let program = Program::with_kernel(Arc::new(mast_forest), entrypoint, self.kernel);
+
+        Ok((program, source_map))
+    }
+}
+
+/// Helpers
+impl ProgramBuilder {
+    fn handle(&mut self, node_ref: MastNodeRef, min_stack_effect: Option<isize>) -> NodeHandle {
+        let digest = self
+            .mast_forest_builder
+            .mast_root_for_ref(node_ref)
+            .expect("nodes added to the builder must have a MAST root");
+        self.min_stack_effects.insert(node_ref, min_stack_effect);
+        NodeHandle { node_ref, digest }
+    }
+
+    fn control_flow_asm_op(&self, op: &str) -> AssemblyOp {
+        AssemblyOp::new(self.location.clone(), self.context_name.clone(), 1, op.into())
+    }
+
+    fn validate_callee(&self, callee: NodeHandle) -> Result<(), Report> {
+        match self.min_stack_effect(callee) {
+            Some(growth) if growth > 0 => {
+                Err(ProgramBuilderError::CalleeGrowsStack { growth }.into())
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Returns the change in operand stack depth caused by executing `op`, assuming the stack is deep
+/// enough that no zeros have to be shifted in.
+fn operation_stack_effect(op: &Operation) -> isize {
+    if op.increments_stack_size() {
+        1
+    } else if op.decrements_stack_size() {
+        -1
+    } else {
+        0
+    }
+}
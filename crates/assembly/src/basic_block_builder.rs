@@ -19,7 +19,10 @@ use miden_core::{
 use crate::{
     ProcedureContext,
     assembler::BodyWrapper,
+    const_eval,
     mast_forest_builder::{AsmOpRef, DebugVarRef, MastForestBuilder, MastNodeRef},
+    peephole::{OptimizationLevel, optimize_basic_block},
+    push_value_ops,
 };
 
 // PENDING ASM OP
@@ -64,18 +67,22 @@ pub struct BasicBlockBuilder<'a> {
     /// Debug variables attached to operations in this block.
     /// Each entry is (op_index, debug_var_ref).
     debug_vars: Vec<(usize, DebugVarRef)>,
+    /// The optimizations applied to the operations of each basic block.
+    optimization_level: OptimizationLevel,
     mast_forest_builder: &'a mut MastForestBuilder,
 }
 
 /// Constructors
 impl<'a> BasicBlockBuilder<'a> {
-    /// Returns a new [`BasicBlockBuilder`] instantiated with the specified optional wrapper.
+    /// Returns a new [`BasicBlockBuilder`] instantiated with the specified optional wrapper, which
+    /// optimizes the blocks it creates according to `optimization_level`.
     ///
     /// If the wrapper is provided, the prologue of the wrapper is immediately appended to the
     /// vector of span operations. The epilogue of the wrapper is appended to the list of operations
     /// upon consumption of the builder via the [`Self::try_into_basic_block`] method.
     pub(super) fn new(
         wrapper: Option<BodyWrapper>,
+        optimization_level: OptimizationLevel,
         mast_forest_builder: &'a mut MastForestBuilder,
     ) -> Self {
         match wrapper {
@@ -85,6 +92,7 @@ impl<'a> BasicBlockBuilder<'a> {
                 pending_asm_op: None,
                 asm_ops: Vec::new(),
                 debug_vars: Vec::new(),
+                optimization_level,
                 mast_forest_builder,
             },
             None => Self {
@@ -93,6 +101,7 @@ impl<'a> BasicBlockBuilder<'a> {
                 pending_asm_op: None,
                 asm_ops: Vec::new(),
                 debug_vars: Default::default(),
+                optimization_level,
                 mast_forest_builder,
             },
         }
@@ -203,6 +212,83 @@ impl BasicBlockBuilder<'_> {
     }
 }
 
+/// Constant evaluation
+impl BasicBlockBuilder<'_> {
+    /// Evaluates the procedure rooted at `callee`, invoked by the instruction currently being
+    /// tracked, on the constants pushed by the instructions preceding it, if the optimization
+    /// level of the builder enables it.
+    ///
+    /// If the procedure is a basic block which can be evaluated on these constants, the
+    /// operations of the instructions it consumes are replaced with pushes of the values it
+    /// leaves on the stack, which are attributed to the invoking instruction, and true is
+    /// returned. Otherwise, the builder is left unchanged and false is returned.
+    pub fn fold_constant_invocation(&mut self, callee: MastNodeRef) -> bool {
+        if !self.optimization_level.is_const_eval_enabled() {
+            return false;
+        }
+        let Some(callee_ops) = self.mast_forest_builder.basic_block_operations(callee) else {
+            return false;
+        };
+
+        // Collect the values pushed by each of the trailing instructions whose operations only
+        // push constants, from the last one backwards
+        let mut pushes = Vec::new();
+        let mut end = self.ops.len();
+        for &(op_idx, asm_op_ref) in self.asm_ops.iter().rev() {
+            let num_cycles = self.mast_forest_builder.asm_op_mut(asm_op_ref).num_cycles() as usize;
+            if op_idx + num_cycles != end
+                || self.debug_vars.iter().any(|&(var_idx, _)| var_idx > op_idx && var_idx <= end)
+            {
+                break;
+            }
+            match const_eval::evaluate(Vec::new(), &self.ops[op_idx..end]) {
+                Some(values) => pushes.push((op_idx, values)),
+                None => break,
+            }
+            end = op_idx;
+        }
+        pushes.reverse();
+
+        let inputs: Vec<Felt> =
+            pushes.iter().flat_map(|(_, values)| values.iter().copied()).collect();
+        let Some(outputs) = const_eval::evaluate(inputs.clone(), &callee_ops) else {
+            return false;
+        };
+
+        // Keep the instructions pushing the deepest inputs which are left unchanged
+        let mut start = self.ops.len();
+        let mut num_kept = inputs.len();
+        let mut depth = 0;
+        for (op_idx, values) in pushes.iter() {
+            if outputs.get(..depth) != Some(&inputs[..depth]) {
+                break;
+            }
+            (start, num_kept) = (*op_idx, depth);
+            depth += values.len();
+        }
+        if outputs.get(..inputs.len()) == Some(&inputs[..]) {
+            (start, num_kept) = (self.ops.len(), inputs.len());
+        }
+
+        // The folded operations are attributed to a single instruction, whose cycle count must
+        // fit in a byte
+        let folded_ops: Vec<Operation> =
+            outputs[num_kept..].iter().flat_map(|&value| push_value_ops(value)).collect();
+        if folded_ops.len() > u8::MAX as usize {
+            return false;
+        }
+
+        self.ops.truncate(start);
+        self.ops.extend(folded_ops);
+        self.asm_ops.retain(|&(op_idx, _)| op_idx < start);
+        if let Some(pending) = self.pending_asm_op.as_mut() {
+            pending.op_start = start;
+        }
+
+        true
+    }
+}
+
 /// Basic Block Constructors
 impl BasicBlockBuilder<'_> {
     /// Creates and returns a new basic block node from the operations currently in this builder.
@@ -210,12 +296,22 @@ impl BasicBlockBuilder<'_> {
     /// If there are no operations however, then no node is created and `None` is returned.
     ///
     /// This consumes all operations in the builder, but does not touch the operations in the
-    /// epilogue of the builder.
+    /// epilogue of the builder. The operations are optimized according to the optimization level
+    /// of the builder before the block is created.
     pub(crate) fn make_basic_block(&mut self) -> Result<Option<MastNodeRef>, Report> {
         if !self.ops.is_empty() {
-            let ops = self.ops.drain(..).collect();
-            let asm_ops = core::mem::take(&mut self.asm_ops);
-            let debug_vars = self.debug_vars.drain(..).collect();
+            let mut ops = self.ops.drain(..).collect();
+            let mut asm_ops = core::mem::take(&mut self.asm_ops);
+            let mut debug_vars: Vec<_> = self.debug_vars.drain(..).collect();
+
+            if self.optimization_level.is_peephole_enabled() {
+                optimize_basic_block(
+                    &mut ops,
+                    &mut asm_ops,
+                    &mut debug_vars,
+                    self.mast_forest_builder,
+                );
+            }
 
             let basic_block_node_ref =
                 self.mast_forest_builder.ensure_block_ref(ops, asm_ops, debug_vars)?;
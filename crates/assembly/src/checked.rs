@@ -0,0 +1,83 @@
+use alloc::{
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use core::ops::Range;
+
+use miden_assembly_syntax::diagnostics::{Diagnostic, Report, Severity};
+use miden_core::program::Program;
+
+use crate::{Assembler, DefaultSourceManager};
+
+// CHECKED ASSEMBLY
+// ================================================================================================
+
+/// Assembles `source` as an executable program, returning the diagnostics reported for it if it
+/// is invalid.
+///
+/// This is intended as the entry point of fuzzing harnesses: malformed input of any kind must be
+/// reported as diagnostics rather than cause a panic, so any panic raised by this function is a
+/// bug. The program is assembled with a default [`Assembler`], without any libraries, so sources
+/// invoking procedures of other modules are rejected.
+///
+/// The diagnostics are reduced to plain data which does not refer to the source, see
+/// [`AssemblyDiagnostic`].
+pub fn assemble_checked(source: &str) -> Result<Program, Vec<AssemblyDiagnostic>> {
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    Assembler::new(source_manager)
+        .assemble_program("checked", source)
+        .and_then(|package| package.try_into_program())
+        .map_err(|report| AssemblyDiagnostic::from_report(&report))
+}
+
+// ASSEMBLY DIAGNOSTIC
+// ================================================================================================
+
+/// A diagnostic reported by [`assemble_checked`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssemblyDiagnostic {
+    /// The severity of the diagnostic.
+    pub severity: Severity,
+    /// The message of the diagnostic.
+    pub message: String,
+    /// The help message of the diagnostic, if any.
+    pub help: Option<String>,
+    /// The labelled byte ranges of the source the diagnostic refers to.
+    pub labels: Vec<(Range<usize>, Option<String>)>,
+}
+
+impl AssemblyDiagnostic {
+    /// Flattens `report` and the diagnostics related to it into a list of diagnostics, in the
+    /// order they were reported.
+    pub fn from_report(report: &Report) -> Vec<Self> {
+        let mut diagnostics = Vec::new();
+        collect_diagnostics(report.as_ref(), &mut diagnostics);
+        diagnostics
+    }
+}
+
+fn collect_diagnostics(diagnostic: &dyn Diagnostic, diagnostics: &mut Vec<AssemblyDiagnostic>) {
+    let labels = diagnostic
+        .labels()
+        .map(|labels| {
+            labels
+                .map(|label| {
+                    let range = label.offset()..(label.offset() + label.len());
+                    (range, label.label().map(ToString::to_string))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    diagnostics.push(AssemblyDiagnostic {
+        severity: diagnostic.severity().unwrap_or(Severity::Error),
+        message: diagnostic.to_string(),
+        help: diagnostic.help().map(|help| help.to_string()),
+        labels,
+    });
+    if let Some(related) = diagnostic.related() {
+        for diagnostic in related {
+            collect_diagnostics(diagnostic, diagnostics);
+        }
+    }
+}
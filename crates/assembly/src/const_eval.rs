@@ -0,0 +1,267 @@
+use alloc::vec::Vec;
+
+use miden_core::{
+    Felt, ONE, ZERO,
+    field::{Field, PrimeCharacteristicRing},
+    operations::Operation,
+};
+
+// CONSTANT EVALUATION
+// ================================================================================================
+
+/// Executes `ops` on an operand stack holding only the constants in `stack`, and returns the
+/// resulting stack.
+///
+/// Both stacks are ordered from the deepest element to the top of the stack, i.e. in the order in
+/// which the elements would be pushed. Only operations which are pure functions of the operand
+/// stack can be evaluated: field and u32 arithmetic, comparisons, and stack manipulation.
+///
+/// Returns `None` if `ops` cannot be evaluated at assembly time, i.e. if:
+/// * an operation is not pure, e.g. it reads memory or the advice provider, or emits an event.
+/// * an operation accesses an element below the constants in `stack`, whose value is unknown.
+/// * an operation would fail, e.g. an assertion or a u32 operation on a non-u32 value. These are
+///   left to fail at runtime, with the appropriate error.
+pub(crate) fn evaluate<'a, I>(stack: Vec<Felt>, ops: I) -> Option<Vec<Felt>>
+where
+    I: IntoIterator<Item = &'a Operation>,
+{
+    let mut stack = ConstStack(stack);
+    for op in ops {
+        stack.apply(op)?;
+    }
+    Some(stack.0)
+}
+
+// CONSTANT STACK
+// ================================================================================================
+
+/// An operand stack of known values, whose top is the last element of the vector.
+struct ConstStack(Vec<Felt>);
+
+impl ConstStack {
+    fn apply(&mut self, op: &Operation) -> Option<()> {
+        use Operation::*;
+
+        match op {
+            Noop => (),
+            Assert(_) => {
+                if self.pop()? != ONE {
+                    return None;
+                }
+            },
+
+            Add => self.binary(|a, b| Some(a + b))?,
+            Neg => self.unary(|a| Some(-a))?,
+            Mul => self.binary(|a, b| Some(a * b))?,
+            Inv => self.unary(|a| (a != ZERO).then(|| a.inverse()))?,
+            Incr => self.unary(|a| Some(a + ONE))?,
+            And => self.binary(|a, b| Some(Felt::from_bool(binary(a)? && binary(b)?)))?,
+            Or => self.binary(|a, b| Some(Felt::from_bool(binary(a)? || binary(b)?)))?,
+            Not => self.unary(|a| Some(Felt::from_bool(!binary(a)?)))?,
+            Eq => self.binary(|a, b| Some(Felt::from_bool(a == b)))?,
+            Eqz => self.unary(|a| Some(Felt::from_bool(a == ZERO)))?,
+            Expacc => {
+                let [exp, acc, base, _] = self.top::<4>()?;
+                let exp = exp.as_canonical_u64();
+                let acc = if exp & 1 == 1 { acc * base } else { acc };
+                let top = self.0.len() - 4;
+                self.0[top..].copy_from_slice(&[
+                    Felt::new_unchecked(exp >> 1),
+                    acc,
+                    base * base,
+                    Felt::new_unchecked(exp & 1),
+                ]);
+            },
+
+            U32split => {
+                let a = self.pop()?.as_canonical_u64();
+                self.push_u64(a >> 32);
+                self.push_u64(a & u32::MAX as u64);
+            },
+            U32assert2(_) => {
+                let [b, a] = self.top::<2>()?;
+                u32_value(a)?;
+                u32_value(b)?;
+            },
+            U32add => {
+                let (b, a) = (self.pop_u32()?, self.pop_u32()?);
+                let sum = a + b;
+                self.push_u64(sum >> 32);
+                self.push_u64(sum & u32::MAX as u64);
+            },
+            U32add3 => {
+                let (c, b, a) = (self.pop_u32()?, self.pop_u32()?, self.pop_u32()?);
+                let sum = a + b + c;
+                self.push_u64(sum >> 32);
+                self.push_u64(sum & u32::MAX as u64);
+            },
+            U32sub => {
+                let (b, a) = (self.pop_u32()?, self.pop_u32()?);
+                let diff = a.wrapping_sub(b);
+                self.push_u64(diff & u32::MAX as u64);
+                self.push_u64(diff >> 63);
+            },
+            U32mul => {
+                let (b, a) = (self.pop_u32()?, self.pop_u32()?);
+                let product = a * b;
+                self.push_u64(product >> 32);
+                self.push_u64(product & u32::MAX as u64);
+            },
+            U32madd => {
+                let (a, b, c) = (self.pop_u32()?, self.pop_u32()?, self.pop_u32()?);
+                let result = a * b + c;
+                self.push_u64(result >> 32);
+                self.push_u64(result & u32::MAX as u64);
+            },
+            U32div => {
+                let (b, a) = (self.pop_u32()?, self.pop_u32()?);
+                if b == 0 {
+                    return None;
+                }
+                self.push_u64(a / b);
+                self.push_u64(a % b);
+            },
+            U32and => {
+                let (b, a) = (self.pop_u32()?, self.pop_u32()?);
+                self.push_u64(a & b);
+            },
+            U32xor => {
+                let (b, a) = (self.pop_u32()?, self.pop_u32()?);
+                self.push_u64(a ^ b);
+            },
+
+            Pad => self.0.push(ZERO),
+            Push(value) => self.0.push(*value),
+            Drop => {
+                self.pop()?;
+            },
+            Dup0 => self.dup(0)?,
+            Dup1 => self.dup(1)?,
+            Dup2 => self.dup(2)?,
+            Dup3 => self.dup(3)?,
+            Dup4 => self.dup(4)?,
+            Dup5 => self.dup(5)?,
+            Dup6 => self.dup(6)?,
+            Dup7 => self.dup(7)?,
+            Dup9 => self.dup(9)?,
+            Dup11 => self.dup(11)?,
+            Dup13 => self.dup(13)?,
+            Dup15 => self.dup(15)?,
+            Swap => self.swap_ranges(0, 1, 1)?,
+            SwapW => self.swap_ranges(0, 4, 4)?,
+            SwapW2 => self.swap_ranges(0, 8, 4)?,
+            SwapW3 => self.swap_ranges(0, 12, 4)?,
+            SwapDW => self.swap_ranges(0, 8, 8)?,
+            MovUp2 => self.movup(2)?,
+            MovUp3 => self.movup(3)?,
+            MovUp4 => self.movup(4)?,
+            MovUp5 => self.movup(5)?,
+            MovUp6 => self.movup(6)?,
+            MovUp7 => self.movup(7)?,
+            MovUp8 => self.movup(8)?,
+            MovDn2 => self.movdn(2)?,
+            MovDn3 => self.movdn(3)?,
+            MovDn4 => self.movdn(4)?,
+            MovDn5 => self.movdn(5)?,
+            MovDn6 => self.movdn(6)?,
+            MovDn7 => self.movdn(7)?,
+            MovDn8 => self.movdn(8)?,
+            CSwap => {
+                if binary(self.pop()?)? {
+                    self.swap_ranges(0, 1, 1)?;
+                }
+            },
+            CSwapW => {
+                if binary(self.pop()?)? {
+                    self.swap_ranges(0, 4, 4)?;
+                }
+            },
+
+            _ => return None,
+        }
+
+        Some(())
+    }
+
+    /// Returns the index in the vector of the element at `depth`, with the top at depth 0.
+    fn index(&self, depth: usize) -> Option<usize> {
+        self.0.len().checked_sub(depth + 1)
+    }
+
+    /// Returns the top `N` elements, the top of the stack being the last of them.
+    fn top<const N: usize>(&self) -> Option<[Felt; N]> {
+        let start = self.0.len().checked_sub(N)?;
+        self.0[start..].try_into().ok()
+    }
+
+    fn pop(&mut self) -> Option<Felt> {
+        self.0.pop()
+    }
+
+    fn pop_u32(&mut self) -> Option<u64> {
+        u32_value(self.pop()?)
+    }
+
+    fn push_u64(&mut self, value: u64) {
+        self.0.push(Felt::new_unchecked(value));
+    }
+
+    fn unary(&mut self, f: impl FnOnce(Felt) -> Option<Felt>) -> Option<()> {
+        let a = self.pop()?;
+        self.0.push(f(a)?);
+        Some(())
+    }
+
+    fn binary(&mut self, f: impl FnOnce(Felt, Felt) -> Option<Felt>) -> Option<()> {
+        let (b, a) = (self.pop()?, self.pop()?);
+        self.0.push(f(a, b)?);
+        Some(())
+    }
+
+    fn dup(&mut self, depth: usize) -> Option<()> {
+        let value = self.0[self.index(depth)?];
+        self.0.push(value);
+        Some(())
+    }
+
+    /// Swaps the `len` elements starting at `first` with the `len` elements starting at `second`,
+    /// where both are depths.
+    fn swap_ranges(&mut self, first: usize, second: usize, len: usize) -> Option<()> {
+        self.index(second + len - 1)?;
+        let top = self.0.len() - 1;
+        for offset in 0..len {
+            self.0.swap(top - first - offset, top - second - offset);
+        }
+        Some(())
+    }
+
+    fn movup(&mut self, depth: usize) -> Option<()> {
+        let value = self.0.remove(self.index(depth)?);
+        self.0.push(value);
+        Some(())
+    }
+
+    fn movdn(&mut self, depth: usize) -> Option<()> {
+        let index = self.index(depth)?;
+        let value = self.pop()?;
+        self.0.insert(index, value);
+        Some(())
+    }
+}
+
+/// Returns the value of `value` as a boolean, if it is binary.
+fn binary(value: Felt) -> Option<bool> {
+    if value == ZERO {
+        Some(false)
+    } else if value == ONE {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+/// Returns the value of `value`, if it is a valid u32 value.
+fn u32_value(value: Felt) -> Option<u64> {
+    let value = value.as_canonical_u64();
+    (value <= u32::MAX as u64).then_some(value)
+}
@@ -0,0 +1,406 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use core::fmt;
+
+use miden_assembly_syntax::{
+    ast::{Immediate, Instruction},
+    debuginfo::Span,
+    parser::{IntValue, PushValue},
+};
+use miden_core::{
+    Felt, ZERO,
+    mast::{MastForest, MastNode, MastNodeExt, MastNodeId},
+    operations::Operation,
+    program::Program,
+    utils::DisplayHex,
+};
+use miden_mast_package::{
+    Package, PackageExport,
+    debug_info::{DebugSourceNodeId, PackageDebugInfo},
+};
+
+use crate::diagnostics::Report;
+
+// DISASSEMBLER
+// ================================================================================================
+
+/// Reconstructs readable Miden Assembly from the MAST forest of a [`Program`] or [`Package`].
+///
+/// Every procedure root of the forest is rendered as a procedure, and the entrypoint of an
+/// executable is rendered as its `begin .. end` block. Procedures exported by a package are
+/// labeled with their exported paths, while all other procedure roots are given synthesized
+/// labels, i.e. `proc_<n>` for the `n`-th procedure root of the forest. Invocations of procedures
+/// which are not part of the forest are rendered by MAST root.
+///
+/// When the package carries debug information, basic blocks are rendered from the assembly
+/// instructions recorded for them. Otherwise, each operation is rendered as the instruction which
+/// assembles to it, with operations that have no single-instruction equivalent rendered as
+/// comments.
+pub struct Disassembler<'a> {
+    mast_forest: &'a MastForest,
+    entrypoint: Option<MastNodeId>,
+    labels: BTreeMap<MastNodeId, String>,
+    exported: BTreeMap<MastNodeId, Option<DebugSourceNodeId>>,
+    debug_info: Option<PackageDebugInfo>,
+}
+
+impl<'a> Disassembler<'a> {
+    /// Creates a disassembler for the procedure roots of `mast_forest`, without any labels or
+    /// debug information.
+    pub fn new(mast_forest: &'a MastForest) -> Self {
+        let labels = mast_forest
+            .procedure_roots()
+            .iter()
+            .enumerate()
+            .map(|(index, root)| (*root, format!("proc_{index}")))
+            .collect();
+        Self {
+            mast_forest,
+            entrypoint: None,
+            labels,
+            exported: BTreeMap::new(),
+            debug_info: None,
+        }
+    }
+
+    /// Creates a disassembler for `program`, whose entrypoint is rendered as a `begin` block.
+    pub fn from_program(program: &'a Program) -> Self {
+        let mut disassembler = Self::new(program.mast_forest());
+        disassembler.entrypoint = Some(program.entrypoint());
+        disassembler
+    }
+
+    /// Creates a disassembler for `package`, labeling its exported procedures and using its
+    /// debug information, if present.
+    ///
+    /// Returns an error if the debug information of `package` cannot be decoded.
+    pub fn from_package(package: &'a Package) -> Result<Self, Report> {
+        let mut disassembler = Self::new(package.mast_forest());
+        disassembler.debug_info = package
+            .debug_info()
+            .map_err(|err| Report::msg(format!("invalid package debug info: {err}")))?;
+        disassembler.entrypoint = package
+            .entrypoint()
+            .and_then(|entrypoint| package.get_procedure_node_by_path(&entrypoint));
+
+        for export in package.manifest.exports().filter_map(PackageExport::as_procedure) {
+            let Some(node_id) = package.get_export_node(export) else {
+                continue;
+            };
+            if Some(node_id) == disassembler.entrypoint
+                || disassembler.exported.contains_key(&node_id)
+            {
+                continue;
+            }
+            let source_node = export.source_node.or_else(|| disassembler.source_root(node_id));
+            disassembler
+                .labels
+                .insert(node_id, format!("\"{}\"", export.path.to_relative()));
+            disassembler.exported.insert(node_id, source_node);
+        }
+
+        Ok(disassembler)
+    }
+
+    /// Returns the reconstructed assembly as a string.
+    pub fn disassemble(&self) -> String {
+        self.to_string()
+    }
+}
+
+// RENDERING
+// ================================================================================================
+
+impl Disassembler<'_> {
+    fn render_procedure(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        index: usize,
+        root: MastNodeId,
+    ) -> fmt::Result {
+        let digest = self.mast_forest[root].digest();
+        if index > 0 {
+            writeln!(f)?;
+        }
+        writeln!(f, "# mast root: {:#x}", DisplayHex(digest.as_bytes().as_slice()))?;
+        let visibility = if self.exported.contains_key(&root) { "pub " } else { "" };
+        writeln!(f, "{visibility}proc {}", self.labels[&root])?;
+        self.render_node(f, root, self.procedure_source_node(root), 1)?;
+        writeln!(f, "end")
+    }
+
+    fn render_node(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        node_id: MastNodeId,
+        source_node: Option<DebugSourceNodeId>,
+        depth: usize,
+    ) -> fmt::Result {
+        match &self.mast_forest[node_id] {
+            MastNode::Block(block) => {
+                let mut asm_ops = source_node
+                    .zip(self.debug_info.as_ref())
+                    .map(|(source_node, debug_info)| {
+                        debug_info.asm_ops_for_source_node(source_node).collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+                asm_ops.sort_by_key(|asm_op| asm_op.op_idx);
+
+                // Operations not covered by the recorded assembly instructions are rendered from
+                // the operations themselves
+                let first_asm_op = asm_ops.first().map_or(usize::MAX, |op| op.op_idx as usize);
+                let ops: Vec<&Operation> = if asm_ops.is_empty() {
+                    block.raw_operations().collect()
+                } else {
+                    block.operations().take(first_asm_op).collect()
+                };
+                for op in ops {
+                    self.render_line(f, depth, &self.render_operation(op))?;
+                }
+                for asm_op in asm_ops {
+                    self.render_line(f, depth, &asm_op.op)?;
+                }
+                Ok(())
+            },
+            MastNode::Join(join) => {
+                self.render_node(f, join.first(), self.child_source_node(source_node, 0), depth)?;
+                self.render_node(f, join.second(), self.child_source_node(source_node, 1), depth)
+            },
+            MastNode::Split(split) => {
+                self.render_line(f, depth, "if.true")?;
+                let on_true = self.child_source_node(source_node, 0);
+                self.render_node(f, split.on_true(), on_true, depth + 1)?;
+                self.render_line(f, depth, "else")?;
+                let on_false = self.child_source_node(source_node, 1);
+                self.render_node(f, split.on_false(), on_false, depth + 1)?;
+                self.render_line(f, depth, "end")
+            },
+            MastNode::Loop(loop_node) => {
+                self.render_line(f, depth, "while.true")?;
+                let body = self.child_source_node(source_node, 0);
+                self.render_node(f, loop_node.body(), body, depth + 1)?;
+                self.render_line(f, depth, "end")
+            },
+            MastNode::Call(call) => {
+                let instruction = if call.is_syscall() { "syscall" } else { "call" };
+                let target = self.invocation_target(call.callee());
+                self.render_line(f, depth, &format!("{instruction}.{target}"))
+            },
+            MastNode::Dyn(dyn_node) => {
+                let instruction = if dyn_node.is_dyncall() { "dyncall" } else { "dynexec" };
+                self.render_line(f, depth, instruction)
+            },
+            MastNode::External(_) => {
+                let target = self.invocation_target(node_id);
+                self.render_line(f, depth, &format!("exec.{target}"))
+            },
+        }
+    }
+
+    fn render_line(&self, f: &mut fmt::Formatter<'_>, depth: usize, line: &str) -> fmt::Result {
+        writeln!(f, "{:indent$}{line}", "", indent = depth * 4)
+    }
+
+    /// Renders `op` as the instruction which assembles to it, or as a comment if there is none.
+    fn render_operation(&self, op: &Operation) -> String {
+        match op {
+            Operation::Assert(err_code)
+            | Operation::MpVerify(err_code)
+            | Operation::U32assert2(err_code) => {
+                let message = self
+                    .debug_info
+                    .as_ref()
+                    .and_then(|debug_info| debug_info.error_message(err_code.as_canonical_u64()));
+                let Some(message) = message else {
+                    let instruction = match op {
+                        Operation::Assert(_) => Instruction::Assert,
+                        Operation::MpVerify(_) => Instruction::MTreeVerify,
+                        _ => Instruction::U32Assert2,
+                    };
+                    return if *err_code == ZERO {
+                        instruction.to_string()
+                    } else {
+                        format!("{instruction} # error code {err_code}")
+                    };
+                };
+                let instruction = match op {
+                    Operation::Assert(_) => Instruction::AssertWithError(error_msg(message)),
+                    Operation::MpVerify(_) => Instruction::MTreeVerifyWithError(error_msg(message)),
+                    _ => Instruction::U32Assert2WithError(error_msg(message)),
+                };
+                instruction.to_string()
+            },
+            Operation::Push(value) => push_instruction(*value).to_string(),
+            Operation::Pad => push_instruction(ZERO).to_string(),
+            op => match instruction_for_operation(op) {
+                Some(instruction) => instruction.to_string(),
+                None => format!("# {op}"),
+            },
+        }
+    }
+
+    /// Returns the label of the procedure rooted at `node_id`, or its MAST root if it is not a
+    /// procedure of this forest.
+    fn invocation_target(&self, node_id: MastNodeId) -> String {
+        match self.labels.get(&node_id) {
+            Some(label) => label.clone(),
+            None => {
+                let digest = self.mast_forest[node_id].digest();
+                format!("{:#x}", DisplayHex(digest.as_bytes().as_slice()))
+            },
+        }
+    }
+
+    fn procedure_source_node(&self, root: MastNodeId) -> Option<DebugSourceNodeId> {
+        match self.exported.get(&root) {
+            Some(source_node) => *source_node,
+            None => self.source_root(root),
+        }
+    }
+
+    fn source_root(&self, node_id: MastNodeId) -> Option<DebugSourceNodeId> {
+        self.debug_info
+            .as_ref()?
+            .unique_source_root_for_exec_node(node_id)
+            .ok()
+            .flatten()
+    }
+
+    fn child_source_node(
+        &self,
+        parent: Option<DebugSourceNodeId>,
+        child_index: usize,
+    ) -> Option<DebugSourceNodeId> {
+        self.debug_info
+            .as_ref()?
+            .child_source_node(parent?, child_index)
+            .ok()
+            .flatten()
+            .map(|(source_node, _)| source_node)
+    }
+}
+
+impl fmt::Display for Disassembler<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let procedures = self
+            .mast_forest
+            .procedure_roots()
+            .iter()
+            .copied()
+            .filter(|root| Some(*root) != self.entrypoint);
+        let mut num_procedures = 0;
+        for (index, root) in procedures.enumerate() {
+            self.render_procedure(f, index, root)?;
+            num_procedures += 1;
+        }
+
+        if let Some(entrypoint) = self.entrypoint {
+            if num_procedures > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "begin")?;
+            self.render_node(f, entrypoint, self.source_root(entrypoint), 1)?;
+            writeln!(f, "end")?;
+        }
+
+        Ok(())
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+fn error_msg(message: Arc<str>) -> Immediate<Arc<str>> {
+    Immediate::Value(Span::unknown(message))
+}
+
+fn push_instruction(value: Felt) -> Instruction {
+    Instruction::Push(Immediate::Value(Span::unknown(PushValue::Int(IntValue::Felt(value)))))
+}
+
+/// Returns the instruction which assembles to exactly `op`, if there is one.
+fn instruction_for_operation(op: &Operation) -> Option<Instruction> {
+    use Operation::*;
+
+    let instruction = match op {
+        Noop => Instruction::Nop,
+        SDepth => Instruction::Sdepth,
+        Caller => Instruction::Caller,
+        Clk => Instruction::Clk,
+        Add => Instruction::Add,
+        Neg => Instruction::Neg,
+        Mul => Instruction::Mul,
+        Inv => Instruction::Inv,
+        Incr => Instruction::Incr,
+        And => Instruction::And,
+        Or => Instruction::Or,
+        Not => Instruction::Not,
+        Eq => Instruction::Eq,
+        Eqz => Instruction::EqImm(Immediate::Value(Span::unknown(ZERO))),
+        U32split => Instruction::U32Split,
+        U32add => Instruction::U32WideningAdd,
+        U32add3 => Instruction::U32WideningAdd3,
+        U32sub => Instruction::U32OverflowingSub,
+        U32mul => Instruction::U32WideningMul,
+        U32madd => Instruction::U32WideningMadd,
+        U32div => Instruction::U32DivMod,
+        U32and => Instruction::U32And,
+        U32xor => Instruction::U32Xor,
+        Drop => Instruction::Drop,
+        Dup0 => Instruction::Dup0,
+        Dup1 => Instruction::Dup1,
+        Dup2 => Instruction::Dup2,
+        Dup3 => Instruction::Dup3,
+        Dup4 => Instruction::Dup4,
+        Dup5 => Instruction::Dup5,
+        Dup6 => Instruction::Dup6,
+        Dup7 => Instruction::Dup7,
+        Dup9 => Instruction::Dup9,
+        Dup11 => Instruction::Dup11,
+        Dup13 => Instruction::Dup13,
+        Dup15 => Instruction::Dup15,
+        Swap => Instruction::Swap1,
+        SwapW => Instruction::SwapW1,
+        SwapW2 => Instruction::SwapW2,
+        SwapW3 => Instruction::SwapW3,
+        SwapDW => Instruction::SwapDw,
+        Emit => Instruction::Emit,
+        MovUp2 => Instruction::MovUp2,
+        MovUp3 => Instruction::MovUp3,
+        MovUp4 => Instruction::MovUp4,
+        MovUp5 => Instruction::MovUp5,
+        MovUp6 => Instruction::MovUp6,
+        MovUp7 => Instruction::MovUp7,
+        MovUp8 => Instruction::MovUp8,
+        MovDn2 => Instruction::MovDn2,
+        MovDn3 => Instruction::MovDn3,
+        MovDn4 => Instruction::MovDn4,
+        MovDn5 => Instruction::MovDn5,
+        MovDn6 => Instruction::MovDn6,
+        MovDn7 => Instruction::MovDn7,
+        MovDn8 => Instruction::MovDn8,
+        CSwap => Instruction::CSwap,
+        CSwapW => Instruction::CSwapW,
+        AdvPop => Instruction::AdvPush,
+        AdvPopW => Instruction::AdvLoadW,
+        MLoadW => Instruction::MemLoadWLe,
+        MStoreW => Instruction::MemStoreWLe,
+        MLoad => Instruction::MemLoad,
+        MStream => Instruction::MemStream,
+        Pipe => Instruction::AdvPipe,
+        CryptoStream => Instruction::CryptoStream,
+        HPerm => Instruction::HPerm,
+        FriE2F4 => Instruction::FriExt2Fold4,
+        HornerBase => Instruction::HornerBase,
+        HornerExt => Instruction::HornerExt,
+        EvalCircuit => Instruction::EvalCircuit,
+        LogDeferred => Instruction::LogDeferred,
+        Expacc | Ext2Mul | MStore | MrUpdate | Assert(_) | MpVerify(_) | U32assert2(_) | Pad
+        | Push(_) => return None,
+    };
+    Some(instruction)
+}
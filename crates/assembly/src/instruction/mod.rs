@@ -541,15 +541,16 @@ impl Assembler {
 
             // ----- exec/call instructions -------------------------------------------------------
             Instruction::Exec(callee) => {
-                return self
-                    .invoke(
-                        InvokeKind::Exec,
-                        callee,
-                        proc_ctx.id(),
-                        block_builder.mast_forest_builder_mut(),
-                        None,
-                    )
-                    .map(Into::into);
+                let node_ref = self.invoke(
+                    InvokeKind::Exec,
+                    callee,
+                    proc_ctx.id(),
+                    block_builder.mast_forest_builder_mut(),
+                    None,
+                )?;
+                if !block_builder.fold_constant_invocation(node_ref) {
+                    return Ok(Some(node_ref));
+                }
             },
             Instruction::Call(callee) => {
                 return self
@@ -74,6 +74,9 @@ impl Assembler {
                 caller.module,
                 block_builder.mast_forest_builder_mut(),
             )?;
+            block_builder
+                .mast_forest_builder_mut()
+                .record_procref_target_ref(caller, resolved.node);
             // Note: it's ok to `unwrap()` here since `proc_body_id` was returned from
             // `mast_forest_builder`
             block_builder.mast_forest_builder().mast_root_for_ref(resolved.node).unwrap()
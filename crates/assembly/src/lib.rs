@@ -11,10 +11,14 @@ use miden_core::{Felt, ONE, ZERO, operations::Operation};
 
 mod assembler;
 mod basic_block_builder;
+mod checked;
+mod const_eval;
+mod disassembler;
 mod fmp;
 mod instruction;
 pub mod linker;
 mod mast_forest_builder;
+mod peephole;
 mod procedure;
 #[cfg(feature = "std")]
 mod project;
@@ -28,14 +32,14 @@ mod tests;
 
 // Re-exported for downstream crates
 pub use miden_assembly_syntax::{
-    ModuleParser, Path, PathBuf, ast,
+    Lint, LintLevel, ModuleParser, ParseOptions, Path, PathBuf, ast,
     ast::{GlobalItemIndex, ModuleIndex},
     debuginfo::{
         self, DefaultSourceManager, SourceFile, SourceId, SourceManager, SourceSpan, Span, Spanned,
     },
     diagnostics,
     diagnostics::{Report, report},
-    module,
+    docs, module,
 };
 /// Syntax components for the Miden Assembly AST
 /// Merkelized abstract syntax tree (MAST) components defining Miden VM programs.
@@ -51,8 +55,13 @@ pub use self::project::{
     SourceProviderRegistry, TargetAssemblyContext,
 };
 pub use self::{
-    assembler::Assembler,
+    assembler::{
+        Assembler, CompileCache, DeadCodeReport, NodeHandle, ProgramBuilder, ProgramBuilderError,
+    },
+    checked::{AssemblyDiagnostic, assemble_checked},
+    disassembler::Disassembler,
     linker::Linkage,
+    peephole::OptimizationLevel,
     procedure::{Procedure, ProcedureContext},
 };
 
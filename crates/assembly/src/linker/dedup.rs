@@ -0,0 +1,94 @@
+use alloc::{collections::BTreeSet, sync::Arc, vec::Vec};
+
+use miden_core::{
+    Word,
+    mast::{MastForest, MastForestRootMap, MastNodeId},
+};
+
+use super::{LinkLibrary, Linker, LinkerError};
+
+// DEDUPLICATED FOREST
+// ================================================================================================
+
+/// A single [MastForest] holding the MAST of several libraries, in which the procedures and
+/// subtrees shared by more than one of them are stored only once.
+///
+/// See [Linker::deduplicate_libraries].
+#[derive(Debug, Clone)]
+pub struct DeduplicatedForest {
+    /// The merged forest.
+    mast_forest: Arc<MastForest>,
+    /// The commitments of the forests that were merged, in the order they were merged in.
+    sources: Vec<Word>,
+    /// Where the nodes of each of the merged forests ended up in `mast_forest`.
+    root_map: MastForestRootMap,
+    /// The total number of nodes of the merged forests.
+    num_source_nodes: usize,
+}
+
+impl DeduplicatedForest {
+    /// Returns the merged forest.
+    pub fn mast_forest(&self) -> &Arc<MastForest> {
+        &self.mast_forest
+    }
+
+    /// Returns the commitments of the forests that were merged, in the order they were merged in.
+    pub fn sources(&self) -> &[Word] {
+        &self.sources
+    }
+
+    /// Returns the ID in the merged forest of the procedure root `root` of the forest with
+    /// commitment `source`, if that forest was merged.
+    pub fn map_root(&self, source: Word, root: MastNodeId) -> Option<MastNodeId> {
+        let index = self.sources.iter().position(|commitment| *commitment == source)?;
+        self.root_map.map_root(index, &root)
+    }
+
+    /// Returns the number of nodes which were removed by deduplication.
+    pub fn num_removed_nodes(&self) -> usize {
+        self.num_source_nodes.saturating_sub(self.mast_forest.nodes().len())
+    }
+}
+
+/// Link-time deduplication
+impl Linker {
+    /// Merges the MAST forests of the kernel and of all libraries linked with this linker into a
+    /// single [DeduplicatedForest].
+    ///
+    /// Libraries which statically link the same dependencies, or which happen to define identical
+    /// procedures, otherwise each carry their own copy of the shared MAST. Providing the merged
+    /// forest to the processor in place of the individual forests stores such procedures once.
+    ///
+    /// The kernel, if any, is merged first, followed by the libraries in the order they were
+    /// linked. Libraries with the same MAST forest are merged only once.
+    pub fn deduplicate_libraries(&self) -> Result<DeduplicatedForest, LinkerError> {
+        let mut seen = BTreeSet::new();
+        let forests = self
+            .kernel_package
+            .iter()
+            .map(|package| package.mast_forest())
+            .chain(self.libraries().map(LinkLibrary::mast))
+            .filter(|forest| seen.insert(forest.commitment()))
+            .collect::<Vec<_>>();
+
+        let sources = forests.iter().map(|forest| forest.commitment()).collect();
+        let num_source_nodes = forests.iter().map(|forest| forest.nodes().len()).sum();
+        let (mast_forest, root_map) = MastForest::merge(forests.iter().map(AsRef::as_ref))
+            .map_err(|error| LinkerError::LibraryMerge { error })?;
+
+        let deduplicated = DeduplicatedForest {
+            mast_forest: Arc::new(mast_forest),
+            sources,
+            root_map,
+            num_source_nodes,
+        };
+        log::debug!(
+            target: "linker",
+            "merged {} library forests, removing {} duplicate nodes",
+            deduplicated.sources.len(),
+            deduplicated.num_removed_nodes(),
+        );
+
+        Ok(deduplicated)
+    }
+}
@@ -9,7 +9,7 @@ use miden_assembly_syntax::{
     debuginfo::{SourceFile, SourceSpan},
     diagnostics::{Diagnostic, RelatedError, RelatedLabel, miette},
 };
-use miden_core::mast::MastNodeId;
+use miden_core::mast::{MastForestError, MastNodeId};
 
 // LINKER ERROR
 // ================================================================================================
@@ -102,6 +102,20 @@ pub enum LinkerError {
         name: String,
         kind: &'static str,
     },
+    #[error("ambiguous name '{name}' in module '{module}'")]
+    #[diagnostic(help(
+        "'{name}' is imported by more than one wildcard import, import it explicitly to disambiguate"
+    ))]
+    AmbiguousGlobImport {
+        #[label("'{name}' is imported by this wildcard import")]
+        span: SourceSpan,
+        #[source_code]
+        source_file: Option<Arc<SourceFile>>,
+        module: Arc<Path>,
+        name: String,
+        #[related]
+        imported: Box<[RelatedLabel]>,
+    },
     #[error("modules cannot be re-exported with `pub use`: '{path}'")]
     #[diagnostic(help(
         "declare the module with `pub mod` in its parent module instead of re-exporting it"
@@ -259,6 +273,12 @@ pub enum LinkerError {
         prev_values: Vec<Felt>,
         new_values: Vec<Felt>,
     },
+    #[error("failed to merge the MAST forests of the linked libraries")]
+    #[diagnostic()]
+    LibraryMerge {
+        #[source]
+        error: MastForestError,
+    },
     #[error("undefined type alias")]
     #[diagnostic()]
     UndefinedType {
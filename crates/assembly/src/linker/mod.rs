@@ -37,6 +37,7 @@
 //!    final issues are discovered, and the AST is prepared for lowering to MAST.
 mod callgraph;
 mod debug;
+mod dedup;
 mod errors;
 mod library;
 mod module;
@@ -58,6 +59,7 @@ use miden_assembly_syntax::{
         Path, SymbolResolution, Visibility, types,
     },
     debuginfo::{SourceManager, SourceSpan, Span, Spanned},
+    diagnostics::RelatedLabel,
     module::{ItemInfo, ModuleDescriptor},
 };
 use miden_core::{Word, advice::AdviceMap, mast::MastNodeId, program::KernelDescriptor};
@@ -66,6 +68,7 @@ use smallvec::{SmallVec, smallvec};
 
 pub use self::{
     callgraph::{CallGraph, CycleError},
+    dedup::DeduplicatedForest,
     errors::LinkerError,
     library::{LinkLibrary, Linkage},
     namespaces::NamespaceGraph,
@@ -324,6 +327,7 @@ impl Linker {
         let submodules = module.submodules().to_vec();
         let mut symbols = Vec::new();
         let imports = module.take_imports().into_iter().map(Import::new).collect::<Vec<_>>();
+        let glob_imports = module.take_glob_imports();
         for item in module.take_items() {
             match item {
                 ast::Item::Type(item) => {
@@ -368,12 +372,104 @@ impl Linker {
         .with_advice_map(module.advice_map().clone())
         .with_submodules(submodules)
         .with_imports(imports)
+        .with_glob_imports(glob_imports)
         .with_symbols(symbols);
 
         self.modules.push(link_module);
         Ok(module_index)
     }
 
+    /// Expands the wildcard imports of all modules into imports of the individual items they
+    /// import.
+    ///
+    /// A wildcard import of a module imports every public item of that module, including the items
+    /// it re-exports, except for those whose names are already declared by the importing module.
+    /// As re-exports may themselves be the product of wildcard imports, expansion is repeated until
+    /// no new imports are produced.
+    ///
+    /// It is an error for two wildcard imports of a module to import the same name, unless both
+    /// originate from the same module through wildcard re-exports.
+    fn expand_glob_imports(&mut self) -> Result<(), LinkerError> {
+        if !self.modules.iter().any(LinkModule::has_glob_imports) {
+            return Ok(());
+        }
+
+        let mut globs = Vec::new();
+        {
+            let namespaces = NamespaceGraph::build(self)?;
+            for module in self.modules.iter() {
+                if !module.has_glob_imports() {
+                    continue;
+                }
+                for glob in module.glob_imports() {
+                    let target =
+                        namespaces.resolve_glob_target(module.id(), glob.module_path(), self)?;
+                    globs.push((module.id(), target, glob.clone()));
+                }
+            }
+        }
+        for module in self.modules.iter_mut() {
+            module.take_glob_imports();
+        }
+
+        // The wildcard import, as an index in `globs`, through which each name was imported into
+        // each module
+        let mut glob_names: BTreeMap<(ModuleIndex, Arc<str>), usize> = BTreeMap::new();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (glob_index, (owner, target, glob)) in globs.iter().enumerate() {
+                let target_module = &self[*target];
+                let names = target_module
+                    .symbols()
+                    .filter(|symbol| symbol.visibility().is_public())
+                    .map(|symbol| symbol.name().clone().into_inner())
+                    .chain(
+                        target_module
+                            .imports()
+                            .filter(|import| import.visibility().is_public())
+                            .map(|import| import.local_name().clone().into_inner()),
+                    )
+                    .collect::<Vec<_>>();
+                for name in names {
+                    if !self[*owner].declares(&name) {
+                        glob_names.insert((*owner, name.clone()), glob_index);
+                        let import = ast::Import::Item(glob.item_import(name));
+                        self.modules[owner.as_usize()].push_import(Import::new(import));
+                        changed = true;
+                        continue;
+                    }
+
+                    // Names declared by the module itself take precedence over wildcard imports
+                    let Some(&other_index) = glob_names.get(&(*owner, name.clone())) else {
+                        continue;
+                    };
+                    let (_, other_target, other_glob) = &globs[other_index];
+                    if glob_origin(&globs, &glob_names, *target, &name)
+                        == glob_origin(&globs, &glob_names, *other_target, &name)
+                    {
+                        continue;
+                    }
+                    return Err(LinkerError::AmbiguousGlobImport {
+                        span: glob.span(),
+                        source_file: self.source_manager.get(glob.span().source_id()).ok(),
+                        module: self[*owner].path().clone(),
+                        name: name.to_string(),
+                        imported: Box::new([RelatedLabel::advice(
+                            "the name is also imported here",
+                        )
+                        .with_labeled_span(other_glob.span(), "also imported here")
+                        .with_source_file(
+                            self.source_manager.get(other_glob.span().source_id()).ok(),
+                        )]),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     #[inline]
     fn next_module_id(&self) -> ModuleIndex {
         ModuleIndex::new(self.modules.len())
@@ -483,6 +579,7 @@ impl Linker {
 
         let root_indices = self.link_modules(roots)?;
         let _support_indices = self.link_modules(support)?;
+        self.expand_glob_imports()?;
         let namespaces = NamespaceGraph::build(self)?;
         let imports = namespaces.resolve_imports(self)?;
 
@@ -533,6 +630,7 @@ impl Linker {
         self.kernel_index = Some(module_index);
 
         let result = (|| {
+            self.expand_glob_imports()?;
             let namespaces = NamespaceGraph::build(self)?;
             let imports = namespaces.resolve_imports(self)?;
             self.link_and_rewrite(&namespaces, &imports)?;
@@ -746,6 +844,11 @@ impl Linker {
         self.callgraph.toposort_caller(caller)
     }
 
+    /// Returns the items invoked by `caller`, i.e., its outbound edges in the callgraph.
+    pub(crate) fn callees(&self, caller: GlobalItemIndex) -> &[GlobalItemIndex] {
+        self.callgraph.out_edges(caller)
+    }
+
     /// Returns a procedure index which corresponds to the provided procedure digest.
     ///
     /// Note that there can be many procedures with the same digest. This method returns an
@@ -980,6 +1083,20 @@ impl Index<GlobalItemIndex> for Linker {
     }
 }
 
+/// Returns the module in which `name`, as exported by `module`, is declared, by following the
+/// wildcard imports recorded in `glob_names` through which it was imported.
+fn glob_origin(
+    globs: &[(ModuleIndex, ModuleIndex, ast::GlobImport)],
+    glob_names: &BTreeMap<(ModuleIndex, Arc<str>), usize>,
+    mut module: ModuleIndex,
+    name: &Arc<str>,
+) -> ModuleIndex {
+    while let Some(&index) = glob_names.get(&(module, name.clone())) {
+        module = globs[index].1;
+    }
+    module
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
@@ -4,8 +4,8 @@ use core::{cell::Cell, ops::Index};
 use miden_assembly_syntax::{
     Path,
     ast::{
-        ItemIndex, LocalSymbol, LocalSymbolResolver, ModuleIndex, ModuleKind, SubmoduleDecl,
-        SymbolResolution, SymbolResolutionError, SymbolTable,
+        GlobImport, ItemIndex, LocalSymbol, LocalSymbolResolver, ModuleIndex, ModuleKind,
+        SubmoduleDecl, SymbolResolution, SymbolResolutionError, SymbolTable,
     },
     debuginfo::{SourceManager, SourceSpan, Span, Spanned},
 };
@@ -48,6 +48,9 @@ pub struct LinkModule {
     symbols: Vec<Symbol>,
     /// The set of imports declared by this module.
     imports: Vec<Import>,
+    /// The set of wildcard imports declared by this module, which have yet to be expanded into
+    /// [Import]s of the individual items they import.
+    glob_imports: Vec<GlobImport>,
     /// The set of submodules declared by this module.
     submodules: Vec<SubmoduleDecl>,
     /// An optional [AdviceMap] to merge into the advice data of the assembled artifact.
@@ -73,6 +76,7 @@ impl LinkModule {
             path,
             symbols: Vec::default(),
             imports: Vec::default(),
+            glob_imports: Vec::default(),
             submodules: Vec::default(),
             advice_map: None,
         }
@@ -95,6 +99,13 @@ impl LinkModule {
         self
     }
 
+    /// Load the wildcard imports declared by this module.
+    #[inline]
+    pub fn with_glob_imports(mut self, glob_imports: Vec<GlobImport>) -> Self {
+        self.glob_imports = glob_imports;
+        self
+    }
+
     /// Load the submodules declared by this module.
     #[inline]
     pub fn with_submodules(mut self, submodules: Vec<SubmoduleDecl>) -> Self {
@@ -183,6 +194,18 @@ impl LinkModule {
         self.symbols.iter()
     }
 
+    /// Returns true if this module has wildcard imports which have yet to be expanded.
+    #[inline]
+    pub fn has_glob_imports(&self) -> bool {
+        !self.glob_imports.is_empty()
+    }
+
+    /// Get an iterator over the unexpanded wildcard imports declared by this module.
+    #[inline]
+    pub fn glob_imports(&self) -> core::slice::Iter<'_, GlobImport> {
+        self.glob_imports.iter()
+    }
+
     /// Get an iterator over the imports declared by this module.
     #[inline]
     pub fn imports(&self) -> core::slice::Iter<'_, Import> {
@@ -213,6 +236,23 @@ impl LinkModule {
         self.imports.iter().find(|import| import.local_name().as_str() == name)
     }
 
+    /// Returns true if `name` is declared by this module, whether as an item, import or submodule.
+    pub fn declares(&self, name: &str) -> bool {
+        self.get(name).is_some()
+            || self.get_import(name).is_some()
+            || self.submodules.iter().any(|submodule| submodule.name.as_str() == name)
+    }
+
+    /// Take the wildcard imports declared by this module, leaving it with none.
+    pub(super) fn take_glob_imports(&mut self) -> Vec<GlobImport> {
+        core::mem::take(&mut self.glob_imports)
+    }
+
+    /// Add `import` to the set of imports declared by this module.
+    pub(super) fn push_import(&mut self, import: Import) {
+        self.imports.push(import);
+    }
+
     /// Resolve `name` relative to this module, using `resolver` for externally-defined symbols.
     pub fn resolve(
         &self,
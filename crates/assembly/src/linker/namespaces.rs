@@ -259,6 +259,9 @@ impl NamespaceGraph {
                     Ok(())
                 }
             },
+            (ImportKind::Glob, _) => {
+                unreachable!("wildcard imports are expanded before imports are resolved")
+            },
             (ImportKind::Item, ResolvedUse::Module(id)) => {
                 Err(LinkerError::InvalidItemImportTarget {
                     span: import.span(),
@@ -269,6 +272,24 @@ impl NamespaceGraph {
         }
     }
 
+    /// Resolve the module imported by a wildcard import of `path` declared in `owner`.
+    pub fn resolve_glob_target(
+        &self,
+        owner: ModuleIndex,
+        path: Span<&Path>,
+        linker: &Linker,
+    ) -> Result<ModuleIndex, LinkerError> {
+        let module = self.find_import_target_parent(owner, path.inner()).ok_or_else(|| {
+            LinkerError::UndefinedModule {
+                span: path.span(),
+                source_file: source_file(linker.source_manager.as_ref(), path.span()),
+                path: path.into_inner().to_path_buf().into_boxed_path().into(),
+            }
+        })?;
+        self.ensure_module_visible(owner, module, path.span(), linker)?;
+        Ok(module)
+    }
+
     fn public_import_dependency(&self, import: &UseDecl) -> Option<(ModuleIndex, String)> {
         let path = import.target().as_deref();
         let (name, parent_path) = path.split_last()?;
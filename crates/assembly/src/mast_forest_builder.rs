@@ -1,5 +1,10 @@
-use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+    vec::Vec,
+};
 
+use miden_assembly_syntax::{ast::Path, debuginfo::Location};
 use miden_core::{
     Felt, Word,
     advice::AdviceMap,
@@ -36,13 +41,23 @@ mod static_import;
 // CONSTANTS
 // ================================================================================================
 
-/// Constant that decides how many operation batches disqualify a procedure from inlining.
+/// Constant that decides how many operation batches disqualify a procedure from inlining, unless
+/// configured otherwise via [`MastForestBuilder::set_inlining_threshold`].
 const PROCEDURE_INLINING_THRESHOLD: usize = 32;
 
 /// Domain used when basic-block interning keys must include execution-visible error codes.
 const BASIC_BLOCK_ERROR_CODE_KEY_DOMAIN: Felt = Felt::new_unchecked(0x2473_0001);
 /// Domain used when control-node interning keys must include child keys.
 const CHILD_KEY_DOMAIN: Felt = Felt::new_unchecked(0x2473_0002);
+/// Domain used when basic-block interning keys must include the number of operations, as `noop`s
+/// may be absorbed into the padding of a block without changing its digest.
+const BASIC_BLOCK_NUM_OPS_KEY_DOMAIN: Felt = Felt::new_unchecked(0x2473_0003);
+
+/// A source node merged into a basic block: its starting operation index in the merged block, the
+/// `exec` call site it was reached through (if any), and the copies of its assembly operations
+/// with that call site appended.
+type MergedSourceOccurrence =
+    (SourceNodeRef, usize, Option<Location>, BTreeMap<AsmOpRef, AsmOpRef>);
 
 // MAST FOREST BUILDER
 // ================================================================================================
@@ -70,6 +85,15 @@ pub struct MastForestBuilder {
     proc_gid_by_mast_root: BTreeMap<Word, GlobalItemIndex>,
     /// Procedure roots recorded by builder-local node ref until finalization.
     procedure_root_refs: Vec<MastNodeRef>,
+    /// The procedures annotated with `@inline`, whose bodies are spliced into the bodies of their
+    /// callers at `exec` sites.
+    inline_procedures: BTreeSet<GlobalItemIndex>,
+    /// The number of operation batches which disqualify a procedure from inlining, or `None` to
+    /// use [`PROCEDURE_INLINING_THRESHOLD`].
+    inlining_threshold: Option<usize>,
+    /// The procedures whose MAST root is taken with `procref`, indexed by the referencing
+    /// procedure. These are invisible to MAST edges, but must survive dead-code elimination.
+    procref_target_refs: BTreeMap<GlobalItemIndex, Vec<MastNodeRef>>,
     /// Procedure roots recorded by builder-local source/debug occurrence ref until finalization.
     procedure_source_root_refs: Vec<SourceNodeRef>,
     /// Number of source/debug occurrences already selected as procedure roots per execution ref.
@@ -378,15 +402,29 @@ impl MastForestBuilder {
         op_batches: &[OpBatch],
     ) -> MastNodeKey {
         debug_assert!(!op_batches.is_empty());
+        // Blocks which only differ by `noop`s absorbed into padding share a digest, but not the
+        // operation indices their debug info refers to
+        let mut block_key = block_digest;
+        let raw_ops = || op_batches.iter().flat_map(OpBatch::raw_ops);
+        if raw_ops().any(|op| matches!(op, Operation::Noop)) {
+            let num_ops = raw_ops().count() as u64;
+            let mut elements = Vec::with_capacity(7);
+            elements.push(BASIC_BLOCK_NUM_OPS_KEY_DOMAIN);
+            elements.extend_from_slice(block_digest.as_elements());
+            elements.push(Felt::from_u32(num_ops as u32));
+            elements.push(Felt::from_u32((num_ops >> 32) as u32));
+            block_key = hasher::hash_elements(&elements);
+        }
+
         let error_code_data = serialize_basic_block_error_codes(op_batches);
         if error_code_data.is_empty() {
-            return block_digest;
+            return block_key;
         }
 
         let data_len = error_code_data.len() as u64;
         let mut elements = Vec::with_capacity(7 + error_code_data.len().div_ceil(4));
         elements.push(BASIC_BLOCK_ERROR_CODE_KEY_DOMAIN);
-        elements.extend_from_slice(block_digest.as_elements());
+        elements.extend_from_slice(block_key.as_elements());
         elements.push(Felt::from_u32(data_len as u32));
         elements.push(Felt::from_u32((data_len >> 32) as u32));
         elements.extend(bytes_to_packed_u32_elements(&error_code_data));
@@ -400,6 +438,11 @@ impl MastForestBuilder {
             .wrap_err("assembler created too many assembly op refs")
     }
 
+    /// Returns a mutable reference to the [`AssemblyOp`] referenced by `asm_op_ref`.
+    pub(crate) fn asm_op_mut(&mut self, asm_op_ref: AsmOpRef) -> &mut AssemblyOp {
+        &mut self.asm_op_by_ref[asm_op_ref]
+    }
+
     fn push_debug_var_ref(&mut self, debug_var: DebugVarInfo) -> Result<DebugVarRef, Report> {
         self.debug_vars
             .push(debug_var)
@@ -687,6 +730,23 @@ impl MastForestBuilder {
     fn pending_basic_block_op_batches(&self, node_ref: MastNodeRef) -> Option<&[OpBatch]> {
         self.nodes[node_ref].kind.basic_block_op_batches()
     }
+
+    /// Returns the operations of `node_ref`, if it is a basic block.
+    pub(crate) fn basic_block_operations(&self, node_ref: MastNodeRef) -> Option<Vec<Operation>> {
+        let op_batches = self.pending_basic_block_op_batches(node_ref)?;
+        Some(op_batches.iter().flat_map(|op_batch| op_batch.raw_ops().copied()).collect())
+    }
+
+    /// Returns the total number of operations in the basic blocks among `node_refs`, ignoring any
+    /// other nodes.
+    pub(crate) fn num_basic_block_operations(&self, node_refs: &[MastNodeRef]) -> usize {
+        node_refs
+            .iter()
+            .filter_map(|&node_ref| self.pending_basic_block_op_batches(node_ref))
+            .flat_map(|op_batches| op_batches.iter())
+            .map(|op_batch| op_batch.raw_ops().count())
+            .sum()
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -773,6 +833,124 @@ impl MastForestBuilder {
     fn is_procedure_root_ref(&self, node_ref: MastNodeRef) -> bool {
         self.procedure_root_refs.contains(&node_ref)
     }
+
+    /// Sets the number of operation batches which disqualify a procedure from being inlined into
+    /// its callers, in place of [`PROCEDURE_INLINING_THRESHOLD`].
+    pub(crate) fn set_inlining_threshold(&mut self, num_op_batches: usize) {
+        self.inlining_threshold = Some(num_op_batches);
+    }
+
+    /// Records that the procedure `gid` is annotated with `@inline`.
+    ///
+    /// This is tracked per procedure rather than per body node, as the body of such a procedure
+    /// may be shared with procedures which are not annotated.
+    pub(crate) fn record_inline_procedure(&mut self, gid: GlobalItemIndex) {
+        self.inline_procedures.insert(gid);
+    }
+
+    /// Returns true if the procedure `gid` is annotated with `@inline`.
+    pub(crate) fn is_inline_procedure(&self, gid: GlobalItemIndex) -> bool {
+        self.inline_procedures.contains(&gid)
+    }
+
+    /// Returns the sequence of nodes executed by `node_ref`, obtained by flattening the JOIN nodes
+    /// at the top of its subtree.
+    ///
+    /// Joining the returned nodes, along with the nodes preceding and following them in a body,
+    /// yields the same behavior as executing `node_ref` in place, while allowing the basic blocks
+    /// at either end to be merged with their neighbors. This is used to inline procedures, and to
+    /// unroll `repeat` blocks.
+    pub(crate) fn inline_node_refs(&self, node_ref: MastNodeRef) -> Vec<MastNodeRef> {
+        let mut node_refs = Vec::new();
+        let mut worklist = vec![node_ref];
+        while let Some(node_ref) = worklist.pop() {
+            let node = &self.nodes[node_ref];
+            if matches!(node.kind, PendingMastNodeKind::Join) {
+                worklist.extend(node.child_refs.iter().rev().copied());
+            } else {
+                node_refs.push(node_ref);
+            }
+        }
+        node_refs
+    }
+
+    /// Records that the procedure `caller` takes the MAST root of the node `target`, e.g. via
+    /// `procref`, so that `target` is kept by [`Self::eliminate_dead_procedures`] whenever
+    /// `caller` is.
+    pub(crate) fn record_procref_target_ref(
+        &mut self,
+        caller: GlobalItemIndex,
+        target: MastNodeRef,
+    ) {
+        let targets = self.procref_target_refs.entry(caller).or_default();
+        if !targets.contains(&target) {
+            targets.push(target);
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Dead-code elimination
+impl MastForestBuilder {
+    /// Removes every procedure which is unreachable from `root_refs`, returning the paths of the
+    /// procedures that were removed.
+    ///
+    /// A procedure is reachable if its body is reachable from one of `root_refs` through MAST
+    /// edges, or if its MAST root is taken by a reachable procedure (see
+    /// [`Self::record_procref_target_ref`]). Procedures whose MAST root is only ever obtained by
+    /// other means, e.g. from the advice provider, cannot be detected, which is why this pass is
+    /// opt-in.
+    ///
+    /// The nodes of removed procedures are pruned when the forest is built.
+    pub(crate) fn eliminate_dead_procedures(
+        &mut self,
+        root_refs: impl IntoIterator<Item = MastNodeRef>,
+    ) -> Vec<Arc<Path>> {
+        let mut reachable = BTreeSet::new();
+        let mut worklist = root_refs.into_iter().collect::<Vec<_>>();
+        while !worklist.is_empty() {
+            while let Some(node_ref) = worklist.pop() {
+                if reachable.insert(node_ref) {
+                    worklist.extend(self.nodes[node_ref].child_refs.iter().copied());
+                }
+            }
+
+            // Procedures which were not kept as a distinct procedure (e.g. aliases of another
+            // procedure with the same MAST root) are conservatively treated as reachable
+            for (caller, targets) in self.procref_target_refs.iter() {
+                let is_reachable = self
+                    .procedures
+                    .get(caller)
+                    .is_none_or(|procedure| reachable.contains(&procedure.body_node_ref()));
+                if is_reachable {
+                    worklist.extend(targets.iter().filter(|target| !reachable.contains(*target)));
+                }
+            }
+        }
+
+        self.procedure_root_refs.retain(|root_ref| reachable.contains(root_ref));
+        let source_nodes = &self.source_nodes;
+        self.procedure_source_root_refs
+            .retain(|source_ref| reachable.contains(&source_nodes[*source_ref].exec_ref));
+
+        let mut removed = Vec::new();
+        self.procedures.retain(|_, procedure| {
+            let is_reachable = reachable.contains(&procedure.body_node_ref());
+            if !is_reachable {
+                log::debug!(
+                    target: "assembler::mast_forest_builder",
+                    "eliminating unreachable procedure '{}'",
+                    procedure.path(),
+                );
+                removed.push(procedure.path().clone());
+            }
+            is_reachable
+        });
+        let procedures = &self.procedures;
+        self.proc_gid_by_mast_root.retain(|_, gid| procedures.contains_key(gid));
+
+        removed
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -782,10 +960,30 @@ impl MastForestBuilder {
         &mut self,
         node_refs: Vec<MastNodeRef>,
         asm_op: Option<AssemblyOp>,
+    ) -> Result<MastNodeRef, Report> {
+        self.join_node_refs_with_call_sites(node_refs, BTreeMap::new(), BTreeSet::new(), asm_op)
+    }
+
+    /// Joins `node_refs` like [`Self::join_node_refs`], where `call_sites` maps the positions of
+    /// the nodes which were reached via an `exec` instruction to the location of that instruction.
+    ///
+    /// When such a node is a basic block merged with its neighbours, the location is recorded as a
+    /// call site of the assembly operations of the block, so that the source span chain of the
+    /// merged operations is preserved.
+    ///
+    /// `inlined` holds the positions of the nodes spliced from the body of a procedure annotated
+    /// with `@inline`, whose basic blocks are merged regardless of the inlining threshold.
+    pub(crate) fn join_node_refs_with_call_sites(
+        &mut self,
+        node_refs: Vec<MastNodeRef>,
+        call_sites: BTreeMap<usize, Location>,
+        inlined: BTreeSet<usize>,
+        asm_op: Option<AssemblyOp>,
     ) -> Result<MastNodeRef, Report> {
         debug_assert!(!node_refs.is_empty(), "cannot combine empty MAST node ref list");
 
-        let mut node_refs = self.merge_contiguous_basic_block_refs(node_refs)?;
+        let mut node_refs =
+            self.merge_contiguous_basic_block_refs(node_refs, call_sites, &inlined)?;
 
         // build a binary tree of blocks joining them using JOIN blocks
         while node_refs.len() > 1 {
@@ -904,13 +1102,20 @@ impl MastForestBuilder {
     fn merge_contiguous_basic_block_refs(
         &mut self,
         node_refs: Vec<MastNodeRef>,
+        mut call_sites: BTreeMap<usize, Location>,
+        inlined: &BTreeSet<usize>,
     ) -> Result<Vec<MastNodeRef>, Report> {
         let mut merged_node_refs = Vec::with_capacity(node_refs.len());
-        let mut contiguous_basic_block_refs: Vec<MastNodeRef> = Vec::new();
+        let mut contiguous_basic_block_refs: Vec<(MastNodeRef, Option<Location>, bool)> =
+            Vec::new();
 
-        for node_ref in node_refs {
+        for (idx, node_ref) in node_refs.into_iter().enumerate() {
             if self.pending_node_is_basic_block(node_ref) {
-                contiguous_basic_block_refs.push(node_ref);
+                contiguous_basic_block_refs.push((
+                    node_ref,
+                    call_sites.remove(&idx),
+                    inlined.contains(&idx),
+                ));
             } else {
                 merged_node_refs.extend(self.merge_basic_block_refs(&contiguous_basic_block_refs)?);
                 contiguous_basic_block_refs.clear();
@@ -927,9 +1132,9 @@ impl MastForestBuilder {
     fn record_merged_source_occurrences(
         &mut self,
         merged_ref: MastNodeRef,
-        merged_source_occurrences: &[(SourceNodeRef, usize)],
+        merged_source_occurrences: Vec<MergedSourceOccurrence>,
     ) -> Result<(), Report> {
-        for &(source_ref, new_start) in merged_source_occurrences {
+        for (source_ref, new_start, call_site, mut call_site_copies) in merged_source_occurrences {
             let source_node = self.source_nodes[source_ref].clone();
             let old_start = source_node.op_start;
             let op_len = source_node.op_end.saturating_sub(old_start);
@@ -938,13 +1143,17 @@ impl MastForestBuilder {
                 op_idx - old_start + new_start
             };
 
+            let asm_ops = self.asm_op_refs_with_call_site(
+                &source_node.asm_ops,
+                call_site.as_ref(),
+                &mut call_site_copies,
+            )?;
             self.push_source_occurrence(
                 merged_ref,
                 source_node.child_refs,
                 new_start,
                 new_start + op_len,
-                source_node
-                    .asm_ops
+                asm_ops
                     .into_iter()
                     .map(|(op_idx, asm_op_ref)| (remap_op_idx(op_idx), asm_op_ref))
                     .collect(),
@@ -960,34 +1169,73 @@ impl MastForestBuilder {
         Ok(())
     }
 
+    /// Returns `asm_ops`, with `call_site` (if any) appended to the call sites of each of the
+    /// referenced assembly operations.
+    ///
+    /// The referenced operations are left untouched, as they are shared with the block the
+    /// operations were merged from. Instead, each of them is copied with the call site appended,
+    /// and `copies` maps the original operations to their copies, so that an operation is copied
+    /// only once for a given call site.
+    fn asm_op_refs_with_call_site(
+        &mut self,
+        asm_ops: &[(usize, AsmOpRef)],
+        call_site: Option<&Location>,
+        copies: &mut BTreeMap<AsmOpRef, AsmOpRef>,
+    ) -> Result<Vec<(usize, AsmOpRef)>, Report> {
+        let Some(call_site) = call_site else {
+            return Ok(asm_ops.to_vec());
+        };
+
+        asm_ops
+            .iter()
+            .map(|&(op_idx, asm_op_ref)| {
+                if let Some(&copy_ref) = copies.get(&asm_op_ref) {
+                    return Ok((op_idx, copy_ref));
+                }
+                let mut asm_op = self.asm_op_by_ref[asm_op_ref].clone();
+                asm_op.push_call_site(call_site.clone());
+                let copy_ref = self.add_asm_op_ref(asm_op)?;
+                copies.insert(asm_op_ref, copy_ref);
+                Ok((op_idx, copy_ref))
+            })
+            .collect()
+    }
+
     fn merge_basic_block_refs(
         &mut self,
-        contiguous_basic_block_refs: &[MastNodeRef],
+        contiguous_basic_block_refs: &[(MastNodeRef, Option<Location>, bool)],
     ) -> Result<Vec<MastNodeRef>, Report> {
         if contiguous_basic_block_refs.is_empty() {
             return Ok(Vec::new());
         }
         if contiguous_basic_block_refs.len() == 1 {
-            return Ok(contiguous_basic_block_refs.to_vec());
+            return Ok(vec![contiguous_basic_block_refs[0].0]);
         }
 
         let mut operations: Vec<Operation> = Vec::new();
         // Track asm_ops and debug_vars being accumulated for merged blocks, with adjusted indices
         let mut merged_asm_ops: Vec<(usize, AsmOpRef)> = Vec::new();
         let mut merged_debug_vars: Vec<(usize, DebugVarRef)> = Vec::new();
-        let mut merged_source_occurrences: Vec<(SourceNodeRef, usize)> = Vec::new();
+        let mut merged_source_occurrences: Vec<MergedSourceOccurrence> = Vec::new();
 
         let mut merged_basic_block_refs: Vec<MastNodeRef> = Vec::new();
 
-        let source_refs = self.source_refs_for_node_ref_occurrences(contiguous_basic_block_refs);
+        let block_refs = contiguous_basic_block_refs
+            .iter()
+            .map(|(node_ref, ..)| *node_ref)
+            .collect::<Vec<_>>();
+        let source_refs = self.source_refs_for_node_ref_occurrences(&block_refs);
 
-        for (&basic_block_ref, source_ref) in contiguous_basic_block_refs.iter().zip(source_refs) {
+        for ((basic_block_ref, call_site, is_inlined), source_ref) in
+            contiguous_basic_block_refs.iter().cloned().zip(source_refs)
+        {
             // check if the block should be merged with other blocks
             if should_merge(
-                self.is_procedure_root_ref(basic_block_ref),
+                self.is_procedure_root_ref(basic_block_ref) && !is_inlined,
                 self.pending_basic_block_op_batches(basic_block_ref)
                     .expect("merge_basic_blocks: expected BasicBlockNode")
                     .len(),
+                self.inlining_threshold.unwrap_or(PROCEDURE_INLINING_THRESHOLD),
             ) {
                 // Collect operations from the block while the node is still immutably borrowed.
                 let block_ops = {
@@ -1000,14 +1248,24 @@ impl MastForestBuilder {
                 };
                 let ops_offset = operations.len();
 
-                merged_source_occurrences.push((source_ref, ops_offset));
+                let mut call_site_copies = BTreeMap::new();
+                let block_asm_ops = self.asm_op_refs_with_call_site(
+                    &self.nodes[basic_block_ref].asm_ops.clone(),
+                    call_site.as_ref(),
+                    &mut call_site_copies,
+                )?;
+                merged_source_occurrences.push((
+                    source_ref,
+                    ops_offset,
+                    call_site,
+                    call_site_copies,
+                ));
 
                 let pending_node = &self.nodes[basic_block_ref];
                 merged_asm_ops.extend(
-                    pending_node
-                        .asm_ops
-                        .iter()
-                        .map(|(op_idx, asm_op_id)| (op_idx + ops_offset, *asm_op_id)),
+                    block_asm_ops
+                        .into_iter()
+                        .map(|(op_idx, asm_op_id)| (op_idx + ops_offset, asm_op_id)),
                 );
                 merged_debug_vars.extend(
                     pending_node
@@ -1029,7 +1287,7 @@ impl MastForestBuilder {
                         self.ensure_block_ref(block_ops, block_asm_ops, block_debug_vars)?;
                     self.record_merged_source_occurrences(
                         merged_basic_block_ref,
-                        &block_source_occurrences,
+                        block_source_occurrences,
                     )?;
 
                     merged_basic_block_refs.push(merged_basic_block_ref);
@@ -1041,7 +1299,7 @@ impl MastForestBuilder {
         if !operations.is_empty() {
             let merged_basic_block =
                 self.ensure_block_ref(operations, merged_asm_ops, merged_debug_vars)?;
-            self.record_merged_source_occurrences(merged_basic_block, &merged_source_occurrences)?;
+            self.record_merged_source_occurrences(merged_basic_block, merged_source_occurrences)?;
             merged_basic_block_refs.push(merged_basic_block);
         }
 
@@ -1119,17 +1377,18 @@ impl MastForestBuilder {
 
 /// Determines if we want to merge a block with other blocks. Currently, this works as follows:
 /// - If the block is a procedure, we merge it only if the number of operation batches is smaller
-///   then the threshold (currently set at 32). The reasoning is based on an estimate of the the
-///   runtime penalty of not inlining the procedure. We assume that this penalty is roughly 3 extra
-///   nodes in the MAST and so would require 3 additional hashes at runtime. Since hashing each
-///   operation batch requires 1 hash, this basically implies that if the runtime penalty is more
-///   than 10%, we inline the block, but if it is less than 10% we accept the penalty to make
-///   deserialization faster.
+///   then the threshold (32 by default). The reasoning is based on an estimate of the the runtime
+///   penalty of not inlining the procedure. We assume that this penalty is roughly 3 extra nodes in
+///   the MAST and so would require 3 additional hashes at runtime. Since hashing each operation
+///   batch requires 1 hash, this basically implies that if the runtime penalty is more than 10%, we
+///   inline the block, but if it is less than 10% we accept the penalty to make deserialization
+///   faster.
 /// - If the block is not a procedure, we always merge it because: (1) if it is a large block, it is
 ///   likely to be unique and, thus, the original block will be orphaned and removed later; (2) if
-///   it is a small block, there is a large run-time benefit for inlining it.
-fn should_merge(is_procedure: bool, num_op_batches: usize) -> bool {
-    !is_procedure || num_op_batches < PROCEDURE_INLINING_THRESHOLD
+///   it is a small block, there is a large run-time benefit for inlining it. Procedures annotated
+///   with `@inline` are treated like non-procedure blocks.
+fn should_merge(is_procedure: bool, num_op_batches: usize, threshold: usize) -> bool {
+    !is_procedure || num_op_batches < threshold
 }
 
 #[cfg(test)]
@@ -1246,6 +1505,7 @@ mod tests {
                     asm_op.op().to_string(),
                     asm_op.num_cycles(),
                 )
+                .with_call_sites(asm_op.call_sites().to_vec())
             }));
             debug_vars.extend(source_node.debug_vars().iter().map(|(op_idx, debug_var)| {
                 DebugSourceVar::new(source_node_id, *op_idx as u32, debug_var.clone())
@@ -1434,8 +1694,12 @@ mod tests {
         let small_block_ref =
             builder.ensure_block_ref(vec![Operation::Add], vec![], vec![]).unwrap();
 
-        let merged_blocks =
-            builder.merge_basic_block_refs(&[large_block_ref, small_block_ref]).unwrap();
+        let merged_blocks = builder
+            .merge_basic_block_refs(&[
+                (large_block_ref, None, false),
+                (small_block_ref, None, false),
+            ])
+            .unwrap();
 
         assert_eq!(merged_blocks.len(), 2);
         assert_eq!(merged_blocks[0], large_block_ref);
@@ -1454,8 +1718,9 @@ mod tests {
         let tail_block_ref =
             builder.ensure_block_ref(vec![Operation::Mul], vec![], vec![]).unwrap();
 
-        let merged_blocks =
-            builder.merge_basic_block_refs(&[root_block_ref, tail_block_ref]).unwrap();
+        let merged_blocks = builder
+            .merge_basic_block_refs(&[(root_block_ref, None, false), (tail_block_ref, None, false)])
+            .unwrap();
         assert_eq!(merged_blocks.len(), 1);
         assert_ne!(merged_blocks[0], root_block_ref);
 
@@ -1479,8 +1744,12 @@ mod tests {
             .ensure_block_ref(vec![Operation::Mul], vec![(0, second_asm_op)], vec![])
             .unwrap();
 
-        let merged_blocks =
-            builder.merge_basic_block_refs(&[first_block_ref, second_block_ref]).unwrap();
+        let merged_blocks = builder
+            .merge_basic_block_refs(&[
+                (first_block_ref, None, false),
+                (second_block_ref, None, false),
+            ])
+            .unwrap();
         assert_eq!(merged_blocks.len(), 1);
         let merged_ref = record_test_root(&mut builder, merged_blocks[0]);
 
@@ -1536,8 +1805,12 @@ mod tests {
             "identical execution blocks should dedup to one execution ref",
         );
 
-        let merged_blocks =
-            builder.merge_basic_block_refs(&[first_block_ref, second_block_ref]).unwrap();
+        let merged_blocks = builder
+            .merge_basic_block_refs(&[
+                (first_block_ref, None, false),
+                (second_block_ref, None, false),
+            ])
+            .unwrap();
         assert_eq!(merged_blocks.len(), 1);
         let merged_ref = record_test_root(&mut builder, merged_blocks[0]);
 
@@ -1634,8 +1907,12 @@ mod tests {
         let small_block_ref =
             builder.ensure_block_ref(vec![Operation::Add], vec![], vec![]).unwrap();
 
-        let merged_blocks =
-            builder.merge_basic_block_refs(&[large_block_ref, small_block_ref]).unwrap();
+        let merged_blocks = builder
+            .merge_basic_block_refs(&[
+                (large_block_ref, None, false),
+                (small_block_ref, None, false),
+            ])
+            .unwrap();
 
         assert_eq!(merged_blocks.len(), 2);
         assert_eq!(merged_blocks[0], large_block_ref);
@@ -2310,7 +2587,12 @@ mod tests {
         let other_block_ref =
             builder.ensure_block_ref(vec![Operation::Mul], vec![], vec![]).unwrap();
 
-        let merged = builder.merge_basic_block_refs(&[root_block_ref, other_block_ref]).unwrap();
+        let merged = builder
+            .merge_basic_block_refs(&[
+                (root_block_ref, None, false),
+                (other_block_ref, None, false),
+            ])
+            .unwrap();
         // Root was small enough to merge, so we get one merged block.
         assert_eq!(merged.len(), 1);
         let merged_ref = merged[0];
@@ -81,6 +81,9 @@ impl SourceNode {
             if let Some(location) = asm_op.location().cloned() {
                 asm_op.set_location(rewrite_location(location));
             }
+            let call_sites =
+                asm_op.call_sites().iter().cloned().map(&mut *rewrite_location).collect();
+            asm_op.set_call_sites(call_sites);
         }
         for (_, debug_var) in self.debug_vars.iter_mut() {
             if let Some(location) = debug_var.location().cloned() {
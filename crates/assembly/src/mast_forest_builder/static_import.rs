@@ -334,10 +334,45 @@ impl MastForestBuilder {
             }
         }
 
-        let mut node_refs_by_source_id = BTreeMap::new();
         let source_forest = Arc::clone(&self.statically_linked_mast);
-        for old_id in SubtreeIterator::new(&linked_root.root_id, source_forest.as_ref()) {
-            let node = self.statically_linked_mast[old_id].clone();
+        self.copy_forest_subtree_ref(source_forest.as_ref(), linked_root.root_id)
+    }
+
+    /// Copies the procedure subtree rooted at `root_id` in `source_forest` into the builder's
+    /// forest.
+    ///
+    /// When `package_debug_info` describes `source_forest` and `source_debug_root_id` identifies
+    /// the source node of the root, the source metadata of the subtree is copied as well.
+    pub(crate) fn import_procedure_subtree_ref(
+        &mut self,
+        source_forest: &MastForest,
+        package_debug_info: Option<&PackageDebugInfo>,
+        root_id: MastNodeId,
+        source_debug_root_id: Option<DebugSourceNodeId>,
+    ) -> Result<MastNodeRef, Report> {
+        if let (Some(package_debug_info), Some(source_debug_root_id)) =
+            (package_debug_info, source_debug_root_id)
+        {
+            return self.copy_package_debug_source_subtree_ref(
+                source_forest,
+                package_debug_info,
+                source_debug_root_id,
+            );
+        }
+
+        self.copy_forest_subtree_ref(source_forest, root_id)
+    }
+
+    /// Copies the subtree rooted at `root_id` in `source_forest` into the builder's forest, without
+    /// any source metadata.
+    fn copy_forest_subtree_ref(
+        &mut self,
+        source_forest: &MastForest,
+        root_id: MastNodeId,
+    ) -> Result<MastNodeRef, Report> {
+        let mut node_refs_by_source_id = BTreeMap::new();
+        for old_id in SubtreeIterator::new(&root_id, source_forest) {
+            let node = source_forest[old_id].clone();
             let child_refs =
                 self.pending_refs_for_statically_linked_source(&node, &node_refs_by_source_id);
             let new_ref =
@@ -345,7 +380,7 @@ impl MastForestBuilder {
             node_refs_by_source_id.insert(old_id, new_ref);
         }
         Ok(*node_refs_by_source_id
-            .get(&linked_root.root_id)
+            .get(&root_id)
             .expect("statically linked subtree root must be copied"))
     }
 
@@ -447,15 +482,14 @@ impl MastForestBuilder {
         let asm_ops = package_debug_info
             .asm_ops_for_source_node(source_node_id)
             .map(|row| {
-                (
-                    row.op_idx as usize,
-                    AssemblyOp::new(
-                        row.location.clone(),
-                        row.context_name.clone(),
-                        row.num_cycles,
-                        row.op.clone(),
-                    ),
-                )
+                let mut asm_op = AssemblyOp::new(
+                    row.location.clone(),
+                    row.context_name.clone(),
+                    row.num_cycles,
+                    row.op.clone(),
+                );
+                asm_op.set_call_sites(row.call_sites.clone());
+                (row.op_idx as usize, asm_op)
             })
             .collect();
         let debug_vars = package_debug_info
@@ -0,0 +1,179 @@
+use alloc::vec::Vec;
+
+use miden_core::operations::Operation;
+
+use crate::mast_forest_builder::{AsmOpRef, DebugVarRef, MastForestBuilder};
+
+// OPTIMIZATION LEVEL
+// ================================================================================================
+
+/// The level of optimization applied by the [`crate::Assembler`] to the code it assembles.
+///
+/// Optimizations never change the observable behavior of the assembled code, but they do change
+/// the MAST roots of the procedures it contains. Procedures assembled with different optimization
+/// levels therefore cannot be used interchangeably when referenced by their MAST root.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum OptimizationLevel {
+    /// Operations are emitted exactly as they are produced by instruction lowering.
+    #[default]
+    None,
+    /// Redundant operation sequences in basic blocks are removed with a peephole pass, e.g.:
+    ///
+    /// * `push.N drop`, `pad drop` and `dup.N drop` are removed.
+    /// * Self-inverse pairs, e.g. `swap swap`, `swapw swapw` or `neg neg`, are removed.
+    /// * Inverse pairs, e.g. `movup.N movdn.N`, are removed.
+    /// * `noop` operations are removed.
+    Basic,
+    /// In addition to the optimizations of [`Self::Basic`], procedures invoked with `exec` are
+    /// evaluated at assembly time when all of their inputs are constants, and the invocation is
+    /// replaced with pushes of the values it leaves on the stack, e.g.:
+    ///
+    /// ```masm,ignore
+    /// push.3 push.4 exec.mul_add
+    /// ```
+    ///
+    /// becomes `push.19` if `mul_add` multiplies its inputs and adds 7 to the result. Only
+    /// procedures whose body is a single basic block of pure field and u32 arithmetic, comparison
+    /// and stack manipulation operations are evaluated, and only when their inputs are pushed by
+    /// the instructions immediately preceding the invocation. Invocations which would fail at
+    /// runtime, e.g. due to a failed assertion, are left as they are.
+    Full,
+}
+
+impl OptimizationLevel {
+    /// Returns true if the peephole pass over basic blocks is enabled at this level.
+    pub const fn is_peephole_enabled(&self) -> bool {
+        matches!(self, Self::Basic | Self::Full)
+    }
+
+    /// Returns true if procedures invoked with constant inputs are evaluated at this level.
+    pub const fn is_const_eval_enabled(&self) -> bool {
+        matches!(self, Self::Full)
+    }
+}
+
+// PEEPHOLE OPTIMIZER
+// ================================================================================================
+
+/// Removes redundant operations from the operations of a basic block.
+///
+/// Pairs of operations that cancel each other out are removed, including pairs which only become
+/// adjacent once the operations between them were removed, e.g. `swap dup.0 drop swap`. A pair is
+/// not removed if a debug variable is attached to an operation within it, as the stack layout the
+/// debug variable describes would no longer exist.
+///
+/// The operation indices of `asm_ops` and `debug_vars` are updated to refer to the optimized
+/// operations, and the cycle counts of the assembly ops are updated accordingly. Assembly ops all
+/// of whose operations were removed are dropped from `asm_ops`.
+///
+/// If all operations are removed, they are replaced by a single `noop`, as basic blocks cannot be
+/// empty.
+pub(crate) fn optimize_basic_block(
+    ops: &mut Vec<Operation>,
+    asm_ops: &mut Vec<(usize, AsmOpRef)>,
+    debug_vars: &mut [(usize, DebugVarRef)],
+    mast_forest_builder: &mut MastForestBuilder,
+) {
+    let mut kept = vec![true; ops.len()];
+    // The indices of the operations kept so far, the last of which may cancel the next operation
+    let mut stack: Vec<usize> = Vec::with_capacity(ops.len());
+    for (idx, op) in ops.iter().enumerate() {
+        if matches!(op, Operation::Noop) {
+            kept[idx] = false;
+            continue;
+        }
+        match stack.last() {
+            Some(&prev_idx)
+                if cancels(&ops[prev_idx], op)
+                    && !debug_vars
+                        .iter()
+                        .any(|&(var_idx, _)| var_idx > prev_idx && var_idx <= idx) =>
+            {
+                stack.pop();
+                kept[prev_idx] = false;
+                kept[idx] = false;
+            },
+            _ => stack.push(idx),
+        }
+    }
+
+    if kept.iter().all(|&keep| keep) {
+        return;
+    }
+
+    // new_indices[i] is the index in the optimized block of the first kept operation at or after
+    // index i in the original block
+    let mut new_indices = Vec::with_capacity(ops.len() + 1);
+    let mut num_kept = 0;
+    for &keep in kept.iter() {
+        new_indices.push(num_kept);
+        num_kept += keep as usize;
+    }
+    new_indices.push(num_kept);
+
+    let num_ops = ops.len();
+    asm_ops.retain_mut(|(op_idx, asm_op_ref)| {
+        let asm_op = mast_forest_builder.asm_op_mut(*asm_op_ref);
+        let end = (*op_idx + asm_op.num_cycles() as usize).min(num_ops);
+        let num_cycles = new_indices[end] - new_indices[*op_idx];
+        asm_op.set_num_cycles(num_cycles as u8);
+        *op_idx = new_indices[*op_idx];
+        num_cycles > 0
+    });
+    for (op_idx, _) in debug_vars.iter_mut() {
+        *op_idx = new_indices[(*op_idx).min(num_ops)];
+    }
+
+    if num_kept == 0 {
+        ops.truncate(1);
+        ops[0] = Operation::Noop;
+    } else {
+        let mut kept = kept.into_iter();
+        ops.retain(|_| kept.next().unwrap_or(true));
+    }
+}
+
+/// Returns true if executing `first` followed immediately by `second` leaves the stack unchanged.
+fn cancels(first: &Operation, second: &Operation) -> bool {
+    use Operation::*;
+
+    matches!(
+        (first, second),
+        (
+            Push(_)
+                | Pad
+                | Dup0
+                | Dup1
+                | Dup2
+                | Dup3
+                | Dup4
+                | Dup5
+                | Dup6
+                | Dup7
+                | Dup9
+                | Dup11
+                | Dup13
+                | Dup15,
+            Drop,
+        ) | (Swap, Swap)
+            | (SwapW, SwapW)
+            | (SwapW2, SwapW2)
+            | (SwapW3, SwapW3)
+            | (SwapDW, SwapDW)
+            | (Neg, Neg)
+            | (MovUp2, MovDn2)
+            | (MovDn2, MovUp2)
+            | (MovUp3, MovDn3)
+            | (MovDn3, MovUp3)
+            | (MovUp4, MovDn4)
+            | (MovDn4, MovUp4)
+            | (MovUp5, MovDn5)
+            | (MovDn5, MovUp5)
+            | (MovUp6, MovDn6)
+            | (MovDn6, MovUp6)
+            | (MovUp7, MovDn7)
+            | (MovDn7, MovUp7)
+            | (MovUp8, MovDn8)
+            | (MovDn8, MovUp8)
+    )
+}
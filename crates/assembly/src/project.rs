@@ -1,4 +1,11 @@
-use alloc::{boxed::Box, collections::BTreeMap, format, string::ToString, sync::Arc, vec::Vec};
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::ToString,
+    sync::Arc,
+    vec::Vec,
+};
 use std::{
     fs,
     path::{Path as FsPath, PathBuf},
@@ -775,7 +782,10 @@ where
             self.store,
             self.assembler.source_manager(),
         )?;
-        context.with_warnings_as_errors(self.assembler.warnings_as_errors());
+        context
+            .with_warnings_as_errors(self.assembler.warnings_as_errors())
+            .with_features(self.assembler.features().clone())
+            .with_lints(self.assembler.parse_options().lints.clone());
 
         let extension = context.resolved_target_root.extension().ok_or_else(|| {
             Report::msg(format!(
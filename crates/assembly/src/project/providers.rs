@@ -1,6 +1,6 @@
 mod masm;
 
-use miden_assembly_syntax::debuginfo::SourceManager;
+use miden_assembly_syntax::{ParseOptions, debuginfo::SourceManager, sema::Lints};
 use miden_package_registry::PackageRegistryAndProvider;
 use miden_project::ProjectDependencyGraph;
 
@@ -30,6 +30,10 @@ pub struct TargetAssemblyContext<'a> {
     pub package_registry: &'a dyn PackageRegistryAndProvider,
     /// The assembler-wide `warnings_as_errors` flag
     pub warnings_as_errors: bool,
+    /// The assembler-wide set of features enabled for conditional compilation
+    pub features: BTreeSet<Arc<str>>,
+    /// The assembler-wide levels at which lints are reported
+    pub lints: Lints,
 }
 
 impl<'a> TargetAssemblyContext<'a> {
@@ -70,6 +74,8 @@ impl<'a> TargetAssemblyContext<'a> {
             source_manager,
             package_registry,
             warnings_as_errors: false,
+            features: BTreeSet::new(),
+            lints: Lints::default(),
         })
     }
 
@@ -78,6 +84,27 @@ impl<'a> TargetAssemblyContext<'a> {
         self.warnings_as_errors = yes;
         self
     }
+
+    #[inline]
+    pub fn with_features(&mut self, features: BTreeSet<Arc<str>>) -> &mut Self {
+        self.features = features;
+        self
+    }
+
+    #[inline]
+    pub fn with_lints(&mut self, lints: Lints) -> &mut Self {
+        self.lints = lints;
+        self
+    }
+
+    /// Returns the options with which the sources of the current target should be parsed.
+    pub fn parse_options(&self) -> ParseOptions {
+        ParseOptions {
+            warnings_as_errors: self.warnings_as_errors,
+            features: self.features.clone(),
+            lints: self.lints.clone(),
+        }
+    }
 }
 
 /// This trait provides source file inputs and package post-processing hooks for a Miden Assembly
@@ -17,7 +17,6 @@ impl ProjectSourceProvider for MasmSourceProvider {
             target,
             resolved_target_root,
             source_manager,
-            warnings_as_errors,
             ..
         } = context;
 
@@ -28,7 +27,7 @@ impl ProjectSourceProvider for MasmSourceProvider {
             Some(namespace),
             Some(kind),
             source_manager.clone(),
-            *warnings_as_errors,
+            &context.parse_options(),
         )?;
 
         Ok(ProjectSourceInputs { root, support })
@@ -46,7 +45,7 @@ impl ProjectSourceProvider for MasmSourceProvider {
             Some(namespace),
             Some(kind),
             context.source_manager.clone(),
-            context.warnings_as_errors,
+            &context.parse_options(),
         )?;
 
         let root = {
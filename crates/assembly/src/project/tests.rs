@@ -339,7 +339,7 @@ end
         let exec_node = debug_source_graph.nodes()[source_node].exec_node;
         source_nodes_by_exec
             .entry(exec_node)
-            .or_insert_with(std::collections::BTreeSet::<DebugSourceNodeId>::new)
+            .or_insert_with(BTreeSet::<DebugSourceNodeId>::new)
             .insert(row.source_node);
     }
 
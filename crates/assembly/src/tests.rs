@@ -14,21 +14,22 @@ use miden_assembly_syntax::{
 };
 use miden_core::{
     Felt, Word,
-    events::EventId,
+    events::{EventId, EventName},
     field::PrimeField64,
-    mast::{MastNode, MastNodeExt},
+    mast::{MastNode, MastNodeExt, SubtreeIterator, error_code_from_msg},
     operations::{AssemblyOp, Operation},
     program::Program,
     serde::{Deserializable, Serializable},
 };
 use miden_mast_package::{
     MastForest, Package, PackageExport, PackageModule, PackageSubmodule, ProcedureExport,
-    TargetType,
+    TargetType, Version, debug_info::AsmOpInfo,
 };
 use miden_project::Linkage;
 
 use crate::{
-    Assembler, PathBuf, SourceSpan, Span,
+    Assembler, CompileCache, Disassembler, Lint, LintLevel, OptimizationLevel, PathBuf,
+    ProgramBuilder, SourceSpan, Span,
     assembler::{MAX_CONTROL_FLOW_NESTING, MAX_PROC_LOCALS},
     ast::{
         Block, Instruction, Module, Op, Procedure, ProcedureName, QualifiedProcedureName,
@@ -373,6 +374,769 @@ fn library_exports() -> Result<(), Report> {
     Ok(())
 }
 
+#[test]
+fn library_doc_model() -> Result<(), Report> {
+    let context = TestContext::new();
+
+    let math = r#"
+        namespace lib::math
+
+        #! The answer.
+        pub const ANSWER = 6 * 7
+
+        #! Adds two felts.
+        pub proc add(a: felt, b: felt) -> felt
+            add
+        end
+
+        proc helper
+            nop
+        end
+    "#;
+    let math = parse_module!(&context, math);
+    let lib = Assembler::new(context.source_manager()).assemble_library(
+        "lib",
+        math,
+        None::<Box<Module>>,
+    )?;
+
+    let model = lib.doc_model();
+    let module = model.get_module(Path::new("::lib::math")).expect("missing module");
+    assert!(module.kind.is_library());
+    assert_eq!(
+        module
+            .procedures
+            .iter()
+            .map(|procedure| procedure.name.as_str())
+            .collect::<Vec<_>>(),
+        ["add"]
+    );
+    assert_eq!(module.procedures[0].signature.as_deref(), Some("(felt, felt) -> felt"));
+    assert_eq!(module.constants[0].name, "ANSWER");
+    assert_eq!(module.constants[0].value, "42");
+    assert!(module.procedures[0].docs.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn deduplicate_libraries_stores_shared_procedures_once() -> Result<(), Report> {
+    let context = TestContext::new();
+
+    // both libraries define a procedure with the same body
+    let lib1 = parse_module!(
+        &context,
+        r#"
+        namespace lib1::math
+
+        pub proc shared
+            push.7 push.8 mul
+        end
+
+        pub proc only_in_lib1
+            push.1 add
+        end
+        "#
+    );
+    let lib1 = Assembler::new(context.source_manager()).assemble_library(
+        "lib1",
+        lib1,
+        None::<Box<Module>>,
+    )?;
+    let lib2 = parse_module!(
+        &context,
+        r#"
+        namespace lib2::math
+
+        pub proc shared
+            push.7 push.8 mul
+        end
+
+        pub proc only_in_lib2
+            push.2 add
+        end
+        "#
+    );
+    let lib2 = Assembler::new(context.source_manager()).assemble_library(
+        "lib2",
+        lib2,
+        None::<Box<Module>>,
+    )?;
+
+    let (lib1, lib2) = (Arc::<Package>::from(lib1), Arc::<Package>::from(lib2));
+    let assembler = Assembler::new(context.source_manager())
+        .with_package(lib1.clone(), Linkage::Dynamic)?
+        .with_package(lib2.clone(), Linkage::Dynamic)?;
+    let deduplicated = assembler.deduplicate_libraries()?;
+
+    let forest = deduplicated.mast_forest();
+    assert_eq!(deduplicated.sources().len(), 2);
+    assert_eq!(deduplicated.num_removed_nodes(), 1);
+    assert_eq!(forest.num_procedures(), 3);
+    for library in [&lib1, &lib2] {
+        let library_forest = library.mast_forest();
+        for &root in library_forest.procedure_roots() {
+            let merged_root = deduplicated
+                .map_root(library_forest.commitment(), root)
+                .expect("every library procedure should be in the merged forest");
+            assert_eq!(forest[merged_root].digest(), library_forest[root].digest());
+        }
+    }
+
+    Ok(())
+}
+
+/// Assembles a library from the given modules in a fresh context, reusing procedures from `cache`.
+fn assemble_with_compile_cache(
+    cache: &Arc<CompileCache>,
+    foo_source: &str,
+    bar_source: &str,
+) -> Result<Box<Package>, Report> {
+    let context = TestContext::new();
+    let foo_module = parse_module!(&context, foo_source.to_string());
+    let bar_module = parse_module!(&context, bar_source.to_string());
+    let root = parse_module!(&context, "namespace lib\n\npub mod foo\npub mod bar\n");
+
+    Assembler::new(context.source_manager())
+        .with_compile_cache(cache.clone())
+        .assemble_library("lib", root, [foo_module, bar_module])
+}
+
+#[test]
+fn compile_cache_reuses_unchanged_procedures() -> TestResult {
+    let foo = r#"
+        namespace lib::foo
+
+        pub proc foo1
+            push.1 add
+        end
+
+        pub proc foo2
+            assert.err="foo2 expects a non-zero value"
+            exec.foo1
+        end
+    "#;
+    let bar = r#"
+        namespace lib::bar
+
+        pub proc bar1
+            exec.::lib::foo::foo2
+        end
+
+        pub proc bar2
+            push.2 mul
+        end
+    "#;
+    let cache = Arc::new(CompileCache::new());
+
+    // the first assembly compiles every procedure
+    let first = assemble_with_compile_cache(&cache, foo, bar)?;
+    assert_eq!(cache.num_hits(), 0);
+    assert_eq!(cache.num_misses(), 4);
+    assert_eq!(cache.len(), 4);
+
+    // reassembling the same sources reuses every procedure, and yields the same artifact
+    let second = assemble_with_compile_cache(&cache, foo, bar)?;
+    assert_eq!(cache.num_hits(), 4);
+    assert_eq!(cache.num_misses(), 4);
+    assert_eq!(second.mast_forest().commitment(), first.mast_forest().commitment());
+    assert_eq!(second.digest(), first.digest());
+    let err_code = second
+        .mast_forest()
+        .procedure_roots()
+        .iter()
+        .flat_map(|&root| SubtreeIterator::new(&root, second.mast_forest()))
+        .find_map(|node_id| match &second.mast_forest()[node_id] {
+            MastNode::Block(block) => block.operations().find_map(|op| match op {
+                Operation::Assert(err_code) => Some(err_code.as_canonical_u64()),
+                _ => None,
+            }),
+            _ => None,
+        })
+        .expect("expected foo2 to contain an assertion");
+    assert_eq!(
+        second.debug_info().unwrap().unwrap().error_message(err_code).as_deref(),
+        Some("foo2 expects a non-zero value")
+    );
+
+    // changing foo1 recompiles the procedures of its module and their callers, but not bar2
+    let changed_foo = foo.replace("push.1 add", "push.3 add");
+    let third = assemble_with_compile_cache(&cache, &changed_foo, bar)?;
+    assert_eq!(cache.num_hits(), 5);
+    assert_eq!(cache.num_misses(), 7);
+    assert_ne!(third.mast_forest().commitment(), first.mast_forest().commitment());
+
+    let uncached = {
+        let context = TestContext::new();
+        let foo = parse_module!(&context, changed_foo);
+        let bar = parse_module!(&context, bar);
+        let root = parse_module!(&context, "namespace lib\n\npub mod foo\npub mod bar\n");
+        Assembler::new(context.source_manager()).assemble_library("lib", root, [foo, bar])?
+    };
+    assert_eq!(third.mast_forest().commitment(), uncached.mast_forest().commitment());
+
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.num_hits(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn dead_code_elimination_removes_unreachable_procedures() -> TestResult {
+    let source = r#"
+        namespace lib
+
+        pub proc foo
+            exec.helper
+            procref.dynamic_target
+            dynexec
+        end
+
+        proc helper
+            push.2 mul
+        end
+
+        proc dynamic_target
+            push.3 add
+        end
+    "#;
+    let context = TestContext::new();
+    let (package, report) = Assembler::new(context.source_manager())
+        .assemble_library_with_dead_code_report(
+            "lib",
+            parse_module!(&context, source),
+            None::<Box<Module>>,
+        )?;
+    let unoptimized = Assembler::new(context.source_manager()).assemble_library(
+        "lib",
+        parse_module!(&context, source),
+        None::<Box<Module>>,
+    )?;
+
+    // `helper` is inlined into `foo`, so only its standalone copy is dead, while `dynamic_target`
+    // stays reachable through `procref`
+    let removed = report.removed_procedures().iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(removed, ["::lib::helper"]);
+    assert_eq!(package.mast_forest().num_procedures(), 2);
+    assert_eq!(unoptimized.mast_forest().num_procedures(), 3);
+    assert!(package.mast_forest().num_nodes() < unoptimized.mast_forest().num_nodes());
+
+    // the report is optional
+    let without_report = Assembler::new(context.source_manager())
+        .with_dead_code_elimination()
+        .assemble_library("lib", parse_module!(&context, source), None::<Box<Module>>)?;
+    assert_eq!(without_report.mast_forest().commitment(), package.mast_forest().commitment());
+
+    // the exports are unaffected
+    let digests = |package: &Package| {
+        package
+            .manifest
+            .exports()
+            .filter_map(|export| match export {
+                PackageExport::Procedure(export) => Some(export.digest),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(digests(&package), digests(&unoptimized));
+
+    Ok(())
+}
+
+#[test]
+fn dead_code_elimination_keeps_program_entrypoint_reachable_code() -> TestResult {
+    let context = TestContext::default();
+    let source = source_file!(
+        &context,
+        "\
+    proc helper
+        push.2 mul
+    end
+
+    proc callee
+        push.3 add
+    end
+
+    begin
+        exec.helper
+        call.callee
+    end"
+    );
+    let (program, report) = Assembler::new(context.source_manager())
+        .assemble_program_with_dead_code_report("test", source)?;
+
+    let removed = report.removed_procedures().iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(removed.len(), 1);
+    assert!(removed[0].ends_with("::helper"), "unexpected removed procedures: {removed:?}");
+    // the entrypoint and the target of the `call` remain procedure roots
+    assert_eq!(program.mast_forest().num_procedures(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn peephole_optimizer_removes_redundant_operations() -> TestResult {
+    let context = TestContext::default();
+    let source = "\
+    begin
+        push.1 push.0 drop
+        swap swap
+        dup.1 drop
+        nop nop
+        movup.2 swap dup.0 drop swap movdn.2
+        add
+    end";
+    let expected = "\
+    begin
+        push.1 add
+    end";
+
+    let optimized = Assembler::new(context.source_manager())
+        .with_optimization_level(OptimizationLevel::Basic)
+        .assemble_program("test", source_file!(&context, source))?
+        .unwrap_program();
+    let unoptimized = Assembler::new(context.source_manager())
+        .assemble_program("test", source_file!(&context, source))?
+        .unwrap_program();
+    let expected = context.assemble(expected)?;
+
+    assert_eq!(optimized.hash(), expected.hash());
+    assert_ne!(unoptimized.hash(), expected.hash());
+
+    Ok(())
+}
+
+#[test]
+fn peephole_optimizer_keeps_a_noop_in_emptied_blocks() -> TestResult {
+    let context = TestContext::default();
+    let source = source_file!(
+        &context,
+        "\
+    begin
+        swap push.5 drop swap
+    end"
+    );
+    let program = Assembler::new(context.source_manager())
+        .with_optimization_level(OptimizationLevel::Basic)
+        .assemble_program("test", source)?
+        .unwrap_program();
+    let expected = context.assemble("begin nop end")?;
+
+    assert_eq!(program.hash(), expected.hash());
+
+    Ok(())
+}
+
+#[test]
+fn constant_invocations_are_evaluated_at_full_optimization() -> TestResult {
+    let context = TestContext::default();
+    let procedures = "\
+    proc mul_add
+        mul add.7
+    end
+
+    proc table
+        push.10.20.30
+    end
+
+    proc checked_double
+        u32assert dup add
+    end
+    ";
+    let assemble = |level: OptimizationLevel, body: &str| {
+        Assembler::new(context.source_manager())
+            .with_optimization_level(level)
+            .assemble_program(
+                "test",
+                source_file!(&context, format!("{procedures}\nbegin\n{body}\nend")),
+            )
+            .map(|package| package.unwrap_program().hash())
+    };
+
+    let cases = [
+        // All inputs are constants
+        ("push.3 push.4 exec.mul_add", "push.19"),
+        // Constants which are not consumed are left in place
+        ("push.1 push.2 push.3 exec.checked_double", "push.1 push.2 push.6"),
+        // Procedures without inputs are folded too
+        ("exec.table exec.mul_add", "push.10 push.607"),
+        // An input is not a constant
+        ("dup push.4 exec.mul_add", "dup push.4 exec.mul_add"),
+        // The procedure would fail at runtime
+        ("push.4294967296 exec.checked_double", "push.4294967296 exec.checked_double"),
+    ];
+    for (body, expected) in cases {
+        let expected = assemble(OptimizationLevel::None, expected)?;
+        assert_eq!(assemble(OptimizationLevel::Full, body)?, expected, "{body}");
+    }
+    assert_ne!(
+        assemble(OptimizationLevel::Basic, "push.3 push.4 exec.mul_add")?,
+        assemble(OptimizationLevel::None, "push.19")?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn inline_procedures_are_spliced_into_callers() -> TestResult {
+    let context = TestContext::default();
+    let source = |attribute: &str| {
+        format!(
+            "\
+    {attribute}
+    proc helper
+        add
+        if.true push.1 else push.2 end
+        mul
+    end
+
+    begin
+        push.3 exec.helper push.4
+    end"
+        )
+    };
+    let expected = "\
+    begin
+        push.3
+        add
+        if.true push.1 else push.2 end
+        mul
+        push.4
+    end";
+
+    let inlined = context.assemble(source("@inline"))?;
+    let not_inlined = context.assemble(source(""))?;
+    let expected = context.assemble(expected)?;
+
+    assert_eq!(inlined.hash(), expected.hash());
+    assert_ne!(not_inlined.hash(), expected.hash());
+
+    Ok(())
+}
+
+#[test]
+fn inline_attribute_does_not_apply_to_procedures_with_the_same_body() -> TestResult {
+    let context = TestContext::default();
+    let source = "\
+    @inline
+    proc inlined
+        add if.true push.1 else push.2 end mul
+    end
+
+    proc not_inlined
+        add if.true push.1 else push.2 end mul
+    end
+
+    begin
+        push.3 exec.inlined push.4 exec.not_inlined push.5
+    end";
+    let expected = "\
+    proc helper
+        add if.true push.1 else push.2 end mul
+    end
+
+    begin
+        push.3 add if.true push.1 else push.2 end mul push.4 exec.helper push.5
+    end";
+
+    // only the annotated procedure is spliced, even though both share the same MAST root
+    assert_eq!(context.assemble(source)?.hash(), context.assemble(expected)?.hash());
+
+    Ok(())
+}
+
+#[test]
+fn inlining_threshold_limits_automatic_inlining() -> TestResult {
+    let context = TestContext::default();
+    let source = |attribute: &str| {
+        format!(
+            "\
+    {attribute}
+    proc helper
+        push.2 mul
+    end
+
+    begin
+        push.3 exec.helper push.4
+    end"
+        )
+    };
+    let assemble = |source: String| {
+        Assembler::new(context.source_manager())
+            .with_inlining_threshold(0)
+            .assemble_program("test", source_file!(&context, source))
+            .map(|product| product.unwrap_program())
+    };
+    let expected = context.assemble("begin push.3 push.2 mul push.4 end")?;
+
+    // small procedures are inlined by default, but not when automatic inlining is disabled
+    assert_eq!(context.assemble(source(""))?.hash(), expected.hash());
+    assert_ne!(assemble(source(""))?.hash(), expected.hash());
+
+    // `@inline` overrides the threshold
+    assert_eq!(assemble(source("@inline"))?.hash(), expected.hash());
+
+    Ok(())
+}
+
+#[test]
+fn repeat_blocks_are_unrolled_within_budget() -> TestResult {
+    let context = TestContext::default();
+    let source = |attribute: &str| {
+        format!(
+            "\
+    proc helper
+        {attribute}
+        repeat.3
+            push.1
+            if.true add else mul end
+            swap
+        end
+    end
+
+    begin
+        exec.helper
+    end"
+        )
+    };
+    let expected = "\
+    proc helper
+        push.1
+        if.true add else mul end
+        swap push.1
+        if.true add else mul end
+        swap push.1
+        if.true add else mul end
+        swap
+    end
+
+    begin
+        exec.helper
+    end";
+    let assemble = |source: String, budget: Option<usize>| {
+        let mut assembler = Assembler::new(context.source_manager());
+        if let Some(budget) = budget {
+            assembler = assembler.with_unroll_budget(budget);
+        }
+        assembler
+            .assemble_program("test", source_file!(&context, source))
+            .map(|product| product.unwrap_program())
+    };
+    let expected = context.assemble(expected)?;
+
+    assert_eq!(assemble(source(""), Some(100))?.hash(), expected.hash());
+    assert_ne!(assemble(source(""), None)?.hash(), expected.hash());
+
+    // the unrolled body exceeds the budget
+    assert_ne!(assemble(source(""), Some(1))?.hash(), expected.hash());
+
+    // unrolling is disabled for the block
+    assert_ne!(assemble(source("@no_unroll"), Some(100))?.hash(), expected.hash());
+    assert_eq!(
+        assemble(source("@no_unroll"), Some(100))?.hash(),
+        assemble(source(""), None)?.hash()
+    );
+
+    // no other attributes are allowed on a block
+    assert!(assemble(source("@inline"), Some(100)).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn source_map_is_emitted_without_debug_info() -> TestResult {
+    let context = TestContext::default();
+    let source = source_file!(
+        &context,
+        "\
+begin
+    push.1
+    push.2 add
+end"
+    );
+    let (package, source_map) = Assembler::new(context.source_manager())
+        .with_profile(&miden_project::Profile::release())
+        .assemble_program_with_source_map("test", source)?;
+
+    assert!(package.debug_info().into_diagnostic()?.is_none());
+    let program = package.unwrap_program();
+    assert!(source_map.describes(program.mast_forest()));
+
+    // the body of the entrypoint is preceded by the 4 operations initializing the frame pointer
+    let entrypoint = program.entrypoint();
+    assert!(source_map.file_line_col(entrypoint, 0).is_none());
+    let push_one = source_map.file_line_col(entrypoint, 4).expect("missing position of push.1");
+    assert_eq!(push_one.line.to_u32(), 2);
+    assert_eq!(push_one.column.to_u32(), 5);
+    let add = source_map.file_line_col(entrypoint, 7).expect("missing position of add");
+    assert_eq!(add.line.to_u32(), 3);
+    assert_eq!(add.column.to_u32(), 12);
+
+    Ok(())
+}
+
+#[test]
+fn error_table_maps_error_codes_to_messages_and_modules() -> TestResult {
+    let context = TestContext::default();
+    let checks = parse_module!(
+        &context,
+        r#"
+        namespace lib::checks
+
+        pub proc check
+            assert.err="value must be one"
+        end
+        "#
+    );
+    let lib = Assembler::new(context.source_manager()).assemble_library(
+        "lib",
+        checks,
+        None::<Box<Module>>,
+    )?;
+    let source = source_file!(
+        &context,
+        r#"
+        use lib::checks
+
+        begin
+            push.1 exec.checks::check
+            push.0 assertz.err="value must be zero"
+        end
+        "#
+    );
+    let (package, error_table) = Assembler::new(context.source_manager())
+        .with_profile(&miden_project::Profile::release())
+        .with_package(Arc::<Package>::from(lib), Linkage::Static)?
+        .assemble_program_with_error_table("test", source)?;
+
+    assert!(package.debug_info().into_diagnostic()?.is_none());
+    assert_eq!(error_table.len(), 2);
+
+    let entry = error_table
+        .get(error_code_from_msg("value must be one"))
+        .expect("missing error raised by the library");
+    assert_eq!(entry.message.as_ref(), "value must be one");
+    assert_eq!(entry.module.as_deref(), Some("::lib::checks"));
+
+    let entry = error_table
+        .get(error_code_from_msg("value must be zero"))
+        .expect("missing error raised by the program");
+    assert_eq!(entry.message.as_ref(), "value must be zero");
+    assert!(entry.module.is_some());
+
+    Ok(())
+}
+
+#[test]
+fn program_builder_matches_assembled_program() -> TestResult {
+    let context = TestContext::default();
+    let expected = context.assemble(source_file!(
+        &context,
+        "\
+begin
+    push.1
+    if.true
+        push.2
+    else
+        push.3
+    end
+    drop
+end"
+    ))?;
+
+    // the assembler initializes the frame pointer at the start of the entrypoint
+    let mut builder = ProgramBuilder::new(context.source_manager());
+    let push_one = builder.add_block(
+        fmp_initialization_sequence()
+            .into_iter()
+            .chain([Operation::Pad, Operation::Incr]),
+    )?;
+    let then_blk = builder.add_block([Operation::Push(Felt::from_u32(2))])?;
+    let else_blk = builder.add_block([Operation::Push(Felt::from_u32(3))])?;
+    let split = builder.add_split(then_blk, else_blk)?;
+    let drop = builder.add_block([Operation::Drop])?;
+    let entry = builder.add_join([push_one, split, drop])?;
+
+    assert_eq!(builder.min_stack_effect(entry), Some(0));
+    let program = builder.build(entry)?;
+    assert_eq!(program.hash(), expected.hash());
+
+    Ok(())
+}
+
+#[test]
+fn program_builder_validates_stack_effects_and_syscalls() -> TestResult {
+    let context = TestContext::default();
+    let mut builder = ProgramBuilder::new(context.source_manager());
+
+    // callees may not return more elements than they were invoked with
+    let push = builder.add_block([Operation::Pad])?;
+    let err = builder.add_call(push).expect_err("callee growing the stack should be rejected");
+    assert_diagnostic!(&err, "the callee leaves at least 1 more elements on the stack");
+
+    // syscalls must target a kernel procedure
+    let external = builder.add_external(Word::default())?;
+    let err = builder.add_syscall(external).expect_err("syscall outside of kernel");
+    assert_diagnostic!(&err, "is not a procedure of the kernel");
+
+    // loops with an unbounded effect are accepted
+    let drop = builder.add_block([Operation::Drop])?;
+    let body = builder.add_loop(drop)?;
+    assert_eq!(builder.min_stack_effect(body), None);
+    assert!(builder.add_call(body).is_ok());
+
+    let err = builder
+        .build(push)
+        .expect_err("entrypoint growing the stack should be rejected");
+    assert_diagnostic!(&err, "the program leaves at least 1 more elements on the stack");
+
+    Ok(())
+}
+
+#[test]
+fn program_builder_attaches_assembly_ops_to_operations() -> TestResult {
+    let context = TestContext::default();
+    let mut builder = ProgramBuilder::new(context.source_manager());
+    builder.set_context_name("backend::entry");
+    let err_code = builder.register_error("value is not one");
+    let entry =
+        builder.add_block([Operation::Pad, Operation::Incr, Operation::Assert(err_code)])?;
+    let (program, source_map) = builder.build_with_source_map(entry)?;
+
+    assert!(source_map.describes(program.mast_forest()));
+    let debug_info = source_map.debug_info();
+    let messages = debug_info.error_messages().expect("missing error messages");
+    assert!(messages.messages().iter().any(|row| row.message.as_ref() == "value is not one"));
+    let asm_op = debug_info
+        .asm_op_for_operation(0u32.into(), 2)
+        .expect("missing assembly op of the assertion");
+    assert_eq!(asm_op.context_name, "backend::entry");
+    assert!(asm_op.op.starts_with("assert"));
+
+    Ok(())
+}
+
+#[test]
+fn denied_lints_fail_assembly() -> TestResult {
+    let context = TestContext::default();
+    let source = "\
+begin
+    adv_push drop
+end";
+    Assembler::new(context.source_manager())
+        .with_warnings_as_errors(true)
+        .assemble_program("test", source)?;
+
+    let err = Assembler::new(context.source_manager())
+        .with_lint_level(Lint::DroppedAdviceValue, LintLevel::Deny)
+        .assemble_program("test", source)
+        .expect_err("expected a denied lint to fail assembly");
+    assert_diagnostic!(&err, "advice value is dropped without being used");
+
+    Ok(())
+}
+
 #[test]
 #[ignore = "disabled until #3040 is resolved"]
 fn library_procedure_collision() -> Result<(), Report> {
@@ -436,6 +1200,80 @@ fn library_procedure_collision() -> Result<(), Report> {
     Ok(())
 }
 
+#[test]
+fn versioned_library_metadata_survives_serialization() -> Result<(), Report> {
+    let context = TestContext::new();
+
+    let foo = r#"
+        namespace lib1::foo
+        pub proc foo1
+            push.1 push.2 add
+        end
+    "#;
+    let foo = parse_module!(&context, foo);
+    let mut lib1 = Assembler::new(context.source_manager()).assemble_library(
+        "lib1",
+        foo,
+        None::<Box<Module>>,
+    )?;
+    lib1.version = "1.2.3".parse().unwrap();
+    let lib1 = Arc::<Package>::from(lib1);
+
+    let bar = r#"
+        namespace lib2::bar
+        pub proc bar1
+            exec.::lib1::foo::foo1
+        end
+    "#;
+    let bar = parse_module!(&context, bar);
+    let version: Version = "0.4.0".parse().unwrap();
+    let lib2 = Assembler::new(context.source_manager())
+        .with_package(lib1.clone(), Linkage::Dynamic)?
+        .assemble_library_versioned(
+            "lib2",
+            version.clone(),
+            &[(lib1.name.clone(), lib1.version.clone())],
+            bar,
+            None::<Box<Module>>,
+        )?;
+
+    let lib2 = Package::read_from_bytes(&lib2.to_bytes()).map_err(Report::msg)?;
+    assert_eq!(lib2.version, version);
+    let dependencies = lib2.manifest.dependencies().collect::<Vec<_>>();
+    assert_eq!(dependencies.len(), 1);
+    assert_eq!(dependencies[0].id(), &lib1.name);
+    assert_eq!(dependencies[0].version(), &lib1.version);
+    assert_eq!(dependencies[0].digest, lib1.digest());
+
+    // pinning a version other than the one linked, or a package which was not linked, is an error
+    let bar = parse_module!(&context, "namespace lib2::bar\npub proc bar1 add end");
+    let err = Assembler::new(context.source_manager())
+        .with_package(lib1.clone(), Linkage::Dynamic)?
+        .assemble_library_versioned(
+            "lib2",
+            version.clone(),
+            &[(lib1.name.clone(), "1.2.4".parse().unwrap())],
+            bar,
+            None::<Box<Module>>,
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("the linked package has version 1.2.3"), "{err}");
+
+    let bar = parse_module!(&context, "namespace lib2::bar\npub proc bar1 add end");
+    let err = Assembler::new(context.source_manager())
+        .assemble_library_versioned(
+            "lib2",
+            version,
+            &[(lib1.name.clone(), lib1.version.clone())],
+            bar,
+            None::<Box<Module>>,
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("no such package has been linked"), "{err}");
+
+    Ok(())
+}
+
 #[test]
 fn get_module_by_path() {
     let context = TestContext::new();
@@ -943,6 +1781,67 @@ fn constant_field_division() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn constant_bitwise_and_shift_expressions() -> TestResult {
+    let context = TestContext::default();
+    let source = source_file!(
+        &context,
+        "\
+    const BITS = 8
+    const MASK = (1 << BITS) - 1
+    const HIGH = 0xABCD >> BITS
+    const FLAGS = 12 | 3 ^ 5 & MASK
+    const REMAINDER = 100 % 7 + 2 * 3
+    const PACKED = word(MASK, HIGH, FLAGS, REMAINDER << 1)
+
+    begin
+        push.MASK.HIGH.FLAGS.REMAINDER
+        push.PACKED
+    end"
+    );
+    let program = context.assemble(source)?;
+
+    let expected = source_file!(
+        &context,
+        "\
+    const PACKED = [255, 171, 14, 16]
+
+    begin
+        push.255.171.14.8
+        push.PACKED
+    end"
+    );
+    let expected = context.assemble(expected)?;
+    assert_eq!(program.hash(), expected.hash());
+    Ok(())
+}
+
+#[test]
+fn constant_err_shift_overflow() {
+    let context = TestContext::default();
+    let source = source_file!(
+        &context,
+        "\
+    const TOO_BIG = 1 << 64 \
+    begin \
+    push.TOO_BIG \
+    end"
+    );
+    let err = context.assemble(source).expect_err("expected constant overflow diagnostic");
+    assert_diagnostic!(&err, "invalid constant expression: value is larger than expected range");
+
+    let source = source_file!(
+        &context,
+        "\
+    const REMAINDER = 5 % 0 \
+    begin \
+    push.REMAINDER \
+    end"
+    );
+    let err = context.assemble(source).expect_err("expected division by zero diagnostic");
+    assert_diagnostic!(&err, "invalid constant expression: division by zero");
+}
+
 #[test]
 fn constant_err_const_not_initialized() {
     let context = TestContext::default();
@@ -2109,6 +3008,105 @@ fn control_flow_nesting_depth_exceeded() {
     assert_diagnostic!(&error, "control-flow nesting depth exceeded");
 }
 
+// MACROS
+// ================================================================================================
+
+#[test]
+fn macro_expansion_matches_handwritten_code() -> TestResult {
+    let context = TestContext::default();
+    let expanded = source_file!(
+        &context,
+        r#"
+    const ERR = "value mismatch"
+    const SHIFT = 4
+
+    macro.check_eq(expected, msg)
+        push.expected assert_eq.err=msg
+    end
+
+    macro.scale(value, shift)
+        push.value u32shl.shift
+        macro_exec.check_eq(value * 16, ERR)
+    end
+
+    begin
+        push.48
+        macro_exec.scale(3, SHIFT)
+        repeat.2
+            macro_exec.check_eq(0, "unreachable")
+        end
+    end"#
+    );
+    let handwritten = source_file!(
+        &context,
+        r#"
+    const ERR = "value mismatch"
+    const SHIFT = 4
+
+    begin
+        push.48
+        push.3 u32shl.SHIFT
+        push.48 assert_eq.err=ERR
+        repeat.2
+            push.0 assert_eq.err="unreachable"
+        end
+    end"#
+    );
+
+    let expanded = context.assemble(expanded)?;
+    let handwritten = context.assemble(handwritten)?;
+    assert_eq!(expanded.hash(), handwritten.hash());
+    Ok(())
+}
+
+// CONDITIONAL COMPILATION
+// ================================================================================================
+
+#[test]
+fn cfg_attributes_select_items_by_assembler_features() -> TestResult {
+    let context = TestContext::default();
+    let source = r#"
+    @cfg(feature = "debug")
+    proc check
+        dup assertz
+    end
+
+    @cfg(not(feature = "debug"))
+    proc check
+        nop
+    end
+
+    @cfg(feature = "debug")
+    begin
+        push.0 exec.check
+    end
+
+    @cfg(not(feature = "debug"))
+    begin
+        push.1 exec.check
+    end"#;
+
+    let assemble = |assembler: Assembler, source: &str| -> Result<Program, Report> {
+        let source = source_file!(&context, source);
+        Ok(assembler.assemble_program("test", source)?.unwrap_program())
+    };
+
+    let debug = assemble(Assembler::new(context.source_manager()).with_feature("debug"), source)?;
+    let expected = assemble(
+        Assembler::new(context.source_manager()),
+        "proc check dup assertz end begin push.0 exec.check end",
+    )?;
+    assert_eq!(debug.hash(), expected.hash());
+
+    let release = assemble(Assembler::new(context.source_manager()), source)?;
+    let expected = assemble(
+        Assembler::new(context.source_manager()),
+        "proc check nop end begin push.1 exec.check end",
+    )?;
+    assert_eq!(release.hash(), expected.hash());
+    Ok(())
+}
+
 // PROGRAMS WITH PROCEDURES
 // ================================================================================================
 
@@ -4979,28 +5977,168 @@ fn link_import_self_relative_public_item_reexport_resolves() -> TestResult {
         "#
     ))?;
 
-    Assembler::new(context.source_manager()).assemble_library("app", root, [child, dep])?;
+    Assembler::new(context.source_manager()).assemble_library("app", root, [child, dep])?;
+
+    Ok(())
+}
+
+#[test]
+fn link_import_public_item_reexport_cycle_is_rejected() -> TestResult {
+    let context = TestContext::new();
+    let a = context.parse_module(source_file!(
+        &context,
+        r#"
+        namespace a
+
+        pub use {B as A} from b
+        "#
+    ))?;
+    let b = context.parse_module(source_file!(
+        &context,
+        r#"
+        namespace b
+
+        pub use {A as B} from a
+        "#
+    ))?;
+    let consumer = context.parse_module(source_file!(
+        &context,
+        r#"
+        namespace app
+
+        use {A} from a
+
+        pub proc entry
+            push.A
+            drop
+        end
+        "#
+    ))?;
+
+    let err = Assembler::new(context.source_manager())
+        .assemble_library("app", consumer, [a, b])
+        .expect_err("public item re-export cycle should be rejected");
+
+    assert_diagnostic!(&err, "import re-export cycle");
+
+    Ok(())
+}
+
+#[test]
+fn link_import_public_item_reexport_cycle_with_self_relative_target_is_rejected() -> TestResult {
+    let context = TestContext::new();
+    let root = context.parse_module(source_file!(
+        &context,
+        r#"
+        namespace root
+
+        pub mod child
+        pub use {B as A} from self::child
+        "#
+    ))?;
+    let child = context.parse_module(source_file!(
+        &context,
+        r#"
+        namespace root::child
+
+        pub use {A as B} from root
+        "#
+    ))?;
+    let consumer = context.parse_module(source_file!(
+        &context,
+        r#"
+        namespace app
+
+        use {A} from root
+
+        pub proc entry
+            push.A
+            drop
+        end
+        "#
+    ))?;
+
+    let err = Assembler::new(context.source_manager())
+        .assemble_library("app", consumer, [root, child])
+        .expect_err("public item re-export cycle should be rejected");
+
+    assert_diagnostic!(&err, "import re-export cycle");
+
+    Ok(())
+}
+
+#[test]
+fn link_glob_import_imports_public_items() -> TestResult {
+    let context = TestContext::new();
+    let dep = context.parse_module(source_file!(
+        &context,
+        r#"
+        namespace dep
+
+        pub const VALUE = 1
+
+        pub proc foo
+            push.VALUE
+        end
+
+        pub proc bar
+            exec.hidden
+        end
+
+        proc hidden
+            push.3
+        end
+        "#
+    ))?;
+    let consumer = context.parse_module(source_file!(
+        &context,
+        r#"
+        namespace app
+
+        use dep::*
+
+        proc bar
+            push.4
+        end
+
+        pub proc entry
+            exec.foo
+            exec.bar
+            push.VALUE
+            drop drop drop
+        end
+        "#
+    ))?;
+
+    let library =
+        Assembler::new(context.source_manager()).assemble_library("app", consumer, [dep])?;
+    let exports = library.manifest.exports().map(PackageExport::path).collect::<BTreeSet<_>>();
+
+    assert_eq!(exports.len(), 1);
+    assert!(exports.contains(&Arc::from(Path::new("::app::entry"))));
 
     Ok(())
 }
 
 #[test]
-fn link_import_public_item_reexport_cycle_is_rejected() -> TestResult {
+fn link_public_glob_import_reexports_public_items() -> TestResult {
     let context = TestContext::new();
-    let a = context.parse_module(source_file!(
+    let dep = context.parse_module(source_file!(
         &context,
         r#"
-        namespace a
+        namespace dep
 
-        pub use {B as A} from b
+        pub proc foo
+            push.1
+        end
         "#
     ))?;
-    let b = context.parse_module(source_file!(
+    let mid = context.parse_module(source_file!(
         &context,
         r#"
-        namespace b
+        namespace mid
 
-        pub use {A as B} from a
+        pub use dep::*
         "#
     ))?;
     let consumer = context.parse_module(source_file!(
@@ -5008,63 +6146,110 @@ fn link_import_public_item_reexport_cycle_is_rejected() -> TestResult {
         r#"
         namespace app
 
-        use {A} from a
+        pub use mid::*
 
         pub proc entry
-            push.A
-            drop
+            exec.foo
         end
         "#
     ))?;
+    let library =
+        Assembler::new(context.source_manager()).assemble_library("app", consumer, [mid, dep])?;
+    let exports = library.manifest.exports().map(PackageExport::path).collect::<BTreeSet<_>>();
 
-    let err = Assembler::new(context.source_manager())
-        .assemble_library("app", consumer, [a, b])
-        .expect_err("public item re-export cycle should be rejected");
-
-    assert_diagnostic!(&err, "import re-export cycle");
+    assert!(exports.contains(&Arc::from(Path::new("::app::foo"))));
+    assert!(exports.contains(&Arc::from(Path::new("::app::entry"))));
 
     Ok(())
 }
 
 #[test]
-fn link_import_public_item_reexport_cycle_with_self_relative_target_is_rejected() -> TestResult {
+fn link_glob_import_of_undefined_module_is_rejected() -> TestResult {
     let context = TestContext::new();
-    let root = context.parse_module(source_file!(
+    let consumer = context.parse_module(source_file!(
         &context,
         r#"
-        namespace root
+        namespace app
 
-        pub mod child
-        pub use {B as A} from self::child
+        use missing::*
+
+        pub proc entry
+            exec.foo
+        end
         "#
     ))?;
-    let child = context.parse_module(source_file!(
-        &context,
-        r#"
-        namespace root::child
 
-        pub use {A as B} from root
+    let err = Assembler::new(context.source_manager())
+        .assemble_library("app", consumer, None::<Box<Module>>)
+        .expect_err("wildcard import of an undefined module should be rejected");
+
+    assert_diagnostic!(&err, "undefined module");
+
+    Ok(())
+}
+
+#[test]
+fn link_ambiguous_glob_imports_are_rejected() -> TestResult {
+    let context = TestContext::new();
+    let parse_dep = |namespace: &str| {
+        context.parse_module(source_file!(
+            &context,
+            format!(
+                r#"
+        namespace {namespace}
+
+        pub proc foo
+            push.1
+        end
         "#
-    ))?;
-    let consumer = context.parse_module(source_file!(
-        &context,
-        r#"
+            )
+        ))
+    };
+    let consumer = |imports: &str| {
+        context.parse_module(source_file!(
+            &context,
+            format!(
+                r#"
         namespace app
 
-        use {A} from root
+        use a::*
+        use b::*
+        {imports}
 
         pub proc entry
-            push.A
-            drop
+            exec.foo
         end
         "#
-    ))?;
+            )
+        ))
+    };
 
     let err = Assembler::new(context.source_manager())
-        .assemble_library("app", consumer, [root, child])
-        .expect_err("public item re-export cycle should be rejected");
+        .assemble_library("app", consumer("")?, [parse_dep("a")?, parse_dep("b")?])
+        .expect_err("a name imported by two wildcard imports should be rejected");
+    assert_diagnostic!(&err, "ambiguous name 'foo' in module '::app'");
+
+    // an explicit import takes precedence over both wildcard imports
+    Assembler::new(context.source_manager()).assemble_library(
+        "app",
+        consumer("use {foo} from a")?,
+        [parse_dep("a")?, parse_dep("b")?],
+    )?;
 
-    assert_diagnostic!(&err, "import re-export cycle");
+    // the same item imported through a wildcard re-export is not ambiguous
+    let b = context.parse_module(source_file!(
+        &context,
+        r#"
+        namespace b
+
+        pub use a::*
+        "#
+    ))?;
+    Assembler::new(context.source_manager()).assemble_library(
+        "app",
+        consumer("")?,
+        [parse_dep("a")?, b],
+    )?;
 
     Ok(())
 }
@@ -6117,6 +7302,57 @@ fn test_assembler_debug_info_present() {
     );
 }
 
+#[test]
+fn merged_procedure_asm_ops_keep_exec_call_sites() -> TestResult {
+    let context = TestContext::default();
+    let source = "\
+proc check
+    push.1 assert
+end
+
+begin
+    push.0 drop
+    exec.check
+    exec.check
+end";
+    let package = Assembler::new(context.source_manager()).assemble_program("test", source)?;
+    let debug_info = package
+        .debug_info()
+        .expect("package debug info should decode")
+        .expect("package should contain debug info");
+    let source_map = debug_info.source_map().expect("missing source map");
+
+    // `check` is small enough to be merged into the basic block of `main`, so the operations of
+    // each of its invocations record the `exec` they were merged through
+    let exec_offsets =
+        source.match_indices("exec.check").map(|(offset, _)| offset).collect::<Vec<_>>();
+    let mut call_site_offsets = source_map
+        .asm_ops()
+        .iter()
+        .filter(|row| row.op == "assert" && !row.call_sites.is_empty())
+        .map(|row| {
+            assert_eq!(row.call_sites.len(), 1);
+            row.call_sites[0].start.to_usize()
+        })
+        .collect::<Vec<_>>();
+    call_site_offsets.sort_unstable();
+    call_site_offsets.dedup();
+    assert_eq!(call_site_offsets, exec_offsets);
+
+    // the location of the instruction itself comes first in its span chain
+    let row = source_map
+        .asm_ops()
+        .iter()
+        .find(|row| row.op == "assert" && !row.call_sites.is_empty())
+        .unwrap();
+    let info = AsmOpInfo::new(row);
+    let chain = info.span_chain().map(|location| location.start.to_usize()).collect::<Vec<_>>();
+    assert_eq!(chain, [source.find("assert").unwrap(), row.call_sites[0].start.to_usize()]);
+    assert_eq!(info.location(), row.location.as_ref());
+
+    Ok(())
+}
+
 #[test]
 fn test_cross_module_constant_resolution() -> TestResult {
     let context = TestContext::default();
@@ -7365,6 +8601,117 @@ fn test_syscall_resolution_to_non_kernel_path_is_checked() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn with_kernel_from_package_links_kernel_into_configured_assembler() -> TestResult {
+    let context = TestContext::default();
+
+    let kernel = context.parse_kernel(source_file!(
+        &context,
+        r#"
+        pub proc foo
+            caller
+            drop
+        end
+        "#
+    ))?;
+    let kernel =
+        Assembler::new(context.source_manager()).assemble_kernel("kernel", kernel, None)?;
+    let kernel_foo_root = kernel.as_ref().get_procedure_root_by_path("::$kernel::foo").unwrap();
+
+    let program = Assembler::new(context.source_manager())
+        .with_warnings_as_errors(true)
+        .with_kernel_from_package(&kernel)?
+        .assemble_program("program", "begin syscall.foo end")?
+        .unwrap_program();
+    assert!(program.kernel().contains_proc(kernel_foo_root));
+
+    // a kernel cannot be replaced once linked
+    let err = Assembler::new(context.source_manager())
+        .with_kernel_from_package(&kernel)?
+        .with_kernel_from_package(&kernel)
+        .err()
+        .expect("expected a second kernel to be rejected");
+    assert_diagnostic!(&err, "duplicate kernels present in the dependency graph");
+
+    // libraries are not kernels
+    let lib = context.parse_module(source_file!(
+        &context,
+        r#"
+        namespace userspace
+
+        pub proc foo
+            push.0
+        end
+        "#
+    ))?;
+    let lib = Assembler::new(context.source_manager()).assemble_library(
+        "lib",
+        lib,
+        None::<Box<Module>>,
+    )?;
+    let err = Assembler::new(context.source_manager())
+        .with_kernel_from_package(&lib)
+        .err()
+        .expect("expected a library to be rejected as a kernel");
+    assert_diagnostic!(&err, "invalid kernel package: 'lib@0.0.0' is not a kernel");
+
+    Ok(())
+}
+
+#[test]
+fn assemble_program_for_kernels_validates_syscalls_per_kernel() -> TestResult {
+    let context = TestContext::default();
+
+    let assemble_kernel = |name: &str, source: &str| -> Result<Arc<Package>, Report> {
+        let kernel = context.parse_kernel(source_file!(&context, source.to_string()))?;
+        let kernel =
+            Assembler::new(context.source_manager()).assemble_kernel(name, kernel, None)?;
+        Ok(Arc::from(kernel))
+    };
+    let full = assemble_kernel(
+        "full",
+        "pub proc foo caller drop end\npub proc bar caller drop push.1 drop end",
+    )?;
+    let other_full = assemble_kernel(
+        "other_full",
+        "pub proc foo caller drop push.2 drop end\npub proc bar caller drop push.3 drop end",
+    )?;
+    let partial = assemble_kernel("partial", "pub proc foo caller drop end")?;
+
+    let source = "begin syscall.foo syscall.bar end";
+    let assembler = Assembler::new(context.source_manager());
+
+    let programs =
+        assembler.assemble_program_for_kernels("program", source, [full.clone(), other_full])?;
+    assert_eq!(programs.len(), 2);
+    let kernels = programs
+        .into_iter()
+        .map(|package| package.unwrap_program().kernel().clone())
+        .collect::<Vec<_>>();
+    assert_ne!(kernels[0], kernels[1]);
+
+    let err = assembler
+        .assemble_program_for_kernels("program", source, [full, partial])
+        .expect_err("expected the program to fail against the partial kernel");
+    assert_diagnostic!(&err, "failed to assemble 'program' against 1 of 2 kernels");
+    let diagnostics = crate::AssemblyDiagnostic::from_report(&err);
+    assert!(
+        diagnostics.iter().any(|diagnostic| diagnostic.message
+            == "failed to assemble 'program' against kernel 'partial@0.0.0'"),
+        "{diagnostics:#?}"
+    );
+    assert!(!diagnostics.iter().any(|diagnostic| diagnostic.message.contains("'full@")));
+    let bar = source.find("bar").unwrap();
+    assert!(
+        diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.labels.iter().any(|(range, _)| range.contains(&bar))),
+        "{diagnostics:#?}"
+    );
+
+    Ok(())
+}
+
 #[test]
 fn syscall_validation_does_not_panic_on_same_digest_userspace_procedure() {
     use std::panic::{AssertUnwindSafe, catch_unwind};
@@ -7705,3 +9052,169 @@ fn test_entrypoint_with_locals_via_constructor_panics() {
 
     let _ = Assembler::new(context.source_manager()).assemble_program("test", module);
 }
+
+#[test]
+fn library_exports_carry_procedure_abi() -> TestResult {
+    let context = TestContext::default();
+    let wallet = parse_module!(
+        &context,
+        r#"
+        namespace lib::wallet
+
+        @abi(inputs = 2, outputs = 1, advice = 1)
+        @emits("miden::wallet::updated", checked)
+        pub proc update
+            adv_loadw swapw dropw swapw dropw
+            push.0 emit drop
+        end
+
+        pub proc noop
+            nop
+        end
+        "#
+    );
+    let lib = Assembler::new(context.source_manager()).assemble_library(
+        "lib",
+        wallet,
+        None::<Box<Module>>,
+    )?;
+
+    // the ABI survives serialization of the package
+    let lib = Package::read_from_bytes(&lib.to_bytes()).into_diagnostic()?;
+    let export = |name: &str| {
+        lib.manifest
+            .get_export(name)
+            .and_then(PackageExport::as_procedure)
+            .cloned()
+            .expect("missing export")
+    };
+
+    let abi = export("::lib::wallet::update").abi().into_diagnostic()?.expect("missing abi");
+    assert_eq!((abi.inputs, abi.outputs, abi.advice), (2, 1, 1));
+    let events = abi.events.iter().map(EventName::as_str).collect::<Vec<_>>();
+    assert_eq!(events, ["miden::wallet::updated", "checked"]);
+    assert!(export("::lib::wallet::noop").abi().into_diagnostic()?.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn disassembler_reconstructs_masm_from_packages() -> TestResult {
+    let context = TestContext::default();
+    let math = parse_module!(
+        &context,
+        r#"
+        namespace lib::math
+
+        proc helper
+            push.2 mul
+        end
+
+        pub proc add_one
+            add.1 u32assert.err="not a u32"
+            dup.0 eq.0 if.true call.helper else push.7 drop end
+            exec.helper
+        end
+        "#
+    );
+    let lib = Assembler::new(context.source_manager()).assemble_library(
+        "lib",
+        math,
+        None::<Box<Module>>,
+    )?;
+    let add_one = lib.get_procedure_root_by_path("::lib::math::add_one").unwrap();
+
+    // with debug info, the assembled instructions are recovered
+    let masm = Disassembler::from_package(&lib)?.disassemble();
+    let expected = format!(
+        "\
+# mast root: {add_one}
+pub proc \"lib::math::add_one\"
+    add.1
+    u32assert.err=\"not a u32\"
+    dup.0
+    eq.0
+    if.true
+        call.proc_0
+    else
+        push.7
+        drop
+    end
+    push.2
+    mul
+end
+"
+    );
+    assert!(masm.contains(&expected), "unexpected disassembly:\n{masm}");
+
+    // the reconstructed assembly assembles to the same procedure
+    let module = parse_module!(&context, format!("namespace lib::math\n{masm}"));
+    let reassembled = Assembler::new(context.source_manager()).assemble_library(
+        "lib",
+        module,
+        None::<Box<Module>>,
+    )?;
+    assert_eq!(
+        reassembled.get_procedure_root_by_path("::lib::math::\"lib::math::add_one\""),
+        Some(add_one)
+    );
+
+    // without debug info, instructions are synthesized from the operations
+    let stripped = lib.clone().without_debug_info().into_diagnostic()?;
+    let masm = Disassembler::from_package(&stripped)?.disassemble();
+    assert!(masm.contains("    push.0\n    u32assert2 # error code "), "{masm}");
+
+    // invocations of procedures outside of the forest are rendered by MAST root
+    let source = source_file!(
+        &context,
+        r#"
+        use lib::math
+        begin
+            push.1 call.math::add_one dyncall
+        end
+        "#
+    );
+    let program = Assembler::new(context.source_manager())
+        .with_package(Arc::<Package>::from(lib), Linkage::Dynamic)?
+        .assemble_program("test", source)?
+        .unwrap_program();
+    let masm = Disassembler::from_program(&program).disassemble();
+    assert!(masm.starts_with("begin\n"), "{masm}");
+    assert!(masm.contains(&format!("    call.{add_one}\n    dyncall\nend\n")), "{masm}");
+
+    Ok(())
+}
+
+#[test]
+fn assemble_checked_reports_diagnostics_instead_of_panicking() {
+    // malformed programs are reported as diagnostics
+    let diagnostics = crate::assemble_checked("begin u32shl.32 end").unwrap_err();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, crate::diagnostics::Severity::Error);
+    assert_eq!(
+        diagnostics[0].message,
+        "invalid immediate: value must be in the range 0..32 (exclusive)"
+    );
+    assert_eq!(diagnostics[0].labels.len(), 1);
+    assert_eq!(diagnostics[0].labels[0].0, 13..15);
+
+    // related diagnostics are flattened
+    let diagnostics =
+        crate::assemble_checked("const A = B\nconst B = A\nbegin push.A end").unwrap_err();
+    assert!(diagnostics.len() > 1);
+    assert!(
+        diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.message.contains("infinite recursion"))
+    );
+
+    // a block made up of the operations of another block followed by `noop`s has the same
+    // digest, which must not cause the two to be merged
+    let program = crate::assemble_checked(
+        "proc log nop end begin push.1 push.2 push.3 push.4 exec.log exec.log end",
+    )
+    .unwrap();
+    let expected =
+        crate::assemble_checked("begin push.1 push.2 push.3 push.4 nop nop end").unwrap();
+    assert_eq!(program.hash(), expected.hash());
+}
@@ -264,6 +264,39 @@ impl Word {
             d: self.a,
         }
     }
+
+    /// Returns the elements of this word in little-endian order.
+    ///
+    /// The least significant element (`word[0]`) comes first. This is the order in which the
+    /// word's elements are laid out in memory (`word[i]` is stored at address `addr + i`), and the
+    /// order in which they appear on the stack when read from the top: `word[0]` is the element
+    /// closest to the top of the stack, as returned by `get_stack_word`.
+    pub const fn to_elements_le(&self) -> [Felt; Self::NUM_ELEMENTS] {
+        [self.a, self.b, self.c, self.d]
+    }
+
+    /// Returns the elements of this word in big-endian order.
+    ///
+    /// The most significant element (`word[3]`) comes first. This is the reverse of
+    /// [`Self::to_elements_le`].
+    pub const fn to_elements_be(&self) -> [Felt; Self::NUM_ELEMENTS] {
+        [self.d, self.c, self.b, self.a]
+    }
+
+    /// Creates a word from elements in little-endian order, i.e. `elements[0]` becomes `word[0]`.
+    ///
+    /// This is the inverse of [`Self::to_elements_le`].
+    pub const fn from_elements_le(elements: [Felt; Self::NUM_ELEMENTS]) -> Self {
+        Self::new(elements)
+    }
+
+    /// Creates a word from elements in big-endian order, i.e. `elements[0]` becomes `word[3]`.
+    ///
+    /// This is the inverse of [`Self::to_elements_be`].
+    pub const fn from_elements_be(elements: [Felt; Self::NUM_ELEMENTS]) -> Self {
+        let [d, c, b, a] = elements;
+        Self { a, b, c, d }
+    }
 }
 
 impl Hash for Word {
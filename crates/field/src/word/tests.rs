@@ -133,6 +133,20 @@ proptest! {
     }
 }
 
+proptest! {
+    #[test]
+    fn word_le_be_elements_are_reverses(word in any::<Word>()) {
+        let mut le = word.to_elements_le();
+        prop_assert_eq!(le, word.into_elements());
+        prop_assert_eq!(Word::from_elements_le(le), word);
+        prop_assert_eq!(Word::from_elements_be(word.to_elements_be()), word);
+
+        le.reverse();
+        prop_assert_eq!(le, word.to_elements_be());
+        prop_assert_eq!(Word::from_elements_be(word.to_elements_le()), word.reversed());
+    }
+}
+
 #[test]
 fn word_elements_array_layout_roundtrip() {
     let mut word = Word::new([
@@ -72,7 +72,7 @@ fn markdown_file_name(ns: &miden_assembly_syntax::Path) -> String {
 /// Writes Miden core library modules documentation markdown files based on the available
 /// modules and comments.
 pub fn build_core_lib_docs(asm_dir: &Path, output_dir: &str) -> io::Result<()> {
-    use miden_assembly_syntax::{Path as MasmPath, ast::ModuleKind, parser};
+    use miden_assembly_syntax::{ParseOptions, Path as MasmPath, ast::ModuleKind, parser};
     let output_path = Path::new(output_dir);
 
     // Try to delete, but ignore “not found” error
@@ -93,7 +93,7 @@ pub fn build_core_lib_docs(asm_dir: &Path, output_dir: &str) -> io::Result<()> {
         Some(namespace),
         Some(ModuleKind::Library),
         source_manager,
-        true,
+        &ParseOptions::default().with_warnings_as_errors(true),
     )
     .unwrap_or_else(|err| panic!("{}", PrintDiagnostic::new(err)));
 
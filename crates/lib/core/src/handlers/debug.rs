@@ -3,11 +3,11 @@
 //! Each `miden::core::debug::print_*` procedure emits a well-known event. This module registers a
 //! single [`DebugPrinter`] handler for all of those events; when one fires, the handler reads the
 //! requested piece of VM state (operand stack, memory, advice stack, or advice map) and prints it
-//! using the VM's tree-style debug formatting via [`miden_processor::write_stack`] /
-//! [`miden_processor::write_interval`]. A range-based procedure may share an event with its
-//! full-state variant when the full-state behavior can be represented as an unbounded range (e.g.
-//! the advice stack); memory uses a dedicated full-state event because `print_mem` enumerates its
-//! (capped) range while `print_mem_all` lists only initialized cells.
+//! using the VM's tree-style debug formatting via a [`miden_processor::VmStatePrinter`]. A
+//! range-based procedure may share an event with its full-state variant when the full-state
+//! behavior can be represented as an unbounded range (e.g. the advice stack); memory uses a
+//! dedicated full-state event because `print_mem` enumerates its (capped) range while
+//! `print_mem_all` lists only initialized cells.
 //!
 //! These are ordinary `emit` events: they carry no MAST/decorator cost and print whenever the
 //! procedure is executed.
@@ -21,12 +21,11 @@ use alloc::{
 };
 use core::fmt;
 
-use miden_core::{Felt, Word};
+use miden_core::{Felt, WORD_SIZE, Word};
 use miden_processor::{
-    MemoryError, ProcessorState, StdoutWriter,
+    MemoryError, ProcessorState, StdoutWriter, VmStatePrinter,
     advice::AdviceMutation,
     event::{EventError, EventHandler, EventId, EventName},
-    write_interval, write_stack,
 };
 use miden_utils_sync::RwLock;
 
@@ -39,6 +38,9 @@ pub const PRINT_STACK_EVENT_NAME: EventName = EventName::new("miden::core::debug
 pub const PRINT_MEM_EVENT_NAME: EventName = EventName::new("miden::core::debug::print_mem");
 /// Prints the entire memory of the current context.
 pub const PRINT_MEM_ALL_EVENT_NAME: EventName = EventName::new("miden::core::debug::print_mem_all");
+/// Prints `num_words` consecutive words of memory of the current context.
+pub const PRINT_MEM_WORDS_EVENT_NAME: EventName =
+    EventName::new("miden::core::debug::print_mem_words");
 /// Prints the advice stack in the range `[start, end)`.
 pub const PRINT_ADV_STACK_EVENT_NAME: EventName =
     EventName::new("miden::core::debug::print_adv_stack");
@@ -54,16 +56,28 @@ pub const PRINT_ADV_MAP_ITEM_EVENT_NAME: EventName =
 /// its own event) to print the entire memory of the current context.
 const MAX_PRINT_MEM_RANGE: u64 = 1024;
 
+/// Maximum number of words a `print_mem_words` region may span, i.e. the same number of addresses
+/// as [`MAX_PRINT_MEM_RANGE`].
+const MAX_PRINT_MEM_WORDS: u64 = MAX_PRINT_MEM_RANGE / WORD_SIZE as u64;
+
 /// Returns the default `(EventName, handler)` pairs for print-style debugging.
 ///
 /// The default set prints operand-stack and memory state to stdout. Advice-stack and advice-map
 /// printers are excluded because they may expose witness data.
 pub fn default_debug_handlers() -> Vec<(EventName, Arc<dyn EventHandler>)> {
-    let printer: Arc<dyn EventHandler> = Arc::new(DebugPrinter::default());
+    default_debug_handlers_with_printer(VmStatePrinter::default())
+}
+
+/// Same as [`default_debug_handlers`], but formats the printed VM state with `printer`.
+pub fn default_debug_handlers_with_printer(
+    printer: VmStatePrinter,
+) -> Vec<(EventName, Arc<dyn EventHandler>)> {
+    let printer: Arc<dyn EventHandler> = Arc::new(DebugPrinter::default().with_printer(printer));
     vec![
         (PRINT_STACK_EVENT_NAME, printer.clone()),
         (PRINT_MEM_EVENT_NAME, printer.clone()),
-        (PRINT_MEM_ALL_EVENT_NAME, printer),
+        (PRINT_MEM_ALL_EVENT_NAME, printer.clone()),
+        (PRINT_MEM_WORDS_EVENT_NAME, printer),
     ]
 }
 
@@ -77,6 +91,7 @@ pub fn noop_debug_handlers() -> Vec<(EventName, Arc<dyn EventHandler>)> {
         (PRINT_STACK_EVENT_NAME, handler.clone()),
         (PRINT_MEM_EVENT_NAME, handler.clone()),
         (PRINT_MEM_ALL_EVENT_NAME, handler.clone()),
+        (PRINT_MEM_WORDS_EVENT_NAME, handler.clone()),
         (PRINT_ADV_STACK_EVENT_NAME, handler.clone()),
         (PRINT_ADV_MAP_EVENT_NAME, handler.clone()),
         (PRINT_ADV_MAP_ITEM_EVENT_NAME, handler),
@@ -92,6 +107,7 @@ pub fn debug_handlers() -> Vec<(EventName, Arc<dyn EventHandler>)> {
         (PRINT_STACK_EVENT_NAME, printer.clone()),
         (PRINT_MEM_EVENT_NAME, printer.clone()),
         (PRINT_MEM_ALL_EVENT_NAME, printer.clone()),
+        (PRINT_MEM_WORDS_EVENT_NAME, printer.clone()),
         (PRINT_ADV_STACK_EVENT_NAME, printer.clone()),
         (PRINT_ADV_MAP_EVENT_NAME, printer.clone()),
         (PRINT_ADV_MAP_ITEM_EVENT_NAME, printer),
@@ -121,21 +137,32 @@ pub fn advice_debug_handlers() -> Vec<(EventName, Arc<dyn EventHandler>)> {
 ///
 /// The writer is guarded by an [`RwLock`] because [`EventHandler::on_event`] takes `&self`. The
 /// default writer prints to stdout (under the `std` feature); a custom writer (e.g. an in-memory
-/// buffer) can be supplied via [`DebugPrinter::new`] for testing.
+/// buffer) can be supplied via [`DebugPrinter::new`] for testing. The VM state is formatted with
+/// the default [`VmStatePrinter`] unless another one is set via [`DebugPrinter::with_printer`].
 pub struct DebugPrinter<W: fmt::Write + Send + Sync = StdoutWriter> {
     writer: RwLock<W>,
+    printer: VmStatePrinter,
 }
 
 impl Default for DebugPrinter<StdoutWriter> {
     fn default() -> Self {
-        Self { writer: RwLock::new(StdoutWriter) }
+        Self::new(StdoutWriter)
     }
 }
 
 impl<W: fmt::Write + Send + Sync> DebugPrinter<W> {
     /// Creates a new [`DebugPrinter`] writing to the provided writer.
     pub fn new(writer: W) -> Self {
-        Self { writer: RwLock::new(writer) }
+        Self {
+            writer: RwLock::new(writer),
+            printer: VmStatePrinter::default(),
+        }
+    }
+
+    /// Sets the printer used to format the VM state.
+    pub fn with_printer(mut self, printer: VmStatePrinter) -> Self {
+        self.printer = printer;
+        self
     }
 }
 
@@ -146,13 +173,14 @@ impl<W: fmt::Write + Send + Sync + 'static> EventHandler for DebugPrinter<W> {
         let id = EventId::from_felt(process.get_stack_item(0));
         let mut writer = self.writer.write();
         let w: &mut W = &mut writer;
+        let printer = &self.printer;
 
         if id == PRINT_STACK_EVENT_NAME.to_event_id() {
             // Skip position 0 (the event id) so only the user's operand stack is shown. Print the
             // entire stack (no cap).
             let stack = process.get_stack_state();
             let operand_stack = stack.get(1..).unwrap_or(&[]);
-            write_stack(w, operand_stack, None, "Stack", process.clock())?;
+            printer.write_stack(w, operand_stack, None, "Stack", process.clock())?;
         } else if id == PRINT_MEM_EVENT_NAME.to_event_id() {
             let bounds = read_mem_print_range(process, 1, 2)?;
             // Guard against an accidentally huge explicit range.
@@ -165,19 +193,31 @@ impl<W: fmt::Write + Send + Sync + 'static> EventHandler for DebugPrinter<W> {
                     .into());
                 }
             }
-            write_mem_range(w, process, bounds)?;
+            write_mem_range(w, printer, process, bounds)?;
         } else if id == PRINT_MEM_ALL_EVENT_NAME.to_event_id() {
-            write_mem_all(w, process)?;
+            write_mem_all(w, printer, process)?;
+        } else if id == PRINT_MEM_WORDS_EVENT_NAME.to_event_id() {
+            let addr = process.get_stack_item(1).as_canonical_u64();
+            let num_words = process.get_stack_item(2).as_canonical_u64();
+            let addr = u32::try_from(addr).map_err(|_| MemoryError::AddressOutOfBounds { addr })?;
+            if num_words > MAX_PRINT_MEM_WORDS {
+                return Err(format!(
+                    "print_mem_words region of {num_words} words exceeds maximum of \
+                     {MAX_PRINT_MEM_WORDS}"
+                )
+                .into());
+            }
+            write_mem_words(w, printer, process, addr, num_words as u32)?;
         } else if id == PRINT_ADV_STACK_EVENT_NAME.to_event_id() {
             let start = stack_item_as_usize(process, 1);
             let end = stack_item_as_usize(process, 2);
             let adv_stack = process.advice_provider().stack();
             let slice = slice_range(&adv_stack, start, end);
-            write_stack(w, slice, None, "Advice stack", process.clock())?;
+            printer.write_stack(w, slice, None, "Advice stack", process.clock())?;
         } else if id == PRINT_ADV_MAP_EVENT_NAME.to_event_id() {
-            write_adv_map(w, process)?;
+            write_adv_map(w, printer, process)?;
         } else if id == PRINT_ADV_MAP_ITEM_EVENT_NAME.to_event_id() {
-            write_adv_map_entry(w, process)?;
+            write_adv_map_entry(w, printer, process)?;
         }
         // Unknown ids are ignored: the handler is only registered for the events above.
 
@@ -250,6 +290,7 @@ fn read_mem_print_range(
 /// `u32`. The caller is responsible for capping the range length (see [`MAX_PRINT_MEM_RANGE`]).
 fn write_mem_range<W: fmt::Write>(
     w: &mut W,
+    printer: &VmStatePrinter,
     process: &ProcessorState,
     bounds: Option<(u32, u32)>,
 ) -> fmt::Result {
@@ -267,11 +308,41 @@ fn write_mem_range<W: fmt::Write>(
             (format!("{addr:#010x}"), value)
         })
         .collect();
-    write_interval(w, items, None)
+    printer.write_interval(w, items, None)
+}
+
+/// Prints `num_words` consecutive words of the current context starting at the word-aligned
+/// `addr`, showing uninitialized words as `EMPTY`.
+fn write_mem_words<W: fmt::Write>(
+    w: &mut W,
+    printer: &VmStatePrinter,
+    process: &ProcessorState,
+    addr: u32,
+    num_words: u32,
+) -> Result<(), EventError> {
+    let (ctx, clk) = (process.ctx(), process.clock());
+    let words = process.get_mem_region(ctx, addr, num_words)?;
+    if words.is_empty() {
+        writeln!(w, "Memory words before step {clk} for context {ctx}: region is empty.")?;
+        return Ok(());
+    }
+
+    writeln!(w, "Memory words before step {clk} for context {ctx} starting at {addr:#010x}:")?;
+    let items: Vec<_> = (addr..)
+        .step_by(WORD_SIZE)
+        .zip(words)
+        .map(|(word_addr, word)| (format!("{word_addr:#010x}"), word.as_ref().map(format_word)))
+        .collect();
+    printer.write_interval(w, items, None)?;
+    Ok(())
 }
 
 /// Prints all initialized memory cells of the current context.
-fn write_mem_all<W: fmt::Write>(w: &mut W, process: &ProcessorState) -> fmt::Result {
+fn write_mem_all<W: fmt::Write>(
+    w: &mut W,
+    printer: &VmStatePrinter,
+    process: &ProcessorState,
+) -> fmt::Result {
     let (ctx, clk) = (process.ctx(), process.clock());
     writeln!(w, "Memory state before step {clk} for context {ctx}:")?;
     let items: Vec<_> = process
@@ -279,11 +350,15 @@ fn write_mem_all<W: fmt::Write>(w: &mut W, process: &ProcessorState) -> fmt::Res
         .into_iter()
         .map(|(addr, value)| (format!("{addr:#010x}"), Some(value.to_string())))
         .collect();
-    write_interval(w, items, None)
+    printer.write_interval(w, items, None)
 }
 
 /// Prints the full advice map.
-fn write_adv_map<W: fmt::Write>(w: &mut W, process: &ProcessorState) -> fmt::Result {
+fn write_adv_map<W: fmt::Write>(
+    w: &mut W,
+    printer: &VmStatePrinter,
+    process: &ProcessorState,
+) -> fmt::Result {
     let clk = process.clock();
     let map = process.advice_provider().map();
     if map.is_empty() {
@@ -295,11 +370,15 @@ fn write_adv_map<W: fmt::Write>(w: &mut W, process: &ProcessorState) -> fmt::Res
         .iter()
         .map(|(key, values)| (format_word(key), Some(format_felt_slice(values))))
         .collect();
-    write_interval(w, items, None)
+    printer.write_interval(w, items, None)
 }
 
 /// Looks up the WORD key (at stack positions 1..5) in the advice map and prints its values.
-fn write_adv_map_entry<W: fmt::Write>(w: &mut W, process: &ProcessorState) -> fmt::Result {
+fn write_adv_map_entry<W: fmt::Write>(
+    w: &mut W,
+    printer: &VmStatePrinter,
+    process: &ProcessorState,
+) -> fmt::Result {
     let key = process.get_stack_word(1);
     let key_str = format_word(&key);
     let clk = process.clock();
@@ -311,7 +390,7 @@ fn write_adv_map_entry<W: fmt::Write>(w: &mut W, process: &ProcessorState) -> fm
                 .enumerate()
                 .map(|(i, v)| (i.to_string(), Some(v.to_string())))
                 .collect();
-            write_interval(w, items, None)
+            printer.write_interval(w, items, None)
         },
         None => writeln!(w, "No advice map entry for key {key_str} before step {clk}."),
     }
@@ -12,7 +12,7 @@ extern crate alloc;
 
 use alloc::{sync::Arc, vec, vec::Vec};
 
-use miden_core::{events::EventName, mast::MastForest};
+use miden_core::{Word, deferred::PrecompileRegistry, events::EventName, mast::MastForest};
 use miden_mast_package::Package;
 use miden_processor::{HostLibrary, event::EventHandler};
 use miden_utils_sync::LazyLock;
@@ -107,6 +107,14 @@ impl From<&CoreLibrary> for HostLibrary {
     }
 }
 
+impl From<&CoreLibrary> for PrecompileRegistry {
+    /// Returns a registry containing the deferred precompiles wrapped by the core library, i.e.
+    /// the registry needed to hydrate the deferred state of programs using those wrappers.
+    fn from(_core_lib: &CoreLibrary) -> Self {
+        miden_precompiles::registry()
+    }
+}
+
 impl CoreLibrary {
     /// Serialized representation of the Miden `core` package.
     pub const SERIALIZED: &'static [u8] =
@@ -122,6 +130,15 @@ impl CoreLibrary {
         self.0.clone()
     }
 
+    /// Returns the MAST root digest of the procedure exported from the core library at the
+    /// specified fully-qualified path (e.g. `miden::core::math::u64::div`), or `None` if no such
+    /// procedure is exported.
+    ///
+    /// This allows hosts to reference specific core library procedures by digest.
+    pub fn digest_of(&self, path: &str) -> Option<Word> {
+        self.0.get_procedure_root_by_path(path)
+    }
+
     /// Returns the default event handlers required by the core library.
     ///
     /// Stack and memory print-style debug handlers write to stdout by default. These handlers can
@@ -191,4 +208,23 @@ mod tests {
 
         assert!(exists);
     }
+
+    #[test]
+    fn digest_of_matches_exported_procedure() {
+        let core_lib = CoreLibrary::default();
+        let path = "miden::core::math::u64::div";
+
+        let expected = core_lib
+            .0
+            .manifest
+            .exports()
+            .filter_map(|export| export.as_procedure())
+            .find(|export| export.path.as_ref() == "::miden::core::math::u64::div")
+            .map(|export| export.digest)
+            .expect("u64::div should be exported from the core library");
+
+        assert_eq!(core_lib.digest_of(path), Some(expected));
+        assert_eq!(core_lib.digest_of("::miden::core::math::u64::div"), Some(expected));
+        assert_eq!(core_lib.digest_of("miden::core::math::u64::does_not_exist"), None);
+    }
 }
@@ -305,6 +305,23 @@ fn falcon_execution() {
     test.expect_stack(&[])
 }
 
+#[cfg(feature = "std")]
+#[test]
+fn falcon_sign_round_trips_through_masm_verify() {
+    let seed = Word::default();
+    let mut rng = RandomCoin::new(seed);
+    let sk = SecretKey::with_rng(&mut rng);
+    let message = random_word();
+
+    assert!(verify_falcon_signature(&sk, message));
+
+    // flipping a single element of the signature must make the MASM verifier reject it
+    let mut signature = falcon512_poseidon2::sign(&sk, message).expect("failed to sign message");
+    let idx = signature.len() / 2;
+    signature[idx] += Felt::ONE;
+    assert!(!verify_falcon_advice(sk.public_key().to_commitment(), message, signature));
+}
+
 #[test]
 fn test_mod_12289_simple() {
     // Simple test to debug mod_12289 with a known input
@@ -628,6 +645,44 @@ fn generate_data_probabilistic_product_test(
     (operand_stack, advice_stack)
 }
 
+/// Signs `msg` with [`falcon512_poseidon2::sign`] and checks whether the resulting signature is
+/// accepted by `falcon512_poseidon2::verify` when executed by the VM.
+#[cfg(feature = "std")]
+fn verify_falcon_signature(sk: &SecretKey, msg: Word) -> bool {
+    let signature = falcon512_poseidon2::sign(sk, msg).expect("failed to sign message");
+    verify_falcon_advice(sk.public_key().to_commitment(), msg, signature)
+}
+
+/// Executes `falcon512_poseidon2::verify` against `[PK, MSG]` with `signature` on the advice stack,
+/// returning whether execution succeeded.
+#[cfg(feature = "std")]
+fn verify_falcon_advice(pk: Word, msg: Word, signature: Vec<Felt>) -> bool {
+    let source = "
+    use miden::core::crypto::dsa::falcon512_poseidon2
+
+    begin
+        exec.falcon512_poseidon2::verify
+    end
+    ";
+
+    let program: Program = Assembler::default()
+        .with_package(CoreLibrary::default().package(), Linkage::Dynamic)
+        .expect("failed to load core library")
+        .assemble_program("program", source)
+        .expect("failed to compile test source")
+        .unwrap_program();
+
+    let stack_inputs = stack_inputs_from_ints(stack_from_words(&[pk, msg]));
+    let advice_inputs = AdviceInputs::default().with_stack(signature);
+    let mut host = DefaultHost::default();
+    host.load_library(&CoreLibrary::default()).expect("failed to load mast forest");
+
+    FastProcessor::new_with_options(stack_inputs, advice_inputs, Default::default())
+        .expect("processor advice inputs should fit advice map limits")
+        .execute_sync(&program, &mut host)
+        .is_ok()
+}
+
 /// Builds operand-stack inputs from words. The first word ends up on top of the stack.
 ///
 /// This matches `stack![]` semantics: `stack_from_words(&[A, B])` results in stack `[A, B, ...]`
@@ -16,7 +16,8 @@ use miden_core_lib::{
     handlers::debug::{
         DebugPrinter, PRINT_ADV_MAP_EVENT_NAME, PRINT_ADV_MAP_ITEM_EVENT_NAME,
         PRINT_ADV_STACK_EVENT_NAME, PRINT_MEM_ALL_EVENT_NAME, PRINT_MEM_EVENT_NAME,
-        PRINT_STACK_EVENT_NAME, advice_debug_handlers, debug_handlers, noop_debug_handlers,
+        PRINT_MEM_WORDS_EVENT_NAME, PRINT_STACK_EVENT_NAME, advice_debug_handlers, debug_handlers,
+        noop_debug_handlers,
     },
 };
 use miden_processor::{
@@ -47,6 +48,7 @@ fn debug_handlers_with_writer(writer: SharedBuf) -> Vec<(EventName, Arc<dyn Even
         (PRINT_STACK_EVENT_NAME, printer.clone()),
         (PRINT_MEM_EVENT_NAME, printer.clone()),
         (PRINT_MEM_ALL_EVENT_NAME, printer.clone()),
+        (PRINT_MEM_WORDS_EVENT_NAME, printer.clone()),
         (PRINT_ADV_STACK_EVENT_NAME, printer.clone()),
         (PRINT_ADV_MAP_EVENT_NAME, printer.clone()),
         (PRINT_ADV_MAP_ITEM_EVENT_NAME, printer),
@@ -226,6 +228,24 @@ fn print_mem_shows_uninitialized_cells_as_empty() {
     }
 }
 
+#[test]
+fn print_mem_words_outputs_region() {
+    let source = "
+    use miden::core::debug
+    begin
+        push.4.3.2.1 push.100 mem_storew_le dropw
+        push.8.7.6.5 push.104 mem_storew_le dropw
+        push.3 push.100   # [addr=100, num_words=3]
+        exec.debug::print_mem_words
+    end
+    ";
+    let out = run_and_capture(source, AdviceInputs::default());
+    assert!(out.contains("Memory words"), "missing header; got:\n{out}");
+    assert!(out.contains("0x00000064: [1, 2, 3, 4]"), "missing first word; got:\n{out}");
+    assert!(out.contains("0x00000068: [5, 6, 7, 8]"), "missing second word; got:\n{out}");
+    assert!(out.contains("0x0000006c: EMPTY"), "expected EMPTY word; got:\n{out}");
+}
+
 #[test]
 fn print_mem_all_outputs_memory() {
     let source = "
@@ -542,7 +562,12 @@ fn default_core_handlers_include_debug_printers() {
     let core_lib = CoreLibrary::default();
     let handlers = core_lib.handlers();
 
-    for debug_event in [PRINT_STACK_EVENT_NAME, PRINT_MEM_EVENT_NAME, PRINT_MEM_ALL_EVENT_NAME] {
+    for debug_event in [
+        PRINT_STACK_EVENT_NAME,
+        PRINT_MEM_EVENT_NAME,
+        PRINT_MEM_ALL_EVENT_NAME,
+        PRINT_MEM_WORDS_EVENT_NAME,
+    ] {
         assert!(
             handlers.iter().any(|(event, _)| event == &debug_event),
             "{debug_event:?} should be registered by default"
@@ -608,6 +633,7 @@ fn debug_handlers_include_all_core_debug_events() {
         PRINT_STACK_EVENT_NAME,
         PRINT_MEM_EVENT_NAME,
         PRINT_MEM_ALL_EVENT_NAME,
+        PRINT_MEM_WORDS_EVENT_NAME,
         PRINT_ADV_STACK_EVENT_NAME,
         PRINT_ADV_MAP_EVENT_NAME,
         PRINT_ADV_MAP_ITEM_EVENT_NAME,
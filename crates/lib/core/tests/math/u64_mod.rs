@@ -773,6 +773,21 @@ fn unchecked_div() {
     test.expect_stack(&[d0, d1]);
 }
 
+#[test]
+fn div_emits_single_u64_div_event() {
+    let source = "
+        use miden::core::math::u64
+        begin
+            exec.u64::div
+        end";
+
+    let (a1, a0) = split_u64(rand_value());
+    let (b1, b0) = split_u64(rand_value::<u64>() | 1);
+
+    let test = build_test!(source, &stack![b0, b1, a0, a1]);
+    test.expect_events(&[U64_DIV_EVENT_NAME]);
+}
+
 /// The `U64Div` event handler is susceptible to crashing the processor if we don't ensure that the
 /// divisor and dividend limbs are proper u32 values.
 #[test]
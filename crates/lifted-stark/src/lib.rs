@@ -135,6 +135,7 @@ pub mod air {
 pub mod hasher {
     pub use miden_stateful_hasher::{
         Alignable, ChainingHasher, SerializingStatefulSponge, StatefulHasher, StatefulSponge,
+        TruncatingHasher,
     };
 }
 
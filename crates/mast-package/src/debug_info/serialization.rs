@@ -19,7 +19,7 @@ use super::{
     DebugFunctionsSection, DebugPrimitiveType, DebugSourceAsmOp, DebugSourceGraphSection,
     DebugSourceInlineCall, DebugSourceMapSection, DebugSourceNode, DebugSourceNodeId,
     DebugSourceVar, DebugSourcesSection, DebugTypeIdx, DebugTypeInfo, DebugTypesSection,
-    DebugVariantInfo, PackageDebugInfo,
+    DebugVariantInfo, MIN_SUPPORTED_DEBUG_SOURCE_MAP_VERSION, PackageDebugInfo,
 };
 
 // PACKAGE DEBUG INFO SERIALIZATION
@@ -297,6 +297,10 @@ impl Serializable for DebugSourceAsmOp {
         self.context_name.write_into(target);
         self.op.write_into(target);
         target.write_u8(self.num_cycles);
+        target.write_usize(self.call_sites.len());
+        for call_site in self.call_sites.iter() {
+            write_required_location(call_site, target);
+        }
     }
 }
 
@@ -308,6 +312,7 @@ impl Deserializable for DebugSourceAsmOp {
         let context_name = String::read_from(source)?;
         let op = String::read_from(source)?;
         let num_cycles = source.read_u8()?;
+        let call_sites = read_call_sites(source, read_required_location)?;
         Ok(Self {
             source_node,
             op_idx,
@@ -315,6 +320,7 @@ impl Deserializable for DebugSourceAsmOp {
             context_name,
             op,
             num_cycles,
+            call_sites,
         })
     }
 
@@ -408,11 +414,13 @@ impl Serializable for DebugSourceMapSection {
 impl Deserializable for DebugSourceMapSection {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let version = source.read_u8()?;
-        if version != DEBUG_SOURCE_MAP_VERSION {
+        if !(MIN_SUPPORTED_DEBUG_SOURCE_MAP_VERSION..=DEBUG_SOURCE_MAP_VERSION).contains(&version) {
             return Err(DeserializationError::InvalidValue(alloc::format!(
-                "unsupported debug_source_map version: {version}, expected {DEBUG_SOURCE_MAP_VERSION}"
+                "unsupported debug_source_map version: {version}, expected \
+                 {MIN_SUPPORTED_DEBUG_SOURCE_MAP_VERSION} to {DEBUG_SOURCE_MAP_VERSION}"
             )));
         }
+        let has_call_sites = version >= 2;
 
         let locations_len = read_bounded_len(
             source,
@@ -433,11 +441,11 @@ impl Deserializable for DebugSourceMapSection {
         let asm_ops_len = read_bounded_len(
             source,
             "debug_source_map asm ops",
-            min_source_map_asm_op_row_serialized_size(),
+            min_source_map_asm_op_row_serialized_size(has_call_sites),
         )?;
         let mut asm_ops = Vec::with_capacity(asm_ops_len);
         for _ in 0..asm_ops_len {
-            asm_ops.push(read_source_asm_op(source, &locations, &strings)?);
+            asm_ops.push(read_source_asm_op(source, &locations, &strings, has_call_sites)?);
         }
 
         let debug_vars_len = read_bounded_len(source, "debug_source_map debug vars", 1)?;
@@ -458,53 +466,92 @@ fn write_source_asm_op<W: ByteWriter>(
     target.write_u32(asm_op.op_idx);
     if let Some(location) = asm_op.location.as_ref() {
         target.write_bool(true);
-        let location_idx = locations
-            .iter()
-            .position(|candidate| candidate == location)
-            .expect("debug source map location table should contain every row location");
-        target.write_u32(location_idx as u32);
+        target.write_u32(source_map_location_index(location, locations) as u32);
     } else {
         target.write_bool(false);
     }
     write_source_map_string_ref(&asm_op.context_name, strings, target);
     write_source_map_string_ref(&asm_op.op, strings, target);
     target.write_u8(asm_op.num_cycles);
+    target.write_usize(asm_op.call_sites.len());
+    for call_site in asm_op.call_sites.iter() {
+        target.write_u32(source_map_location_index(call_site, locations) as u32);
+    }
 }
 
+/// Reads an assembly operation row of a debug_source_map section.
+///
+/// Rows of version 1 sections do not record call sites, which are read as empty.
 fn read_source_asm_op<R: ByteReader>(
     source: &mut R,
     locations: &[Location],
     strings: &[String],
+    has_call_sites: bool,
 ) -> Result<DebugSourceAsmOp, DeserializationError> {
     let source_node = DebugSourceNodeId::read_from(source)?;
     let op_idx = source.read_u32()?;
     let location = if source.read_bool()? {
-        let location_idx = source.read_u32()? as usize;
-        Some(locations.get(location_idx).cloned().ok_or_else(|| {
-            DeserializationError::InvalidValue(alloc::format!(
-                "debug source asm op location index {location_idx} out of bounds for {} locations",
-                locations.len()
-            ))
-        })?)
+        Some(read_source_map_location_ref(source, locations)?)
     } else {
         None
     };
     let context_name = read_source_map_string_ref(source, strings)?;
     let op = read_source_map_string_ref(source, strings)?;
     let num_cycles = source.read_u8()?;
-    Ok(DebugSourceAsmOp::new(
-        source_node,
-        op_idx,
-        location,
-        context_name,
-        op,
-        num_cycles,
-    ))
+    let call_sites = if has_call_sites {
+        read_call_sites(source, |source| read_source_map_location_ref(source, locations))?
+    } else {
+        Vec::new()
+    };
+    Ok(
+        DebugSourceAsmOp::new(source_node, op_idx, location, context_name, op, num_cycles)
+            .with_call_sites(call_sites),
+    )
+}
+
+fn min_source_map_asm_op_row_serialized_size(has_call_sites: bool) -> usize {
+    // The location index is conditional and is omitted for location-less rows, and the call-site
+    // list is only its length prefix for rows without call sites.
+    DebugSourceNodeId::min_serialized_size() + 4 + 1 + 4 + 4 + 1 + usize::from(has_call_sites)
+}
+
+/// The smallest encoding of a call site, i.e. an index into the location table of a
+/// debug_source_map section.
+const MIN_CALL_SITE_SERIALIZED_SIZE: usize = 4;
+
+/// Reads the length-prefixed call sites of an assembly operation row, using `read_call_site` to
+/// read each of them.
+fn read_call_sites<R: ByteReader>(
+    source: &mut R,
+    mut read_call_site: impl FnMut(&mut R) -> Result<Location, DeserializationError>,
+) -> Result<Vec<Location>, DeserializationError> {
+    let call_sites_len =
+        read_bounded_len(source, "debug source asm op call sites", MIN_CALL_SITE_SERIALIZED_SIZE)?;
+    let mut call_sites = Vec::with_capacity(call_sites_len);
+    for _ in 0..call_sites_len {
+        call_sites.push(read_call_site(source)?);
+    }
+    Ok(call_sites)
+}
+
+fn source_map_location_index(location: &Location, locations: &[Location]) -> usize {
+    locations
+        .iter()
+        .position(|candidate| candidate == location)
+        .expect("debug source map location table should contain every row location")
 }
 
-fn min_source_map_asm_op_row_serialized_size() -> usize {
-    // The location index is conditional and is omitted for location-less rows.
-    DebugSourceNodeId::min_serialized_size() + 4 + 1 + 4 + 4 + 1
+fn read_source_map_location_ref<R: ByteReader>(
+    source: &mut R,
+    locations: &[Location],
+) -> Result<Location, DeserializationError> {
+    let location_idx = source.read_u32()? as usize;
+    locations.get(location_idx).cloned().ok_or_else(|| {
+        DeserializationError::InvalidValue(alloc::format!(
+            "debug source asm op location index {location_idx} out of bounds for {} locations",
+            locations.len()
+        ))
+    })
 }
 
 fn write_source_map_string_ref<W: ByteWriter>(string: &String, strings: &[String], target: &mut W) {
@@ -1185,6 +1232,8 @@ mod tests {
         let source_node = DebugSourceNodeId::from(0);
         let location =
             Location::new(Uri::new("file://test.masm"), ByteIndex::new(10), ByteIndex::new(14));
+        let call_site =
+            Location::new(Uri::new("file://test.masm"), ByteIndex::new(20), ByteIndex::new(30));
         let section = DebugSourceMapSection::from_parts(
             alloc::vec![
                 DebugSourceAsmOp::new(
@@ -1202,12 +1251,13 @@ mod tests {
                     "test::ctx".into(),
                     "add".into(),
                     1,
-                ),
+                )
+                .with_call_sites(alloc::vec![call_site.clone(), location.clone()]),
             ],
             alloc::vec![],
         );
 
-        assert_eq!(section.locations(), &[location]);
+        assert_eq!(section.locations(), &[location, call_site]);
 
         let bytes = section.to_bytes();
         let deserialized = DebugSourceMapSection::read_from_bytes(&bytes).unwrap();
@@ -1215,6 +1265,46 @@ mod tests {
         assert_eq!(deserialized.asm_ops(), section.asm_ops());
     }
 
+    #[test]
+    fn test_debug_source_map_version_1_is_read_without_call_sites() {
+        let source_node = DebugSourceNodeId::from(0);
+        let location =
+            Location::new(Uri::new("file://test.masm"), ByteIndex::new(10), ByteIndex::new(14));
+
+        // a version 1 section, whose asm op rows end with the number of cycles
+        let mut bytes = Vec::new();
+        bytes.write_u8(1);
+        bytes.write_usize(1);
+        write_required_location(&location, &mut bytes);
+        bytes.write_usize(2);
+        String::from("test::ctx").write_into(&mut bytes);
+        String::from("add").write_into(&mut bytes);
+        bytes.write_usize(1);
+        source_node.write_into(&mut bytes);
+        bytes.write_u32(0);
+        bytes.write_bool(true);
+        bytes.write_u32(0);
+        bytes.write_u32(0);
+        bytes.write_u32(1);
+        bytes.write_u8(1);
+        bytes.write_usize(0);
+        bytes.write_usize(0);
+
+        let section = DebugSourceMapSection::read_from_bytes(&bytes).unwrap();
+        assert_eq!(section.version(), DEBUG_SOURCE_MAP_VERSION);
+        assert_eq!(
+            section.asm_ops(),
+            &[DebugSourceAsmOp::new(
+                source_node,
+                0,
+                Some(location),
+                "test::ctx".into(),
+                "add".into(),
+                1,
+            )]
+        );
+    }
+
     #[test]
     fn test_debug_source_map_strings_are_deduplicated() {
         let source_node = DebugSourceNodeId::from(0);
@@ -15,7 +15,14 @@
 //! Debuggers can use this information along with MAST debug metadata to provide source-level
 //! variable inspection, stepping, and call stack visualization.
 
-use alloc::{boxed::Box, collections::BTreeMap, string::String, sync::Arc, vec::Vec};
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
 
 use miden_core::{
     Word,
@@ -306,7 +313,13 @@ pub const DEBUG_FUNCTIONS_VERSION: u8 = 2;
 /// The version of the debug_source_graph section format.
 pub const DEBUG_SOURCE_GRAPH_VERSION: u8 = 1;
 /// The version of the debug_source_map section format.
-pub const DEBUG_SOURCE_MAP_VERSION: u8 = 1;
+///
+/// Version 2 adds the call-site locations of assembly operation rows.
+pub const DEBUG_SOURCE_MAP_VERSION: u8 = 2;
+/// The oldest version of the debug_source_map section format which can still be read.
+///
+/// Assembly operation rows of version 1 sections are read without call sites.
+pub const MIN_SUPPORTED_DEBUG_SOURCE_MAP_VERSION: u8 = 1;
 /// The version of the debug_error_messages section format.
 pub const DEBUG_ERROR_MESSAGES_VERSION: u8 = 1;
 
@@ -582,6 +595,7 @@ impl PackageDebugInfo {
                     context_name: row.context_name.clone(),
                     op: row.op.clone(),
                     num_cycles: row.num_cycles,
+                    call_sites: row.call_sites.clone(),
                 });
             }
             for row in source_map.debug_vars() {
@@ -704,6 +718,17 @@ impl PackageDebugInfo {
         self.source_map.as_ref()?.asm_op_for_operation(source_node, op_idx)
     }
 
+    /// Returns the provenance of the operation at `op_idx` of `source_node`, if known.
+    ///
+    /// This uses the same lookup as [`Self::asm_op_for_operation`].
+    pub fn asm_op_info(
+        &self,
+        source_node: DebugSourceNodeId,
+        op_idx: u32,
+    ) -> Option<AsmOpInfo<'_>> {
+        self.asm_op_for_operation(source_node, op_idx).map(AsmOpInfo::new)
+    }
+
     /// Returns debug variable rows for a source/debug occurrence.
     pub fn debug_vars_for_source_node(
         &self,
@@ -746,6 +771,53 @@ impl PackageDebugInfo {
             .flat_map(move |source_map| source_map.inline_calls_for_operation(source_node, op_idx))
     }
 
+    /// Returns human-readable descriptions of all debug metadata rows attached to `source_node`,
+    /// ordered by operation index.
+    ///
+    /// Assembly operations, debug variables, and inline calls at the same operation index are
+    /// listed in that order. Function names and file paths of inline calls are resolved through
+    /// the functions and sources sections when present.
+    pub fn describe_source_node(&self, source_node: DebugSourceNodeId) -> Vec<DebugRowDescription> {
+        let mut rows = Vec::new();
+        rows.extend(self.asm_ops_for_source_node(source_node).map(|row| {
+            let mut description =
+                format!("asmop {} in {} ({} cycles)", row.op, row.context_name, row.num_cycles);
+            if let Some(location) = &row.location {
+                description.push_str(&format!(
+                    " at {}:{}..{}",
+                    location.uri, location.start, location.end
+                ));
+            }
+            DebugRowDescription::new(row.op_idx, DebugRowKind::AsmOp, description)
+        }));
+        rows.extend(self.debug_vars_for_source_node(source_node).map(|row| {
+            DebugRowDescription::new(row.op_idx, DebugRowKind::DebugVar, row.var.to_string())
+        }));
+        rows.extend(self.inline_calls_for_source_node(source_node).map(|row| {
+            let callee = self
+                .functions
+                .as_ref()
+                .and_then(|functions| {
+                    let function = functions.functions.get(row.callee_idx as usize)?;
+                    functions.get_string(function.name_idx)
+                })
+                .map(|name| name.to_string())
+                .unwrap_or_else(|| format!("#{}", row.callee_idx));
+            let file = self
+                .sources
+                .as_ref()
+                .and_then(|sources| sources.get_string(sources.get_file(row.file_idx)?.path_idx))
+                .map(|path| path.to_string())
+                .unwrap_or_else(|| format!("#{}", row.file_idx));
+            let description =
+                format!("inline call to {callee} at {file}:{}:{}", row.line, row.column);
+            DebugRowDescription::new(row.op_idx, DebugRowKind::InlineCall, description)
+        }));
+        // the sort is stable, so rows at the same operation index keep their kind order
+        rows.sort_by_key(|row| row.op_idx);
+        rows
+    }
+
     /// Returns the assertion error message for `err_code`, if present.
     pub fn error_message(&self, err_code: u64) -> Option<Arc<str>> {
         self.error_messages.as_ref()?.message(err_code)
@@ -1203,6 +1275,9 @@ pub struct DebugSourceAsmOp {
     pub op: String,
     /// Number of VM cycles taken by the operation.
     pub num_cycles: u8,
+    /// Locations of the `exec` instructions through which the operation was merged into the code
+    /// of its callers, innermost first.
+    pub call_sites: Vec<Location>,
 }
 
 impl DebugSourceAsmOp {
@@ -1222,8 +1297,51 @@ impl DebugSourceAsmOp {
             context_name,
             op,
             num_cycles,
+            call_sites: Vec::new(),
         }
     }
+
+    /// Sets the call-site locations of this row, innermost first.
+    pub fn with_call_sites(mut self, call_sites: Vec<Location>) -> Self {
+        self.call_sites = call_sites;
+        self
+    }
+}
+
+/// The provenance of an executed operation, as recorded by its assembly operation row.
+///
+/// When the assembler merges the code of a procedure into the basic block of its caller, the
+/// operations of that procedure keep the location of the instruction they were assembled from, and
+/// record the locations of the `exec` instructions they were merged through as call sites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AsmOpInfo<'a> {
+    asm_op: &'a DebugSourceAsmOp,
+}
+
+impl<'a> AsmOpInfo<'a> {
+    /// Creates the provenance of the operations covered by `asm_op`.
+    pub fn new(asm_op: &'a DebugSourceAsmOp) -> Self {
+        Self { asm_op }
+    }
+
+    /// Returns the assembly operation row this provenance was derived from.
+    pub fn asm_op(&self) -> &'a DebugSourceAsmOp {
+        self.asm_op
+    }
+
+    /// Returns the most precise known source location of the operation.
+    ///
+    /// This is the location of the assembly instruction itself if known, and otherwise the
+    /// innermost call site through which it was merged.
+    pub fn location(&self) -> Option<&'a Location> {
+        self.asm_op.location.as_ref().or_else(|| self.asm_op.call_sites.first())
+    }
+
+    /// Returns the chain of source locations of the operation, starting with the location of the
+    /// assembly instruction itself (if known), followed by its call sites, innermost first.
+    pub fn span_chain(&self) -> impl Iterator<Item = &'a Location> {
+        self.asm_op.location.iter().chain(self.asm_op.call_sites.iter())
+    }
 }
 
 /// Debug variable metadata keyed by a source/debug MAST occurrence.
@@ -1282,6 +1400,43 @@ impl DebugSourceInlineCall {
     }
 }
 
+/// The kind of debug metadata row described by a [`DebugRowDescription`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugRowKind {
+    /// An assembly operation row.
+    AsmOp,
+    /// A debug variable row.
+    DebugVar,
+    /// An inline-call row.
+    InlineCall,
+}
+
+/// Human-readable description of a debug metadata row attached to a source/debug occurrence.
+///
+/// Returned by [`PackageDebugInfo::describe_source_node`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugRowDescription {
+    /// Operation index local to the reduced execution node.
+    pub op_idx: u32,
+    /// Kind of the described row.
+    pub kind: DebugRowKind,
+    /// Rendered description of the row.
+    pub description: String,
+}
+
+impl DebugRowDescription {
+    /// Creates a description of a debug metadata row.
+    pub fn new(op_idx: u32, kind: DebugRowKind, description: String) -> Self {
+        Self { op_idx, kind, description }
+    }
+}
+
+impl core::fmt::Display for DebugRowDescription {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {}", self.op_idx, self.description)
+    }
+}
+
 /// Package-owned source-keyed debug metadata rows.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct DebugSourceMapSection {
@@ -1437,7 +1592,7 @@ impl DebugSourceMapSection {
 fn intern_locations(asm_ops: &[DebugSourceAsmOp]) -> Vec<Location> {
     let mut locations = Vec::new();
     let mut by_location = BTreeMap::new();
-    for location in asm_ops.iter().filter_map(|row| row.location.as_ref()) {
+    for location in asm_ops.iter().flat_map(|row| row.location.iter().chain(&row.call_sites)) {
         by_location.entry(location.clone()).or_insert_with(|| {
             let idx = locations.len();
             locations.push(location.clone());
@@ -1851,6 +2006,78 @@ mod tests {
         assert!(source_map.inline_calls_for_operation(source_a, 4).next().is_none());
     }
 
+    #[test]
+    fn test_describe_source_node_lists_rows_by_operation() {
+        use miden_core::operations::DebugVarLocation;
+
+        let source_node = DebugSourceNodeId::from(0);
+        let other_node = DebugSourceNodeId::from(1);
+        let asm_op = DebugSourceAsmOp::new(
+            source_node,
+            1,
+            None,
+            String::from("$exec::main"),
+            String::from("u32wrapping_add"),
+            1,
+        );
+        let debug_var =
+            DebugSourceVar::new(source_node, 0, DebugVarInfo::new("x", DebugVarLocation::Stack(0)));
+        let other_asm_op = DebugSourceAsmOp::new(
+            other_node,
+            0,
+            None,
+            String::from("$exec::main"),
+            String::from("drop"),
+            1,
+        );
+        let inline_call = DebugSourceInlineCall::new(
+            source_node,
+            1,
+            0,
+            0,
+            LineNumber::new(10).unwrap(),
+            ColumnNumber::new(4).unwrap(),
+        );
+
+        let mut functions = DebugFunctionsSection::new();
+        let name_idx = functions.add_string(Arc::from("helper"));
+        functions.add_function(DebugFunctionInfo::new(
+            name_idx,
+            0,
+            LineNumber::new(1).unwrap(),
+            ColumnNumber::new(1).unwrap(),
+        ));
+        let mut sources = DebugSourcesSection::new();
+        let path_idx = sources.add_string(Arc::from("lib.masm"));
+        sources.add_file(DebugFileInfo::new(path_idx));
+
+        let debug_info = PackageDebugInfo {
+            functions: Some(functions),
+            sources: Some(sources),
+            ..PackageDebugInfo::default()
+        }
+        .with_source_map(DebugSourceMapSection::from_parts_with_inline_calls(
+            alloc::vec![other_asm_op, asm_op],
+            alloc::vec![debug_var],
+            alloc::vec![inline_call],
+        ));
+
+        let descriptions = debug_info.describe_source_node(source_node);
+        assert_eq!(
+            descriptions.iter().map(|row| (row.op_idx, row.kind)).collect::<Vec<_>>(),
+            alloc::vec![
+                (0, DebugRowKind::DebugVar),
+                (1, DebugRowKind::AsmOp),
+                (1, DebugRowKind::InlineCall),
+            ],
+        );
+        assert_eq!(descriptions[0].to_string(), "0: var.x = stack[0]");
+        assert_eq!(descriptions[1].description, "asmop u32wrapping_add in $exec::main (1 cycles)");
+        assert_eq!(descriptions[2].description, "inline call to helper at lib.masm:10:4");
+
+        assert!(PackageDebugInfo::default().describe_source_node(source_node).is_empty());
+    }
+
     #[test]
     fn test_package_source_debug_merge_remaps_execution_nodes_without_collapsing_sources() {
         use miden_core::{
@@ -0,0 +1,184 @@
+//! The serialization format of [`ErrorTable`] is as follows:
+//!
+//! - `MAGIC_ERROR_TABLE`, a 4-byte tag, followed by a NUL-byte, i.e. `b"\0"`
+//! - `ERROR_TABLE_VERSION`, a single byte
+//! - `entries`, the number of entries, followed by each entry as its error code, message and
+//!   optional module path
+
+use alloc::{collections::BTreeMap, format, string::String, sync::Arc};
+
+use miden_core::{
+    Felt,
+    serde::{
+        ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+        read_bounded_len,
+    },
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Magic string for detecting that a file is a serialized [`ErrorTable`]
+const MAGIC_ERROR_TABLE: &[u8; 5] = b"MAER\0";
+
+/// The format version of serialized [`ErrorTable`]s.
+pub const ERROR_TABLE_VERSION: u8 = 1;
+
+// ERROR TABLE
+// ================================================================================================
+
+/// A standalone map from the error codes raised by assertions of an assembled program to the
+/// messages they were assembled from.
+///
+/// Error codes are derived from the hash of their message, so an assembled program only carries
+/// the codes of its assertions. The error table is produced by the assembler alongside the
+/// program, so that the original messages can be recovered when the program is executed without
+/// its debug information, e.g. by loading the table into the host running it.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ErrorTable {
+    entries: BTreeMap<u64, ErrorTableEntry>,
+}
+
+/// The source of an error code in an [`ErrorTable`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorTableEntry {
+    /// The message the error code was derived from.
+    pub message: Arc<str>,
+    /// The path of the module which raises the error, if known.
+    pub module: Option<Arc<str>>,
+}
+
+impl ErrorTable {
+    /// Returns true if this table has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the number of error codes in this table.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Records that `err_code` was derived from `message`, raised in `module` if known.
+    ///
+    /// If `err_code` is already present, its message is kept, and its module is only set if it
+    /// was previously unknown.
+    pub fn insert(&mut self, err_code: u64, message: Arc<str>, module: Option<Arc<str>>) {
+        let entry = self
+            .entries
+            .entry(err_code)
+            .or_insert(ErrorTableEntry { message, module: None });
+        if entry.module.is_none() {
+            entry.module = module;
+        }
+    }
+
+    /// Returns the entry of `err_code`, if present.
+    pub fn get(&self, err_code: Felt) -> Option<&ErrorTableEntry> {
+        self.entries.get(&err_code.as_canonical_u64())
+    }
+
+    /// Returns the message `err_code` was derived from, if present.
+    pub fn message(&self, err_code: Felt) -> Option<Arc<str>> {
+        self.get(err_code).map(|entry| entry.message.clone())
+    }
+
+    /// Returns an iterator over the error codes in this table and their entries, in ascending
+    /// order of error code.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &ErrorTableEntry)> {
+        self.entries.iter().map(|(err_code, entry)| (*err_code, entry))
+    }
+
+    /// Adds the entries of `other` to this table; see [`Self::insert`].
+    pub fn merge(&mut self, other: ErrorTable) {
+        for (err_code, ErrorTableEntry { message, module }) in other.entries {
+            self.insert(err_code, message, module);
+        }
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ErrorTable {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(MAGIC_ERROR_TABLE);
+        target.write_u8(ERROR_TABLE_VERSION);
+        target.write_usize(self.entries.len());
+        for (err_code, entry) in self.entries.iter() {
+            target.write_u64(*err_code);
+            entry.message.as_ref().write_into(target);
+            target.write_bool(entry.module.is_some());
+            if let Some(module) = entry.module.as_ref() {
+                module.as_ref().write_into(target);
+            }
+        }
+    }
+}
+
+impl Deserializable for ErrorTable {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let magic: [u8; 5] = source.read_array()?;
+        if magic != *MAGIC_ERROR_TABLE {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid magic bytes. Expected '{MAGIC_ERROR_TABLE:?}', got '{magic:?}'"
+            )));
+        }
+        let version = source.read_u8()?;
+        if version != ERROR_TABLE_VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported error table version: {version}, expected {ERROR_TABLE_VERSION}"
+            )));
+        }
+
+        let num_entries = read_bounded_len(source, "error table entries", 10)?;
+        let mut entries = BTreeMap::new();
+        for _ in 0..num_entries {
+            let err_code = source.read_u64()?;
+            let message = Arc::from(String::read_from(source)?);
+            let module = if source.read_bool()? {
+                Some(Arc::from(String::read_from(source)?))
+            } else {
+                None
+            };
+            if entries.insert(err_code, ErrorTableEntry { message, module }).is_some() {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "duplicate error code in error table: {err_code}"
+                )));
+            }
+        }
+
+        Ok(Self { entries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miden_core::{mast::error_code_from_msg, serde::SliceReader};
+
+    use super::*;
+
+    #[test]
+    fn error_table_serialization_roundtrip_and_lookup() {
+        let err_code = error_code_from_msg("value is too large");
+        let mut table = ErrorTable::default();
+        table.insert(err_code.as_canonical_u64(), "value is too large".into(), None);
+        table.insert(
+            err_code.as_canonical_u64(),
+            "value is too large".into(),
+            Some("lib::math".into()),
+        );
+        table.insert(7, "unattributed".into(), None);
+
+        let bytes = table.to_bytes();
+        let decoded = ErrorTable::read_from(&mut SliceReader::new(&bytes)).unwrap();
+        assert_eq!(decoded, table);
+        assert_eq!(decoded.len(), 2);
+
+        let entry = decoded.get(err_code).expect("missing error code");
+        assert_eq!(entry.message.as_ref(), "value is too large");
+        assert_eq!(entry.module.as_deref(), Some("lib::math"));
+        assert_eq!(decoded.message(Felt::from_u32(7)).as_deref(), Some("unattributed"));
+        assert!(decoded.get(Felt::from_u32(8)).is_none());
+    }
+}
@@ -9,7 +9,9 @@ extern crate std;
 
 pub mod debug_info;
 mod dependency;
+mod error_table;
 mod package;
+mod source_map;
 
 pub use miden_assembly_syntax::{
     PathBuf, Version, VersionError,
@@ -21,10 +23,12 @@ pub use miden_core::{Word, mast::MastForest, program::Program};
 pub use self::package::arbitrary;
 pub use self::{
     dependency::Dependency,
+    error_table::{ERROR_TABLE_VERSION, ErrorTable, ErrorTableEntry},
     package::{
-        ConstantExport, InvalidSectionIdError, InvalidTargetTypeError, ManifestValidationError,
-        Package, PackageDebugInfoError, PackageExport, PackageId, PackageManifest, PackageModule,
-        PackageStripError, PackageSubmodule, ProcedureExport, Section, SectionId, TargetType,
-        TypeExport,
+        ConstantExport, ForestInterner, InvalidSectionIdError, InvalidTargetTypeError,
+        ManifestValidationError, Package, PackageDebugInfoError, PackageExport, PackageId,
+        PackageManifest, PackageModule, PackageStripError, PackageSubmodule, ProcedureExport,
+        Section, SectionId, TargetType, TypeExport,
     },
+    source_map::{SOURCE_MAP_VERSION, SourceMap},
 };
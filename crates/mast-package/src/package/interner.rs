@@ -0,0 +1,65 @@
+use alloc::{collections::BTreeMap, sync::Arc};
+
+use miden_core::{
+    crypto::hash::Blake3_256,
+    mast::MastForest,
+    serde::{Deserializable, DeserializationError, Serializable},
+};
+
+use super::Package;
+
+/// Deduplicates [`MastForest`]s shared by multiple loaded packages.
+///
+/// Hosts which load many packages frequently end up with several copies of the same forest, e.g.
+/// when the same library package is loaded once per dependent. Loading packages through
+/// [`Package::load_interned`] makes every package with identical MAST content share a single
+/// [`Arc<MastForest>`].
+///
+/// Forests are identified by a hash of their serialized form rather than by
+/// [`MastForest::commitment`], since the commitment does not bind debug information, and two
+/// forests which only differ in their debug information must not be merged.
+#[derive(Debug, Default)]
+pub struct ForestInterner {
+    forests: BTreeMap<[u8; 32], Arc<MastForest>>,
+}
+
+impl ForestInterner {
+    /// Creates an empty interner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the canonical instance of `forest`.
+    ///
+    /// If an identical forest was interned previously, the previously interned instance is
+    /// returned and `forest` is dropped; otherwise `forest` becomes the canonical instance.
+    pub fn intern(&mut self, forest: Arc<MastForest>) -> Arc<MastForest> {
+        let key = *Blake3_256::hash(&forest.to_bytes()).as_bytes();
+        Arc::clone(self.forests.entry(key).or_insert(forest))
+    }
+
+    /// Returns the number of distinct forests held by this interner.
+    pub fn len(&self) -> usize {
+        self.forests.len()
+    }
+
+    /// Returns true if no forests have been interned yet.
+    pub fn is_empty(&self) -> bool {
+        self.forests.is_empty()
+    }
+}
+
+impl Package {
+    /// Deserializes a package from `bytes`, sharing its [`MastForest`] with any identical forest
+    /// previously loaded through `interner`.
+    ///
+    /// The package is read with the untrusted reader, see [`Package::read_from_bytes`].
+    pub fn load_interned(
+        bytes: &[u8],
+        interner: &mut ForestInterner,
+    ) -> Result<Self, DeserializationError> {
+        let mut package = Self::read_from_bytes(bytes)?;
+        package.mast = interner.intern(package.mast);
+        Ok(package)
+    }
+}
@@ -6,9 +6,12 @@ use alloc::{
 };
 use core::fmt;
 
-use miden_assembly_syntax::ast::{
-    self, AttributeSet, Path,
-    types::{FunctionType, Type},
+use miden_assembly_syntax::{
+    ParsingError,
+    ast::{
+        self, AttributeSet, Path,
+        types::{FunctionType, Type},
+    },
 };
 #[cfg(all(feature = "arbitrary", test))]
 use miden_core::serde::{Deserializable, Serializable};
@@ -580,6 +583,15 @@ impl ProcedureExport {
         self.source_node = source_node;
         self
     }
+
+    /// Returns the interface declared by the `@abi` and `@emits` attributes of this procedure, if
+    /// any.
+    ///
+    /// Returns an error if the attributes are malformed, which can only be the case for exports
+    /// that were not produced by the assembler.
+    pub fn abi(&self) -> Result<Option<ast::ProcedureAbi>, ParsingError> {
+        ast::ProcedureAbi::from_attributes(&self.attributes)
+    }
 }
 
 impl fmt::Debug for ProcedureExport {
@@ -2,6 +2,7 @@
 pub mod arbitrary;
 mod error;
 mod id;
+mod interner;
 mod manifest;
 mod section;
 #[cfg(test)]
@@ -22,7 +23,9 @@ use alloc::{
 use miden_assembly_syntax::{
     Path, Report,
     ast::{self, QualifiedProcedureName},
+    docs::{ConstantDoc, DocModel, ModuleDoc, ProcedureDoc},
     module::ModuleDescriptor,
+    prettier::PrettyPrint,
 };
 #[cfg(feature = "std")]
 use miden_core::serde::DeserializationError;
@@ -38,6 +41,7 @@ use miden_core::{
 pub use self::{
     error::{PackageDebugInfoError, PackageStripError},
     id::PackageId,
+    interner::ForestInterner,
     manifest::{
         ConstantExport, ManifestValidationError, PackageExport, PackageManifest, PackageModule,
         PackageSubmodule, ProcedureExport, TypeExport,
@@ -224,6 +228,23 @@ impl Package {
         self.strip_debug_info()?;
         Ok(self)
     }
+
+    /// Returns a copy of this package containing only what is needed to load and execute it.
+    ///
+    /// The MAST forest, manifest (exports, module surface, dependencies and entrypoint), and any
+    /// embedded kernel are preserved, so the result has the same [`Self::digest`] and executable
+    /// surface as `self`. Debug information (including that of the embedded kernel), the package
+    /// description, and all other sections are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the embedded kernel package cannot be decoded.
+    pub fn to_runtime_minimal(&self) -> Result<Self, PackageStripError> {
+        let mut package = self.clone().without_debug_info()?;
+        package.description = None;
+        package.sections.retain(|section| section.id == SectionId::KERNEL);
+        Ok(package)
+    }
 }
 
 /// Accessors
@@ -617,6 +638,64 @@ impl Package {
         Ok(modules_by_path.into_values().collect())
     }
 
+    /// Extracts the documentation model of the modules, procedures and constants exported by this
+    /// package.
+    ///
+    /// Doc comments are not retained in compiled packages, so the `docs` fields of the returned
+    /// model are always `None`; use [`DocModel::from_modules`] on the parsed sources to include
+    /// them. All procedures and constants in the model are public, as only exported items are
+    /// known.
+    pub fn doc_model(&self) -> DocModel {
+        let kind = if self.is_kernel() {
+            ast::ModuleKind::Kernel
+        } else {
+            ast::ModuleKind::Library
+        };
+        let new_module_doc = |path: Arc<Path>| ModuleDoc {
+            path,
+            kind,
+            docs: None,
+            procedures: Vec::new(),
+            constants: Vec::new(),
+        };
+
+        let mut modules_by_path: BTreeMap<Arc<Path>, ModuleDoc> = BTreeMap::new();
+        for module in self.manifest.modules() {
+            modules_by_path.insert(module.path.clone(), new_module_doc(module.path.clone()));
+        }
+
+        for export in self.manifest.exports() {
+            let module_name: Arc<Path> =
+                Arc::from(export.path().parent().unwrap().to_path_buf().into_boxed_path());
+            let module = modules_by_path
+                .entry(Arc::clone(&module_name))
+                .or_insert_with(|| new_module_doc(module_name));
+            match export {
+                PackageExport::Procedure(ProcedureExport { path, signature, .. }) => {
+                    module.procedures.push(ProcedureDoc {
+                        name: path.last().unwrap().to_string(),
+                        visibility: ast::Visibility::Public,
+                        signature: signature.as_ref().map(render_signature),
+                        docs: None,
+                    });
+                },
+                PackageExport::Constant(ConstantExport { path, value }) => {
+                    module.constants.push(ConstantDoc {
+                        name: path.last().unwrap().to_string(),
+                        visibility: ast::Visibility::Public,
+                        value: value.to_pretty_string(),
+                        docs: None,
+                    });
+                },
+                PackageExport::Type(_) => (),
+            }
+        }
+
+        DocModel {
+            modules: modules_by_path.into_values().collect(),
+        }
+    }
+
     fn read_debug_section<T>(&self, id: SectionId) -> Result<Option<T>, PackageDebugInfoError>
     where
         T: Deserializable,
@@ -1053,6 +1132,18 @@ impl Package {
     }
 }
 
+/// Renders `signature` the way procedure signatures are written in source, e.g.
+/// `(felt, felt) -> felt`.
+fn render_signature(signature: &ast::types::FunctionType) -> String {
+    let params = signature.params().iter().map(ToString::to_string).collect::<Vec<_>>();
+    let results = signature.results().iter().map(ToString::to_string).collect::<Vec<_>>();
+    match results.as_slice() {
+        [] => format!("({})", params.join(", ")),
+        [result] => format!("({}) -> {result}", params.join(", ")),
+        _ => format!("({}) -> ({})", params.join(", "), results.join(", ")),
+    }
+}
+
 fn read_section_payload<T>(id: &SectionId, bytes: &[u8]) -> Result<T, PackageDebugInfoError>
 where
     T: Deserializable,
@@ -2030,6 +2121,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn runtime_minimal_package_keeps_exports_and_is_smaller() {
+        let kernel =
+            build_debug_package("kernel", TargetType::Kernel, "kernel::boot", "kernel_ctx");
+        let mut package =
+            build_debug_package("app", TargetType::Executable, "app::entry", "app_ctx");
+        package.description = Some("an application with some metadata".into());
+        package.sections.extend(debug_sections());
+        package
+            .sections
+            .push(Section::new(SectionId::ACCOUNT_COMPONENT_METADATA, vec![1, 3, 5]));
+        package.sections.push(Section::new(SectionId::KERNEL, kernel.to_bytes()));
+
+        let minimal = package.to_runtime_minimal().expect("minimization should succeed");
+
+        assert_eq!(minimal.digest(), package.digest());
+        assert_eq!(minimal.manifest.exports, package.manifest.exports);
+        assert_eq!(minimal.manifest.dependencies, package.manifest.dependencies);
+        assert!(minimal.description.is_none());
+        assert!(minimal.debug_info().unwrap().is_none());
+        assert!(minimal.sections.iter().all(|section| section.id == SectionId::KERNEL));
+        assert!(minimal.embedded_kernel_package().unwrap().is_some());
+        assert!(minimal.to_bytes().len() < package.to_bytes().len());
+    }
+
+    #[test]
+    fn load_interned_shares_identical_forests() {
+        // Two copies of the same library, e.g. loaded once for each package depending on it
+        let dep = build_package("dep", TargetType::Library, "dep::helper", [], Vec::new());
+        let dep_bytes = dep.to_bytes();
+        let other = build_kernel_package("kernel");
+        let (split_mast, split_exports, ..) = build_split_package_exports("other::entry", None);
+        let unrelated = Package::create(
+            PackageId::from("unrelated"),
+            Version::new(1, 0, 0),
+            TargetType::Library,
+            split_mast,
+            split_exports,
+            None,
+        )
+        .unwrap();
+
+        let mut interner = ForestInterner::new();
+        assert!(interner.is_empty());
+        let first = Package::load_interned(&dep_bytes, &mut interner).unwrap();
+        let second = Package::load_interned(&dep_bytes, &mut interner).unwrap();
+        assert!(Arc::ptr_eq(first.mast_forest(), second.mast_forest()));
+        assert_eq!(interner.len(), 1);
+
+        // a different package with the same MAST content shares the forest too
+        let kernel = Package::load_interned(&other.to_bytes(), &mut interner).unwrap();
+        assert!(Arc::ptr_eq(first.mast_forest(), kernel.mast_forest()));
+        assert_eq!(interner.len(), 1);
+
+        let unrelated = Package::load_interned(&unrelated.to_bytes(), &mut interner).unwrap();
+        assert!(!Arc::ptr_eq(first.mast_forest(), unrelated.mast_forest()));
+        assert_eq!(interner.len(), 2);
+    }
+
     #[test]
     fn malformed_procedure_lookup_paths_are_not_exported() {
         let package = build_package("app", TargetType::Library, "app::entry", [], Vec::new());
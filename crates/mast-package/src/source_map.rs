@@ -0,0 +1,227 @@
+//! The serialization format of [`SourceMap`] is as follows:
+//!
+//! - `MAGIC_SOURCE_MAP`, a 4-byte tag, followed by a NUL-byte, i.e. `b"\0"`
+//! - `SOURCE_MAP_VERSION`, a single byte
+//! - `commitment`, the commitment of the [`MastForest`] described by the source map
+//! - `debug_info`, a [`PackageDebugInfo`] holding the source graph, source map and error messages
+//! - `file_line_cols`, the optional resolved position of each location in the source map
+
+use alloc::{format, vec::Vec};
+
+use miden_core::{
+    Word,
+    mast::{MastForest, MastNodeId},
+    serde::{
+        ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+        read_bounded_len,
+    },
+};
+use miden_debug_types::{FileLineCol, Location};
+
+use crate::debug_info::{
+    DebugErrorMessagesSection, DebugSourceGraphSection, DebugSourceMapSection, PackageDebugInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Magic string for detecting that a file is a serialized [`SourceMap`]
+const MAGIC_SOURCE_MAP: &[u8; 5] = b"MASR\0";
+
+/// The format version of serialized [`SourceMap`]s.
+pub const SOURCE_MAP_VERSION: u8 = 1;
+
+// SOURCE MAP
+// ================================================================================================
+
+/// A standalone map from the operations of an assembled [`MastForest`] to the source code they
+/// were assembled from.
+///
+/// Unlike the debug sections of a [`crate::Package`], a source map is a separate artifact: it is
+/// produced by the assembler regardless of whether debug information is emitted into the package,
+/// so that release artifacts can be shipped without source information, while errors raised by
+/// them can still be reported against the original source code.
+///
+/// Besides the source graph, assembly-op rows and error messages used by the processor for error
+/// reporting, a source map records the file, line and column of every source location it refers
+/// to, so that it can be queried without access to the source files themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceMap {
+    /// The commitment of the MAST forest described by this source map.
+    commitment: Word,
+    /// The source graph, source map and error message sections.
+    debug_info: PackageDebugInfo,
+    /// The resolved position of each location in `debug_info.source_map.locations()`, by index.
+    file_line_cols: Vec<Option<FileLineCol>>,
+}
+
+impl SourceMap {
+    /// Creates a source map for the MAST forest with the given `commitment`.
+    ///
+    /// `resolve` is used to compute the file, line and column of each source location referenced
+    /// by `source_map`. Locations which cannot be resolved are still available via
+    /// [`Self::debug_info`], but have no position.
+    pub fn new(
+        commitment: Word,
+        source_graph: DebugSourceGraphSection,
+        source_map: DebugSourceMapSection,
+        error_messages: DebugErrorMessagesSection,
+        mut resolve: impl FnMut(&Location) -> Option<FileLineCol>,
+    ) -> Self {
+        let file_line_cols = source_map.locations().iter().map(&mut resolve).collect();
+        let debug_info = PackageDebugInfo::with_source_debug(source_graph, source_map);
+        let debug_info = if error_messages.is_empty() {
+            debug_info
+        } else {
+            debug_info.with_error_messages(error_messages)
+        };
+
+        Self { commitment, debug_info, file_line_cols }
+    }
+
+    /// Returns the commitment of the MAST forest described by this source map.
+    pub fn commitment(&self) -> Word {
+        self.commitment
+    }
+
+    /// Returns true if this source map describes `mast_forest`.
+    pub fn describes(&self, mast_forest: &MastForest) -> bool {
+        self.commitment == mast_forest.commitment()
+    }
+
+    /// Returns the debug information in this source map, in the form consumed by the processor.
+    pub fn debug_info(&self) -> &PackageDebugInfo {
+        &self.debug_info
+    }
+
+    /// Consumes this source map, returning its debug information.
+    pub fn into_debug_info(self) -> PackageDebugInfo {
+        self.debug_info
+    }
+
+    /// Returns the source position of the operation at `op_idx` of the node `node_id`, if known.
+    ///
+    /// If `node_id` was assembled from more than one place in the source code, the position of the
+    /// first of them is returned.
+    pub fn file_line_col(&self, node_id: MastNodeId, op_idx: usize) -> Option<&FileLineCol> {
+        let source_graph = self.debug_info.source_graph()?;
+        let locations = self.debug_info.source_map()?.locations();
+        let op_idx = u32::try_from(op_idx).ok()?;
+
+        source_graph
+            .nodes()
+            .iter()
+            .enumerate()
+            .filter(|(_, source_node)| source_node.exec_node == node_id)
+            .find_map(|(source_index, _)| {
+                let asm_op =
+                    self.debug_info.asm_op_for_operation((source_index as u32).into(), op_idx)?;
+                let location = asm_op.location.as_ref()?;
+                let index = locations.iter().position(|candidate| candidate == location)?;
+                self.file_line_cols[index].as_ref()
+            })
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for SourceMap {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(MAGIC_SOURCE_MAP);
+        target.write_u8(SOURCE_MAP_VERSION);
+        self.commitment.write_into(target);
+        self.debug_info.write_into(target);
+        target.write_usize(self.file_line_cols.len());
+        for file_line_col in self.file_line_cols.iter() {
+            file_line_col.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for SourceMap {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let magic: [u8; 5] = source.read_array()?;
+        if magic != *MAGIC_SOURCE_MAP {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid magic bytes. Expected '{MAGIC_SOURCE_MAP:?}', got '{magic:?}'"
+            )));
+        }
+        let version = source.read_u8()?;
+        if version != SOURCE_MAP_VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported source map version: {version}, expected {SOURCE_MAP_VERSION}"
+            )));
+        }
+
+        let commitment = Word::read_from(source)?;
+        let debug_info = PackageDebugInfo::read_from(source)?;
+        let file_line_cols_len = read_bounded_len(source, "source map positions", 1)?;
+        let file_line_cols: Vec<Option<FileLineCol>> =
+            source.read_many_iter(file_line_cols_len)?.collect::<Result<_, _>>()?;
+
+        let num_locations =
+            debug_info.source_map().map_or(0, |source_map| source_map.locations().len());
+        if file_line_cols.len() != num_locations {
+            return Err(DeserializationError::InvalidValue(format!(
+                "source map has {} positions for {num_locations} locations",
+                file_line_cols.len()
+            )));
+        }
+
+        Ok(Self { commitment, debug_info, file_line_cols })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use miden_core::serde::SliceReader;
+    use miden_debug_types::{ByteIndex, ColumnNumber, LineNumber, Uri};
+
+    use super::*;
+    use crate::debug_info::{DebugErrorMessage, DebugSourceAsmOp, DebugSourceNode};
+
+    #[test]
+    fn source_map_serialization_roundtrip_and_lookup() {
+        let uri = Uri::from("test.masm");
+        let location = Location::new(uri.clone(), ByteIndex::new(10), ByteIndex::new(13));
+        let source_graph = DebugSourceGraphSection::from_parts(
+            vec![DebugSourceNode::new(MastNodeId::new_unchecked(0), Vec::new(), 0, 2)],
+            vec![0.into()],
+        );
+        let source_map = DebugSourceMapSection::from_parts(
+            vec![DebugSourceAsmOp::new(
+                0.into(),
+                1,
+                Some(location.clone()),
+                "test::main".into(),
+                "add".into(),
+                1,
+            )],
+            Vec::new(),
+        );
+        let error_messages =
+            DebugErrorMessagesSection::from_parts(vec![DebugErrorMessage::new(1, "oops".into())]);
+        let position =
+            FileLineCol::new(uri, LineNumber::new(2).unwrap(), ColumnNumber::new(5).unwrap());
+        let source_map = SourceMap::new(
+            Word::default(),
+            source_graph,
+            source_map,
+            error_messages,
+            |candidate| (candidate == &location).then(|| position.clone()),
+        );
+
+        let bytes = source_map.to_bytes();
+        let decoded = SourceMap::read_from(&mut SliceReader::new(&bytes)).unwrap();
+        assert_eq!(decoded, source_map);
+
+        let node_id = MastNodeId::new_unchecked(0);
+        assert_eq!(decoded.file_line_col(node_id, 0), None);
+        assert_eq!(decoded.file_line_col(node_id, 1), Some(&position));
+        assert_eq!(decoded.file_line_col(node_id, 2), Some(&position));
+        assert!(decoded.debug_info().error_messages().is_some());
+    }
+}
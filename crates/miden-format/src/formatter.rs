@@ -4,7 +4,7 @@ use miden_assembly_syntax_cst::{
     Item, Operation, SyntaxKind, SyntaxNode, SyntaxToken,
     ast::{
         BeginBlock, Block, DoWhileOp, IfOp, Import, ImportKind, ImportSpecifier, Instruction,
-        Procedure, RepeatOp, Signature, SourceFile, TypeBody, TypeDecl, WhileOp,
+        Macro, Procedure, RepeatOp, Signature, SourceFile, TypeBody, TypeDecl, WhileOp,
     },
     rowan::{NodeOrToken, ast::AstNode},
 };
@@ -199,8 +199,12 @@ fn render_item(item: &Item, indent: usize, config: &Config) -> String {
         Item::AdviceMap(advice_map) => {
             render_value_declaration(advice_map.syntax(), indent, config)
         },
+        Item::DataSegment(data_segment) => {
+            render_value_declaration(data_segment.syntax(), indent, config)
+        },
         Item::BeginBlock(begin) => render_begin_block(begin, indent, config),
         Item::Procedure(procedure) => render_procedure(procedure, indent, config),
+        Item::Macro(macro_decl) => render_macro(macro_decl, indent, config),
     }
 }
 
@@ -234,6 +238,7 @@ fn render_import(import: &Import, indent: usize, config: &Config) -> String {
     let mut lines = match import.kind() {
         ImportKind::Module => render_module_import_lines(import, header, path, indent, config),
         ImportKind::Items => render_item_import_lines(import, header, path, indent, config),
+        ImportKind::Glob => vec![format!("{header} {path}::*")],
     };
 
     let mut rendered = if lines.len() == 1 && line_length(&lines[0]) <= config.max_line_length() {
@@ -479,7 +484,11 @@ fn render_type_body(
 }
 
 fn render_begin_block(begin: &BeginBlock, indent: usize, config: &Config) -> String {
-    let mut rendered = format!("{}begin", indent_string(indent));
+    let mut rendered = render_attribute_prologue(begin.syntax(), indent)
+        .into_iter()
+        .map(|line| format!("{line}\n"))
+        .collect::<String>();
+    rendered.push_str(&format!("{}begin", indent_string(indent)));
     if let Some(comment) = comment_before_child_of_kind(begin.syntax(), SyntaxKind::Block, 0) {
         append_inline_comment(&mut rendered, &comment);
     }
@@ -496,7 +505,7 @@ fn render_begin_block(begin: &BeginBlock, indent: usize, config: &Config) -> Str
 }
 
 fn render_procedure(procedure: &Procedure, indent: usize, config: &Config) -> String {
-    let mut lines = render_procedure_attribute_prologue(procedure, indent);
+    let mut lines = render_attribute_prologue(procedure.syntax(), indent);
 
     let mut header = indent_string(indent);
     if procedure.visibility().is_some() {
@@ -539,12 +548,53 @@ fn render_procedure(procedure: &Procedure, indent: usize, config: &Config) -> St
     lines.join("\n")
 }
 
-fn render_procedure_attribute_prologue(procedure: &Procedure, indent: usize) -> Vec<String> {
+fn render_macro(macro_decl: &Macro, indent: usize, config: &Config) -> String {
+    let mut header = format!("{}macro.", indent_string(indent));
+    if let Some(name) = macro_decl.name_token() {
+        header.push_str(name.text());
+    }
+    let params = macro_decl
+        .param_tokens()
+        .map(|token| token.text().to_string())
+        .collect::<Vec<_>>();
+    if !params.is_empty() {
+        header.push_str(&format!("({})", params.join(", ")));
+    }
+
+    let mut lines = vec![header];
+    if let Some(comment) = comment_before_child_of_kind(macro_decl.syntax(), SyntaxKind::Block, 0)
+        && let Some(last_line) = lines.last_mut()
+    {
+        append_inline_comment(last_line, &comment);
+    }
+
+    let body_comments =
+        standalone_comments_before_child_of_kind(macro_decl.syntax(), SyntaxKind::Block, 0);
+    lines.extend(
+        body_comments
+            .into_iter()
+            .map(|comment| format!("{}{}", indent_string(indent + config.indent_size()), comment)),
+    );
+
+    if let Some(block) = macro_decl.block() {
+        let body = render_block(&block, indent + config.indent_size(), config);
+        if !body.is_empty() {
+            lines.extend(body.split('\n').map(ToOwned::to_owned));
+        }
+    }
+
+    lines.push(format!("{}end", indent_string(indent)));
+    lines.join("\n")
+}
+
+/// Renders the attributes (and the comments between them) leading the procedure, `begin` item, or
+/// `repeat` operation `item`, one per line.
+fn render_attribute_prologue(item: &SyntaxNode, indent: usize) -> Vec<String> {
     let mut lines = Vec::new();
     let mut inline_comment_target = None;
     let mut same_line = false;
 
-    for element in procedure.syntax().children_with_tokens() {
+    for element in item.children_with_tokens() {
         match element {
             NodeOrToken::Node(child) if child.kind() == SyntaxKind::Attribute => {
                 lines.push(format!("{}{}", indent_string(indent), render_compact_tokens(&child)));
@@ -941,11 +991,16 @@ fn render_do_while(do_while_op: &DoWhileOp, indent: usize, config: &Config) -> S
 }
 
 fn render_repeat(repeat_op: &RepeatOp, indent: usize, config: &Config) -> String {
-    let mut rendered = format!(
+    let mut rendered = String::new();
+    for line in render_attribute_prologue(repeat_op.syntax(), indent) {
+        rendered.push_str(&line);
+        rendered.push('\n');
+    }
+    rendered.push_str(&format!(
         "{}{}",
         indent_string(indent),
         render_prefix_before_first_block(repeat_op.syntax())
-    );
+    ));
     if let Some(comment) = comment_before_child_of_kind(repeat_op.syntax(), SyntaxKind::Block, 0) {
         append_inline_comment(&mut rendered, &comment);
     }
@@ -968,6 +1023,8 @@ fn render_prefix_before_first_block(node: &SyntaxNode) -> String {
     for child in node.children_with_tokens() {
         match child {
             NodeOrToken::Node(child_node) if child_node.kind() == SyntaxKind::Block => break,
+            // Attributes are rendered on their own lines, see `render_attribute_prologue`
+            NodeOrToken::Node(child_node) if child_node.kind() == SyntaxKind::Attribute => (),
             NodeOrToken::Node(child_node) => tokens.extend(significant_tokens(&child_node)),
             NodeOrToken::Token(token) if !token.kind().is_trivia() => tokens.push(token),
             NodeOrToken::Token(_) => (),
@@ -1676,6 +1733,16 @@ fn needs_space(previous: &SyntaxToken, next: &SyntaxToken, style: SpacingStyle)
     let previous_kind = previous.kind();
     let next_kind = next.kind();
 
+    // Shift operators in constant expressions are lexed as a pair of adjacent angle brackets
+    if is_shift_operator_half(next) && next.next_token().is_some_and(|t| t.kind() == next_kind) {
+        return true;
+    }
+    if is_shift_operator_half(previous)
+        && previous.prev_token().is_some_and(|t| t.kind() == previous_kind)
+    {
+        return !is_shift_operator_half(next);
+    }
+
     if matches!(previous_kind, At | Dot | ColonColon | LAngle | LParen | LBracket | LBrace) {
         return false;
     }
@@ -1689,20 +1756,27 @@ fn needs_space(previous: &SyntaxToken, next: &SyntaxToken, style: SpacingStyle)
         LBracket | Equal if matches!(style, SpacingStyle::CompactInstruction) => false,
         Colon if matches!(style, SpacingStyle::TypeBodyItem) => false,
         Tombstone | Error | SourceFile | Doc | Namespace | ExternPackage | Submodule | Import
-        | ImportList | ImportSpecifier | Constant | TypeDecl | AdviceMap | BeginBlock
-        | Procedure | Attribute | Visibility | Signature | Block | IfOp | WhileOp | DoWhileOp
-        | RepeatOp | Instruction | Path | Expr | TypeBody | Whitespace | Newline | Comment
-        | DocComment | Ident | SpecialIdent | Number | QuotedIdent | QuotedString | At | Bang
-        | Colon | Equal | LBrace | LBracket | LParen | Minus | Plus | RArrow | Semicolon
-        | Slash | SlashSlash | Star => match previous_kind {
-            Equal if matches!(style, SpacingStyle::CompactInstruction) => false,
-            DotDot => false,
-            Comma | Equal | RArrow | Colon | Plus | Minus | Star | Slash | SlashSlash => true,
-            _ => true,
+        | ImportList | ImportSpecifier | Constant | TypeDecl | AdviceMap | DataSegment
+        | BeginBlock | Procedure | Macro | Attribute | Visibility | Signature | Block | IfOp
+        | WhileOp | DoWhileOp | RepeatOp | Instruction | Path | Expr | TypeBody | Whitespace
+        | Newline | Comment | DocComment | Ident | SpecialIdent | Number | QuotedIdent
+        | QuotedString | Amp | At | Bang | Caret | Colon | Equal | LBrace | LBracket | LParen
+        | Minus | Percent | Pipe | Plus | RArrow | Semicolon | Slash | SlashSlash | Star => {
+            match previous_kind {
+                Equal if matches!(style, SpacingStyle::CompactInstruction) => false,
+                DotDot => false,
+                Comma | Equal | RArrow | Colon | Plus | Minus | Star | Slash | SlashSlash => true,
+                _ => true,
+            }
         },
     }
 }
 
+fn is_shift_operator_half(token: &SyntaxToken) -> bool {
+    matches!(token.kind(), SyntaxKind::LAngle | SyntaxKind::RAngle)
+        && token.parent().is_some_and(|parent| parent.kind() == SyntaxKind::Expr)
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
@@ -1867,6 +1941,125 @@ const Y = event(\"miden::event\")
         assert_eq!(reformatted, formatted);
     }
 
+    #[test]
+    fn formats_macro_declarations() {
+        let source = "\
+macro.push_pair( a,b ) # pair
+  push.a   push.b
+end
+
+begin
+  macro_exec.push_pair(1,2)
+end
+";
+
+        let parse = parse_text(source);
+        assert!(!parse.has_errors(), "{:?}", parse.diagnostics());
+
+        let config = Config::default();
+        let formatted = format_syntax(&config, &parse.syntax());
+        let expected = "\
+macro.push_pair(a, b) # pair
+    push.a push.b
+end
+
+begin
+    macro_exec.push_pair(1, 2)
+end
+";
+
+        assert_eq!(formatted, expected);
+        assert_format_idempotent(source, "macro declaration");
+    }
+
+    #[test]
+    fn formats_cfg_attributes_on_procedures_and_begin_blocks() {
+        let source = "\
+@cfg( feature = \"debug\" )
+proc log
+  nop
+end
+
+@cfg(not(feature = \"debug\"))  # release build
+begin
+  push.1
+end
+";
+
+        let parse = parse_text(source);
+        assert!(!parse.has_errors(), "{:?}", parse.diagnostics());
+
+        let config = Config::default();
+        let formatted = format_syntax(&config, &parse.syntax());
+        let expected = "\
+@cfg(feature = \"debug\")
+proc log
+    nop
+end
+
+@cfg(not(feature = \"debug\")) # release build
+begin
+    push.1
+end
+";
+
+        assert_eq!(formatted, expected);
+        assert_format_idempotent(source, "cfg attributes");
+    }
+
+    #[test]
+    fn formats_attributes_on_repeat_blocks() {
+        let source = "\
+proc foo
+  @no_unroll   repeat.4
+    nop
+  end
+end
+";
+
+        let parse = parse_text(source);
+        assert!(!parse.has_errors(), "{:?}", parse.diagnostics());
+
+        let config = Config::default();
+        let formatted = format_syntax(&config, &parse.syntax());
+        let expected = "\
+proc foo
+    @no_unroll
+    repeat.4
+        nop
+    end
+end
+";
+
+        assert_eq!(formatted, expected);
+        assert_format_idempotent(source, "repeat attributes");
+    }
+
+    #[test]
+    fn formats_bitwise_and_shift_constant_operators() {
+        let source = "\
+const MASK = (1<<8)-1
+const FLAGS = A|B^C&MASK
+const HIGH = VALUE  >>  32 % 4
+const PACKED = word(A, B<<1, 0, 1)
+";
+
+        let parse = parse_text(source);
+        assert!(!parse.has_errors(), "{:?}", parse.diagnostics());
+
+        let config = Config::default();
+        let formatted = format_syntax(&config, &parse.syntax());
+        let expected = "\
+const MASK = (1 << 8) - 1
+const FLAGS = A | B ^ C & MASK
+const HIGH = VALUE >> 32 % 4
+const PACKED = word(A, B << 1, 0, 1)
+";
+
+        assert_eq!(formatted, expected);
+        assert_format_idempotent(source, "constant operators");
+    }
+
     #[test]
     fn wraps_root_parenthesized_value_expressions() {
         let source = "const X = (alpha, beta, gamma, delta, epsilon, zeta)\n";
@@ -1940,6 +2133,7 @@ use   miden::core::mem   as   memory
 # const comment
 pub const EVENT=event(\"miden::event\")
 adv_map   TABLE=[0x01,0x02]
+data  LOOKUP=[[1,2,3,4],0x0100000000000000000000000000000000000000000000000000000000000000]
 type T   = struct {f:u32,   other: felt}
 begin
  swap  dup.1 add
@@ -1964,6 +2158,7 @@ use miden::core::mem as memory
 # const comment
 pub const EVENT = event(\"miden::event\")
 adv_map TABLE = [0x01, 0x02]
+data LOOKUP = [[1, 2, 3, 4], 0x0100000000000000000000000000000000000000000000000000000000000000]
 type T = struct { f: u32, other: felt }
 begin
     swap dup.1 add
@@ -2187,6 +2382,8 @@ use   some::module   as   sm
 use   foo
 use   {foo,bar  as  baz,\"as\" as \"from\"}   from   some::module # items
 pub   use   {alpha}   from   core
+use   some::other  ::  *
+pub   use   math::*   # everything
 ";
 
         let parse = parse_text(source);
@@ -2199,6 +2396,8 @@ use some::module as sm
 use foo
 use {foo, bar as baz, \"as\" as \"from\"} from some::module # items
 pub use {alpha} from core
+use some::other::*
+pub use math::* # everything
 ";
 
         assert_eq!(formatted, expected);
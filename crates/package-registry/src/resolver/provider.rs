@@ -1,4 +1,4 @@
-use alloc::{boxed::Box, format, string::String};
+use alloc::{boxed::Box, format, string::String, vec::Vec};
 use core::{cmp::Reverse, convert::Infallible};
 
 use pubgrub::{Dependencies, DependencyProvider, SelectedDependencies};
@@ -238,10 +238,30 @@ impl<R: PackageRegistry + ?Sized> PackageResolver<'_, R> {
     }
 }
 
+type DerivationTree = pubgrub::DerivationTree<PackageId, VersionSet, String>;
+
 #[derive(thiserror::Error)]
 pub enum DependencyResolutionError {
     #[error("dependency resolution failed: {}", format_solution_error(.0))]
-    NoSolution(Box<pubgrub::DerivationTree<PackageId, VersionSet, String>>),
+    NoSolution(Box<DerivationTree>),
+    /// Two or more packages require mutually incompatible versions of `package`.
+    ///
+    /// `constraints[i]` is the requirement placed on `package` by `required_by[i]`.
+    #[error(
+        "dependency resolution failed: conflicting requirements on '{package}' ({}): {}",
+        format_constraints(.required_by, .constraints),
+        format_solution_error(.derivation)
+    )]
+    VersionConflict {
+        /// The package on which incompatible requirements were placed
+        package: PackageId,
+        /// The packages which require `package`
+        required_by: Vec<PackageId>,
+        /// The version constraints placed on `package`, in the same order as `required_by`
+        constraints: Vec<VersionSet>,
+        /// The full explanation of the resolution failure
+        derivation: Box<DerivationTree>,
+    },
     #[error("could not get dependencies for '{package}' version '{version}': {error}")]
     FailedRetreivingDependencies {
         package: PackageId,
@@ -260,15 +280,79 @@ impl core::fmt::Debug for DependencyResolutionError {
     }
 }
 
-fn format_solution_error(tree: &pubgrub::DerivationTree<PackageId, VersionSet, String>) -> String {
+fn format_solution_error(tree: &DerivationTree) -> String {
     use pubgrub::{DefaultStringReporter, Reporter};
 
     DefaultStringReporter::report(tree)
 }
 
-impl From<pubgrub::DerivationTree<PackageId, VersionSet, String>> for DependencyResolutionError {
-    fn from(tree: pubgrub::DerivationTree<PackageId, VersionSet, String>) -> Self {
-        Self::NoSolution(Box::new(tree))
+fn format_constraints(required_by: &[PackageId], constraints: &[VersionSet]) -> String {
+    required_by
+        .iter()
+        .zip(constraints)
+        .map(|(dependent, constraint)| format!("'{dependent}' requires {constraint}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Collect the `(dependent, dependency, constraint)` edges that contributed to `tree`.
+fn collect_dependency_edges<'a>(
+    tree: &'a DerivationTree,
+    edges: &mut Vec<(&'a PackageId, &'a PackageId, &'a VersionSet)>,
+) {
+    use pubgrub::External;
+
+    match tree {
+        DerivationTree::External(External::FromDependencyOf(dependent, _, dependency, set)) => {
+            if !edges.contains(&(dependent, dependency, set)) {
+                edges.push((dependent, dependency, set));
+            }
+        },
+        DerivationTree::External(_) => (),
+        DerivationTree::Derived(derived) => {
+            collect_dependency_edges(&derived.cause1, edges);
+            collect_dependency_edges(&derived.cause2, edges);
+        },
+    }
+}
+
+impl From<DerivationTree> for DependencyResolutionError {
+    /// Explains the failure as a [`Self::VersionConflict`] when the derivation contains disjoint
+    /// requirements on the same package, falling back to [`Self::NoSolution`] otherwise.
+    fn from(tree: DerivationTree) -> Self {
+        use pubgrub::VersionSet as _;
+
+        let mut edges = Vec::new();
+        collect_dependency_edges(&tree, &mut edges);
+
+        let conflict = edges.iter().find_map(|(_, package, _)| {
+            let requirements = edges
+                .iter()
+                .filter(|(_, dependency, _)| dependency == package)
+                .collect::<Vec<_>>();
+            let has_conflict = requirements.iter().enumerate().any(|(i, (_, _, a))| {
+                requirements[i + 1..]
+                    .iter()
+                    .any(|(_, _, b)| a.intersection(b) == VersionSet::empty())
+            });
+            has_conflict.then(|| {
+                let (required_by, constraints) = requirements
+                    .into_iter()
+                    .map(|(dependent, _, set)| ((*dependent).clone(), (*set).clone()))
+                    .unzip();
+                ((*package).clone(), required_by, constraints)
+            })
+        });
+
+        match conflict {
+            Some((package, required_by, constraints)) => Self::VersionConflict {
+                package,
+                required_by,
+                constraints,
+                derivation: Box::new(tree),
+            },
+            None => Self::NoSolution(Box::new(tree)),
+        }
     }
 }
 
@@ -293,7 +377,7 @@ impl<R: PackageRegistry + ?Sized> From<pubgrub::PubGrubError<PackageResolver<'_,
 
 #[cfg(test)]
 mod tests {
-    use alloc::vec;
+    use alloc::{string::ToString, vec};
 
     use miden_core::{Word, crypto::hash::Rpo256};
     use pubgrub::SelectedDependencies;
@@ -523,6 +607,47 @@ mod tests {
         let _ = resolver.resolve().unwrap();
     }
 
+    #[test]
+    fn resolver_reports_version_conflict_with_all_constraints() {
+        let index = InMemoryPackageRegistry::from_iter([
+            ("app", vec![("0.1.0".parse().unwrap(), vec![("a", any()), ("b", any())])]),
+            ("a", vec![("1.0.0".parse().unwrap(), vec![("c", req("^1.0.0"))])]),
+            ("b", vec![("1.0.0".parse().unwrap(), vec![("c", req("^2.0.0"))])]),
+            (
+                "c",
+                vec![("1.0.0".parse().unwrap(), vec![]), ("2.0.0".parse().unwrap(), vec![])],
+            ),
+        ]);
+
+        let resolver = PackageResolver::for_package("app", "0.1.0".parse().unwrap(), &index);
+        let error = resolver.resolve().expect_err("resolution should fail");
+        let DependencyResolutionError::VersionConflict {
+            package, required_by, constraints, ..
+        } = &error
+        else {
+            panic!("expected a version conflict, got: {error}");
+        };
+
+        assert_eq!(package, &PackageId::from("c"));
+        let mut requirements = required_by
+            .iter()
+            .map(ToString::to_string)
+            .zip(constraints.iter().map(ToString::to_string))
+            .collect::<Vec<_>>();
+        requirements.sort();
+        assert_eq!(
+            requirements,
+            [
+                ("a".to_string(), VersionSet::from(req("^1.0.0")).to_string()),
+                ("b".to_string(), VersionSet::from(req("^2.0.0")).to_string()),
+            ]
+        );
+
+        let message = error.to_string();
+        assert!(message.contains("'a' requires"), "{message}");
+        assert!(message.contains("'b' requires"), "{message}");
+    }
+
     #[test]
     fn resolver_resolve_compatible_packages() {
         let index = InMemoryPackageRegistry::from_iter([
@@ -164,6 +164,8 @@ pub(crate) enum ProjectFileError {
         span: SourceSpan,
         #[label("previous workspace member")]
         prev: SourceSpan,
+        #[related]
+        related: Vec<RelatedError>,
     },
     #[error("no profile named '{name}' has been defined yet")]
     UnknownProfile {
@@ -15,6 +15,7 @@ use crate::{Map, MetadataSet, RelatedLabel, SemVer, SourceId, Span, Uri};
 #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct PackageTable {
     /// The name of this package
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "parsing::deserialize_spanned"))]
     pub name: Span<Arc<str>>,
     /// Additional package information, optionally inheritable from a parent workspace (if present)
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -130,6 +130,40 @@ mod maybe_inherit {
     }
 }
 
+/// Deserialize a [Span] whose source span is the byte range of the value in the manifest.
+///
+/// The `Deserialize` implementation of [Span] has no access to the location of the value, so
+/// fields referenced by diagnostics use this instead. The [SourceId] is attached afterwards, see
+/// [SetSourceId].
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize_spanned<'de, D, T>(deserializer: D) -> Result<Span<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    use miden_assembly_syntax::debuginfo::serde_spanned::Spanned;
+
+    <Spanned<T> as serde::Deserialize>::deserialize(deserializer)
+        .map(|spanned| Span::from_serde_spanned(SourceId::UNKNOWN, spanned))
+}
+
+/// Like [deserialize_spanned], but for a sequence of values.
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize_spanned_vec<'de, D, T>(deserializer: D) -> Result<Vec<Span<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    use miden_assembly_syntax::debuginfo::serde_spanned::Spanned;
+
+    <Vec<Spanned<T>> as serde::Deserialize>::deserialize(deserializer).map(|values| {
+        values
+            .into_iter()
+            .map(|spanned| Span::from_serde_spanned(SourceId::UNKNOWN, spanned))
+            .collect()
+    })
+}
+
 /// This trait is implemented for all types which have source spans with an associated [SourceId].
 ///
 /// After parsing via `serde`, it is necessary for us to post-process such spans to attach the
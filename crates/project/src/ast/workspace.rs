@@ -10,7 +10,10 @@ use crate::{SourceId, Span, Uri};
 #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct WorkspaceTable {
     /// The relative paths of all workspace members
-    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "parsing::deserialize_spanned_vec")
+    )]
     pub members: Vec<Span<Uri>>,
     /// The contents of the `[workspace.package]` table
     #[cfg_attr(feature = "serde", serde(default))]
@@ -21,14 +21,14 @@ use alloc::{sync::Arc, vec::Vec};
 
 use miden_assembly_syntax::{
     Report,
-    debuginfo::{SourceSpan, Span},
+    debuginfo::{SourceFile, SourceSpan, Span},
     diagnostics::{Diagnostic, miette},
 };
 // Re-exported for consistency
 pub use miden_assembly_syntax::{Word, debuginfo::Uri, semver};
 #[cfg(feature = "serde")]
 use miden_assembly_syntax::{
-    debuginfo::{SourceFile, SourceId},
+    debuginfo::SourceId,
     diagnostics::{Label, RelatedError, RelatedLabel},
 };
 pub use miden_mast_package::TargetType;
@@ -37,8 +37,12 @@ use serde::{Deserialize, Serialize};
 pub use toml::Value;
 
 pub use self::{
-    dependencies::*, linkage::Linkage, package::Package, profile::Profile, target::Target,
-    workspace::Workspace,
+    dependencies::*,
+    linkage::Linkage,
+    package::Package,
+    profile::Profile,
+    target::{Target, TargetCapabilities},
+    workspace::{DependencyGraph, ResolvedDependency, ResolvedVersion, Workspace},
 };
 
 /// An alias for [`alloc::collections::BTreeMap`].
@@ -104,6 +108,88 @@ impl Project {
     }
 }
 
+/// Diagnostics
+impl Project {
+    /// Render `report` as a human-readable diagnostic, with annotated source snippets.
+    ///
+    /// Diagnostics raised for a project frequently relate to several manifests, e.g. a workspace
+    /// manifest and those of its members. Each label is rendered against the source file of the
+    /// diagnostic it is attached to, so related diagnostics which refer to other files are shown
+    /// with snippets from those files, rather than the file of the top-level diagnostic. Labels of
+    /// diagnostics which do not carry a source file of their own are rendered against the
+    /// manifest of this project.
+    pub fn render_diagnostic(&self, report: &Report) -> alloc::string::String {
+        use alloc::string::ToString;
+
+        let package = self.package();
+        let diagnostic = ProjectDiagnostic {
+            diagnostic: report.as_ref(),
+            manifest: package.manifest().map(|manifest| &**manifest),
+        };
+        RenderedDiagnostic(diagnostic).to_string()
+    }
+}
+
+/// Renders a [ProjectDiagnostic] without color, like [PrintDiagnostic::new_without_color].
+///
+/// [PrintDiagnostic::new_without_color]:
+///     miden_assembly_syntax::diagnostics::reporting::PrintDiagnostic::new_without_color
+struct RenderedDiagnostic<'a>(ProjectDiagnostic<'a>);
+
+impl core::fmt::Display for RenderedDiagnostic<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use miden_assembly_syntax::diagnostics::reporting::{DefaultReportHandler, ReportHandler};
+
+        #[cfg(feature = "std")]
+        let handler = DefaultReportHandler::new_themed(
+            miden_assembly_syntax::diagnostics::reporting::GraphicalTheme::none(),
+        );
+        #[cfg(not(feature = "std"))]
+        let handler = DefaultReportHandler::new();
+        handler.debug(&self.0, f)
+    }
+}
+
+/// A diagnostic rendered on behalf of a [Project], using the project manifest as the source code of
+/// a diagnostic which does not provide its own.
+#[derive(Debug, thiserror::Error)]
+#[error("{diagnostic}")]
+struct ProjectDiagnostic<'a> {
+    diagnostic: &'a dyn Diagnostic,
+    manifest: Option<&'a SourceFile>,
+}
+
+impl Diagnostic for ProjectDiagnostic<'_> {
+    fn code<'a>(&'a self) -> Option<alloc::boxed::Box<dyn core::fmt::Display + 'a>> {
+        self.diagnostic.code()
+    }
+    fn severity(&self) -> Option<miette::Severity> {
+        self.diagnostic.severity()
+    }
+    fn help<'a>(&'a self) -> Option<alloc::boxed::Box<dyn core::fmt::Display + 'a>> {
+        self.diagnostic.help()
+    }
+    fn url<'a>(&'a self) -> Option<alloc::boxed::Box<dyn core::fmt::Display + 'a>> {
+        self.diagnostic.url()
+    }
+    fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+        self.diagnostic
+            .source_code()
+            .or_else(|| self.manifest.map(|manifest| manifest as &dyn miette::SourceCode))
+    }
+    fn labels(&self) -> Option<alloc::boxed::Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+    fn related<'a>(
+        &'a self,
+    ) -> Option<alloc::boxed::Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.diagnostic.related()
+    }
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.diagnostic.diagnostic_source()
+    }
+}
+
 /// Parsing
 #[cfg(all(feature = "std", feature = "serde"))]
 impl Project {
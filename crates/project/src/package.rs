@@ -5,6 +5,7 @@ use alloc::{
 #[cfg(feature = "std")]
 use std::path::Path;
 
+use miden_assembly_syntax::debuginfo::SourceFile;
 #[cfg(all(feature = "std", feature = "serde"))]
 use miden_assembly_syntax::debuginfo::Spanned;
 use miden_mast_package::PackageId;
@@ -19,6 +20,8 @@ pub struct Package {
     /// The file path of the manifest corresponding to this package metadata, if applicable.
     #[cfg(feature = "std")]
     manifest_path: Option<Box<Path>>,
+    /// The source of the manifest corresponding to this package metadata, if applicable.
+    manifest: Option<Arc<SourceFile>>,
     /// The name of the package
     name: Span<PackageId>,
     /// The semantic version associated with the package
@@ -61,6 +64,7 @@ impl Package {
         Box::new(Self {
             #[cfg(feature = "std")]
             manifest_path: None,
+            manifest: None,
             name: Span::unknown(name),
             version: Span::unknown(SemVer::new(0, 0, 0)),
             description: None,
@@ -237,6 +241,11 @@ impl Package {
         self.manifest_path.as_deref()
     }
 
+    /// Get the source of the manifest this package was loaded from, if known/applicable.
+    pub(crate) fn manifest(&self) -> Option<&Arc<SourceFile>> {
+        self.manifest.as_ref()
+    }
+
     /// Get the location of the manifest this package was loaded from, or return an error if not
     /// available.
     #[cfg(feature = "std")]
@@ -252,6 +261,7 @@ impl Package {
         let Self {
             #[cfg(feature = "std")]
                 manifest_path: _,
+            manifest: _,
             name,
             version,
             description: _,
@@ -369,6 +379,7 @@ impl Package {
 
         Ok(Box::new(Self {
             manifest_path,
+            manifest: Some(source),
             name: package_ast.package.name.map(Into::into),
             version,
             description,
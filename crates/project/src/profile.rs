@@ -107,6 +107,23 @@ impl Profile {
         Ok(profile)
     }
 
+    /// Derive the options used to prove programs built with this profile.
+    ///
+    /// Profiles which emit debug information, e.g. `dev`, are intended for fast iteration and
+    /// prove using BLAKE3. All other profiles, e.g. `release`, prove using Poseidon2, whose proofs
+    /// can be verified recursively by the VM. The STARK security parameters are determined by the
+    /// selected hash function.
+    #[cfg(feature = "prover")]
+    pub fn proving_options(&self) -> miden_prover::ProvingOptions {
+        use miden_prover::{HashFunction, ProvingOptions};
+
+        if self.debug {
+            ProvingOptions::new(HashFunction::Blake3_256)
+        } else {
+            ProvingOptions::new(HashFunction::Poseidon2)
+        }
+    }
+
     /// Merge configuration from `other` into `self`.
     ///
     /// This has the effect of overriding any options in `self` which have different values in
@@ -1,6 +1,7 @@
 use alloc::string::{String, ToString};
 
 use miden_assembly_syntax::Path;
+use miden_core::proof::HashFunction;
 
 use crate::*;
 
@@ -67,6 +68,25 @@ impl Target {
         matches!(self.ty, TargetType::Kernel)
     }
 
+    /// Describe the artifact produced by this target, and what it requires of its sources.
+    pub const fn capabilities(&self) -> TargetCapabilities {
+        const ALL_HASH_FUNCTIONS: &[HashFunction] = &[
+            HashFunction::Blake3_192,
+            HashFunction::Blake3_256,
+            HashFunction::Rpo256,
+            HashFunction::Rpx256,
+            HashFunction::Poseidon2,
+            HashFunction::Keccak,
+        ];
+
+        let is_executable = self.is_executable();
+        TargetCapabilities {
+            requires_entrypoint: is_executable,
+            package_kind: self.ty,
+            hash_functions: if is_executable { ALL_HASH_FUNCTIONS } else { &[] },
+        }
+    }
+
     /// Append the selected target fields that affect package artifact reuse to `out`.
     pub fn append_build_provenance_projection(&self, out: &mut String) {
         let Self { ty, name, namespace, path } = self;
@@ -85,3 +105,23 @@ impl Target {
         out.push('\n');
     }
 }
+
+/// Describes the artifact produced by a [Target], see [`Target::capabilities`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TargetCapabilities {
+    /// Whether the target sources must define a program entrypoint, i.e. a `begin .. end` block
+    pub requires_entrypoint: bool,
+    /// The kind of package produced by assembling the target
+    pub package_kind: TargetType,
+    /// The hash functions which can be used to prove execution of the produced package.
+    ///
+    /// This is empty for library-like targets, as they are only ever proven as part of a program.
+    pub hash_functions: &'static [HashFunction],
+}
+
+impl TargetCapabilities {
+    /// Returns true if execution of the produced package can be proven using `hash_function`
+    pub fn supports_hash_function(&self, hash_function: HashFunction) -> bool {
+        self.hash_functions.contains(&hash_function)
+    }
+}
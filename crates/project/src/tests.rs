@@ -8,7 +8,9 @@ use miden_assembly_syntax::{
 };
 use tempfile::TempDir;
 
-use crate::{DependencyVersionScheme, Linkage, Project, TargetType, Workspace};
+use crate::{
+    DependencyVersionScheme, Linkage, Project, ResolvedVersion, SemVer, TargetType, Workspace,
+};
 
 struct TestContext {
     pub source_manager: Arc<dyn SourceManager>,
@@ -474,3 +476,238 @@ path = "lib.masm"
 
     assert!(format!("{error}").contains("duplicate"), "{error}");
 }
+
+#[test]
+fn workspace_dependency_graph_resolves_member_dependencies() -> Result<(), Report> {
+    let tempdir = TempDir::new().unwrap();
+    let root = tempdir.path().join("workspace");
+    fs::create_dir_all(&root).unwrap();
+    let root = root.canonicalize().unwrap();
+    let app_dir = root.join("app");
+    let dep_dir = root.join("dep");
+    fs::create_dir_all(&app_dir).unwrap();
+    fs::create_dir_all(&dep_dir).unwrap();
+
+    fs::write(
+        root.join("miden-project.toml"),
+        r#"[workspace]
+members = ["app", "dep"]
+"#,
+    )
+    .unwrap();
+
+    fs::write(
+        app_dir.join("miden-project.toml"),
+        r#"[package]
+name = "app"
+version = "0.1.0"
+
+[lib]
+path = "lib.masm"
+
+[dependencies]
+dep = { path = "../dep" }
+external = "1.2"
+"#,
+    )
+    .unwrap();
+    fs::write(app_dir.join("lib.masm"), "export.foo\nend\n").unwrap();
+
+    fs::write(
+        dep_dir.join("miden-project.toml"),
+        r#"[package]
+name = "dep"
+version = "2.3.4"
+
+[lib]
+path = "lib.masm"
+"#,
+    )
+    .unwrap();
+    fs::write(dep_dir.join("lib.masm"), "export.bar\nend\n").unwrap();
+
+    let context = TestContext::default();
+    let workspace = context.load_workspace(root.join("miden-project.toml"))?;
+    let graph = workspace.dependency_graph();
+
+    assert_eq!(graph.nodes().len(), 2);
+    assert_eq!(graph.dependencies("dep"), Some([].as_slice()));
+
+    let app_deps = graph.dependencies("app").expect("app should be part of the graph");
+    assert_eq!(app_deps.len(), 2);
+    let dep = app_deps.iter().find(|d| d.name == "dep").expect("missing edge app -> dep");
+    assert_eq!(dep.version, ResolvedVersion::Member(SemVer::new(2, 3, 4)));
+    let external = app_deps
+        .iter()
+        .find(|d| d.name == "external")
+        .expect("missing edge app -> external");
+    assert_matches!(external.version, ResolvedVersion::Unresolved(_));
+
+    assert!(graph.is_acyclic());
+
+    Ok(())
+}
+
+#[test]
+fn workspace_dependency_graph_reports_cycles() -> Result<(), Report> {
+    let tempdir = TempDir::new().unwrap();
+    let root = tempdir.path().join("workspace");
+    fs::create_dir_all(&root).unwrap();
+    let root = root.canonicalize().unwrap();
+
+    fs::write(
+        root.join("miden-project.toml"),
+        r#"[workspace]
+members = ["a", "b"]
+"#,
+    )
+    .unwrap();
+    for (name, dep) in [("a", "b"), ("b", "a")] {
+        let dir = root.join(name);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("miden-project.toml"),
+            format!(
+                r#"[package]
+name = "{name}"
+version = "1.0.0"
+
+[lib]
+path = "lib.masm"
+
+[dependencies]
+{dep} = {{ path = "../{dep}" }}
+"#
+            ),
+        )
+        .unwrap();
+        fs::write(dir.join("lib.masm"), "export.foo\nend\n").unwrap();
+    }
+
+    let context = TestContext::default();
+    let workspace = context.load_workspace(root.join("miden-project.toml"))?;
+    let cycles = workspace.dependency_graph().cycles();
+
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(cycles[0], ["a", "b"]);
+
+    Ok(())
+}
+
+#[test]
+fn target_capabilities_describe_entrypoint_requirements() {
+    use miden_assembly_syntax::debuginfo::Uri;
+    use miden_core::proof::HashFunction;
+
+    use crate::Target;
+
+    let program = Target::executable("app", Uri::new("main.masm")).capabilities();
+    assert!(program.requires_entrypoint);
+    assert_eq!(program.package_kind, TargetType::Executable);
+    assert!(program.supports_hash_function(HashFunction::Poseidon2));
+
+    let library = Target::library(MasmPath::new("::app"), Uri::new("lib.masm")).capabilities();
+    assert!(!library.requires_entrypoint);
+    assert_eq!(library.package_kind, TargetType::Library);
+    assert!(library.hash_functions.is_empty());
+}
+
+#[cfg(feature = "prover")]
+#[test]
+fn profile_proving_options_follow_profile() {
+    use miden_prover::HashFunction;
+
+    use crate::Profile;
+
+    assert_eq!(Profile::default().proving_options().hash_fn(), HashFunction::Blake3_256);
+    assert_eq!(Profile::release().proving_options().hash_fn(), HashFunction::Poseidon2);
+}
+
+#[test]
+fn render_diagnostic_shows_labels_from_every_related_file() {
+    let tempdir = TempDir::new().unwrap();
+    let root = tempdir.path().join("workspace");
+    fs::create_dir_all(&root).unwrap();
+    let root = root.canonicalize().unwrap();
+
+    fs::write(
+        root.join("miden-project.toml"),
+        r#"[workspace]
+members = ["first", "second"]
+"#,
+    )
+    .unwrap();
+    for member in ["first", "second"] {
+        let dir = root.join(member);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("miden-project.toml"),
+            format!(
+                r#"[package]
+name = "dep"
+version = "1.0.0"
+
+[lib]
+path = "{member}.masm"
+"#
+            ),
+        )
+        .unwrap();
+    }
+
+    let context = TestContext::default();
+    let error = context
+        .load_workspace(root.join("miden-project.toml"))
+        .expect_err("duplicate member package names should be rejected");
+
+    let project = standalone_project(&tempdir, &context);
+    let rendered = project.render_diagnostic(&error);
+
+    // Every label points at the offending value in its own manifest
+    let workspace = root.join("miden-project.toml");
+    let first = root.join("first").join("miden-project.toml");
+    let second = root.join("second").join("miden-project.toml");
+    assert!(rendered.contains(&format!("[{}:2:21]", workspace.display())), "{rendered}");
+    assert!(rendered.contains(&format!("[{}:2:8]", first.display())), "{rendered}");
+    assert!(rendered.contains(&format!("[{}:2:8]", second.display())), "{rendered}");
+    assert!(rendered.contains("package 'dep'"), "{rendered}");
+}
+
+#[test]
+fn render_diagnostic_falls_back_to_the_project_manifest() {
+    use miden_assembly_syntax::diagnostics::RelatedLabel;
+
+    let tempdir = TempDir::new().unwrap();
+    let context = TestContext::default();
+    let project = standalone_project(&tempdir, &context);
+
+    let package = project.package();
+    let error = Report::from(
+        RelatedLabel::error("invalid package name")
+            .with_labeled_span(package.name().span(), "declared here"),
+    );
+    let rendered = project.render_diagnostic(&error);
+
+    let manifest = project.manifest_path().unwrap();
+    assert!(rendered.contains(&format!("[{}:2:8]", manifest.display())), "{rendered}");
+    assert!(rendered.contains(r#"name = "app""#), "{rendered}");
+}
+
+/// Load a standalone package named `app` from a manifest created in `tempdir`.
+fn standalone_project(tempdir: &TempDir, context: &TestContext) -> Project {
+    let dir = tempdir.path().join("app");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("miden-project.toml"),
+        r#"[package]
+name = "app"
+version = "1.0.0"
+
+[lib]
+path = "app.masm"
+"#,
+    )
+    .unwrap();
+
+    Project::load(&dir, &*context.source_manager).expect("standalone project should load")
+}
@@ -1,3 +1,4 @@
+use alloc::collections::BTreeSet;
 #[cfg(all(feature = "std", feature = "serde"))]
 use std::string::{String, ToString};
 #[cfg(feature = "std")]
@@ -5,6 +6,7 @@ use std::{boxed::Box, path::Path};
 
 #[cfg(all(feature = "std", feature = "serde"))]
 use miden_assembly_syntax::debuginfo::SourceManager;
+use miden_mast_package::PackageId;
 
 use crate::*;
 
@@ -88,7 +90,8 @@ impl Workspace {
             manifest_path,
             members: Vec::with_capacity(members.len()),
         });
-        let mut seen_member_names = Map::<String, SourceSpan>::default();
+        let mut seen_member_names =
+            Map::<String, (SourceSpan, Arc<SourceFile>, SourceSpan)>::default();
 
         for member in members {
             let Some(workspace_root) = workspace.workspace_root() else {
@@ -125,10 +128,24 @@ impl Workspace {
                     span: Label::new(member.span(), err.to_string()),
                 }
             })?;
-            let package = Package::load_from_workspace(member_manifest, &file)?;
+            let package = Package::load_from_workspace(member_manifest.clone(), &file)?;
             let package_name = package.name().inner().to_string();
-            if let Some(prev) = seen_member_names.insert(package_name.clone(), member.span()) {
+            let declaration = (member.span(), member_manifest.clone(), package.name().span());
+            if let Some((prev, prev_manifest, prev_name)) =
+                seen_member_names.insert(package_name.clone(), declaration)
+            {
+                let declared_at = |manifest: Arc<SourceFile>, span: SourceSpan| {
+                    RelatedError::wrap(
+                        RelatedLabel::advice("the package name is declared here")
+                            .with_labeled_span(span, format!("package '{package_name}'"))
+                            .with_source_file(Some(manifest)),
+                    )
+                };
                 return Err(ProjectFileError::DuplicateWorkspaceMember {
+                    related: vec![
+                        declared_at(member_manifest, package.name().span()),
+                        declared_at(prev_manifest, prev_name),
+                    ],
                     name: package_name,
                     source_file: source.clone(),
                     span: member.span(),
@@ -142,3 +159,130 @@ impl Workspace {
         Ok(workspace)
     }
 }
+
+/// Dependency graph
+impl Workspace {
+    /// Compute the dependency graph of the members of this workspace.
+    ///
+    /// Each member is mapped to its declared dependencies. Source dependencies (i.e. workspace or
+    /// path dependencies) naming another workspace member are resolved to the version declared by
+    /// that member; all other dependencies are left unresolved, and are represented by the
+    /// version requirement they were declared with.
+    ///
+    /// Cycles between members do not cause this function to fail, use
+    /// [`DependencyGraph::cycles`] to detect and report them.
+    pub fn dependency_graph(&self) -> DependencyGraph {
+        let mut nodes = Map::default();
+        for member in self.members() {
+            let dependencies = member
+                .dependencies()
+                .iter()
+                .map(|dependency| {
+                    let name = PackageId::from(dependency.name().clone());
+                    let version = match dependency.scheme() {
+                        DependencyVersionScheme::Workspace { .. }
+                        | DependencyVersionScheme::WorkspacePath { .. }
+                        | DependencyVersionScheme::Path { .. } => {
+                            self.get_member_by_name(dependency.name()).map(|package| {
+                                ResolvedVersion::Member(package.version().into_inner().clone())
+                            })
+                        },
+                        _ => None,
+                    }
+                    .unwrap_or_else(|| ResolvedVersion::Unresolved(dependency.required_version()));
+                    ResolvedDependency { name, version }
+                })
+                .collect();
+            nodes.insert(member.name().into_inner(), dependencies);
+        }
+
+        DependencyGraph { nodes }
+    }
+}
+
+/// The dependency graph of the members of a [Workspace], see [`Workspace::dependency_graph`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyGraph {
+    nodes: Map<PackageId, Vec<ResolvedDependency>>,
+}
+
+/// A dependency edge in a [DependencyGraph]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedDependency {
+    /// The name of the package depended upon
+    pub name: PackageId,
+    /// The version of the package depended upon
+    pub version: ResolvedVersion,
+}
+
+/// The version of a dependency in a [DependencyGraph]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolvedVersion {
+    /// The dependency is a member of the workspace, with the given version
+    Member(SemVer),
+    /// The dependency is external to the workspace, and must satisfy the given requirement
+    Unresolved(VersionRequirement),
+}
+
+impl DependencyGraph {
+    /// Get the dependencies of every workspace member, keyed by package name
+    pub fn nodes(&self) -> &Map<PackageId, Vec<ResolvedDependency>> {
+        &self.nodes
+    }
+
+    /// Get the dependencies of the workspace member named `package`
+    pub fn dependencies(&self, package: &str) -> Option<&[ResolvedDependency]> {
+        self.nodes.get(package).map(Vec::as_slice)
+    }
+
+    /// Returns true if there are no dependency cycles between workspace members
+    pub fn is_acyclic(&self) -> bool {
+        self.cycles().is_empty()
+    }
+
+    /// Find dependency cycles between workspace members.
+    ///
+    /// If the graph contains any cycle, at least one cycle is reported for it; cycles which only
+    /// revisit packages already known to be part of a reported cycle may be omitted. Each cycle
+    /// is reported as the sequence of packages visited along it, starting from the
+    /// lexicographically smallest package name. For example, `a -> b -> a` is reported as
+    /// `[a, b]`, and a package depending on itself is reported as a single-element cycle.
+    pub fn cycles(&self) -> Vec<Vec<PackageId>> {
+        let mut cycles = Vec::new();
+        let mut visited = BTreeSet::new();
+        let mut stack = Vec::new();
+        for root in self.nodes.keys() {
+            self.visit(root, &mut visited, &mut stack, &mut cycles);
+        }
+        cycles.sort();
+        cycles.dedup();
+        cycles
+    }
+
+    fn visit<'a>(
+        &'a self,
+        package: &'a PackageId,
+        visited: &mut BTreeSet<&'a PackageId>,
+        stack: &mut Vec<&'a PackageId>,
+        cycles: &mut Vec<Vec<PackageId>>,
+    ) {
+        if let Some(pos) = stack.iter().position(|p| *p == package) {
+            let mut cycle = stack[pos..].iter().map(|p| (*p).clone()).collect::<Vec<_>>();
+            let start = cycle.iter().enumerate().min_by_key(|(_, p)| *p).map_or(0, |(i, _)| i);
+            cycle.rotate_left(start);
+            cycles.push(cycle);
+            return;
+        }
+        if !visited.insert(package) {
+            return;
+        }
+
+        stack.push(package);
+        for dependency in self.nodes[package].iter() {
+            if let Some((name, _)) = self.nodes.get_key_value(&dependency.name) {
+                self.visit(name, visited, stack, cycles);
+            }
+        }
+        stack.pop();
+    }
+}
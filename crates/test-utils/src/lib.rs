@@ -31,7 +31,7 @@ pub use miden_core::{
 };
 use miden_core::{
     chiplets::hasher::apply_permutation,
-    events::{EventName, SystemEvent},
+    events::{EventId, EventName, SystemEvent},
 };
 use miden_mast_package::{Package, debug_info::PackageDebugInfo};
 #[cfg(not(target_family = "wasm"))]
@@ -461,6 +461,53 @@ impl Test {
         assert_eq!(expected, result, "Expected stack to be {:?}, found {:?}", expected, result);
     }
 
+    /// Executes the test and asserts that the program emits exactly the specified sequence of
+    /// events, including system events.
+    ///
+    /// On mismatch, the assertion message lists the expected and emitted sequences side by side.
+    /// Emitted events are shown by name when the name is known from `names`, the test's event
+    /// handlers, or the set of system events, and by ID otherwise.
+    #[cfg(not(target_family = "wasm"))]
+    #[track_caller]
+    pub fn expect_events(&self, names: &[EventName]) {
+        let trace = self
+            .execute()
+            .inspect_err(|_err| {
+                #[cfg(feature = "std")]
+                std::eprintln!("{}", PrintDiagnostic::new_without_color(_err))
+            })
+            .expect("failed to execute");
+
+        let expected = names.iter().map(EventName::to_event_id).collect::<Vec<_>>();
+        let emitted = trace.emitted_event_ids();
+        if expected == emitted {
+            return;
+        }
+
+        let describe = |event_id: EventId| {
+            names
+                .iter()
+                .chain(self.handlers.iter().map(|(name, _)| name))
+                .find(|name| name.to_event_id() == event_id)
+                .cloned()
+                .or_else(|| SystemEvent::from_event_id(event_id).map(|event| event.event_name()))
+                .map(|name| name.to_string())
+                .unwrap_or_else(|| format!("<unknown event {event_id}>"))
+        };
+        let mut diff = String::new();
+        for idx in 0..expected.len().max(emitted.len()) {
+            let expected = expected.get(idx).map(|&id| describe(id));
+            let emitted = emitted.get(idx).map(|&id| describe(id));
+            let marker = if expected == emitted { ' ' } else { '!' };
+            diff.push_str(&format!(
+                "{marker} {idx}: expected {}, emitted {}\n",
+                expected.as_deref().unwrap_or("nothing"),
+                emitted.as_deref().unwrap_or("nothing"),
+            ));
+        }
+        panic!("emitted events do not match the expected sequence:\n{diff}");
+    }
+
     // UTILITY METHODS
     // --------------------------------------------------------------------------------------------
 
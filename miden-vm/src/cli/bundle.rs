@@ -2,7 +2,7 @@ use std::path::PathBuf;
 
 use clap::Parser;
 use miden_assembly::{
-    Assembler, Linkage, PathBuf as LibraryPath, ast,
+    Assembler, Linkage, Lint, LintLevel, PathBuf as LibraryPath, ast,
     diagnostics::{IntoDiagnostic, Report},
 };
 use miden_core_lib::CoreLibrary;
@@ -35,6 +35,12 @@ pub struct BundleCmd {
     /// Path of the output `.masp` file.
     #[arg(short, long)]
     output: Option<PathBuf>,
+    /// Treat warnings, including the findings of lints, as errors.
+    #[arg(long = "deny-warnings")]
+    deny_warnings: bool,
+    /// Report the findings of the given lint as warnings, e.g. `unused_procedure`.
+    #[arg(short = 'W', long = "warn", value_name = "LINT")]
+    warn: Vec<Lint>,
 }
 
 impl BundleCmd {
@@ -43,7 +49,10 @@ impl BundleCmd {
         println!("Build library");
         println!("============================================================");
 
-        let mut assembler = Assembler::default();
+        let mut assembler = Assembler::default().with_warnings_as_errors(self.deny_warnings);
+        for lint in self.warn.iter() {
+            assembler = assembler.with_lint_level(*lint, LintLevel::Warn);
+        }
 
         if !self.root.is_file() {
             return Err(Report::msg("`root` must be a '.masm' file."));
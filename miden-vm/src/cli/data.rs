@@ -9,12 +9,14 @@ use miden_assembly::{
     Assembler, DefaultSourceManager, Path as LibraryPath, SourceManager,
     ast::{Module, ModuleKind},
     diagnostics::{Report, WrapErr},
+    linker::{LinkLibrary, Linker},
     report,
     serde::Deserializable,
 };
 use miden_core::{Felt, field::QuotientMap};
 use miden_core_lib::CoreLibrary;
 use miden_mast_package::Package;
+use miden_processor::HostLibrary;
 use miden_vm::{ExecutionProof, Program, StackOutputs, Word, serde::SliceReader};
 #[cfg(feature = "arbitrary")]
 use proptest::prelude::*;
@@ -297,4 +299,29 @@ impl Libraries {
 
         Ok(Self { libraries })
     }
+
+    /// Returns the libraries to load into the host executing a program linked against them.
+    ///
+    /// When there are several libraries, their MAST forests are merged into a single forest, so
+    /// that the procedures shared by several of the libraries are loaded only once. The package
+    /// debug info of the libraries is not carried over to the merged forest.
+    pub fn host_libraries(&self) -> Result<Vec<HostLibrary>, Report> {
+        if self.libraries.len() < 2 {
+            return Ok(self.libraries.iter().cloned().map(HostLibrary::from).collect());
+        }
+
+        let mut linker = Linker::new(Arc::new(DefaultSourceManager::default()));
+        for library in self.libraries.iter().cloned() {
+            linker
+                .link_library(
+                    LinkLibrary::from_package(library)
+                        .with_linkage(miden_assembly::Linkage::Dynamic),
+                )
+                .wrap_err("Failed to load libraries")?;
+        }
+        let deduplicated =
+            linker.deduplicate_libraries().wrap_err("Failed to merge library forests")?;
+
+        Ok(vec![HostLibrary::from(deduplicated.mast_forest())])
+    }
 }
@@ -0,0 +1,515 @@
+//! A [Debug Adapter Protocol](https://microsoft.github.io/debug-adapter-protocol/) server for
+//! Miden Assembly programs.
+//!
+//! The adapter talks DAP over stdin/stdout, which allows editors such as VS Code to set line
+//! breakpoints in `.masm` files, step through a program line by line, and inspect the operand stack
+//! and memory of the VM while it is paused.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    io::{self, BufRead, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use clap::Parser;
+use miden_assembly::{
+    DefaultSourceManager, SourceManager,
+    diagnostics::{IntoDiagnostic, Report, WrapErr},
+};
+use miden_core_lib::CoreLibrary;
+use miden_processor::{DefaultHost, ExecutionOptions, FastProcessor, ResumeContext};
+use miden_vm::internal::InputFile;
+use serde_json::{Value, json};
+
+use super::{data::Libraries, utils::get_masm_program};
+
+/// The identifier of the single thread reported to the client.
+const THREAD_ID: u64 = 1;
+
+/// The variables reference of the operand stack scope.
+const STACK_REFERENCE: u64 = 1;
+
+/// The variables reference of the memory scope.
+const MEMORY_REFERENCE: u64 = 2;
+
+#[derive(Debug, Clone, Parser)]
+#[command(about = "Start a Debug Adapter Protocol server communicating over stdin/stdout")]
+pub struct DebugAdapterCmd {}
+
+impl DebugAdapterCmd {
+    pub fn execute(&self) -> Result<(), Report> {
+        let stdin = io::stdin();
+        let stdout = io::stdout();
+        DebugSession::new(stdin.lock(), stdout.lock()).run()
+    }
+}
+
+// DEBUG SESSION
+// ================================================================================================
+
+/// The state of a single debugging session.
+///
+/// The session executes the program on the thread which handles the DAP requests, so a request to
+/// resume execution is only answered once the program stops again.
+struct DebugSession<R, W> {
+    reader: R,
+    writer: W,
+    /// The sequence number of the next message sent to the client.
+    seq: u64,
+    /// Lines with a breakpoint, keyed by the canonical path of their source file.
+    breakpoints: BTreeMap<PathBuf, BTreeSet<u32>>,
+    /// Whether the client finished configuring the session, i.e. sent `configurationDone`.
+    configured: bool,
+    program: Option<LaunchedProgram>,
+}
+
+/// What to do once the response to a request was sent.
+enum FollowUp {
+    Nothing,
+    Initialized,
+    Start,
+    Resume(RunMode),
+    Disconnect,
+}
+
+/// How far to run the program before stopping.
+#[derive(Debug, Clone, Copy)]
+enum RunMode {
+    /// Run until a line with a breakpoint is entered.
+    Continue,
+    /// Run until a different source line is entered.
+    Step,
+}
+
+impl<R: BufRead, W: Write> DebugSession<R, W> {
+    fn new(reader: R, writer: W) -> Self {
+        Self {
+            reader,
+            writer,
+            seq: 1,
+            breakpoints: BTreeMap::new(),
+            configured: false,
+            program: None,
+        }
+    }
+
+    /// Handles requests until the client disconnects or closes the input stream.
+    fn run(&mut self) -> Result<(), Report> {
+        while let Some(message) = self.read_message()? {
+            if message["type"] != "request" {
+                continue;
+            }
+
+            let command = message["command"].as_str().unwrap_or_default().to_string();
+            let follow_up = match self.handle_request(&command, &message["arguments"]) {
+                Ok((body, follow_up)) => {
+                    self.send_response(&message, true, None, body)?;
+                    follow_up
+                },
+                Err(err) => {
+                    self.send_response(&message, false, Some(err), Value::Null)?;
+                    FollowUp::Nothing
+                },
+            };
+
+            match follow_up {
+                FollowUp::Nothing => {},
+                FollowUp::Initialized => self.send_event("initialized", json!({}))?,
+                FollowUp::Start => self.start()?,
+                FollowUp::Resume(mode) => self.resume(mode)?,
+                FollowUp::Disconnect => break,
+            }
+        }
+
+        Ok(())
+    }
+
+    // REQUEST HANDLERS
+    // --------------------------------------------------------------------------------------------
+
+    fn handle_request(&mut self, command: &str, args: &Value) -> Result<(Value, FollowUp), String> {
+        match command {
+            "initialize" => Ok((
+                json!({
+                    "supportsConfigurationDoneRequest": true,
+                    "supportsTerminateRequest": true,
+                }),
+                FollowUp::Initialized,
+            )),
+            "launch" => {
+                self.program = Some(LaunchedProgram::launch(args).map_err(|err| format!("{err}"))?);
+                Ok((Value::Null, self.start_if_ready()))
+            },
+            "setBreakpoints" => Ok((self.set_breakpoints(args)?, FollowUp::Nothing)),
+            "configurationDone" => {
+                self.configured = true;
+                Ok((Value::Null, self.start_if_ready()))
+            },
+            "threads" => {
+                Ok((json!({ "threads": [{ "id": THREAD_ID, "name": "main" }] }), FollowUp::Nothing))
+            },
+            "stackTrace" => Ok((self.stack_trace()?, FollowUp::Nothing)),
+            "scopes" => Ok((
+                json!({ "scopes": [
+                    { "name": "Stack", "variablesReference": STACK_REFERENCE, "expensive": false },
+                    { "name": "Memory", "variablesReference": MEMORY_REFERENCE, "expensive": false },
+                ]}),
+                FollowUp::Nothing,
+            )),
+            "variables" => Ok((self.variables(args)?, FollowUp::Nothing)),
+            "continue" => {
+                Ok((json!({ "allThreadsContinued": true }), FollowUp::Resume(RunMode::Continue)))
+            },
+            "next" => Ok((Value::Null, FollowUp::Resume(RunMode::Step))),
+            "disconnect" | "terminate" => Ok((Value::Null, FollowUp::Disconnect)),
+            _ => Err(format!("unsupported request `{command}`")),
+        }
+    }
+
+    /// Returns [`FollowUp::Start`] once the program was launched and the session configured.
+    fn start_if_ready(&self) -> FollowUp {
+        if self.configured && self.program.is_some() {
+            FollowUp::Start
+        } else {
+            FollowUp::Nothing
+        }
+    }
+
+    fn set_breakpoints(&mut self, args: &Value) -> Result<Value, String> {
+        let path = args["source"]["path"]
+            .as_str()
+            .ok_or_else(|| "setBreakpoints requires a source path".to_string())?;
+        let lines: BTreeSet<u32> = args["breakpoints"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|breakpoint| breakpoint["line"].as_u64())
+            .filter_map(|line| u32::try_from(line).ok())
+            .collect();
+
+        let breakpoints: Vec<Value> =
+            lines.iter().map(|line| json!({ "verified": true, "line": line })).collect();
+        self.breakpoints.insert(canonicalize(Path::new(path)), lines);
+
+        Ok(json!({ "breakpoints": breakpoints }))
+    }
+
+    fn stack_trace(&mut self) -> Result<Value, String> {
+        let program = self.program.as_mut().ok_or_else(|| "no program was launched".to_string())?;
+        let clk = program.processor.state().clock();
+
+        let mut frame = json!({
+            "id": 0,
+            "name": format!("clk {clk}"),
+            "line": 0,
+            "column": 0,
+        });
+        if let Some((path, line)) = program.current_line() {
+            frame["source"] = json!({
+                "name": path.file_name().map(|name| name.to_string_lossy()),
+                "path": path.display().to_string(),
+            });
+            frame["line"] = json!(line);
+            frame["column"] = json!(1);
+        }
+
+        Ok(json!({ "stackFrames": [frame], "totalFrames": 1 }))
+    }
+
+    fn variables(&self, args: &Value) -> Result<Value, String> {
+        let processor = &self.launched_program()?.processor;
+
+        let variables: Vec<Value> = match args["variablesReference"].as_u64() {
+            Some(STACK_REFERENCE) => processor
+                .stack()
+                .iter()
+                .rev()
+                .enumerate()
+                .map(|(idx, value)| variable(format!("[{idx}]"), value))
+                .collect(),
+            Some(MEMORY_REFERENCE) => processor
+                .memory()
+                .get_memory_state(processor.state().ctx())
+                .into_iter()
+                .map(|(addr, value)| variable(addr.to_string(), &value))
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        Ok(json!({ "variables": variables }))
+    }
+
+    fn launched_program(&self) -> Result<&LaunchedProgram, String> {
+        self.program.as_ref().ok_or_else(|| "no program was launched".to_string())
+    }
+
+    // EXECUTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Starts executing the launched program, or stops on its entry if requested.
+    fn start(&mut self) -> Result<(), Report> {
+        if self.program.as_ref().is_some_and(|program| program.stop_on_entry) {
+            return self.send_stopped("entry", None);
+        }
+        self.resume(RunMode::Continue)
+    }
+
+    /// Executes the program until it stops according to `mode`, or terminates.
+    fn resume(&mut self, mode: RunMode) -> Result<(), Report> {
+        let Some(program) = self.program.as_mut() else {
+            return Ok(());
+        };
+
+        let mut last_line = program.current_line();
+        let stop = loop {
+            let Some(resume_ctx) = program.resume_ctx.take() else {
+                break Stop::Exited(program.error.is_some());
+            };
+
+            match program.processor.step_sync(&mut program.host, resume_ctx) {
+                Ok(Some(resume_ctx)) => program.resume_ctx = Some(resume_ctx),
+                Ok(None) => break Stop::Exited(false),
+                Err(err) => {
+                    let message = format!("{err}");
+                    program.error = Some(message.clone());
+                    break Stop::Exception(message);
+                },
+            }
+
+            let line = program.current_line();
+            if line.is_none() || line == last_line {
+                continue;
+            }
+            last_line = line;
+
+            let (path, line) = last_line.as_ref().expect("line was checked above");
+            match mode {
+                RunMode::Step => break Stop::Step,
+                RunMode::Continue
+                    if self.breakpoints.get(path).is_some_and(|lines| lines.contains(line)) =>
+                {
+                    break Stop::Breakpoint;
+                },
+                RunMode::Continue => {},
+            }
+        };
+
+        match stop {
+            Stop::Step => self.send_stopped("step", None),
+            Stop::Breakpoint => self.send_stopped("breakpoint", None),
+            Stop::Exception(message) => self.send_stopped("exception", Some(message)),
+            Stop::Exited(failed) => {
+                if !failed {
+                    let outputs: Vec<String> = program
+                        .processor
+                        .stack_top()
+                        .iter()
+                        .rev()
+                        .map(ToString::to_string)
+                        .collect();
+                    self.send_event(
+                        "output",
+                        json!({ "category": "stdout", "output": format!("Output: [{}]\n", outputs.join(", ")) }),
+                    )?;
+                }
+                self.send_event("exited", json!({ "exitCode": u8::from(failed) }))?;
+                self.send_event("terminated", json!({}))
+            },
+        }
+    }
+
+    // MESSAGES
+    // --------------------------------------------------------------------------------------------
+
+    /// Reads the next message sent by the client, or returns `None` if the input stream was closed.
+    fn read_message(&mut self) -> Result<Option<Value>, Report> {
+        let mut content_length = None;
+        loop {
+            let mut line = String::new();
+            if self.reader.read_line(&mut line).into_diagnostic()? == 0 {
+                return Ok(None);
+            }
+
+            let line = line.trim_end();
+            if let Some(value) = line.strip_prefix("Content-Length:") {
+                let value = value.trim().parse::<usize>().into_diagnostic()?;
+                content_length = Some(value);
+            } else if line.is_empty() && content_length.is_some() {
+                break;
+            }
+        }
+
+        let mut body = vec![0; content_length.expect("header was read above")];
+        self.reader.read_exact(&mut body).into_diagnostic()?;
+        serde_json::from_slice(&body)
+            .into_diagnostic()
+            .wrap_err("Failed to parse debug adapter message")
+            .map(Some)
+    }
+
+    fn send_response(
+        &mut self,
+        request: &Value,
+        success: bool,
+        message: Option<String>,
+        body: Value,
+    ) -> Result<(), Report> {
+        let mut response = json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "success": success,
+            "command": request["command"],
+            "body": body,
+        });
+        if let Some(message) = message {
+            response["message"] = json!(message);
+        }
+        self.send(response)
+    }
+
+    fn send_stopped(&mut self, reason: &str, text: Option<String>) -> Result<(), Report> {
+        let mut body = json!({
+            "reason": reason,
+            "threadId": THREAD_ID,
+            "allThreadsStopped": true,
+        });
+        if let Some(text) = text {
+            body["text"] = json!(text);
+        }
+        self.send_event("stopped", body)
+    }
+
+    fn send_event(&mut self, event: &str, body: Value) -> Result<(), Report> {
+        self.send(json!({ "type": "event", "event": event, "body": body }))
+    }
+
+    fn send(&mut self, mut message: Value) -> Result<(), Report> {
+        message["seq"] = json!(self.seq);
+        self.seq += 1;
+
+        let message = message.to_string();
+        write!(self.writer, "Content-Length: {}\r\n\r\n{message}", message.len())
+            .and_then(|_| self.writer.flush())
+            .into_diagnostic()
+            .wrap_err("Failed to send debug adapter message")
+    }
+}
+
+/// The reason execution stopped.
+enum Stop {
+    Step,
+    Breakpoint,
+    Exception(String),
+    /// The program finished; the flag is set if it failed.
+    Exited(bool),
+}
+
+// LAUNCHED PROGRAM
+// ================================================================================================
+
+/// A program being debugged, along with the state of its execution.
+struct LaunchedProgram {
+    processor: FastProcessor,
+    host: DefaultHost<DefaultSourceManager>,
+    source_manager: Arc<DefaultSourceManager>,
+    /// The context from which to continue execution, or `None` if the program completed or
+    /// failed.
+    resume_ctx: Option<ResumeContext>,
+    /// The error the program failed with, if any.
+    error: Option<String>,
+    stop_on_entry: bool,
+    /// The canonical paths of the source files seen so far, keyed by URI.
+    source_paths: BTreeMap<String, PathBuf>,
+}
+
+impl LaunchedProgram {
+    /// Compiles the `.masm` program described by the `launch` request arguments and prepares it
+    /// for execution.
+    fn launch(args: &Value) -> Result<Self, Report> {
+        let program_path = args["program"]
+            .as_str()
+            .map(|path| canonicalize(Path::new(path)))
+            .ok_or_else(|| Report::msg("launch requires a `program` path"))?;
+        let input_path = args["inputs"].as_str().map(PathBuf::from);
+        let library_paths: Vec<PathBuf> = args["libraries"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|path| path.as_str().map(PathBuf::from))
+            .collect();
+
+        let libraries = Libraries::new(&library_paths)?;
+        let (program, package_debug_info, entrypoint_source_node, source_manager) =
+            get_masm_program(&program_path, &libraries, None)?;
+        let input_data = InputFile::read(&input_path, &program_path)?;
+        let stack_inputs = input_data.parse_stack_inputs().map_err(Report::msg)?;
+        let advice_inputs = input_data.parse_advice_inputs().map_err(Report::msg)?;
+
+        let mut host = DefaultHost::default().with_source_manager(source_manager.clone());
+        host.load_library(&CoreLibrary::default())
+            .into_diagnostic()
+            .wrap_err("Failed to load core library")?;
+        for lib in libraries.host_libraries()? {
+            host.load_library(lib).into_diagnostic().wrap_err("Failed to load library")?;
+        }
+
+        let mut processor = FastProcessor::new_with_options(
+            stack_inputs,
+            advice_inputs,
+            ExecutionOptions::default(),
+        )
+        .map_err(|err| Report::msg(format!("{err}")))?;
+        let package_debug_info = package_debug_info
+            .ok_or_else(|| Report::msg("The compiled program has no debug info"))?;
+        let resume_ctx = processor
+            .get_initial_resume_context_with_package_debug_info(
+                &program,
+                &package_debug_info,
+                entrypoint_source_node,
+            )
+            .map_err(|err| Report::msg(format!("{err}")))?;
+
+        Ok(Self {
+            processor,
+            host,
+            source_manager,
+            resume_ctx: Some(resume_ctx),
+            error: None,
+            stop_on_entry: args["stopOnEntry"].as_bool().unwrap_or(false),
+            source_paths: BTreeMap::new(),
+        })
+    }
+
+    /// Returns the canonical path of the source file and the one-indexed line of the next
+    /// operation to execute, if known.
+    fn current_line(&mut self) -> Option<(PathBuf, u32)> {
+        let location = self.resume_ctx.as_ref()?.source_location()?;
+        let source_file = self.source_manager.get_by_uri(&location.uri)?;
+        let line = source_file.content().location(location.start)?.line;
+
+        let path = match self.source_paths.get(location.uri.as_str()) {
+            Some(path) => path.clone(),
+            None => {
+                let path = canonicalize(&location.uri.to_path()?);
+                self.source_paths.insert(location.uri.as_str().to_string(), path.clone());
+                path
+            },
+        };
+
+        Some((path, line.to_u32()))
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the canonical form of `path`, or `path` itself if it cannot be canonicalized.
+fn canonicalize(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn variable(name: String, value: &impl ToString) -> Value {
+    json!({ "name": name, "value": value.to_string(), "variablesReference": 0 })
+}
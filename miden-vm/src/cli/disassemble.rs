@@ -0,0 +1,71 @@
+use std::{fs, path::PathBuf};
+
+use clap::Parser;
+use miden_assembly::{
+    Disassembler,
+    diagnostics::{IntoDiagnostic, Report, WrapErr},
+};
+use miden_core::program::Program;
+use miden_mast_package::Package;
+use miden_prover::serde::Deserializable;
+
+#[derive(Debug, Clone, Parser)]
+#[command(about = "Reconstruct Miden assembly from a compiled program or package")]
+pub struct DisassembleCmd {
+    /// Path to a .masp package file or a .masb program file
+    #[arg(value_parser)]
+    input_file: PathBuf,
+    /// Path to output file, otherwise the assembly is written to stdout
+    #[arg(short = 'o', long = "output", value_parser)]
+    output_file: Option<PathBuf>,
+    /// Render packages from the assembly instructions recorded in their debug information.
+    ///
+    /// Debug sections are only read from trusted packages, as the recorded instructions are not
+    /// checked against the operations of the MAST they describe.
+    #[arg(long = "trust-debug-info")]
+    trust_debug_info: bool,
+}
+
+impl DisassembleCmd {
+    pub fn execute(&self) -> Result<(), Report> {
+        let ext = self
+            .input_file
+            .extension()
+            .and_then(|s| s.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        let masm = match ext.as_str() {
+            "masp" => {
+                let package = if self.trust_debug_info {
+                    Package::deserialize_from_file_trusted(&self.input_file)
+                } else {
+                    Package::deserialize_from_file(&self.input_file)
+                };
+                let package =
+                    package.into_diagnostic().wrap_err("Failed to deserialize package")?;
+                Disassembler::from_package(&package)?.disassemble()
+            },
+            "masb" => {
+                let bytes = fs::read(&self.input_file).into_diagnostic().wrap_err_with(|| {
+                    format!("Failed to read program `{}`", self.input_file.display())
+                })?;
+                let program = Program::read_from_bytes(&bytes)
+                    .into_diagnostic()
+                    .wrap_err("Failed to deserialize program")?;
+                Disassembler::from_program(&program).disassemble()
+            },
+            _ => return Err(Report::msg("The provided file must have a .masp or .masb extension")),
+        };
+
+        match &self.output_file {
+            Some(output_file) => fs::write(output_file, masm)
+                .into_diagnostic()
+                .wrap_err("Failed to write the disassembled file"),
+            None => {
+                print!("{masm}");
+                Ok(())
+            },
+        }
+    }
+}
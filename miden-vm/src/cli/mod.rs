@@ -1,6 +1,8 @@
 mod bundle;
 mod compile;
 pub mod data;
+mod debug_adapter;
+mod disassemble;
 
 mod prove;
 mod run;
@@ -9,6 +11,8 @@ mod verify;
 
 pub use bundle::BundleCmd;
 pub use compile::CompileCmd;
+pub use debug_adapter::DebugAdapterCmd;
+pub use disassemble::DisassembleCmd;
 pub use prove::ProveCmd;
 pub use run::RunCmd;
 pub use verify::VerifyCmd;
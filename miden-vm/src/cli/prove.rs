@@ -49,7 +49,7 @@ pub struct ProveCmd {
     proof_file: Option<PathBuf>,
 
     /// Specifies the hash function to be used
-    /// Valid options: blake3-256, rpo, rpx, poseidon2
+    /// Valid options: blake3-192, blake3-256, rpo, rpx, poseidon2
     #[arg(long = "hasher", default_value = "blake3-256")]
     hasher: String,
 
@@ -121,7 +121,7 @@ impl ProveCmd {
                 let (program, package_debug_info, entrypoint_source_node, source_manager) =
                     get_masm_program(&self.program_file, &libraries, self.kernel_file.as_deref())?;
                 let mut host = host.with_source_manager(source_manager);
-                for library in libraries.libraries.iter().cloned() {
+                for library in libraries.host_libraries()? {
                     host.load_library(library)
                         .into_diagnostic()
                         .wrap_err("Failed to load library")?;
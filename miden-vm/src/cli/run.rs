@@ -1,10 +1,13 @@
 use std::{path::PathBuf, time::Instant};
 
 use clap::Parser;
-use miden_assembly::diagnostics::{IntoDiagnostic, Report, WrapErr};
-use miden_core_lib::CoreLibrary;
+use miden_assembly::{
+    SourceManager,
+    diagnostics::{IntoDiagnostic, Report, WrapErr},
+};
+use miden_core_lib::{CoreLibrary, handlers::debug::default_debug_handlers_with_printer};
 use miden_processor::{
-    DefaultHost, ExecutionOptions, FastProcessor,
+    DefaultHost, ExecutionOptions, FastProcessor, TreeConnectors, VmStatePrinter,
     trace::{ExecutionTrace, build_trace},
 };
 use miden_vm::internal::InputFile;
@@ -49,6 +52,14 @@ pub struct RunCmd {
     /// Path to a file (.masm or .masp) containing the kernel to be loaded with the program
     #[arg(long = "kernel", value_parser)]
     kernel_file: Option<PathBuf>,
+
+    /// Draw the trace summary and debug output with ASCII instead of Unicode connectors
+    #[arg(long = "ascii")]
+    ascii: bool,
+
+    /// Color the trace summary and debug output with ANSI escape sequences
+    #[arg(long = "color")]
+    color: bool,
 }
 
 impl RunCmd {
@@ -94,17 +105,19 @@ impl RunCmd {
             * 100
             / trace.trace_len_summary().padded_trace_len();
         // print the required cycles for each component
+        let printer = self.printer();
+        let (branch, last, vertical) = (printer.branch(), printer.last(), printer.vertical());
         println!(
             "VM cycles: {} extended to {} steps ({}% padding).
-├── Stack rows: {}
-├── Range checker rows: {}
-├── Chiplets rows: {}
-│   ├── Hash chiplet rows: {}
-│   ├── Bitwise chiplet rows: {}
-│   ├── Memory chiplet rows: {}
-│   ├── ACE chiplet rows: {}
-│   └── Kernel ROM rows: {}
-└── Poseidon2 permutation rows: {}",
+{branch}Stack rows: {}
+{branch}Range checker rows: {}
+{branch}Chiplets rows: {}
+{vertical}{branch}Hash chiplet rows: {}
+{vertical}{branch}Bitwise chiplet rows: {}
+{vertical}{branch}Memory chiplet rows: {}
+{vertical}{branch}ACE chiplet rows: {}
+{vertical}{last}Kernel ROM rows: {}
+{last}Poseidon2 permutation rows: {}",
             trace.trace_len_summary().trace_len(),
             trace.trace_len_summary().padded_trace_len(),
             padding_percentage,
@@ -121,6 +134,16 @@ impl RunCmd {
 
         Ok(())
     }
+
+    /// Returns the printer formatting the trace summary and the debug output of the program.
+    fn printer(&self) -> VmStatePrinter {
+        let connectors = if self.ascii {
+            TreeConnectors::Ascii
+        } else {
+            TreeConnectors::Unicode
+        };
+        VmStatePrinter::new().with_connectors(connectors).with_color(self.color)
+    }
 }
 
 // HELPER FUNCTIONS
@@ -136,6 +159,7 @@ fn run_masp_program(params: &RunCmd) -> Result<(ExecutionTrace, [u8; 32]), Repor
     let stack_inputs = input_data.parse_stack_inputs().map_err(Report::msg)?;
     let advice_inputs = input_data.parse_advice_inputs().map_err(Report::msg)?;
     let mut host = DefaultHost::default().with_library(&CoreLibrary::default())?;
+    use_debug_printer(&mut host, params.printer());
 
     let program_hash: [u8; 32] = program.hash().into();
 
@@ -191,7 +215,8 @@ fn run_masm_program(params: &RunCmd) -> Result<(ExecutionTrace, [u8; 32]), Repor
     host.load_library(&CoreLibrary::default())
         .into_diagnostic()
         .wrap_err("Failed to load core library")?;
-    for lib in libraries.libraries {
+    use_debug_printer(&mut host, params.printer());
+    for lib in libraries.host_libraries()? {
         host.load_library(lib).into_diagnostic().wrap_err("Failed to load library")?;
     }
 
@@ -227,3 +252,11 @@ fn run_masm_program(params: &RunCmd) -> Result<(ExecutionTrace, [u8; 32]), Repor
 
     Ok((trace, program_hash))
 }
+
+/// Replaces the debug handlers of the core library loaded into `host` with ones formatting the VM
+/// state with `printer`.
+fn use_debug_printer<S: SourceManager>(host: &mut DefaultHost<S>, printer: VmStatePrinter) {
+    for (event, handler) in default_debug_handlers_with_printer(printer) {
+        host.replace_handler(event, handler);
+    }
+}
@@ -10,13 +10,12 @@ pub use miden_assembly::{
     diagnostics,
 };
 pub use miden_core::proof::{DeferredProof, ExecutionProof, HashFunction, StarkProof};
-#[cfg(not(target_family = "wasm"))]
-pub use miden_processor::execute_sync;
 pub use miden_processor::{
-    BaseHost, DefaultHost, ExecutionError, ExecutionOptions, ExecutionOutput, FastProcessor,
-    FutureMaybeSend, Host, KernelDescriptor, Program, ProgramInfo, StackInputs, SyncHost,
-    TraceBuildInputs, TraceGenerationContext, ZERO, advice, crypto, execute, field,
-    operation::Operation, serde, trace, trace::ExecutionTrace, utils,
+    BaseHost, CancellationToken, DefaultHost, ExecutionError, ExecutionOptions, ExecutionOutput,
+    FastProcessor, FutureMaybeSend, Host, KernelDescriptor, Program, ProgramInfo, StackInputs,
+    SyncHost, TraceBuildInputs, TraceGenerationContext, ZERO, advice, crypto, execute,
+    execute_batch, execute_sync, field, operation::Operation, serde, trace, trace::ExecutionTrace,
+    utils,
 };
 pub use miden_prover::{InputError, ProvingOptions, StackOutputs, TraceProvingInputs, Word, prove};
 #[cfg(not(target_family = "wasm"))]
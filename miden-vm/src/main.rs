@@ -7,7 +7,7 @@ use miden_assembly::diagnostics::Report;
 #[cfg(feature = "tracing-forest")]
 use tracing_forest::ForestLayer;
 #[cfg(not(feature = "tracing-forest"))]
-use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::fmt::{format::FmtSpan, writer::BoxMakeWriter};
 use tracing_subscriber::{EnvFilter, prelude::*};
 
 mod cli;
@@ -99,6 +99,8 @@ pub enum Actions {
     Prove(cli::ProveCmd),
     Run(cli::RunCmd),
     Verify(cli::VerifyCmd),
+    DebugAdapter(cli::DebugAdapterCmd),
+    Disassemble(cli::DisassembleCmd),
 }
 
 /// CLI entry point
@@ -110,6 +112,8 @@ impl Cli {
             Actions::Prove(prove) => prove.execute(),
             Actions::Run(run) => run.execute(),
             Actions::Verify(verify) => verify.execute(),
+            Actions::DebugAdapter(debug_adapter) => debug_adapter.execute(),
+            Actions::Disassemble(disassemble) => disassemble.execute(),
         }
     }
 
@@ -150,6 +154,13 @@ pub fn main() -> Result<(), Report> {
             .with_ansi(false)
             .compact();
 
+        // the debug adapter talks to its client over stdout, so logs must not be written there
+        let format = if matches!(cli.action, Actions::DebugAdapter(_)) {
+            format.with_writer(BoxMakeWriter::new(std::io::stderr)).boxed()
+        } else {
+            format.boxed()
+        };
+
         registry.with(format).init();
     }
 
@@ -130,6 +130,27 @@ fn cli_bundle_debug() {
     fs::remove_file(&output_file).unwrap();
 }
 
+#[test]
+fn cli_disassemble_bundle() {
+    let bundle_file = std::env::temp_dir().join("cli_disassemble_bundle.masp");
+
+    let mut cmd = bin_under_test().command();
+    cmd.arg("bundle")
+        .arg("./tests/integration/cli/data/lib/mod.masm")
+        .arg("--namespace")
+        .arg("lib")
+        .arg("--output")
+        .arg(bundle_file.as_path());
+    cmd.assert().success();
+
+    let mut cmd = bin_under_test().command();
+    cmd.arg("disassemble").arg(bundle_file.as_path());
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("pub proc \"lib::lib_proc\"\n    swap.1\nend\n"));
+    fs::remove_file(&bundle_file).unwrap();
+}
+
 #[test]
 fn cli_bundle_no_exports() {
     let mut cmd = bin_under_test().command();
@@ -207,9 +228,80 @@ fn cli_run_with_lib() {
     fs::remove_file("cli_run_with_lib.masp").unwrap();
 }
 
+// Run a program linked against two libraries which define the same procedures, so that their
+// MAST forests are merged before being loaded into the host.
+#[test]
+fn cli_run_with_libs_sharing_procedures() {
+    for (namespace, output) in [
+        ("lib", "cli_run_with_shared_lib.masp"),
+        ("lib2", "cli_run_with_shared_lib2.masp"),
+    ] {
+        let mut cmd = bin_under_test().command();
+        cmd.arg("bundle")
+            .arg("./tests/integration/cli/data/lib/mod.masm")
+            .arg("--namespace")
+            .arg(namespace)
+            .arg("--output")
+            .arg(output);
+        cmd.assert().success();
+    }
+
+    let mut cmd = bin_under_test().command();
+    cmd.arg("run")
+        .arg("./tests/integration/cli/data/main.masm")
+        .arg("-l")
+        .arg("./cli_run_with_shared_lib.masp")
+        .arg("-l")
+        .arg("./cli_run_with_shared_lib2.masp");
+    cmd.assert().success();
+
+    fs::remove_file("cli_run_with_shared_lib.masp").unwrap();
+    fs::remove_file("cli_run_with_shared_lib2.masp").unwrap();
+}
+
 #[test]
 fn test_advmap_cli() {
     let mut cmd = bin_under_test().command();
     cmd.arg("run").arg("./tests/integration/cli/data/adv_map.masm");
     cmd.assert().success();
 }
+
+#[test]
+fn cli_debug_adapter_stops_at_breakpoint() {
+    let program_path = test_file_path("debug-adapter").with_extension("masm");
+    fs::write(&program_path, "begin\n    push.1\n    push.2\n    add\n    swap drop\nend\n")
+        .unwrap();
+    fs::write(program_path.with_extension("inputs"), r#"{"operand_stack": []}"#).unwrap();
+
+    let program = program_path.display();
+    let requests = [
+        r#"{"seq":1,"type":"request","command":"initialize","arguments":{}}"#.to_string(),
+        format!(
+            r#"{{"seq":2,"type":"request","command":"launch","arguments":{{"program":"{program}"}}}}"#
+        ),
+        format!(
+            r#"{{"seq":3,"type":"request","command":"setBreakpoints","arguments":{{"source":{{"path":"{program}"}},"breakpoints":[{{"line":4}}]}}}}"#
+        ),
+        r#"{"seq":4,"type":"request","command":"configurationDone"}"#.to_string(),
+        r#"{"seq":5,"type":"request","command":"stackTrace","arguments":{"threadId":1}}"#
+            .to_string(),
+        r#"{"seq":6,"type":"request","command":"continue","arguments":{"threadId":1}}"#.to_string(),
+        r#"{"seq":7,"type":"request","command":"disconnect"}"#.to_string(),
+    ];
+    let stdin: String = requests
+        .iter()
+        .map(|request| format!("Content-Length: {}\r\n\r\n{request}", request.len()))
+        .collect();
+
+    let mut cmd = assert_cmd::Command::from_std(bin_under_test().command());
+    cmd.arg("debug-adapter").write_stdin(stdin);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(r#""reason":"breakpoint""#))
+        .stdout(predicate::str::contains(r#""line":4"#))
+        .stdout(predicate::str::contains("Output: [3, "))
+        .stdout(predicate::str::contains(r#""event":"terminated""#));
+
+    fs::remove_file(program_path.with_extension("inputs")).unwrap();
+    fs::remove_file(program_path).unwrap();
+}
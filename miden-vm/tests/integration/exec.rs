@@ -2,7 +2,7 @@ use alloc::sync::Arc;
 use core::assert_matches;
 
 use miden_assembly::{Assembler, DefaultSourceManager};
-use miden_core::{ONE, Word, advice::AdviceMap, program::Program};
+use miden_core::{Felt, ONE, Word, advice::AdviceMap, program::Program};
 use miden_processor::{
     ExecutionOptions, StackInputs,
     advice::{AdviceError, AdviceInputs},
@@ -67,3 +67,20 @@ fn advice_map_loaded_before_execution() {
     )
     .unwrap();
 }
+
+#[test]
+fn assemble_and_execute_snippet() {
+    let program = Assembler::default().assemble_snippet("push.1 push.2 add swap drop").unwrap();
+
+    let mut host = DefaultHost::default();
+    let output = miden_processor::execute_sync(
+        &program,
+        StackInputs::default(),
+        AdviceInputs::default(),
+        &mut host,
+        ExecutionOptions::default(),
+    )
+    .unwrap();
+
+    assert_eq!(output.stack.get_element(0), Some(Felt::from_u32(3)));
+}
@@ -498,3 +498,30 @@ fn run_insert_mem_with_max_size(
     )?;
     Ok(())
 }
+
+#[test]
+fn advice_push_mapval_of_data_segment() {
+    let source = "
+    data LOOKUP = [
+        [1, 2, 3, 4],
+        [5, 6, 7, 8],
+    ]
+
+    begin
+        # the data segment holds 2 words
+        push.LOOKUP_LEN push.2 assert_eq
+
+        # load the words of the data segment onto the advice stack
+        push.LOOKUP adv.push_mapval dropw
+        # State Transition:
+        # advice_stack: [1, 2, 3, 4, 5, 6, 7, 8]
+
+        # move the words from the advice stack to the operand stack
+        adv_loadw swapw adv_loadw
+        # State Transition:
+        # stack: [5, 6, 7, 8, 1, 2, 3, 4]
+    end";
+
+    let test = build_test!(source, &[]);
+    test.expect_stack(&[5, 6, 7, 8, 1, 2, 3, 4]);
+}
@@ -5,17 +5,18 @@ use alloc::sync::Arc;
 use miden_assembly::{Assembler, DefaultSourceManager, Linkage};
 use miden_core::{
     Felt,
-    deferred::{DeferredState, TRUE_DIGEST},
-    proof::{DeferredProof, ExecutionProof},
+    deferred::{DeferredState, PrecompileRegistry, TRUE_DIGEST},
+    proof::{DeferredProof, ExecutionProof, StarkProof},
     utils::bytes_to_packed_u32_elements,
 };
 use miden_core_lib::CoreLibrary;
 use miden_processor::ExecutionOptions;
 use miden_prover::{
-    AdviceInputs, ProgramInfo, ProvingOptions, PublicInputs, StackInputs, StackOutputs, prove_sync,
+    AdviceInputs, ProgramInfo, ProvingOptions, PublicInputs, StackInputs, StackOutputs,
+    prove_partial_sync, prove_sync,
 };
 use miden_utils_testing::{recursive_verifier::generate_advice_inputs, stack_inputs_from_ints};
-use miden_verifier::Verifier;
+use miden_verifier::{Verifier, VerifierFailure, verify_stark_diagnostic};
 use miden_vm::{DefaultHost, HashFunction};
 
 fn masm_push_felts(felts: &[Felt]) -> String {
@@ -196,6 +197,113 @@ fn test_keccak_precompile_wrapper_prove_verify_final() {
         .expect("Verification failed");
 }
 
+#[test]
+fn test_keccak_precompile_wrapper_partial_proof_hydrates_under_core_lib_registry() {
+    let core_lib = CoreLibrary::default();
+    let input: Vec<u8> = (0u8..32).collect();
+    let input = masm_push_felts(&bytes_to_packed_u32_elements(&input));
+    let source = format!(
+        "
+        begin
+            {input}
+            exec.::miden::core::crypto::hashes::keccak256::hash
+            dropw dropw
+        end
+        "
+    );
+    let program = Assembler::default()
+        .with_package(core_lib.package(), Linkage::Dynamic)
+        .expect("failed to link core library")
+        .assemble_program("keccak_precompile_wrapper_test", &source)
+        .expect("failed to assemble Keccak precompile wrapper test")
+        .unwrap_program();
+    let stack_inputs = StackInputs::default();
+    let mut host = DefaultHost::default()
+        .with_library(&core_lib)
+        .expect("failed to load CoreLibrary into the host");
+
+    let (stack_outputs, proof) = prove_partial_sync(
+        &program,
+        stack_inputs,
+        AdviceInputs::default(),
+        &mut host,
+        ExecutionOptions::default(),
+        ProvingOptions::with_96_bit_security(HashFunction::Blake3_256),
+    )
+    .expect("Keccak precompile wrapper should prove");
+    let DeferredProof::Wire(wire) = proof.deferred_proof() else {
+        panic!("partial proof should carry the deferred wire");
+    };
+
+    let registry = Arc::new(PrecompileRegistry::from(&core_lib));
+    let hydrated = DeferredState::from_wire(registry, wire, usize::MAX)
+        .expect("deferred wire should hydrate under the core library registry");
+    let (_, verified_state) = Verifier::new()
+        .verify_partial(program.to_info(), stack_inputs, stack_outputs, proof)
+        .expect("wire-backed partial proof should verify");
+    assert_eq!(hydrated.root(), verified_state.root());
+}
+
+#[test]
+fn test_keccak_precompile_wrapper_final_digest_matches_partial_root() {
+    let core_lib = CoreLibrary::default();
+    let input: Vec<u8> = (0u8..32).collect();
+    let input = masm_push_felts(&bytes_to_packed_u32_elements(&input));
+    let source = format!(
+        "
+        begin
+            {input}
+            exec.::miden::core::crypto::hashes::keccak256::hash
+            dropw dropw
+        end
+        "
+    );
+    let program = Assembler::default()
+        .with_package(core_lib.package(), Linkage::Dynamic)
+        .expect("failed to link core library")
+        .assemble_program("keccak_precompile_wrapper_test", &source)
+        .expect("failed to assemble Keccak precompile wrapper test")
+        .unwrap_program();
+    let stack_inputs = StackInputs::default();
+    let mut host = DefaultHost::default()
+        .with_library(&core_lib)
+        .expect("failed to load CoreLibrary into the host");
+    let proving_options = ProvingOptions::with_96_bit_security(HashFunction::Blake3_256);
+
+    let (stack_outputs, final_proof) = prove_sync(
+        &program,
+        stack_inputs,
+        AdviceInputs::default(),
+        &mut host,
+        ExecutionOptions::default(),
+        proving_options.clone(),
+    )
+    .expect("Keccak precompile wrapper should prove");
+    let (partial_outputs, partial_proof) = prove_partial_sync(
+        &program,
+        stack_inputs,
+        AdviceInputs::default(),
+        &mut host,
+        ExecutionOptions::default(),
+        proving_options,
+    )
+    .expect("Keccak precompile wrapper should prove");
+
+    let security_level = Verifier::new()
+        .verify(program.to_info(), stack_inputs, stack_outputs, final_proof.clone())
+        .expect("final proof should verify");
+    let (digest_security_level, digest) = Verifier::new()
+        .verify_returning_digest(program.to_info(), stack_inputs, stack_outputs, final_proof)
+        .expect("final proof should verify");
+    let (_, verified_state) = Verifier::new()
+        .verify_partial(program.to_info(), stack_inputs, partial_outputs, partial_proof)
+        .expect("wire-backed partial proof should verify");
+
+    assert_eq!(digest_security_level, security_level);
+    assert_ne!(digest, TRUE_DIGEST);
+    assert_eq!(digest, verified_state.root());
+}
+
 #[test]
 fn test_blake3_256_prove_verify() {
     // Compute many Fibonacci iterations to generate a trace >= 2048 rows
@@ -210,6 +318,19 @@ fn test_blake3_256_prove_verify() {
     assert_prove_verify(source, HashFunction::Blake3_256, "Blake3_256", false, false);
 }
 
+#[test]
+fn test_blake3_192_prove_verify() {
+    let source = "
+        begin
+            repeat.1000
+                swap dup.1 add
+            end
+        end
+    ";
+
+    assert_prove_verify(source, HashFunction::Blake3_192, "Blake3_192", false, false);
+}
+
 #[test]
 fn test_keccak_prove_verify() {
     // Compute 150th Fibonacci number to generate a longer trace
@@ -326,6 +447,41 @@ fn test_rpx_prove_verify() {
     assert_prove_verify(source, HashFunction::Rpx256, "RPX", true, false);
 }
 
+#[test]
+fn test_diagnostic_verify_reports_commitment_failure_for_tampered_proof() {
+    let program = Assembler::default()
+        .assemble_program("program", "begin repeat.100 swap dup.1 add end end")
+        .unwrap()
+        .unwrap_program();
+    let stack_inputs = stack_inputs_from_ints([0, 1]);
+    let mut host =
+        DefaultHost::default().with_source_manager(Arc::new(DefaultSourceManager::default()));
+    let (stack_outputs, proof) = prove_sync(
+        &program,
+        stack_inputs,
+        AdviceInputs::default(),
+        &mut host,
+        ExecutionOptions::default(),
+        ProvingOptions::with_96_bit_security(HashFunction::Poseidon2),
+    )
+    .expect("Proving failed");
+
+    verify_stark_diagnostic(program.to_info(), stack_inputs, stack_outputs, &proof)
+        .expect("untampered proof should verify");
+
+    // The tail of the proof holds the Merkle openings of the last FRI query
+    let (mut bytes, hash_fn) = proof.miden_proof().clone().into_parts();
+    *bytes.last_mut().unwrap() ^= 1;
+    let tampered = ExecutionProof::new(StarkProof::new(bytes, hash_fn), DeferredProof::Empty);
+
+    let err = verify_stark_diagnostic(program.to_info(), stack_inputs, stack_outputs, &tampered)
+        .unwrap_err();
+    assert!(
+        matches!(err, VerifierFailure::Commitment(_)),
+        "expected tampered opening to fail the commitment check, got {err:?}"
+    );
+}
+
 // ================================================================================================
 // FAST PROCESSOR + PARALLEL TRACE GENERATION TESTS
 // ================================================================================================
@@ -18,7 +18,9 @@ use miden_lifted_stark::{Preprocessed, StarkConfig, lmcs::Lmcs};
 use super::prove::ChipletMultiAir;
 use crate::{
     logup::NUM_PUBLIC_VALUES,
-    stark_config::{Blake3Config, KeccakConfig, Poseidon2Config, RpoConfig, RpxConfig},
+    stark_config::{
+        Blake3_192Config, Blake3Config, KeccakConfig, Poseidon2Config, RpoConfig, RpxConfig,
+    },
 };
 
 /// Either a process-cached (`std`) or freshly built (`no_std`) bundle;
@@ -87,6 +89,7 @@ macro_rules! cached_preprocessed {
     };
 }
 
+cached_preprocessed!(blake3_192, Blake3_192Config);
 cached_preprocessed!(blake3, Blake3Config);
 cached_preprocessed!(rpo, RpoConfig);
 cached_preprocessed!(rpx, RpxConfig);
@@ -50,7 +50,7 @@ use crate::{
     primitives::byte_pair_lut::BytePairLutAir,
     session::{NUM_CHIPLETS, SessionTraces, fixed_ecgroup_msgs, fixed_uintval_msgs},
     stark_config::{
-        DEFAULT_HASH_FUNCTION, RelationDigest, blake3_256_config, keccak_config,
+        DEFAULT_HASH_FUNCTION, RelationDigest, blake3_192_config, blake3_256_config, keccak_config,
         observe_protocol_params, poseidon2_config, precompile_pcs_params, rpo_config, rpx_config,
         test_challenger,
     },
@@ -295,6 +295,11 @@ impl SessionTraces {
     pub fn prove_stark(self, hash_fn: HashFunction) -> Result<StarkProof, ProveError> {
         let params = precompile_pcs_params();
         match hash_fn {
+            HashFunction::Blake3_192 => {
+                let config = blake3_192_config(params, PLACEHOLDER_RELATION_DIGEST);
+                let preprocessed = preprocessed_cache::blake3_192(&config);
+                self.prove_stark_with_config(&config, &preprocessed, hash_fn)
+            },
             HashFunction::Blake3_256 => {
                 let config = blake3_256_config(params, PLACEHOLDER_RELATION_DIGEST);
                 let preprocessed = preprocessed_cache::blake3(&config);
@@ -385,6 +390,11 @@ pub fn verify_deferred(proof: &DeferredProof) -> Result<DeferredRoot, VerifyErro
 pub fn verify_stark(proof: &StarkProof, public_root: P2Digest) -> Result<(), VerifyError> {
     let params = precompile_pcs_params();
     match proof.hash_fn() {
+        HashFunction::Blake3_192 => {
+            let config = blake3_192_config(params, PLACEHOLDER_RELATION_DIGEST);
+            let preprocessed = preprocessed_cache::blake3_192(&config);
+            verify_stark_with_config(&config, &preprocessed, proof.bytes(), public_root)
+        },
         HashFunction::Blake3_256 => {
             let config = blake3_256_config(params, PLACEHOLDER_RELATION_DIGEST);
             let preprocessed = preprocessed_cache::blake3(&config);
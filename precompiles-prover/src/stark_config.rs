@@ -10,9 +10,9 @@
 //! before proving or verifying.
 
 pub use miden_air::config::{
-    Blake3Config, KeccakConfig, Poseidon2Config, RelationDigest, RpoConfig, RpxConfig,
-    blake3_256_config, keccak_config, observe_protocol_params, poseidon2_config, rpo_config,
-    rpx_config,
+    Blake3_192Config, Blake3Config, KeccakConfig, Poseidon2Config, RelationDigest, RpoConfig,
+    RpxConfig, blake3_192_config, blake3_256_config, keccak_config, observe_protocol_params,
+    poseidon2_config, rpo_config, rpx_config,
 };
 use miden_core::Felt;
 use miden_crypto::{
@@ -448,6 +448,7 @@ fn prove_deferred_state_proves_non_empty_root() {
 fn prove_deferred_state_round_trips_for_every_hash_function() {
     let synthetic = synthetic_keccak_state(b"abc");
     let hash_fns = [
+        HashFunction::Blake3_192,
         HashFunction::Blake3_256,
         HashFunction::Rpo256,
         HashFunction::Rpx256,
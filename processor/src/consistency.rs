@@ -0,0 +1,362 @@
+//! Checks that fast execution and trace generation agree on the state of the processor.
+//!
+//! Trace generation does not re-run the [`FastProcessor`]; instead, it replays the fragments
+//! recorded during execution with a separate processor, in parallel. A bug in either of them makes
+//! the generated trace diverge from the state the program actually went through, which usually
+//! only surfaces as an unsatisfied constraint much later in the prover. [`find_divergence`]
+//! executes a program once, builds its trace, and reports the first control-flow boundary at which
+//! the two disagree.
+
+use alloc::{sync::Arc, vec::Vec};
+use core::fmt;
+
+use miden_air::trace::{MainTrace, RowIndex, chiplets::hasher::STATE_WIDTH};
+use miden_core::{
+    Felt, Word,
+    mast::{ExecutableMastForest, MastForest, MastNodeId},
+    program::{MIN_STACK_DEPTH, Program},
+};
+
+use crate::{
+    AdviceInputs, ContextId, ExecutionError, ExecutionOptions, FastProcessor, StackInputs,
+    SyncHost, TraceBuildInputs,
+    continuation_stack::{Continuation, ContinuationStack},
+    crypto::merkle::MerklePath,
+    errors::MapExecErrNoCtx,
+    processor::{Processor, SystemInterface},
+    trace::{build_trace, chiplets::CircuitEvaluation, execution_tracer::ExecutionTracer},
+    tracer::{OperationHelperRegisters, Tracer},
+};
+
+// DIVERGENCE
+// ================================================================================================
+
+/// The state of the processor at the start of a clock cycle, as far as it is compared by
+/// [`find_divergence`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateSnapshot {
+    /// The clock cycle.
+    pub clk: RowIndex,
+    /// The execution context.
+    pub ctx: ContextId,
+    /// The depth of the operand stack in the execution context.
+    pub stack_depth: u32,
+    /// The top of the operand stack, with the top element at index 0.
+    pub stack_top: [Felt; MIN_STACK_DEPTH],
+}
+
+impl StateSnapshot {
+    /// Returns the state of `processor` at the start of the current clock cycle.
+    fn from_processor(processor: &FastProcessor) -> Self {
+        Self {
+            clk: processor.system().clock(),
+            ctx: processor.system().ctx(),
+            stack_depth: processor.stack_depth(),
+            stack_top: core::array::from_fn(|idx| processor.stack_get(idx)),
+        }
+    }
+
+    /// Returns the state recorded in row `row` of `trace`, or `None` if the trace has no such row.
+    fn from_trace(trace: &MainTrace, row: RowIndex) -> Option<Self> {
+        if row.as_usize() >= trace.core_height() {
+            return None;
+        }
+
+        Some(Self {
+            clk: RowIndex::from(trace.clk(row).as_canonical_u64() as u32),
+            ctx: ContextId::from(trace.ctx(row).as_canonical_u64() as u32),
+            stack_depth: trace.stack_depth(row).as_canonical_u64() as u32,
+            stack_top: core::array::from_fn(|idx| trace.stack_element(idx, row)),
+        })
+    }
+}
+
+/// A point in the execution of a program at which the processor state is compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlFlowBoundary {
+    /// Execution of the MAST node with the specified ID and digest is about to start.
+    NodeStarted { node_id: MastNodeId, digest: Word },
+    /// Execution of the MAST node with the specified ID and digest is about to finish.
+    NodeFinished { node_id: MastNodeId, digest: Word },
+}
+
+/// The first control-flow boundary at which the generated trace disagrees with execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    /// The control-flow boundary at which the states were compared.
+    pub boundary: ControlFlowBoundary,
+    /// The state of the processor during execution.
+    pub execution: StateSnapshot,
+    /// The state recorded in the generated trace, or `None` if the trace does not contain the
+    /// clock cycle at all.
+    pub trace: Option<StateSnapshot>,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (event, node_id, digest) = match self.boundary {
+            ControlFlowBoundary::NodeStarted { node_id, digest } => ("start of", node_id, digest),
+            ControlFlowBoundary::NodeFinished { node_id, digest } => ("end of", node_id, digest),
+        };
+        writeln!(
+            f,
+            "trace diverges from execution at clock cycle {}, at the {event} node {node_id} ({digest})",
+            self.execution.clk,
+        )?;
+        writeln!(f, "  execution: {:?}", self.execution)?;
+        match &self.trace {
+            Some(trace) => write!(f, "  trace:     {trace:?}"),
+            None => write!(f, "  trace:     <missing row>"),
+        }
+    }
+}
+
+// FIND DIVERGENCE
+// ================================================================================================
+
+/// Executes `program`, builds its trace, and returns the first control-flow boundary at which the
+/// state recorded in the trace differs from the state of the processor during execution, or
+/// `None` if they agree at every boundary.
+///
+/// The clock cycle, execution context, stack depth, and top of the operand stack are compared at
+/// the start of every clock cycle which starts or finishes a MAST node. Memory, including the
+/// frame pointer, is not part of the core trace and is therefore not compared.
+///
+/// # Errors
+/// Returns an error if the program fails to execute, or if its trace cannot be built.
+pub fn find_divergence(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+    host: &mut impl SyncHost,
+    options: ExecutionOptions,
+) -> Result<Option<Divergence>, ExecutionError> {
+    let processor = FastProcessor::new_with_options(stack_inputs, advice_inputs, options)
+        .map_exec_err_no_ctx()?;
+    let mut tracer = BoundaryTracer {
+        inner: processor.execution_tracer(),
+        boundaries: Vec::new(),
+    };
+    let execution_output = processor.execute_with_tracer_sync(program, host, &mut tracer)?;
+
+    let BoundaryTracer { inner, boundaries } = tracer;
+    let trace_inputs = TraceBuildInputs::from_execution(
+        program,
+        execution_output,
+        inner.into_trace_generation_context()?,
+    );
+    let trace = build_trace(trace_inputs)?;
+
+    Ok(boundaries.into_iter().find_map(|(boundary, execution)| {
+        let trace = StateSnapshot::from_trace(trace.main_trace(), execution.clk);
+        (trace != Some(execution)).then_some(Divergence { boundary, execution, trace })
+    }))
+}
+
+// BOUNDARY TRACER
+// ================================================================================================
+
+/// An [`ExecutionTracer`] which additionally records the processor state at every control-flow
+/// boundary.
+struct BoundaryTracer {
+    inner: ExecutionTracer,
+    boundaries: Vec<(ControlFlowBoundary, StateSnapshot)>,
+}
+
+impl Tracer for BoundaryTracer {
+    type Processor = FastProcessor;
+    type Forest = Arc<MastForest>;
+
+    fn start_clock_cycle(
+        &mut self,
+        processor: &FastProcessor,
+        continuation: Continuation<Arc<MastForest>>,
+        continuation_stack: &ContinuationStack<Arc<MastForest>>,
+        current_forest: &Arc<MastForest>,
+    ) {
+        let boundary = match continuation {
+            Continuation::StartNode(node_id) => Some((node_id, true)),
+            Continuation::FinishJoin(node_id)
+            | Continuation::FinishSplit(node_id)
+            | Continuation::FinishLoop(node_id)
+            | Continuation::FinishCall(node_id)
+            | Continuation::FinishDyn(node_id)
+            | Continuation::FinishBasicBlock(node_id) => Some((node_id, false)),
+            Continuation::ResumeBasicBlock { .. }
+            | Continuation::Respan { .. }
+            | Continuation::EnterForest { .. } => None,
+        };
+        if let Some((node_id, started)) = boundary {
+            let digest = current_forest.get_digest_by_id(node_id).unwrap_or_default();
+            let boundary = if started {
+                ControlFlowBoundary::NodeStarted { node_id, digest }
+            } else {
+                ControlFlowBoundary::NodeFinished { node_id, digest }
+            };
+            self.boundaries.push((boundary, StateSnapshot::from_processor(processor)));
+        }
+
+        self.inner
+            .start_clock_cycle(processor, continuation, continuation_stack, current_forest);
+    }
+
+    fn finalize_clock_cycle(
+        &mut self,
+        processor: &FastProcessor,
+        op_helper_registers: OperationHelperRegisters,
+        current_forest: &Arc<MastForest>,
+    ) {
+        self.inner.finalize_clock_cycle(processor, op_helper_registers, current_forest);
+    }
+
+    fn record_mast_forest_resolution(&mut self, node_id: MastNodeId, forest: &Arc<MastForest>) {
+        self.inner.record_mast_forest_resolution(node_id, forest);
+    }
+
+    fn record_external_node_entered(
+        &mut self,
+        external_node_id: MastNodeId,
+        forest: &Arc<MastForest>,
+    ) {
+        self.inner.record_external_node_entered(external_node_id, forest);
+    }
+
+    fn record_hasher_permute(
+        &mut self,
+        input_state: [Felt; STATE_WIDTH],
+        output_state: [Felt; STATE_WIDTH],
+    ) {
+        self.inner.record_hasher_permute(input_state, output_state);
+    }
+
+    fn record_hasher_build_merkle_root(
+        &mut self,
+        node: Word,
+        path: Option<&MerklePath>,
+        index: Felt,
+        output_root: Word,
+    ) {
+        self.inner.record_hasher_build_merkle_root(node, path, index, output_root);
+    }
+
+    fn record_hasher_update_merkle_root(
+        &mut self,
+        old_value: Word,
+        new_value: Word,
+        path: Option<&MerklePath>,
+        index: Felt,
+        old_root: Word,
+        new_root: Word,
+    ) {
+        self.inner.record_hasher_update_merkle_root(
+            old_value, new_value, path, index, old_root, new_root,
+        );
+    }
+
+    fn record_memory_read_element(
+        &mut self,
+        element: Felt,
+        addr: Felt,
+        ctx: ContextId,
+        clk: RowIndex,
+    ) {
+        self.inner.record_memory_read_element(element, addr, ctx, clk);
+    }
+
+    fn record_memory_read_word(&mut self, word: Word, addr: Felt, ctx: ContextId, clk: RowIndex) {
+        self.inner.record_memory_read_word(word, addr, ctx, clk);
+    }
+
+    fn record_memory_write_element(
+        &mut self,
+        element: Felt,
+        addr: Felt,
+        ctx: ContextId,
+        clk: RowIndex,
+    ) {
+        self.inner.record_memory_write_element(element, addr, ctx, clk);
+    }
+
+    fn record_memory_write_word(&mut self, word: Word, addr: Felt, ctx: ContextId, clk: RowIndex) {
+        self.inner.record_memory_write_word(word, addr, ctx, clk);
+    }
+
+    fn record_memory_read_element_pair(
+        &mut self,
+        element_0: Felt,
+        addr_0: Felt,
+        element_1: Felt,
+        addr_1: Felt,
+        ctx: ContextId,
+        clk: RowIndex,
+    ) {
+        self.inner
+            .record_memory_read_element_pair(element_0, addr_0, element_1, addr_1, ctx, clk);
+    }
+
+    fn record_memory_read_dword(
+        &mut self,
+        words: [Word; 2],
+        addr: Felt,
+        ctx: ContextId,
+        clk: RowIndex,
+    ) {
+        self.inner.record_memory_read_dword(words, addr, ctx, clk);
+    }
+
+    fn record_dyncall_memory(
+        &mut self,
+        callee_hash: Word,
+        read_addr: Felt,
+        read_ctx: ContextId,
+        fmp_ctx: ContextId,
+        clk: RowIndex,
+    ) {
+        self.inner.record_dyncall_memory(callee_hash, read_addr, read_ctx, fmp_ctx, clk);
+    }
+
+    fn record_crypto_stream(
+        &mut self,
+        plaintext: [Word; 2],
+        src_addr: Felt,
+        ciphertext: [Word; 2],
+        dst_addr: Felt,
+        ctx: ContextId,
+        clk: RowIndex,
+    ) {
+        self.inner
+            .record_crypto_stream(plaintext, src_addr, ciphertext, dst_addr, ctx, clk);
+    }
+
+    fn record_pipe(&mut self, words: [Word; 2], addr: Felt, ctx: ContextId, clk: RowIndex) {
+        self.inner.record_pipe(words, addr, ctx, clk);
+    }
+
+    fn record_advice_pop_stack(&mut self, value: Felt) {
+        self.inner.record_advice_pop_stack(value);
+    }
+
+    fn record_advice_pop_stack_word(&mut self, word: Word) {
+        self.inner.record_advice_pop_stack_word(word);
+    }
+
+    fn record_u32and(&mut self, a: Felt, b: Felt) {
+        self.inner.record_u32and(a, b);
+    }
+
+    fn record_u32xor(&mut self, a: Felt, b: Felt) {
+        self.inner.record_u32xor(a, b);
+    }
+
+    fn record_u32_range_checks(&mut self, u32_lo: Felt, u32_hi: Felt) {
+        self.inner.record_u32_range_checks(u32_lo, u32_hi);
+    }
+
+    fn record_kernel_proc_access(&mut self, proc_hash: Word) {
+        self.inner.record_kernel_proc_access(proc_hash);
+    }
+
+    fn record_circuit_evaluation(&mut self, circuit_evaluation: CircuitEvaluation) {
+        self.inner.record_circuit_evaluation(circuit_evaluation);
+    }
+}
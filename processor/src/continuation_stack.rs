@@ -1,6 +1,10 @@
-use alloc::{sync::Arc, vec::Vec};
+use alloc::{format, sync::Arc, vec::Vec};
 
-use miden_core::{mast::MastNodeId, program::Program};
+use miden_core::{
+    mast::MastNodeId,
+    program::Program,
+    serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
 use miden_mast_package::debug_info::{DebugSourceNodeId, PackageDebugInfo};
 
 /// A hint for the initial size of the continuation stack.
@@ -260,6 +264,11 @@ impl<F> ContinuationStack<F> {
         self.stack
     }
 
+    /// Returns an iterator over the continuations on the stack, from the bottom to the top.
+    pub(crate) fn iter(&self) -> impl ExactSizeIterator<Item = &Continuation<F>> {
+        self.stack.iter()
+    }
+
     fn push_source_node_id(&mut self, source_node_id: Option<DebugSourceNodeId>) {
         if let Some(source_node_ids) = &mut self.source_node_ids {
             source_node_ids.push(source_node_id);
@@ -341,6 +350,117 @@ impl<F> ContinuationStack<F> {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+const START_NODE: u8 = 0;
+const FINISH_JOIN: u8 = 1;
+const FINISH_SPLIT: u8 = 2;
+const FINISH_LOOP: u8 = 3;
+const FINISH_CALL: u8 = 4;
+const FINISH_DYN: u8 = 5;
+const RESUME_BASIC_BLOCK: u8 = 6;
+const RESPAN: u8 = 7;
+const FINISH_BASIC_BLOCK: u8 = 8;
+const ENTER_FOREST: u8 = 9;
+
+/// Writes `continuation` into `target`, using `write_forest` to encode the forest reference of an
+/// [`Continuation::EnterForest`] continuation.
+///
+/// The package debug info attached to an `EnterForest` continuation is not serialized.
+pub(crate) fn write_continuation<F, W: ByteWriter>(
+    continuation: &Continuation<F>,
+    target: &mut W,
+    write_forest: impl FnOnce(&F, &mut W),
+) {
+    match continuation {
+        Continuation::StartNode(node_id) => {
+            target.write_u8(START_NODE);
+            node_id.write_into(target);
+        },
+        Continuation::FinishJoin(node_id) => {
+            target.write_u8(FINISH_JOIN);
+            node_id.write_into(target);
+        },
+        Continuation::FinishSplit(node_id) => {
+            target.write_u8(FINISH_SPLIT);
+            node_id.write_into(target);
+        },
+        Continuation::FinishLoop(node_id) => {
+            target.write_u8(FINISH_LOOP);
+            node_id.write_into(target);
+        },
+        Continuation::FinishCall(node_id) => {
+            target.write_u8(FINISH_CALL);
+            node_id.write_into(target);
+        },
+        Continuation::FinishDyn(node_id) => {
+            target.write_u8(FINISH_DYN);
+            node_id.write_into(target);
+        },
+        Continuation::ResumeBasicBlock { node_id, batch_index, op_idx_in_batch } => {
+            target.write_u8(RESUME_BASIC_BLOCK);
+            node_id.write_into(target);
+            target.write_usize(*batch_index);
+            target.write_usize(*op_idx_in_batch);
+        },
+        Continuation::Respan { node_id, batch_index } => {
+            target.write_u8(RESPAN);
+            node_id.write_into(target);
+            target.write_usize(*batch_index);
+        },
+        Continuation::FinishBasicBlock(node_id) => {
+            target.write_u8(FINISH_BASIC_BLOCK);
+            node_id.write_into(target);
+        },
+        Continuation::EnterForest { forest, package_debug_info: _ } => {
+            target.write_u8(ENTER_FOREST);
+            write_forest(forest, target);
+        },
+    }
+}
+
+/// Reads a continuation written by [`write_continuation`] from `source`, using `read_forest` to
+/// decode the forest reference of an [`Continuation::EnterForest`] continuation.
+pub(crate) fn read_continuation<F, R: ByteReader>(
+    source: &mut R,
+    read_forest: impl FnOnce(&mut R) -> Result<F, DeserializationError>,
+) -> Result<Continuation<F>, DeserializationError> {
+    let tag = source.read_u8()?;
+    if tag == ENTER_FOREST {
+        return Ok(Continuation::EnterForest {
+            forest: read_forest(source)?,
+            package_debug_info: None,
+        });
+    }
+
+    let node_id = MastNodeId::read_from(source)?;
+    let continuation = match tag {
+        START_NODE => Continuation::StartNode(node_id),
+        FINISH_JOIN => Continuation::FinishJoin(node_id),
+        FINISH_SPLIT => Continuation::FinishSplit(node_id),
+        FINISH_LOOP => Continuation::FinishLoop(node_id),
+        FINISH_CALL => Continuation::FinishCall(node_id),
+        FINISH_DYN => Continuation::FinishDyn(node_id),
+        RESUME_BASIC_BLOCK => Continuation::ResumeBasicBlock {
+            node_id,
+            batch_index: source.read_usize()?,
+            op_idx_in_batch: source.read_usize()?,
+        },
+        RESPAN => Continuation::Respan {
+            node_id,
+            batch_index: source.read_usize()?,
+        },
+        FINISH_BASIC_BLOCK => Continuation::FinishBasicBlock(node_id),
+        _ => {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid continuation tag {tag}"
+            )));
+        },
+    };
+    Ok(continuation)
+}
+
 // TESTS
 // ================================================================================================
 
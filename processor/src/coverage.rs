@@ -0,0 +1,124 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt::Write;
+
+use miden_core::{
+    Word,
+    mast::{MastForest, MastNode, MastNodeExt},
+};
+use miden_debug_types::SourceManager;
+use miden_mast_package::debug_info::{DebugSourceMapSection, PackageDebugInfo};
+
+// COVERAGE REPORT
+// ================================================================================================
+
+/// The operations executed by a program, recorded per basic block.
+///
+/// Basic blocks are identified by their digest, so operations executed in MAST forests loaded
+/// from the host during execution are accounted for as well. For each executed basic block, the
+/// report holds the number of times each of its operations was executed, indexed by the position
+/// of the operation in the block.
+///
+/// Together with the debug information of the package the program was assembled from, the report
+/// can be mapped back to source lines via [`Self::to_lcov`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoverageReport {
+    blocks: BTreeMap<Word, Vec<u32>>,
+}
+
+impl CoverageReport {
+    /// Returns the number of times each operation of the basic block with the specified digest
+    /// was executed, or `None` if no operation of the block was executed.
+    ///
+    /// The returned slice is indexed by the position of the operation in the block, and omits the
+    /// trailing operations which were never executed.
+    pub fn operation_counts(&self, block_digest: &Word) -> Option<&[u32]> {
+        self.blocks.get(block_digest).map(Vec::as_slice)
+    }
+
+    /// Returns the number of times the operation at `op_idx` in the basic block with the specified
+    /// digest was executed.
+    pub fn num_executions(&self, block_digest: &Word, op_idx: usize) -> u32 {
+        self.operation_counts(block_digest)
+            .and_then(|counts| counts.get(op_idx))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns an iterator over the executed basic blocks and the number of times each of their
+    /// operations was executed, ordered by block digest.
+    pub fn iter(&self) -> impl Iterator<Item = (&Word, &[u32])> {
+        self.blocks.iter().map(|(digest, counts)| (digest, counts.as_slice()))
+    }
+
+    /// Renders the source line coverage of the program in the lcov tracefile format.
+    ///
+    /// `forest` and `debug_info` must be the MAST forest and debug information of the package the
+    /// program was assembled from, and `source_manager` must hold the sources referenced by the
+    /// debug information. Every assembly instruction of a basic block with a known source location
+    /// contributes a line, including instructions which were never executed, so that uncovered
+    /// lines are reported with a count of zero. Instructions whose source file is not known to
+    /// `source_manager` are skipped.
+    ///
+    /// Source files are named by their URI, and appear in the order of their URIs.
+    pub fn to_lcov(
+        &self,
+        forest: &MastForest,
+        debug_info: &PackageDebugInfo,
+        source_manager: &dyn SourceManager,
+    ) -> String {
+        let mut lines: BTreeMap<String, BTreeMap<u32, u32>> = BTreeMap::new();
+        for asm_op in debug_info.source_map().into_iter().flat_map(DebugSourceMapSection::asm_ops) {
+            let Some(location) = &asm_op.location else {
+                continue;
+            };
+            let Some(block) = debug_info
+                .source_node(asm_op.source_node)
+                .and_then(|source_node| forest.get_node_by_id(source_node.exec_node))
+                .and_then(MastNode::get_basic_block)
+            else {
+                continue;
+            };
+            let Some(source_file) = source_manager.get_by_uri(&location.uri) else {
+                continue;
+            };
+            let Some(line_col) = source_file.content().location(location.start) else {
+                continue;
+            };
+
+            // All operations of an assembly instruction are executed together, so the count of
+            // its first operation is the number of times the instruction was executed.
+            let count = self.num_executions(&block.digest(), asm_op.op_idx as usize);
+
+            let line = lines
+                .entry(String::from(location.uri.as_str()))
+                .or_default()
+                .entry(line_col.line.to_u32())
+                .or_default();
+            *line = (*line).max(count);
+        }
+
+        let mut lcov = String::new();
+        for (file, file_lines) in lines {
+            let num_hit = file_lines.values().filter(|&&count| count > 0).count();
+            let _ = writeln!(lcov, "TN:");
+            let _ = writeln!(lcov, "SF:{file}");
+            for (line, count) in &file_lines {
+                let _ = writeln!(lcov, "DA:{line},{count}");
+            }
+            let _ = writeln!(lcov, "LF:{}", file_lines.len());
+            let _ = writeln!(lcov, "LH:{num_hit}");
+            let _ = writeln!(lcov, "end_of_record");
+        }
+        lcov
+    }
+
+    /// Records an execution of the operation at `op_idx` in the basic block with the specified
+    /// digest.
+    pub(crate) fn record_operation(&mut self, block_digest: Word, op_idx: usize) {
+        let counts = self.blocks.entry(block_digest).or_default();
+        if counts.len() <= op_idx {
+            counts.resize(op_idx + 1, 0);
+        }
+        counts[op_idx] += 1;
+    }
+}
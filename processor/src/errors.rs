@@ -3,19 +3,23 @@
 
 use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
 
-use miden_core::{deferred::PrecompileError, program::MIN_STACK_DEPTH};
+use miden_core::{
+    deferred::{IntegrityError, PrecompileError},
+    program::MIN_STACK_DEPTH,
+};
 use miden_debug_types::{Location, SourceFile, SourceSpan};
 use miden_mast_package::{
     PackageDebugInfoError,
-    debug_info::{DebugSourceNodeId, PackageDebugInfo},
+    debug_info::{AsmOpInfo, DebugSourceNodeId, PackageDebugInfo},
 };
-use miden_utils_diagnostics::{Diagnostic, miette};
+use miden_utils_diagnostics::{Diagnostic, Report, miette};
 
 use crate::{
-    BaseHost, ContextId, Felt, Word,
+    BaseHost, ContextId, Felt, MastForestLoadError, Word,
     advice::AdviceError,
     event::{EventError, EventId, EventName},
     fast::SystemEventError,
+    trace::TraceFragmentStoreError,
     utils::to_hex,
 };
 
@@ -41,10 +45,18 @@ pub enum ExecutionError {
         label: SourceSpan,
         #[source_code]
         source_file: Option<Arc<SourceFile>>,
+        #[related]
+        call_sites: Box<[CallSite]>,
         err: AdviceError,
     },
     #[error("exceeded the allowed number of max cycles {0}")]
     CycleLimitExceeded(u32),
+    #[error("execution was cancelled after {0} cycles")]
+    Cancelled(u32),
+    #[error("call depth {depth} exceeds the maximum of {max}")]
+    CallDepthLimitExceeded { depth: usize, max: usize },
+    #[error("exceeded the allowed number of emitted events {0}")]
+    EventLimitExceeded(usize),
     #[error("error during processing of event {}", match event_name {
         Some(name) => format!("'{name}' (ID: {event_id})"),
         None => format!("with ID: {event_id}"),
@@ -55,6 +67,8 @@ pub enum ExecutionError {
         label: SourceSpan,
         #[source_code]
         source_file: Option<Arc<SourceFile>>,
+        #[related]
+        call_sites: Box<[CallSite]>,
         event_id: EventId,
         event_name: Option<EventName>,
         #[source]
@@ -68,10 +82,15 @@ pub enum ExecutionError {
         label: SourceSpan,
         #[source_code]
         source_file: Option<Arc<SourceFile>>,
+        #[related]
+        call_sites: Box<[CallSite]>,
         err: PrecompileError,
     },
     #[error("failed to execute the program for internal reason: {0}")]
     Internal(&'static str),
+    #[error("source map describes MAST forest {expected}, but the program's forest is {actual}")]
+    #[diagnostic(help("the source map must be produced when assembling the executed program"))]
+    SourceMapMismatch { expected: Word, actual: Word },
     #[error("operand stack depth {depth} exceeds the maximum of {max}")]
     StackDepthLimitExceeded { depth: usize, max: usize },
     /// This means trace generation would go over the configured row limit.
@@ -89,6 +108,8 @@ pub enum ExecutionError {
         label: SourceSpan,
         #[source_code]
         source_file: Option<Arc<SourceFile>>,
+        #[related]
+        call_sites: Box<[CallSite]>,
         err: MemoryError,
     },
     /// Memory error without source context (for internal operations like FMP initialization).
@@ -105,6 +126,8 @@ pub enum ExecutionError {
         label: SourceSpan,
         #[source_code]
         source_file: Option<Arc<SourceFile>>,
+        #[related]
+        call_sites: Box<[CallSite]>,
         err: OperationError,
     },
     #[error("stack should have at most {MIN_STACK_DEPTH} elements at the end of program execution, but had {} elements", MIN_STACK_DEPTH + .0)]
@@ -118,8 +141,41 @@ pub enum ExecutionError {
         source_file: Option<Arc<SourceFile>>,
         root_digest: Word,
     },
+    /// The host refused to resolve a procedure via [`SyncHost::on_external_resolution()`].
+    ///
+    /// [`SyncHost::on_external_resolution()`]: crate::SyncHost::on_external_resolution
+    #[error("host denied resolution of procedure with root digest {root_digest}")]
+    #[diagnostic()]
+    ExternalResolutionDenied {
+        #[label]
+        label: SourceSpan,
+        #[source_code]
+        source_file: Option<Arc<SourceFile>>,
+        root_digest: Word,
+        #[source]
+        error: EventError,
+    },
+    /// The host found the MAST forest of a procedure but failed to load it via
+    /// [`SyncHost::try_get_mast_forest()`].
+    ///
+    /// [`SyncHost::try_get_mast_forest()`]: crate::SyncHost::try_get_mast_forest
+    #[error("failed to load the MAST forest of procedure with root digest {root_digest}")]
+    #[diagnostic()]
+    MastForestLoadFailed {
+        #[label]
+        label: SourceSpan,
+        #[source_code]
+        source_file: Option<Arc<SourceFile>>,
+        root_digest: Word,
+        #[source]
+        error: MastForestLoadError,
+    },
     #[error("failed to generate STARK proof: {0}")]
     ProvingError(String),
+    /// Writing a core trace fragment to, or reading it back from, a
+    /// [`crate::trace::CoreTraceFragmentStore`] failed.
+    #[error("failed to access the core trace fragment store")]
+    TraceFragmentStoreError(#[source] TraceFragmentStoreError),
     #[error(transparent)]
     HostError(#[from] HostError),
     #[error(transparent)]
@@ -127,11 +183,21 @@ pub enum ExecutionError {
 }
 
 impl ExecutionError {
+    /// Converts this error into a [`Report`] which renders with the source location of the
+    /// failing operation highlighted, when that location is known.
+    ///
+    /// The source file is attached to the report only if it was available when the error was
+    /// raised, i.e. when the program was executed with debug information.
+    pub fn into_diagnostic(self) -> Report {
+        Report::new(self)
+    }
+
     /// Wraps an advice error without source-location context.
     pub fn advice_error_no_context(err: AdviceError) -> Self {
         Self::AdviceError {
             label: SourceSpan::UNKNOWN,
             source_file: None,
+            call_sites: Box::default(),
             err,
         }
     }
@@ -143,6 +209,35 @@ impl AsRef<dyn Diagnostic> for ExecutionError {
     }
 }
 
+// CHECKPOINT ERROR
+// ================================================================================================
+
+/// Error type for checkpointing the execution of a program and resuming it.
+#[derive(Debug, thiserror::Error)]
+pub enum CheckpointError {
+    #[error(transparent)]
+    Advice(#[from] AdviceError),
+    #[error("failed to rebuild the deferred state of the processor")]
+    DeferredStateIntegrity(#[source] IntegrityError),
+    #[error(
+        "cannot checkpoint execution of a procedure from a MAST forest loaded to resolve an external node"
+    )]
+    ExternalForestActive,
+    #[error("invalid continuation in processor snapshot: {0}")]
+    InvalidContinuation(String),
+    #[error(
+        "processor snapshot has an operand stack of depth {depth}, but the depth must be between {min} and {max}",
+        min = MIN_STACK_DEPTH
+    )]
+    InvalidStackDepth { depth: usize, max: usize },
+    #[error(transparent)]
+    Memory(#[from] MemoryError),
+    #[error(
+        "processor snapshot was taken while executing MAST forest {expected}, but the program's forest is {actual}"
+    )]
+    ProgramMismatch { expected: Word, actual: Word },
+}
+
 // ACE ERROR
 // ================================================================================================
 
@@ -243,6 +338,13 @@ pub enum MemoryError {
         "increase the limit via `ExecutionOptions::with_max_memory_elements`, or reduce the number of distinct memory addresses the program writes to"
     ))]
     MemoryElementLimitExceeded { ctx: ContextId, addr: u32, max: usize },
+    #[error(
+        "writing to memory address {addr} in context {ctx} would exceed the maximum number of memory elements per context {max}"
+    )]
+    #[diagnostic(help(
+        "increase the limit via `ExecutionOptions::with_max_context_memory_elements`, or reduce the number of distinct memory addresses the program writes to in a single context"
+    ))]
+    ContextMemoryLimitExceeded { ctx: ContextId, addr: u32, max: usize },
 }
 
 // CRYPTO ERROR
@@ -391,7 +493,12 @@ impl OperationError {
     /// where the `OperationResultExt::map_exec_err` extension trait cannot be used directly.
     pub fn with_context(self) -> ExecutionError {
         let (label, source_file) = get_label_and_source_file();
-        ExecutionError::OperationError { label, source_file, err: self }
+        ExecutionError::OperationError {
+            label,
+            source_file,
+            call_sites: Box::default(),
+            err: self,
+        }
     }
 
     /// Wraps this error with package-owned source-occurrence execution context.
@@ -404,30 +511,43 @@ impl OperationError {
         host: &(dyn BaseHost + '_),
         op_idx: Option<usize>,
     ) -> ExecutionError {
-        let (label, source_file) =
-            label_and_source_file_from_location(context.assembly_location(op_idx), host);
+        let (label, source_file, call_sites) = package_error_context(context, op_idx, host);
         ExecutionError::OperationError {
             label,
             source_file,
-            err: self.with_package_debug_info(context.debug_info()),
+            call_sites,
+            err: self
+                .with_package_debug_info(context.debug_info())
+                .with_host_error_messages(host),
         }
     }
 
     fn with_package_debug_info(self, debug_info: &PackageDebugInfo) -> Self {
+        self.with_error_messages(|err_code| debug_info.error_message(err_code.as_canonical_u64()))
+    }
+
+    /// Resolves the message of an assertion error which has none using the [`ErrorTable`] loaded
+    /// into `host`, if any.
+    ///
+    /// [`ErrorTable`]: miden_mast_package::ErrorTable
+    fn with_host_error_messages(self, host: &(dyn BaseHost + '_)) -> Self {
+        self.with_error_messages(|err_code| host.resolve_error_message(err_code))
+    }
+
+    fn with_error_messages(self, resolve: impl Fn(Felt) -> Option<Arc<str>>) -> Self {
         match self {
-            Self::FailedAssertion { err_code, err_msg: None } => Self::FailedAssertion {
-                err_msg: debug_info.error_message(err_code.as_canonical_u64()),
-                err_code,
+            Self::FailedAssertion { err_code, err_msg: None } => {
+                Self::FailedAssertion { err_msg: resolve(err_code), err_code }
             },
             Self::U32AssertionFailed { err_code, err_msg: None, invalid_values } => {
                 Self::U32AssertionFailed {
-                    err_msg: debug_info.error_message(err_code.as_canonical_u64()),
+                    err_msg: resolve(err_code),
                     err_code,
                     invalid_values,
                 }
             },
             Self::MerklePathVerificationFailed { mut inner } if inner.err_msg.is_none() => {
-                inner.err_msg = debug_info.error_message(inner.err_code.as_canonical_u64());
+                inner.err_msg = resolve(inner.err_code);
                 Self::MerklePathVerificationFailed { inner }
             },
             err => err,
@@ -447,6 +567,20 @@ pub struct MerklePathVerificationFailedInner {
     pub err_msg: Option<Arc<str>>,
 }
 
+/// A call site through which the operation that caused an [`ExecutionError`] was merged into the
+/// code of its caller.
+///
+/// Call sites are rendered as related diagnostics of the error, innermost first.
+#[derive(Debug, Clone, thiserror::Error, Diagnostic)]
+#[error("the failing operation was merged into its caller here")]
+#[diagnostic(severity(Advice))]
+pub struct CallSite {
+    #[label]
+    pub label: SourceSpan,
+    #[source_code]
+    pub source_file: Option<Arc<SourceFile>>,
+}
+
 // EXTENSION TRAITS
 // ================================================================================================
 
@@ -490,7 +624,15 @@ impl<'a> PackageSourceDebugContext<'a> {
     /// Returns source location metadata for `op_idx`, if present.
     ///
     /// If `op_idx` is absent, this falls back to the first operation row for the source occurrence.
+    /// See [`AsmOpInfo::location`] for how the location is chosen.
     pub fn assembly_location(&self, op_idx: Option<usize>) -> Option<&'a Location> {
+        self.asm_op_info(op_idx)?.location()
+    }
+
+    /// Returns the provenance of the operation at `op_idx`, if present.
+    ///
+    /// If `op_idx` is absent, this falls back to the first operation row for the source occurrence.
+    pub fn asm_op_info(&self, op_idx: Option<usize>) -> Option<AsmOpInfo<'a>> {
         let source_node_id = self.source_node_id?;
         let assembly_op = match op_idx {
             Some(op_idx) => u32::try_from(op_idx)
@@ -499,7 +641,7 @@ impl<'a> PackageSourceDebugContext<'a> {
             None => self.debug_info.first_asm_op_for_source_node(source_node_id),
         }?;
 
-        assembly_op.location.as_ref()
+        Some(AsmOpInfo::new(assembly_op))
     }
 }
 
@@ -513,6 +655,29 @@ fn label_and_source_file_from_location(
     )
 }
 
+/// Returns the label and source file of the operation at `op_idx` of `context`, along with the
+/// call sites through which the operation was merged into the code of its callers.
+///
+/// See [`PackageSourceDebugContext::asm_op_info`] for how `op_idx` is resolved.
+fn package_error_context(
+    context: PackageSourceDebugContext<'_>,
+    op_idx: Option<usize>,
+    host: &(dyn BaseHost + '_),
+) -> (SourceSpan, Option<Arc<SourceFile>>, Box<[CallSite]>) {
+    let Some(info) = context.asm_op_info(op_idx) else {
+        return (SourceSpan::UNKNOWN, None, Box::default());
+    };
+    let mut span_chain = info.span_chain();
+    let (label, source_file) = label_and_source_file_from_location(span_chain.next(), host);
+    let call_sites = span_chain
+        .map(|location| {
+            let (label, source_file) = host.get_label_and_source_file(location);
+            CallSite { label, source_file }
+        })
+        .collect();
+    (label, source_file, call_sites)
+}
+
 /// Computes the label and source file for error context.
 ///
 /// This function is called by the extension traits to compute source location
@@ -528,7 +693,12 @@ fn get_label_and_source_file() -> (SourceSpan, Option<Arc<SourceFile>>) {
 /// where the extension traits cannot be used directly.
 pub fn advice_error_with_context(err: AdviceError) -> ExecutionError {
     let (label, source_file) = get_label_and_source_file();
-    ExecutionError::AdviceError { label, source_file, err }
+    ExecutionError::AdviceError {
+        label,
+        source_file,
+        call_sites: Box::default(),
+        err,
+    }
 }
 
 /// Wraps an `AdviceError` with package-owned source-occurrence execution context.
@@ -538,9 +708,8 @@ pub fn advice_error_with_package_source_context(
     host: &(dyn BaseHost + '_),
     op_idx: Option<usize>,
 ) -> ExecutionError {
-    let (label, source_file) =
-        label_and_source_file_from_location(context.assembly_location(op_idx), host);
-    ExecutionError::AdviceError { label, source_file, err }
+    let (label, source_file, call_sites) = package_error_context(context, op_idx, host);
+    ExecutionError::AdviceError { label, source_file, call_sites, err }
 }
 
 /// Wraps an `EventError` with execution context to produce an `ExecutionError`.
@@ -556,6 +725,7 @@ pub fn event_error_with_context(
     ExecutionError::EventError {
         label,
         source_file,
+        call_sites: Box::default(),
         event_id,
         event_name,
         error,
@@ -571,17 +741,60 @@ pub fn event_error_with_package_source_context(
     event_id: EventId,
     event_name: Option<EventName>,
 ) -> ExecutionError {
-    let (label, source_file) =
-        label_and_source_file_from_location(context.assembly_location(op_idx), host);
+    let (label, source_file, call_sites) = package_error_context(context, op_idx, host);
     ExecutionError::EventError {
         label,
         source_file,
+        call_sites,
         event_id,
         event_name,
         error,
     }
 }
 
+/// Creates an `ExternalResolutionDenied` error with execution context.
+pub fn external_resolution_denied_with_context(
+    root_digest: Word,
+    error: EventError,
+) -> ExecutionError {
+    let (label, source_file) = get_label_and_source_file();
+    ExecutionError::ExternalResolutionDenied { label, source_file, root_digest, error }
+}
+
+/// Creates an `ExternalResolutionDenied` error with package-owned source-occurrence execution
+/// context.
+pub fn external_resolution_denied_with_package_source_context(
+    root_digest: Word,
+    error: EventError,
+    context: PackageSourceDebugContext<'_>,
+    host: &(dyn BaseHost + '_),
+) -> ExecutionError {
+    let (label, source_file) =
+        label_and_source_file_from_location(context.assembly_location(None), host);
+    ExecutionError::ExternalResolutionDenied { label, source_file, root_digest, error }
+}
+
+/// Creates a `MastForestLoadFailed` error with execution context.
+pub fn mast_forest_load_failed_with_context(
+    root_digest: Word,
+    error: MastForestLoadError,
+) -> ExecutionError {
+    let (label, source_file) = get_label_and_source_file();
+    ExecutionError::MastForestLoadFailed { label, source_file, root_digest, error }
+}
+
+/// Creates a `MastForestLoadFailed` error with package-owned source-occurrence execution context.
+pub fn mast_forest_load_failed_with_package_source_context(
+    root_digest: Word,
+    error: MastForestLoadError,
+    context: PackageSourceDebugContext<'_>,
+    host: &(dyn BaseHost + '_),
+) -> ExecutionError {
+    let (label, source_file) =
+        label_and_source_file_from_location(context.assembly_location(None), host);
+    ExecutionError::MastForestLoadFailed { label, source_file, root_digest, error }
+}
+
 /// Creates a `ProcedureNotFound` error with execution context.
 pub fn procedure_not_found_with_context(root_digest: Word) -> ExecutionError {
     let (label, source_file) = get_label_and_source_file();
@@ -670,7 +883,12 @@ impl<T> MapExecErr<T> for Result<T, OperationError> {
             Ok(v) => Ok(v),
             Err(err) => {
                 let (label, source_file) = get_label_and_source_file();
-                Err(ExecutionError::OperationError { label, source_file, err })
+                Err(ExecutionError::OperationError {
+                    label,
+                    source_file,
+                    call_sites: Box::default(),
+                    err,
+                })
             },
         }
     }
@@ -683,7 +901,12 @@ impl<T> MapExecErrWithOpIdx<T> for Result<T, OperationError> {
             Ok(v) => Ok(v),
             Err(err) => {
                 let (label, source_file) = get_label_and_source_file();
-                Err(ExecutionError::OperationError { label, source_file, err })
+                Err(ExecutionError::OperationError {
+                    label,
+                    source_file,
+                    call_sites: Box::default(),
+                    err,
+                })
             },
         }
     }
@@ -702,7 +925,13 @@ impl<T> MapExecErrWithOpIdx<T> for Result<T, OperationError> {
             },
             (Err(err), None) => {
                 let (label, source_file) = get_label_and_source_file();
-                Err(ExecutionError::OperationError { label, source_file, err })
+                let err = err.with_host_error_messages(host);
+                Err(ExecutionError::OperationError {
+                    label,
+                    source_file,
+                    call_sites: Box::default(),
+                    err,
+                })
             },
         }
     }
@@ -716,6 +945,7 @@ impl<T> MapExecErrNoCtx<T> for Result<T, OperationError> {
             Err(err) => Err(ExecutionError::OperationError {
                 label: SourceSpan::UNKNOWN,
                 source_file: None,
+                call_sites: Box::default(),
                 err,
             }),
         }
@@ -741,6 +971,7 @@ impl<T> MapExecErrNoCtx<T> for Result<T, AdviceError> {
             Err(err) => Err(ExecutionError::AdviceError {
                 label: SourceSpan::UNKNOWN,
                 source_file: None,
+                call_sites: Box::default(),
                 err,
             }),
         }
@@ -755,7 +986,12 @@ impl<T> MapExecErr<T> for Result<T, MemoryError> {
             Ok(v) => Ok(v),
             Err(err) => {
                 let (label, source_file) = get_label_and_source_file();
-                Err(ExecutionError::MemoryError { label, source_file, err })
+                Err(ExecutionError::MemoryError {
+                    label,
+                    source_file,
+                    call_sites: Box::default(),
+                    err,
+                })
             },
         }
     }
@@ -768,7 +1004,12 @@ impl<T> MapExecErrWithOpIdx<T> for Result<T, MemoryError> {
             Ok(v) => Ok(v),
             Err(err) => {
                 let (label, source_file) = get_label_and_source_file();
-                Err(ExecutionError::MemoryError { label, source_file, err })
+                Err(ExecutionError::MemoryError {
+                    label,
+                    source_file,
+                    call_sites: Box::default(),
+                    err,
+                })
             },
         }
     }
@@ -783,15 +1024,18 @@ impl<T> MapExecErrWithOpIdx<T> for Result<T, MemoryError> {
         match (self, context) {
             (Ok(v), _) => Ok(v),
             (Err(err), Some(context)) => {
-                let (label, source_file) = label_and_source_file_from_location(
-                    context.assembly_location(Some(op_idx)),
-                    host,
-                );
-                Err(ExecutionError::MemoryError { label, source_file, err })
+                let (label, source_file, call_sites) =
+                    package_error_context(context, Some(op_idx), host);
+                Err(ExecutionError::MemoryError { label, source_file, call_sites, err })
             },
             (Err(err), None) => {
                 let (label, source_file) = get_label_and_source_file();
-                Err(ExecutionError::MemoryError { label, source_file, err })
+                Err(ExecutionError::MemoryError {
+                    label,
+                    source_file,
+                    call_sites: Box::default(),
+                    err,
+                })
             },
         }
     }
@@ -806,17 +1050,29 @@ impl<T> MapExecErr<T> for Result<T, SystemEventError> {
             Err(err) => {
                 let (label, source_file) = get_label_and_source_file();
                 Err(match err {
-                    SystemEventError::Advice(err) => {
-                        ExecutionError::AdviceError { label, source_file, err }
+                    SystemEventError::Advice(err) => ExecutionError::AdviceError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
-                    SystemEventError::Operation(err) => {
-                        ExecutionError::OperationError { label, source_file, err }
+                    SystemEventError::Operation(err) => ExecutionError::OperationError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
-                    SystemEventError::Memory(err) => {
-                        ExecutionError::MemoryError { label, source_file, err }
+                    SystemEventError::Memory(err) => ExecutionError::MemoryError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
-                    SystemEventError::Deferred(err) => {
-                        ExecutionError::DeferredError { label, source_file, err }
+                    SystemEventError::Deferred(err) => ExecutionError::DeferredError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
                 })
             },
@@ -832,17 +1088,29 @@ impl<T> MapExecErrWithOpIdx<T> for Result<T, SystemEventError> {
             Err(err) => {
                 let (label, source_file) = get_label_and_source_file();
                 Err(match err {
-                    SystemEventError::Advice(err) => {
-                        ExecutionError::AdviceError { label, source_file, err }
+                    SystemEventError::Advice(err) => ExecutionError::AdviceError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
-                    SystemEventError::Operation(err) => {
-                        ExecutionError::OperationError { label, source_file, err }
+                    SystemEventError::Operation(err) => ExecutionError::OperationError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
-                    SystemEventError::Memory(err) => {
-                        ExecutionError::MemoryError { label, source_file, err }
+                    SystemEventError::Memory(err) => ExecutionError::MemoryError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
-                    SystemEventError::Deferred(err) => {
-                        ExecutionError::DeferredError { label, source_file, err }
+                    SystemEventError::Deferred(err) => ExecutionError::DeferredError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
                 })
             },
@@ -859,39 +1127,49 @@ impl<T> MapExecErrWithOpIdx<T> for Result<T, SystemEventError> {
         match (self, context) {
             (Ok(v), _) => Ok(v),
             (Err(err), Some(context)) => {
-                let (label, source_file) = label_and_source_file_from_location(
-                    context.assembly_location(Some(op_idx)),
-                    host,
-                );
+                let (label, source_file, call_sites) =
+                    package_error_context(context, Some(op_idx), host);
                 Err(match err {
                     SystemEventError::Advice(err) => {
-                        ExecutionError::AdviceError { label, source_file, err }
+                        ExecutionError::AdviceError { label, source_file, call_sites, err }
                     },
                     SystemEventError::Operation(err) => {
-                        ExecutionError::OperationError { label, source_file, err }
+                        ExecutionError::OperationError { label, source_file, call_sites, err }
                     },
                     SystemEventError::Memory(err) => {
-                        ExecutionError::MemoryError { label, source_file, err }
+                        ExecutionError::MemoryError { label, source_file, call_sites, err }
                     },
                     SystemEventError::Deferred(err) => {
-                        ExecutionError::DeferredError { label, source_file, err }
+                        ExecutionError::DeferredError { label, source_file, call_sites, err }
                     },
                 })
             },
             (Err(err), None) => {
                 let (label, source_file) = get_label_and_source_file();
                 Err(match err {
-                    SystemEventError::Advice(err) => {
-                        ExecutionError::AdviceError { label, source_file, err }
+                    SystemEventError::Advice(err) => ExecutionError::AdviceError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
-                    SystemEventError::Operation(err) => {
-                        ExecutionError::OperationError { label, source_file, err }
+                    SystemEventError::Operation(err) => ExecutionError::OperationError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
-                    SystemEventError::Memory(err) => {
-                        ExecutionError::MemoryError { label, source_file, err }
+                    SystemEventError::Memory(err) => ExecutionError::MemoryError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
-                    SystemEventError::Deferred(err) => {
-                        ExecutionError::DeferredError { label, source_file, err }
+                    SystemEventError::Deferred(err) => ExecutionError::DeferredError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
                 })
             },
@@ -908,10 +1186,23 @@ impl<T> MapExecErrWithOpIdx<T> for Result<T, IoError> {
             Err(err) => {
                 let (label, source_file) = get_label_and_source_file();
                 Err(match err {
-                    IoError::Advice(err) => ExecutionError::AdviceError { label, source_file, err },
-                    IoError::Memory(err) => ExecutionError::MemoryError { label, source_file, err },
-                    IoError::Operation(err) => {
-                        ExecutionError::OperationError { label, source_file, err }
+                    IoError::Advice(err) => ExecutionError::AdviceError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
+                    },
+                    IoError::Memory(err) => ExecutionError::MemoryError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
+                    },
+                    IoError::Operation(err) => ExecutionError::OperationError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
                     // Execution errors are already fully formed with their own message.
                     IoError::Execution(boxed_err) => *boxed_err,
@@ -931,15 +1222,17 @@ impl<T> MapExecErrWithOpIdx<T> for Result<T, IoError> {
             (Ok(v), _) => Ok(v),
             (Err(IoError::Execution(boxed_err)), _) => Err(*boxed_err),
             (Err(err), Some(context)) => {
-                let (label, source_file) = label_and_source_file_from_location(
-                    context.assembly_location(Some(op_idx)),
-                    host,
-                );
+                let (label, source_file, call_sites) =
+                    package_error_context(context, Some(op_idx), host);
                 Err(match err {
-                    IoError::Advice(err) => ExecutionError::AdviceError { label, source_file, err },
-                    IoError::Memory(err) => ExecutionError::MemoryError { label, source_file, err },
+                    IoError::Advice(err) => {
+                        ExecutionError::AdviceError { label, source_file, call_sites, err }
+                    },
+                    IoError::Memory(err) => {
+                        ExecutionError::MemoryError { label, source_file, call_sites, err }
+                    },
                     IoError::Operation(err) => {
-                        ExecutionError::OperationError { label, source_file, err }
+                        ExecutionError::OperationError { label, source_file, call_sites, err }
                     },
                     IoError::Execution(_) => unreachable!("handled above"),
                 })
@@ -947,10 +1240,23 @@ impl<T> MapExecErrWithOpIdx<T> for Result<T, IoError> {
             (Err(err), None) => {
                 let (label, source_file) = get_label_and_source_file();
                 Err(match err {
-                    IoError::Advice(err) => ExecutionError::AdviceError { label, source_file, err },
-                    IoError::Memory(err) => ExecutionError::MemoryError { label, source_file, err },
-                    IoError::Operation(err) => {
-                        ExecutionError::OperationError { label, source_file, err }
+                    IoError::Advice(err) => ExecutionError::AdviceError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
+                    },
+                    IoError::Memory(err) => ExecutionError::MemoryError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
+                    },
+                    IoError::Operation(err) => ExecutionError::OperationError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
                     IoError::Execution(_) => unreachable!("handled above"),
                 })
@@ -968,11 +1274,17 @@ impl<T> MapExecErrWithOpIdx<T> for Result<T, CryptoError> {
             Err(err) => {
                 let (label, source_file) = get_label_and_source_file();
                 Err(match err {
-                    CryptoError::Advice(err) => {
-                        ExecutionError::AdviceError { label, source_file, err }
+                    CryptoError::Advice(err) => ExecutionError::AdviceError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
-                    CryptoError::Operation(err) => {
-                        ExecutionError::OperationError { label, source_file, err }
+                    CryptoError::Operation(err) => ExecutionError::OperationError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
                 })
             },
@@ -989,29 +1301,36 @@ impl<T> MapExecErrWithOpIdx<T> for Result<T, CryptoError> {
         match (self, context) {
             (Ok(v), _) => Ok(v),
             (Err(err), Some(context)) => {
-                let (label, source_file) = label_and_source_file_from_location(
-                    context.assembly_location(Some(op_idx)),
-                    host,
-                );
+                let (label, source_file, call_sites) =
+                    package_error_context(context, Some(op_idx), host);
                 Err(match err {
                     CryptoError::Advice(err) => {
-                        ExecutionError::AdviceError { label, source_file, err }
+                        ExecutionError::AdviceError { label, source_file, call_sites, err }
                     },
                     CryptoError::Operation(err) => ExecutionError::OperationError {
                         label,
                         source_file,
-                        err: err.with_package_debug_info(context.debug_info()),
+                        call_sites,
+                        err: err
+                            .with_package_debug_info(context.debug_info())
+                            .with_host_error_messages(host),
                     },
                 })
             },
             (Err(err), None) => {
                 let (label, source_file) = get_label_and_source_file();
                 Err(match err {
-                    CryptoError::Advice(err) => {
-                        ExecutionError::AdviceError { label, source_file, err }
+                    CryptoError::Advice(err) => ExecutionError::AdviceError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
-                    CryptoError::Operation(err) => {
-                        ExecutionError::OperationError { label, source_file, err }
+                    CryptoError::Operation(err) => ExecutionError::OperationError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err: err.with_host_error_messages(host),
                     },
                 })
             },
@@ -1031,8 +1350,11 @@ impl<T> MapExecErrWithOpIdx<T> for Result<T, AceEvalError> {
                     AceEvalError::Ace(error) => {
                         ExecutionError::AceChipError { label, source_file, error }
                     },
-                    AceEvalError::Memory(err) => {
-                        ExecutionError::MemoryError { label, source_file, err }
+                    AceEvalError::Memory(err) => ExecutionError::MemoryError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
                 })
             },
@@ -1049,16 +1371,14 @@ impl<T> MapExecErrWithOpIdx<T> for Result<T, AceEvalError> {
         match (self, context) {
             (Ok(v), _) => Ok(v),
             (Err(err), Some(context)) => {
-                let (label, source_file) = label_and_source_file_from_location(
-                    context.assembly_location(Some(op_idx)),
-                    host,
-                );
+                let (label, source_file, call_sites) =
+                    package_error_context(context, Some(op_idx), host);
                 Err(match err {
                     AceEvalError::Ace(error) => {
                         ExecutionError::AceChipError { label, source_file, error }
                     },
                     AceEvalError::Memory(err) => {
-                        ExecutionError::MemoryError { label, source_file, err }
+                        ExecutionError::MemoryError { label, source_file, call_sites, err }
                     },
                 })
             },
@@ -1068,8 +1388,11 @@ impl<T> MapExecErrWithOpIdx<T> for Result<T, AceEvalError> {
                     AceEvalError::Ace(error) => {
                         ExecutionError::AceChipError { label, source_file, error }
                     },
-                    AceEvalError::Memory(err) => {
-                        ExecutionError::MemoryError { label, source_file, err }
+                    AceEvalError::Memory(err) => ExecutionError::MemoryError {
+                        label,
+                        source_file,
+                        call_sites: Box::default(),
+                        err,
                     },
                 })
             },
@@ -1174,7 +1497,7 @@ mod error_assertions {
         let err = OperationError::DivideByZero.with_package_source_context(context, &host, Some(0));
 
         match err {
-            ExecutionError::OperationError { label, source_file, err } => {
+            ExecutionError::OperationError { label, source_file, err, .. } => {
                 assert_eq!(label, host.returned_span);
                 assert!(source_file.is_none());
                 assert!(matches!(err, OperationError::DivideByZero));
@@ -1216,7 +1539,7 @@ mod error_assertions {
         );
 
         match err {
-            ExecutionError::AdviceError { label, source_file, err } => {
+            ExecutionError::AdviceError { label, source_file, err, .. } => {
                 assert_eq!(label, SourceSpan::UNKNOWN);
                 assert!(source_file.is_none());
                 assert!(matches!(err, AdviceError::StackReadFailed));
@@ -1255,6 +1578,7 @@ mod error_assertions {
                 label,
                 source_file,
                 err: OperationError::FailedAssertion { err_msg, .. },
+                ..
             } => {
                 assert_eq!(label, SourceSpan::UNKNOWN);
                 assert!(source_file.is_none());
@@ -1288,4 +1612,65 @@ mod error_assertions {
             err => panic!("expected MerklePathVerificationFailed, got {err:?}"),
         }
     }
+
+    /// A host which labels each location with its byte range in a fixed source file.
+    struct SpanHost;
+
+    impl BaseHost for SpanHost {
+        fn get_label_and_source_file(
+            &self,
+            location: &Location,
+        ) -> (SourceSpan, Option<Arc<SourceFile>>) {
+            let span =
+                SourceSpan::new(SourceId::new(7), location.start.to_u32()..location.end.to_u32());
+            (span, None)
+        }
+    }
+
+    #[test]
+    fn package_source_context_reports_call_sites_as_related_diagnostics() {
+        let source_node_id = DebugSourceNodeId::from(0);
+        let location = |start: u32, end: u32| {
+            Location::new(
+                Uri::new("file://pkg/lib.masm"),
+                ByteIndex::new(start),
+                ByteIndex::new(end),
+            )
+        };
+        let debug_info =
+            PackageDebugInfo::default().with_source_map(DebugSourceMapSection::from_parts(
+                vec![
+                    DebugSourceAsmOp::new(
+                        source_node_id,
+                        0,
+                        Some(location(10, 13)),
+                        "lib::div".into(),
+                        "div".into(),
+                        1,
+                    )
+                    .with_call_sites(vec![location(20, 28), location(30, 38)]),
+                ],
+                Vec::new(),
+            ));
+        let context = PackageSourceDebugContext::new(&debug_info, source_node_id);
+
+        let err =
+            OperationError::DivideByZero.with_package_source_context(context, &SpanHost, Some(0));
+        assert_eq!(Diagnostic::related(&err).map(Iterator::count), Some(2));
+        match err {
+            ExecutionError::OperationError { label, call_sites, .. } => {
+                assert_eq!(label, SourceSpan::new(SourceId::new(7), 10u32..13));
+                let call_site_labels =
+                    call_sites.iter().map(|call_site| call_site.label).collect::<Vec<_>>();
+                assert_eq!(
+                    call_site_labels,
+                    [
+                        SourceSpan::new(SourceId::new(7), 20u32..28),
+                        SourceSpan::new(SourceId::new(7), 30u32..38),
+                    ]
+                );
+            },
+            err => panic!("expected operation error, got {err:?}"),
+        }
+    }
 }
@@ -10,7 +10,7 @@ use crate::{
         ExecutionState, InternalBreakReason, execute_op, finalize_clock_cycle_with_continuation,
         finalize_clock_cycle_with_continuation_and_op_helpers,
     },
-    mast::{BasicBlockNode, ExecutableMastForest, MastNodeId},
+    mast::{BasicBlockNode, ExecutableMastForest, MastNodeExt, MastNodeId},
     operation::Operation,
     processor::Processor,
     tracer::Tracer,
@@ -261,6 +261,10 @@ where
             current_forest,
         );
 
+        if let Some(coverage) = state.processor.coverage_mut() {
+            coverage.record_operation(basic_block.digest(), op_idx_in_block);
+        }
+
         // Execute the operation.
         let operation_helpers = match op {
             Operation::Emit => {
@@ -289,14 +293,25 @@ where
                         state.current_source_node_id(),
                     )
                 });
-                match execute_op(
+                #[cfg(feature = "std")]
+                let op_start =
+                    state.processor.op_timings_mut().is_some().then(std::time::Instant::now);
+                let result = execute_op(
                     state.processor,
                     op,
                     op_idx_in_block,
                     state.host,
                     state.tracer,
                     package_source_context,
-                ) {
+                );
+                #[cfg(feature = "std")]
+                if let Some(op_start) = op_start
+                    && let Some(op_timings) = state.processor.op_timings_mut()
+                {
+                    *op_timings.entry(op.mnemonic()).or_default() += op_start.elapsed();
+                }
+
+                match result {
                     Ok(operation_helpers) => operation_helpers,
                     Err(err) => {
                         return ControlFlow::Break(BreakReason::Err(err).into());
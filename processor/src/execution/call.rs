@@ -9,7 +9,7 @@ use crate::{
         ExecutionState, finalize_clock_cycle, finalize_clock_cycle_with_continuation,
         get_next_ctx_id,
     },
-    mast::{CallNode, ExecutableMastForest, MastNodeId},
+    mast::{CallNode, ExecutableMastForest, MastNode, MastNodeId},
     operation::OperationError,
     option_map_break_reason,
     processor::{MemoryInterface, Processor, StackInterface, SystemInterface},
@@ -41,8 +41,10 @@ where
         current_forest,
     );
 
+    if let Err(err) = state.processor.system_mut().save_call_state() {
+        return ControlFlow::Break(BreakReason::Err(err));
+    }
     state.processor.stack_mut().start_context();
-    state.processor.system_mut().save_call_state();
 
     let callee_hash = option_map_break_reason(
         current_forest.get_digest_by_id(call_node.callee()),
@@ -57,11 +59,16 @@ where
             ));
         }
         state.tracer.record_kernel_proc_access(callee_hash);
+        let clk = state.processor.system().clock();
+        if let Some(report) = state.processor.kernel_access_report_mut() {
+            report.record_access(callee_hash, clk);
+        }
 
         // set the system registers to the syscall context
         state.processor.system_mut().set_ctx(ContextId::root());
     } else {
         let new_ctx: ContextId = get_next_ctx_id(state.processor);
+        let clk = state.processor.system().clock();
 
         // Set the system registers to the callee context.
         state.processor.system_mut().set_ctx(new_ctx);
@@ -71,7 +78,7 @@ where
         if let Err(err) = state
             .processor
             .memory_mut()
-            .write_element(new_ctx, FMP_ADDR, FMP_INIT_VALUE)
+            .write_element(new_ctx, FMP_ADDR, clk, FMP_INIT_VALUE)
             .map_exec_err()
         {
             return ControlFlow::Break(BreakReason::Err(err));
@@ -84,6 +91,14 @@ where
         );
     }
 
+    // An external callee reports the breakpoint itself once it has been resolved.
+    if !current_forest
+        .get_node_by_id(call_node.callee())
+        .is_some_and(MastNode::is_external)
+    {
+        state.processor.check_breakpoint(callee_hash);
+    }
+
     // Update the continuation stack: first push the finish call continuation, then the callee node
     // (to be executed next).
     let callee_source_node_id = match state.child_source_node_id(0) {
@@ -131,8 +146,10 @@ where
         current_forest,
     );
 
+    if let Err(err) = state.processor.system_mut().save_call_state() {
+        return ControlFlow::Break(BreakReason::Err(err));
+    }
     state.processor.stack_mut().start_context();
-    state.processor.system_mut().save_call_state();
 
     let callee_hash = option_map_break_reason(
         current_forest.get_digest_by_id(call_node.callee()),
@@ -147,11 +164,16 @@ where
             ));
         }
         state.tracer.record_kernel_proc_access(callee_hash);
+        let clk = state.processor.system().clock();
+        if let Some(report) = state.processor.kernel_access_report_mut() {
+            report.record_access(callee_hash, clk);
+        }
 
         // set the system registers to the syscall context
         state.processor.system_mut().set_ctx(ContextId::root());
     } else {
         let new_ctx: ContextId = get_next_ctx_id(state.processor);
+        let clk = state.processor.system().clock();
 
         // Set the system registers to the callee context.
         state.processor.system_mut().set_ctx(new_ctx);
@@ -161,7 +183,7 @@ where
         if let Err(err) = state
             .processor
             .memory_mut()
-            .write_element(new_ctx, FMP_ADDR, FMP_INIT_VALUE)
+            .write_element(new_ctx, FMP_ADDR, clk, FMP_INIT_VALUE)
             .map_exec_err()
         {
             return ControlFlow::Break(BreakReason::Err(err));
@@ -174,6 +196,14 @@ where
         );
     }
 
+    // An external callee reports the breakpoint itself once it has been resolved.
+    if !current_forest
+        .get_node_by_id(call_node.callee())
+        .is_some_and(MastNode::is_external)
+    {
+        state.processor.check_breakpoint(callee_hash);
+    }
+
     // Update the continuation stack: first push the finish call continuation, then the callee node
     // (to be executed next).
     state.continuation_stack.push_finish_call(current_node_id);
@@ -73,8 +73,10 @@ where
         let new_ctx: ContextId = get_next_ctx_id(state.processor);
 
         // Save the current state, and update the system registers.
+        if let Err(err) = state.processor.system_mut().save_call_state() {
+            return ControlFlow::Break(BreakReason::Err(err).into());
+        }
         state.processor.stack_mut().start_context();
-        state.processor.system_mut().save_call_state();
 
         state.processor.system_mut().set_ctx(new_ctx);
         state.processor.system_mut().set_caller_hash(callee_hash);
@@ -83,7 +85,7 @@ where
         if let Err(err) = state
             .processor
             .memory_mut()
-            .write_element(new_ctx, FMP_ADDR, FMP_INIT_VALUE)
+            .write_element(new_ctx, FMP_ADDR, clk, FMP_INIT_VALUE)
             .map_exec_err()
         {
             return ControlFlow::Break(BreakReason::Err(err).into());
@@ -95,6 +97,8 @@ where
         state.tracer.record_memory_read_word(callee_hash, mem_addr, read_ctx, clk);
     };
 
+    state.processor.check_breakpoint(callee_hash);
+
     // Update continuation stack
     // -----------------------------
     state.continuation_stack.push_with_source_node_id(
@@ -194,8 +198,10 @@ where
         let new_ctx: ContextId = get_next_ctx_id(state.processor);
 
         // Save the current state, and update the system registers.
+        if let Err(err) = state.processor.system_mut().save_call_state() {
+            return ControlFlow::Break(BreakReason::Err(err).into());
+        }
         state.processor.stack_mut().start_context();
-        state.processor.system_mut().save_call_state();
 
         state.processor.system_mut().set_ctx(new_ctx);
         state.processor.system_mut().set_caller_hash(callee_hash);
@@ -204,7 +210,7 @@ where
         if let Err(err) = state
             .processor
             .memory_mut()
-            .write_element(new_ctx, FMP_ADDR, FMP_INIT_VALUE)
+            .write_element(new_ctx, FMP_ADDR, clk, FMP_INIT_VALUE)
             .map_exec_err()
         {
             return ControlFlow::Break(BreakReason::Err(err).into());
@@ -216,6 +222,8 @@ where
         state.tracer.record_memory_read_word(callee_hash, mem_addr, read_ctx, clk);
     };
 
+    state.processor.check_breakpoint(callee_hash);
+
     // Update continuation stack
     // -----------------------------
     state.continuation_stack.push_finish_dyn(current_node_id);
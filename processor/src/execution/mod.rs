@@ -305,6 +305,12 @@ where
         state.continuation_stack.pop_continuation_with_source_node_id()
     {
         state.current_source_node_id = source_node_id;
+        meter_continuation(
+            state.processor,
+            &continuation,
+            state.continuation_stack,
+            current_forest,
+        );
         match continuation {
             Continuation::StartNode(node_id) => {
                 let node = current_forest.get_node_by_id(node_id).unwrap();
@@ -434,6 +440,12 @@ where
     };
 
     while let Some(continuation) = state.continuation_stack.pop_continuation() {
+        meter_continuation(
+            state.processor,
+            &continuation,
+            state.continuation_stack,
+            current_forest,
+        );
         match continuation {
             Continuation::StartNode(node_id) => {
                 let node = current_forest.get_node_by_id(node_id).unwrap();
@@ -535,6 +547,28 @@ where
     ControlFlow::Continue(())
 }
 
+/// Updates the processor's cycle meter and node spans, if enabled, after `continuation` was popped
+/// off the continuation stack.
+#[inline(always)]
+fn meter_continuation<P, F>(
+    processor: &mut P,
+    continuation: &Continuation<F>,
+    continuation_stack: &ContinuationStack<F>,
+    current_forest: &F,
+) where
+    P: Processor,
+    F: ExecutableMastForest,
+{
+    let clk = processor.system().clock();
+    if let Some(meter) = processor.cycle_meter_mut() {
+        meter.on_continuation(continuation, continuation_stack.len(), clk, current_forest);
+    }
+    #[cfg(feature = "trace-nodes")]
+    if let Some(node_spans) = processor.node_spans_mut() {
+        node_spans.on_continuation(continuation, continuation_stack.len(), clk, current_forest);
+    }
+}
+
 // INTERNAL BREAK REASON
 // ================================================================================================
 
@@ -285,8 +285,8 @@ pub(super) fn op_horner_eval_base<P: Processor, T: Tracer>(
     // Read the evaluation point alpha from memory
     let alpha = {
         let addr = processor.stack().get(ALPHA_ADDR_INDEX);
-        let eval_point_0 = processor.memory_mut().read_element(ctx, addr)?;
-        let eval_point_1 = processor.memory_mut().read_element(ctx, addr + ONE)?;
+        let eval_point_0 = processor.memory_mut().read_element(ctx, addr, clk)?;
+        let eval_point_1 = processor.memory_mut().read_element(ctx, addr + ONE, clk)?;
 
         tracer.record_memory_read_element_pair(
             eval_point_0,
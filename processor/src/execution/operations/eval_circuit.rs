@@ -104,7 +104,7 @@ pub(crate) fn eval_circuit_impl(
     }
     // perform EVAL operations
     for _ in 0..num_eval_rows {
-        let instruction = mem.read_element(ctx, ptr)?;
+        let instruction = mem.read_element(ctx, ptr, clk)?;
         evaluation_context.do_eval(ptr, instruction)?;
         ptr += PTR_OFFSET_ELEM;
     }
@@ -160,15 +160,11 @@ pub(super) fn op_mload<P: Processor, T: Tracer>(
     tracer: &mut T,
 ) -> Result<OperationHelperRegisters, IoError> {
     let ctx = processor.system().ctx();
+    let clk = processor.system().clock();
     let addr = processor.stack().get(0);
 
-    let element = processor.memory_mut().read_element(ctx, addr)?;
-    tracer.record_memory_read_element(
-        element,
-        addr,
-        processor.system().ctx(),
-        processor.system().clock(),
-    );
+    let element = processor.memory_mut().read_element(ctx, addr, clk)?;
+    tracer.record_memory_read_element(element, addr, ctx, clk);
 
     processor.stack_mut().set(0, element);
 
@@ -195,16 +191,12 @@ where
     let addr = processor.stack().get(0);
     let value = processor.stack().get(1);
     let ctx = processor.system().ctx();
+    let clk = processor.system().clock();
 
     processor.stack_mut().decrement_size()?;
 
-    processor.memory_mut().write_element(ctx, addr, value)?;
-    tracer.record_memory_write_element(
-        value,
-        addr,
-        processor.system().ctx(),
-        processor.system().clock(),
-    );
+    processor.memory_mut().write_element(ctx, addr, clk, value)?;
+    tracer.record_memory_write_element(value, addr, ctx, clk);
 
     Ok(OperationHelperRegisters::Empty)
 }
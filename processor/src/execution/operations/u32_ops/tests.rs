@@ -17,6 +17,7 @@ use crate::{
     execution::operations::execute_op,
     fast::{FastProcessor, NoopTracer},
     operation::{Operation, OperationError},
+    tracer::OperationHelperRegisters,
 };
 
 // CASTING OPERATIONS
@@ -149,6 +150,39 @@ fn test_op_u32assert2_first_invalid() {
     );
 }
 
+/// All u32 operations validate their operands before touching the stack, so a non-u32 operand is
+/// always reported as `NotU32Values` with the offending values, and the stack is left unchanged.
+#[test]
+fn test_u32_ops_reject_non_u32_operands_up_front() {
+    type U32Op =
+        fn(&mut FastProcessor, &mut NoopTracer) -> Result<OperationHelperRegisters, OperationError>;
+
+    let ops: [(&str, U32Op); 8] = [
+        ("u32add", op_u32add),
+        ("u32add3", op_u32add3),
+        ("u32sub", op_u32sub),
+        ("u32mul", op_u32mul),
+        ("u32madd", op_u32madd),
+        ("u32div", op_u32div),
+        ("u32and", op_u32and),
+        ("u32xor", op_u32xor),
+    ];
+
+    let invalid = Felt::new_unchecked(u32::MAX as u64 + 1);
+    let inputs = [Felt::new_unchecked(7), invalid, Felt::new_unchecked(3)];
+    for (name, op) in ops {
+        let mut processor = FastProcessor::new(StackInputs::new(&inputs).unwrap());
+        let mut tracer = NoopTracer;
+
+        let err = op(&mut processor, &mut tracer).unwrap_err();
+        assert!(
+            matches!(err, OperationError::NotU32Values { ref values } if values == &[invalid]),
+            "expected {name} to report the non-u32 operand, got: {err:?}"
+        );
+        assert_eq!([0, 1, 2].map(|idx| processor.stack_get(idx)), inputs);
+    }
+}
+
 #[test]
 fn test_op_u32assert2_err_code_propagates_on_invalid() {
     // err_code and the offending value must appear in U32AssertionFailed
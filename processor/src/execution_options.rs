@@ -1,6 +1,9 @@
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicBool, Ordering};
+
 use miden_air::trace::MIN_TRACE_LEN;
 use miden_core::{
-    deferred::DEFAULT_MAX_DEFERRED_ELEMENTS as DEFAULT_DEFERRED_STATE_ELEMENTS,
+    WORD_SIZE, deferred::DEFAULT_MAX_DEFERRED_ELEMENTS as DEFAULT_DEFERRED_STATE_ELEMENTS,
     program::MIN_STACK_DEPTH,
 };
 
@@ -11,7 +14,7 @@ use miden_core::{
 ///
 /// - `max_cycles` specifies the maximum number of cycles a program is allowed to execute.
 /// - `expected_cycles` specifies the number of cycles a program is expected to execute.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ExecutionOptions {
     max_cycles: u32,
     expected_cycles: u32,
@@ -30,6 +33,15 @@ pub struct ExecutionOptions {
     max_num_continuations: usize,
     /// Maximum number of internal nodes allowed in the advice provider's Merkle store.
     max_merkle_store_nodes: usize,
+    /// Maximum number of advice map reads a program is allowed to perform, or `None` if reads are
+    /// not limited.
+    max_adv_map_reads: Option<usize>,
+    /// Maximum number of nested `call`, `dyncall`, and `syscall` contexts, or `None` if nesting is
+    /// only bounded by the other execution limits.
+    max_call_depth: Option<usize>,
+    /// Maximum number of `emit` operations a program is allowed to execute, or `None` if events
+    /// are not limited.
+    max_emitted_events: Option<usize>,
     /// Maximum number of field elements allowed on the operand stack across the active execution
     /// context and all suspended contexts.
     ///
@@ -39,9 +51,34 @@ pub struct ExecutionOptions {
     /// context switches cannot accumulate hidden operand-stack memory beyond the configured
     /// budget.
     max_stack_depth: usize,
+    /// Number of field elements for which the operand stack buffer is allocated when execution
+    /// starts. The buffer grows on demand, up to `max_stack_depth`, if a program needs more.
+    initial_stack_buffer_size: usize,
     /// Maximum number of field elements allowed in the processor's memory at any point during
     /// execution, rounded up to the nearest multiple of 4.
     max_memory_elements: usize,
+    /// Maximum number of field elements allowed in the memory of any single execution context, or
+    /// `None` if only the total across all contexts is limited.
+    max_context_memory_elements: Option<usize>,
+    /// Whether to record the sequence of executed operations when building an execution trace.
+    record_op_log: bool,
+    /// Whether to record the changes made to the advice stack when building an execution trace.
+    record_advice_timeline: bool,
+    /// Whether to record the control-flow nesting depth at each control-flow boundary when
+    /// building an execution trace.
+    record_depth_profile: bool,
+    /// Whether to measure the wall-clock time spent executing each operation kind.
+    record_op_timing: bool,
+    /// Whether to account for the cycles consumed by each executed procedure.
+    record_cycle_report: bool,
+    /// Whether to record the operations executed in each basic block.
+    record_coverage: bool,
+    /// Whether to record every memory access made by executed operations.
+    record_memory_access_log: bool,
+    /// Whether to record the kernel procedures invoked via `syscall`.
+    record_kernel_access_report: bool,
+    /// Token through which execution can be cancelled from outside the processor.
+    cancellation_token: Option<CancellationToken>,
 }
 
 impl Default for ExecutionOptions {
@@ -56,8 +93,22 @@ impl Default for ExecutionOptions {
             max_deferred_elements: Self::DEFAULT_MAX_DEFERRED_ELEMENTS,
             max_num_continuations: Self::DEFAULT_MAX_NUM_CONTINUATIONS,
             max_merkle_store_nodes: Self::DEFAULT_MAX_MERKLE_STORE_NODES,
+            max_adv_map_reads: None,
+            max_call_depth: None,
+            max_emitted_events: None,
             max_stack_depth: Self::DEFAULT_MAX_STACK_DEPTH,
+            initial_stack_buffer_size: Self::DEFAULT_INITIAL_STACK_BUFFER_SIZE,
             max_memory_elements: Self::DEFAULT_MAX_MEMORY_ELEMENTS,
+            max_context_memory_elements: None,
+            record_op_log: false,
+            record_advice_timeline: false,
+            record_depth_profile: false,
+            record_op_timing: false,
+            record_cycle_report: false,
+            record_coverage: false,
+            record_memory_access_log: false,
+            record_kernel_access_report: false,
+            cancellation_token: None,
         }
     }
 }
@@ -69,6 +120,9 @@ impl ExecutionOptions {
     /// The maximum number of VM cycles a program is allowed to take.
     pub const MAX_CYCLES: u32 = 1 << 29;
 
+    /// Number of cycles between two consecutive checks of the cancellation token.
+    pub const CANCELLATION_CHECK_INTERVAL: u32 = 1 << 10;
+
     /// Default fragment size for core trace generation.
     pub const DEFAULT_CORE_TRACE_FRAGMENT_SIZE: usize = 4096; // 2^12
 
@@ -105,6 +159,18 @@ impl ExecutionOptions {
     /// `FastProcessor` stack buffer.
     pub const DEFAULT_MAX_STACK_DEPTH: usize = 6615;
 
+    /// Default number of field elements for which the operand stack buffer is allocated.
+    ///
+    /// This is large enough for the vast majority of programs to never grow the buffer. Programs
+    /// with a shallow stack can use a smaller buffer via [`Self::with_initial_stack_buffer_size`].
+    pub const DEFAULT_INITIAL_STACK_BUFFER_SIZE: usize = 6850;
+
+    /// Minimum number of field elements for which the operand stack buffer can be allocated.
+    ///
+    /// The processor reserves room below the initial 16 stack elements so that dropping elements
+    /// never needs to move the stack, which sets a lower bound on the size of the buffer.
+    pub const MIN_INITIAL_STACK_BUFFER_SIZE: usize = 251;
+
     /// Default maximum number of field elements allowed in the processor's memory.
     ///
     /// Memory is element-addressable, so this bounds the total number of elements live across all
@@ -152,32 +218,15 @@ impl ExecutionOptions {
         // Round up the expected number of cycles to the next power of two. If it is smaller than
         // MIN_TRACE_LEN -- pad expected number to it.
         let expected_cycles = expected_cycles.next_power_of_two().max(MIN_TRACE_LEN as u32);
-        // Validate expected cycles (after rounding) against max_cycles.
-        if max_cycles < expected_cycles {
-            return Err(ExecutionOptionsError::ExpectedCyclesTooBig {
-                max_cycles,
-                expected_cycles,
-            });
-        }
 
-        // Validate core trace fragment size.
-        if core_trace_fragment_size == 0 {
-            return Err(ExecutionOptionsError::CoreTraceFragmentSizeTooSmall);
-        }
-
-        Ok(ExecutionOptions {
+        let options = ExecutionOptions {
             max_cycles,
             expected_cycles,
             core_trace_fragment_size,
-            max_adv_map_value_size: Self::DEFAULT_MAX_ADV_MAP_VALUE_SIZE,
-            max_adv_map_elements: Self::DEFAULT_MAX_ADV_MAP_ELEMENTS,
-            max_hash_len_bytes: Self::DEFAULT_MAX_HASH_LEN_BYTES,
-            max_deferred_elements: Self::DEFAULT_MAX_DEFERRED_ELEMENTS,
-            max_num_continuations: Self::DEFAULT_MAX_NUM_CONTINUATIONS,
-            max_merkle_store_nodes: Self::DEFAULT_MAX_MERKLE_STORE_NODES,
-            max_stack_depth: Self::DEFAULT_MAX_STACK_DEPTH,
-            max_memory_elements: Self::DEFAULT_MAX_MEMORY_ELEMENTS,
-        })
+            ..Self::default()
+        };
+        options.validate()?;
+        Ok(options)
     }
 
     /// Sets the fragment size for core trace generation.
@@ -194,6 +243,86 @@ impl ExecutionOptions {
         Ok(self)
     }
 
+    /// Checks that these options do not contain contradictory settings.
+    ///
+    /// [`Self::new`] and the fallible `with_*` setters validate their inputs as they are applied,
+    /// but the infallible setters do not, since whether a value is valid may depend on values
+    /// configured afterwards. Call this method once all options have been set to catch such
+    /// misconfigurations before execution.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - after rounding up to the next power of two, `expected_cycles` exceeds `max_cycles`
+    /// - `core_trace_fragment_size` is zero
+    /// - `max_stack_depth` is smaller than the minimum stack depth
+    /// - a single advice map value of `max_adv_map_value_size` elements could never fit within the
+    ///   `max_adv_map_elements` budget
+    /// - `max_context_memory_elements` exceeds `max_memory_elements`
+    /// - the limit on advice map reads, call depth or emitted events exceeds `max_cycles`, since
+    ///   each of these takes at least one cycle and the limit could thus never be reached
+    pub fn validate(&self) -> Result<(), ExecutionOptionsError> {
+        if self.max_cycles < self.expected_cycles {
+            return Err(ExecutionOptionsError::ExpectedCyclesTooBig {
+                max_cycles: self.max_cycles,
+                expected_cycles: self.expected_cycles,
+            });
+        }
+
+        if self.core_trace_fragment_size == 0 {
+            return Err(ExecutionOptionsError::CoreTraceFragmentSizeTooSmall);
+        }
+
+        if self.max_stack_depth < MIN_STACK_DEPTH {
+            return Err(ExecutionOptionsError::MaxStackDepthTooSmall {
+                max_stack_depth: self.max_stack_depth,
+                min_stack_depth: MIN_STACK_DEPTH,
+            });
+        }
+
+        if self.initial_stack_buffer_size < Self::MIN_INITIAL_STACK_BUFFER_SIZE {
+            return Err(ExecutionOptionsError::InitialStackBufferSizeTooSmall {
+                initial_stack_buffer_size: self.initial_stack_buffer_size,
+                min_initial_stack_buffer_size: Self::MIN_INITIAL_STACK_BUFFER_SIZE,
+            });
+        }
+
+        // every advice map entry also accounts for the elements of its key
+        let max_entry_elements = self.max_adv_map_value_size.saturating_add(WORD_SIZE);
+        if max_entry_elements > self.max_adv_map_elements {
+            return Err(ExecutionOptionsError::AdviceMapValueSizeExceedsBudget {
+                max_value_size: self.max_adv_map_value_size,
+                max_elements: self.max_adv_map_elements,
+            });
+        }
+
+        if let Some(max_context_memory_elements) = self.max_context_memory_elements
+            && max_context_memory_elements > self.max_memory_elements
+        {
+            return Err(ExecutionOptionsError::ContextMemoryLimitExceedsTotal {
+                max_context_memory_elements,
+                max_memory_elements: self.max_memory_elements,
+            });
+        }
+
+        for (name, limit) in [
+            ("advice map reads", self.max_adv_map_reads),
+            ("call depth", self.max_call_depth),
+            ("emitted events", self.max_emitted_events),
+        ] {
+            if let Some(limit) = limit
+                && limit > self.max_cycles as usize
+            {
+                return Err(ExecutionOptionsError::LimitExceedsMaxCycles {
+                    name,
+                    limit,
+                    max_cycles: self.max_cycles,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -278,6 +407,27 @@ impl ExecutionOptions {
         self.max_merkle_store_nodes
     }
 
+    /// Returns the maximum number of advice map reads a program is allowed to perform, or `None`
+    /// if reads are not limited.
+    #[inline]
+    pub fn max_adv_map_reads(&self) -> Option<usize> {
+        self.max_adv_map_reads
+    }
+
+    /// Returns the maximum number of nested `call`, `dyncall`, and `syscall` contexts, or `None`
+    /// if nesting is not limited.
+    #[inline]
+    pub fn max_call_depth(&self) -> Option<usize> {
+        self.max_call_depth
+    }
+
+    /// Returns the maximum number of `emit` operations a program is allowed to execute, or `None`
+    /// if events are not limited.
+    #[inline]
+    pub fn max_emitted_events(&self) -> Option<usize> {
+        self.max_emitted_events
+    }
+
     /// Returns the maximum number of field elements allowed on the operand stack across the active
     /// execution context and all suspended contexts.
     #[inline]
@@ -285,6 +435,13 @@ impl ExecutionOptions {
         self.max_stack_depth
     }
 
+    /// Returns the number of field elements for which the operand stack buffer is allocated when
+    /// execution starts.
+    #[inline]
+    pub fn initial_stack_buffer_size(&self) -> usize {
+        self.initial_stack_buffer_size
+    }
+
     /// Returns the configured maximum number of field elements allowed in the processor's memory.
     ///
     /// This is the raw value as set via [`Self::with_max_memory_elements`]; the effective cap is
@@ -294,6 +451,72 @@ impl ExecutionOptions {
         self.max_memory_elements
     }
 
+    /// Returns the configured maximum number of field elements allowed in the memory of any single
+    /// execution context, or `None` if memory is not limited per context.
+    ///
+    /// As with [`Self::max_memory_elements`], the effective cap is rounded up to a whole number of
+    /// words.
+    #[inline]
+    pub fn max_context_memory_elements(&self) -> Option<usize> {
+        self.max_context_memory_elements
+    }
+
+    /// Returns true if the sequence of executed operations should be recorded during trace
+    /// generation.
+    #[inline]
+    pub fn records_op_log(&self) -> bool {
+        self.record_op_log
+    }
+
+    /// Returns true if the changes made to the advice stack should be recorded during trace
+    /// generation.
+    #[inline]
+    pub fn records_advice_timeline(&self) -> bool {
+        self.record_advice_timeline
+    }
+
+    /// Returns true if the control-flow nesting depth should be recorded during trace generation.
+    #[inline]
+    pub fn records_depth_profile(&self) -> bool {
+        self.record_depth_profile
+    }
+
+    /// Returns true if the wall-clock time spent executing each operation kind should be measured.
+    #[inline]
+    pub fn records_op_timing(&self) -> bool {
+        self.record_op_timing
+    }
+
+    /// Returns true if the cycles consumed by each executed procedure should be accounted for.
+    #[inline]
+    pub fn records_cycle_report(&self) -> bool {
+        self.record_cycle_report
+    }
+
+    /// Returns true if the operations executed in each basic block should be recorded.
+    #[inline]
+    pub fn records_coverage(&self) -> bool {
+        self.record_coverage
+    }
+
+    /// Returns true if every memory access made by executed operations should be recorded.
+    #[inline]
+    pub fn records_memory_access_log(&self) -> bool {
+        self.record_memory_access_log
+    }
+
+    /// Returns true if the kernel procedures invoked via `syscall` should be recorded.
+    #[inline]
+    pub fn records_kernel_access_report(&self) -> bool {
+        self.record_kernel_access_report
+    }
+
+    /// Returns the token through which execution can be cancelled, if any.
+    #[inline]
+    pub fn cancellation_token(&self) -> Option<&CancellationToken> {
+        self.cancellation_token.as_ref()
+    }
+
     /// Sets the maximum number of continuations allowed on the continuation stack.
     pub fn with_max_num_continuations(mut self, max_num_continuations: usize) -> Self {
         self.max_num_continuations = max_num_continuations;
@@ -306,6 +529,34 @@ impl ExecutionOptions {
         self
     }
 
+    /// Sets the maximum number of advice map reads a program is allowed to perform.
+    ///
+    /// Every `adv.push_mapval`, `adv.push_mapval_count`, `adv.push_mapvaln`, and `adv.has_mapkey`
+    /// counts as a single read, regardless of whether the key is present in the map.
+    pub fn with_max_advice_reads(mut self, limit: usize) -> Self {
+        self.max_adv_map_reads = Some(limit);
+        self
+    }
+
+    /// Sets the maximum number of nested `call`, `dyncall`, and `syscall` contexts.
+    ///
+    /// Entering a context beyond this depth fails with
+    /// [`crate::ExecutionError::CallDepthLimitExceeded`], which lets a host stop runaway
+    /// recursion in untrusted programs before it exhausts other resources.
+    pub fn with_max_call_depth(mut self, limit: usize) -> Self {
+        self.max_call_depth = Some(limit);
+        self
+    }
+
+    /// Sets the maximum number of `emit` operations a program is allowed to execute.
+    ///
+    /// Every `emit` counts towards the limit, including those handled by the VM itself as system
+    /// events. Executing more fails with [`crate::ExecutionError::EventLimitExceeded`].
+    pub fn with_max_emitted_events(mut self, limit: usize) -> Self {
+        self.max_emitted_events = Some(limit);
+        self
+    }
+
     /// Sets the maximum number of field elements allowed on the operand stack across the active
     /// execution context and all suspended contexts.
     pub fn with_max_stack_depth(
@@ -322,13 +573,167 @@ impl ExecutionOptions {
         Ok(self)
     }
 
+    /// Sets the number of field elements for which the operand stack buffer is allocated when
+    /// execution starts.
+    ///
+    /// A smaller buffer is cheaper to allocate for programs with a shallow stack; if a program
+    /// pushes past the end of the buffer, the buffer is reallocated to a larger size rather than
+    /// failing execution. The operand stack depth is only limited by [`Self::max_stack_depth`].
+    pub fn with_initial_stack_buffer_size(
+        mut self,
+        initial_stack_buffer_size: usize,
+    ) -> Result<Self, ExecutionOptionsError> {
+        if initial_stack_buffer_size < Self::MIN_INITIAL_STACK_BUFFER_SIZE {
+            return Err(ExecutionOptionsError::InitialStackBufferSizeTooSmall {
+                initial_stack_buffer_size,
+                min_initial_stack_buffer_size: Self::MIN_INITIAL_STACK_BUFFER_SIZE,
+            });
+        }
+        self.initial_stack_buffer_size = initial_stack_buffer_size;
+        Ok(self)
+    }
+
     /// Sets the maximum number of field elements allowed in the processor's memory.
     pub fn with_max_memory_elements(mut self, max_memory_elements: usize) -> Self {
         self.max_memory_elements = max_memory_elements;
         self
     }
+
+    /// Sets the maximum number of field elements allowed in the memory of any single execution
+    /// context.
+    ///
+    /// Writes which would grow the memory of a context past this limit fail with
+    /// [`crate::MemoryError::ContextMemoryLimitExceeded`]. This bounds the memory footprint of
+    /// each context independently of [`Self::with_max_memory_elements`], which limits the total
+    /// across all contexts.
+    pub fn with_max_context_memory_elements(mut self, max_elements: usize) -> Self {
+        self.max_context_memory_elements = Some(max_elements);
+        self
+    }
+
+    /// Enables recording of the ordered list of operations executed by the VM.
+    ///
+    /// When enabled, the execution trace produced from this execution exposes the log via
+    /// [`crate::trace::ExecutionTrace::op_log`].
+    pub fn with_op_log(mut self) -> Self {
+        self.record_op_log = true;
+        self
+    }
+
+    /// Enables recording of the values pushed onto and popped off the advice stack.
+    ///
+    /// When enabled, the execution trace produced from this execution exposes the recorded events
+    /// via [`crate::trace::ExecutionTrace::advice_timeline`].
+    pub fn with_advice_timeline(mut self) -> Self {
+        self.record_advice_timeline = true;
+        self
+    }
+
+    /// Enables recording of the control-flow nesting depth at each control-flow boundary.
+    ///
+    /// When enabled, the execution trace produced from this execution exposes the recorded samples
+    /// via [`crate::trace::ExecutionTrace::depth_profile`].
+    pub fn with_depth_profile(mut self) -> Self {
+        self.record_depth_profile = true;
+        self
+    }
+
+    /// Enables measuring the wall-clock time spent executing each operation kind.
+    ///
+    /// When enabled, the accumulated timings are available via
+    /// [`crate::ExecutionOutput::op_timings`]. Since every operation is timed individually, this
+    /// adds significant overhead to execution and should only be used for profiling the VM itself.
+    #[cfg(feature = "std")]
+    pub fn with_op_timing(mut self) -> Self {
+        self.record_op_timing = true;
+        self
+    }
+
+    /// Enables accounting for the cycles consumed by each executed procedure and call stack.
+    ///
+    /// When enabled, the resulting report is available via
+    /// [`crate::ExecutionOutput::cycle_report`], and can be exported for flamegraph tools via
+    /// [`crate::CycleReport::to_folded_stacks`].
+    pub fn with_cycle_report(mut self) -> Self {
+        self.record_cycle_report = true;
+        self
+    }
+
+    /// Enables recording of the operations executed in each basic block.
+    ///
+    /// When enabled, the resulting report is available via [`crate::ExecutionOutput::coverage`].
+    pub fn with_coverage(mut self) -> Self {
+        self.record_coverage = true;
+        self
+    }
+
+    /// Enables recording of every memory access made by executed operations.
+    ///
+    /// When enabled, the ordered list of accesses is available via
+    /// [`crate::FastProcessor::memory_access_log`] during execution, and via `access_log()` on
+    /// [`crate::ExecutionOutput::memory`] afterwards.
+    pub fn with_memory_access_log(mut self) -> Self {
+        self.record_memory_access_log = true;
+        self
+    }
+
+    /// Enables recording of the kernel procedures invoked via `syscall`.
+    ///
+    /// When enabled, the resulting report is available via
+    /// [`crate::ExecutionOutput::kernel_access_report`].
+    pub fn with_kernel_access_report(mut self) -> Self {
+        self.record_kernel_access_report = true;
+        self
+    }
+
+    /// Sets the token through which execution can be cancelled.
+    ///
+    /// The token is checked every [`Self::CANCELLATION_CHECK_INTERVAL`] cycles; once it has been
+    /// cancelled, execution stops with [`crate::ExecutionError::Cancelled`]. This allows a host to
+    /// abort a runaway program without waiting for the cycle limit to be reached.
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
 }
 
+// CANCELLATION TOKEN
+// ================================================================================================
+
+/// A handle through which an ongoing execution can be cancelled, possibly from another thread.
+///
+/// Clones of a token share the same cancellation state, so a clone can be passed to
+/// [`ExecutionOptions::with_cancellation_token`] while the original is kept to cancel execution.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Returns a new token which has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation of every execution using this token (or a clone of it).
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if cancellation has been requested.
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+impl PartialEq for CancellationToken {
+    /// Two tokens are equal if they share the same cancellation state.
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for CancellationToken {}
+
 // EXECUTION OPTIONS ERROR
 // ================================================================================================
 
@@ -349,6 +754,35 @@ pub enum ExecutionOptionsError {
         max_stack_depth: usize,
         min_stack_depth: usize,
     },
+    #[error(
+        "initial stack buffer size {initial_stack_buffer_size} must be at least {min_initial_stack_buffer_size}"
+    )]
+    InitialStackBufferSizeTooSmall {
+        initial_stack_buffer_size: usize,
+        min_initial_stack_buffer_size: usize,
+    },
+    #[error(
+        "maximum advice map value size {max_value_size} does not fit within the advice map budget of {max_elements} elements"
+    )]
+    AdviceMapValueSizeExceedsBudget {
+        max_value_size: usize,
+        max_elements: usize,
+    },
+    #[error(
+        "maximum context memory of {max_context_memory_elements} elements exceeds the maximum memory of {max_memory_elements} elements"
+    )]
+    ContextMemoryLimitExceedsTotal {
+        max_context_memory_elements: usize,
+        max_memory_elements: usize,
+    },
+    #[error(
+        "limit of {limit} on {name} can never be reached within the maximum number of cycles {max_cycles}"
+    )]
+    LimitExceedsMaxCycles {
+        name: &'static str,
+        limit: usize,
+        max_cycles: u32,
+    },
 }
 
 // TESTS
@@ -356,6 +790,8 @@ pub enum ExecutionOptionsError {
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
+
     use super::*;
 
     #[test]
@@ -408,6 +844,58 @@ mod tests {
         assert_eq!(opts.unwrap().expected_cycles(), 64);
     }
 
+    #[test]
+    fn validate_rejects_contradictory_options() {
+        assert!(ExecutionOptions::default().validate().is_ok());
+
+        let options = ExecutionOptions::default()
+            .with_max_adv_map_value_size(1 << 10)
+            .with_max_adv_map_elements(1 << 10);
+        let err = options.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionOptionsError::AdviceMapValueSizeExceedsBudget {
+                max_value_size: 1024,
+                max_elements: 1024,
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "maximum advice map value size 1024 does not fit within the advice map budget of 1024 elements"
+        );
+
+        let options = options.with_max_adv_map_elements((1 << 10) + WORD_SIZE);
+        assert!(options.validate().is_ok());
+
+        let options = options
+            .with_max_memory_elements(1 << 10)
+            .with_max_context_memory_elements(1 << 11);
+        assert!(matches!(
+            options.validate(),
+            Err(ExecutionOptionsError::ContextMemoryLimitExceedsTotal {
+                max_context_memory_elements: 2048,
+                max_memory_elements: 1024,
+            })
+        ));
+
+        let options = ExecutionOptions::new(Some(1 << 10), 64, 1024)
+            .unwrap()
+            .with_max_emitted_events((1 << 10) + 1);
+        let err = options.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionOptionsError::LimitExceedsMaxCycles {
+                name: "emitted events",
+                limit: 1025,
+                max_cycles: 1024
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "limit of 1025 on emitted events can never be reached within the maximum number of cycles 1024"
+        );
+    }
+
     #[test]
     fn max_stack_depth_validates_minimum_depth() {
         let result = ExecutionOptions::default().with_max_stack_depth(MIN_STACK_DEPTH - 1);
@@ -423,4 +911,21 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap().max_stack_depth(), MIN_STACK_DEPTH);
     }
+
+    #[test]
+    fn initial_stack_buffer_size_validates_minimum_size() {
+        const MIN_SIZE: usize = ExecutionOptions::MIN_INITIAL_STACK_BUFFER_SIZE;
+
+        let result = ExecutionOptions::default().with_initial_stack_buffer_size(MIN_SIZE - 1);
+        assert!(matches!(
+            result,
+            Err(ExecutionOptionsError::InitialStackBufferSizeTooSmall {
+                initial_stack_buffer_size,
+                min_initial_stack_buffer_size: MIN_SIZE,
+            }) if initial_stack_buffer_size == MIN_SIZE - 1
+        ));
+
+        let result = ExecutionOptions::default().with_initial_stack_buffer_size(MIN_SIZE);
+        assert_eq!(result.unwrap().initial_stack_buffer_size(), MIN_SIZE);
+    }
 }
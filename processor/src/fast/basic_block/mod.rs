@@ -5,7 +5,7 @@ use miden_core::events::{EventId, SystemEvent};
 use miden_mast_package::debug_info::{DebugSourceNodeId, PackageDebugInfo};
 
 use crate::{
-    BaseHost, Host, SyncHost,
+    BaseHost, ExecutionError, Host, SyncHost,
     advice::AdviceMutation,
     errors::{
         MapExecErrWithOpIdx, PackageSourceDebugContext, advice_error_with_context,
@@ -22,6 +22,19 @@ pub use sys_event_handlers::SystemEventError;
 use sys_event_handlers::handle_system_event;
 
 impl FastProcessor {
+    /// Counts an executed `emit` operation against the configured event limit, returning a
+    /// `BreakReason::Err` if the limit is exceeded.
+    #[inline(always)]
+    fn count_emitted_event<F>(&mut self) -> ControlFlow<BreakReason<F>> {
+        self.num_emitted_events += 1;
+        match self.options.max_emitted_events() {
+            Some(max) if self.num_emitted_events > max => {
+                ControlFlow::Break(BreakReason::Err(ExecutionError::EventLimitExceeded(max)))
+            },
+            _ => ControlFlow::Continue(()),
+        }
+    }
+
     #[inline(always)]
     fn handle_system_event<F>(
         &mut self,
@@ -95,6 +108,7 @@ impl FastProcessor {
         package_debug_info: Option<&PackageDebugInfo>,
         source_node_id: Option<DebugSourceNodeId>,
     ) -> ControlFlow<BreakReason<F>> {
+        self.count_emitted_event()?;
         let event_id = EventId::from_felt(self.stack_get(0));
 
         // If it's a system event, handle it directly. Otherwise, forward it to the host.
@@ -128,6 +142,7 @@ impl FastProcessor {
         package_debug_info: Option<&PackageDebugInfo>,
         source_node_id: Option<DebugSourceNodeId>,
     ) -> ControlFlow<BreakReason<F>> {
+        self.count_emitted_event()?;
         let event_id = EventId::from_felt(self.stack_get(0));
 
         if let Some(system_event) = SystemEvent::from_event_id(event_id) {
@@ -345,6 +345,7 @@ fn copy_map_value_to_adv_stack(
 ) -> Result<(), SystemEventError> {
     let key = processor.stack_get_word(1);
 
+    processor.advice.record_map_read()?;
     processor.advice.push_from_map(key, include_len, pad_to)?;
 
     Ok(())
@@ -371,6 +372,7 @@ fn copy_map_value_length_to_adv_stack(
 ) -> Result<(), SystemEventError> {
     let key = processor.stack_get_word(1);
 
+    processor.advice.record_map_read()?;
     let values_len = processor
         .advice
         .get_mapped_values(&key)
@@ -400,7 +402,11 @@ fn copy_map_value_length_to_adv_stack(
 pub fn push_key_presence_flag(processor: &mut FastProcessor) -> Result<(), SystemEventError> {
     let map_key = processor.stack_get_word(1);
 
+    processor.advice.record_map_read()?;
     let presence_flag = processor.advice.contains_map_key(&map_key);
+    if !presence_flag && processor.advice.has_strict_map_key_checks() {
+        return Err(AdviceError::MapKeyNotFound { key: map_key }.into());
+    }
     processor.advice.push_stack(Felt::from_bool(presence_flag))?;
 
     Ok(())
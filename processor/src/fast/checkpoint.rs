@@ -0,0 +1,391 @@
+//! This module defines [`ProcessorSnapshot`], which allows the execution of a program to be paused,
+//! persisted, and resumed later.
+
+use alloc::{format, sync::Arc, vec, vec::Vec};
+use core::convert::Infallible;
+
+use miden_air::{Felt, trace::RowIndex};
+use miden_core::{
+    Word, ZERO,
+    advice::AdviceInputs,
+    deferred::{DeferredState, DeferredStateWire},
+    mast::{MastForest, MastNode, MastNodeId},
+    program::{MIN_STACK_DEPTH, Program},
+    serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+use super::{FastProcessor, ResumeContext, STACK_BUFFER_BASE_IDX, SystemCallState};
+use crate::{
+    ContextId, ExecutionOptions,
+    continuation_stack::{Continuation, ContinuationStack, read_continuation, write_continuation},
+    errors::CheckpointError,
+};
+
+// PROCESSOR SNAPSHOT
+// ================================================================================================
+
+/// The complete state of a [`FastProcessor`] paused in the middle of the execution of a program.
+///
+/// A snapshot is taken with [`FastProcessor::checkpoint`] and can be serialized, persisted, and
+/// later turned back into a processor ready to continue execution with
+/// [`FastProcessor::resume_from`].
+///
+/// A snapshot captures the operand stack (including the overflow of suspended execution contexts),
+/// memory, advice provider, deferred state, continuation stack, clock cycle, number of emitted
+/// events, and number of advice map reads. It does not capture the host, execution options, or the
+/// MAST forest of the program, which must be provided again when resuming.
+#[derive(Debug, Clone)]
+pub struct ProcessorSnapshot {
+    /// Commitment to the MAST forest of the program being executed.
+    forest_commitment: Word,
+    clk: RowIndex,
+    /// Number of `emit` operations executed so far, checked against the emitted events limit.
+    num_emitted_events: usize,
+    /// Number of advice map reads performed so far, checked against the advice map read limit.
+    num_map_reads: usize,
+    ctx: ContextId,
+    caller_hash: Word,
+    /// The operand stack of the active context, with the top of the stack at the last index.
+    stack: Vec<Felt>,
+    /// The saved state of each suspended execution context, from outermost to innermost.
+    suspended_contexts: Vec<SuspendedContext>,
+    memory: Vec<(ContextId, u32, Word)>,
+    advice: AdviceInputs,
+    deferred_state: DeferredStateWire,
+    /// The continuation stack, with the next continuation to execute at the last index.
+    continuations: Vec<Continuation<Infallible>>,
+}
+
+/// The state of an execution context suspended by a `call`, `syscall` or `dyncall`.
+#[derive(Debug, Clone)]
+struct SuspendedContext {
+    ctx: ContextId,
+    caller_hash: Word,
+    stack_overflow: Vec<Felt>,
+}
+
+impl ProcessorSnapshot {
+    /// Returns the clock cycle at which the snapshot was taken.
+    pub fn clk(&self) -> RowIndex {
+        self.clk
+    }
+
+    /// Returns the commitment to the MAST forest of the program whose execution was paused.
+    pub fn forest_commitment(&self) -> Word {
+        self.forest_commitment
+    }
+}
+
+// CHECKPOINT AND RESUME
+// ================================================================================================
+
+impl FastProcessor {
+    /// Returns a snapshot of the processor state which allows execution to be resumed from the
+    /// point described by `resume_ctx` (e.g., as returned by [`Self::step_sync`]).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the processor is executing a procedure from a MAST forest other than the program's, i.e.
+    ///   one which was loaded from the host to resolve an external node.
+    /// - the deferred state could not be serialized.
+    pub fn checkpoint(
+        &self,
+        resume_ctx: &ResumeContext,
+    ) -> Result<ProcessorSnapshot, CheckpointError> {
+        let continuations = resume_ctx
+            .continuation_stack
+            .clone()
+            .into_inner()
+            .into_iter()
+            .map(|continuation| match continuation {
+                Continuation::EnterForest { .. } => Err(CheckpointError::ExternalForestActive),
+                continuation => Ok(continuation.map_forest()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let suspended_contexts = self
+            .system_call_state_stack
+            .iter()
+            .zip(self.stack_overflow_save_stack.iter())
+            .map(|(state, stack_overflow)| SuspendedContext {
+                ctx: state.ctx,
+                caller_hash: state.caller_hash,
+                stack_overflow: stack_overflow.clone(),
+            })
+            .collect();
+
+        let advice = AdviceInputs {
+            stack: self.advice.stack(),
+            map: self.advice.map().clone(),
+            store: self.advice.merkle_store().clone(),
+        };
+
+        Ok(ProcessorSnapshot {
+            forest_commitment: resume_ctx.current_forest.commitment(),
+            clk: self.clk,
+            num_emitted_events: self.num_emitted_events,
+            num_map_reads: self.advice.map_read_count(),
+            ctx: self.ctx,
+            caller_hash: self.caller_hash,
+            stack: self.stack().to_vec(),
+            suspended_contexts,
+            memory: self.memory.snapshot(),
+            advice,
+            deferred_state: self
+                .deferred_state
+                .to_wire()
+                .map_err(CheckpointError::DeferredStateIntegrity)?,
+            continuations,
+        })
+    }
+
+    /// Creates a processor from `snapshot`, and returns it together with the resume context to be
+    /// used with the next call to [`Self::step_sync`].
+    ///
+    /// `program` must be the program whose execution was paused when the snapshot was taken.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the MAST forest of `program` is not the one being executed when the snapshot was taken.
+    /// - the continuations in the snapshot do not refer to valid nodes of `program`.
+    /// - the snapshot state does not fit the limits of `options`.
+    pub fn resume_from(
+        snapshot: &ProcessorSnapshot,
+        program: &Program,
+        options: ExecutionOptions,
+    ) -> Result<(Self, ResumeContext), CheckpointError> {
+        let forest = program.mast_forest();
+        if forest.commitment() != snapshot.forest_commitment {
+            return Err(CheckpointError::ProgramMismatch {
+                expected: snapshot.forest_commitment,
+                actual: forest.commitment(),
+            });
+        }
+
+        let mut continuation_stack = ContinuationStack::default();
+        for continuation in snapshot.continuations.iter() {
+            validate_continuation(continuation, forest)?;
+            continuation_stack.push_continuation(continuation.clone().map_forest());
+        }
+
+        let saved_overflow_len = snapshot
+            .suspended_contexts
+            .iter()
+            .map(|context| context.stack_overflow.len())
+            .sum::<usize>();
+        let depth = snapshot.stack.len() + saved_overflow_len;
+        if snapshot.stack.len() < MIN_STACK_DEPTH || depth > options.max_stack_depth() {
+            return Err(CheckpointError::InvalidStackDepth {
+                depth,
+                max: options.max_stack_depth(),
+            });
+        }
+
+        let max_deferred_elements = options.max_deferred_elements();
+        let initial_stack_buffer_size = options.initial_stack_buffer_size();
+        let mut processor =
+            Self::new_with_options(Default::default(), snapshot.advice.clone(), options)?;
+
+        // Place the live stack at the normal base of a buffer large enough to hold it.
+        let stack_bot_idx = STACK_BUFFER_BASE_IDX;
+        let stack_top_idx = stack_bot_idx + snapshot.stack.len();
+        let mut stack = vec![ZERO; initial_stack_buffer_size.max(stack_top_idx + 1)];
+        stack[stack_bot_idx..stack_top_idx].copy_from_slice(&snapshot.stack);
+        processor.stack = stack.into_boxed_slice();
+        processor.stack_bot_idx = stack_bot_idx;
+        processor.stack_top_idx = stack_top_idx;
+
+        for context in snapshot.suspended_contexts.iter() {
+            processor.system_call_state_stack.push(SystemCallState {
+                ctx: context.ctx,
+                caller_hash: context.caller_hash,
+            });
+            processor.stack_overflow_save_stack.push(context.stack_overflow.clone());
+        }
+        processor.saved_overflow_len = saved_overflow_len;
+
+        for &(ctx, addr, word) in snapshot.memory.iter() {
+            processor
+                .memory
+                .write_word(ctx, Felt::from_u32(addr), RowIndex::from(0_u32), word)?;
+        }
+
+        processor.deferred_state = DeferredState::from_wire(
+            Arc::new(miden_precompiles::registry()),
+            &snapshot.deferred_state,
+            max_deferred_elements,
+        )
+        .map_err(CheckpointError::DeferredStateIntegrity)?;
+
+        processor.clk = snapshot.clk;
+        processor.num_emitted_events = snapshot.num_emitted_events;
+        processor.advice.set_map_read_count(snapshot.num_map_reads);
+        processor.ctx = snapshot.ctx;
+        processor.caller_hash = snapshot.caller_hash;
+
+        let resume_ctx = ResumeContext {
+            current_forest: forest.clone(),
+            continuation_stack,
+            kernel: program.kernel().clone(),
+            package_debug_info: None,
+        };
+
+        Ok((processor, resume_ctx))
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+impl<F> Continuation<F> {
+    /// Converts a continuation which does not enter a MAST forest to a continuation with a
+    /// different forest representation.
+    ///
+    /// # Panics
+    /// Panics if the continuation is [`Continuation::EnterForest`].
+    fn map_forest<G>(self) -> Continuation<G> {
+        match self {
+            Continuation::StartNode(id) => Continuation::StartNode(id),
+            Continuation::FinishJoin(id) => Continuation::FinishJoin(id),
+            Continuation::FinishSplit(id) => Continuation::FinishSplit(id),
+            Continuation::FinishLoop(id) => Continuation::FinishLoop(id),
+            Continuation::FinishCall(id) => Continuation::FinishCall(id),
+            Continuation::FinishDyn(id) => Continuation::FinishDyn(id),
+            Continuation::ResumeBasicBlock { node_id, batch_index, op_idx_in_batch } => {
+                Continuation::ResumeBasicBlock { node_id, batch_index, op_idx_in_batch }
+            },
+            Continuation::Respan { node_id, batch_index } => {
+                Continuation::Respan { node_id, batch_index }
+            },
+            Continuation::FinishBasicBlock(id) => Continuation::FinishBasicBlock(id),
+            Continuation::EnterForest { .. } => {
+                panic!("EnterForest continuations cannot change their forest representation")
+            },
+        }
+    }
+}
+
+/// Checks that `continuation` refers to a node of `forest` of the kind expected by the
+/// continuation.
+fn validate_continuation(
+    continuation: &Continuation<Infallible>,
+    forest: &MastForest,
+) -> Result<(), CheckpointError> {
+    let invalid = || CheckpointError::InvalidContinuation(format!("{continuation:?}"));
+    let node = |node_id: &MastNodeId| forest.get_node_by_id(*node_id).ok_or_else(invalid);
+
+    let is_valid = match continuation {
+        Continuation::StartNode(node_id) => !matches!(node(node_id)?, MastNode::External(_)),
+        Continuation::FinishJoin(node_id) => matches!(node(node_id)?, MastNode::Join(_)),
+        Continuation::FinishSplit(node_id) => matches!(node(node_id)?, MastNode::Split(_)),
+        Continuation::FinishLoop(node_id) => matches!(node(node_id)?, MastNode::Loop(_)),
+        Continuation::FinishCall(node_id) => matches!(node(node_id)?, MastNode::Call(_)),
+        Continuation::FinishDyn(node_id) => matches!(node(node_id)?, MastNode::Dyn(_)),
+        Continuation::FinishBasicBlock(node_id) => node(node_id)?.is_basic_block(),
+        Continuation::ResumeBasicBlock { node_id, batch_index, op_idx_in_batch } => node(node_id)?
+            .get_basic_block()
+            .and_then(|block| block.op_batches().get(*batch_index))
+            .is_some_and(|batch| *op_idx_in_batch < batch.ops().len()),
+        Continuation::Respan { node_id, batch_index } => node(node_id)?
+            .get_basic_block()
+            .is_some_and(|block| *batch_index < block.op_batches().len()),
+        Continuation::EnterForest { forest, .. } => match *forest {},
+    };
+
+    if is_valid { Ok(()) } else { Err(invalid()) }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ProcessorSnapshot {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.forest_commitment.write_into(target);
+        target.write_u32(self.clk.into());
+        target.write_usize(self.num_emitted_events);
+        target.write_usize(self.num_map_reads);
+        target.write_u32(self.ctx.into());
+        self.caller_hash.write_into(target);
+        self.stack.write_into(target);
+
+        target.write_usize(self.suspended_contexts.len());
+        for context in self.suspended_contexts.iter() {
+            target.write_u32(context.ctx.into());
+            context.caller_hash.write_into(target);
+            context.stack_overflow.write_into(target);
+        }
+
+        target.write_usize(self.memory.len());
+        for &(ctx, addr, word) in self.memory.iter() {
+            target.write_u32(ctx.into());
+            target.write_u32(addr);
+            word.write_into(target);
+        }
+
+        self.advice.write_into(target);
+        self.deferred_state.write_into(target);
+
+        target.write_usize(self.continuations.len());
+        for continuation in self.continuations.iter() {
+            write_continuation(continuation, target, |forest, _| match *forest {});
+        }
+    }
+}
+
+impl Deserializable for ProcessorSnapshot {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let forest_commitment = Word::read_from(source)?;
+        let clk = RowIndex::from(source.read_u32()?);
+        let num_emitted_events = source.read_usize()?;
+        let num_map_reads = source.read_usize()?;
+        let ctx = ContextId::from(source.read_u32()?);
+        let caller_hash = Word::read_from(source)?;
+        let stack = Vec::<Felt>::read_from(source)?;
+
+        let num_suspended_contexts = source.read_usize()?;
+        let mut suspended_contexts = Vec::new();
+        for _ in 0..num_suspended_contexts {
+            suspended_contexts.push(SuspendedContext {
+                ctx: ContextId::from(source.read_u32()?),
+                caller_hash: Word::read_from(source)?,
+                stack_overflow: Vec::<Felt>::read_from(source)?,
+            });
+        }
+
+        let num_memory_words = source.read_usize()?;
+        let mut memory = Vec::new();
+        for _ in 0..num_memory_words {
+            let ctx = ContextId::from(source.read_u32()?);
+            let addr = source.read_u32()?;
+            memory.push((ctx, addr, Word::read_from(source)?));
+        }
+
+        let advice = AdviceInputs::read_from(source)?;
+        let deferred_state = DeferredStateWire::read_from(source)?;
+
+        let num_continuations = source.read_usize()?;
+        let mut continuations = Vec::new();
+        for _ in 0..num_continuations {
+            continuations.push(read_continuation(source, |_| {
+                Err(DeserializationError::InvalidValue(
+                    "processor snapshot cannot contain a forest continuation".into(),
+                ))
+            })?);
+        }
+
+        Ok(Self {
+            forest_commitment,
+            clk,
+            num_emitted_events,
+            num_map_reads,
+            ctx,
+            caller_hash,
+            stack,
+            suspended_contexts,
+            memory,
+            advice,
+            deferred_state,
+            continuations,
+        })
+    }
+}
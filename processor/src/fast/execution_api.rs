@@ -1,4 +1,4 @@
-use alloc::{sync::Arc, vec::Vec};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 use core::ops::ControlFlow;
 
 use miden_core::{
@@ -6,15 +6,19 @@ use miden_core::{
     mast::{MastForest, MastNodeId},
     program::{KernelDescriptor, MIN_STACK_DEPTH, Program, StackOutputs},
 };
-use miden_mast_package::debug_info::{
-    DebugSourceGraphLookupError, DebugSourceNodeId, PackageDebugInfo,
+use miden_mast_package::{
+    SourceMap,
+    debug_info::{DebugSourceGraphLookupError, DebugSourceNodeId, PackageDebugInfo},
 };
 use tracing::instrument;
 
 use super::{
     FastProcessor, NoopTracer,
     external::maybe_use_caller_error_context,
-    step::{BreakReason, NeverStopper, ResumeContext, StepStopper},
+    observer::{ExecutionObserver, ObserverTracer},
+    step::{
+        BreakReason, BreakpointStopper, NeverStopper, ResumeContext, StepStopper, WatchpointStopper,
+    },
 };
 use crate::{
     ExecutionError, ExecutionOutput, Host, LoadedMastForest, Stopper, SyncHost, TraceBuildInputs,
@@ -26,7 +30,8 @@ use crate::{
         InternalBreakReason, execute_impl, finish_emit_op_execution,
         finish_load_mast_forest_from_dyn_start, finish_load_mast_forest_from_external,
     },
-    trace::execution_tracer::ExecutionTracer,
+    processor::Processor,
+    trace::{CoreTraceFragmentStore, execution_tracer::ExecutionTracer},
     tracer::Tracer,
 };
 
@@ -35,6 +40,8 @@ impl FastProcessor {
     // -------------------------------------------------------------------------------------------
 
     /// Executes the given program synchronously and returns the execution output.
+    ///
+    /// Execution runs on the calling thread and does not require an async runtime.
     pub fn execute_sync(
         self,
         program: &Program,
@@ -136,6 +143,36 @@ impl FastProcessor {
         .await
     }
 
+    /// Executes the given program synchronously, reporting errors against the source code described
+    /// by `source_map`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExecutionError::SourceMapMismatch`] without executing the program if
+    /// `source_map` does not describe the MAST forest of `program`.
+    pub fn execute_with_source_map_sync(
+        self,
+        program: &Program,
+        source_map: &SourceMap,
+        host: &mut impl SyncHost,
+    ) -> Result<ExecutionOutput, ExecutionError> {
+        check_source_map(program, source_map)?;
+        self.execute_with_package_debug_info_sync(program, source_map.debug_info(), host)
+    }
+
+    /// Async variant of [`Self::execute_with_source_map_sync`].
+    #[inline(always)]
+    pub async fn execute_with_source_map(
+        self,
+        program: &Program,
+        source_map: &SourceMap,
+        host: &mut impl Host,
+    ) -> Result<ExecutionOutput, ExecutionError> {
+        check_source_map(program, source_map)?;
+        self.execute_with_package_debug_info(program, source_map.debug_info(), host)
+            .await
+    }
+
     /// Executes the given program synchronously and returns the bundled trace inputs required by
     /// [`crate::trace::build_trace`].
     ///
@@ -163,12 +200,9 @@ impl FastProcessor {
         program: &Program,
         host: &mut impl SyncHost,
     ) -> Result<TraceBuildInputs, ExecutionError> {
-        let mut tracer = ExecutionTracer::new(
-            self.options.core_trace_fragment_size(),
-            self.options.max_stack_depth(),
-        );
+        let mut tracer = self.execution_tracer();
         let execution_output = self.execute_with_tracer_sync(program, host, &mut tracer)?;
-        Ok(Self::trace_build_inputs_from_parts(program, execution_output, tracer))
+        Self::trace_build_inputs_from_parts(program, execution_output, tracer)
     }
 
     /// Executes the given program synchronously with package-owned source/debug context and returns
@@ -180,10 +214,7 @@ impl FastProcessor {
         package_debug_info: &PackageDebugInfo,
         host: &mut impl SyncHost,
     ) -> Result<TraceBuildInputs, ExecutionError> {
-        let mut tracer = ExecutionTracer::new(
-            self.options.core_trace_fragment_size(),
-            self.options.max_stack_depth(),
-        );
+        let mut tracer = self.execution_tracer();
         let execution_output = self.execute_with_package_debug_info_and_tracer_sync(
             program,
             package_debug_info,
@@ -191,7 +222,7 @@ impl FastProcessor {
             host,
             &mut tracer,
         )?;
-        Ok(Self::trace_build_inputs_from_parts(program, execution_output, tracer))
+        Self::trace_build_inputs_from_parts(program, execution_output, tracer)
     }
 
     /// Executes the given program synchronously with package-owned source/debug context rooted at
@@ -208,10 +239,7 @@ impl FastProcessor {
         entrypoint_source_node_id: DebugSourceNodeId,
         host: &mut impl SyncHost,
     ) -> Result<TraceBuildInputs, ExecutionError> {
-        let mut tracer = ExecutionTracer::new(
-            self.options.core_trace_fragment_size(),
-            self.options.max_stack_depth(),
-        );
+        let mut tracer = self.execution_tracer();
         let execution_output = self.execute_with_package_debug_info_and_tracer_sync(
             program,
             package_debug_info,
@@ -219,7 +247,25 @@ impl FastProcessor {
             host,
             &mut tracer,
         )?;
-        Ok(Self::trace_build_inputs_from_parts(program, execution_output, tracer))
+        Self::trace_build_inputs_from_parts(program, execution_output, tracer)
+    }
+
+    /// Executes the given program synchronously like [`Self::execute_trace_inputs_sync`], but
+    /// spills the recorded core trace fragment contexts to `store` as execution progresses instead
+    /// of keeping them in memory.
+    ///
+    /// [`crate::trace::build_trace`] reads the fragment contexts back from the store when
+    /// generating the core trace.
+    #[instrument(name = "execute_trace_inputs_with_fragment_store_sync", skip_all)]
+    pub fn execute_trace_inputs_with_fragment_store_sync(
+        self,
+        program: &Program,
+        host: &mut impl SyncHost,
+        store: Box<dyn CoreTraceFragmentStore>,
+    ) -> Result<TraceBuildInputs, ExecutionError> {
+        let mut tracer = self.execution_tracer().with_fragment_store(store);
+        let execution_output = self.execute_with_tracer_sync(program, host, &mut tracer)?;
+        Self::trace_build_inputs_from_parts(program, execution_output, tracer)
     }
 
     /// Async variant of [`Self::execute_trace_inputs_sync`] for async hosts.
@@ -230,12 +276,23 @@ impl FastProcessor {
         program: &Program,
         host: &mut impl Host,
     ) -> Result<TraceBuildInputs, ExecutionError> {
-        let mut tracer = ExecutionTracer::new(
-            self.options.core_trace_fragment_size(),
-            self.options.max_stack_depth(),
-        );
+        let mut tracer = self.execution_tracer();
+        let execution_output = self.execute_with_tracer(program, host, &mut tracer).await?;
+        Self::trace_build_inputs_from_parts(program, execution_output, tracer)
+    }
+
+    /// Async variant of [`Self::execute_trace_inputs_with_fragment_store_sync`].
+    #[inline(always)]
+    #[instrument(name = "execute_trace_inputs_with_fragment_store", skip_all)]
+    pub async fn execute_trace_inputs_with_fragment_store(
+        self,
+        program: &Program,
+        host: &mut impl Host,
+        store: Box<dyn CoreTraceFragmentStore>,
+    ) -> Result<TraceBuildInputs, ExecutionError> {
+        let mut tracer = self.execution_tracer().with_fragment_store(store);
         let execution_output = self.execute_with_tracer(program, host, &mut tracer).await?;
-        Ok(Self::trace_build_inputs_from_parts(program, execution_output, tracer))
+        Self::trace_build_inputs_from_parts(program, execution_output, tracer)
     }
 
     /// Async variant of [`Self::execute_trace_inputs_with_package_debug_info_sync`].
@@ -248,10 +305,7 @@ impl FastProcessor {
         package_debug_info: &PackageDebugInfo,
         host: &mut impl Host,
     ) -> Result<TraceBuildInputs, ExecutionError> {
-        let mut tracer = ExecutionTracer::new(
-            self.options.core_trace_fragment_size(),
-            self.options.max_stack_depth(),
-        );
+        let mut tracer = self.execution_tracer();
         let execution_output = self
             .execute_with_package_debug_info_and_tracer(
                 program,
@@ -261,7 +315,7 @@ impl FastProcessor {
                 &mut tracer,
             )
             .await?;
-        Ok(Self::trace_build_inputs_from_parts(program, execution_output, tracer))
+        Self::trace_build_inputs_from_parts(program, execution_output, tracer)
     }
 
     /// Async variant of
@@ -276,10 +330,7 @@ impl FastProcessor {
         entrypoint_source_node_id: DebugSourceNodeId,
         host: &mut impl Host,
     ) -> Result<TraceBuildInputs, ExecutionError> {
-        let mut tracer = ExecutionTracer::new(
-            self.options.core_trace_fragment_size(),
-            self.options.max_stack_depth(),
-        );
+        let mut tracer = self.execution_tracer();
         let execution_output = self
             .execute_with_package_debug_info_and_tracer(
                 program,
@@ -289,7 +340,7 @@ impl FastProcessor {
                 &mut tracer,
             )
             .await?;
-        Ok(Self::trace_build_inputs_from_parts(program, execution_output, tracer))
+        Self::trace_build_inputs_from_parts(program, execution_output, tracer)
     }
 
     /// Executes the given program with the provided tracer using an async host.
@@ -384,6 +435,29 @@ impl FastProcessor {
         Self::execution_result_from_flow(flow, self)
     }
 
+    /// Executes the given program synchronously, reporting [`crate::ExecutionEvent`]s to `observer`
+    /// as they occur.
+    pub fn execute_with_observer_sync(
+        self,
+        program: &Program,
+        host: &mut impl SyncHost,
+        observer: &mut impl ExecutionObserver,
+    ) -> Result<ExecutionOutput, ExecutionError> {
+        self.execute_with_tracer_sync(program, host, &mut ObserverTracer::new(observer))
+    }
+
+    /// Async variant of [`Self::execute_with_observer_sync`].
+    #[inline(always)]
+    pub async fn execute_with_observer(
+        self,
+        program: &Program,
+        host: &mut impl Host,
+        observer: &mut impl ExecutionObserver,
+    ) -> Result<ExecutionOutput, ExecutionError> {
+        self.execute_with_tracer(program, host, &mut ObserverTracer::new(observer))
+            .await
+    }
+
     /// Executes the given program with package-owned source/debug context and the provided tracer
     /// using a sync host.
     fn execute_with_package_debug_info_and_tracer_sync<T>(
@@ -449,6 +523,78 @@ impl FastProcessor {
         )
     }
 
+    /// Executes the program synchronously until the end of the first clock cycle in which a watched
+    /// memory address is accessed, or until the program completes.
+    ///
+    /// The recorded accesses are available via [`Self::watchpoint_hits`]; hits recorded by previous
+    /// calls are discarded. Returns `None` if the program completed.
+    pub fn step_until_watchpoint_sync(
+        &mut self,
+        host: &mut impl SyncHost,
+        resume_ctx: ResumeContext,
+    ) -> Result<Option<ResumeContext>, ExecutionError> {
+        let ResumeContext {
+            mut current_forest,
+            mut continuation_stack,
+            kernel,
+            mut package_debug_info,
+        } = resume_ctx;
+
+        self.memory.clear_watchpoint_hits();
+        let flow = self.execute_impl(
+            &mut continuation_stack,
+            &mut current_forest,
+            &kernel,
+            host,
+            &mut NoopTracer,
+            &WatchpointStopper,
+            &mut package_debug_info,
+        );
+        Self::resume_context_from_flow(
+            flow,
+            continuation_stack,
+            current_forest,
+            kernel,
+            package_debug_info,
+        )
+    }
+
+    /// Executes the program synchronously until a breakpoint set via [`Self::add_breakpoint`] is
+    /// hit, or until the program completes.
+    ///
+    /// Execution pauses right before the first operation of the invoked procedure, whose MAST root
+    /// is available via [`Self::breakpoint_hit`]. Returns `None` if the program completed.
+    pub fn step_until_breakpoint_sync(
+        &mut self,
+        host: &mut impl SyncHost,
+        resume_ctx: ResumeContext,
+    ) -> Result<Option<ResumeContext>, ExecutionError> {
+        let ResumeContext {
+            mut current_forest,
+            mut continuation_stack,
+            kernel,
+            mut package_debug_info,
+        } = resume_ctx;
+
+        self.breakpoint_hit = None;
+        let flow = self.execute_impl(
+            &mut continuation_stack,
+            &mut current_forest,
+            &kernel,
+            host,
+            &mut NoopTracer,
+            &BreakpointStopper,
+            &mut package_debug_info,
+        );
+        Self::resume_context_from_flow(
+            flow,
+            continuation_stack,
+            current_forest,
+            kernel,
+            package_debug_info,
+        )
+    }
+
     /// Executes a single clock cycle synchronously with package-owned source/debug context.
     #[cfg(any(test, feature = "testing"))]
     pub fn step_with_package_debug_info_sync(
@@ -521,6 +667,76 @@ impl FastProcessor {
         )
     }
 
+    /// Async variant of [`Self::step_until_watchpoint_sync`].
+    #[inline(always)]
+    pub async fn step_until_watchpoint(
+        &mut self,
+        host: &mut impl Host,
+        resume_ctx: ResumeContext,
+    ) -> Result<Option<ResumeContext>, ExecutionError> {
+        let ResumeContext {
+            mut current_forest,
+            mut continuation_stack,
+            kernel,
+            mut package_debug_info,
+        } = resume_ctx;
+
+        self.memory.clear_watchpoint_hits();
+        let flow = self
+            .execute_impl_async(
+                &mut continuation_stack,
+                &mut current_forest,
+                &kernel,
+                host,
+                &mut NoopTracer,
+                &WatchpointStopper,
+                &mut package_debug_info,
+            )
+            .await;
+        Self::resume_context_from_flow(
+            flow,
+            continuation_stack,
+            current_forest,
+            kernel,
+            package_debug_info,
+        )
+    }
+
+    /// Async variant of [`Self::step_until_breakpoint_sync`].
+    #[inline(always)]
+    pub async fn step_until_breakpoint(
+        &mut self,
+        host: &mut impl Host,
+        resume_ctx: ResumeContext,
+    ) -> Result<Option<ResumeContext>, ExecutionError> {
+        let ResumeContext {
+            mut current_forest,
+            mut continuation_stack,
+            kernel,
+            mut package_debug_info,
+        } = resume_ctx;
+
+        self.breakpoint_hit = None;
+        let flow = self
+            .execute_impl_async(
+                &mut continuation_stack,
+                &mut current_forest,
+                &kernel,
+                host,
+                &mut NoopTracer,
+                &BreakpointStopper,
+                &mut package_debug_info,
+            )
+            .await;
+        Self::resume_context_from_flow(
+            flow,
+            continuation_stack,
+            current_forest,
+            kernel,
+            package_debug_info,
+        )
+    }
+
     /// Async variant of [`Self::step_with_package_debug_info_sync`].
     #[cfg(any(test, feature = "testing"))]
     #[inline(always)]
@@ -562,18 +778,30 @@ impl FastProcessor {
         )
     }
 
+    /// Creates the [`ExecutionTracer`] used to record trace generation inputs, configured from
+    /// this processor's execution options.
+    pub(crate) fn execution_tracer(&self) -> ExecutionTracer {
+        ExecutionTracer::new(
+            self.options.core_trace_fragment_size(),
+            self.options.max_stack_depth(),
+        )
+        .with_op_log(self.options.records_op_log())
+        .with_advice_timeline(self.options.records_advice_timeline())
+        .with_depth_profile(self.options.records_depth_profile())
+    }
+
     /// Pairs execution output with the trace inputs captured by the tracer.
     #[inline(always)]
     fn trace_build_inputs_from_parts(
         program: &Program,
         execution_output: ExecutionOutput,
         tracer: ExecutionTracer,
-    ) -> TraceBuildInputs {
-        TraceBuildInputs::from_execution(
+    ) -> Result<TraceBuildInputs, ExecutionError> {
+        Ok(TraceBuildInputs::from_execution(
             program,
             execution_output,
-            tracer.into_trace_generation_context(),
-        )
+            tracer.into_trace_generation_context()?,
+        ))
     }
 
     fn source_aware_continuation_stack(
@@ -610,8 +838,14 @@ impl FastProcessor {
         Ok(ContinuationStack::new_with_source_node_id(program, source_node_id))
     }
 
-    #[cfg(any(test, feature = "testing"))]
-    fn source_aware_resume_context(
+    /// Returns the resume context to be used with the first call to `step_sync()` when stepping
+    /// through `program` with package-owned source/debug context.
+    ///
+    /// Unlike [`Self::get_initial_resume_context`], the returned context tracks the source/debug
+    /// occurrence of each continuation, so that [`ResumeContext::source_location`] can report the
+    /// source location of the next operation to execute. If `entrypoint_source_node_id` is `None`,
+    /// the entrypoint source occurrence is derived from `package_debug_info`.
+    pub fn get_initial_resume_context_with_package_debug_info(
         &mut self,
         program: &Program,
         package_debug_info: &PackageDebugInfo,
@@ -826,6 +1060,13 @@ impl FastProcessor {
                         continuation_stack,
                         tracer,
                     )?;
+
+                    // Resolving an external node does not take a clock cycle, so a breakpoint on
+                    // the resolved procedure has to be checked here rather than by the stopper.
+                    self.check_breakpoint(procedure_hash);
+                    if stopper.should_stop_at_breakpoint(self) {
+                        return ControlFlow::Break(BreakReason::Stopped(None));
+                    }
                 },
             }
         }
@@ -954,6 +1195,13 @@ impl FastProcessor {
                         continuation_stack,
                         tracer,
                     )?;
+
+                    // Resolving an external node does not take a clock cycle, so a breakpoint on
+                    // the resolved procedure has to be checked here rather than by the stopper.
+                    self.check_breakpoint(procedure_hash);
+                    if stopper.should_stop_at_breakpoint(self) {
+                        return ControlFlow::Break(BreakReason::Stopped(None));
+                    }
                 },
             }
         }
@@ -991,8 +1239,34 @@ impl FastProcessor {
         ),
         ExecutionError,
     > {
-        let loaded_mast_forest = host.get_mast_forest(&node_digest).ok_or_else(|| {
-            match (package_debug_info, source_node_id) {
+        if let Err(err) = host.on_external_resolution(node_digest) {
+            return Err(match (package_debug_info, source_node_id) {
+                (Some(debug_info), Some(source_node_id)) => {
+                    crate::errors::external_resolution_denied_with_package_source_context(
+                        node_digest,
+                        err,
+                        PackageSourceDebugContext::new(debug_info, source_node_id),
+                        host,
+                    )
+                },
+                _ => crate::errors::external_resolution_denied_with_context(node_digest, err),
+            });
+        }
+
+        let loaded_mast_forest = host
+            .try_get_mast_forest(&node_digest)
+            .map_err(|err| match (package_debug_info, source_node_id) {
+                (Some(debug_info), Some(source_node_id)) => {
+                    crate::errors::mast_forest_load_failed_with_package_source_context(
+                        node_digest,
+                        err,
+                        PackageSourceDebugContext::new(debug_info, source_node_id),
+                        host,
+                    )
+                },
+                _ => crate::errors::mast_forest_load_failed_with_context(node_digest, err),
+            })?
+            .ok_or_else(|| match (package_debug_info, source_node_id) {
                 (Some(debug_info), Some(source_node_id)) => {
                     crate::errors::procedure_not_found_with_package_source_context(
                         node_digest,
@@ -1001,8 +1275,7 @@ impl FastProcessor {
                     )
                 },
                 _ => crate::errors::procedure_not_found_with_context(node_digest),
-            }
-        })?;
+            })?;
         let mast_forest = loaded_mast_forest.mast_forest().clone();
 
         let root_id = mast_forest.find_procedure_root(node_digest).ok_or_else(|| {
@@ -1042,20 +1315,47 @@ impl FastProcessor {
         ),
         ExecutionError,
     > {
-        let loaded_mast_forest = if let Some(mast_forest) = host.get_mast_forest(&node_digest).await
-        {
-            mast_forest
-        } else {
+        if let Err(err) = host.on_external_resolution(node_digest).await {
             return Err(match (package_debug_info, source_node_id) {
                 (Some(debug_info), Some(source_node_id)) => {
-                    crate::errors::procedure_not_found_with_package_source_context(
+                    crate::errors::external_resolution_denied_with_package_source_context(
                         node_digest,
+                        err,
                         PackageSourceDebugContext::new(debug_info, source_node_id),
                         host,
                     )
                 },
-                _ => crate::errors::procedure_not_found_with_context(node_digest),
+                _ => crate::errors::external_resolution_denied_with_context(node_digest, err),
             });
+        }
+
+        let loaded_mast_forest = match host.try_get_mast_forest(&node_digest).await {
+            Ok(Some(mast_forest)) => mast_forest,
+            Err(err) => {
+                return Err(match (package_debug_info, source_node_id) {
+                    (Some(debug_info), Some(source_node_id)) => {
+                        crate::errors::mast_forest_load_failed_with_package_source_context(
+                            node_digest,
+                            err,
+                            PackageSourceDebugContext::new(debug_info, source_node_id),
+                            host,
+                        )
+                    },
+                    _ => crate::errors::mast_forest_load_failed_with_context(node_digest, err),
+                });
+            },
+            Ok(None) => {
+                return Err(match (package_debug_info, source_node_id) {
+                    (Some(debug_info), Some(source_node_id)) => {
+                        crate::errors::procedure_not_found_with_package_source_context(
+                            node_digest,
+                            PackageSourceDebugContext::new(debug_info, source_node_id),
+                            host,
+                        )
+                    },
+                    _ => crate::errors::procedure_not_found_with_context(node_digest),
+                });
+            },
         };
         let mast_forest = loaded_mast_forest.mast_forest().clone();
 
@@ -1136,8 +1436,11 @@ impl FastProcessor {
         package_debug_info: &PackageDebugInfo,
         host: &mut impl SyncHost,
     ) -> Result<StackOutputs, ExecutionError> {
-        let mut current_resume_ctx =
-            self.source_aware_resume_context(program, package_debug_info, None)?;
+        let mut current_resume_ctx = self.get_initial_resume_context_with_package_debug_info(
+            program,
+            package_debug_info,
+            None,
+        )?;
 
         loop {
             match self.step_with_package_debug_info_sync(
@@ -1163,7 +1466,7 @@ impl FastProcessor {
         entrypoint_source_node_id: DebugSourceNodeId,
         host: &mut impl SyncHost,
     ) -> Result<StackOutputs, ExecutionError> {
-        let mut current_resume_ctx = self.source_aware_resume_context(
+        let mut current_resume_ctx = self.get_initial_resume_context_with_package_debug_info(
             program,
             package_debug_info,
             Some(entrypoint_source_node_id),
@@ -1212,8 +1515,11 @@ impl FastProcessor {
         package_debug_info: &PackageDebugInfo,
         host: &mut impl Host,
     ) -> Result<StackOutputs, ExecutionError> {
-        let mut current_resume_ctx =
-            self.source_aware_resume_context(program, package_debug_info, None)?;
+        let mut current_resume_ctx = self.get_initial_resume_context_with_package_debug_info(
+            program,
+            package_debug_info,
+            None,
+        )?;
         let mut processor = self;
 
         loop {
@@ -1240,7 +1546,7 @@ impl FastProcessor {
         entrypoint_source_node_id: DebugSourceNodeId,
         host: &mut impl Host,
     ) -> Result<StackOutputs, ExecutionError> {
-        let mut current_resume_ctx = self.source_aware_resume_context(
+        let mut current_resume_ctx = self.get_initial_resume_context_with_package_debug_info(
             program,
             package_debug_info,
             Some(entrypoint_source_node_id),
@@ -1315,3 +1621,18 @@ impl FastProcessor {
         Self::stack_result_from_flow(flow)
     }
 }
+
+// HELPERS
+// ================================================================================================
+
+/// Returns an error if `source_map` does not describe the MAST forest of `program`.
+fn check_source_map(program: &Program, source_map: &SourceMap) -> Result<(), ExecutionError> {
+    if source_map.describes(program.mast_forest()) {
+        Ok(())
+    } else {
+        Err(ExecutionError::SourceMapMismatch {
+            expected: source_map.commitment(),
+            actual: program.mast_forest().commitment(),
+        })
+    }
+}
@@ -1,14 +1,27 @@
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+    vec::Vec,
+};
 
 use miden_air::trace::RowIndex;
 use miden_core::{EMPTY_WORD, Felt, WORD_SIZE, Word, ZERO};
 
 use crate::{ContextId, ExecutionOptions, MemoryAddress, MemoryError, processor::MemoryInterface};
 
+/// The number of words held by a single memory page.
+const PAGE_WORDS: u32 = 64;
+
 /// The memory for the processor.
 ///
-/// Allows to read/write elements or words to memory. Internally, it is implemented as a map from
-///(context_id, word_address) to the word stored starting at that memory location.
+/// Allows to read/write elements or words to memory. Internally, the memory of each context is
+/// split into pages of [`PAGE_WORDS`] consecutive words, and implemented as a map from
+/// (context_id, page_index) to the page. Pages are only allocated on the first write to one of
+/// their words, so creating a new context is free, and many short-lived contexts do not churn a
+/// map entry per accessed word.
+///
+/// Pages are shared copy-on-write: cloning a [`Memory`] only copies references to its pages, and a
+/// page is copied the first time it is written to while shared.
 ///
 /// # Invariants
 /// The memory submodule assumes that the following invariants hold:
@@ -19,9 +32,11 @@ use crate::{ContextId, ExecutionOptions, MemoryAddress, MemoryError, processor::
 /// processor operations (i.e. all variants of the [`miden_core::operations::Operation`] enum). This
 /// is a consequence of the design of the memory chiplet constraints, which allow for multiple reads
 /// but not multiple writes in the same clock cycle to the same address.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Memory {
-    memory: BTreeMap<(ContextId, u32), Word>,
+    pages: BTreeMap<(ContextId, u32), Arc<Page>>,
+    /// Number of word entries (i.e., words which were written at least once) across all pages.
+    num_entries: usize,
     /// Maximum number of word entries allowed in `memory`. Memory is stored at word granularity
     /// (each entry holds `WORD_SIZE` elements), so a write that would insert a new word entry
     /// beyond this limit is rejected. This bounds host-memory growth from writes to
@@ -31,6 +46,20 @@ pub struct Memory {
     /// word-granular limit once, at construction time, so the per-write check is a plain
     /// comparison.
     max_entries: usize,
+    /// Maximum number of word entries allowed in `memory` for any single context, if limited.
+    max_entries_per_context: Option<usize>,
+    /// Number of word entries in `memory` for each context. Only maintained while
+    /// `max_entries_per_context` is set.
+    entries_per_context: BTreeMap<ContextId, usize>,
+    /// Element addresses for which accesses by executed operations are recorded as
+    /// [`WatchpointHit`]s.
+    watchpoints: BTreeSet<(ContextId, u32)>,
+    /// Accesses to watched addresses recorded since the hits were last cleared, up to
+    /// [`Self::MAX_WATCHPOINT_HITS`].
+    watchpoint_hits: Vec<WatchpointHit>,
+    /// All accesses by executed operations, in the order in which they were made. `None` unless
+    /// enabled via [`ExecutionOptions::with_memory_access_log`].
+    access_log: Option<Vec<MemoryAccessRecord>>,
 }
 
 impl Default for Memory {
@@ -40,16 +69,43 @@ impl Default for Memory {
 }
 
 impl Memory {
+    /// The maximum number of watchpoint hits recorded between two clears of the hits.
+    ///
+    /// Hits are cleared at the start of every [`crate::FastProcessor::step_until_watchpoint_sync`]
+    /// call, which stops as soon as a hit is recorded, but not when executing with watchpoints set
+    /// by other means. Once this many hits are recorded, further hits are discarded, so that the
+    /// recorded hits cannot grow with the length of the execution.
+    pub const MAX_WATCHPOINT_HITS: usize = 1024;
+
     /// Creates a new memory instance allowing at most `max_elements` field elements.
     ///
     /// Memory is stored at word granularity, so the limit is rounded up to a whole number of words.
     pub fn new(max_elements: usize) -> Self {
         Self {
-            memory: BTreeMap::new(),
+            pages: BTreeMap::new(),
+            num_entries: 0,
             max_entries: max_elements.div_ceil(WORD_SIZE),
+            max_entries_per_context: None,
+            entries_per_context: BTreeMap::new(),
+            watchpoints: BTreeSet::new(),
+            watchpoint_hits: Vec::new(),
+            access_log: None,
         }
     }
 
+    /// Starts or stops recording every access made by executed operations in the access log.
+    ///
+    /// Enabling an already enabled log keeps the accesses recorded so far.
+    pub(crate) fn set_access_log_enabled(&mut self, enabled: bool) {
+        self.access_log = enabled.then(|| self.access_log.take().unwrap_or_default());
+    }
+
+    /// Returns all accesses made by executed operations, in the order in which they were made, or
+    /// `None` if recording was not enabled via [`ExecutionOptions::with_memory_access_log`].
+    pub fn access_log(&self) -> Option<&[MemoryAccessRecord]> {
+        self.access_log.as_deref()
+    }
+
     /// Sets the maximum number of field elements allowed in memory.
     ///
     /// As with [`Self::new`], the limit is rounded up to a whole number of words. It governs future
@@ -58,6 +114,23 @@ impl Memory {
         self.max_entries = max_elements.div_ceil(WORD_SIZE);
     }
 
+    /// Sets the maximum number of field elements allowed in memory for any single context, or
+    /// removes the limit if `None`.
+    ///
+    /// As with [`Self::set_max_elements`], the limit is rounded up to a whole number of words, and
+    /// governs future growth only.
+    pub(crate) fn set_max_elements_per_context(&mut self, max_elements: Option<usize>) {
+        self.max_entries_per_context =
+            max_elements.map(|max_elements| max_elements.div_ceil(WORD_SIZE));
+
+        self.entries_per_context.clear();
+        if self.max_entries_per_context.is_some() {
+            for (&(ctx, _), page) in self.pages.iter() {
+                *self.entries_per_context.entry(ctx).or_default() += page.num_entries();
+            }
+        }
+    }
+
     /// Reads an element from memory at the provided address in the provided context.
     ///
     /// # Errors
@@ -99,29 +172,12 @@ impl Memory {
     ) -> Result<(), MemoryError> {
         let (word_addr, idx) = split_addr(clean_addr(addr)?);
 
-        // Reject writes that would grow the map beyond the configured maximum. Modifying an
-        // existing entry never grows the map, so it is always allowed.
-        if !self.memory.contains_key(&(ctx, word_addr)) && self.memory.len() >= self.max_entries {
-            return Err(MemoryError::MemoryElementLimitExceeded {
-                ctx,
-                addr: word_addr,
-                max: self.max_element_limit(),
-            });
-        }
-
-        self.memory
-            .entry((ctx, word_addr))
-            .and_modify(|word| {
-                let mut result: [Felt; WORD_SIZE];
-                result = (*word).into();
-                result[idx as usize] = element;
-                *word = result.into();
-            })
-            .or_insert_with(|| {
-                let mut word = [ZERO; WORD_SIZE];
-                word[idx as usize] = element;
-                word.into()
-            });
+        // Words which were never written read as zero, so updating a single element of a new
+        // entry leaves the other elements zeroed.
+        let word = self.word_entry_mut(ctx, word_addr)?;
+        let mut result: [Felt; WORD_SIZE] = (*word).into();
+        result[idx as usize] = element;
+        *word = result.into();
 
         Ok(())
     }
@@ -139,18 +195,7 @@ impl Memory {
         word: Word,
     ) -> Result<(), MemoryError> {
         let addr = enforce_word_aligned_addr(ctx, clean_addr(addr)?)?;
-
-        // Reject writes that would grow the map beyond the configured maximum. Overwriting an
-        // existing entry never grows the map, so it is always allowed.
-        if !self.memory.contains_key(&(ctx, addr)) && self.memory.len() >= self.max_entries {
-            return Err(MemoryError::MemoryElementLimitExceeded {
-                ctx,
-                addr,
-                max: self.max_element_limit(),
-            });
-        }
-
-        self.memory.insert((ctx, addr), word);
+        *self.word_entry_mut(ctx, addr)? = word;
 
         Ok(())
     }
@@ -160,10 +205,8 @@ impl Memory {
     /// The state is returned as a vector of (address, value) tuples, and includes addresses which
     /// have been accessed at least once.
     pub fn get_memory_state(&self, ctx: ContextId) -> Vec<(MemoryAddress, Felt)> {
-        self.memory
-            .iter()
-            .filter(|((c, _), _)| *c == ctx)
-            .flat_map(|(&(_c, addr), word)| {
+        self.entries(ctx)
+            .flat_map(|(_c, addr, word)| {
                 let addr: MemoryAddress = addr.into();
                 [
                     (addr, word[0]),
@@ -175,9 +218,174 @@ impl Memory {
             .collect()
     }
 
+    /// Returns all words in memory as `(context, address, word)` tuples, ordered by context and
+    /// address.
+    ///
+    /// The result can be passed to [`FastProcessor::warm_start`](super::FastProcessor::warm_start)
+    /// to start another execution from this memory state.
+    pub fn snapshot(&self) -> Vec<(ContextId, u32, Word)> {
+        self.pages
+            .iter()
+            .flat_map(|(&(ctx, page_idx), page)| page.entries(ctx, page_idx))
+            .collect()
+    }
+
+    /// Returns an iterator over the `(context, address, word)` entries of context `ctx`, ordered
+    /// by address.
+    fn entries(&self, ctx: ContextId) -> impl Iterator<Item = (ContextId, u32, Word)> + '_ {
+        self.pages
+            .range((ctx, 0)..=(ctx, u32::MAX))
+            .flat_map(move |(&(_, page_idx), page)| page.entries(ctx, page_idx))
+    }
+
+    // WATCHPOINTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Starts recording accesses to the element at `addr` in context `ctx`.
+    pub(crate) fn add_watchpoint(&mut self, ctx: ContextId, addr: u32) {
+        self.watchpoints.insert((ctx, addr));
+    }
+
+    /// Stops recording accesses to the element at `addr` in context `ctx`, returning `false` if the
+    /// address was not being watched.
+    pub(crate) fn remove_watchpoint(&mut self, ctx: ContextId, addr: u32) -> bool {
+        self.watchpoints.remove(&(ctx, addr))
+    }
+
+    /// Moves the watchpoints of `other` to this memory, replacing those of this memory.
+    ///
+    /// The hits recorded by `other` are not moved.
+    pub(crate) fn take_watchpoints_from(&mut self, other: &mut Self) {
+        self.watchpoints = core::mem::take(&mut other.watchpoints);
+    }
+
+    /// Returns the accesses to watched addresses recorded since the hits were last cleared.
+    pub(crate) fn watchpoint_hits(&self) -> &[WatchpointHit] {
+        &self.watchpoint_hits
+    }
+
+    /// Discards all recorded watchpoint hits.
+    pub(crate) fn clear_watchpoint_hits(&mut self) {
+        self.watchpoint_hits.clear();
+    }
+
+    /// Appends an access to the access log, if enabled.
+    #[inline(always)]
+    fn log_access(
+        &mut self,
+        ctx: ContextId,
+        addr: Felt,
+        clk: RowIndex,
+        access: MemoryAccess,
+        value: MemoryAccessValue,
+    ) {
+        if let Some(access_log) = &mut self.access_log {
+            access_log.push(MemoryAccessRecord {
+                ctx,
+                addr: addr.as_canonical_u64() as u32,
+                clk,
+                access,
+                value,
+            });
+        }
+    }
+
+    /// Returns the watched addresses in `[start_addr, start_addr + len)` of context `ctx`, along
+    /// with the values currently stored at them.
+    fn watched_values(&self, ctx: ContextId, start_addr: u32, len: u32) -> Vec<(u32, Felt)> {
+        self.watchpoints
+            .range((ctx, start_addr)..=(ctx, start_addr + (len - 1)))
+            .map(|&(_, addr)| (addr, self.read_element_impl(ctx, addr).unwrap_or(ZERO)))
+            .collect()
+    }
+
+    /// Records a read hit for every watched address in `[start_addr, start_addr + len)`.
+    fn record_read_hits(&mut self, ctx: ContextId, start_addr: u32, len: u32, clk: RowIndex) {
+        for (addr, value) in self.watched_values(ctx, start_addr, len) {
+            self.push_watchpoint_hit(WatchpointHit {
+                ctx,
+                addr,
+                clk,
+                access: MemoryAccess::Read,
+                old_value: value,
+                new_value: value,
+            });
+        }
+    }
+
+    /// Records a write hit for every address in `old_values`, which must have been obtained via
+    /// [`Self::watched_values`] before the write.
+    fn record_write_hits(&mut self, ctx: ContextId, clk: RowIndex, old_values: Vec<(u32, Felt)>) {
+        for (addr, old_value) in old_values {
+            self.push_watchpoint_hit(WatchpointHit {
+                ctx,
+                addr,
+                clk,
+                access: MemoryAccess::Write,
+                old_value,
+                new_value: self.read_element_impl(ctx, addr).unwrap_or(ZERO),
+            });
+        }
+    }
+
+    /// Records `hit`, unless [`Self::MAX_WATCHPOINT_HITS`] hits were already recorded.
+    fn push_watchpoint_hit(&mut self, hit: WatchpointHit) {
+        if self.watchpoint_hits.len() < Self::MAX_WATCHPOINT_HITS {
+            self.watchpoint_hits.push(hit);
+        }
+    }
+
     // HELPERS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns a mutable reference to the word at `word_addr` in context `ctx`, creating the entry
+    /// (and its page) if the word was never written.
+    ///
+    /// # Errors
+    /// - Returns an error if a new entry would exceed the configured limits. Modifying an existing
+    ///   entry never grows the memory, so it is always allowed.
+    #[inline(always)]
+    fn word_entry_mut(&mut self, ctx: ContextId, word_addr: u32) -> Result<&mut Word, MemoryError> {
+        let (page_idx, slot) = split_word_addr(word_addr);
+        if self.pages.get(&(ctx, page_idx)).is_none_or(|page| !page.is_occupied(slot)) {
+            self.reserve_entry(ctx, word_addr)?;
+        }
+
+        let page = Arc::make_mut(self.pages.entry((ctx, page_idx)).or_default());
+        page.occupied |= 1 << slot;
+        Ok(&mut page.words[slot])
+    }
+
+    /// Checks that a new entry for the word at `word_addr` in context `ctx` fits within the
+    /// configured limits, and accounts for it in the entry counts.
+    ///
+    /// Must only be called right before inserting an entry which is not yet in `memory`.
+    #[inline(always)]
+    fn reserve_entry(&mut self, ctx: ContextId, word_addr: u32) -> Result<(), MemoryError> {
+        if self.num_entries >= self.max_entries {
+            return Err(MemoryError::MemoryElementLimitExceeded {
+                ctx,
+                addr: word_addr,
+                max: self.max_element_limit(),
+            });
+        }
+
+        if let Some(max_entries_per_context) = self.max_entries_per_context {
+            let num_entries = self.entries_per_context.entry(ctx).or_default();
+            if *num_entries >= max_entries_per_context {
+                return Err(MemoryError::ContextMemoryLimitExceeded {
+                    ctx,
+                    addr: word_addr,
+                    max: max_entries_per_context.saturating_mul(WORD_SIZE),
+                });
+            }
+            *num_entries += 1;
+        }
+
+        self.num_entries += 1;
+        Ok(())
+    }
+
     /// Returns the configured entry limit expressed as an element count, for reporting in errors.
     ///
     /// This lives off the hot path: it is only evaluated when a write is being rejected.
@@ -194,7 +402,7 @@ impl Memory {
     pub(crate) fn read_element_impl(&self, ctx: ContextId, addr: u32) -> Option<Felt> {
         let (word_addr, idx) = split_addr(addr);
 
-        self.memory.get(&(ctx, word_addr)).copied().map(|word| word[idx as usize])
+        self.get_word(ctx, word_addr).map(|word| word[idx as usize])
     }
 
     /// Reads a word from memory starting at the provided address in the provided context.
@@ -209,9 +417,16 @@ impl Memory {
         addr: u32,
     ) -> Result<Option<Word>, MemoryError> {
         let addr = enforce_word_aligned_addr(ctx, addr)?;
-        let word = self.memory.get(&(ctx, addr)).copied();
 
-        Ok(word)
+        Ok(self.get_word(ctx, addr))
+    }
+
+    /// Returns the word at the word-aligned address `word_addr` in context `ctx`, if it was
+    /// written previously.
+    #[inline(always)]
+    fn get_word(&self, ctx: ContextId, word_addr: u32) -> Option<Word> {
+        let (page_idx, slot) = split_word_addr(word_addr);
+        self.pages.get(&(ctx, page_idx)).and_then(|page| page.get(slot))
     }
 
     // TEST HELPERS
@@ -220,7 +435,56 @@ impl Memory {
     /// Returns the number of words that were accessed at least once across all contexts.
     #[cfg(test)]
     pub fn num_accessed_words(&self) -> usize {
-        self.memory.len()
+        self.num_entries
+    }
+}
+
+// PAGE
+// ================================================================================================
+
+/// [`PAGE_WORDS`] consecutive words of a single context's memory.
+#[derive(Debug, Clone)]
+struct Page {
+    words: [Word; PAGE_WORDS as usize],
+    /// Bitmap of the words which were written at least once; bit `i` corresponds to `words[i]`.
+    occupied: u64,
+}
+
+impl Default for Page {
+    fn default() -> Self {
+        Self {
+            words: [EMPTY_WORD; PAGE_WORDS as usize],
+            occupied: 0,
+        }
+    }
+}
+
+impl Page {
+    fn is_occupied(&self, slot: usize) -> bool {
+        self.occupied & (1 << slot) != 0
+    }
+
+    fn get(&self, slot: usize) -> Option<Word> {
+        self.is_occupied(slot).then(|| self.words[slot])
+    }
+
+    fn num_entries(&self) -> usize {
+        self.occupied.count_ones() as usize
+    }
+
+    /// Returns an iterator over the `(context, address, word)` entries of this page, ordered by
+    /// address.
+    fn entries(
+        &self,
+        ctx: ContextId,
+        page_idx: u32,
+    ) -> impl Iterator<Item = (ContextId, u32, Word)> + '_ {
+        (0..PAGE_WORDS as usize)
+            .filter(|&slot| self.is_occupied(slot))
+            .map(move |slot| {
+                let addr = (page_idx * PAGE_WORDS + slot as u32) * WORD_SIZE as u32;
+                (ctx, addr, self.words[slot])
+            })
     }
 }
 
@@ -245,6 +509,14 @@ fn split_addr(addr: u32) -> (u32, u32) {
     (addr - idx, idx)
 }
 
+/// Splits the provided word-aligned address into the index of the page holding the word and the
+/// index of the word within the page.
+#[inline(always)]
+fn split_word_addr(word_addr: u32) -> (u32, usize) {
+    let word_idx = word_addr / WORD_SIZE as u32;
+    (word_idx / PAGE_WORDS, (word_idx % PAGE_WORDS) as usize)
+}
+
 /// Enforces that the provided address is word-aligned; that is, that it be divisible by 4 (in
 /// the integer sense).
 ///
@@ -263,8 +535,18 @@ fn enforce_word_aligned_addr(ctx: ContextId, addr: u32) -> Result<u32, MemoryErr
 }
 
 impl MemoryInterface for Memory {
-    fn read_element(&mut self, ctx: ContextId, addr: Felt) -> Result<Felt, MemoryError> {
-        Memory::read_element(self, ctx, addr)
+    fn read_element(
+        &mut self,
+        ctx: ContextId,
+        addr: Felt,
+        clk: RowIndex,
+    ) -> Result<Felt, MemoryError> {
+        let element = Memory::read_element(self, ctx, addr)?;
+        self.log_access(ctx, addr, clk, MemoryAccess::Read, MemoryAccessValue::Element(element));
+        if !self.watchpoints.is_empty() {
+            self.record_read_hits(ctx, clean_addr(addr)?, 1, clk);
+        }
+        Ok(element)
     }
 
     fn read_word(
@@ -273,16 +555,30 @@ impl MemoryInterface for Memory {
         addr: Felt,
         clk: RowIndex,
     ) -> Result<Word, MemoryError> {
-        Memory::read_word(self, ctx, addr, clk)
+        let word = Memory::read_word(self, ctx, addr, clk)?;
+        self.log_access(ctx, addr, clk, MemoryAccess::Read, MemoryAccessValue::Word(word));
+        if !self.watchpoints.is_empty() {
+            self.record_read_hits(ctx, clean_addr(addr)?, WORD_SIZE as u32, clk);
+        }
+        Ok(word)
     }
 
     fn write_element(
         &mut self,
         ctx: ContextId,
         addr: Felt,
+        clk: RowIndex,
         element: Felt,
     ) -> Result<(), MemoryError> {
-        Memory::write_element(self, ctx, addr, element)
+        if self.watchpoints.is_empty() {
+            Memory::write_element(self, ctx, addr, element)?;
+        } else {
+            let old_values = self.watched_values(ctx, clean_addr(addr)?, 1);
+            Memory::write_element(self, ctx, addr, element)?;
+            self.record_write_hits(ctx, clk, old_values);
+        }
+        self.log_access(ctx, addr, clk, MemoryAccess::Write, MemoryAccessValue::Element(element));
+        Ok(())
     }
 
     fn write_word(
@@ -292,6 +588,70 @@ impl MemoryInterface for Memory {
         clk: RowIndex,
         word: Word,
     ) -> Result<(), MemoryError> {
-        Memory::write_word(self, ctx, addr, clk, word)
+        if self.watchpoints.is_empty() {
+            Memory::write_word(self, ctx, addr, clk, word)?;
+        } else {
+            let addr_u32 = enforce_word_aligned_addr(ctx, clean_addr(addr)?)?;
+            let old_values = self.watched_values(ctx, addr_u32, WORD_SIZE as u32);
+            Memory::write_word(self, ctx, addr, clk, word)?;
+            self.record_write_hits(ctx, clk, old_values);
+        }
+        self.log_access(ctx, addr, clk, MemoryAccess::Write, MemoryAccessValue::Word(word));
+        Ok(())
     }
 }
+
+// WATCHPOINTS AND ACCESS LOG
+// ================================================================================================
+
+/// The kind of memory access reported by a [`WatchpointHit`] or [`MemoryAccessRecord`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryAccess {
+    Read,
+    Write,
+}
+
+/// An access to a watched memory address by an executed operation.
+///
+/// See [`crate::FastProcessor::add_mem_watchpoint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatchpointHit {
+    /// The context in which the address was accessed.
+    pub ctx: ContextId,
+    /// The watched element address.
+    pub addr: u32,
+    /// The clock cycle at which the access took place.
+    pub clk: RowIndex,
+    /// Whether the address was read or written.
+    pub access: MemoryAccess,
+    /// The value stored at the address before the access.
+    pub old_value: Felt,
+    /// The value stored at the address after the access; equal to `old_value` for reads.
+    pub new_value: Felt,
+}
+
+/// The value read or written by a memory access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryAccessValue {
+    /// A single element was accessed.
+    Element(Felt),
+    /// A word was accessed, starting at the (word-aligned) address of the access.
+    Word(Word),
+}
+
+/// A memory access made by an executed operation, as recorded in the memory access log.
+///
+/// See [`ExecutionOptions::with_memory_access_log`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryAccessRecord {
+    /// The context in which the access took place.
+    pub ctx: ContextId,
+    /// The element address of the access.
+    pub addr: u32,
+    /// The clock cycle at which the access took place.
+    pub clk: RowIndex,
+    /// Whether the memory was read or written.
+    pub access: MemoryAccess,
+    /// The value read or written.
+    pub value: MemoryAccessValue,
+}
@@ -1,5 +1,11 @@
-use alloc::{boxed::Box, sync::Arc, vec, vec::Vec};
-use core::{cmp::min, ops::ControlFlow};
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+use core::{cmp::min, ops::ControlFlow, time::Duration};
 
 use miden_air::{Felt, trace::RowIndex};
 use miden_core::{
@@ -12,22 +18,30 @@ use miden_core::{
 use miden_mast_package::Package;
 
 use crate::{
-    AdviceInputs, AdviceProvider, ContextId, ExecutionError, ExecutionOptions, ProcessorState,
-    advice::AdviceError,
+    AdviceInputs, AdviceProvider, ContextId, CoverageReport, CycleReport, ExecutionError,
+    ExecutionOptions, KernelAccessReport, ProcessorState,
+    advice::{AdviceError, AdviceProviderSnapshot},
     continuation_stack::{Continuation, ContinuationStack},
     errors::MapExecErrNoCtx,
+    metering::CycleMeter,
     tracer::{OperationHelperRegisters, Tracer},
 };
 
 mod basic_block;
+mod checkpoint;
 mod execution_api;
 mod external;
 mod memory;
+mod observer;
 mod operation;
+mod reversible;
 mod step;
 
 pub use basic_block::SystemEventError;
-pub use memory::Memory;
+pub use checkpoint::ProcessorSnapshot;
+pub use memory::{Memory, MemoryAccess, MemoryAccessRecord, MemoryAccessValue, WatchpointHit};
+pub use observer::{ExecutionEvent, ExecutionObserver};
+pub use reversible::ReversibleStepper;
 pub use step::{BreakReason, ResumeContext};
 
 #[cfg(test)]
@@ -36,15 +50,14 @@ mod tests;
 // CONSTANTS
 // ================================================================================================
 
-/// The initial size of the stack buffer.
+/// The default initial size of the stack buffer.
 ///
-/// Note: This value is much larger than it needs to be for the majority of programs. However, some
-/// existing programs need it, so we're forced to push it up (though this should be double-checked).
-/// At this high a value, we're starting to see some performance degradation on benchmarks. For
-/// example, the blake3 benchmark went from 285 MHz to 250 MHz (~10% degradation). Perhaps a better
-/// solution would be to make this value much smaller (~1000), and then fallback to a `Vec` if the
-/// stack overflows.
-const INITIAL_STACK_BUFFER_SIZE: usize = 6850;
+/// Note: This value is much larger than it needs to be for the majority of programs, and at this
+/// high a value, we're starting to see some performance degradation on benchmarks. For example, the
+/// blake3 benchmark went from 285 MHz to 250 MHz (~10% degradation). Programs which are known to
+/// keep a shallow stack can start from a smaller buffer via
+/// [`ExecutionOptions::with_initial_stack_buffer_size`], which grows on demand.
+const INITIAL_STACK_BUFFER_SIZE: usize = ExecutionOptions::DEFAULT_INITIAL_STACK_BUFFER_SIZE;
 
 /// The initial position of the top of the stack in the stack buffer.
 ///
@@ -61,6 +74,9 @@ const DEFAULT_MAX_STACK_DEPTH: usize =
 const _: [(); 1] =
     [(); (ExecutionOptions::DEFAULT_MAX_STACK_DEPTH == DEFAULT_MAX_STACK_DEPTH) as usize];
 
+const _: [(); 1] =
+    [(); (ExecutionOptions::MIN_INITIAL_STACK_BUFFER_SIZE == INITIAL_STACK_TOP_IDX + 1) as usize];
+
 /// The stack buffer index where the logical operand stack starts after reset/recenter.
 const STACK_BUFFER_BASE_IDX: usize = INITIAL_STACK_TOP_IDX - MIN_STACK_DEPTH;
 
@@ -76,7 +92,9 @@ const STACK_BUFFER_BASE_IDX: usize = INITIAL_STACK_TOP_IDX - MIN_STACK_DEPTH;
 /// # Stack Management
 /// A few key points about how the stack was designed for maximum performance:
 ///
-/// - The stack starts with a fixed buffer size defined by `INITIAL_STACK_BUFFER_SIZE`.
+/// - The stack starts with a fixed buffer size defined by
+///   [`ExecutionOptions::initial_stack_buffer_size`], which is reallocated to a larger size if a
+///   program pushes past its end.
 ///     - This was observed to increase performance by at least 2x compared to using a `Vec` with
 ///       `push()` & `pop()`.
 ///     - We track the stack top and bottom using indices `stack_top_idx` and `stack_bot_idx`,
@@ -146,17 +164,40 @@ pub struct FastProcessor {
 
     /// Deferred witness accumulated during execution and returned for verifier rehydration.
     deferred_state: DeferredState,
+
+    /// The optional recorders enabled via the `record_*` flags of [`ExecutionOptions`].
+    recorders: Recorders,
+
+    /// Tracing spans of the Join, Loop, and Call nodes being executed.
+    #[cfg(feature = "trace-nodes")]
+    node_spans: crate::node_spans::NodeSpans,
+
+    /// MAST roots of the procedures on whose invocation execution should be paused (see
+    /// [`Self::add_breakpoint`]).
+    breakpoints: BTreeSet<Word>,
+
+    /// MAST root of the procedure whose breakpoint was hit most recently, if any.
+    breakpoint_hit: Option<Word>,
+
+    /// Number of `emit` operations executed so far, checked against
+    /// [`ExecutionOptions::max_emitted_events`].
+    num_emitted_events: usize,
 }
 
 impl FastProcessor {
     /// Packages the processor state after successful execution into a public result type.
     #[inline(always)]
     fn into_execution_output(self, stack: StackOutputs) -> ExecutionOutput {
+        let reports = self.recorders.into_reports(self.clk);
+        let reports = reports.is_any_enabled().then_some(reports);
+        #[cfg(feature = "trace-nodes")]
+        self.node_spans.finish(self.clk);
         ExecutionOutput {
             stack,
             advice: self.advice,
             memory: self.memory,
             deferred_state: self.deferred_state,
+            reports,
         }
     }
 
@@ -230,6 +271,19 @@ impl FastProcessor {
         Ok(self)
     }
 
+    /// Sets the advice provider state of the processor to the one captured in `snapshot`.
+    ///
+    /// Unlike loading the snapshot via [`AdviceInputs::from_provider_snapshot`] and
+    /// [`Self::with_advice`], this also restores the number of advice map reads, so that reads
+    /// performed by earlier executions count towards the advice map read limit.
+    pub fn with_advice_snapshot(
+        mut self,
+        snapshot: AdviceProviderSnapshot,
+    ) -> Result<Self, AdviceError> {
+        self.advice = AdviceProvider::from_snapshot(snapshot, &self.options)?;
+        Ok(self)
+    }
+
     /// Sets the execution options for the processor.
     ///
     /// Existing advice inputs are revalidated against the new options before they are applied. To
@@ -241,7 +295,19 @@ impl FastProcessor {
         self.advice.set_options(&options)?;
         self.deferred_state.set_max_elements(options.max_deferred_elements());
         self.memory.set_max_elements(options.max_memory_elements());
+        self.memory.set_max_elements_per_context(options.max_context_memory_elements());
+
+        // The stack inputs are already placed in the buffer, so keep everything up to the top of
+        // the stack when resizing it.
+        let stack_buffer_size = options.initial_stack_buffer_size().max(self.stack_top_idx + 1);
+        if self.stack.len() != stack_buffer_size {
+            let mut stack = vec![ZERO; stack_buffer_size].into_boxed_slice();
+            stack[..self.stack_top_idx].copy_from_slice(&self.stack[..self.stack_top_idx]);
+            self.stack = stack;
+        }
+
         self.options = options;
+        self.configure_recorders();
         Ok(self)
     }
 
@@ -258,7 +324,7 @@ impl FastProcessor {
             // Note: we use `Vec::into_boxed_slice()` here, since `Box::new([T; N])` first allocates
             // the array on the stack, and then moves it to the heap. This might cause a
             // stack overflow on some systems.
-            let mut stack = vec![ZERO; INITIAL_STACK_BUFFER_SIZE].into_boxed_slice();
+            let mut stack = vec![ZERO; options.initial_stack_buffer_size()].into_boxed_slice();
 
             // Copy inputs in reverse order so first element ends up at top of stack
             for (i, &input) in stack_inputs.iter().enumerate() {
@@ -267,7 +333,7 @@ impl FastProcessor {
             stack
         };
 
-        Ok(Self {
+        let mut processor = Self {
             advice: AdviceProvider::new(advice_inputs, &options)?,
             stack,
             stack_top_idx,
@@ -275,7 +341,11 @@ impl FastProcessor {
             clk: 0_u32.into(),
             ctx: 0_u32.into(),
             caller_hash: EMPTY_WORD,
-            memory: Memory::new(options.max_memory_elements()),
+            memory: {
+                let mut memory = Memory::new(options.max_memory_elements());
+                memory.set_max_elements_per_context(options.max_context_memory_elements());
+                memory
+            },
             system_call_state_stack: Vec::new(),
             stack_overflow_save_stack: Vec::new(),
             saved_overflow_len: 0,
@@ -284,8 +354,82 @@ impl FastProcessor {
                 options.max_deferred_elements(),
             )
             .map_err(AdviceError::DeferredStateInitializationFailed)?,
+            recorders: Recorders::default(),
+            #[cfg(feature = "trace-nodes")]
+            node_spans: Default::default(),
+            breakpoints: BTreeSet::new(),
+            breakpoint_hit: None,
+            num_emitted_events: 0,
             options,
-        })
+        };
+        processor.configure_recorders();
+        Ok(processor)
+    }
+
+    /// Enables the recorders requested by the current execution options, and disables the others.
+    ///
+    /// This is the only place where the `record_*` flags of [`ExecutionOptions`] are applied to
+    /// the processor, so that they take effect regardless of how the options were set. Recorders
+    /// which were already enabled keep what they recorded so far.
+    fn configure_recorders(&mut self) {
+        self.recorders.configure(&self.options);
+        self.memory.set_access_log_enabled(self.options.records_memory_access_log());
+    }
+
+    /// Creates a `FastProcessor` whose memory is preloaded with the provided words.
+    ///
+    /// Each snapshot entry is a `(context, address, word)` tuple, where `address` must be
+    /// word-aligned. This makes it possible to repeatedly execute a program from a known memory
+    /// state (e.g., one captured with [`Memory::snapshot`] after a previous run) without
+    /// re-running the code which produced that state.
+    ///
+    /// Note that preloaded memory is not part of the initial state of the proven execution, so
+    /// warm-started processors are intended for execution only; traces built from them will not
+    /// satisfy the memory chiplet constraints.
+    ///
+    /// The processor uses the default execution options; see [`Self::warm_start_with_options`]
+    /// to specify them.
+    ///
+    /// # Errors
+    /// Returns an error if the advice inputs do not fit the default execution options, or if a
+    /// snapshot entry cannot be written to memory.
+    pub fn warm_start(
+        stack_inputs: StackInputs,
+        advice_inputs: AdviceInputs,
+        memory_snapshot: Vec<(ContextId, u32, Word)>,
+    ) -> Result<Self, ExecutionError> {
+        Self::warm_start_with_options(
+            stack_inputs,
+            advice_inputs,
+            memory_snapshot,
+            ExecutionOptions::default(),
+        )
+    }
+
+    /// Creates a `FastProcessor` with the provided execution options, whose memory is preloaded
+    /// with the provided words.
+    ///
+    /// See [`Self::warm_start`] for details.
+    ///
+    /// # Errors
+    /// Returns an error if the advice inputs do not fit the provided execution options, or if a
+    /// snapshot entry cannot be written to memory.
+    pub fn warm_start_with_options(
+        stack_inputs: StackInputs,
+        advice_inputs: AdviceInputs,
+        memory_snapshot: Vec<(ContextId, u32, Word)>,
+        options: ExecutionOptions,
+    ) -> Result<Self, ExecutionError> {
+        let mut processor =
+            Self::new_with_options(stack_inputs, advice_inputs, options).map_exec_err_no_ctx()?;
+        for (ctx, addr, word) in memory_snapshot {
+            processor
+                .memory
+                .write_word(ctx, Felt::from_u32(addr), RowIndex::from(0_u32), word)
+                .map_err(ExecutionError::MemoryErrorNoCtx)?;
+        }
+
+        Ok(processor)
     }
 
     /// Returns the resume context to be used with the first call to `step_sync()`.
@@ -387,6 +531,16 @@ impl FastProcessor {
         }
     }
 
+    /// Same as [`Self::stack_get()`], but returns `None` if `idx` is not smaller than the current
+    /// stack depth.
+    ///
+    /// Unlike `stack_get()`, this can be used with any `idx`, including positions in the stack
+    /// overflow region.
+    #[inline(always)]
+    pub fn try_stack_get(&self, idx: usize) -> Option<Felt> {
+        (idx < self.stack_depth() as usize).then(|| self.stack[self.stack_top_idx - idx - 1])
+    }
+
     /// Mutable variant of `stack_get()`.
     ///
     /// This method is only meant to be used to access the stack top by operation handlers, and
@@ -470,6 +624,29 @@ impl FastProcessor {
         &self.memory
     }
 
+    /// Returns the accesses to watched memory addresses recorded during the most recent call to
+    /// [`Self::step_until_watchpoint_sync`] or [`Self::step_until_watchpoint`].
+    ///
+    /// A single operation may access several watched addresses, in which case one hit is reported
+    /// for each of them. At most [`Memory::MAX_WATCHPOINT_HITS`] hits are recorded.
+    pub fn watchpoint_hits(&self) -> &[WatchpointHit] {
+        self.memory.watchpoint_hits()
+    }
+
+    /// Returns all memory accesses made by the operations executed so far, in the order in which
+    /// they were made, or `None` if recording was not enabled via
+    /// [`ExecutionOptions::with_memory_access_log`].
+    pub fn memory_access_log(&self) -> Option<&[MemoryAccessRecord]> {
+        self.memory.access_log()
+    }
+
+    /// Returns the MAST root of the procedure whose breakpoint was hit during the most recent call
+    /// to [`Self::step_until_breakpoint_sync`] or [`Self::step_until_breakpoint`], or `None` if no
+    /// breakpoint was hit.
+    pub fn breakpoint_hit(&self) -> Option<Word> {
+        self.breakpoint_hit
+    }
+
     /// Consumes the processor and returns the advice provider and memory.
     pub fn into_parts(self) -> (AdviceProvider, Memory) {
         (self.advice, self.memory)
@@ -509,6 +686,39 @@ impl FastProcessor {
         self.stack[range(word_start_idx, WORD_SIZE)].copy_from_slice(&source)
     }
 
+    /// Adds a watchpoint on the memory element at `addr` in context `ctx`.
+    ///
+    /// Reads and writes of a watched address by executed operations are recorded as
+    /// [`WatchpointHit`]s, and pause execution driven by [`Self::step_until_watchpoint_sync`] at
+    /// the end of the clock cycle in which they occur. Word accesses hit every watched element of
+    /// the word.
+    pub fn add_mem_watchpoint(&mut self, ctx: ContextId, addr: u32) {
+        self.memory.add_watchpoint(ctx, addr);
+    }
+
+    /// Removes the watchpoint on the memory element at `addr` in context `ctx`, returning `false`
+    /// if no such watchpoint was set.
+    pub fn remove_mem_watchpoint(&mut self, ctx: ContextId, addr: u32) -> bool {
+        self.memory.remove_watchpoint(ctx, addr)
+    }
+
+    /// Adds a breakpoint on the procedure with the MAST root `procedure_root`.
+    ///
+    /// A breakpoint is hit whenever a `call`, `syscall`, `dyn`, `dyncall`, or external node
+    /// invokes the procedure, and pauses execution driven by [`Self::step_until_breakpoint_sync`]
+    /// right before the first operation of the procedure. Since procedures are identified by their
+    /// MAST root, this works for library procedures for which no source-level debug information is
+    /// available.
+    pub fn add_breakpoint(&mut self, procedure_root: Word) {
+        self.breakpoints.insert(procedure_root);
+    }
+
+    /// Removes the breakpoint on the procedure with the MAST root `procedure_root`, returning
+    /// `false` if no such breakpoint was set.
+    pub fn remove_breakpoint(&mut self, procedure_root: Word) -> bool {
+        self.breakpoints.remove(&procedure_root)
+    }
+
     /// Swaps the elements at the given indices on the stack.
     #[inline(always)]
     pub fn stack_swap(&mut self, idx1: usize, idx2: usize) {
@@ -665,6 +875,81 @@ pub struct ExecutionOutput {
     pub advice: AdviceProvider,
     pub memory: Memory,
     pub deferred_state: DeferredState,
+    /// Optional reports recorded during execution, or `None` if no report was enabled via
+    /// [`ExecutionOptions`].
+    pub reports: Option<ExecutionReports>,
+}
+
+/// Optional reports recorded during a program execution, as enabled via [`ExecutionOptions`].
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct ExecutionReports {
+    /// Wall-clock time spent executing each operation kind, keyed by operation mnemonic, if
+    /// enabled via [`ExecutionOptions::with_op_timing`].
+    ///
+    /// `EMIT` operations are not timed, since their cost is dominated by the host's event
+    /// handlers.
+    pub op_timings: Option<BTreeMap<&'static str, Duration>>,
+    /// Cycles consumed by each executed procedure, if enabled via
+    /// [`ExecutionOptions::with_cycle_report`].
+    pub cycle_report: Option<CycleReport>,
+    /// Number of times each operation of each executed basic block was executed, if enabled via
+    /// [`ExecutionOptions::with_coverage`].
+    pub coverage: Option<CoverageReport>,
+    /// Kernel procedures invoked via `syscall`, if enabled via
+    /// [`ExecutionOptions::with_kernel_access_report`].
+    pub kernel_access_report: Option<KernelAccessReport>,
+}
+
+impl ExecutionReports {
+    /// Returns true if at least one of the reports was recorded.
+    fn is_any_enabled(&self) -> bool {
+        self.op_timings.is_some()
+            || self.cycle_report.is_some()
+            || self.coverage.is_some()
+            || self.kernel_access_report.is_some()
+    }
+}
+
+// RECORDERS
+// ===============================================================================================
+
+/// The optional recorders of a [`FastProcessor`], each of which is `None` unless enabled via the
+/// corresponding `record_*` flag of [`ExecutionOptions`].
+#[derive(Debug, Default)]
+struct Recorders {
+    /// Wall-clock time spent executing each operation kind, keyed by operation mnemonic.
+    op_timings: Option<BTreeMap<&'static str, Duration>>,
+    /// Per-procedure cycle accounting.
+    cycle_meter: Option<CycleMeter>,
+    /// Operations executed in each basic block.
+    coverage: Option<CoverageReport>,
+    /// Kernel procedures invoked via `syscall`.
+    kernel_access_report: Option<KernelAccessReport>,
+}
+
+impl Recorders {
+    /// Enables the recorders requested by `options`, and disables the others.
+    fn configure(&mut self, options: &ExecutionOptions) {
+        fn configure<T: Default>(recorder: &mut Option<T>, enabled: bool) {
+            *recorder = enabled.then(|| recorder.take().unwrap_or_default());
+        }
+
+        configure(&mut self.op_timings, options.records_op_timing());
+        configure(&mut self.cycle_meter, options.records_cycle_report());
+        configure(&mut self.coverage, options.records_coverage());
+        configure(&mut self.kernel_access_report, options.records_kernel_access_report());
+    }
+
+    /// Converts the recorders into the reports of an execution which ended at `clk`.
+    fn into_reports(self, clk: RowIndex) -> ExecutionReports {
+        ExecutionReports {
+            op_timings: self.op_timings,
+            cycle_report: self.cycle_meter.map(|meter| meter.into_report(clk)),
+            coverage: self.coverage,
+            kernel_access_report: self.kernel_access_report,
+        }
+    }
 }
 
 // SYSTEM CALL STATE
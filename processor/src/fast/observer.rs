@@ -0,0 +1,240 @@
+use alloc::sync::Arc;
+
+use miden_air::trace::RowIndex;
+use miden_core::{
+    FMP_ADDR, FMP_INIT_VALUE, Felt, ONE, WORD_SIZE, Word,
+    mast::{ExecutableMastForest, MastForest, MastNodeId},
+};
+
+use super::FastProcessor;
+use crate::{
+    ContextId,
+    continuation_stack::{Continuation, ContinuationStack},
+    tracer::{OperationHelperRegisters, Tracer},
+};
+
+// EXECUTION EVENT
+// ================================================================================================
+
+/// An event emitted to an [`ExecutionObserver`] while a program is being executed.
+///
+/// Every event carries the clock cycle at which it occurred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionEvent {
+    /// Execution of the MAST node with the specified ID and digest started.
+    ///
+    /// External nodes are never reported; the node they resolve to is reported instead. Note that
+    /// node IDs are only meaningful within the MAST forest in which the node is defined, while
+    /// digests identify nodes across forests.
+    NodeEntered {
+        clk: RowIndex,
+        node_id: MastNodeId,
+        digest: Word,
+    },
+    /// Execution of the MAST node with the specified ID and digest finished.
+    NodeExited {
+        clk: RowIndex,
+        node_id: MastNodeId,
+        digest: Word,
+    },
+    /// The execution context changed from `from` to `to`, either because a `call`, `syscall`, or
+    /// `dyncall` started, or because one of them returned.
+    ContextSwitched {
+        clk: RowIndex,
+        from: ContextId,
+        to: ContextId,
+    },
+    /// A single element was written to memory at `addr` in context `ctx`.
+    MemoryElementWritten {
+        clk: RowIndex,
+        ctx: ContextId,
+        addr: u32,
+        element: Felt,
+    },
+    /// A word was written to memory starting at `addr` in context `ctx`.
+    MemoryWordWritten {
+        clk: RowIndex,
+        ctx: ContextId,
+        addr: u32,
+        word: Word,
+    },
+    /// A single element was popped from the advice stack.
+    AdviceElementPopped { clk: RowIndex, element: Felt },
+    /// A word was popped from the advice stack.
+    AdviceWordPopped { clk: RowIndex, word: Word },
+}
+
+// EXECUTION OBSERVER
+// ================================================================================================
+
+/// Receives the [`ExecutionEvent`]s emitted during
+/// [`FastProcessor::execute_with_observer_sync`].
+///
+/// This allows building external tools such as profilers or coverage reporters on top of the
+/// processor without modifying its execution loop. The trait is implemented for all
+/// `FnMut(ExecutionEvent)` closures.
+pub trait ExecutionObserver {
+    /// Handles an event emitted during execution.
+    fn on_event(&mut self, event: ExecutionEvent);
+}
+
+impl<F: FnMut(ExecutionEvent)> ExecutionObserver for F {
+    fn on_event(&mut self, event: ExecutionEvent) {
+        self(event)
+    }
+}
+
+// OBSERVER TRACER
+// ================================================================================================
+
+/// A [`Tracer`] which translates the execution information it receives into [`ExecutionEvent`]s
+/// forwarded to an [`ExecutionObserver`].
+pub(crate) struct ObserverTracer<'a, O> {
+    observer: &'a mut O,
+    /// The clock cycle being executed.
+    clk: RowIndex,
+    /// The execution context at the start of the clock cycle being executed.
+    ctx: ContextId,
+    /// The node exited by the clock cycle being executed, if any, reported once the cycle
+    /// completes.
+    exited_node: Option<(MastNodeId, Word)>,
+}
+
+impl<'a, O: ExecutionObserver> ObserverTracer<'a, O> {
+    pub fn new(observer: &'a mut O) -> Self {
+        Self {
+            observer,
+            clk: RowIndex::from(0_u32),
+            ctx: ContextId::root(),
+            exited_node: None,
+        }
+    }
+
+    fn memory_word_written(&mut self, clk: RowIndex, ctx: ContextId, addr: Felt, word: Word) {
+        let addr = addr.as_canonical_u64() as u32;
+        self.observer
+            .on_event(ExecutionEvent::MemoryWordWritten { clk, ctx, addr, word });
+    }
+}
+
+impl<O: ExecutionObserver> Tracer for ObserverTracer<'_, O> {
+    type Processor = FastProcessor;
+    type Forest = Arc<MastForest>;
+
+    fn start_clock_cycle(
+        &mut self,
+        processor: &FastProcessor,
+        continuation: Continuation<Arc<MastForest>>,
+        _continuation_stack: &ContinuationStack<Arc<MastForest>>,
+        current_forest: &Arc<MastForest>,
+    ) {
+        self.clk = processor.clk;
+        self.ctx = processor.ctx;
+
+        let digest = |node_id| current_forest.get_digest_by_id(node_id).unwrap_or_default();
+        match continuation {
+            Continuation::StartNode(node_id) => {
+                self.observer.on_event(ExecutionEvent::NodeEntered {
+                    clk: self.clk,
+                    node_id,
+                    digest: digest(node_id),
+                });
+            },
+            // a loop is only exited once its condition is no longer ONE; otherwise, the body is
+            // executed again
+            Continuation::FinishLoop(_) if processor.stack_get(0) == ONE => {},
+            Continuation::FinishJoin(node_id)
+            | Continuation::FinishSplit(node_id)
+            | Continuation::FinishLoop(node_id)
+            | Continuation::FinishCall(node_id)
+            | Continuation::FinishDyn(node_id)
+            | Continuation::FinishBasicBlock(node_id) => {
+                self.exited_node = Some((node_id, digest(node_id)));
+            },
+            Continuation::ResumeBasicBlock { .. }
+            | Continuation::Respan { .. }
+            | Continuation::EnterForest { .. } => {},
+        }
+    }
+
+    fn finalize_clock_cycle(
+        &mut self,
+        processor: &FastProcessor,
+        _op_helper_registers: OperationHelperRegisters,
+        _current_forest: &Arc<MastForest>,
+    ) {
+        if processor.ctx != self.ctx {
+            self.observer.on_event(ExecutionEvent::ContextSwitched {
+                clk: self.clk,
+                from: self.ctx,
+                to: processor.ctx,
+            });
+        }
+        if let Some((node_id, digest)) = self.exited_node.take() {
+            self.observer
+                .on_event(ExecutionEvent::NodeExited { clk: self.clk, node_id, digest });
+        }
+    }
+
+    fn record_memory_write_element(
+        &mut self,
+        element: Felt,
+        addr: Felt,
+        ctx: ContextId,
+        clk: RowIndex,
+    ) {
+        let addr = addr.as_canonical_u64() as u32;
+        self.observer
+            .on_event(ExecutionEvent::MemoryElementWritten { clk, ctx, addr, element });
+    }
+
+    fn record_memory_write_word(&mut self, word: Word, addr: Felt, ctx: ContextId, clk: RowIndex) {
+        self.memory_word_written(clk, ctx, addr, word);
+    }
+
+    fn record_dyncall_memory(
+        &mut self,
+        _callee_hash: Word,
+        _read_addr: Felt,
+        _read_ctx: ContextId,
+        fmp_ctx: ContextId,
+        clk: RowIndex,
+    ) {
+        self.record_memory_write_element(FMP_INIT_VALUE, FMP_ADDR, fmp_ctx, clk);
+    }
+
+    fn record_crypto_stream(
+        &mut self,
+        _plaintext: [Word; 2],
+        _src_addr: Felt,
+        ciphertext: [Word; 2],
+        dst_addr: Felt,
+        ctx: ContextId,
+        clk: RowIndex,
+    ) {
+        self.memory_word_written(clk, ctx, dst_addr, ciphertext[0]);
+        self.memory_word_written(
+            clk,
+            ctx,
+            dst_addr + Felt::from_u32(WORD_SIZE as u32),
+            ciphertext[1],
+        );
+    }
+
+    fn record_pipe(&mut self, words: [Word; 2], addr: Felt, ctx: ContextId, clk: RowIndex) {
+        for word in words {
+            self.observer.on_event(ExecutionEvent::AdviceWordPopped { clk, word });
+        }
+        self.memory_word_written(clk, ctx, addr, words[0]);
+        self.memory_word_written(clk, ctx, addr + Felt::from_u32(WORD_SIZE as u32), words[1]);
+    }
+
+    fn record_advice_pop_stack(&mut self, element: Felt) {
+        self.observer
+            .on_event(ExecutionEvent::AdviceElementPopped { clk: self.clk, element });
+    }
+
+    fn record_advice_pop_stack_word(&mut self, word: Word) {
+        self.observer.on_event(ExecutionEvent::AdviceWordPopped { clk: self.clk, word });
+    }
+}
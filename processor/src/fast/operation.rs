@@ -1,4 +1,8 @@
+#[cfg(feature = "std")]
+use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::time::Duration;
 
 use miden_air::{
     Felt,
@@ -13,9 +17,10 @@ use miden_core::{
 };
 
 use crate::{
-    AdviceProvider, ContextId, ExecutionError,
+    AdviceProvider, ContextId, CoverageReport, ExecutionError, KernelAccessReport,
     errors::OperationError,
     fast::{FastProcessor, INITIAL_STACK_TOP_IDX, SystemCallState, memory::Memory},
+    metering::CycleMeter,
     processor::{HasherInterface, Processor, StackInterface, SystemInterface},
 };
 
@@ -51,6 +56,40 @@ impl Processor for FastProcessor {
         &mut self.memory
     }
 
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn op_timings_mut(&mut self) -> Option<&mut BTreeMap<&'static str, Duration>> {
+        self.recorders.op_timings.as_mut()
+    }
+
+    #[inline(always)]
+    fn cycle_meter_mut(&mut self) -> Option<&mut CycleMeter> {
+        self.recorders.cycle_meter.as_mut()
+    }
+
+    #[cfg(feature = "trace-nodes")]
+    #[inline(always)]
+    fn node_spans_mut(&mut self) -> Option<&mut crate::node_spans::NodeSpans> {
+        Some(&mut self.node_spans)
+    }
+
+    #[inline(always)]
+    fn coverage_mut(&mut self) -> Option<&mut CoverageReport> {
+        self.recorders.coverage.as_mut()
+    }
+
+    #[inline(always)]
+    fn kernel_access_report_mut(&mut self) -> Option<&mut KernelAccessReport> {
+        self.recorders.kernel_access_report.as_mut()
+    }
+
+    #[inline(always)]
+    fn check_breakpoint(&mut self, procedure_root: Word) {
+        if self.breakpoints.contains(&procedure_root) {
+            self.breakpoint_hit = Some(procedure_root);
+        }
+    }
+
     #[inline(always)]
     fn hasher(&mut self) -> &mut Self::Hasher {
         self
@@ -170,11 +209,19 @@ impl SystemInterface for FastProcessor {
     }
 
     #[inline(always)]
-    fn save_call_state(&mut self) {
+    fn save_call_state(&mut self) -> Result<(), ExecutionError> {
+        if let Some(max) = self.options.max_call_depth() {
+            let depth = self.system_call_state_stack.len() + 1;
+            if depth > max {
+                return Err(ExecutionError::CallDepthLimitExceeded { depth, max });
+            }
+        }
+
         self.system_call_state_stack.push(SystemCallState {
             ctx: self.ctx,
             caller_hash: self.caller_hash,
         });
+        Ok(())
     }
 
     #[inline(always)]
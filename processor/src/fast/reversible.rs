@@ -0,0 +1,168 @@
+//! This module defines [`ReversibleStepper`], which allows stepping backwards through the execution
+//! of a program.
+
+use alloc::vec::Vec;
+
+use miden_air::trace::RowIndex;
+use miden_core::program::Program;
+
+use super::{FastProcessor, ResumeContext};
+use crate::{ExecutionError, SyncHost, fast::ProcessorSnapshot};
+
+// REVERSIBLE STEPPER
+// ================================================================================================
+
+/// Steps through the execution of a program one clock cycle at a time, in either direction.
+///
+/// The stepper takes a [`ProcessorSnapshot`] every `checkpoint_interval` cycles while stepping
+/// forward. Moving back to an earlier clock cycle restores the closest snapshot taken at or before
+/// that cycle and re-executes the program from there, so going back costs at most
+/// `checkpoint_interval` cycles of re-execution rather than a restart of the program.
+///
+/// Since re-executed cycles are run against the provided host again, the host is expected to be
+/// deterministic: it must respond to the same events in the same way every time.
+#[derive(Debug)]
+pub struct ReversibleStepper {
+    program: Program,
+    processor: FastProcessor,
+    /// The context from which to continue execution, or `None` if the program completed or
+    /// failed.
+    resume_ctx: Option<ResumeContext>,
+    /// Snapshots taken so far, in order of increasing clock cycle.
+    checkpoints: Vec<ProcessorSnapshot>,
+    checkpoint_interval: usize,
+}
+
+impl ReversibleStepper {
+    /// Returns a new stepper positioned at the start of the execution of `program` by `processor`.
+    ///
+    /// # Panics
+    /// Panics if `checkpoint_interval` is zero.
+    pub fn new(
+        mut processor: FastProcessor,
+        program: &Program,
+        checkpoint_interval: usize,
+    ) -> Result<Self, ExecutionError> {
+        assert!(checkpoint_interval > 0, "checkpoint interval must be greater than zero");
+
+        let resume_ctx = processor.get_initial_resume_context(program)?;
+        let mut stepper = Self {
+            program: program.clone(),
+            processor,
+            resume_ctx: Some(resume_ctx),
+            checkpoints: Vec::new(),
+            checkpoint_interval,
+        };
+        stepper.record_checkpoint();
+
+        Ok(stepper)
+    }
+
+    // ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the processor in its state at the current clock cycle.
+    pub fn processor(&self) -> &FastProcessor {
+        &self.processor
+    }
+
+    /// Returns the current clock cycle.
+    pub fn clk(&self) -> RowIndex {
+        self.processor.clk
+    }
+
+    /// Returns `true` if execution can no longer move forward, either because the program
+    /// completed or because the last step failed.
+    pub fn is_finished(&self) -> bool {
+        self.resume_ctx.is_none()
+    }
+
+    // STEPPING
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes a single clock cycle.
+    ///
+    /// Returns `false` if the program has completed, in which case no cycle is executed. If the
+    /// step fails, the stepper is left finished, but can still be moved back with [`Self::back`]
+    /// or [`Self::seek`].
+    pub fn step(&mut self, host: &mut impl SyncHost) -> Result<bool, ExecutionError> {
+        let Some(resume_ctx) = self.resume_ctx.take() else {
+            return Ok(false);
+        };
+
+        let clk = self.clk();
+        self.resume_ctx = self.processor.step_sync(host, resume_ctx)?;
+        self.record_checkpoint();
+
+        Ok(self.clk() > clk)
+    }
+
+    /// Moves back by a single clock cycle.
+    ///
+    /// Returns `false` if execution is at the first clock cycle, in which case nothing changes.
+    pub fn back(&mut self, host: &mut impl SyncHost) -> Result<bool, ExecutionError> {
+        let clk = self.clk();
+        if clk == RowIndex::from(0_u32) {
+            return Ok(false);
+        }
+        self.seek(host, clk - 1)?;
+
+        Ok(true)
+    }
+
+    /// Moves to the specified clock cycle, either backwards or forwards.
+    ///
+    /// If `clk` is past the end of the program, execution stops once the program completes.
+    pub fn seek(&mut self, host: &mut impl SyncHost, clk: RowIndex) -> Result<(), ExecutionError> {
+        if clk < self.clk() {
+            self.restore_checkpoint(clk)?;
+        }
+
+        while self.clk() < clk && self.step(host)? {}
+
+        Ok(())
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Takes a snapshot of the current state if at least `checkpoint_interval` cycles were
+    /// executed since the last one.
+    ///
+    /// Snapshots cannot be taken while executing a procedure loaded from the host, in which case
+    /// the checkpoint is retried on the next cycle.
+    fn record_checkpoint(&mut self) {
+        let Some(resume_ctx) = &self.resume_ctx else {
+            return;
+        };
+        if let Some(last) = self.checkpoints.last()
+            && self.clk() < last.clk() + self.checkpoint_interval
+        {
+            return;
+        }
+
+        if let Ok(snapshot) = self.processor.checkpoint(resume_ctx) {
+            self.checkpoints.push(snapshot);
+        }
+    }
+
+    /// Restores the state from the latest snapshot taken at or before `clk`.
+    ///
+    /// Breakpoints and memory watchpoints are not part of snapshots, and are carried over from the
+    /// current processor. Watchpoint hits recorded after the snapshot are discarded.
+    fn restore_checkpoint(&mut self, clk: RowIndex) -> Result<(), ExecutionError> {
+        let num_checkpoints = self.checkpoints.partition_point(|snapshot| snapshot.clk() <= clk);
+        let snapshot = &self.checkpoints[num_checkpoints - 1];
+
+        let options = self.processor.execution_options().clone();
+        let (mut processor, resume_ctx) =
+            FastProcessor::resume_from(snapshot, &self.program, options)
+                .map_err(|_| ExecutionError::Internal("failed to restore execution checkpoint"))?;
+        processor.breakpoints = core::mem::take(&mut self.processor.breakpoints);
+        processor.memory.take_watchpoints_from(&mut self.processor.memory);
+        self.processor = processor;
+        self.resume_ctx = Some(resume_ctx);
+
+        Ok(())
+    }
+}
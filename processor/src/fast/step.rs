@@ -3,11 +3,15 @@
 use alloc::sync::Arc;
 use core::ops::ControlFlow;
 
-use miden_core::{mast::MastForest, program::KernelDescriptor};
-use miden_mast_package::debug_info::{DebugSourceNodeId, PackageDebugInfo};
+use miden_core::{
+    mast::{MastForest, MastNode},
+    program::KernelDescriptor,
+};
+use miden_debug_types::Location;
+use miden_mast_package::debug_info::{AsmOpInfo, DebugSourceNodeId, PackageDebugInfo};
 
 use crate::{
-    ExecutionError, FastProcessor, Stopper,
+    CancellationToken, ExecutionError, ExecutionOptions, FastProcessor, Stopper,
     continuation_stack::{Continuation, ContinuationStack},
 };
 
@@ -44,6 +48,39 @@ impl ResumeContext {
     pub fn kernel(&self) -> &KernelDescriptor {
         &self.kernel
     }
+
+    /// Returns the source location of the next operation to execute, if known.
+    ///
+    /// Source locations are only available when stepping with package-owned source/debug context,
+    /// i.e. when execution was started from
+    /// [`FastProcessor::get_initial_resume_context_with_package_debug_info`].
+    pub fn source_location(&self) -> Option<&Location> {
+        let debug_info = self.package_debug_info.as_deref()?;
+        let (continuation, source_node_id) =
+            self.continuation_stack.peek_continuation_with_source_node_id()?;
+        let source_node_id = source_node_id?;
+
+        let (node_id, batch_index, op_idx_in_batch) = match *continuation {
+            Continuation::ResumeBasicBlock { node_id, batch_index, op_idx_in_batch } => {
+                (node_id, batch_index, op_idx_in_batch)
+            },
+            Continuation::Respan { node_id, batch_index } => (node_id, batch_index, 0),
+            _ => {
+                let asm_op = debug_info.first_asm_op_for_source_node(source_node_id)?;
+                return AsmOpInfo::new(asm_op).location();
+            },
+        };
+        let Some(MastNode::Block(basic_block)) = self.current_forest.get_node_by_id(node_id) else {
+            return None;
+        };
+        let op_idx = basic_block.op_batches()[..batch_index]
+            .iter()
+            .map(|batch| batch.ops().len())
+            .sum::<usize>()
+            + op_idx_in_batch;
+
+        debug_info.asm_op_info(source_node_id, u32::try_from(op_idx).ok()?)?.location()
+    }
 }
 
 // STOPPERS
@@ -68,6 +105,7 @@ impl Stopper for NeverStopper {
         )>,
     ) -> ControlFlow<BreakReason<Arc<MastForest>>> {
         check_if_max_cycles_exceeded(processor)?;
+        check_if_cancelled(processor)?;
         check_if_continuation_stack_too_large(processor, continuation_stack)
     }
 }
@@ -91,12 +129,80 @@ impl Stopper for StepStopper {
         )>,
     ) -> ControlFlow<BreakReason<Arc<MastForest>>> {
         check_if_max_cycles_exceeded(processor)?;
+        check_if_cancelled(processor)?;
         check_if_continuation_stack_too_large(processor, continuation_stack)?;
 
         ControlFlow::Break(BreakReason::Stopped(continuation_after_stop()))
     }
 }
 
+/// A [`Stopper`] that stops execution after any clock cycle in which a watched memory address was
+/// accessed (see [`FastProcessor::add_mem_watchpoint`]). An error is returned if the maximum cycle
+/// count is exceeded.
+pub struct WatchpointStopper;
+
+impl Stopper for WatchpointStopper {
+    type Processor = FastProcessor;
+    type Forest = Arc<MastForest>;
+
+    #[inline(always)]
+    fn should_stop(
+        &self,
+        processor: &FastProcessor,
+        continuation_stack: &ContinuationStack<Arc<MastForest>>,
+        continuation_after_stop: impl FnOnce() -> Option<(
+            Continuation<Arc<MastForest>>,
+            Option<DebugSourceNodeId>,
+        )>,
+    ) -> ControlFlow<BreakReason<Arc<MastForest>>> {
+        check_if_max_cycles_exceeded(processor)?;
+        check_if_cancelled(processor)?;
+        check_if_continuation_stack_too_large(processor, continuation_stack)?;
+
+        if processor.memory.watchpoint_hits().is_empty() {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(BreakReason::Stopped(continuation_after_stop()))
+        }
+    }
+}
+
+/// A [`Stopper`] that stops execution right before the first operation of a procedure on which a
+/// breakpoint is set (see [`FastProcessor::add_breakpoint`]). An error is returned if the maximum
+/// cycle count is exceeded.
+pub struct BreakpointStopper;
+
+impl Stopper for BreakpointStopper {
+    type Processor = FastProcessor;
+    type Forest = Arc<MastForest>;
+
+    #[inline(always)]
+    fn should_stop(
+        &self,
+        processor: &FastProcessor,
+        continuation_stack: &ContinuationStack<Arc<MastForest>>,
+        continuation_after_stop: impl FnOnce() -> Option<(
+            Continuation<Arc<MastForest>>,
+            Option<DebugSourceNodeId>,
+        )>,
+    ) -> ControlFlow<BreakReason<Arc<MastForest>>> {
+        check_if_max_cycles_exceeded(processor)?;
+        check_if_cancelled(processor)?;
+        check_if_continuation_stack_too_large(processor, continuation_stack)?;
+
+        if self.should_stop_at_breakpoint(processor) {
+            ControlFlow::Break(BreakReason::Stopped(continuation_after_stop()))
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    #[inline(always)]
+    fn should_stop_at_breakpoint(&self, processor: &FastProcessor) -> bool {
+        processor.breakpoint_hit.is_some()
+    }
+}
+
 /// Checks if the maximum cycle count has been exceeded, returning a `BreakReason::Err` if so.
 #[inline(always)]
 fn check_if_max_cycles_exceeded<F>(processor: &FastProcessor) -> ControlFlow<BreakReason<F>> {
@@ -109,6 +215,26 @@ fn check_if_max_cycles_exceeded<F>(processor: &FastProcessor) -> ControlFlow<Bre
     }
 }
 
+/// Checks if execution has been cancelled via the configured cancellation token, returning a
+/// `BreakReason::Err` if so.
+///
+/// The token is only checked every [`ExecutionOptions::CANCELLATION_CHECK_INTERVAL`] cycles to keep
+/// the overhead off the hot path.
+#[inline(always)]
+fn check_if_cancelled<F>(processor: &FastProcessor) -> ControlFlow<BreakReason<F>> {
+    let clk = u32::from(processor.clk);
+    if clk.is_multiple_of(ExecutionOptions::CANCELLATION_CHECK_INTERVAL)
+        && processor
+            .options
+            .cancellation_token()
+            .is_some_and(CancellationToken::is_cancelled)
+    {
+        ControlFlow::Break(BreakReason::Err(ExecutionError::Cancelled(clk)))
+    } else {
+        ControlFlow::Continue(())
+    }
+}
+
 /// Checks if the continuation stack size exceeds the maximum allowed, returning a
 /// `BreakReason::Err` if so.
 #[inline(always)]
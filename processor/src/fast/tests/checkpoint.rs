@@ -0,0 +1,233 @@
+use super::*;
+use crate::{CheckpointError, ProcessorSnapshot, ReversibleStepper, advice::AdviceError};
+
+/// A program which calls into a new context (so that the snapshot includes a suspended context
+/// with overflow), and reads from memory and the advice provider both before and after the point
+/// at which execution is paused.
+const CHECKPOINT_SOURCE: &str = "
+    proc store_and_double
+        push.3 push.100 mem_store
+        repeat.4
+            adv_push mul.2 add
+        end
+        push.100 mem_load add
+    end
+
+    begin
+        push.11 push.12 push.13 push.14 push.15 push.16 push.17 push.18
+        push.7 push.200 mem_store
+        call.store_and_double
+        push.200 mem_load add
+        swapw.3 dropw dropw dropw dropw
+    end
+";
+
+fn checkpoint_program() -> Program {
+    Assembler::default()
+        .assemble_program("program", CHECKPOINT_SOURCE)
+        .expect("program should assemble")
+        .unwrap_program()
+}
+
+fn checkpoint_processor() -> FastProcessor {
+    FastProcessor::new(stack_inputs_from_ints([1_u64, 2, 3]))
+        .with_advice(AdviceInputs::default().with_stack_values([5, 6, 7, 8]).unwrap())
+        .unwrap()
+}
+
+/// Executes `resume_ctx` one step at a time until the end of the program.
+fn step_to_end(
+    processor: &mut FastProcessor,
+    host: &mut DefaultHost,
+    mut resume_ctx: ResumeContext,
+) -> StackOutputs {
+    while let Some(next_resume_ctx) = processor.step_sync(host, resume_ctx).unwrap() {
+        resume_ctx = next_resume_ctx;
+    }
+    StackOutputs::new(&processor.stack().iter().rev().copied().collect::<Vec<_>>()).unwrap()
+}
+
+#[rstest]
+#[case::start(0)]
+#[case::inside_call(20)]
+#[case::inside_repeat(30)]
+#[case::after_call(60)]
+fn checkpoint_and_resume_matches_uninterrupted_execution(#[case] num_steps: usize) {
+    let program = checkpoint_program();
+    let expected = checkpoint_processor().execute_sync(&program, &mut DefaultHost::default());
+    let expected = expected.unwrap().stack;
+
+    // Run the first part of the program and take a checkpoint.
+    let mut host = DefaultHost::default();
+    let mut processor = checkpoint_processor();
+    let mut resume_ctx = processor.get_initial_resume_context(&program).unwrap();
+    for _ in 0..num_steps {
+        resume_ctx = processor
+            .step_sync(&mut host, resume_ctx)
+            .unwrap()
+            .expect("program should not finish before the checkpoint");
+    }
+    let snapshot = processor.checkpoint(&resume_ctx).unwrap();
+    assert_eq!(snapshot.clk(), processor.clk);
+
+    // Persist the snapshot, then resume execution from it on a new processor.
+    let bytes = snapshot.to_bytes();
+    let snapshot = ProcessorSnapshot::read_from_bytes(&bytes).unwrap();
+    drop(processor);
+
+    let (mut processor, resume_ctx) =
+        FastProcessor::resume_from(&snapshot, &program, ExecutionOptions::default()).unwrap();
+    let stack_outputs = step_to_end(&mut processor, &mut DefaultHost::default(), resume_ctx);
+
+    assert_eq!(stack_outputs, expected);
+}
+
+#[test]
+fn resume_from_rejects_snapshot_of_another_program() {
+    let program = checkpoint_program();
+    let mut processor = checkpoint_processor();
+    let resume_ctx = processor.get_initial_resume_context(&program).unwrap();
+    let snapshot = processor.checkpoint(&resume_ctx).unwrap();
+
+    let other_program = Assembler::default()
+        .assemble_program("program", "begin push.1 drop end")
+        .unwrap()
+        .unwrap_program();
+    let err = FastProcessor::resume_from(&snapshot, &other_program, ExecutionOptions::default())
+        .unwrap_err();
+    assert_matches!(err, CheckpointError::ProgramMismatch { .. });
+}
+
+#[test]
+fn resume_from_restores_the_number_of_emitted_events() {
+    let program = Assembler::default()
+        .assemble_program(
+            "program",
+            "begin emit.event(\"test::noop\") emit.event(\"test::noop\") emit.event(\"test::noop\") end",
+        )
+        .unwrap()
+        .unwrap_program();
+    let new_host = || {
+        let mut host = DefaultHost::default();
+        host.register_handler(EventName::new("test::noop"), Arc::new(NoopEventHandler))
+            .unwrap();
+        host
+    };
+
+    // Pause execution after the first two events were emitted.
+    let mut host = new_host();
+    let mut processor = FastProcessor::new(StackInputs::default());
+    let mut resume_ctx = processor.get_initial_resume_context(&program).unwrap();
+    while processor.num_emitted_events < 2 {
+        resume_ctx = processor.step_sync(&mut host, resume_ctx).unwrap().unwrap();
+    }
+    let snapshot = processor.checkpoint(&resume_ctx).unwrap();
+    let snapshot = ProcessorSnapshot::read_from_bytes(&snapshot.to_bytes()).unwrap();
+
+    // The third event exceeds the limit, even though it is the first one emitted after resuming.
+    let options = ExecutionOptions::default().with_max_emitted_events(2);
+    let (mut processor, mut resume_ctx) =
+        FastProcessor::resume_from(&snapshot, &program, options).unwrap();
+    let mut host = new_host();
+    let err = loop {
+        match processor.step_sync(&mut host, resume_ctx) {
+            Ok(Some(next_resume_ctx)) => resume_ctx = next_resume_ctx,
+            Ok(None) => panic!("execution should exceed the event limit"),
+            Err(err) => break err,
+        }
+    };
+    assert_matches!(err, ExecutionError::EventLimitExceeded(2));
+}
+
+#[test]
+fn resume_from_restores_the_number_of_advice_map_reads() {
+    let program = Assembler::default()
+        .assemble_program(
+            "program",
+            "begin push.[1,0,0,0] repeat.3 adv.push_mapval adv_push drop end dropw end",
+        )
+        .unwrap()
+        .unwrap_program();
+    let advice_inputs =
+        AdviceInputs::default().with_map([(Word::from([1u32, 0, 0, 0]), vec![ONE])]);
+
+    // Pause execution after the first two advice map reads.
+    let mut host = DefaultHost::default();
+    let mut processor =
+        FastProcessor::new(StackInputs::default()).with_advice(advice_inputs).unwrap();
+    let mut resume_ctx = processor.get_initial_resume_context(&program).unwrap();
+    while processor.advice.map_read_count() < 2 {
+        resume_ctx = processor.step_sync(&mut host, resume_ctx).unwrap().unwrap();
+    }
+    let snapshot = processor.checkpoint(&resume_ctx).unwrap();
+    let snapshot = ProcessorSnapshot::read_from_bytes(&snapshot.to_bytes()).unwrap();
+
+    // The third read exceeds the limit, even though it is the first one performed after resuming.
+    let options = ExecutionOptions::default().with_max_advice_reads(2);
+    let (mut processor, mut resume_ctx) =
+        FastProcessor::resume_from(&snapshot, &program, options).unwrap();
+    let err = loop {
+        match processor.step_sync(&mut host, resume_ctx) {
+            Ok(Some(next_resume_ctx)) => resume_ctx = next_resume_ctx,
+            Ok(None) => panic!("execution should exceed the advice map read limit"),
+            Err(err) => break err,
+        }
+    };
+    assert_matches!(
+        err,
+        ExecutionError::AdviceError {
+            err: AdviceError::AdviceReadLimitExceeded { limit: 2 },
+            ..
+        }
+    );
+}
+
+#[test]
+fn reversible_stepper_revisits_earlier_states() {
+    let program = checkpoint_program();
+    let mut host = DefaultHost::default();
+    let mut stepper = ReversibleStepper::new(checkpoint_processor(), &program, 8).unwrap();
+
+    // Record the stack at every clock cycle while stepping forward to the end of the program.
+    let mut stacks = vec![stepper.processor().stack().to_vec()];
+    while stepper.step(&mut host).unwrap() {
+        stacks.push(stepper.processor().stack().to_vec());
+    }
+    assert!(stepper.is_finished());
+    assert_eq!(stepper.clk().as_usize(), stacks.len() - 1);
+
+    // Step back through the whole program, one cycle at a time.
+    for (clk, expected) in stacks.iter().enumerate().rev().skip(1) {
+        assert!(stepper.back(&mut host).unwrap());
+        assert_eq!(stepper.clk().as_usize(), clk);
+        assert_eq!(stepper.processor().stack(), expected.as_slice());
+    }
+    assert!(!stepper.back(&mut host).unwrap());
+
+    // Seek back and forth across checkpoints.
+    for clk in [37_usize, 5, 60, 21, 22] {
+        stepper.seek(&mut host, RowIndex::from(clk)).unwrap();
+        assert_eq!(stepper.clk().as_usize(), clk);
+        assert_eq!(stepper.processor().stack(), stacks[clk].as_slice());
+    }
+}
+
+#[test]
+fn reversible_stepper_keeps_watchpoints_when_moving_back() {
+    let program = checkpoint_program();
+    let mut host = DefaultHost::default();
+    let mut processor = checkpoint_processor();
+    processor.add_mem_watchpoint(ContextId::root(), 200);
+    let mut stepper = ReversibleStepper::new(processor, &program, 8).unwrap();
+
+    while stepper.step(&mut host).unwrap() {}
+    let hits = stepper.processor().watchpoint_hits().to_vec();
+    assert!(!hits.is_empty());
+
+    // The hits recorded after the restored checkpoint are discarded, and recorded again when the
+    // same cycles are re-executed
+    stepper.seek(&mut host, RowIndex::from(0_u32)).unwrap();
+    assert!(stepper.processor().watchpoint_hits().is_empty());
+    while stepper.step(&mut host).unwrap() {}
+    assert_eq!(stepper.processor().watchpoint_hits(), hits.as_slice());
+}
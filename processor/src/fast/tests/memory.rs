@@ -1,7 +1,9 @@
 use core::assert_matches;
 
 use super::*;
-use crate::{ExecutionError, MemoryError, fast::Memory};
+use crate::{
+    ContextId, ExecutionError, MemoryAccess, MemoryAccessValue, MemoryError, fast::Memory,
+};
 
 #[test]
 fn test_memory_word_access_alignment() {
@@ -244,6 +246,49 @@ fn test_memory_element_limit_enforced() {
     );
 }
 
+#[test]
+fn test_memory_pages_are_copied_on_write() {
+    let ctx = ContextId::root();
+    let other_ctx = 1_u32.into();
+    let clk: RowIndex = 0_u32.into();
+    let mut memory = Memory::default();
+
+    // Words on both sides of a page boundary, and in another context at the same addresses.
+    for addr in [252_u32, 256] {
+        memory
+            .write_word(ctx, Felt::from_u32(addr), clk, [ONE; WORD_SIZE].into())
+            .unwrap();
+    }
+    memory.write_element(other_ctx, Felt::from_u32(257), ONE).unwrap();
+    assert_eq!(memory.num_accessed_words(), 3);
+
+    // Writes to a clone do not affect the original, and vice versa.
+    let mut clone = memory.clone();
+    clone.write_element(ctx, Felt::from_u32(252), ZERO).unwrap();
+    clone.write_element(ctx, Felt::from_u32(8), ONE).unwrap();
+    memory.write_element(other_ctx, Felt::from_u32(256), ONE).unwrap();
+
+    assert_eq!(
+        memory.snapshot(),
+        vec![
+            (ctx, 252, [ONE; WORD_SIZE].into()),
+            (ctx, 256, [ONE; WORD_SIZE].into()),
+            (other_ctx, 256, [ONE, ONE, ZERO, ZERO].into()),
+        ]
+    );
+    assert_eq!(
+        clone.snapshot(),
+        vec![
+            (ctx, 8, [ONE, ZERO, ZERO, ZERO].into()),
+            (ctx, 252, [ZERO, ONE, ONE, ONE].into()),
+            (ctx, 256, [ONE; WORD_SIZE].into()),
+            (other_ctx, 256, [ZERO, ONE, ZERO, ZERO].into()),
+        ]
+    );
+    assert_eq!(clone.num_accessed_words(), 4);
+    assert_eq!(memory.get_memory_state(other_ctx).len(), WORD_SIZE);
+}
+
 #[test]
 fn test_memory_element_limit_enforced_during_execution() {
     let mut host = DefaultHost::default();
@@ -319,3 +364,218 @@ fn test_memory_element_limit_enforced_via_with_options() {
         }
     );
 }
+
+#[test]
+fn test_context_memory_limit_enforced() {
+    let root_ctx = ContextId::root();
+    let other_ctx = 1_u32.into();
+    let clk: RowIndex = 0_u32.into();
+    let mut memory = Memory::new(ExecutionOptions::DEFAULT_MAX_MEMORY_ELEMENTS);
+    memory
+        .write_word(root_ctx, Felt::from_u32(0), clk, [ONE; WORD_SIZE].into())
+        .unwrap();
+
+    // Entries written before the limit was set count towards it.
+    memory.set_max_elements_per_context(Some(WORD_SIZE));
+    let err = memory.write_element(root_ctx, Felt::from_u32(4), ONE).unwrap_err();
+    assert_matches!(
+        err,
+        MemoryError::ContextMemoryLimitExceeded { ctx, addr: 4, max: WORD_SIZE } if ctx == root_ctx
+    );
+    memory.write_element(root_ctx, Felt::from_u32(1), ONE).unwrap();
+
+    // The limit applies to each context separately.
+    memory
+        .write_word(other_ctx, Felt::from_u32(4), clk, [ONE; WORD_SIZE].into())
+        .unwrap();
+    let err = memory
+        .write_word(other_ctx, Felt::from_u32(8), clk, [ONE; WORD_SIZE].into())
+        .unwrap_err();
+    assert_matches!(
+        err,
+        MemoryError::ContextMemoryLimitExceeded { ctx, addr: 8, .. } if ctx == other_ctx
+    );
+
+    memory.set_max_elements_per_context(None);
+    memory
+        .write_word(other_ctx, Felt::from_u32(8), clk, [ONE; WORD_SIZE].into())
+        .unwrap();
+}
+
+#[test]
+fn test_context_memory_limit_enforced_during_execution() {
+    let mut host = DefaultHost::default();
+
+    // Together with the procedure frame word written on entry to each context, the root context
+    // touches 2 distinct word addresses, and the called context touches 3.
+    let program_source = "
+    proc store_two
+        mem_storew_be.0
+        mem_storew_be.4
+    end
+
+    begin
+        mem_storew_be.0
+        call.store_two
+    end
+    ";
+    let program = Assembler::default()
+        .assemble_program("program", program_source)
+        .expect("program should assemble")
+        .unwrap_program();
+
+    let options = ExecutionOptions::default().with_max_context_memory_elements(2 * WORD_SIZE);
+    let processor = FastProcessor::new(StackInputs::default()).with_options(options).unwrap();
+    let err = processor.execute_sync(&program, &mut host).unwrap_err();
+    assert_matches!(
+        err,
+        ExecutionError::MemoryError {
+            err: MemoryError::ContextMemoryLimitExceeded { ctx, addr: 4, .. },
+            ..
+        } if ctx != ContextId::root()
+    );
+
+    // The limit is per context, so it doesn't need to accommodate the 5 words written in total.
+    let options = ExecutionOptions::default().with_max_context_memory_elements(3 * WORD_SIZE);
+    let mut processor =
+        FastProcessor::new_with_options(StackInputs::default(), AdviceInputs::default(), options)
+            .unwrap();
+    processor.execute_mut_sync(&program, &mut host).unwrap();
+    assert_eq!(processor.memory.num_accessed_words(), 5);
+}
+
+#[test]
+fn step_until_watchpoint_reports_accesses_to_watched_address() {
+    let source = "
+        begin
+            push.7 push.100 mem_store
+            push.1.2.3.4 push.104 mem_storew_le dropw
+            push.100 mem_load drop
+            push.9 push.100 mem_store
+        end
+    ";
+    let program = Assembler::default()
+        .assemble_program("program", source)
+        .unwrap()
+        .unwrap_program();
+    let ctx = ContextId::root();
+    let watched = |access, old_value: u32, new_value: u32| {
+        (access, Felt::from_u32(old_value), Felt::from_u32(new_value))
+    };
+
+    let mut host = DefaultHost::default();
+    let mut processor = FastProcessor::new(StackInputs::default());
+    processor.add_mem_watchpoint(ctx, 100);
+    let mut resume_ctx = processor.get_initial_resume_context(&program).unwrap();
+
+    let mut accesses = Vec::new();
+    while let Some(next_resume_ctx) =
+        processor.step_until_watchpoint_sync(&mut host, resume_ctx).unwrap()
+    {
+        let [hit] = processor.watchpoint_hits() else {
+            panic!("expected a single hit, got {:?}", processor.watchpoint_hits());
+        };
+        assert_eq!((hit.ctx, hit.addr), (ctx, 100));
+        // Execution pauses at the end of the cycle in which the access took place.
+        assert_eq!(processor.clk, hit.clk + 1);
+        accesses.push((hit.access, hit.old_value, hit.new_value));
+        resume_ctx = next_resume_ctx;
+    }
+
+    assert_eq!(
+        accesses,
+        [
+            watched(MemoryAccess::Write, 0, 7),
+            watched(MemoryAccess::Read, 7, 7),
+            watched(MemoryAccess::Write, 7, 9),
+        ]
+    );
+    assert!(processor.watchpoint_hits().is_empty());
+    assert!(processor.remove_mem_watchpoint(ctx, 100));
+    assert!(!processor.remove_mem_watchpoint(ctx, 100));
+}
+
+#[test]
+fn watchpoint_hits_are_capped() {
+    let source = "
+        begin
+            repeat.2000
+                push.1 push.100 mem_store
+            end
+        end
+    ";
+    let program = Assembler::default()
+        .assemble_program("program", source)
+        .unwrap()
+        .unwrap_program();
+
+    let mut host = DefaultHost::default();
+    let mut processor = FastProcessor::new(StackInputs::default());
+    processor.add_mem_watchpoint(ContextId::root(), 100);
+    processor.execute_mut_sync(&program, &mut host).unwrap();
+
+    let hits = processor.watchpoint_hits();
+    assert_eq!(hits.len(), Memory::MAX_WATCHPOINT_HITS);
+    // the earliest hits are kept, starting with the first write of the address
+    assert_eq!(hits[0].old_value, ZERO);
+}
+
+#[test]
+fn memory_access_log_records_accesses_in_order() {
+    let source = "
+        begin
+            push.7 push.100 mem_store
+            push.1.2.3.4 push.104 mem_storew_le dropw
+            push.100 mem_load drop
+            push.104 mem_loadw_le dropw
+        end
+    ";
+    let program = Assembler::default()
+        .assemble_program("program", source)
+        .unwrap()
+        .unwrap_program();
+    let mut host = DefaultHost::default();
+
+    // The log is only recorded when enabled.
+    let output = FastProcessor::new(StackInputs::default())
+        .execute_sync(&program, &mut host)
+        .unwrap();
+    assert!(output.memory.access_log().is_none());
+
+    let options = ExecutionOptions::default().with_memory_access_log();
+    let processor =
+        FastProcessor::new_with_options(StackInputs::default(), AdviceInputs::default(), options)
+            .expect("processor advice inputs should fit advice map limits");
+    let output = processor.execute_sync(&program, &mut host).unwrap();
+    let log = output.memory.access_log().unwrap();
+
+    // `push.1.2.3.4` leaves 4 on top of the stack, which `mem_storew_le` stores first.
+    let word = Word::from([4_u32, 3, 2, 1]);
+    let accesses: Vec<_> =
+        log.iter().map(|record| (record.addr, record.access, record.value)).collect();
+    assert_eq!(
+        accesses,
+        [
+            // The frame pointer is initialized before the program starts executing.
+            (
+                FMP_ADDR.as_canonical_u64() as u32,
+                MemoryAccess::Write,
+                MemoryAccessValue::Element(FMP_INIT_VALUE)
+            ),
+            (100, MemoryAccess::Write, MemoryAccessValue::Element(Felt::from_u32(7))),
+            (104, MemoryAccess::Write, MemoryAccessValue::Word(word)),
+            (100, MemoryAccess::Read, MemoryAccessValue::Element(Felt::from_u32(7))),
+            (104, MemoryAccess::Read, MemoryAccessValue::Word(word)),
+        ]
+    );
+    assert!(log.iter().all(|record| record.ctx == ContextId::root()));
+    assert!(log.windows(2).all(|pair| pair[0].clk < pair[1].clk));
+
+    // the log can also be enabled by the `with_options` builder
+    let output = FastProcessor::new(StackInputs::default())
+        .with_options(ExecutionOptions::default().with_memory_access_log())
+        .unwrap()
+        .execute_sync(&program, &mut host)
+        .unwrap();
+    assert_eq!(output.memory.access_log().map(<[_]>::len), Some(accesses.len()));
+}
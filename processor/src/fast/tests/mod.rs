@@ -7,8 +7,8 @@ use miden_assembly::{
     ast::{Module, ModuleKind, QualifiedProcedureName},
 };
 use miden_core::{
-    ONE, Word,
-    events::SystemEvent,
+    FMP_ADDR, FMP_INIT_VALUE, ONE, Word,
+    events::{EventName, SystemEvent},
     mast::{
         BasicBlockNodeBuilder, CallNodeBuilder, ExternalNodeBuilder, JoinNodeBuilder, MastNodeExt,
         SplitNodeBuilder,
@@ -32,15 +32,19 @@ use rstest::rstest;
 
 use super::*;
 use crate::{
-    AdviceInputs, BaseHost, DefaultHost, LoadedMastForest, ProcessorState, SyncHost,
+    AdviceInputs, BaseHost, CachingHost, CancellationToken, DefaultHost, ExecutionEvent,
+    ExecutionReplay, LoadedMastForest, MastForestLoadError, ProcessorState, ReplayRecorder,
+    SyncHost,
     advice::AdviceMutation,
-    event::EventError,
+    event::{EventError, EventNext, NoopEventHandler},
     operation::OperationError,
+    procedure_names,
     processor::{StackInterface, SystemInterface},
 };
 
 mod advice_provider;
 mod all_ops;
+mod checkpoint;
 mod masm_consistency;
 mod memory;
 
@@ -101,6 +105,25 @@ fn stack_get_safe_boundary() {
     assert_eq!(processor.stack_get_safe(usize::MAX), ZERO);
 }
 
+#[test]
+fn try_stack_get_reads_overflow_until_stack_depth() {
+    // Stack inputs hold elements 5..=20; pushing 4, 3, 2, 1 on top gives a stack of depth 20 with
+    // the element at position `idx` equal to `idx + 1`.
+    let mut processor = FastProcessor::new(stack_inputs_from_ints(5..=20_u64));
+    for value in (1..=4_u32).rev() {
+        processor.increment_size().unwrap();
+        processor.set(0, Felt::from_u32(value));
+    }
+    assert_eq!(processor.stack_depth(), 20);
+
+    assert_eq!(processor.try_stack_get(0), Some(Felt::from_u32(1)));
+    assert_eq!(processor.try_stack_get(15), Some(Felt::from_u32(16)));
+    assert_eq!(processor.try_stack_get(16), Some(Felt::from_u32(17)));
+    assert_eq!(processor.try_stack_get(19), Some(Felt::from_u32(20)));
+    assert_eq!(processor.try_stack_get(20), None);
+    assert_eq!(processor.try_stack_get(usize::MAX), None);
+}
+
 #[test]
 fn stack_get_word_safe_partial_read() {
     let inputs = stack_inputs_from_ints(1..=16_u64);
@@ -113,6 +136,655 @@ fn stack_get_word_safe_partial_read() {
     assert_eq!(word, [Felt::new_unchecked(16), ZERO, ZERO, ZERO].into());
 }
 
+#[test]
+fn stack_get_word_matches_little_endian_element_order() {
+    let inputs = stack_inputs_from_ints(1..=16_u64);
+    let processor = FastProcessor::new(inputs);
+
+    // Elements read from the top of the stack are the word's elements in little-endian order.
+    let word = processor.stack_get_word(0);
+    let top = [0, 1, 2, 3].map(|idx| processor.stack_get(idx));
+    assert_eq!(word.to_elements_le(), top);
+    assert_eq!(Word::from_elements_le(top), word);
+
+    let mut reversed = top;
+    reversed.reverse();
+    assert_eq!(word.to_elements_be(), reversed);
+    assert_eq!(Word::from_elements_be(reversed), word);
+}
+
+#[test]
+fn op_timing_is_collected_only_when_enabled() {
+    let ops = vec![Operation::Add, Operation::Mul, Operation::Swap, Operation::Add];
+    let mut mast_forest = MastForest::new();
+    let basic_block_id = BasicBlockNodeBuilder::new(ops).add_to_forest(&mut mast_forest).unwrap();
+    mast_forest.make_root(basic_block_id);
+    let program = Program::new(mast_forest.into(), basic_block_id);
+
+    let execute = |options: ExecutionOptions| {
+        FastProcessor::new_with_options(
+            stack_inputs_from_ints([1, 2, 3]),
+            AdviceInputs::default(),
+            options,
+        )
+        .unwrap()
+        .execute_sync(&program, &mut DefaultHost::default())
+        .unwrap()
+    };
+
+    let output = execute(ExecutionOptions::default().with_op_timing());
+    let op_timings = output.reports.unwrap().op_timings.expect("op timings should be collected");
+    assert_eq!(op_timings.keys().copied().collect::<Vec<_>>(), ["add", "mul", "swap"]);
+
+    // op timing can also be enabled by the `with_options` builder
+    let output = FastProcessor::new(stack_inputs_from_ints([1, 2, 3]))
+        .with_options(ExecutionOptions::default().with_op_timing())
+        .unwrap()
+        .execute_sync(&program, &mut DefaultHost::default())
+        .unwrap();
+    assert!(output.reports.unwrap().op_timings.is_some());
+
+    let output = execute(ExecutionOptions::default());
+    assert!(output.reports.is_none());
+}
+
+#[test]
+fn cycle_report_attributes_cycles_to_procedures() {
+    let source = "
+        proc leaf
+            push.1 add
+        end
+
+        proc wrapper
+            call.leaf call.leaf
+        end
+
+        begin
+            push.5 call.wrapper call.leaf swap drop
+        end
+    ";
+    let program = Assembler::default()
+        .assemble_program("program", source)
+        .unwrap()
+        .unwrap_program();
+
+    let execute = |options: ExecutionOptions| {
+        FastProcessor::new_with_options(StackInputs::default(), AdviceInputs::default(), options)
+            .unwrap()
+            .execute_sync(&program, &mut DefaultHost::default())
+            .unwrap()
+    };
+
+    let output = execute(ExecutionOptions::default().with_cycle_report());
+    assert_eq!(output.stack.get_element(0), Some(Felt::from_u32(8)));
+    let report = output.reports.unwrap().cycle_report.expect("cycle report should be collected");
+
+    // Every cycle is charged to exactly one procedure.
+    let charged: u32 = report.iter().map(|(_, procedure)| procedure.cycles).sum();
+    assert_eq!(charged, report.total_cycles());
+
+    let main = report.get(&program.hash()).expect("entrypoint should be metered");
+    assert_eq!(main.invocations, 1);
+
+    let invocations = report.iter().map(|(_, procedure)| procedure.invocations).collect::<Vec<_>>();
+    assert_eq!(invocations.iter().sum::<u32>(), 5);
+    let (_, leaf) = report
+        .iter()
+        .find(|(_, procedure)| procedure.invocations == 3)
+        .expect("leaf procedure should be invoked three times");
+    assert_eq!(leaf.cycles % 3, 0);
+
+    // the cycle report can also be enabled by the `with_options` builder
+    let output = FastProcessor::new(StackInputs::default())
+        .with_options(ExecutionOptions::default().with_cycle_report())
+        .unwrap()
+        .execute_sync(&program, &mut DefaultHost::default())
+        .unwrap();
+    assert!(output.reports.unwrap().cycle_report.is_some());
+
+    let output = execute(ExecutionOptions::default());
+    assert!(output.reports.is_none());
+}
+
+#[cfg(feature = "trace-nodes")]
+#[test]
+fn node_spans_record_cycles_per_node() {
+    use std::sync::Mutex;
+
+    use tracing::{
+        Event, Metadata, Subscriber,
+        field::{Field, Visit},
+        span,
+    };
+
+    struct RecordedSpan {
+        name: &'static str,
+        parent: Option<u64>,
+        cycles: Option<u64>,
+    }
+
+    /// A subscriber which records the name, parent, and `cycles` field of every span.
+    #[derive(Clone, Default)]
+    struct SpanRecorder(Arc<Mutex<Vec<RecordedSpan>>>);
+
+    struct CyclesVisitor<'a>(&'a mut Option<u64>);
+
+    impl Visit for CyclesVisitor<'_> {
+        fn record_u64(&mut self, field: &Field, value: u64) {
+            if field.name() == "cycles" {
+                *self.0 = Some(value);
+            }
+        }
+
+        fn record_debug(&mut self, _field: &Field, _value: &dyn core::fmt::Debug) {}
+    }
+
+    impl Subscriber for SpanRecorder {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attrs: &span::Attributes<'_>) -> span::Id {
+            let mut spans = self.0.lock().unwrap();
+            spans.push(RecordedSpan {
+                name: attrs.metadata().name(),
+                parent: attrs.parent().map(span::Id::into_u64),
+                cycles: None,
+            });
+            span::Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, id: &span::Id, values: &span::Record<'_>) {
+            let mut spans = self.0.lock().unwrap();
+            let span = &mut spans[id.into_u64() as usize - 1];
+            values.record(&mut CyclesVisitor(&mut span.cycles));
+        }
+
+        fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, _span: &span::Id) {}
+
+        fn exit(&self, _span: &span::Id) {}
+    }
+
+    let source = "
+        proc leaf
+            push.1 add
+        end
+
+        begin
+            push.5 call.leaf push.1 while.true push.0 end swap drop
+        end
+    ";
+    let program = Assembler::default()
+        .assemble_program("program", source)
+        .unwrap()
+        .unwrap_program();
+
+    let recorder = SpanRecorder::default();
+    tracing::subscriber::with_default(recorder.clone(), || {
+        FastProcessor::new(StackInputs::default())
+            .execute_sync(&program, &mut DefaultHost::default())
+            .unwrap();
+    });
+
+    let spans = recorder.0.lock().unwrap();
+    let node_spans = spans
+        .iter()
+        .enumerate()
+        .filter(|(_, span)| matches!(span.name, "join" | "loop" | "call"))
+        .collect::<Vec<_>>();
+    assert!(node_spans.iter().all(|(_, span)| span.cycles.is_some()));
+
+    // spans of nested nodes are children of the spans of their enclosing nodes
+    let (_, root) = node_spans[0];
+    let (_, call) = node_spans.iter().find(|(_, span)| span.name == "call").unwrap();
+    let (_, r#loop) = node_spans.iter().find(|(_, span)| span.name == "loop").unwrap();
+    assert_eq!(root.name, "join");
+    assert_eq!(root.parent, None);
+    let call_parent = call.parent.expect("call span should have a parent") as usize - 1;
+    assert_eq!(spans[call_parent].name, "join");
+    assert!(call.cycles.unwrap() + r#loop.cycles.unwrap() < root.cycles.unwrap());
+}
+
+#[test]
+fn cycle_report_renders_folded_call_stacks() {
+    let source = "
+        proc leaf
+            push.1 add
+        end
+
+        proc wrapper
+            call.leaf call.leaf
+        end
+
+        begin
+            push.5 call.wrapper call.leaf swap drop
+        end
+    ";
+    let package = Assembler::default().assemble_program("program", source).unwrap();
+    let debug_info = package.debug_info().unwrap().unwrap();
+    let program = package.unwrap_program();
+
+    let options = ExecutionOptions::default().with_cycle_report();
+    let report =
+        FastProcessor::new_with_options(StackInputs::default(), AdviceInputs::default(), options)
+            .unwrap()
+            .execute_sync(&program, &mut DefaultHost::default())
+            .unwrap()
+            .reports
+            .and_then(|reports| reports.cycle_report)
+            .expect("cycle report should be collected");
+
+    // Every cycle charged to a procedure is charged to exactly one of its call stacks.
+    let stack_cycles: u32 = report.call_stacks().map(|(_, cycles)| cycles).sum();
+    assert_eq!(stack_cycles, report.total_cycles());
+
+    let names = procedure_names(program.mast_forest(), &debug_info);
+    let folded = report.to_folded_stacks(|root| names.get(root).cloned());
+    assert_eq!(
+        folded,
+        "::$exec::$main 22\n\
+         ::$exec::$main;::$exec::wrapper 6\n\
+         ::$exec::$main;::$exec::wrapper;::$exec::leaf 10\n\
+         ::$exec::$main;::$exec::leaf 5\n"
+    );
+
+    // Procedures without a name fall back to their MAST root.
+    let folded = report.to_folded_stacks(|_| None);
+    assert!(folded.starts_with(&format!("{} ", program.hash().to_hex())));
+}
+
+#[test]
+fn coverage_report_maps_executed_operations_to_source_lines() {
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let source = "
+        begin
+            push.3
+            dup neq.0
+            while.true
+                sub.1
+                dup neq.0
+            end
+            if.true
+                push.5
+            else
+                push.7
+            end
+            drop
+        end
+    ";
+    let package = Assembler::new(source_manager.clone())
+        .assemble_program("program", source)
+        .unwrap();
+    let debug_info = package.debug_info().unwrap().unwrap();
+    let program = package.unwrap_program();
+
+    let execute = |options: ExecutionOptions| {
+        FastProcessor::new_with_options(StackInputs::default(), AdviceInputs::default(), options)
+            .unwrap()
+            .execute_sync(&program, &mut DefaultHost::default())
+            .unwrap()
+    };
+
+    let output = execute(ExecutionOptions::default().with_coverage());
+    let report = output.reports.unwrap().coverage.expect("coverage should be recorded");
+    assert!(report.operation_counts(&program.hash()).is_none());
+
+    let lcov = report.to_lcov(program.mast_forest(), &debug_info, source_manager.as_ref());
+    let lines = lcov.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "TN:");
+    assert!(lines[1].starts_with("SF:"));
+    // Only the branch which was not taken is reported as uncovered.
+    assert_eq!(
+        lines[2..],
+        [
+            "DA:3,1",
+            "DA:4,1",
+            "DA:6,3",
+            "DA:7,3",
+            "DA:10,0",
+            "DA:12,1",
+            "DA:14,1",
+            "LF:7",
+            "LH:6",
+            "end_of_record"
+        ]
+    );
+
+    // coverage can also be enabled by the `with_options` builder
+    let output = FastProcessor::new(StackInputs::default())
+        .with_options(ExecutionOptions::default().with_coverage())
+        .unwrap()
+        .execute_sync(&program, &mut DefaultHost::default())
+        .unwrap();
+    assert!(output.reports.unwrap().coverage.is_some());
+
+    let output = execute(ExecutionOptions::default());
+    assert!(output.reports.is_none());
+}
+
+#[test]
+fn step_until_breakpoint_stops_on_procedure_invocations() {
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let lib_module = {
+        let source = "
+            namespace foo::bar
+
+            pub proc watched
+                push.1 add
+            end
+
+            pub proc other
+                push.2 add
+            end
+        ";
+        let mut parser = Module::parser(None);
+        parser
+            .parse_str(Some(Path::new("foo::bar")), source, source_manager.clone())
+            .unwrap()
+    };
+    let library = Assembler::new(source_manager.clone())
+        .assemble_library("lib", lib_module, None::<Box<Module>>)
+        .map(Arc::<Package>::from)
+        .unwrap();
+    let watched = library.get_procedure_root_by_path("foo::bar::watched").unwrap();
+
+    let program_source = "
+        use foo::bar
+
+        begin
+            push.10 call.bar::watched drop
+            push.20 exec.bar::watched drop
+            push.30 call.bar::other drop
+            push.40 procref.bar::watched mem_storew_le.100 dropw push.100 dynexec
+            swap drop
+        end
+    ";
+    let program = Assembler::new(source_manager)
+        .with_package(library.clone(), Linkage::Dynamic)
+        .unwrap()
+        .assemble_program("program", program_source)
+        .unwrap()
+        .unwrap_program();
+
+    let mut host = DefaultHost::default();
+    host.load_library(library.mast_forest()).unwrap();
+    let mut processor = FastProcessor::new(StackInputs::default());
+    processor.add_breakpoint(watched);
+    let mut resume_ctx = processor.get_initial_resume_context(&program).unwrap();
+
+    // Execution pauses before the procedure runs, so the argument is still on top of the stack.
+    let mut stack_tops = Vec::new();
+    while let Some(next_resume_ctx) =
+        processor.step_until_breakpoint_sync(&mut host, resume_ctx).unwrap()
+    {
+        assert_eq!(processor.breakpoint_hit(), Some(watched));
+        stack_tops.push(processor.stack_get(0));
+        resume_ctx = next_resume_ctx;
+    }
+
+    assert_eq!(stack_tops, [10, 20, 40].map(Felt::from_u32));
+    assert_eq!(processor.breakpoint_hit(), None);
+    assert_eq!(processor.stack_get(0), Felt::from_u32(41));
+}
+
+#[test]
+fn execution_replay_reproduces_host_interactions() {
+    // The signature matches the `EventHandler` callback type.
+    #[allow(clippy::unnecessary_wraps)]
+    fn push_advice(_process: &ProcessorState) -> Result<Vec<AdviceMutation>, EventError> {
+        Ok(vec![AdviceMutation::extend_stack([3, 4].map(Felt::from_u32))])
+    }
+
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let lib_module = {
+        let source = "
+            namespace foo::bar
+
+            pub proc double
+                dup add
+            end
+        ";
+        let mut parser = Module::parser(None);
+        parser
+            .parse_str(Some(Path::new("foo::bar")), source, source_manager.clone())
+            .unwrap()
+    };
+    let library = Assembler::new(source_manager.clone())
+        .assemble_library("lib", lib_module, None::<Box<Module>>)
+        .map(Arc::<Package>::from)
+        .unwrap();
+    let assemble = |source: &str| {
+        Assembler::new(source_manager.clone())
+            .with_package(library.clone(), Linkage::Dynamic)
+            .unwrap()
+            .assemble_program("program", source)
+            .unwrap()
+            .unwrap_program()
+    };
+    let program = assemble(
+        "
+        use foo::bar
+
+        begin
+            emit.event(\"test::replay\") adv_push adv_push add call.bar::double swap drop
+        end
+        ",
+    );
+
+    let mut host = DefaultHost::default();
+    host.load_library(library.mast_forest()).unwrap();
+    host.register_handler(EventName::new("test::replay"), Arc::new(push_advice))
+        .unwrap();
+    let mut recorder = ReplayRecorder::new(host, StackInputs::default(), AdviceInputs::default());
+    let output = FastProcessor::new(StackInputs::default())
+        .execute_sync(&program, &mut recorder)
+        .unwrap();
+    assert_eq!(output.stack.get_element(0), Some(Felt::from_u32(14)));
+
+    let replay = recorder.into_replay();
+    let replay = ExecutionReplay::read_from_bytes(&replay.to_bytes()).unwrap();
+    let replayed = replay.execute(&program, ExecutionOptions::default()).unwrap();
+    assert_eq!(replayed.stack, output.stack);
+
+    // A program which requests something else from the host diverges from the replay.
+    let other_program = assemble(
+        "
+        begin
+            emit.event(\"test::other\")
+        end
+        ",
+    );
+    assert!(replay.execute(&other_program, ExecutionOptions::default()).is_err());
+}
+
+#[test]
+fn event_middleware_wraps_registered_handlers() {
+    // The signature matches the `EventHandler` callback type.
+    #[allow(clippy::unnecessary_wraps)]
+    fn push_five(_process: &ProcessorState) -> Result<Vec<AdviceMutation>, EventError> {
+        Ok(vec![AdviceMutation::extend_stack([Felt::from_u32(5)])])
+    }
+
+    let log = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let logger = {
+        let log = log.clone();
+        move |event: &EventName, process: &ProcessorState, next: EventNext<'_>| {
+            log.lock().unwrap().push(event.as_str().to_string());
+            next.run(process)
+        }
+    };
+    let deny_list = |event: &EventName, process: &ProcessorState, next: EventNext<'_>| {
+        if event.as_str() == "test::denied" {
+            return Err(format!("event '{event}' is not allowed").into());
+        }
+        next.run(process)
+    };
+
+    let mut host = DefaultHost::default()
+        .with_event_middleware(Arc::new(logger))
+        .with_event_middleware(Arc::new(deny_list));
+    host.register_handler(EventName::new("test::allowed"), Arc::new(push_five))
+        .unwrap();
+    host.register_handler(EventName::new("test::denied"), Arc::new(push_five))
+        .unwrap();
+
+    let allowed = Assembler::default()
+        .assemble_program("program", "begin emit.event(\"test::allowed\") adv_push swap drop end")
+        .unwrap()
+        .unwrap_program();
+    let output = FastProcessor::new(StackInputs::default())
+        .execute_sync(&allowed, &mut host)
+        .unwrap();
+    assert_eq!(output.stack.get_element(0), Some(Felt::from_u32(5)));
+
+    let denied = Assembler::default()
+        .assemble_program("program", "begin emit.event(\"test::denied\") end")
+        .unwrap()
+        .unwrap_program();
+    let err = FastProcessor::new(StackInputs::default())
+        .execute_sync(&denied, &mut host)
+        .unwrap_err();
+    assert!(err.to_string().contains("test::denied"));
+
+    // the logger is the outermost middleware, so it observes denied events as well
+    assert_eq!(*log.lock().unwrap(), ["test::allowed", "test::denied"]);
+}
+
+#[test]
+fn execute_with_observer_reports_execution_events() {
+    let source = "
+        proc store
+            adv_push push.8 mem_store
+        end
+
+        begin
+            call.store
+            push.2 push.1 while.true sub.1 dup neq.0 end drop
+        end
+    ";
+    let program = Assembler::default()
+        .assemble_program("program", source)
+        .unwrap()
+        .unwrap_program();
+
+    let mut events = Vec::new();
+    FastProcessor::new(StackInputs::default())
+        .with_advice(AdviceInputs::default().with_stack_values([5]).unwrap())
+        .unwrap()
+        .execute_with_observer_sync(&program, &mut DefaultHost::default(), &mut |event| {
+            events.push(event)
+        })
+        .unwrap();
+
+    // The program root encloses all other nodes.
+    assert_matches!(
+        events.first(),
+        Some(ExecutionEvent::NodeEntered { digest, .. }) if *digest == program.hash()
+    );
+    assert_matches!(
+        events.last(),
+        Some(ExecutionEvent::NodeExited { digest, .. }) if *digest == program.hash()
+    );
+    // Loop iterations which repeat the body do not exit the loop node.
+    let entered = events
+        .iter()
+        .filter(|e| matches!(e, ExecutionEvent::NodeEntered { .. }))
+        .count();
+    let exited = events.iter().filter(|e| matches!(e, ExecutionEvent::NodeExited { .. })).count();
+    assert_eq!(entered, exited);
+
+    let callee_ctx = events
+        .iter()
+        .find_map(|event| match event {
+            ExecutionEvent::ContextSwitched { to, .. } => Some(*to),
+            _ => None,
+        })
+        .expect("the call should switch contexts");
+    let other_events = events
+        .iter()
+        .filter(|e| {
+            !matches!(e, ExecutionEvent::NodeEntered { .. } | ExecutionEvent::NodeExited { .. })
+        })
+        .map(|event| match *event {
+            ExecutionEvent::ContextSwitched { from, to, .. } => format!("switch {from} -> {to}"),
+            ExecutionEvent::MemoryElementWritten { ctx, addr, element, .. } => {
+                format!("write {element} to {ctx}:{addr}")
+            },
+            ExecutionEvent::AdviceElementPopped { element, .. } => format!("pop {element}"),
+            event => panic!("unexpected event {event:?}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        other_events,
+        [
+            format!("write {FMP_INIT_VALUE} to {}:{FMP_ADDR}", ContextId::root()),
+            format!("write {FMP_INIT_VALUE} to {callee_ctx}:{FMP_ADDR}"),
+            format!("switch {} -> {callee_ctx}", ContextId::root()),
+            "pop 5".to_string(),
+            format!("write 5 to {callee_ctx}:8"),
+            format!("switch {callee_ctx} -> {}", ContextId::root()),
+        ]
+    );
+}
+
+#[test]
+fn warm_start_preloads_memory() {
+    let word = Word::from([1_u32, 2, 3, 4]);
+    let program = Assembler::default()
+        .assemble_program("program", "begin padw mem_loadw_le.40 swapw dropw end")
+        .unwrap()
+        .unwrap_program();
+
+    let processor = FastProcessor::warm_start(
+        StackInputs::default(),
+        AdviceInputs::default(),
+        vec![(ContextId::root(), 40, word)],
+    )
+    .unwrap();
+    let output = processor.execute_sync(&program, &mut DefaultHost::default()).unwrap();
+
+    assert_eq!(output.stack.get_word(0).unwrap(), word);
+    assert!(output.memory.snapshot().contains(&(ContextId::root(), 40, word)));
+
+    // snapshot addresses must be word-aligned
+    let err = FastProcessor::warm_start(
+        StackInputs::default(),
+        AdviceInputs::default(),
+        vec![(ContextId::root(), 41, word)],
+    )
+    .unwrap_err();
+    assert_matches!(
+        err,
+        ExecutionError::MemoryErrorNoCtx(crate::MemoryError::UnalignedWordAccess { addr: 41, .. })
+    );
+}
+
+#[test]
+fn warm_start_with_options_applies_the_options() {
+    let word = Word::from([1_u32, 2, 3, 4]);
+    let program = Assembler::default()
+        .assemble_program("program", "begin padw mem_loadw_le.40 swapw dropw end")
+        .unwrap()
+        .unwrap_program();
+
+    let options = ExecutionOptions::default().with_memory_access_log();
+    let processor = FastProcessor::warm_start_with_options(
+        StackInputs::default(),
+        AdviceInputs::default(),
+        vec![(ContextId::root(), 40, word)],
+        options,
+    )
+    .unwrap();
+    let output = processor.execute_sync(&program, &mut DefaultHost::default()).unwrap();
+
+    assert_eq!(output.stack.get_word(0).unwrap(), word);
+    assert!(output.memory.access_log().is_some_and(|log| !log.is_empty()));
+}
+
 #[test]
 fn stack_get_word_safe_usize_max() {
     let processor = FastProcessor::new(StackInputs::default());
@@ -284,6 +956,7 @@ fn host_loaded_package_debug_info_reports_loaded_source_span() {
             label,
             source_file: Some(actual_source_file),
             err: OperationError::FailedAssertion { err_code, .. },
+            ..
         } if label == SourceSpan::new(loaded_source_file.id(), 0u32..11)
             && actual_source_file.id() == loaded_source_file.id()
             && err_code == Felt::from_u32(9)
@@ -331,6 +1004,7 @@ fn host_loaded_package_debug_info_requires_source_aware_execution() {
             label,
             source_file: Some(actual_source_file),
             err: OperationError::FailedAssertion { err_code, .. },
+            ..
         } if label == SourceSpan::new(loaded_source_file.id(), 0u32..11)
             && actual_source_file.id() == loaded_source_file.id()
             && err_code == Felt::from_u32(9)
@@ -362,6 +1036,7 @@ fn host_loaded_package_debug_info_survives_missing_caller_entrypoint_root() {
             label,
             source_file: Some(actual_source_file),
             err: OperationError::FailedAssertion { err_code, .. },
+            ..
         } if label == SourceSpan::new(loaded_source_file.id(), 0u32..11)
             && actual_source_file.id() == loaded_source_file.id()
             && err_code == Felt::from_u32(9)
@@ -392,6 +1067,7 @@ fn host_loaded_package_debug_info_survives_step_execution() {
             label,
             source_file: Some(actual_source_file),
             err: OperationError::FailedAssertion { err_code, .. },
+            ..
         } if label == SourceSpan::new(loaded_source_file.id(), 0u32..11)
             && actual_source_file.id() == loaded_source_file.id()
             && err_code == Felt::from_u32(9)
@@ -431,6 +1107,7 @@ fn direct_step_with_package_debug_info_seeds_initial_resume_context() {
             label,
             source_file: Some(actual_source_file),
             err: OperationError::FailedAssertion { err_code, .. },
+            ..
         } if label == SourceSpan::new(loaded_source_file.id(), 0u32..11)
             && actual_source_file.id() == loaded_source_file.id()
             && err_code == Felt::from_u32(9)
@@ -487,6 +1164,7 @@ fn host_loaded_stripped_package_restores_caller_debug_info() {
             label,
             source_file: Some(actual_source_file),
             err: OperationError::FailedAssertion { err_code, .. },
+            ..
         } if label == SourceSpan::new(caller_source_file.id(), 12u32..23)
             && actual_source_file.id() == caller_source_file.id()
             && err_code == Felt::from_u32(11)
@@ -522,6 +1200,7 @@ fn host_loaded_debug_info_survives_stripped_intermediate_package() {
             label,
             source_file: Some(actual_source_file),
             err: OperationError::FailedAssertion { err_code, .. },
+            ..
         } if label == SourceSpan::new(leaf_source_file.id(), 0u32..11)
             && actual_source_file.id() == leaf_source_file.id()
             && err_code == Felt::from_u32(9)
@@ -749,6 +1428,7 @@ fn package_source_debug_execution_distinguishes_same_exec_node_split_children()
             label,
             source_file: Some(actual_source_file),
             err: OperationError::FailedAssertion { err_code, .. },
+            ..
         } if label == SourceSpan::new(source_file.id(), 6u32..12)
             && actual_source_file.id() == source_file.id()
             && err_code == Felt::from_u32(7)
@@ -783,6 +1463,7 @@ fn package_source_debug_execution_uses_manifest_entrypoint_source_node() {
             label,
             source_file: Some(actual_source_file),
             err: OperationError::FailedAssertion { err_code, .. },
+            ..
         } if label == SourceSpan::new(fixture.source_file.id(), 9u32..17)
             && actual_source_file.id() == fixture.source_file.id()
             && err_code == Felt::from_u32(9)
@@ -1242,6 +1923,7 @@ fn package_source_debug_malformed_external_preserves_external_source_span() {
             label,
             source_file: Some(actual_source_file),
             err: OperationError::MalformedMastForestInHost { .. },
+            ..
         } if label == expected_span && actual_source_file.id() == source_file.id()
     );
 }
@@ -1273,6 +1955,7 @@ async fn package_source_debug_malformed_external_preserves_external_source_span_
             label,
             source_file: Some(actual_source_file),
             err: OperationError::MalformedMastForestInHost { .. },
+            ..
         } if label == expected_span && actual_source_file.id() == source_file.id()
     );
 }
@@ -1316,6 +1999,197 @@ fn test_cycle_limit_exceeded() {
     assert_matches!(err, ExecutionError::CycleLimitExceeded(max_cycles) if max_cycles == MIN_TRACE_LEN as u32);
 }
 
+/// Tests that `ExecutionError::Cancelled` is emitted at the next cancellation check once the
+/// cancellation token has been cancelled.
+#[test]
+fn test_execution_cancelled() {
+    let mut host = DefaultHost::default();
+    let program = simple_program_with_ops(vec![
+        Operation::Swap;
+        2 * ExecutionOptions::CANCELLATION_CHECK_INTERVAL
+            as usize
+    ]);
+
+    let token = CancellationToken::new();
+    let options = ExecutionOptions::default().with_cancellation_token(token.clone());
+
+    // Execution completes while the token has not been cancelled.
+    let processor = FastProcessor::new_with_options(
+        StackInputs::default(),
+        AdviceInputs::default(),
+        options.clone(),
+    )
+    .expect("processor advice inputs should fit advice map limits");
+    processor.execute_sync(&program, &mut host).unwrap();
+
+    token.cancel();
+    let processor =
+        FastProcessor::new_with_options(StackInputs::default(), AdviceInputs::default(), options)
+            .expect("processor advice inputs should fit advice map limits");
+    let err = processor.execute_sync(&program, &mut host).unwrap_err();
+
+    assert_matches!(
+        err,
+        ExecutionError::Cancelled(clk) if clk == ExecutionOptions::CANCELLATION_CHECK_INTERVAL
+    );
+}
+
+/// Tests that `ExecutionError::CallDepthLimitExceeded` is emitted when a program nests more
+/// contexts than allowed.
+#[test]
+fn test_call_depth_limit_exceeded() {
+    let source = "
+        proc inner
+            push.1 drop
+        end
+
+        proc middle
+            call.inner
+        end
+
+        proc outer
+            call.middle
+        end
+
+        begin
+            call.outer
+        end
+    ";
+    let program = Assembler::default()
+        .assemble_program("program", source)
+        .unwrap()
+        .unwrap_program();
+    let execute = |max_call_depth| {
+        let options = ExecutionOptions::default().with_max_call_depth(max_call_depth);
+        FastProcessor::new_with_options(StackInputs::default(), AdviceInputs::default(), options)
+            .expect("processor advice inputs should fit advice map limits")
+            .execute_sync(&program, &mut DefaultHost::default())
+    };
+
+    execute(3).unwrap();
+    let err = execute(2).unwrap_err();
+    assert_matches!(err, ExecutionError::CallDepthLimitExceeded { depth: 3, max: 2 });
+
+    // a rejected call leaves the stack of the caller, including its overflow, untouched
+    let program = Assembler::default()
+        .assemble_program("program", "proc inner nop end begin repeat.4 push.1 end call.inner end")
+        .unwrap()
+        .unwrap_program();
+    let options = ExecutionOptions::default().with_max_call_depth(0);
+    let mut processor =
+        FastProcessor::new_with_options(StackInputs::default(), AdviceInputs::default(), options)
+            .unwrap();
+    let mut host = DefaultHost::default();
+    let mut resume_ctx = processor.get_initial_resume_context(&program).unwrap();
+    let err = loop {
+        match processor.step_sync(&mut host, resume_ctx) {
+            Ok(Some(next_resume_ctx)) => resume_ctx = next_resume_ctx,
+            Ok(None) => panic!("the call should exceed the call depth limit"),
+            Err(err) => break err,
+        }
+    };
+    assert_matches!(err, ExecutionError::CallDepthLimitExceeded { depth: 1, max: 0 });
+    assert_eq!(processor.stack().len(), 20);
+}
+
+/// Tests that `ExecutionError::EventLimitExceeded` is emitted when a program executes more `emit`
+/// operations than allowed.
+#[test]
+fn test_event_limit_exceeded() {
+    let program = Assembler::default()
+        .assemble_program(
+            "program",
+            "begin emit.event(\"test::noop\") emit.event(\"test::noop\") emit.event(\"test::noop\") end",
+        )
+        .unwrap()
+        .unwrap_program();
+    let execute = |max_emitted_events| {
+        let mut host = DefaultHost::default();
+        host.register_handler(EventName::new("test::noop"), Arc::new(NoopEventHandler))
+            .unwrap();
+        let options = ExecutionOptions::default().with_max_emitted_events(max_emitted_events);
+        FastProcessor::new_with_options(StackInputs::default(), AdviceInputs::default(), options)
+            .expect("processor advice inputs should fit advice map limits")
+            .execute_sync(&program, &mut host)
+    };
+
+    execute(3).unwrap();
+    let err = execute(2).unwrap_err();
+    assert_matches!(err, ExecutionError::EventLimitExceeded(2));
+}
+
+/// Tests that the kernel access report records each `syscall` with the clock cycle at which it was
+/// made.
+#[test]
+fn test_kernel_access_report() {
+    let kernel_source = "
+        pub proc foo
+            push.1 drop
+        end
+
+        pub proc bar
+            push.2 drop
+        end
+
+        pub proc unused
+            push.3 drop
+        end
+    ";
+    let program_source = "
+        begin
+            syscall.foo syscall.bar syscall.foo
+        end
+    ";
+
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let kernel = parse_kernel_source(source_manager.clone(), kernel_source);
+    let kernel_lib = Assembler::new(source_manager.clone())
+        .assemble_kernel("kernel", kernel, None)
+        .map(Arc::<miden_assembly::package::Package>::from)
+        .unwrap();
+    let program = Assembler::with_kernel(source_manager, kernel_lib.clone())
+        .unwrap()
+        .assemble_program("program", program_source)
+        .unwrap()
+        .unwrap_program();
+    let proc_root = |name: &str| {
+        kernel_lib
+            .get_procedure_root_by_path(format!("::$kernel::{name}").as_str())
+            .unwrap()
+    };
+
+    let mut host =
+        crate::test_utils::TestHost::with_kernel_forest(kernel_lib.mast_forest().clone());
+    let options = ExecutionOptions::default().with_kernel_access_report();
+    let output =
+        FastProcessor::new_with_options(StackInputs::default(), AdviceInputs::default(), options)
+            .expect("processor advice inputs should fit advice map limits")
+            .execute_sync(&program, &mut host)
+            .unwrap();
+    let report = output.reports.unwrap().kernel_access_report.unwrap();
+
+    let (foo, bar) = (proc_root("foo"), proc_root("bar"));
+    assert_eq!(report.num_invocations(&foo), 2);
+    assert_eq!(report.num_invocations(&bar), 1);
+    assert_eq!(report.num_invocations(&proc_root("unused")), 0);
+    let [first_foo, second_foo] = report.invocation_cycles(&foo) else {
+        panic!("expected two invocations of foo");
+    };
+    assert!(first_foo < &report.invocation_cycles(&bar)[0]);
+    assert!(&report.invocation_cycles(&bar)[0] < second_foo);
+
+    assert!(report.procedures_not_in(&[foo, bar]).is_empty());
+    assert_eq!(report.procedures_not_in(&[foo]), [bar]);
+
+    // the report can also be enabled by the `with_options` builder
+    let output = FastProcessor::new(StackInputs::default())
+        .with_options(ExecutionOptions::default().with_kernel_access_report())
+        .unwrap()
+        .execute_sync(&program, &mut host)
+        .unwrap();
+    assert_eq!(output.reports.unwrap().kernel_access_report.unwrap().num_invocations(&foo), 2);
+}
+
 /// Tests that a program using exactly `max_cycles` cycles succeeds.
 ///
 /// This is a regression test for the off-by-one error where the cycle limit check used `>=`
@@ -1737,9 +2611,12 @@ fn stack_buffer_is_not_preallocated_to_operand_stack_depth_limit() {
     let options = ExecutionOptions::default()
         .with_max_stack_depth(DEFAULT_MAX_STACK_DEPTH + GROWTH_MARGIN)
         .unwrap();
-    let mut processor =
-        FastProcessor::new_with_options(StackInputs::default(), AdviceInputs::default(), options)
-            .expect("processor advice inputs should fit advice map limits");
+    let mut processor = FastProcessor::new_with_options(
+        StackInputs::default(),
+        AdviceInputs::default(),
+        options.clone(),
+    )
+    .expect("processor advice inputs should fit advice map limits");
 
     assert_eq!(
         processor.stack.len(),
@@ -1776,6 +2653,31 @@ fn stack_buffer_is_not_preallocated_to_operand_stack_depth_limit() {
     );
 }
 
+#[test]
+fn small_initial_stack_buffer_grows_on_demand() {
+    const MIN_SIZE: usize = ExecutionOptions::MIN_INITIAL_STACK_BUFFER_SIZE;
+
+    let options = ExecutionOptions::default().with_initial_stack_buffer_size(MIN_SIZE).unwrap();
+    let stack_inputs = stack_inputs_from_ints(vec![1, 2, 3]);
+    let processor =
+        FastProcessor::new_with_options(stack_inputs, AdviceInputs::default(), options.clone())
+            .expect("processor advice inputs should fit advice map limits");
+    assert_eq!(processor.stack.len(), MIN_SIZE);
+
+    // Options applied after construction resize the buffer while preserving the stack inputs.
+    let mut processor = FastProcessor::new(stack_inputs).with_options(options).unwrap();
+    assert_eq!(processor.stack.len(), MIN_SIZE);
+
+    let mut ops = pad_then_drop_ops(4 * MIN_SIZE);
+    ops.push(Operation::Add);
+    let output = processor
+        .execute_mut_sync(&simple_program_with_ops(ops), &mut DefaultHost::default())
+        .expect("pushing past a small initial buffer should grow it instead of failing");
+    assert!(processor.stack.len() > MIN_SIZE);
+    assert_eq!(output.get_element(0), Some(Felt::from_u32(3)));
+    assert_eq!(output.get_element(1), Some(Felt::from_u32(3)));
+}
+
 #[test]
 fn stack_growth_recenters_shallow_context_when_requested_len_exceeds_allocation_cap() {
     let options = ExecutionOptions::default()
@@ -2249,3 +3151,201 @@ fn pad_then_drop_ops(num_pads: usize) -> Vec<Operation> {
     ops.extend(vec![Operation::Drop; num_pads]);
     ops
 }
+
+/// A host which serves a single library but refuses to resolve one of its procedures.
+struct DenyingHost {
+    library: LoadedMastForest,
+    denied_digest: Word,
+}
+
+impl BaseHost for DenyingHost {
+    fn get_label_and_source_file(
+        &self,
+        _location: &Location,
+    ) -> (SourceSpan, Option<Arc<SourceFile>>) {
+        (SourceSpan::UNKNOWN, None)
+    }
+}
+
+impl SyncHost for DenyingHost {
+    fn get_mast_forest(&self, node_digest: &Word) -> Option<LoadedMastForest> {
+        self.library.mast_forest().find_procedure_root(*node_digest)?;
+        Some(self.library.clone())
+    }
+
+    fn on_event(&mut self, _process: &ProcessorState) -> Result<Vec<AdviceMutation>, EventError> {
+        Ok(Vec::new())
+    }
+
+    fn on_external_resolution(&mut self, node_digest: Word) -> Result<(), EventError> {
+        if node_digest == self.denied_digest {
+            return Err(format!("procedure {node_digest} is not allow-listed").into());
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn host_can_deny_external_node_resolution() {
+    let mut library = MastForest::new();
+    let allowed_id = BasicBlockNodeBuilder::new(vec![Operation::Swap])
+        .add_to_forest(&mut library)
+        .unwrap();
+    let denied_id = BasicBlockNodeBuilder::new(vec![Operation::Add])
+        .add_to_forest(&mut library)
+        .unwrap();
+    library.make_root(allowed_id);
+    library.make_root(denied_id);
+    let allowed_digest = library[allowed_id].digest();
+    let denied_digest = library[denied_id].digest();
+
+    let mut host = DenyingHost {
+        library: LoadedMastForest::new(Arc::new(library)),
+        denied_digest,
+    };
+
+    let (program, _) = external_program_for_digest(allowed_digest);
+    FastProcessor::new(StackInputs::default())
+        .execute_sync(&program, &mut host)
+        .expect("resolution of an allowed procedure should succeed");
+
+    let (program, _) = external_program_for_digest(denied_digest);
+    let err = FastProcessor::new(StackInputs::default())
+        .execute_sync(&program, &mut host)
+        .unwrap_err();
+    assert_matches!(
+        err,
+        ExecutionError::ExternalResolutionDenied { root_digest, .. } if root_digest == denied_digest
+    );
+}
+
+#[test]
+fn host_can_load_mast_forests_on_demand() {
+    let mut library = MastForest::new();
+    let loaded_id = BasicBlockNodeBuilder::new(vec![Operation::Swap])
+        .add_to_forest(&mut library)
+        .unwrap();
+    library.make_root(loaded_id);
+    let loaded_digest = library[loaded_id].digest();
+    let library = LoadedMastForest::new(Arc::new(library));
+    let unavailable_digest = Word::from([1_u32, 2, 3, 4]);
+    let unknown_digest = Word::from([5_u32, 6, 7, 8]);
+
+    let mut host = DefaultHost::default().with_mast_forest_loader(Arc::new(
+        move |digest: &Word| -> Result<Option<LoadedMastForest>, MastForestLoadError> {
+            if *digest == loaded_digest {
+                Ok(Some(library.clone()))
+            } else if *digest == unavailable_digest {
+                Err("library is unavailable".into())
+            } else {
+                Ok(None)
+            }
+        },
+    ));
+
+    let (program, _) = external_program_for_digest(loaded_digest);
+    FastProcessor::new(StackInputs::default())
+        .execute_sync(&program, &mut host)
+        .expect("procedure from a lazily loaded forest should execute");
+
+    let (program, _) = external_program_for_digest(unavailable_digest);
+    let err = FastProcessor::new(StackInputs::default())
+        .execute_sync(&program, &mut host)
+        .unwrap_err();
+    assert_matches!(
+        err,
+        ExecutionError::MastForestLoadFailed { root_digest, .. } if root_digest == unavailable_digest
+    );
+
+    let (program, _) = external_program_for_digest(unknown_digest);
+    let err = FastProcessor::new(StackInputs::default())
+        .execute_sync(&program, &mut host)
+        .unwrap_err();
+    assert_matches!(
+        err,
+        ExecutionError::ProcedureNotFound { root_digest, .. } if root_digest == unknown_digest
+    );
+}
+
+/// A host which serves a single library and counts how many times it was asked for a forest.
+struct CountingLibraryHost {
+    library: LoadedMastForest,
+    num_lookups: core::cell::Cell<usize>,
+}
+
+impl BaseHost for CountingLibraryHost {
+    fn get_label_and_source_file(
+        &self,
+        _location: &Location,
+    ) -> (SourceSpan, Option<Arc<SourceFile>>) {
+        (SourceSpan::UNKNOWN, None)
+    }
+}
+
+impl SyncHost for CountingLibraryHost {
+    fn get_mast_forest(&self, node_digest: &Word) -> Option<LoadedMastForest> {
+        self.num_lookups.set(self.num_lookups.get() + 1);
+        self.library.mast_forest().find_procedure_root(*node_digest)?;
+        Some(self.library.clone())
+    }
+
+    fn on_event(&mut self, _process: &ProcessorState) -> Result<Vec<AdviceMutation>, EventError> {
+        Ok(Vec::new())
+    }
+}
+
+#[test]
+fn caching_host_reuses_resolutions_across_executions() {
+    let mut library = MastForest::new();
+    let swap_id = BasicBlockNodeBuilder::new(vec![Operation::Swap])
+        .add_to_forest(&mut library)
+        .unwrap();
+    let add_id = BasicBlockNodeBuilder::new(vec![Operation::Add])
+        .add_to_forest(&mut library)
+        .unwrap();
+    library.make_root(swap_id);
+    library.make_root(add_id);
+    let swap_digest = library[swap_id].digest();
+    let add_digest = library[add_id].digest();
+    let library = Arc::new(library);
+
+    let mut host = CachingHost::new(CountingLibraryHost {
+        library: LoadedMastForest::new(library.clone()),
+        num_lookups: Default::default(),
+    });
+
+    // the first resolution caches every procedure of the library, so that later executions,
+    // including ones calling other procedures of the library, are served from the cache
+    let (swap_program, _) = external_program_for_digest(swap_digest);
+    let (add_program, _) = external_program_for_digest(add_digest);
+    for program in [&swap_program, &add_program, &swap_program] {
+        FastProcessor::new(StackInputs::default())
+            .execute_sync(program, &mut host)
+            .unwrap();
+    }
+    assert_eq!(host.host().num_lookups.get(), 1);
+    assert_eq!(host.num_cached_procedures(), 2);
+
+    // unknown procedures are not cached
+    let (unknown_program, _) = external_program_for_digest(Word::from([1_u32, 2, 3, 4]));
+    for _ in 0..2 {
+        FastProcessor::new(StackInputs::default())
+            .execute_sync(&unknown_program, &mut host)
+            .unwrap_err();
+    }
+    assert_eq!(host.host().num_lookups.get(), 3);
+
+    assert!(host.invalidate(&swap_digest));
+    assert!(!host.invalidate(&swap_digest));
+    FastProcessor::new(StackInputs::default())
+        .execute_sync(&add_program, &mut host)
+        .unwrap();
+    assert_eq!(host.host().num_lookups.get(), 3);
+    FastProcessor::new(StackInputs::default())
+        .execute_sync(&swap_program, &mut host)
+        .unwrap();
+    assert_eq!(host.host().num_lookups.get(), 4);
+
+    host.invalidate_forest(&library);
+    assert_eq!(host.num_cached_procedures(), 0);
+}
@@ -37,6 +37,8 @@ pub enum AdviceError {
         "Merkle store node budget exceeded: adding {added} nodes to the current {current} would exceed the maximum of {max}"
     )]
     MerkleStoreNodeBudgetExceeded { current: usize, added: usize, max: usize },
+    #[error("advice map read limit of {limit} exceeded")]
+    AdviceReadLimitExceeded { limit: usize },
     #[error("failed to initialize deferred state with the built-in precompile registry")]
     DeferredStateInitializationFailed(#[source] PrecompileError),
     #[error(
@@ -3,16 +3,17 @@ use alloc::{
     vec::Vec,
 };
 
+#[cfg(test)]
+use miden_core::crypto::hash::Blake3_256;
 use miden_core::{
     Felt, WORD_SIZE, Word,
-    advice::{AdviceInputs, AdviceMap},
+    advice::{AdviceInputs, AdviceMap, AdviceProviderSnapshot},
     crypto::{
         hash::Poseidon2,
         merkle::{InnerNodeInfo, MerkleError, MerklePath, MerkleStore, NodeIndex},
     },
+    serde::{ByteWriter, Serializable},
 };
-#[cfg(test)]
-use miden_core::{crypto::hash::Blake3_256, serde::Serializable};
 
 mod errors;
 pub use errors::AdviceError;
@@ -103,6 +104,11 @@ pub struct AdviceProvider {
     store: MerkleStore,
     merkle_store_node_count: usize,
     max_merkle_store_nodes: usize,
+    map_read_count: usize,
+    max_map_reads: Option<usize>,
+    /// Whether `adv.has_mapkey` fails for a missing key, as reading the value of the key would,
+    /// instead of reporting the key as absent.
+    strict_map_key_checks: bool,
 }
 
 impl Default for AdviceProvider {
@@ -124,6 +130,21 @@ impl AdviceProvider {
         Ok(provider)
     }
 
+    /// Creates a new advice provider restoring the state captured in `snapshot`, including the
+    /// number of advice map reads performed so far.
+    ///
+    /// As with [`Self::new`], the advice map limits in `options` are enforced while loading the
+    /// snapshot.
+    pub fn from_snapshot(
+        snapshot: AdviceProviderSnapshot,
+        options: &ExecutionOptions,
+    ) -> Result<Self, AdviceError> {
+        let map_read_count = snapshot.map_read_count;
+        let mut provider = Self::new(AdviceInputs::from_provider_snapshot(snapshot), options)?;
+        provider.map_read_count = map_read_count;
+        Ok(provider)
+    }
+
     fn empty(options: &ExecutionOptions) -> Self {
         let store = MerkleStore::default();
         let merkle_store_node_count = store.num_internal_nodes();
@@ -136,6 +157,9 @@ impl AdviceProvider {
             store,
             merkle_store_node_count,
             max_merkle_store_nodes: options.max_merkle_store_nodes(),
+            map_read_count: 0,
+            max_map_reads: options.max_adv_map_reads(),
+            strict_map_key_checks: false,
         }
     }
 
@@ -166,11 +190,11 @@ impl AdviceProvider {
         self.max_map_value_size = options.max_adv_map_value_size();
         self.max_map_elements = options.max_adv_map_elements();
         self.max_merkle_store_nodes = options.max_merkle_store_nodes();
+        self.max_map_reads = options.max_adv_map_reads();
         Ok(())
     }
 
-    #[cfg(test)]
-    #[expect(dead_code)]
+    /// Returns the Merkle store of the advice provider.
     pub(crate) fn merkle_store(&self) -> &MerkleStore {
         &self.store
     }
@@ -378,6 +402,16 @@ impl AdviceProvider {
         self.stack.iter().copied().collect()
     }
 
+    /// Returns the number of elements on the advice stack.
+    pub(crate) fn stack_len(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Returns the top `count` elements of the advice stack, ordered from the top.
+    pub(crate) fn stack_top(&self, count: usize) -> Vec<Felt> {
+        self.stack.iter().take(count).copied().collect()
+    }
+
     /// Extends the stack with the given elements.
     pub fn extend_stack<I>(&mut self, iter: I) -> Result<(), AdviceError>
     where
@@ -394,11 +428,52 @@ impl AdviceProvider {
     // ADVICE MAP
     // --------------------------------------------------------------------------------------------
 
+    /// Records a read of the advice map performed by the executing program.
+    ///
+    /// # Errors
+    /// Returns an error if the read would exceed the maximum number of advice map reads configured
+    /// via [`ExecutionOptions::with_max_advice_reads`].
+    pub(crate) fn record_map_read(&mut self) -> Result<(), AdviceError> {
+        if let Some(limit) = self.max_map_reads
+            && self.map_read_count >= limit
+        {
+            return Err(AdviceError::AdviceReadLimitExceeded { limit });
+        }
+        self.map_read_count += 1;
+        Ok(())
+    }
+
+    /// Returns the number of advice map reads recorded so far.
+    pub(crate) fn map_read_count(&self) -> usize {
+        self.map_read_count
+    }
+
+    /// Sets the number of advice map reads recorded so far, e.g. when resuming an execution which
+    /// already performed some reads.
+    pub(crate) fn set_map_read_count(&mut self, map_read_count: usize) {
+        self.map_read_count = map_read_count;
+    }
+
     /// Returns true if the key has a corresponding value in the map.
     pub fn contains_map_key(&self, key: &Word) -> bool {
         self.map.contains_key(key)
     }
 
+    /// Makes `adv.has_mapkey` fail with [`AdviceError::MapKeyNotFound`] for keys which are not in
+    /// the map, so that checking for a key is treated like reading its value.
+    ///
+    /// This is used by [`crate::probe_dependencies`], as a program checking for a missing key may
+    /// behave differently once the key is provided.
+    pub(crate) fn enable_strict_map_key_checks(&mut self) {
+        self.strict_map_key_checks = true;
+    }
+
+    /// Returns true if `adv.has_mapkey` fails for keys which are not in the map, see
+    /// [`Self::enable_strict_map_key_checks`].
+    pub(crate) fn has_strict_map_key_checks(&self) -> bool {
+        self.strict_map_key_checks
+    }
+
     /// Returns a reference to the value(s) associated with the specified key in the advice map.
     pub fn get_mapped_values(&self, key: &Word) -> Option<&[Felt]> {
         self.map.get(key).map(AsRef::as_ref)
@@ -696,6 +771,32 @@ impl AdviceProvider {
         Ok(())
     }
 
+    /// Returns statistics about the size of the Merkle store.
+    pub fn merkle_store_stats(&self) -> MerkleStoreStats {
+        MerkleStoreStats {
+            num_nodes: self.merkle_store_node_count,
+            max_nodes: self.max_merkle_store_nodes,
+        }
+    }
+
+    /// Removes from the Merkle store all nodes which are not descendants of `reachable_roots`,
+    /// returning the number of removed nodes.
+    ///
+    /// Every `mtree_set` leaves the nodes of the previous tree version in the store, so hosts
+    /// reusing the advice state across executions can call this to bound its growth. Roots which
+    /// are not in the store are ignored.
+    pub fn compact_merkle_store(
+        &mut self,
+        reachable_roots: impl IntoIterator<Item = Word>,
+    ) -> usize {
+        self.store = self.store.subset(reachable_roots.into_iter());
+
+        let num_nodes = self.store.num_internal_nodes();
+        let num_removed = self.merkle_store_node_count - num_nodes;
+        self.merkle_store_node_count = num_nodes;
+        num_removed
+    }
+
     // MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -706,6 +807,19 @@ impl AdviceProvider {
         self.extend_map(&inputs.map)
     }
 
+    /// Returns a snapshot of the advice stack, map, Merkle store, and number of advice map reads.
+    ///
+    /// The snapshot can be serialized and later reloaded via [`Self::from_snapshot()`] to resume
+    /// from the same advice state.
+    pub fn snapshot(&self) -> AdviceProviderSnapshot {
+        AdviceProviderSnapshot {
+            stack: self.stack(),
+            map: self.map.clone(),
+            store: self.store.clone(),
+            map_read_count: self.map_read_count,
+        }
+    }
+
     /// Consumes `self` and return its parts (stack, map, store).
     ///
     /// The returned stack vector is ordered from top (index 0) to bottom.
@@ -714,6 +828,37 @@ impl AdviceProvider {
     }
 }
 
+// MERKLE STORE STATS
+// ================================================================================================
+
+/// Statistics about the size of the Merkle store of an [`AdviceProvider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MerkleStoreStats {
+    /// The number of internal nodes currently in the store, including the pre-populated roots of
+    /// empty subtrees.
+    pub num_nodes: usize,
+    /// The maximum number of internal nodes the store may hold, as set by
+    /// [`ExecutionOptions::max_merkle_store_nodes()`].
+    pub max_nodes: usize,
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+/// Serializes the advice stack, map, Merkle store, and number of advice map reads in the
+/// [`AdviceProviderSnapshot`] format.
+///
+/// Execution limits are not serialized, and are instead set by the [`ExecutionOptions`] of the
+/// execution the snapshot is reloaded into.
+impl Serializable for AdviceProvider {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.stack().write_into(target);
+        self.map.write_into(target);
+        self.store.write_into(target);
+        target.write_usize(self.map_read_count);
+    }
+}
+
 // ADVICE PROVIDER INTERFACE IMPLEMENTATION
 // ================================================================================================
 
@@ -759,12 +904,15 @@ impl AdviceProviderInterface for AdviceProvider {
 mod tests {
     use alloc::{collections::BTreeMap, vec, vec::Vec};
 
-    use miden_core::WORD_SIZE;
+    use miden_core::{
+        WORD_SIZE,
+        serde::{Deserializable, Serializable},
+    };
 
     use super::AdviceProvider;
     use crate::{
         AdviceInputs, ExecutionOptions, Felt, Word,
-        advice::{AdviceError, AdviceMap},
+        advice::{AdviceError, AdviceMap, AdviceProviderSnapshot},
         crypto::merkle::{MerkleStore, MerkleTree},
     };
 
@@ -810,6 +958,67 @@ mod tests {
         assert_eq!(provider_a.fingerprint(), provider_b.fingerprint());
     }
 
+    #[test]
+    fn snapshot_round_trips_through_advice_inputs() {
+        let tree =
+            MerkleTree::new([make_leaf(1), make_leaf(5), make_leaf(9), make_leaf(13)]).unwrap();
+        let mut provider = AdviceProvider::new(
+            AdviceInputs::default().with_stack([Felt::ONE, Felt::new_unchecked(2)]),
+            &Default::default(),
+        )
+        .unwrap();
+
+        // mutate the provider the way a program execution would
+        provider.pop_stack().unwrap();
+        provider.push_stack_word(&make_leaf(20)).unwrap();
+        provider.insert_into_map(make_leaf(30), vec![Felt::new_unchecked(7)]).unwrap();
+        provider.extend_merkle_store(tree.inner_nodes()).unwrap();
+        provider.record_map_read().unwrap();
+
+        let bytes = provider.to_bytes();
+        assert_eq!(bytes, provider.snapshot().to_bytes());
+
+        let snapshot = AdviceProviderSnapshot::read_from_bytes(&bytes).unwrap();
+        assert_eq!(snapshot, provider.snapshot());
+
+        let reloaded = AdviceProvider::from_snapshot(snapshot, &Default::default()).unwrap();
+        assert_eq!(reloaded, provider);
+        assert!(reloaded.has_merkle_root(tree.root()));
+    }
+
+    #[test]
+    fn compact_merkle_store_removes_unreachable_nodes() {
+        let tree =
+            MerkleTree::new([make_leaf(1), make_leaf(5), make_leaf(9), make_leaf(13)]).unwrap();
+        let mut store = MerkleStore::default();
+        store.extend(tree.inner_nodes());
+        let mut provider = AdviceProvider::new(
+            AdviceInputs::default().with_merkle_store(store),
+            &Default::default(),
+        )
+        .unwrap();
+        let base_node_count = MerkleStore::default().num_internal_nodes();
+        let old_root = tree.root();
+
+        // each update adds the nodes along the path of the new tree version
+        let depth = Felt::new_unchecked(2);
+        let (_, new_root) =
+            provider.update_merkle_node(old_root, depth, Felt::ZERO, make_leaf(40)).unwrap();
+        assert_eq!(provider.merkle_store_stats().num_nodes, base_node_count + 3 + 2);
+
+        // compacting down to the latest version drops the nodes only reachable from the old root
+        assert_eq!(provider.compact_merkle_store([new_root]), 2);
+        let stats = provider.merkle_store_stats();
+        assert_eq!(stats.num_nodes, base_node_count + 3);
+        assert_eq!(stats.max_nodes, ExecutionOptions::default().max_merkle_store_nodes());
+        assert!(provider.has_merkle_root(new_root));
+        assert!(!provider.has_merkle_root(old_root));
+        assert_eq!(
+            provider.get_tree_node(new_root, depth, Felt::new_unchecked(3)).unwrap(),
+            make_leaf(13)
+        );
+    }
+
     #[test]
     fn advice_map_insert_respects_element_budget() {
         let options = ExecutionOptions::default().with_max_adv_map_elements(WORD_SIZE + 1);
@@ -1,11 +1,15 @@
 use alloc::{
+    format,
     string::{String, ToString},
     vec::Vec,
 };
 use core::fmt;
 
+use miden_air::trace::RowIndex;
 use miden_core::Felt;
 
+use crate::{ContextId, MemoryAddress, ProcessorState};
+
 // WRITER IMPLEMENTATIONS
 // ================================================================================================
 
@@ -21,105 +25,312 @@ impl fmt::Write for StdoutWriter {
     }
 }
 
-// SHARED PRINTING HELPERS
+// TREE CONNECTORS
 // ================================================================================================
-//
-// These functions implement the VM's tree-style debug formatting independently of any host or
-// handler, so event-based debugging procedures (e.g. `miden::core::debug`) can reuse it.
 
-/// Writes a stack-like list of elements (operand or advice stack) in the VM's debug format.
+/// The connectors used to draw the items of the VM's tree-style debug output.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TreeConnectors {
+    /// Unicode box-drawing connectors, i.e. `├── ` and `└── `.
+    #[default]
+    Unicode,
+    /// ASCII-only connectors, i.e. `|-- ` and `` `-- ``, for terminals which cannot render
+    /// box-drawing characters.
+    Ascii,
+}
+
+impl TreeConnectors {
+    /// Returns the prefix of all items except the last one.
+    pub const fn branch(&self) -> &'static str {
+        match self {
+            Self::Unicode => "├── ",
+            Self::Ascii => "|-- ",
+        }
+    }
+
+    /// Returns the prefix of the last item.
+    pub const fn last(&self) -> &'static str {
+        match self {
+            Self::Unicode => "└── ",
+            Self::Ascii => "`-- ",
+        }
+    }
+
+    /// Returns the indentation of the children of an item which is not the last one.
+    pub const fn vertical(&self) -> &'static str {
+        match self {
+            Self::Unicode => "│   ",
+            Self::Ascii => "|   ",
+        }
+    }
+}
+
+// VM STATE PRINTER
+// ================================================================================================
+
+/// ANSI escape sequence rendering the following text dimmed.
+const ANSI_DIM: &str = "\x1b[2m";
+
+/// ANSI escape sequence rendering the following text in cyan.
+const ANSI_CYAN: &str = "\x1b[36m";
+
+/// ANSI escape sequence resetting the text style.
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// The theme of the VM's tree-style console output.
 ///
-/// `stack` is ordered top-first. `n` is the number of items to show; `None` shows all of them.
-/// `label` is the human-readable name of the stack (e.g. `"Stack"` or `"Advice stack"`), and
-/// `clk` is the clock cycle reported in the header.
-pub fn write_stack<W: fmt::Write>(
-    writer: &mut W,
-    stack: &[Felt],
-    n: Option<usize>,
-    label: &str,
-    clk: impl fmt::Display,
-) -> fmt::Result {
-    if stack.is_empty() {
-        writeln!(writer, "{label} empty before step {clk}.")?;
-        return Ok(());
+/// The printer selects the connectors drawing the tree, and whether the output is colored with
+/// ANSI escape sequences. When colored, the connectors are dimmed and the item labels (e.g. stack
+/// positions or memory addresses) are highlighted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct VmStatePrinter {
+    connectors: TreeConnectors,
+    color: bool,
+}
+
+impl VmStatePrinter {
+    /// Returns a printer using Unicode connectors and no colors.
+    pub const fn new() -> Self {
+        Self {
+            connectors: TreeConnectors::Unicode,
+            color: false,
+        }
     }
 
-    // Determine how many items to show
-    let num_items = n.unwrap_or(stack.len());
+    /// Sets the connectors used to draw the tree.
+    pub const fn with_connectors(mut self, connectors: TreeConnectors) -> Self {
+        self.connectors = connectors;
+        self
+    }
 
-    // Write header
-    if num_items == 0 {
-        writeln!(writer, "{label} state in interval [0, 0) before step {clk}:")?;
-        return Ok(());
+    /// Sets whether the output is colored with ANSI escape sequences.
+    pub const fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
     }
 
-    let is_partial = num_items < stack.len();
-    if is_partial {
-        writeln!(writer, "{label} state in interval [0, {}] before step {clk}:", num_items - 1)?
-    } else {
-        writeln!(writer, "{label} state before step {clk}:")?
+    /// Returns the connectors used to draw the tree.
+    pub const fn connectors(&self) -> TreeConnectors {
+        self.connectors
     }
 
-    // Build stack items for display
-    let mut stack_items = Vec::new();
-    for (i, element) in stack.iter().enumerate().take(num_items) {
-        stack_items.push((i.to_string(), Some(element.to_string())));
+    /// Returns true if the output is colored with ANSI escape sequences.
+    pub const fn color(&self) -> bool {
+        self.color
     }
-    // Add extra EMPTY slots if requested more than available
-    for i in stack.len()..num_items {
-        stack_items.push((i.to_string(), None));
+
+    /// Returns the prefix of all items except the last one.
+    pub fn branch(&self) -> String {
+        self.dim(self.connectors.branch())
     }
 
-    // Calculate remaining items for partial views
-    let remaining = if num_items < stack.len() {
-        Some(stack.len() - num_items)
-    } else {
-        None
-    };
+    /// Returns the prefix of the last item.
+    pub fn last(&self) -> String {
+        self.dim(self.connectors.last())
+    }
+
+    /// Returns the indentation of the children of an item which is not the last one.
+    pub fn vertical(&self) -> String {
+        self.dim(self.connectors.vertical())
+    }
+
+    /// Writes a stack-like list of elements (operand or advice stack) in the VM's debug format.
+    ///
+    /// `stack` is ordered top-first. `n` is the number of items to show; `None` shows all of them.
+    /// `label` is the human-readable name of the stack (e.g. `"Stack"` or `"Advice stack"`), and
+    /// `clk` is the clock cycle reported in the header.
+    pub fn write_stack<W: fmt::Write>(
+        &self,
+        writer: &mut W,
+        stack: &[Felt],
+        n: Option<usize>,
+        label: &str,
+        clk: impl fmt::Display,
+    ) -> fmt::Result {
+        if stack.is_empty() {
+            writeln!(writer, "{label} empty before step {clk}.")?;
+            return Ok(());
+        }
+
+        // Determine how many items to show
+        let num_items = n.unwrap_or(stack.len());
+
+        // Write header
+        if num_items == 0 {
+            writeln!(writer, "{label} state in interval [0, 0) before step {clk}:")?;
+            return Ok(());
+        }
+
+        let is_partial = num_items < stack.len();
+        if is_partial {
+            writeln!(writer, "{label} state in interval [0, {}] before step {clk}:", num_items - 1)?
+        } else {
+            writeln!(writer, "{label} state before step {clk}:")?
+        }
+
+        // Build stack items for display
+        let mut stack_items = Vec::new();
+        for (i, element) in stack.iter().enumerate().take(num_items) {
+            stack_items.push((i.to_string(), Some(element.to_string())));
+        }
+        // Add extra EMPTY slots if requested more than available
+        for i in stack.len()..num_items {
+            stack_items.push((i.to_string(), None));
+        }
+
+        // Calculate remaining items for partial views
+        let remaining = if num_items < stack.len() {
+            Some(stack.len() - num_items)
+        } else {
+            None
+        };
+
+        self.write_interval(writer, stack_items, remaining)
+    }
+
+    /// Writes a generic interval with proper alignment and optional remaining count.
+    ///
+    /// Takes a vector of (address_string, optional_value_string) pairs where:
+    /// - address_string: The address as a string (not pre-padded)
+    /// - optional_value_string: Some(value) or None (prints "EMPTY")
+    /// - remaining: Optional count of remaining items to show as "(N more items)"
+    pub fn write_interval<W: fmt::Write>(
+        &self,
+        writer: &mut W,
+        items: Vec<(String, Option<String>)>,
+        remaining: Option<usize>,
+    ) -> fmt::Result {
+        // Find the maximum address width for proper alignment
+        let max_addr_width = items.iter().map(|(addr, _)| addr.len()).max().unwrap_or(0);
+
+        // Collect formatted items
+        let mut formatted_items: Vec<String> = items
+            .into_iter()
+            .map(|(addr, value_opt)| {
+                let addr = self.highlight(&format!("{addr:>max_addr_width$}"));
+                let value_string = format_value(value_opt);
+                format!("{addr}: {value_string}")
+            })
+            .collect();
+
+        // Add remaining count if specified
+        if let Some(count) = remaining {
+            formatted_items.push(format!("({count} more items)"));
+        }
+
+        // Prints a list of items with proper tree-style indentation.
+        // All items except the last are prefixed with the branch connector, and the last item with
+        // the last connector.
+        if let Some((last, front)) = formatted_items.split_last() {
+            let branch = self.branch();
+            for item in front {
+                writeln!(writer, "{branch}{item}")?;
+            }
+            writeln!(writer, "{}{last}", self.last())?;
+        }
 
-    write_interval(writer, stack_items, remaining)
+        Ok(())
+    }
+
+    fn dim(&self, text: &str) -> String {
+        self.styled(ANSI_DIM, text)
+    }
+
+    fn highlight(&self, text: &str) -> String {
+        self.styled(ANSI_CYAN, text)
+    }
+
+    fn styled(&self, style: &str, text: &str) -> String {
+        if self.color {
+            format!("{style}{text}{ANSI_RESET}")
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+// VM STATE
+// ================================================================================================
+
+/// The state of the VM at some clock cycle, as shown in the VM's console output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VmState {
+    /// The clock cycle at which the state was captured.
+    pub clk: RowIndex,
+    /// The execution context at which the state was captured.
+    pub ctx: ContextId,
+    /// The operand stack, ordered top-first.
+    pub stack: Vec<Felt>,
+    /// The elements of the memory of `ctx` which were accessed at least once, ordered by address.
+    pub memory: Vec<(MemoryAddress, Felt)>,
+}
+
+impl VmState {
+    /// Captures the state of the VM described by `state`.
+    pub fn from_processor_state(state: &ProcessorState) -> Self {
+        let ctx = state.ctx();
+        Self {
+            clk: state.clock(),
+            ctx,
+            stack: state.get_stack_state(),
+            memory: state.get_mem_state(ctx),
+        }
+    }
+
+    /// Renders the operand stack and memory of this state with `printer`.
+    pub fn render(&self, printer: &VmStatePrinter) -> String {
+        let mut out = String::new();
+        self.write_into(&mut out, printer).expect("writing to a string cannot fail");
+        out
+    }
+
+    fn write_into<W: fmt::Write>(&self, writer: &mut W, printer: &VmStatePrinter) -> fmt::Result {
+        let Self { clk, ctx, stack, memory } = self;
+        printer.write_stack(writer, stack, None, "Stack", clk)?;
+
+        if memory.is_empty() {
+            return writeln!(writer, "Memory of context {ctx} empty before step {clk}.");
+        }
+        writeln!(writer, "Memory state before step {clk} for context {ctx}:")?;
+        let items = memory
+            .iter()
+            .map(|(addr, value)| (format!("{addr:#010x}"), Some(value.to_string())))
+            .collect();
+        printer.write_interval(writer, items, None)
+    }
+}
+
+// SHARED PRINTING HELPERS
+// ================================================================================================
+//
+// These functions implement the VM's tree-style debug formatting independently of any host or
+// handler, so event-based debugging procedures (e.g. `miden::core::debug`) can reuse it. They use
+// the default [`VmStatePrinter`].
+
+/// Writes a stack-like list of elements in the VM's debug format using the default
+/// [`VmStatePrinter`].
+///
+/// See [`VmStatePrinter::write_stack`] for a description of the arguments.
+pub fn write_stack<W: fmt::Write>(
+    writer: &mut W,
+    stack: &[Felt],
+    n: Option<usize>,
+    label: &str,
+    clk: impl fmt::Display,
+) -> fmt::Result {
+    VmStatePrinter::default().write_stack(writer, stack, n, label, clk)
 }
 
-/// Writes a generic interval with proper alignment and optional remaining count.
+/// Writes a generic interval with proper alignment and optional remaining count using the
+/// default [`VmStatePrinter`].
 ///
-/// Takes a vector of (address_string, optional_value_string) pairs where:
-/// - address_string: The address as a string (not pre-padded)
-/// - optional_value_string: Some(value) or None (prints "EMPTY")
-/// - remaining: Optional count of remaining items to show as "(N more items)"
+/// See [`VmStatePrinter::write_interval`] for a description of the arguments.
 pub fn write_interval<W: fmt::Write>(
     writer: &mut W,
     items: Vec<(String, Option<String>)>,
     remaining: Option<usize>,
 ) -> fmt::Result {
-    // Find the maximum address width for proper alignment
-    let max_addr_width = items.iter().map(|(addr, _)| addr.len()).max().unwrap_or(0);
-
-    // Collect formatted items
-    let mut formatted_items: Vec<String> = items
-        .into_iter()
-        .map(|(addr, value_opt)| {
-            let value_string = format_value(value_opt);
-            format!("{addr:>max_addr_width$}: {value_string}")
-        })
-        .collect();
-
-    // Add remaining count if specified
-    if let Some(count) = remaining {
-        formatted_items.push(format!("({count} more items)"));
-    }
-
-    // Prints a list of items with proper tree-style indentation.
-    // All items except the last are prefixed with "├── ", and the last item with "└── ".
-    if let Some((last, front)) = formatted_items.split_last() {
-        // Print all items except the last with "├── " prefix
-        for item in front {
-            writeln!(writer, "├── {item}")?;
-        }
-        // Print the last item with "└── " prefix
-        writeln!(writer, "└── {last}")?;
-    }
-
-    Ok(())
+    VmStatePrinter::default().write_interval(writer, items, remaining)
 }
 
 // HELPER FUNCTIONS
@@ -139,7 +350,10 @@ mod tests {
 
     use miden_core::Felt;
 
-    use super::{format_value, write_interval, write_stack};
+    use super::{
+        TreeConnectors, VmState, VmStatePrinter, format_value, write_interval, write_stack,
+    };
+    use crate::ContextId;
 
     #[test]
     fn write_stack_full_uses_tree_style() {
@@ -160,6 +374,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn vm_state_renders_ascii_and_unicode_connectors() {
+        let state = VmState {
+            clk: 4_u32.into(),
+            ctx: ContextId::root(),
+            stack: vec![Felt::new_unchecked(9), Felt::new_unchecked(8)],
+            memory: vec![(8_u32.into(), Felt::new_unchecked(7))],
+        };
+
+        let unicode = state.render(&VmStatePrinter::new());
+        assert_eq!(
+            unicode,
+            "Stack state before step 4:\n├── 0: 9\n└── 1: 8\n\
+             Memory state before step 4 for context 0:\n└── 0x00000008: 7\n"
+        );
+
+        let ascii = state.render(&VmStatePrinter::new().with_connectors(TreeConnectors::Ascii));
+        assert_eq!(
+            ascii,
+            "Stack state before step 4:\n|-- 0: 9\n`-- 1: 8\n\
+             Memory state before step 4 for context 0:\n`-- 0x00000008: 7\n"
+        );
+        assert!(ascii.is_ascii());
+    }
+
+    #[test]
+    fn vm_state_printer_colors_connectors_and_labels() {
+        let printer = VmStatePrinter::new().with_connectors(TreeConnectors::Ascii).with_color(true);
+        let mut out = String::new();
+        printer
+            .write_stack(&mut out, &[Felt::new_unchecked(9)], None, "Stack", 0u32)
+            .unwrap();
+        assert_eq!(out, "Stack state before step 0:\n\x1b[2m`-- \x1b[0m\x1b[36m0\x1b[0m: 9\n");
+    }
+
     #[test]
     fn write_stack_zero_count_does_not_underflow() {
         let mut out = String::new();
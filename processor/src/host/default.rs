@@ -1,17 +1,17 @@
 use alloc::{sync::Arc, vec::Vec};
 
 use miden_core::{
-    Word,
+    Felt, Word,
     events::{EventId, EventName},
     mast::MastForest,
 };
 use miden_debug_types::{DefaultSourceManager, Location, SourceFile, SourceManager, SourceSpan};
-use miden_mast_package::{PackageDebugInfoError, debug_info::PackageDebugInfo};
+use miden_mast_package::{ErrorTable, PackageDebugInfoError, debug_info::PackageDebugInfo};
 
-use super::handlers::{EventError, EventHandler, EventHandlerRegistry};
+use super::handlers::{EventError, EventHandler, EventHandlerRegistry, EventMiddleware};
 use crate::{
-    BaseHost, ExecutionError, LoadedMastForest, MastForestStore, MemMastForestStore,
-    ProcessorState, SyncHost, advice::AdviceMutation,
+    BaseHost, ExecutionError, LoadedMastForest, MastForestLoadError, MastForestLoader,
+    MastForestStore, MemMastForestStore, ProcessorState, SyncHost, advice::AdviceMutation,
 };
 
 // DEFAULT HOST IMPLEMENTATION
@@ -23,6 +23,7 @@ pub struct DefaultHost<S: SourceManager = DefaultSourceManager> {
     store: MemMastForestStore,
     event_handlers: EventHandlerRegistry,
     source_manager: Arc<S>,
+    error_table: ErrorTable,
 }
 
 impl Default for DefaultHost {
@@ -31,6 +32,7 @@ impl Default for DefaultHost {
             store: MemMastForestStore::default(),
             event_handlers: EventHandlerRegistry::default(),
             source_manager: Arc::new(DefaultSourceManager::default()),
+            error_table: ErrorTable::default(),
         }
     }
 }
@@ -49,6 +51,7 @@ where
             store: self.store,
             event_handlers: self.event_handlers,
             source_manager,
+            error_table: self.error_table,
         }
     }
 
@@ -73,6 +76,32 @@ where
         Ok(self)
     }
 
+    /// Sets the [`MastForestLoader`] used to fetch the MAST forests of procedures which are not
+    /// part of any loaded library on demand.
+    pub fn set_mast_forest_loader(&mut self, loader: Arc<dyn MastForestLoader>) {
+        self.store.set_loader(loader);
+    }
+
+    /// Adds a [`MastForestLoader`] to this host; see [`Self::set_mast_forest_loader`].
+    pub fn with_mast_forest_loader(mut self, loader: Arc<dyn MastForestLoader>) -> Self {
+        self.set_mast_forest_loader(loader);
+        self
+    }
+
+    /// Loads an [`ErrorTable`], so that the messages of the assertion errors it describes are
+    /// reported when they are raised by code whose debug information does not provide them.
+    ///
+    /// Tables loaded by repeated calls are merged.
+    pub fn load_error_table(&mut self, error_table: ErrorTable) {
+        self.error_table.merge(error_table);
+    }
+
+    /// Adds an [`ErrorTable`] to this host; see [`Self::load_error_table`].
+    pub fn with_error_table(mut self, error_table: ErrorTable) -> Self {
+        self.load_error_table(error_table);
+        self
+    }
+
     /// Registers a single [`EventHandler`] into this host.
     ///
     /// The handler can be either a closure or a free function with signature
@@ -85,6 +114,21 @@ where
         self.event_handlers.register(event, handler)
     }
 
+    /// Adds an [`EventMiddleware`] which wraps every event handled by this host.
+    ///
+    /// The middleware can be either a closure or a free function with signature
+    /// `fn(&EventName, &ProcessorState, EventNext) -> Result<Vec<AdviceMutation>, EventError>`.
+    /// Middleware runs in the order it was added, with the first one being the outermost.
+    pub fn add_event_middleware(&mut self, middleware: Arc<dyn EventMiddleware>) {
+        self.event_handlers.add_middleware(middleware);
+    }
+
+    /// Adds an [`EventMiddleware`] to this host; see [`Self::add_event_middleware`].
+    pub fn with_event_middleware(mut self, middleware: Arc<dyn EventMiddleware>) -> Self {
+        self.add_event_middleware(middleware);
+        self
+    }
+
     /// Un-registers a handler with the given id, returning a flag indicating whether a handler
     /// was previously registered with this id.
     pub fn unregister_handler(&mut self, id: EventId) -> bool {
@@ -117,6 +161,10 @@ where
     fn resolve_event(&self, event_id: EventId) -> Option<&EventName> {
         self.event_handlers.resolve_event(event_id)
     }
+
+    fn resolve_error_message(&self, err_code: Felt) -> Option<Arc<str>> {
+        self.error_table.message(err_code)
+    }
 }
 
 impl<S> SyncHost for DefaultHost<S>
@@ -127,6 +175,13 @@ where
         self.store.get(node_digest)
     }
 
+    fn try_get_mast_forest(
+        &self,
+        node_digest: &Word,
+    ) -> Result<Option<LoadedMastForest>, MastForestLoadError> {
+        self.store.try_get(node_digest)
+    }
+
     fn on_event(
         &mut self,
         process: &ProcessorState<'_>,
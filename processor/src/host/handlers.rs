@@ -46,6 +46,74 @@ impl EventHandler for NoopEventHandler {
     }
 }
 
+// EVENT MIDDLEWARE
+// ================================================================================================
+
+/// An [`EventMiddleware`] wraps the invocation of every registered [`EventHandler`].
+///
+/// Middleware can be used to implement cross-cutting concerns such as logging, metrics,
+/// rate-limiting, or allow/deny lists without modifying individual handlers. A middleware may
+/// inspect the event before and after calling [`EventNext::run`], alter the resulting advice
+/// mutations, or short-circuit the chain by returning without calling `next` at all.
+pub trait EventMiddleware: Send + Sync + 'static {
+    /// Handles the event named `event`, delegating to the rest of the chain via `next`.
+    fn on_event(
+        &self,
+        event: &EventName,
+        process: &ProcessorState,
+        next: EventNext<'_>,
+    ) -> Result<Vec<AdviceMutation>, EventError>;
+}
+
+/// Default implementation for both free functions and closures with signature
+/// `fn(&EventName, &ProcessorState, EventNext) -> Result<Vec<AdviceMutation>, EventError>`
+impl<F> EventMiddleware for F
+where
+    F: for<'a, 'b> Fn(
+            &'a EventName,
+            &'a ProcessorState,
+            EventNext<'b>,
+        ) -> Result<Vec<AdviceMutation>, EventError>
+        + Send
+        + Sync
+        + 'static,
+{
+    fn on_event(
+        &self,
+        event: &EventName,
+        process: &ProcessorState,
+        next: EventNext<'_>,
+    ) -> Result<Vec<AdviceMutation>, EventError> {
+        self(event, process, next)
+    }
+}
+
+/// The remainder of an event middleware chain, ending with the registered [`EventHandler`].
+pub struct EventNext<'a> {
+    event: &'a EventName,
+    middleware: &'a [Arc<dyn EventMiddleware>],
+    handler: &'a dyn EventHandler,
+}
+
+impl EventNext<'_> {
+    /// Invokes the next middleware in the chain or, once all middleware has run, the event
+    /// handler itself.
+    pub fn run(self, process: &ProcessorState) -> Result<Vec<AdviceMutation>, EventError> {
+        match self.middleware.split_first() {
+            Some((middleware, rest)) => middleware.on_event(
+                self.event,
+                process,
+                EventNext {
+                    event: self.event,
+                    middleware: rest,
+                    handler: self.handler,
+                },
+            ),
+            None => self.handler.on_event(process),
+        }
+    }
+}
+
 // EVENT ERROR
 // ================================================================================================
 
@@ -102,11 +170,15 @@ pub type EventError = Box<dyn Error + Send + Sync + 'static>;
 #[derive(Default)]
 pub struct EventHandlerRegistry {
     handlers: BTreeMap<EventId, (EventName, Arc<dyn EventHandler>)>,
+    middleware: Vec<Arc<dyn EventMiddleware>>,
 }
 
 impl EventHandlerRegistry {
     pub fn new() -> Self {
-        Self { handlers: BTreeMap::new() }
+        Self {
+            handlers: BTreeMap::new(),
+            middleware: Vec::new(),
+        }
     }
 
     /// Registers an [`EventHandler`] with a given event name.
@@ -144,6 +216,14 @@ impl EventHandlerRegistry {
         self.handlers.remove(&id).is_some()
     }
 
+    /// Adds an [`EventMiddleware`] wrapping all registered handlers.
+    ///
+    /// Middleware runs in the order it was added: the first middleware added is the outermost
+    /// one, and is therefore the first to observe an event and the last to observe its result.
+    pub fn add_middleware(&mut self, middleware: Arc<dyn EventMiddleware>) {
+        self.middleware.push(middleware);
+    }
+
     /// Returns the [`EventName`] registered for `id`, if any.
     pub fn resolve_event(&self, id: EventId) -> Option<&EventName> {
         self.handlers.get(&id).map(|(event, _)| event)
@@ -151,6 +231,8 @@ impl EventHandlerRegistry {
 
     /// Handles the event if the registry contains a handler with the same identifier.
     ///
+    /// The handler is invoked through all middleware added via [`Self::add_middleware`].
+    ///
     /// Returns an `Option<_>` indicating whether the event was handled. Returns `None` if the
     /// event was not handled, `Some(mutations)` if it was handled successfully, and propagates
     /// handler errors to the caller.
@@ -159,8 +241,13 @@ impl EventHandlerRegistry {
         id: EventId,
         process: &ProcessorState,
     ) -> Result<Option<Vec<AdviceMutation>>, EventError> {
-        if let Some((_event_name, handler)) = self.handlers.get(&id) {
-            let mutations = handler.on_event(process)?;
+        if let Some((event, handler)) = self.handlers.get(&id) {
+            let next = EventNext {
+                event,
+                middleware: &self.middleware,
+                handler: handler.as_ref(),
+            };
+            let mutations = next.run(process)?;
             return Ok(Some(mutations));
         }
 
@@ -171,6 +258,9 @@ impl EventHandlerRegistry {
 impl Debug for EventHandlerRegistry {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let events: Vec<_> = self.handlers.values().map(|(event, _)| event).collect();
-        f.debug_struct("EventHandlerRegistry").field("handlers", &events).finish()
+        f.debug_struct("EventHandlerRegistry")
+            .field("handlers", &events)
+            .field("num_middleware", &self.middleware.len())
+            .finish()
     }
 }
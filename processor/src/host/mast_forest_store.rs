@@ -1,4 +1,5 @@
-use alloc::{collections::BTreeMap, sync::Arc};
+use alloc::{boxed::Box, collections::BTreeMap, sync::Arc};
+use core::{error::Error, fmt};
 
 use miden_core::{Word, mast::MastForest};
 use miden_mast_package::{PackageDebugInfoError, debug_info::PackageDebugInfo};
@@ -53,15 +54,75 @@ pub trait MastForestStore {
     /// Returns a [`MastForest`] which is guaranteed to contain a procedure with the provided
     /// procedure hash as one of its procedure, if any.
     fn get(&self, procedure_hash: &Word) -> Option<LoadedMastForest>;
+
+    /// Returns a [`MastForest`] which is guaranteed to contain a procedure with the provided
+    /// procedure hash as one of its procedure, if any.
+    ///
+    /// Unlike [`Self::get()`], this distinguishes a procedure which is unknown to the store
+    /// (`Ok(None)`) from a failure to load the forest which contains it (`Err`). The default
+    /// implementation never fails.
+    fn try_get(
+        &self,
+        procedure_hash: &Word,
+    ) -> Result<Option<LoadedMastForest>, MastForestLoadError> {
+        Ok(self.get(procedure_hash))
+    }
+}
+
+// MAST FOREST LOADER
+// ================================================================================================
+
+/// The error returned by a [`MastForestLoader`] which failed to load a [`MastForest`].
+pub type MastForestLoadError = Box<dyn Error + Send + Sync + 'static>;
+
+/// Loads [`MastForest`]s on demand, e.g. from disk or over the network, instead of requiring all
+/// of them to be registered with a [`MemMastForestStore`] upfront.
+///
+/// A loader is consulted only for procedures which are not already in the store, and is
+/// consulted every time such a procedure is requested; loaders which fetch forests from slow
+/// sources are expected to cache them.
+pub trait MastForestLoader: Send + Sync {
+    /// Loads the [`MastForest`] which contains a procedure with the provided procedure hash.
+    ///
+    /// Returns `Ok(None)` if the loader does not know of such a forest, and an error if the
+    /// forest is known but could not be loaded.
+    fn load(&self, procedure_hash: &Word) -> Result<Option<LoadedMastForest>, MastForestLoadError>;
 }
 
+impl<F> MastForestLoader for F
+where
+    F: Fn(&Word) -> Result<Option<LoadedMastForest>, MastForestLoadError> + Send + Sync,
+{
+    fn load(&self, procedure_hash: &Word) -> Result<Option<LoadedMastForest>, MastForestLoadError> {
+        self(procedure_hash)
+    }
+}
+
+// MEMORY MAST FOREST STORE
+// ================================================================================================
+
 /// A simple [`MastForestStore`] where all known [`MastForest`]s are held in memory.
-#[derive(Debug, Default, Clone)]
+///
+/// Procedures which are not in memory can be resolved through an optional [`MastForestLoader`].
+#[derive(Default, Clone)]
 pub struct MemMastForestStore {
     mast_forests: BTreeMap<Word, LoadedMastForest>,
+    loader: Option<Arc<dyn MastForestLoader>>,
 }
 
 impl MemMastForestStore {
+    /// Sets the loader used to resolve procedures which are not in the store.
+    pub fn set_loader(&mut self, loader: Arc<dyn MastForestLoader>) {
+        self.loader = Some(loader);
+    }
+
+    /// Returns this store with the loader used to resolve procedures which are not in the store
+    /// set to `loader`.
+    pub fn with_loader(mut self, loader: Arc<dyn MastForestLoader>) -> Self {
+        self.set_loader(loader);
+        self
+    }
+
     /// Inserts all the procedures of the provided MAST forest in the store.
     pub fn insert(&mut self, mast_forest: Arc<MastForest>) {
         self.insert_loaded(LoadedMastForest::new(mast_forest));
@@ -77,7 +138,33 @@ impl MemMastForestStore {
 }
 
 impl MastForestStore for MemMastForestStore {
+    /// Returns the forest containing the procedure from memory or, failing that, from the loader.
+    ///
+    /// Loader errors are reported as `None`; use [`MastForestStore::try_get()`] to observe them.
     fn get(&self, procedure_hash: &Word) -> Option<LoadedMastForest> {
-        self.mast_forests.get(procedure_hash).cloned()
+        self.try_get(procedure_hash).ok().flatten()
+    }
+
+    fn try_get(
+        &self,
+        procedure_hash: &Word,
+    ) -> Result<Option<LoadedMastForest>, MastForestLoadError> {
+        if let Some(loaded_mast_forest) = self.mast_forests.get(procedure_hash) {
+            return Ok(Some(loaded_mast_forest.clone()));
+        }
+
+        match &self.loader {
+            Some(loader) => loader.load(procedure_hash),
+            None => Ok(None),
+        }
+    }
+}
+
+impl fmt::Debug for MemMastForestStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemMastForestStore")
+            .field("mast_forests", &self.mast_forests)
+            .field("has_loader", &self.loader.is_some())
+            .finish()
     }
 }
@@ -20,14 +20,22 @@ pub mod default;
 pub mod handlers;
 use handlers::EventError;
 
+mod replay;
+pub use replay::{ExecutionReplay, ReplayRecorder};
+
+mod resolution_cache;
+pub use resolution_cache::CachingHost;
+
 mod mast_forest_store;
-pub use mast_forest_store::{LoadedMastForest, MastForestStore, MemMastForestStore};
+pub use mast_forest_store::{
+    LoadedMastForest, MastForestLoadError, MastForestLoader, MastForestStore, MemMastForestStore,
+};
 
 // ADVICE MAP MUTATIONS
 // ================================================================================================
 
 /// Any possible way an event can modify the advice provider.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AdviceMutation {
     ExtendStack { values: Vec<Felt> },
     ExtendMap { other: AdviceMap },
@@ -75,6 +83,16 @@ pub trait BaseHost {
     fn resolve_event(&self, _event_id: EventId) -> Option<&EventName> {
         None
     }
+
+    /// Returns the message of the assertion error with the provided error code, if known.
+    ///
+    /// This is consulted when an assertion fails in code whose package debug information does not
+    /// provide the message, e.g. a program assembled without debug information. Hosts which load
+    /// an [`ErrorTable`](miden_mast_package::ErrorTable) can override this method to report the
+    /// original message. The default implementation returns `None`.
+    fn resolve_error_message(&self, _err_code: Felt) -> Option<Arc<str>> {
+        None
+    }
 }
 
 impl<T: BaseHost + ?Sized> BaseHost for &mut T {
@@ -88,6 +106,10 @@ impl<T: BaseHost + ?Sized> BaseHost for &mut T {
     fn resolve_event(&self, event_id: EventId) -> Option<&EventName> {
         (**self).resolve_event(event_id)
     }
+
+    fn resolve_error_message(&self, err_code: Felt) -> Option<Arc<str>> {
+        (**self).resolve_error_message(err_code)
+    }
 }
 
 /// Defines a synchronous interface by which the VM can interact with the host during execution.
@@ -96,6 +118,19 @@ pub trait SyncHost: BaseHost {
     /// this digest could not be found in this host.
     fn get_mast_forest(&self, node_digest: &Word) -> Option<LoadedMastForest>;
 
+    /// Returns MAST forest corresponding to the specified digest, `Ok(None)` if the MAST forest
+    /// for this digest could not be found in this host, or an error if it was found but could not
+    /// be loaded.
+    ///
+    /// The VM resolves external procedures through this method. The default implementation
+    /// delegates to [`Self::get_mast_forest()`] and never fails.
+    fn try_get_mast_forest(
+        &self,
+        node_digest: &Word,
+    ) -> Result<Option<LoadedMastForest>, MastForestLoadError> {
+        Ok(self.get_mast_forest(node_digest))
+    }
+
     /// Handles the event emitted from the VM and provides advice mutations to be applied to
     /// the advice provider.
     ///
@@ -110,6 +145,16 @@ pub trait SyncHost: BaseHost {
     /// - System events (IDs 0-255) are handled by the VM before calling this method
     fn on_event(&mut self, process: &ProcessorState<'_>)
     -> Result<Vec<AdviceMutation>, EventError>;
+
+    /// Invoked before the VM asks the host for the MAST forest of a procedure which is not part
+    /// of the forest being executed (i.e., when resolving an external node or a dynamic call).
+    ///
+    /// Returning an error denies the resolution and aborts execution. This allows hosts to
+    /// restrict which libraries a program may call into. The default implementation allows all
+    /// resolutions.
+    fn on_external_resolution(&mut self, _node_digest: Word) -> Result<(), EventError> {
+        Ok(())
+    }
 }
 
 /// Defines an async interface by which the VM can interact with the host during execution.
@@ -141,6 +186,36 @@ pub trait Host: BaseHost {
         &mut self,
         process: &ProcessorState<'_>,
     ) -> impl FutureMaybeSend<Result<Vec<AdviceMutation>, EventError>>;
+
+    // PROVIDED METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns MAST forest corresponding to the specified digest, `Ok(None)` if the MAST forest
+    /// for this digest could not be found in this host, or an error if it was found but could not
+    /// be loaded (e.g., because fetching it from disk or over the network failed).
+    ///
+    /// The VM resolves external procedures through this method. The default implementation
+    /// delegates to [`Self::get_mast_forest()`] and never fails.
+    fn try_get_mast_forest(
+        &self,
+        node_digest: &Word,
+    ) -> impl FutureMaybeSend<Result<Option<LoadedMastForest>, MastForestLoadError>> {
+        let loaded = self.get_mast_forest(node_digest);
+        async move { Ok(loaded.await) }
+    }
+
+    /// Invoked before the VM asks the host for the MAST forest of a procedure which is not part
+    /// of the forest being executed (i.e., when resolving an external node or a dynamic call).
+    ///
+    /// Returning an error denies the resolution and aborts execution. This allows hosts to
+    /// restrict which libraries a program may call into. The default implementation allows all
+    /// resolutions.
+    fn on_external_resolution(
+        &mut self,
+        _node_digest: Word,
+    ) -> impl FutureMaybeSend<Result<(), EventError>> {
+        async { Ok(()) }
+    }
 }
 
 impl<T> Host for T
@@ -155,6 +230,14 @@ where
         async move { result }
     }
 
+    fn try_get_mast_forest(
+        &self,
+        node_digest: &Word,
+    ) -> impl FutureMaybeSend<Result<Option<LoadedMastForest>, MastForestLoadError>> {
+        let result = SyncHost::try_get_mast_forest(self, node_digest);
+        async move { result }
+    }
+
     fn on_event(
         &mut self,
         process: &ProcessorState<'_>,
@@ -162,6 +245,14 @@ where
         let result = SyncHost::on_event(self, process);
         async move { result }
     }
+
+    fn on_external_resolution(
+        &mut self,
+        node_digest: Word,
+    ) -> impl FutureMaybeSend<Result<(), EventError>> {
+        let result = SyncHost::on_external_resolution(self, node_digest);
+        async move { result }
+    }
 }
 
 /// Alias for a `Future`
@@ -0,0 +1,441 @@
+//! Recording and replaying of the interactions between a program and its host.
+//!
+//! Everything a program execution depends on besides the program itself comes either from its
+//! inputs or from the host: MAST forests resolved for external procedures, and advice provided by
+//! event handlers (which, together with the advice inputs, determines every value the program pops
+//! from the advice provider). A [`ReplayRecorder`] captures all of these into an
+//! [`ExecutionReplay`], which can later be used to re-run the program exactly as it was executed
+//! without access to the original host.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use core::cell::{Cell, RefCell};
+
+use miden_core::{
+    Felt, Word,
+    advice::AdviceMap,
+    crypto::merkle::InnerNodeInfo,
+    events::{EventId, EventName},
+    mast::MastForest,
+    program::{Program, StackInputs},
+    serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+use miden_debug_types::{Location, SourceFile, SourceSpan};
+
+use super::handlers::EventError;
+use crate::{
+    AdviceInputs, BaseHost, ExecutionError, ExecutionOptions, ExecutionOutput, FastProcessor,
+    LoadedMastForest, MastForestLoadError, ProcessorState, SyncHost, advice::AdviceMutation,
+};
+
+// EXECUTION REPLAY
+// ================================================================================================
+
+/// The inputs of a program execution together with every response the host gave during that
+/// execution, in the order in which they were requested.
+///
+/// A replay is produced by a [`ReplayRecorder`] and can be serialized, e.g. to attach it to a bug
+/// report. [`Self::execute`] re-runs the program against the recorded responses, which makes it
+/// possible to reproduce an execution exactly without the host it originally ran against.
+///
+/// Only the MAST forests of resolved external procedures are recorded; their package debug info is
+/// not, so a replayed execution reports errors without source locations for those procedures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutionReplay {
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+    host_responses: Vec<HostResponse>,
+}
+
+impl ExecutionReplay {
+    /// Returns the stack inputs of the recorded execution.
+    pub fn stack_inputs(&self) -> &StackInputs {
+        &self.stack_inputs
+    }
+
+    /// Returns the advice inputs of the recorded execution.
+    pub fn advice_inputs(&self) -> &AdviceInputs {
+        &self.advice_inputs
+    }
+
+    /// Returns the number of host responses recorded during the execution.
+    pub fn num_host_responses(&self) -> usize {
+        self.host_responses.len()
+    }
+
+    /// Re-executes `program` using the recorded inputs and host responses.
+    ///
+    /// The program is expected to be the one the replay was recorded for. If the execution
+    /// diverges from the recorded one, i.e. it requests something from the host other than what
+    /// was recorded, the request fails: MAST forests are reported as not found, and events and
+    /// external resolutions return an error.
+    pub fn execute(
+        &self,
+        program: &Program,
+        options: ExecutionOptions,
+    ) -> Result<ExecutionOutput, ExecutionError> {
+        let processor =
+            FastProcessor::new_with_options(self.stack_inputs, self.advice_inputs.clone(), options)
+                .map_err(ExecutionError::advice_error_no_context)?;
+        processor.execute_sync(program, &mut ReplayHost::new(&self.host_responses))
+    }
+}
+
+/// A single response given by the host during the recorded execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum HostResponse {
+    /// The MAST forest returned for the specified digest, if any.
+    MastForest {
+        digest: Word,
+        forest: Option<Arc<MastForest>>,
+    },
+    /// The result of handling the specified event; errors are recorded by their message.
+    Event {
+        event_id: EventId,
+        result: Result<Vec<AdviceMutation>, String>,
+    },
+    /// The result of notifying the host of the resolution of the specified external node.
+    ExternalResolution { digest: Word, result: Result<(), String> },
+}
+
+// REPLAY RECORDER
+// ================================================================================================
+
+/// A [`SyncHost`] which forwards all requests to an inner host, and records its responses into an
+/// [`ExecutionReplay`].
+///
+/// The recorder must be used for exactly one execution, run with the stack and advice inputs
+/// provided to [`Self::new`].
+#[derive(Debug)]
+pub struct ReplayRecorder<H> {
+    host: H,
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+    host_responses: RefCell<Vec<HostResponse>>,
+}
+
+impl<H: SyncHost> ReplayRecorder<H> {
+    /// Returns a new recorder wrapping `host`, for an execution with the specified inputs.
+    pub fn new(host: H, stack_inputs: StackInputs, advice_inputs: AdviceInputs) -> Self {
+        Self {
+            host,
+            stack_inputs,
+            advice_inputs,
+            host_responses: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns a reference to the wrapped host.
+    pub fn host(&self) -> &H {
+        &self.host
+    }
+
+    /// Consumes the recorder and returns the recorded replay.
+    pub fn into_replay(self) -> ExecutionReplay {
+        ExecutionReplay {
+            stack_inputs: self.stack_inputs,
+            advice_inputs: self.advice_inputs,
+            host_responses: self.host_responses.into_inner(),
+        }
+    }
+
+    fn record(&self, response: HostResponse) {
+        self.host_responses.borrow_mut().push(response);
+    }
+}
+
+impl<H: SyncHost> BaseHost for ReplayRecorder<H> {
+    fn get_label_and_source_file(
+        &self,
+        location: &Location,
+    ) -> (SourceSpan, Option<Arc<SourceFile>>) {
+        self.host.get_label_and_source_file(location)
+    }
+
+    fn resolve_event(&self, event_id: EventId) -> Option<&EventName> {
+        self.host.resolve_event(event_id)
+    }
+
+    fn resolve_error_message(&self, err_code: Felt) -> Option<Arc<str>> {
+        self.host.resolve_error_message(err_code)
+    }
+}
+
+impl<H: SyncHost> SyncHost for ReplayRecorder<H> {
+    fn get_mast_forest(&self, node_digest: &Word) -> Option<LoadedMastForest> {
+        let loaded = self.host.get_mast_forest(node_digest);
+        self.record(HostResponse::MastForest {
+            digest: *node_digest,
+            forest: loaded.as_ref().map(|loaded| loaded.mast_forest().clone()),
+        });
+        loaded
+    }
+
+    fn try_get_mast_forest(
+        &self,
+        node_digest: &Word,
+    ) -> Result<Option<LoadedMastForest>, MastForestLoadError> {
+        // a failed load aborts execution, so there is nothing to replay past it
+        let loaded = self.host.try_get_mast_forest(node_digest)?;
+        self.record(HostResponse::MastForest {
+            digest: *node_digest,
+            forest: loaded.as_ref().map(|loaded| loaded.mast_forest().clone()),
+        });
+        Ok(loaded)
+    }
+
+    fn on_event(
+        &mut self,
+        process: &ProcessorState<'_>,
+    ) -> Result<Vec<AdviceMutation>, EventError> {
+        let event_id = EventId::from_felt(process.get_stack_item(0));
+        let result = self.host.on_event(process);
+        self.record(HostResponse::Event {
+            event_id,
+            result: match &result {
+                Ok(mutations) => Ok(mutations.clone()),
+                Err(err) => Err(err.to_string()),
+            },
+        });
+        result
+    }
+
+    fn on_external_resolution(&mut self, node_digest: Word) -> Result<(), EventError> {
+        let result = self.host.on_external_resolution(node_digest);
+        self.record(HostResponse::ExternalResolution {
+            digest: node_digest,
+            result: result.as_ref().map(|_| ()).map_err(ToString::to_string),
+        });
+        result
+    }
+}
+
+// REPLAY HOST
+// ================================================================================================
+
+/// A [`SyncHost`] which responds to requests with the responses recorded in an
+/// [`ExecutionReplay`], in order.
+struct ReplayHost<'a> {
+    host_responses: &'a [HostResponse],
+    next_response: Cell<usize>,
+}
+
+impl<'a> ReplayHost<'a> {
+    fn new(host_responses: &'a [HostResponse]) -> Self {
+        Self {
+            host_responses,
+            next_response: Cell::new(0),
+        }
+    }
+
+    /// Returns the next recorded response, advancing past it.
+    fn next_response(&self) -> Option<&'a HostResponse> {
+        let response = self.host_responses.get(self.next_response.get())?;
+        self.next_response.set(self.next_response.get() + 1);
+        Some(response)
+    }
+}
+
+impl BaseHost for ReplayHost<'_> {
+    fn get_label_and_source_file(
+        &self,
+        _location: &Location,
+    ) -> (SourceSpan, Option<Arc<SourceFile>>) {
+        (SourceSpan::UNKNOWN, None)
+    }
+}
+
+impl SyncHost for ReplayHost<'_> {
+    fn get_mast_forest(&self, node_digest: &Word) -> Option<LoadedMastForest> {
+        match self.next_response()? {
+            HostResponse::MastForest { digest, forest } if digest == node_digest => {
+                forest.clone().map(LoadedMastForest::new)
+            },
+            _ => None,
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        process: &ProcessorState<'_>,
+    ) -> Result<Vec<AdviceMutation>, EventError> {
+        let event_id = EventId::from_felt(process.get_stack_item(0));
+        match self.next_response() {
+            Some(HostResponse::Event { event_id: recorded_id, result })
+                if *recorded_id == event_id =>
+            {
+                result.clone().map_err(EventError::from)
+            },
+            _ => Err(ReplayDiverged.into()),
+        }
+    }
+
+    fn on_external_resolution(&mut self, node_digest: Word) -> Result<(), EventError> {
+        match self.next_response() {
+            Some(HostResponse::ExternalResolution { digest, result }) if *digest == node_digest => {
+                result.clone().map_err(EventError::from)
+            },
+            _ => Err(ReplayDiverged.into()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("execution diverged from the recorded replay")]
+struct ReplayDiverged;
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ExecutionReplay {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.stack_inputs.write_into(target);
+        self.advice_inputs.write_into(target);
+
+        target.write_usize(self.host_responses.len());
+        for response in self.host_responses.iter() {
+            match response {
+                HostResponse::MastForest { digest, forest } => {
+                    target.write_u8(0);
+                    digest.write_into(target);
+                    forest.as_deref().write_into(target);
+                },
+                HostResponse::Event { event_id, result } => {
+                    target.write_u8(1);
+                    target.write_u64(event_id.as_u64());
+                    write_result(result, target, |mutations, target| {
+                        target.write_usize(mutations.len());
+                        for mutation in mutations.iter() {
+                            write_advice_mutation(mutation, target);
+                        }
+                    });
+                },
+                HostResponse::ExternalResolution { digest, result } => {
+                    target.write_u8(2);
+                    digest.write_into(target);
+                    write_result(result, target, |_, _| {});
+                },
+            }
+        }
+    }
+}
+
+impl Deserializable for ExecutionReplay {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let stack_inputs = StackInputs::read_from(source)?;
+        let advice_inputs = AdviceInputs::read_from(source)?;
+
+        let num_responses = source.read_usize()?;
+        let mut host_responses = Vec::new();
+        for _ in 0..num_responses {
+            let response = match source.read_u8()? {
+                0 => HostResponse::MastForest {
+                    digest: Word::read_from(source)?,
+                    forest: Option::<MastForest>::read_from(source)?.map(Arc::new),
+                },
+                1 => HostResponse::Event {
+                    event_id: EventId::from_u64(source.read_u64()?),
+                    result: read_result(source, |source| {
+                        let num_mutations = source.read_usize()?;
+                        (0..num_mutations).map(|_| read_advice_mutation(source)).collect()
+                    })?,
+                },
+                2 => HostResponse::ExternalResolution {
+                    digest: Word::read_from(source)?,
+                    result: read_result(source, |_| Ok(()))?,
+                },
+                tag => {
+                    return Err(DeserializationError::InvalidValue(format!(
+                        "invalid host response tag {tag}"
+                    )));
+                },
+            };
+            host_responses.push(response);
+        }
+
+        Ok(Self {
+            stack_inputs,
+            advice_inputs,
+            host_responses,
+        })
+    }
+}
+
+fn write_result<T, W: ByteWriter>(
+    result: &Result<T, String>,
+    target: &mut W,
+    write_ok: impl FnOnce(&T, &mut W),
+) {
+    match result {
+        Ok(value) => {
+            target.write_bool(true);
+            write_ok(value, target);
+        },
+        Err(message) => {
+            target.write_bool(false);
+            message.write_into(target);
+        },
+    }
+}
+
+fn read_result<T, R: ByteReader>(
+    source: &mut R,
+    read_ok: impl FnOnce(&mut R) -> Result<T, DeserializationError>,
+) -> Result<Result<T, String>, DeserializationError> {
+    if source.read_bool()? {
+        Ok(Ok(read_ok(source)?))
+    } else {
+        Ok(Err(String::read_from(source)?))
+    }
+}
+
+fn write_advice_mutation<W: ByteWriter>(mutation: &AdviceMutation, target: &mut W) {
+    match mutation {
+        AdviceMutation::ExtendStack { values } => {
+            target.write_u8(0);
+            values.write_into(target);
+        },
+        AdviceMutation::ExtendMap { other } => {
+            target.write_u8(1);
+            other.write_into(target);
+        },
+        AdviceMutation::ExtendMerkleStore { infos } => {
+            target.write_u8(2);
+            target.write_usize(infos.len());
+            for info in infos.iter() {
+                info.value.write_into(target);
+                info.left.write_into(target);
+                info.right.write_into(target);
+            }
+        },
+    }
+}
+
+fn read_advice_mutation<R: ByteReader>(
+    source: &mut R,
+) -> Result<AdviceMutation, DeserializationError> {
+    match source.read_u8()? {
+        0 => Ok(AdviceMutation::ExtendStack { values: Vec::<Felt>::read_from(source)? }),
+        1 => Ok(AdviceMutation::ExtendMap { other: AdviceMap::read_from(source)? }),
+        2 => {
+            let num_infos = source.read_usize()?;
+            let infos = (0..num_infos)
+                .map(|_| {
+                    Ok(InnerNodeInfo {
+                        value: Word::read_from(source)?,
+                        left: Word::read_from(source)?,
+                        right: Word::read_from(source)?,
+                    })
+                })
+                .collect::<Result<_, DeserializationError>>()?;
+            Ok(AdviceMutation::ExtendMerkleStore { infos })
+        },
+        tag => {
+            Err(DeserializationError::InvalidValue(format!("invalid advice mutation tag {tag}")))
+        },
+    }
+}
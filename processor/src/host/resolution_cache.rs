@@ -0,0 +1,142 @@
+use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
+use core::cell::RefCell;
+
+use miden_core::{
+    Felt, Word,
+    events::{EventId, EventName},
+    mast::MastForest,
+};
+use miden_debug_types::{Location, SourceFile, SourceSpan};
+
+use super::handlers::EventError;
+use crate::{
+    BaseHost, LoadedMastForest, MastForestLoadError, ProcessorState, SyncHost,
+    advice::AdviceMutation,
+};
+
+// CACHING HOST
+// ================================================================================================
+
+/// A [`SyncHost`] which caches the MAST forests resolved by the wrapped host.
+///
+/// When a program calls into a procedure which is not part of its own forest, the VM asks the host
+/// for the forest containing it. A `CachingHost` remembers every forest the wrapped host returns,
+/// keyed by the digests of its local procedures, and serves later requests for any of these
+/// procedures from the cache. Since the cache lives in the host rather than in the processor, it
+/// is shared by all executions which use the same host, which spares batch executors from
+/// resolving the same libraries over and over.
+///
+/// Only successful resolutions are cached; procedures the wrapped host does not know of, or fails
+/// to load, are requested from it again. [`SyncHost::on_external_resolution()`] and events are
+/// always forwarded to the wrapped host. If the forests served by the wrapped host change,
+/// stale entries can be dropped via [`Self::invalidate()`], [`Self::invalidate_forest()`], or
+/// [`Self::clear()`].
+#[derive(Debug)]
+pub struct CachingHost<H> {
+    host: H,
+    cache: RefCell<BTreeMap<Word, LoadedMastForest>>,
+}
+
+impl<H: SyncHost> CachingHost<H> {
+    /// Returns a new [`CachingHost`] wrapping `host`, with an empty cache.
+    pub fn new(host: H) -> Self {
+        Self { host, cache: RefCell::default() }
+    }
+
+    /// Returns a reference to the wrapped host.
+    pub fn host(&self) -> &H {
+        &self.host
+    }
+
+    /// Returns a mutable reference to the wrapped host.
+    ///
+    /// Changing the forests served by the host does not invalidate the cache.
+    pub fn host_mut(&mut self) -> &mut H {
+        &mut self.host
+    }
+
+    /// Consumes `self` and returns the wrapped host.
+    pub fn into_inner(self) -> H {
+        self.host
+    }
+
+    /// Returns the number of procedures whose MAST forest is cached.
+    pub fn num_cached_procedures(&self) -> usize {
+        self.cache.borrow().len()
+    }
+
+    /// Removes the cached MAST forest of the procedure with the provided digest, returning a flag
+    /// indicating whether it was cached.
+    ///
+    /// Other procedures of the same forest remain cached.
+    pub fn invalidate(&mut self, procedure_digest: &Word) -> bool {
+        self.cache.get_mut().remove(procedure_digest).is_some()
+    }
+
+    /// Removes all procedures of the provided MAST forest from the cache.
+    pub fn invalidate_forest(&mut self, mast_forest: &MastForest) {
+        let cache = self.cache.get_mut();
+        for proc_digest in mast_forest.local_procedure_digests() {
+            cache.remove(&proc_digest);
+        }
+    }
+
+    /// Removes all entries from the cache.
+    pub fn clear(&mut self) {
+        self.cache.get_mut().clear();
+    }
+}
+
+impl<H: SyncHost> BaseHost for CachingHost<H> {
+    fn get_label_and_source_file(
+        &self,
+        location: &Location,
+    ) -> (SourceSpan, Option<Arc<SourceFile>>) {
+        self.host.get_label_and_source_file(location)
+    }
+
+    fn resolve_event(&self, event_id: EventId) -> Option<&EventName> {
+        self.host.resolve_event(event_id)
+    }
+
+    fn resolve_error_message(&self, err_code: Felt) -> Option<Arc<str>> {
+        self.host.resolve_error_message(err_code)
+    }
+}
+
+impl<H: SyncHost> SyncHost for CachingHost<H> {
+    fn get_mast_forest(&self, node_digest: &Word) -> Option<LoadedMastForest> {
+        self.try_get_mast_forest(node_digest).ok().flatten()
+    }
+
+    fn try_get_mast_forest(
+        &self,
+        node_digest: &Word,
+    ) -> Result<Option<LoadedMastForest>, MastForestLoadError> {
+        if let Some(loaded_mast_forest) = self.cache.borrow().get(node_digest) {
+            return Ok(Some(loaded_mast_forest.clone()));
+        }
+
+        let Some(loaded_mast_forest) = self.host.try_get_mast_forest(node_digest)? else {
+            return Ok(None);
+        };
+
+        // only register the procedures which are local to this forest
+        let mut cache = self.cache.borrow_mut();
+        for proc_digest in loaded_mast_forest.mast_forest().local_procedure_digests() {
+            cache.insert(proc_digest, loaded_mast_forest.clone());
+        }
+        Ok(Some(loaded_mast_forest))
+    }
+
+    fn on_event(
+        &mut self,
+        process: &ProcessorState<'_>,
+    ) -> Result<Vec<AdviceMutation>, EventError> {
+        self.host.on_event(process)
+    }
+
+    fn on_external_resolution(&mut self, node_digest: Word) -> Result<(), EventError> {
+        self.host.on_external_resolution(node_digest)
+    }
+}
@@ -0,0 +1,53 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use miden_air::trace::RowIndex;
+use miden_core::Word;
+
+// KERNEL ACCESS REPORT
+// ================================================================================================
+
+/// The kernel procedures invoked by a program via `syscall`.
+///
+/// For each invoked kernel procedure, identified by its MAST root, the report holds the clock
+/// cycles at which the procedure was invoked, in execution order. These are the same accesses
+/// which are recorded in the kernel ROM chiplet when a trace of the execution is built.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KernelAccessReport {
+    procedures: BTreeMap<Word, Vec<RowIndex>>,
+}
+
+impl KernelAccessReport {
+    /// Returns the number of times the kernel procedure with the specified MAST root was invoked.
+    pub fn num_invocations(&self, proc_root: &Word) -> usize {
+        self.invocation_cycles(proc_root).len()
+    }
+
+    /// Returns the clock cycles at which the kernel procedure with the specified MAST root was
+    /// invoked, in execution order.
+    pub fn invocation_cycles(&self, proc_root: &Word) -> &[RowIndex] {
+        self.procedures.get(proc_root).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Returns an iterator over the invoked kernel procedures and the clock cycles at which each
+    /// of them was invoked, ordered by procedure MAST root.
+    pub fn iter(&self) -> impl Iterator<Item = (&Word, &[RowIndex])> {
+        self.procedures.iter().map(|(root, cycles)| (root, cycles.as_slice()))
+    }
+
+    /// Returns the MAST roots of the invoked kernel procedures which are not in `allow_list`,
+    /// ordered by procedure MAST root.
+    ///
+    /// An empty result means that the program invoked only allow-listed kernel procedures.
+    pub fn procedures_not_in(&self, allow_list: &[Word]) -> Vec<Word> {
+        self.procedures
+            .keys()
+            .filter(|proc_root| !allow_list.contains(proc_root))
+            .copied()
+            .collect()
+    }
+
+    /// Records an invocation of the kernel procedure with the specified MAST root at `clk`.
+    pub(crate) fn record_access(&mut self, proc_root: Word, clk: RowIndex) {
+        self.procedures.entry(proc_root).or_default().push(clk);
+    }
+}
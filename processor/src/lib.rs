@@ -18,11 +18,17 @@ use core::{
 use miden_mast_package::debug_info::DebugSourceNodeId;
 
 mod continuation_stack;
+mod coverage;
 mod errors;
 mod execution;
 mod execution_options;
 mod fast;
 mod host;
+mod kernel_access;
+mod metering;
+#[cfg(feature = "trace-nodes")]
+mod node_spans;
+mod probe;
 mod processor;
 mod tracer;
 
@@ -51,19 +57,30 @@ mod tests;
 // ================================================================================================
 
 pub use continuation_stack::Continuation;
+pub use coverage::CoverageReport;
 pub use errors::{
-    AceError, ExecutionError, HostError, MemoryError, PackageSourceDebugContext,
-    advice_error_with_package_source_context, event_error_with_package_source_context,
-    procedure_not_found_with_package_source_context,
+    AceError, CallSite, CheckpointError, ExecutionError, HostError, MemoryError,
+    PackageSourceDebugContext, advice_error_with_package_source_context,
+    event_error_with_package_source_context, procedure_not_found_with_package_source_context,
+};
+pub use execution_options::{CancellationToken, ExecutionOptions, ExecutionOptionsError};
+pub use fast::{
+    BreakReason, ExecutionEvent, ExecutionObserver, ExecutionOutput, ExecutionReports,
+    FastProcessor, MemoryAccess, MemoryAccessRecord, MemoryAccessValue, ProcessorSnapshot,
+    ResumeContext, ReversibleStepper, WatchpointHit,
 };
-pub use execution_options::{ExecutionOptions, ExecutionOptionsError};
-pub use fast::{BreakReason, ExecutionOutput, FastProcessor, ResumeContext};
 pub use host::{
-    BaseHost, FutureMaybeSend, Host, LoadedMastForest, MastForestStore, MemMastForestStore,
+    BaseHost, CachingHost, ExecutionReplay, FutureMaybeSend, Host, LoadedMastForest,
+    MastForestLoadError, MastForestLoader, MastForestStore, MemMastForestStore, ReplayRecorder,
     SyncHost,
-    debug::{StdoutWriter, format_value, write_interval, write_stack},
+    debug::{
+        StdoutWriter, TreeConnectors, VmState, VmStatePrinter, format_value, write_interval,
+        write_stack,
+    },
     default::{DefaultHost, HostLibrary},
 };
+pub use kernel_access::KernelAccessReport;
+pub use metering::{CycleReport, ProcedureCycles, procedure_names};
 pub use miden_core::{
     EMPTY_WORD, Felt, ONE, WORD_SIZE, Word, ZERO, crypto, field, mast,
     program::{
@@ -72,14 +89,17 @@ pub use miden_core::{
     },
     serde, utils,
 };
+pub use probe::{DependencyProbe, probe_dependencies};
 pub use trace::{TraceBuildInputs, TraceGenerationContext};
 
 pub mod advice {
-    pub use miden_core::advice::{AdviceInputs, AdviceMap, AdviceStackBuilder};
+    pub use miden_core::advice::{
+        AdviceInputs, AdviceMap, AdviceProviderSnapshot, AdviceStackBuilder,
+    };
 
     pub use super::host::{
         AdviceMutation,
-        advice::{AdviceError, AdviceProvider, MAX_ADVICE_STACK_SIZE},
+        advice::{AdviceError, AdviceProvider, MAX_ADVICE_STACK_SIZE, MerkleStoreStats},
     };
 }
 
@@ -87,7 +107,8 @@ pub mod event {
     pub use miden_core::events::*;
 
     pub use crate::host::handlers::{
-        EventError, EventHandler, EventHandlerRegistry, NoopEventHandler,
+        EventError, EventHandler, EventHandlerRegistry, EventMiddleware, EventNext,
+        NoopEventHandler,
     };
 }
 
@@ -97,6 +118,7 @@ pub mod operation {
     pub use crate::errors::{BinaryValueErrorContext, OperationError};
 }
 
+pub mod consistency;
 pub mod trace;
 
 // EXECUTORS
@@ -123,15 +145,13 @@ pub async fn execute(
     processor.execute(program, host).await
 }
 
-/// Synchronous wrapper for the async `execute()` function.
+/// Synchronous variant of [`execute()`] for hosts implementing [`SyncHost`].
 ///
-/// This method is only available on non-wasm32 targets. On wasm32, use the async `execute()`
-/// method directly since wasm32 runs in the browser's event loop.
+/// Execution is driven directly on the calling thread: no async runtime is created or required,
+/// so embedders with purely synchronous hosts do not need to depend on an executor.
 ///
-/// # Panics
-/// Panics if called from within an existing Tokio runtime. Use the async `execute()` method
-/// instead in async contexts.
-#[cfg(not(target_family = "wasm"))]
+/// # Errors
+/// Returns an error if program execution fails for any reason.
 #[tracing::instrument("execute_program_sync", skip_all)]
 pub fn execute_sync(
     program: &Program,
@@ -145,6 +165,36 @@ pub fn execute_sync(
     processor.execute_sync(program, host)
 }
 
+/// Executes a batch of independent programs in parallel and returns their execution outputs.
+///
+/// Each entry of `programs` is executed against its own host, created by calling `host_factory`
+/// with the index of the entry in `programs`. Programs are distributed across the global `rayon`
+/// thread pool, and every program is executed with the same `options`.
+///
+/// The returned vector holds the result of each program in the order of `programs`; a failing
+/// program does not affect the execution of the others.
+#[tracing::instrument("execute_program_batch", skip_all)]
+pub fn execute_batch<H, F>(
+    programs: &[(Program, StackInputs, AdviceInputs)],
+    host_factory: F,
+    options: ExecutionOptions,
+) -> Vec<Result<ExecutionOutput, ExecutionError>>
+where
+    H: SyncHost,
+    F: Fn(usize) -> H + Sync,
+{
+    use rayon::prelude::*;
+
+    programs
+        .par_iter()
+        .enumerate()
+        .map(|(idx, (program, stack_inputs, advice_inputs))| {
+            let mut host = host_factory(idx);
+            execute_sync(program, *stack_inputs, advice_inputs.clone(), &mut host, options.clone())
+        })
+        .collect()
+}
+
 // PROCESSOR STATE
 // ===============================================================================================
 
@@ -152,6 +202,14 @@ pub fn execute_sync(
 ///
 /// This struct provides read access to the processor's state, including the stack, memory,
 /// advice provider, and execution context information.
+///
+/// The view is deliberately read-only. Memory can only be modified by VM operations, since every
+/// memory write must be matched by a memory chiplet row requested from the stack; a write made
+/// directly by the host would leave the chiplets bus unbalanced and the resulting execution could
+/// not be proven. Event handlers which need to produce data for a program should instead return
+/// [`advice::AdviceMutation`]s (e.g., extending the advice stack or map), and let the program
+/// move the data into memory itself, e.g., via `adv_pipe` or `adv_loadw` followed by
+/// `mem_storew`.
 #[derive(Debug)]
 pub struct ProcessorState<'a> {
     processor: &'a FastProcessor,
@@ -229,6 +287,28 @@ impl<'a> ProcessorState<'a> {
         self.processor.memory().read_word_impl(ctx, addr)
     }
 
+    /// Returns `num_words` consecutive words starting at the specified context/address, with
+    /// `None` for words which haven't been accessed previously.
+    ///
+    /// # Errors
+    /// - If the address is not word aligned.
+    /// - If the region extends beyond the end of the memory address space.
+    pub fn get_mem_region(
+        &self,
+        ctx: ContextId,
+        start_addr: u32,
+        num_words: u32,
+    ) -> Result<Vec<Option<Word>>, MemoryError> {
+        let end_addr = u64::from(start_addr) + u64::from(num_words) * WORD_SIZE as u64;
+        if end_addr > u32::MAX as u64 + 1 {
+            return Err(MemoryError::AddressOutOfBounds { addr: end_addr - 1 });
+        }
+
+        (0..num_words)
+            .map(|idx| self.get_mem_word(ctx, start_addr + idx * WORD_SIZE as u32))
+            .collect()
+    }
+
     /// Reads (start_addr, end_addr) tuple from the specified elements of the operand stack (
     /// without modifying the state of the stack), and verifies that memory range is valid.
     ///
@@ -338,6 +418,15 @@ pub trait Stopper {
             Option<DebugSourceNodeId>,
         )>,
     ) -> ControlFlow<BreakReason<Self::Forest>>;
+
+    /// Determines whether execution should be stopped right after a procedure breakpoint was hit
+    /// outside of a clock cycle, i.e. when resolving an external node.
+    ///
+    /// At that point, the continuation stack fully encodes the next step of execution, so no
+    /// continuation needs to be provided. Returns `false` by default.
+    fn should_stop_at_breakpoint(&self, _processor: &Self::Processor) -> bool {
+        false
+    }
 }
 
 // EXECUTION CONTEXT
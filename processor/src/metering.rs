@@ -0,0 +1,240 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use miden_air::trace::RowIndex;
+use miden_core::{
+    Word,
+    mast::{ExecutableMastForest, MastForest, MastNode, MastNodeExt, MastNodeId},
+};
+use miden_mast_package::debug_info::{DebugSourceMapSection, PackageDebugInfo};
+
+use crate::continuation_stack::Continuation;
+
+// CYCLE REPORT
+// ================================================================================================
+
+/// The number of VM cycles consumed by a program, broken down by procedure.
+///
+/// Each cycle is charged to the innermost procedure being executed at that cycle, so the cycles of
+/// a procedure exclude those spent in the procedures it invokes, and the per-procedure counts add
+/// up to [`Self::total_cycles`]. Procedures are identified by their MAST root.
+///
+/// A procedure invoked via `exec` is only accounted for separately when its body remains a
+/// distinct node of the MAST; code which the assembler merged into the caller's basic block is
+/// charged to the caller.
+///
+/// The report also breaks the cycles down by call stack, i.e., by the chain of procedures which
+/// were active at the time. This breakdown can be rendered in the folded stack format consumed by
+/// flamegraph tools such as `inferno` via [`Self::to_folded_stacks`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CycleReport {
+    total_cycles: u32,
+    procedures: BTreeMap<Word, ProcedureCycles>,
+    call_stacks: BTreeMap<Vec<Word>, u32>,
+}
+
+impl CycleReport {
+    /// Returns the total number of cycles consumed by the program.
+    pub fn total_cycles(&self) -> u32 {
+        self.total_cycles
+    }
+
+    /// Returns the cycles consumed by the procedure with the specified MAST root, or `None` if the
+    /// procedure was not executed.
+    pub fn get(&self, procedure_root: &Word) -> Option<&ProcedureCycles> {
+        self.procedures.get(procedure_root)
+    }
+
+    /// Returns an iterator over the executed procedures and the cycles they consumed, ordered by
+    /// MAST root.
+    pub fn iter(&self) -> impl Iterator<Item = (&Word, &ProcedureCycles)> {
+        self.procedures.iter()
+    }
+
+    /// Returns an iterator over the executed call stacks and the cycles spent with each of them
+    /// active, ordered by call stack.
+    ///
+    /// Each call stack lists the MAST roots of the active procedures from the outermost to the
+    /// innermost one, and its cycles are the cycles charged to the innermost procedure while it
+    /// was invoked from that exact chain of callers.
+    pub fn call_stacks(&self) -> impl Iterator<Item = (&[Word], u32)> {
+        self.call_stacks.iter().map(|(stack, &cycles)| (stack.as_slice(), cycles))
+    }
+
+    /// Renders the cycles spent per call stack in the folded stack format, with one
+    /// `outer;...;inner <cycles>` line per call stack.
+    ///
+    /// Procedures are named by `resolve_name`, falling back to the hex encoding of their MAST root
+    /// when it returns `None`; [`procedure_names`] can be used to derive names from the debug
+    /// information of a package.
+    pub fn to_folded_stacks(&self, resolve_name: impl Fn(&Word) -> Option<String>) -> String {
+        let mut names = BTreeMap::new();
+        let mut folded = String::new();
+        for (stack, cycles) in self.call_stacks() {
+            for (i, procedure_root) in stack.iter().enumerate() {
+                let name = names.entry(*procedure_root).or_insert_with(|| {
+                    resolve_name(procedure_root).unwrap_or_else(|| procedure_root.to_hex())
+                });
+                if i > 0 {
+                    folded.push(';');
+                }
+                folded.push_str(name);
+            }
+            let _ = writeln!(folded, " {cycles}");
+        }
+        folded
+    }
+}
+
+/// Returns the names of the procedures of `forest`, keyed by MAST root, as recorded in the
+/// assembly operation metadata of `debug_info`.
+///
+/// A procedure is named after the context of the first assembly operation found in its body,
+/// without descending into the other procedures it invokes. Procedures without any assembly
+/// operation metadata are omitted.
+pub fn procedure_names(
+    forest: &MastForest,
+    debug_info: &PackageDebugInfo,
+) -> BTreeMap<Word, String> {
+    let mut node_names = BTreeMap::new();
+    for asm_op in debug_info.source_map().into_iter().flat_map(DebugSourceMapSection::asm_ops) {
+        if let Some(source_node) = debug_info.source_node(asm_op.source_node) {
+            node_names.entry(source_node.exec_node).or_insert(asm_op.context_name.as_str());
+        }
+    }
+
+    let mut names = BTreeMap::new();
+    for &root_id in forest.procedure_roots() {
+        let mut pending = vec![root_id];
+        while let Some(node_id) = pending.pop() {
+            if let Some(name) = node_names.get(&node_id) {
+                if let Some(node) = forest.get_node_by_id(root_id) {
+                    names.insert(node.digest(), name.to_string());
+                }
+                break;
+            }
+            if node_id != root_id && forest.is_procedure_root(node_id) {
+                continue;
+            }
+            if let Some(node) = forest.get_node_by_id(node_id) {
+                let first_child = pending.len();
+                node.for_each_child(|child_id| pending.push(child_id));
+                // visit the children in execution order
+                pending[first_child..].reverse();
+            }
+        }
+    }
+    names
+}
+
+/// The cycles consumed by a single procedure, as recorded in a [`CycleReport`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProcedureCycles {
+    /// The number of times the procedure was invoked.
+    pub invocations: u32,
+    /// The number of cycles spent executing the procedure itself, excluding the cycles spent in
+    /// the procedures it invoked.
+    pub cycles: u32,
+}
+
+// CYCLE METER
+// ================================================================================================
+
+/// Attributes the cycles consumed during execution to the procedures being executed.
+///
+/// The meter observes every continuation popped off the continuation stack. Starting a procedure
+/// root opens a frame which records the depth of the continuation stack at that point; the
+/// procedure has finished once every continuation it pushed has been consumed, i.e. once the
+/// continuation stack drops below that depth. Cycles are charged to the innermost open frame
+/// whenever a frame is opened or closed.
+#[derive(Debug, Default)]
+pub(crate) struct CycleMeter {
+    frames: Vec<ProcedureFrame>,
+    /// The MAST roots of the open frames, from the outermost to the innermost one.
+    call_stack: Vec<Word>,
+    last_clk: u32,
+    procedures: BTreeMap<Word, ProcedureCycles>,
+    call_stacks: BTreeMap<Vec<Word>, u32>,
+}
+
+#[derive(Debug)]
+struct ProcedureFrame {
+    procedure_root: Word,
+    continuation_depth: usize,
+}
+
+impl CycleMeter {
+    /// Updates the meter after `continuation` was popped off the continuation stack, leaving
+    /// `continuation_depth` continuations on the stack.
+    pub fn on_continuation<F: ExecutableMastForest>(
+        &mut self,
+        continuation: &Continuation<F>,
+        continuation_depth: usize,
+        clk: RowIndex,
+        current_forest: &F,
+    ) {
+        while self
+            .frames
+            .last()
+            .is_some_and(|frame| frame.continuation_depth > continuation_depth)
+        {
+            self.charge(clk);
+            self.frames.pop();
+            self.call_stack.pop();
+        }
+
+        if let Continuation::StartNode(node_id) = continuation
+            && let Some(procedure_root) = procedure_root(current_forest, *node_id)
+        {
+            self.charge(clk);
+            self.frames.push(ProcedureFrame { procedure_root, continuation_depth });
+            self.call_stack.push(procedure_root);
+            self.procedures.entry(procedure_root).or_default().invocations += 1;
+        }
+    }
+
+    /// Charges the remaining cycles up to `clk` and returns the resulting report.
+    pub fn into_report(mut self, clk: RowIndex) -> CycleReport {
+        self.charge(clk);
+        CycleReport {
+            total_cycles: clk.as_u32(),
+            procedures: self.procedures,
+            call_stacks: self.call_stacks,
+        }
+    }
+
+    /// Charges the cycles executed since the last charge to the innermost open frame.
+    fn charge(&mut self, clk: RowIndex) {
+        let clk = clk.as_u32();
+        let cycles = clk - self.last_clk;
+        if let Some(frame) = self.frames.last() {
+            self.procedures.entry(frame.procedure_root).or_default().cycles += cycles;
+            if cycles > 0 {
+                match self.call_stacks.get_mut(self.call_stack.as_slice()) {
+                    Some(stack_cycles) => *stack_cycles += cycles,
+                    None => {
+                        self.call_stacks.insert(self.call_stack.clone(), cycles);
+                    },
+                }
+            }
+        }
+        self.last_clk = clk;
+    }
+}
+
+/// Returns the MAST root of the procedure rooted at `node_id`, or `None` if the node is not a
+/// procedure root.
+///
+/// External nodes are skipped, since the procedure they refer to is accounted for once resolved.
+fn procedure_root<F: ExecutableMastForest>(forest: &F, node_id: MastNodeId) -> Option<Word> {
+    if matches!(forest.get_node_by_id(node_id), Some(MastNode::External(_))) {
+        return None;
+    }
+    let digest = forest.get_digest_by_id(node_id)?;
+    (forest.find_procedure_root(digest) == Some(node_id)).then_some(digest)
+}
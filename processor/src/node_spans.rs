@@ -0,0 +1,108 @@
+use alloc::vec::Vec;
+
+use miden_air::trace::RowIndex;
+use miden_core::mast::{ExecutableMastForest, MastNode, MastNodeExt};
+use tracing::Span;
+
+use crate::continuation_stack::Continuation;
+
+// NODE SPANS
+// ================================================================================================
+
+/// Tracks a [`tracing`] span for every Join, Loop, and Call node being executed.
+///
+/// A span is opened when the node starts and closed once it finishes, at which point its `cycles`
+/// field is set to the number of cycles spent in the node, including its children. Spans of nested
+/// nodes are children of the spans of their enclosing nodes, and the spans of outermost nodes are
+/// children of the span which is current when they are opened.
+///
+/// Spans are never entered, since execution may move between threads at `await` points; tracing
+/// backends which measure span durations from creation to close (e.g., OpenTelemetry exporters)
+/// report the wall-clock time spent in each node.
+#[derive(Debug, Default)]
+pub(crate) struct NodeSpans {
+    frames: Vec<NodeSpanFrame>,
+}
+
+#[derive(Debug)]
+struct NodeSpanFrame {
+    span: Span,
+    continuation_depth: usize,
+    start_clk: RowIndex,
+}
+
+impl NodeSpans {
+    /// Updates the open spans after `continuation` was popped off the continuation stack, leaving
+    /// it with `continuation_depth` continuations.
+    ///
+    /// A node's finish continuation sits at the depth of its start continuation, so the node is
+    /// complete once the stack shrinks below that depth.
+    pub fn on_continuation<F: ExecutableMastForest>(
+        &mut self,
+        continuation: &Continuation<F>,
+        continuation_depth: usize,
+        clk: RowIndex,
+        current_forest: &F,
+    ) {
+        while self
+            .frames
+            .last()
+            .is_some_and(|frame| frame.continuation_depth > continuation_depth)
+        {
+            self.close_innermost(clk);
+        }
+
+        if let Continuation::StartNode(node_id) = continuation
+            && let Some(node) = current_forest.get_node_by_id(*node_id)
+        {
+            let parent = self.frames.last().map_or_else(Span::current, |frame| frame.span.clone());
+            let digest = node.digest();
+            let start_clk = clk.as_u32();
+            let span = match node {
+                MastNode::Join(_) => tracing::info_span!(
+                    parent: &parent,
+                    "join",
+                    %digest,
+                    clk = start_clk,
+                    cycles = tracing::field::Empty
+                ),
+                MastNode::Loop(_) => tracing::info_span!(
+                    parent: &parent,
+                    "loop",
+                    %digest,
+                    clk = start_clk,
+                    cycles = tracing::field::Empty
+                ),
+                MastNode::Call(call_node) if call_node.is_syscall() => tracing::info_span!(
+                    parent: &parent,
+                    "syscall",
+                    %digest,
+                    clk = start_clk,
+                    cycles = tracing::field::Empty
+                ),
+                MastNode::Call(_) => tracing::info_span!(
+                    parent: &parent,
+                    "call",
+                    %digest,
+                    clk = start_clk,
+                    cycles = tracing::field::Empty
+                ),
+                _ => return,
+            };
+            self.frames.push(NodeSpanFrame { span, continuation_depth, start_clk: clk });
+        }
+    }
+
+    /// Closes all spans which are still open at the end of execution.
+    pub fn finish(mut self, clk: RowIndex) {
+        while !self.frames.is_empty() {
+            self.close_innermost(clk);
+        }
+    }
+
+    fn close_innermost(&mut self, clk: RowIndex) {
+        if let Some(frame) = self.frames.pop() {
+            frame.span.record("cycles", clk.as_u32() - frame.start_clk.as_u32());
+        }
+    }
+}
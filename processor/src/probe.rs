@@ -0,0 +1,125 @@
+use alloc::{sync::Arc, vec::Vec};
+
+use miden_core::{
+    Word,
+    events::EventId,
+    program::{Program, StackInputs},
+};
+use miden_debug_types::{Location, SourceFile, SourceSpan};
+
+use crate::{
+    BaseHost, ExecutionError, ExecutionOptions, FastProcessor, LoadedMastForest, ProcessorState,
+    SyncHost,
+    advice::{AdviceError, AdviceInputs, AdviceMutation},
+    errors::MapExecErrNoCtx,
+    event::EventError,
+    processor::Processor,
+};
+
+// DEPENDENCY PROBE
+// ================================================================================================
+
+/// The first interaction with the environment required by a program, as reported by
+/// [`probe_dependencies`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DependencyProbe {
+    /// The program ran to completion without requiring anything from its environment.
+    Completed,
+    /// The program called into a procedure with the specified MAST root which is not part of the
+    /// program's MAST forest.
+    MastForest(Word),
+    /// The program read the value for the specified key from the advice map, or checked whether the
+    /// key is present in it with `adv.has_mapkey`.
+    AdviceMapKey(Word),
+    /// The program read from the advice stack.
+    AdviceStack,
+    /// The program emitted the specified event, which needs to be handled by the host.
+    Event(EventId),
+}
+
+/// Executes `program` until its first interaction with the environment, and reports what the
+/// program required at that point.
+///
+/// The program is executed against empty advice inputs and a host which provides no libraries and
+/// handles no events. Execution stops at the first of:
+/// - the resolution of a procedure which is not part of the program's MAST forest,
+/// - a read of a value from the advice map, a check for a key which is not in the advice map, or a
+///   read of an element from the advice stack,
+/// - the emission of an event which is not a system event.
+///
+/// This allows tooling to determine what a program needs (e.g., which library to load) before
+/// executing it in full.
+///
+/// # Errors
+/// Returns an error if execution fails for any other reason before the program interacts with its
+/// environment.
+pub fn probe_dependencies(
+    program: &Program,
+    stack_inputs: StackInputs,
+    options: ExecutionOptions,
+) -> Result<DependencyProbe, ExecutionError> {
+    let mut host = ProbingHost::default();
+    let mut processor =
+        FastProcessor::new_with_options(stack_inputs, AdviceInputs::default(), options)
+            .map_exec_err_no_ctx()?;
+    processor.advice_provider_mut().enable_strict_map_key_checks();
+
+    let err = match processor.execute_sync(program, &mut host) {
+        Ok(_) => return Ok(DependencyProbe::Completed),
+        Err(err) => err,
+    };
+    if let Some(probe) = host.probe {
+        return Ok(probe);
+    }
+    match err {
+        ExecutionError::AdviceError {
+            err: AdviceError::MapKeyNotFound { key }, ..
+        } => Ok(DependencyProbe::AdviceMapKey(key)),
+        ExecutionError::AdviceError { err: AdviceError::StackReadFailed, .. } => {
+            Ok(DependencyProbe::AdviceStack)
+        },
+        err => Err(err),
+    }
+}
+
+// PROBING HOST
+// ================================================================================================
+
+/// A host which records the first library or event required by the program, and refuses to
+/// provide it so that execution stops.
+#[derive(Debug, Default)]
+struct ProbingHost {
+    probe: Option<DependencyProbe>,
+}
+
+impl ProbingHost {
+    fn record(&mut self, probe: DependencyProbe) {
+        self.probe.get_or_insert(probe);
+    }
+}
+
+impl BaseHost for ProbingHost {
+    fn get_label_and_source_file(
+        &self,
+        _location: &Location,
+    ) -> (SourceSpan, Option<Arc<SourceFile>>) {
+        (SourceSpan::UNKNOWN, None)
+    }
+}
+
+impl SyncHost for ProbingHost {
+    fn get_mast_forest(&self, _node_digest: &Word) -> Option<LoadedMastForest> {
+        None
+    }
+
+    fn on_event(&mut self, process: &ProcessorState) -> Result<Vec<AdviceMutation>, EventError> {
+        let event_id = EventId::from_felt(process.get_stack_item(0));
+        self.record(DependencyProbe::Event(event_id));
+        Err("execution stopped by dependency probe".into())
+    }
+
+    fn on_external_resolution(&mut self, node_digest: Word) -> Result<(), EventError> {
+        self.record(DependencyProbe::MastForest(node_digest));
+        Err("execution stopped by dependency probe".into())
+    }
+}
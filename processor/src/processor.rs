@@ -1,9 +1,14 @@
+#[cfg(feature = "std")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use core::time::Duration;
+
 use miden_air::trace::{RowIndex, chiplets::hasher::HasherState};
 use miden_core::deferred::Digest;
 
 use crate::{
-    ContextId, ExecutionError, Felt, MemoryError, Word, advice::AdviceError,
-    crypto::merkle::MerklePath, errors::OperationError,
+    ContextId, CoverageReport, ExecutionError, Felt, KernelAccessReport, MemoryError, Word,
+    advice::AdviceError, crypto::merkle::MerklePath, errors::OperationError, metering::CycleMeter,
 };
 
 // PROCESSOR
@@ -41,6 +46,43 @@ pub(crate) trait Processor: Sized {
 
     /// Returns a mutable reference to the internal hasher subsystem.
     fn hasher(&mut self) -> &mut Self::Hasher;
+
+    /// Returns a mutable reference to the per-operation timings, or `None` if operation timing is
+    /// disabled or not supported by this processor.
+    #[cfg(feature = "std")]
+    fn op_timings_mut(&mut self) -> Option<&mut BTreeMap<&'static str, Duration>> {
+        None
+    }
+
+    /// Returns a mutable reference to the cycle meter, or `None` if cycle metering is disabled or
+    /// not supported by this processor.
+    fn cycle_meter_mut(&mut self) -> Option<&mut CycleMeter> {
+        None
+    }
+
+    /// Returns a mutable reference to the tracing spans of the nodes being executed, or `None` if
+    /// node spans are not supported by this processor.
+    #[cfg(feature = "trace-nodes")]
+    fn node_spans_mut(&mut self) -> Option<&mut crate::node_spans::NodeSpans> {
+        None
+    }
+
+    /// Returns a mutable reference to the coverage report being recorded, or `None` if coverage
+    /// recording is disabled or not supported by this processor.
+    fn coverage_mut(&mut self) -> Option<&mut CoverageReport> {
+        None
+    }
+
+    /// Returns a mutable reference to the kernel access report being recorded, or `None` if kernel
+    /// access recording is disabled or not supported by this processor.
+    fn kernel_access_report_mut(&mut self) -> Option<&mut KernelAccessReport> {
+        None
+    }
+
+    /// Notifies the processor that the procedure with the specified MAST root is about to be
+    /// invoked via a `call`, `syscall`, `dyn`, `dyncall`, or external node, so that it can record
+    /// a hit if a breakpoint is set on that procedure.
+    fn check_breakpoint(&mut self, _procedure_root: Word) {}
 }
 
 // SYSTEM INTERFACE
@@ -89,7 +131,9 @@ pub(crate) trait SystemInterface {
     /// Saves the current context ID and CALLER_HASH onto an internal call-state stack, so they can
     /// later be restored by [`SystemInterface::restore_call_state`] when returning from a `call`,
     /// `syscall` or `dyncall`.
-    fn save_call_state(&mut self);
+    ///
+    /// Returns an error if saving the call state would exceed the maximum call depth.
+    fn save_call_state(&mut self) -> Result<(), ExecutionError>;
 
     /// Restores the most recently saved context ID and CALLER_HASH.
     fn restore_call_state(&mut self) -> Result<(), OperationError>;
@@ -296,7 +340,12 @@ pub(crate) trait AdviceProviderInterface {
 /// Trait representing the memory subsystem of the processor.
 pub(crate) trait MemoryInterface {
     /// Reads an element from memory at the provided address in the provided context.
-    fn read_element(&mut self, ctx: ContextId, addr: Felt) -> Result<Felt, MemoryError>;
+    fn read_element(
+        &mut self,
+        ctx: ContextId,
+        addr: Felt,
+        clk: RowIndex,
+    ) -> Result<Felt, MemoryError>;
 
     /// Reads a word from memory starting at the provided address in the provided context.
     fn read_word(&mut self, ctx: ContextId, addr: Felt, clk: RowIndex)
@@ -307,6 +356,7 @@ pub(crate) trait MemoryInterface {
         &mut self,
         ctx: ContextId,
         addr: Felt,
+        clk: RowIndex,
         element: Felt,
     ) -> Result<(), MemoryError>;
 
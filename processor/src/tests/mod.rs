@@ -1,4 +1,5 @@
 use alloc::{boxed::Box, string::ToString, sync::Arc, vec::Vec};
+use core::assert_matches;
 
 use miden_assembly::{
     Assembler, DefaultSourceManager, Path, PathBuf,
@@ -7,18 +8,22 @@ use miden_assembly::{
 };
 use miden_core::{
     crypto::merkle::{MerkleStore, MerkleTree},
-    mast::{BasicBlockNodeBuilder, MastForest, error_code_from_msg},
+    mast::{BasicBlockNodeBuilder, MastForest, MastNodeExt, error_code_from_msg},
+    serde::{Deserializable, Serializable},
 };
 use miden_debug_types::{Location, SourceFile, SourceManager, SourceSpan};
+use miden_mast_package::{ErrorTable, SourceMap};
 use miden_utils_testing::crypto::{init_merkle_leaves, init_merkle_store};
 
 /// Tests in this file make sure that diagnostics presented to the user are as expected.
 use crate::{
-    BaseHost, DefaultHost, FastProcessor, KernelDescriptor, LoadedMastForest, ONE, ProcessorState,
-    Program, StackInputs, SyncHost, Word, ZERO,
-    advice::{AdviceInputs, AdviceMap, AdviceMutation},
+    BaseHost, DefaultHost, DependencyProbe, ExecutionError, ExecutionOptions, FastProcessor, Felt,
+    KernelDescriptor, LoadedMastForest, ONE, ProcessorState, Program, StackInputs, SyncHost, Word,
+    ZERO,
+    advice::{AdviceError, AdviceInputs, AdviceMap, AdviceMutation},
     event::{EventError, EventHandler, EventName},
     operation::Operation,
+    probe_dependencies,
 };
 
 macro_rules! assert_diagnostic_lines {
@@ -138,6 +143,78 @@ end";
     build_test.execute().unwrap();
 }
 
+#[test]
+fn test_advice_inputs_from_json_fixture() {
+    let fixture = r#"{
+        "stack": [7, 9],
+        "map": {
+            "0x0100000000000000000000000000000000000000000000000000000000000000": [5]
+        }
+    }"#;
+    let source = "
+        begin
+            adv_push push.7 assert_eq
+            adv_push push.9 assert_eq
+            push.[1,0,0,0] adv.push_mapval dropw
+            adv_push push.5 assert_eq
+        end";
+
+    let advice_inputs = AdviceInputs::from_json(fixture).unwrap();
+    let program = Assembler::default()
+        .assemble_program("program", source)
+        .unwrap()
+        .unwrap_program();
+    let mut host = DefaultHost::default();
+    FastProcessor::new(StackInputs::default())
+        .with_advice(advice_inputs)
+        .expect("advice inputs should fit advice map limits")
+        .execute_sync(&program, &mut host)
+        .unwrap();
+}
+
+#[test]
+fn test_advice_map_read_limit() {
+    let source = "
+        begin
+            push.[1,0,0,0]
+            repeat.4
+                adv.push_mapval
+                adv_push drop
+            end
+            dropw
+        end";
+
+    let program = Assembler::default()
+        .assemble_program("program", source)
+        .unwrap()
+        .unwrap_program();
+    let advice_inputs = || {
+        AdviceInputs::default()
+            .with_map([(Word::from([1u32, 0, 0, 0]), vec![Felt::new_unchecked(5)])])
+    };
+
+    // four reads fit within a limit of four
+    let options = ExecutionOptions::default().with_max_advice_reads(4);
+    FastProcessor::new_with_options(StackInputs::default(), advice_inputs(), options)
+        .unwrap()
+        .execute_sync(&program, &mut DefaultHost::default())
+        .unwrap();
+
+    // the fourth read exceeds a limit of three
+    let options = ExecutionOptions::default().with_max_advice_reads(3);
+    let err = FastProcessor::new_with_options(StackInputs::default(), advice_inputs(), options)
+        .unwrap()
+        .execute_sync(&program, &mut DefaultHost::default())
+        .unwrap_err();
+    assert_matches!(
+        err,
+        ExecutionError::AdviceError {
+            err: AdviceError::AdviceReadLimitExceeded { limit: 3 },
+            ..
+        }
+    );
+}
+
 // AdviceMapKeyAlreadyPresent
 // ------------------------------------------------------------------------------------------------
 
@@ -425,6 +502,27 @@ fn test_diagnostic_procedure_not_found_dyncall() {
 // FailedAssertion
 // ------------------------------------------------------------------------------------------------
 
+#[test]
+fn test_execution_error_into_diagnostic_highlights_source_span() {
+    let source = "
+        begin
+            push.1.2
+            assertz
+            push.3.4
+        end";
+
+    let build_test = build_test_by_mode!(true, source, &[1, 2]);
+    let report = build_test.execute().expect_err("expected error").into_diagnostic();
+    assert_diagnostic_lines!(
+        report,
+        "  x assertion failed with error code: 0",
+        regex!(r#",-\[test[\d]+:4:13\]"#),
+        " 4 |             assertz",
+        "   :             ^^^^^^^",
+        "   `----"
+    );
+}
+
 #[test]
 fn test_diagnostic_failed_assertion() {
     // No error message
@@ -1421,6 +1519,63 @@ fn test_diagnostic_syscall_target_not_in_kernel() {
     );
 }
 
+// Tests that a standalone source map restores source locations and assertion messages.
+#[test]
+fn test_diagnostic_assertion_failure_with_source_map() {
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let source = "
+        begin
+            push.1.2
+            assertz.err=\"some error message\"
+        end";
+    let (package, source_map) = Assembler::new(source_manager.clone())
+        .assemble_program_with_source_map("program", source)
+        .unwrap();
+    let program = package.unwrap_program();
+    let source_map = SourceMap::read_from_bytes(&source_map.to_bytes()).unwrap();
+    let mut host = DefaultHost::default().with_source_manager(source_manager);
+
+    let processor = FastProcessor::new(StackInputs::default())
+        .with_advice(AdviceInputs::default())
+        .expect("advice inputs should fit advice map limits");
+    let err = processor
+        .execute_with_source_map_sync(&program, &source_map, &mut host)
+        .expect_err("expected error");
+    assert_diagnostic_lines!(
+        err,
+        "  x assertion failed with error message: some error message",
+        regex!(r#",-\[.*:4:13\]"#),
+        " 3 |             push.1.2",
+        r#" 4 |             assertz.err="some error message""#,
+        "   :             ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^",
+        " 5 |         end",
+        "   `----"
+    );
+}
+
+// Tests that a source map of another program is rejected rather than ignored.
+#[test]
+fn test_source_map_of_another_program_is_rejected() {
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let (_, source_map) = Assembler::new(source_manager.clone())
+        .assemble_program_with_source_map("other", "begin push.1 drop end")
+        .unwrap();
+    let program = Assembler::new(source_manager.clone())
+        .assemble_program("program", "begin push.2 drop end")
+        .unwrap()
+        .unwrap_program();
+    let mut host = DefaultHost::default().with_source_manager(source_manager);
+
+    let err = FastProcessor::new(StackInputs::default())
+        .execute_with_source_map_sync(&program, &source_map, &mut host)
+        .expect_err("expected error");
+    assert_matches!(
+        err,
+        ExecutionError::SourceMapMismatch { expected, actual }
+            if expected == source_map.commitment() && actual == program.mast_forest().commitment()
+    );
+}
+
 // Tests that assertion messages are not recovered without package debug info.
 #[test]
 fn test_assert_message_without_debug_info_reports_error_code() {
@@ -1451,3 +1606,100 @@ fn test_assert_message_without_debug_info_reports_error_code() {
         "non-debug execution should not recover package debug assertion messages:\n{diagnostic}"
     );
 }
+
+// Tests that an error table loaded into the host restores assertion messages.
+#[test]
+fn test_assert_message_with_host_error_table() {
+    let source = "
+        begin
+            push.1
+            assertz.err=\"Value is not zero\"
+        end";
+    let (package, error_table) = Assembler::default()
+        .assemble_program_with_error_table("program", source)
+        .unwrap();
+    let program = package.unwrap_program();
+    let error_table = ErrorTable::read_from_bytes(&error_table.to_bytes()).unwrap();
+    let mut host = DefaultHost::default().with_error_table(error_table);
+
+    let err = FastProcessor::new(StackInputs::default())
+        .execute_sync(&program, &mut host)
+        .expect_err("expected error");
+    assert_diagnostic_lines!(err, "  x assertion failed with error message: Value is not zero");
+}
+
+// Dependency probing
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_probe_dependencies_reports_external_procedure() {
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let lib_module = parse_library_module(
+        source_manager.clone(),
+        "foo::bar",
+        "
+        pub proc dummy_proc
+            push.1
+        end
+    ",
+    );
+    let library = Assembler::new(source_manager.clone())
+        .assemble_library("lib", lib_module, None::<Box<Module>>)
+        .unwrap();
+    let proc_id = library.get_export_node_id("foo::bar::dummy_proc");
+    let proc_digest = library.mast_forest()[proc_id].digest();
+
+    let program_source = "
+        use foo::bar
+
+        begin
+            push.1 drop
+            call.bar::dummy_proc
+            emit.event(\"test::probe::unreachable\")
+        end
+    ";
+    let program = Assembler::new(source_manager)
+        .with_package(library.into(), miden_assembly::Linkage::Dynamic)
+        .unwrap()
+        .assemble_program("program", program_source)
+        .unwrap()
+        .unwrap_program();
+
+    let probe =
+        probe_dependencies(&program, StackInputs::default(), ExecutionOptions::default()).unwrap();
+    assert_eq!(probe, DependencyProbe::MastForest(proc_digest));
+}
+
+#[test]
+fn test_probe_dependencies_reports_first_event_or_advice_read() {
+    let probe = |source: &str| {
+        let program = Assembler::default()
+            .assemble_program("program", source)
+            .unwrap()
+            .unwrap_program();
+        probe_dependencies(&program, StackInputs::default(), ExecutionOptions::default()).unwrap()
+    };
+
+    const EVENT: EventName = EventName::new("test::probe::event");
+    assert_eq!(
+        probe("begin emit.event(\"test::probe::event\") adv_push drop end"),
+        DependencyProbe::Event(EVENT.to_event_id())
+    );
+    assert_eq!(
+        probe("begin push.[1,2,3,4] adv.push_mapval dropw end"),
+        DependencyProbe::AdviceMapKey(Word::from([1u32, 2, 3, 4]))
+    );
+    assert_eq!(probe("begin adv_push drop end"), DependencyProbe::AdviceStack);
+    assert_eq!(probe("begin push.1 drop end"), DependencyProbe::Completed);
+
+    // checking for a missing key is a dependency on the advice map, unlike checking for a key of
+    // the program's own advice map
+    assert_eq!(
+        probe("begin push.[1,2,3,4] adv.has_mapkey adv_push dropw drop end"),
+        DependencyProbe::AdviceMapKey(Word::from([1u32, 2, 3, 4]))
+    );
+    assert_eq!(
+        probe("adv_map A = [0x01]\nbegin push.A adv.has_mapkey adv_push dropw drop end"),
+        DependencyProbe::Completed
+    );
+}
@@ -0,0 +1,71 @@
+use alloc::vec::Vec;
+
+use crate::{Felt, RowIndex, Word, advice::AdviceProvider};
+
+// ADVICE EVENT
+// ================================================================================================
+
+/// A change to the advice stack made during execution.
+///
+/// Values are listed in stack order, i.e. the first value is the one at the top of the advice
+/// stack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdviceEvent {
+    /// Values were pushed onto the advice stack by the event emitted at `clk`, either by a system
+    /// event or by a host event handler.
+    Push { clk: RowIndex, values: Vec<Felt> },
+    /// Values were popped off the advice stack by the operation executed at `clk` (`ADVPOP`,
+    /// `ADVPOPW` or `PIPE`).
+    Pop { clk: RowIndex, values: Vec<Felt> },
+}
+
+// ADVICE TIMELINE RECORDER
+// ================================================================================================
+
+/// Records [`AdviceEvent`]s as execution progresses.
+///
+/// Pops are reported by the operations performing them. Pushes are only performed while handling
+/// events, and are detected by comparing the size of the advice stack at the start and at the end
+/// of each clock cycle.
+#[derive(Debug, Default)]
+pub(crate) struct AdviceTimelineRecorder {
+    events: Vec<AdviceEvent>,
+    clk: RowIndex,
+    stack_len_at_cycle_start: usize,
+    popped: Vec<Felt>,
+}
+
+impl AdviceTimelineRecorder {
+    /// Marks the start of the clock cycle `clk`.
+    pub fn start_clock_cycle(&mut self, clk: RowIndex, advice: &AdviceProvider) {
+        self.clk = clk;
+        self.stack_len_at_cycle_start = advice.stack_len();
+        self.popped.clear();
+    }
+
+    pub fn record_pop_stack(&mut self, value: Felt) {
+        self.popped.push(value);
+    }
+
+    pub fn record_pop_stack_word(&mut self, word: Word) {
+        self.popped.extend_from_slice(word.as_elements());
+    }
+
+    /// Marks the successful end of the current clock cycle.
+    pub fn finalize_clock_cycle(&mut self, advice: &AdviceProvider) {
+        if !self.popped.is_empty() {
+            let values = core::mem::take(&mut self.popped);
+            self.events.push(AdviceEvent::Pop { clk: self.clk, values });
+        }
+
+        let stack_len = advice.stack_len();
+        if stack_len > self.stack_len_at_cycle_start {
+            let values = advice.stack_top(stack_len - self.stack_len_at_cycle_start);
+            self.events.push(AdviceEvent::Push { clk: self.clk, values });
+        }
+    }
+
+    pub fn into_events(self) -> Vec<AdviceEvent> {
+        self.events
+    }
+}
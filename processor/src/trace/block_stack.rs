@@ -48,6 +48,10 @@ impl BlockStack {
         self.blocks.is_empty()
     }
 
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
     pub fn peek(&self) -> &BlockInfo {
         self.blocks.last().expect("block stack is empty")
     }
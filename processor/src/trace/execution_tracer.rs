@@ -1,12 +1,14 @@
-use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
+use alloc::{boxed::Box, collections::BTreeMap, sync::Arc, vec::Vec};
 
 use miden_air::trace::chiplets::hasher::{
     CONTROLLER_ROWS_PER_PERM_FELT, CONTROLLER_ROWS_PER_PERMUTATION, STATE_WIDTH,
 };
-use miden_core::{FMP_ADDR, FMP_INIT_VALUE, operations::Operation};
+use miden_core::{FMP_ADDR, FMP_INIT_VALUE, operations::Operation, serde::Serializable};
 
 use super::{
+    advice_timeline::{AdviceEvent, AdviceTimelineRecorder},
     block_stack::{BlockInfo, BlockStack, ExecutionContextInfo},
+    fragment_store::{CoreTraceFragmentStore, TraceFragmentStoreError},
     stack::OverflowTable,
     trace_state::{
         AceReplay, AdviceReplay, BitwiseReplay, BlockAddressReplay, BlockStackReplay,
@@ -18,7 +20,8 @@ use super::{
     utils::split_u32_into_u16,
 };
 use crate::{
-    ContextId, EMPTY_WORD, FastProcessor, Felt, MIN_STACK_DEPTH, ONE, RowIndex, Word, ZERO,
+    ContextId, EMPTY_WORD, ExecutionError, FastProcessor, Felt, MIN_STACK_DEPTH, ONE, RowIndex,
+    Word, ZERO,
     continuation_stack::{Continuation, ContinuationStack},
     crypto::merkle::MerklePath,
     mast::{
@@ -51,8 +54,21 @@ struct StateSnapshot {
 #[derive(Debug)]
 pub struct TraceGenerationContext {
     /// The list of trace fragment contexts built during execution.
+    ///
+    /// Empty if the fragment contexts were spilled to `core_trace_fragment_store` instead.
     pub core_trace_contexts: Vec<CoreTraceFragmentContext>,
 
+    /// The store holding the serialized trace fragment contexts, if they were spilled to a
+    /// [`CoreTraceFragmentStore`] during execution.
+    pub core_trace_fragment_store: Option<Box<dyn CoreTraceFragmentStore>>,
+
+    /// The memory reads of the fragment contexts in `core_trace_fragment_store`, in the order the
+    /// contexts were spilled.
+    ///
+    /// The memory chiplet needs the reads of all fragments at once, so these are kept in memory
+    /// rather than being spilled along with the rest of each fragment context.
+    pub core_trace_memory_reads: Vec<MemoryReadsReplay>,
+
     /// Sparse MAST forests, one per source [`MastForest`] visited during execution.
     ///
     /// Each entry contains only the [`MastNode`]s that were actually visited, while preserving the
@@ -77,6 +93,15 @@ pub struct TraceGenerationContext {
     /// The maximum number of field elements allowed on the operand stack in an active execution
     /// context.
     pub max_stack_depth: usize,
+
+    /// The ordered list of operations executed inside basic blocks, if recording was enabled.
+    pub op_log: Option<Vec<(RowIndex, Operation)>>,
+
+    /// The ordered list of changes made to the advice stack, if recording was enabled.
+    pub advice_timeline: Option<Vec<AdviceEvent>>,
+
+    /// The control-flow nesting depth at each control-flow boundary, if recording was enabled.
+    pub depth_profile: Option<Vec<(RowIndex, usize)>>,
 }
 
 /// Builder for recording the context to generate trace fragments during execution.
@@ -126,6 +151,16 @@ pub struct ExecutionTracer {
     // Output
     fragment_contexts: Vec<CoreTraceFragmentContext>,
 
+    /// The store to which completed fragment contexts are spilled instead of being kept in
+    /// `fragment_contexts`. `None` unless set via [`Self::with_fragment_store`].
+    fragment_store: Option<Box<dyn CoreTraceFragmentStore>>,
+
+    /// The first error returned by `fragment_store`; once set, no more fragments are spilled.
+    fragment_store_error: Option<TraceFragmentStoreError>,
+
+    /// The memory reads of the fragment contexts spilled to `fragment_store`.
+    fragment_memory_reads: Vec<MemoryReadsReplay>,
+
     /// Per-source-forest sparse builders, indexed by `mast_forest_indices`.
     ///
     /// Each builder accumulates the [`MastNodeId`]s of nodes visited inside its source forest
@@ -158,6 +193,18 @@ pub struct ExecutionTracer {
     /// Flag set in `start_clock_cycle` when an `EvalCircuit` operation is encountered, consumed
     /// in `finalize_clock_cycle` to record the memory reads performed by the operation.
     is_eval_circuit_op: bool,
+
+    /// The ordered list of executed basic block operations, together with the clock cycle at
+    /// which each was executed. `None` unless enabled via [`Self::with_op_log`].
+    op_log: Option<Vec<(RowIndex, Operation)>>,
+
+    /// Recorder of the changes made to the advice stack. `None` unless enabled via
+    /// [`Self::with_advice_timeline`].
+    advice_timeline: Option<AdviceTimelineRecorder>,
+
+    /// The control-flow nesting depth right after each control-flow boundary, together with the
+    /// clock cycle of the boundary. `None` unless enabled via [`Self::with_depth_profile`].
+    depth_profile: Option<Vec<(RowIndex, usize)>>,
 }
 
 impl ExecutionTracer {
@@ -182,15 +229,45 @@ impl ExecutionTracer {
             ace: AceReplay::default(),
             external: MastForestResolutionReplay::default(),
             fragment_contexts: Vec::new(),
+            fragment_store: None,
+            fragment_store_error: None,
+            fragment_memory_reads: Vec::new(),
             mast_forest_builders: Vec::new(),
             mast_forest_ids: BTreeMap::new(),
             fragment_size,
             max_stack_depth,
             pending_restore_context: false,
             is_eval_circuit_op: false,
+            op_log: None,
+            advice_timeline: None,
+            depth_profile: None,
         }
     }
 
+    /// Spills completed fragment contexts to `store` instead of keeping them in memory.
+    pub fn with_fragment_store(mut self, store: Box<dyn CoreTraceFragmentStore>) -> Self {
+        self.fragment_store = Some(store);
+        self
+    }
+
+    /// Enables or disables recording of the executed operations.
+    pub fn with_op_log(mut self, enabled: bool) -> Self {
+        self.op_log = enabled.then(Vec::new);
+        self
+    }
+
+    /// Enables or disables recording of the changes made to the advice stack.
+    pub fn with_advice_timeline(mut self, enabled: bool) -> Self {
+        self.advice_timeline = enabled.then(AdviceTimelineRecorder::default);
+        self
+    }
+
+    /// Enables or disables recording of the control-flow nesting depth.
+    pub fn with_depth_profile(mut self, enabled: bool) -> Self {
+        self.depth_profile = enabled.then(Vec::new);
+        self
+    }
+
     /// Returns the [`MastForestId`] of `forest` in [`Self::mast_forest_builders`], creating a new
     /// builder for it on first encounter. Forests are identified by `Arc::as_ptr`.
     #[inline]
@@ -267,10 +344,18 @@ impl ExecutionTracer {
 
     /// Convert the `ExecutionTracer` into a [TraceGenerationContext] using the data accumulated
     /// during execution.
+    ///
+    /// # Errors
+    /// Returns an error if a fragment context could not be spilled to the fragment store.
     #[inline(always)]
-    pub fn into_trace_generation_context(mut self) -> TraceGenerationContext {
+    pub fn into_trace_generation_context(
+        mut self,
+    ) -> Result<TraceGenerationContext, ExecutionError> {
         // If there is an ongoing trace state being built, finish it
         self.finish_current_fragment_context();
+        if let Some(err) = self.fragment_store_error {
+            return Err(ExecutionError::TraceFragmentStoreError(err));
+        }
 
         // Finalize each per-source-forest builder into a `SparseMastForest`. Indices stored on
         // fragments and replays line up with the position in this vector by construction (the
@@ -281,8 +366,10 @@ impl ExecutionTracer {
             .map(|builder| Arc::new(builder.finalize()))
             .collect();
 
-        TraceGenerationContext {
+        Ok(TraceGenerationContext {
             core_trace_contexts: self.fragment_contexts,
+            core_trace_fragment_store: self.fragment_store,
+            core_trace_memory_reads: self.fragment_memory_reads,
             mast_forest_store,
             range_checker_replay: self.range_checker,
             memory_writes: self.memory_writes,
@@ -292,7 +379,10 @@ impl ExecutionTracer {
             ace_replay: self.ace,
             fragment_size: self.fragment_size,
             max_stack_depth: self.max_stack_depth,
-        }
+            op_log: self.op_log,
+            advice_timeline: self.advice_timeline.map(AdviceTimelineRecorder::into_events),
+            depth_profile: self.depth_profile,
+        })
     }
 
     // HELPERS
@@ -530,7 +620,7 @@ impl ExecutionTracer {
             let block_stack_replay = core::mem::take(&mut self.block_stack_replay);
             let execution_context_replay = core::mem::take(&mut self.execution_context_replay);
 
-            let trace_state = CoreTraceFragmentContext {
+            let mut trace_state = CoreTraceFragmentContext {
                 state: snapshot.state,
                 replay: ExecutionReplay {
                     hasher: hasher_replay,
@@ -546,7 +636,18 @@ impl ExecutionTracer {
                 initial_mast_forest_id: snapshot.initial_mast_forest_id,
             };
 
-            self.fragment_contexts.push(trace_state);
+            match &mut self.fragment_store {
+                Some(store) => {
+                    let memory_reads = core::mem::take(&mut trace_state.replay.memory_reads);
+                    self.fragment_memory_reads.push(memory_reads);
+                    if self.fragment_store_error.is_none()
+                        && let Err(err) = store.push(&trace_state.to_bytes())
+                    {
+                        self.fragment_store_error = Some(err);
+                    }
+                },
+                None => self.fragment_contexts.push(trace_state),
+            }
         }
     }
 
@@ -583,6 +684,10 @@ impl Tracer for ExecutionTracer {
         continuation_stack: &ContinuationStack<Arc<MastForest>>,
         current_forest: &Arc<MastForest>,
     ) {
+        if let Some(timeline) = self.advice_timeline.as_mut() {
+            timeline.start_clock_cycle(processor.system().clock(), processor.advice_provider());
+        }
+
         // check if we need to start a new trace state
         if processor.system().clock().as_usize().is_multiple_of(self.fragment_size) {
             self.start_new_fragment_context(
@@ -605,6 +710,17 @@ impl Tracer for ExecutionTracer {
             self.record_visit(current_forest, visited_node_id);
         }
 
+        let is_control_flow_boundary = matches!(
+            continuation,
+            Continuation::StartNode(_)
+                | Continuation::FinishJoin(_)
+                | Continuation::FinishSplit(_)
+                | Continuation::FinishCall(_)
+                | Continuation::FinishDyn(_)
+                | Continuation::FinishLoop(_)
+                | Continuation::FinishBasicBlock(_)
+        );
+
         match continuation {
             Continuation::ResumeBasicBlock { node_id, batch_index, op_idx_in_batch } => {
                 // Update overflow table based on whether the operation increments or decrements
@@ -621,6 +737,10 @@ impl Tracer for ExecutionTracer {
                 if matches!(op, Operation::EvalCircuit) {
                     self.is_eval_circuit_op = true;
                 }
+
+                if let Some(op_log) = self.op_log.as_mut() {
+                    op_log.push((processor.system().clock(), *op));
+                }
             },
             Continuation::StartNode(mast_node_id) => match &current_forest[mast_node_id] {
                 MastNode::Join(_) | MastNode::Loop(_) => {
@@ -716,6 +836,10 @@ impl Tracer for ExecutionTracer {
                 panic!("EnterForest continuations are guaranteed not to be passed here")
             },
         }
+
+        if is_control_flow_boundary && let Some(depth_profile) = self.depth_profile.as_mut() {
+            depth_profile.push((processor.system().clock(), self.block_stack.len()));
+        }
     }
 
     #[inline(always)]
@@ -872,6 +996,10 @@ impl Tracer for ExecutionTracer {
     #[inline(always)]
     fn record_pipe(&mut self, words: [Word; 2], addr: Felt, ctx: ContextId, clk: RowIndex) {
         self.advice.record_pop_stack_dword(words);
+        if let Some(timeline) = self.advice_timeline.as_mut() {
+            timeline.record_pop_stack_word(words[0]);
+            timeline.record_pop_stack_word(words[1]);
+        }
         self.memory_writes.record_write_word(words[0], addr, ctx, clk);
         self.memory_writes.record_write_word(words[1], addr + PTR_OFFSET_WORD, ctx, clk);
     }
@@ -879,11 +1007,17 @@ impl Tracer for ExecutionTracer {
     #[inline(always)]
     fn record_advice_pop_stack(&mut self, value: Felt) {
         self.advice.record_pop_stack(value);
+        if let Some(timeline) = self.advice_timeline.as_mut() {
+            timeline.record_pop_stack(value);
+        }
     }
 
     #[inline(always)]
     fn record_advice_pop_stack_word(&mut self, word: Word) {
         self.advice.record_pop_stack_word(word);
+        if let Some(timeline) = self.advice_timeline.as_mut() {
+            timeline.record_pop_stack_word(word);
+        }
     }
 
     #[inline(always)]
@@ -968,6 +1102,10 @@ impl Tracer for ExecutionTracer {
 
             self.is_eval_circuit_op = false;
         }
+
+        if let Some(timeline) = self.advice_timeline.as_mut() {
+            timeline.finalize_clock_cycle(processor.advice_provider());
+        }
     }
 }
 
@@ -0,0 +1,132 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{error::Error, fmt::Debug};
+
+// CORE TRACE FRAGMENT STORE
+// ================================================================================================
+
+/// A generic [`Error`] wrapper for the errors returned by a [`CoreTraceFragmentStore`].
+pub type TraceFragmentStoreError = Box<dyn Error + Send + Sync + 'static>;
+
+/// Storage for the core trace fragment contexts recorded while executing a program for trace
+/// generation.
+///
+/// By default, the context needed to generate each fragment of the core trace is kept in memory
+/// until the trace is built, which for long-running programs can take up a significant amount of
+/// memory. A fragment store instead receives each fragment context in serialized form as soon as
+/// the fragment is complete, and hands it back while [`super::build_trace`] generates the
+/// corresponding part of the trace. This way, only the fragments currently being processed need
+/// to be held in memory.
+///
+/// A store is attached to an execution via
+/// [`crate::FastProcessor::execute_trace_inputs_with_fragment_store_sync`].
+pub trait CoreTraceFragmentStore: Debug + Send + Sync {
+    /// Appends a serialized fragment context to the store.
+    fn push(&mut self, fragment: &[u8]) -> Result<(), TraceFragmentStoreError>;
+
+    /// Returns the number of fragment contexts in the store.
+    fn len(&self) -> usize;
+
+    /// Returns true if the store does not hold any fragment context.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the serialized fragment context at `index`, in the order the contexts were pushed.
+    ///
+    /// Trace generation processes fragments in parallel, so this may be called concurrently from
+    /// multiple threads.
+    fn read(&self, index: usize) -> Result<Vec<u8>, TraceFragmentStoreError>;
+}
+
+// FILE FRAGMENT STORE
+// ================================================================================================
+
+/// A [`CoreTraceFragmentStore`] which spills fragment contexts to a file.
+///
+/// Fragment contexts are appended to the file as they are pushed, and read back from their offset
+/// without moving the file cursor, so that concurrent reads do not need to be synchronized. The
+/// file is not removed when the store is dropped.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct FileFragmentStore {
+    file: std::fs::File,
+    /// The offset and length of each fragment context in the file.
+    fragments: Vec<(u64, usize)>,
+    end: u64,
+}
+
+#[cfg(feature = "std")]
+impl FileFragmentStore {
+    /// Creates a store which spills fragment contexts to a file at `path`, truncating the file if
+    /// it already exists.
+    pub fn create(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        Ok(Self { file, fragments: Vec::new(), end: 0 })
+    }
+}
+
+#[cfg(feature = "std")]
+impl CoreTraceFragmentStore for FileFragmentStore {
+    fn push(&mut self, fragment: &[u8]) -> Result<(), TraceFragmentStoreError> {
+        use std::io::{Seek, SeekFrom, Write};
+
+        self.file.seek(SeekFrom::Start(self.end))?;
+        self.file.write_all(fragment)?;
+
+        self.fragments.push((self.end, fragment.len()));
+        self.end += fragment.len() as u64;
+        Ok(())
+    }
+
+    fn len(&self) -> usize {
+        self.fragments.len()
+    }
+
+    fn read(&self, index: usize) -> Result<Vec<u8>, TraceFragmentStoreError> {
+        let &(offset, len) = self.fragments.get(index).ok_or("fragment index out of bounds")?;
+        let mut fragment = alloc::vec![0; len];
+        read_exact_at(&self.file, &mut fragment, offset)?;
+        Ok(fragment)
+    }
+}
+
+/// Fills `buf` with the contents of `file` starting at `offset`, without using the file cursor.
+#[cfg(all(feature = "std", unix))]
+fn read_exact_at(file: &std::fs::File, buf: &mut [u8], offset: u64) -> std::io::Result<()> {
+    std::os::unix::fs::FileExt::read_exact_at(file, buf, offset)
+}
+
+/// Fills `buf` with the contents of `file` starting at `offset`.
+///
+/// On Windows, this moves the file cursor, which is why [`FileFragmentStore::push`] always seeks
+/// to the end of the fragments before writing.
+#[cfg(all(feature = "std", windows))]
+fn read_exact_at(file: &std::fs::File, mut buf: &mut [u8], mut offset: u64) -> std::io::Result<()> {
+    use std::{io::ErrorKind, os::windows::fs::FileExt};
+
+    while !buf.is_empty() {
+        match file.seek_read(buf, offset) {
+            Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+            Ok(n) => {
+                buf = &mut buf[n..];
+                offset += n as u64;
+            },
+            Err(err) if err.kind() == ErrorKind::Interrupted => {},
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(all(feature = "std", not(any(unix, windows))))]
+fn read_exact_at(_file: &std::fs::File, _buf: &mut [u8], _offset: u64) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "reading fragments back from a file is not supported on this platform",
+    ))
+}
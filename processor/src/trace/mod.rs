@@ -1,4 +1,4 @@
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 #[cfg(any(test, feature = "testing"))]
 use core::ops::Range;
 
@@ -6,7 +6,9 @@ use miden_air::{
     MidenMultiAir, ProverStatement, PublicInputs, StarkConfig, Statement, config, debug,
     trace::{MainTrace, decoder::NUM_USER_OP_HELPERS},
 };
-use miden_core::deferred::DeferredState;
+use miden_core::{
+    crypto::hash::Poseidon2, deferred::DeferredState, events::EventId, operations::Operation,
+};
 
 use crate::{
     Felt, MIN_STACK_DEPTH, Program, ProgramInfo, StackInputs, StackOutputs, Word, ZERO,
@@ -19,7 +21,9 @@ use utils::ChipletTraceFragment;
 pub mod chiplets;
 pub(crate) mod execution_tracer;
 
+mod advice_timeline;
 mod block_stack;
+mod fragment_store;
 mod parallel;
 mod range;
 mod stack;
@@ -31,9 +35,16 @@ mod tests;
 // RE-EXPORTS
 // ================================================================================================
 
+pub use advice_timeline::AdviceEvent;
 pub use execution_tracer::TraceGenerationContext;
+#[cfg(feature = "std")]
+pub use fragment_store::FileFragmentStore;
+pub use fragment_store::{CoreTraceFragmentStore, TraceFragmentStoreError};
 pub use miden_air::trace::RowIndex;
-pub use parallel::{CORE_TRACE_WIDTH, build_trace, build_trace_with_max_len};
+pub use parallel::{
+    CORE_TRACE_WIDTH, FragmentScheduling, build_trace, build_trace_with_max_len,
+    build_trace_with_scheduling,
+};
 pub use utils::{ChipletsLengths, TraceLenSummary};
 
 /// Inputs required to build an execution trace from pre-executed data.
@@ -57,6 +68,7 @@ impl TraceBuildOutput {
             advice: _,
             memory: _,
             deferred_state,
+            reports: _,
         } = execution_output;
 
         Self { stack_outputs: stack, deferred_state }
@@ -117,6 +129,10 @@ impl TraceBuildInputs {
 /// - Information about the program (program hash and the kernel).
 /// - Information about execution outputs (stack state and final deferred state).
 /// - Summary of trace lengths of the main trace components.
+/// - Optionally, the ordered log of executed operations (see [`ExecutionTrace::op_log`]).
+/// - Optionally, the ordered changes made to the advice stack (see
+///   [`ExecutionTrace::advice_timeline`]).
+/// - Optionally, the control-flow nesting depth over time (see [`ExecutionTrace::depth_profile`]).
 #[derive(Debug)]
 pub struct ExecutionTrace {
     main_trace: MainTrace,
@@ -124,6 +140,9 @@ pub struct ExecutionTrace {
     stack_outputs: StackOutputs,
     deferred_state: DeferredState,
     trace_len_summary: TraceLenSummary,
+    op_log: Vec<(RowIndex, Operation)>,
+    advice_timeline: Vec<AdviceEvent>,
+    depth_profile: Vec<(RowIndex, usize)>,
 }
 
 impl ExecutionTrace {
@@ -144,9 +163,30 @@ impl ExecutionTrace {
             stack_outputs,
             deferred_state,
             trace_len_summary,
+            op_log: Vec::new(),
+            advice_timeline: Vec::new(),
+            depth_profile: Vec::new(),
         }
     }
 
+    /// Attaches the log of executed operations recorded during execution.
+    pub(crate) fn with_op_log(mut self, op_log: Vec<(RowIndex, Operation)>) -> Self {
+        self.op_log = op_log;
+        self
+    }
+
+    /// Attaches the changes made to the advice stack recorded during execution.
+    pub(crate) fn with_advice_timeline(mut self, advice_timeline: Vec<AdviceEvent>) -> Self {
+        self.advice_timeline = advice_timeline;
+        self
+    }
+
+    /// Attaches the control-flow nesting depth samples recorded during execution.
+    pub(crate) fn with_depth_profile(mut self, depth_profile: Vec<(RowIndex, usize)>) -> Self {
+        self.depth_profile = depth_profile;
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -195,6 +235,118 @@ impl ExecutionTrace {
         &self.deferred_state
     }
 
+    /// Returns the ordered list of operations executed by the VM, together with the clock cycle
+    /// at which each of them was executed.
+    ///
+    /// The log includes every operation executed inside a basic block, including `NOOP`s inserted
+    /// during batching. Control-flow rows (e.g. `SPAN`, `END`, `REPEAT`) are not [`Operation`]s
+    /// and are therefore not part of the log.
+    ///
+    /// The log is empty unless recording was enabled via
+    /// [`crate::ExecutionOptions::with_op_log`].
+    pub fn op_log(&self) -> &[(RowIndex, Operation)] {
+        &self.op_log
+    }
+
+    /// Returns the ordered list of values pushed onto and popped off the advice stack during
+    /// execution.
+    ///
+    /// Pushes performed by system events and host event handlers are attributed to the clock cycle
+    /// of the `EMIT` operation which triggered them. The initial contents of the advice stack are
+    /// not part of the timeline.
+    ///
+    /// The timeline is empty unless recording was enabled via
+    /// [`crate::ExecutionOptions::with_advice_timeline`].
+    pub fn advice_timeline(&self) -> &[AdviceEvent] {
+        &self.advice_timeline
+    }
+
+    /// Returns the control-flow nesting depth sampled at each control-flow boundary, together
+    /// with the clock cycle of the boundary.
+    ///
+    /// A sample is recorded for every row which starts a MAST node (e.g. `JOIN`, `CALL`, `SPAN`)
+    /// or ends one (`END`, `REPEAT`). The depth is the number of MAST nodes being executed after
+    /// the row, so the sample for a node's start includes the node itself, and the sample for its
+    /// `END` does not.
+    ///
+    /// The profile is empty unless recording was enabled via
+    /// [`crate::ExecutionOptions::with_depth_profile`].
+    pub fn depth_profile(&self) -> &[(RowIndex, usize)] {
+        &self.depth_profile
+    }
+
+    /// Returns the number of times each operation was executed, keyed by operation mnemonic.
+    ///
+    /// The histogram is built from [`Self::op_log`], and is therefore empty unless recording was
+    /// enabled via [`crate::ExecutionOptions::with_op_log`]. Operations carrying an immediate value
+    /// (e.g. `push`) are counted under a single mnemonic regardless of the value.
+    pub fn op_histogram(&self) -> BTreeMap<&'static str, usize> {
+        let mut histogram = BTreeMap::new();
+        for (_, op) in self.op_log.iter() {
+            *histogram.entry(op.mnemonic()).or_default() += 1;
+        }
+        histogram
+    }
+
+    /// Returns the IDs of all events emitted by the program, in the order in which they were
+    /// emitted.
+    ///
+    /// This includes system events (e.g. those triggered by `adv.push_mapval`) as well as events
+    /// handled by the host.
+    pub fn emitted_event_ids(&self) -> Vec<EventId> {
+        let emit_op_code = Felt::from_u8(Operation::Emit.op_code());
+        (0..=self.main_trace.last_program_row().as_usize())
+            .map(RowIndex::from)
+            .filter(|&row| self.main_trace.get_op_code(row) == emit_op_code)
+            .map(|row| EventId::from_felt(self.main_trace.stack_element(0, row)))
+            .collect()
+    }
+
+    /// Returns a digest of the observable behavior of the execution which produced this trace.
+    ///
+    /// The fingerprint commits to the stack outputs, the final contents of all memory words
+    /// touched during execution, the sequence of event IDs emitted by the program, and the final
+    /// deferred root (which commits to all precompile requests). Two executions with identical
+    /// observable behavior produce identical fingerprints, regardless of how the traces were
+    /// laid out, which makes this suitable for differential testing across VM versions.
+    pub fn behavior_fingerprint(&self) -> Word {
+        let main_trace = &self.main_trace;
+
+        // memory chiplet rows are sorted by context, address, and clock cycle, so the last row
+        // for each word holds its final value
+        let mut memory = BTreeMap::new();
+        for row in 0..main_trace.chiplets_height() {
+            let row = RowIndex::from(row);
+            if main_trace.is_memory_row(row) {
+                let key = (
+                    main_trace.chiplet_memory_ctx(row).as_canonical_u64(),
+                    main_trace.chiplet_memory_word(row).as_canonical_u64(),
+                );
+                let value = [
+                    main_trace.chiplet_memory_value_0(row),
+                    main_trace.chiplet_memory_value_1(row),
+                    main_trace.chiplet_memory_value_2(row),
+                    main_trace.chiplet_memory_value_3(row),
+                ];
+                memory.insert(key, value);
+            }
+        }
+        let memory_elements = memory
+            .into_iter()
+            .flat_map(|((ctx, addr), value)| {
+                [Felt::new_unchecked(ctx), Felt::new_unchecked(addr)].into_iter().chain(value)
+            })
+            .collect::<Vec<_>>();
+
+        let event_ids = self.emitted_event_ids().iter().map(EventId::as_felt).collect::<Vec<_>>();
+
+        let mut elements = self.stack_outputs.to_vec();
+        elements.extend(Poseidon2::hash_elements(&memory_elements));
+        elements.extend(Poseidon2::hash_elements(&event_ids));
+        elements.extend(self.deferred_state.root());
+        Poseidon2::hash_elements(&elements)
+    }
+
     /// Returns the owned stack outputs required for proof packaging.
     pub fn into_outputs(self) -> StackOutputs {
         self.stack_outputs
@@ -277,6 +429,17 @@ impl ExecutionTrace {
         debug::check_constraints(&prover_statement, config.challenger());
     }
 
+    /// Records on which rows of this trace each AIR constraint was active into `coverage`.
+    ///
+    /// Recording a corpus of traces into the same [`ConstraintCoverage`] reports the constraints
+    /// which the corpus never exercises.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn record_constraint_coverage(&self, coverage: &mut miden_air::ConstraintCoverage) {
+        let (core_matrix, chiplets_matrix, poseidon2_matrix) = self.main_trace.to_air_matrices();
+        let (public_values, _) = self.public_inputs().to_air_inputs();
+        coverage.record(&[core_matrix, chiplets_matrix, poseidon2_matrix], &public_values);
+    }
+
     /// Splits the trace into the per-AIR matrices consumed by the multi-AIR proving path.
     pub fn to_air_matrices(
         &self,
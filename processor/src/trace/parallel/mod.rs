@@ -15,18 +15,20 @@ use miden_core::{
     mast::{ExecutableMastForest, MastForestId, MastNode, SparseMastForest},
     operations::opcodes,
     program::{KernelDescriptor, MIN_STACK_DEPTH},
+    serde::Deserializable,
     utils::Idx,
 };
-use rayon::prelude::*;
+use rayon::{iter::Either, prelude::*};
 use tracing::instrument;
 
 use super::{
     chiplets::Chiplets,
     execution_tracer::TraceGenerationContext,
+    fragment_store::CoreTraceFragmentStore,
     trace_state::{
         AceReplay, BitwiseOp, BitwiseReplay, CoreTraceFragmentContext, CoreTraceState,
-        ExecutionReplay, HasherOp, HasherRequestReplay, KernelReplay, MemoryWritesReplay,
-        RangeCheckerReplay,
+        ExecutionReplay, HasherOp, HasherRequestReplay, KernelReplay, MemoryReadsReplay,
+        MemoryWritesReplay, RangeCheckerReplay,
     },
 };
 use crate::{
@@ -52,7 +54,7 @@ pub const CORE_STORAGE_WIDTH: usize = CORE_TRACE_WIDTH + RANGE_CHECK_TRACE_WIDTH
 
 /// `build_trace()` uses this as a hard cap on trace rows.
 ///
-/// The code checks `core_trace_contexts.len() * fragment_size` before allocation. It checks the
+/// The code checks `core_trace_fragments.len() * fragment_size` before allocation. It checks the
 /// same cap again while replaying chiplet activity. This keeps memory use bounded.
 const MAX_TRACE_LEN: usize = 1 << 29;
 
@@ -64,6 +66,22 @@ mod tracer;
 #[cfg(test)]
 mod tests;
 
+// FRAGMENT SCHEDULING
+// ================================================================================================
+
+/// Specifies how the fragments of the core trace are scheduled during trace generation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FragmentScheduling {
+    /// Fragments are generated concurrently on the current rayon thread pool.
+    #[default]
+    Parallel,
+    /// Fragments are generated one at a time, in order.
+    ///
+    /// When the fragment contexts are kept in a [`CoreTraceFragmentStore`], only one of them is
+    /// loaded at any given time, which bounds the peak memory used by trace generation.
+    Sequential,
+}
+
 // BUILD TRACE
 // ================================================================================================
 
@@ -99,6 +117,23 @@ pub fn build_trace(inputs: TraceBuildInputs) -> Result<ExecutionTrace, Execution
 pub fn build_trace_with_max_len(
     inputs: TraceBuildInputs,
     max_trace_len: usize,
+) -> Result<ExecutionTrace, ExecutionError> {
+    build_trace_impl(inputs, max_trace_len, FragmentScheduling::default())
+}
+
+/// Same as [`build_trace`], but generates the core trace fragments according to `scheduling`.
+#[instrument(name = "build_trace", skip_all)]
+pub fn build_trace_with_scheduling(
+    inputs: TraceBuildInputs,
+    scheduling: FragmentScheduling,
+) -> Result<ExecutionTrace, ExecutionError> {
+    build_trace_impl(inputs, MAX_TRACE_LEN, scheduling)
+}
+
+fn build_trace_impl(
+    inputs: TraceBuildInputs,
+    max_trace_len: usize,
+    scheduling: FragmentScheduling,
 ) -> Result<ExecutionTrace, ExecutionError> {
     let TraceBuildInputs {
         trace_output,
@@ -108,6 +143,8 @@ pub fn build_trace_with_max_len(
 
     let TraceGenerationContext {
         core_trace_contexts,
+        core_trace_fragment_store,
+        core_trace_memory_reads,
         mast_forest_store,
         range_checker_replay,
         memory_writes,
@@ -117,15 +154,26 @@ pub fn build_trace_with_max_len(
         ace_replay,
         fragment_size,
         max_stack_depth,
+        op_log,
+        advice_timeline,
+        depth_profile,
     } = trace_generation_context;
 
+    let core_trace_fragments = match core_trace_fragment_store {
+        Some(store) => CoreTraceFragments::Stored {
+            store,
+            memory_reads: core_trace_memory_reads,
+        },
+        None => CoreTraceFragments::InMemory(core_trace_contexts),
+    };
+
     // Before any trace generation, check that the number of core trace rows doesn't exceed the
     // maximum trace length. This is a necessary check to avoid OOM panics during trace generation,
     // which can occur if the execution produces an extremely large number of steps.
     //
     // Note that we add 1 to the total core trace rows to account for the additional HALT opcode row
     // that is pushed at the end of the last fragment.
-    let total_core_trace_rows = core_trace_contexts
+    let total_core_trace_rows = core_trace_fragments
         .len()
         .checked_mul(fragment_size)
         .and_then(|n| n.checked_add(1))
@@ -134,15 +182,22 @@ pub fn build_trace_with_max_len(
         return Err(ExecutionError::TraceLenExceeded(max_trace_len));
     }
 
-    if core_trace_contexts.is_empty() {
+    if core_trace_fragments.len() == 0 {
         return Err(ExecutionError::Internal(
             "no trace fragments provided in the trace generation context",
         ));
     }
 
+    let memory_reads: Vec<&MemoryReadsReplay> = match &core_trace_fragments {
+        CoreTraceFragments::InMemory(contexts) => {
+            contexts.iter().map(|ctx| &ctx.replay.memory_reads).collect()
+        },
+        CoreTraceFragments::Stored { memory_reads, .. } => memory_reads.iter().collect(),
+    };
+
     let chiplets = initialize_chiplets(
         program_info.kernel().clone(),
-        &core_trace_contexts,
+        &memory_reads,
         memory_writes,
         bitwise,
         kernel_replay,
@@ -155,11 +210,12 @@ pub fn build_trace_with_max_len(
     let range_checker = initialize_range_checker(range_checker_replay, &chiplets);
 
     let mut core_trace_data = generate_core_trace_row_major(
-        core_trace_contexts,
+        core_trace_fragments,
         program_info.kernel().clone(),
         fragment_size,
         &mast_forest_store,
         max_stack_depth,
+        scheduling,
     )?;
 
     let core_trace_len = core_trace_data.len() / CORE_STORAGE_WIDTH;
@@ -214,17 +270,52 @@ pub fn build_trace_with_max_len(
         )
     };
 
-    Ok(ExecutionTrace::new_from_parts(
-        program_info,
-        trace_output,
-        main_trace,
-        trace_len_summary,
-    ))
+    Ok(
+        ExecutionTrace::new_from_parts(program_info, trace_output, main_trace, trace_len_summary)
+            .with_op_log(op_log.unwrap_or_default())
+            .with_advice_timeline(advice_timeline.unwrap_or_default())
+            .with_depth_profile(depth_profile.unwrap_or_default()),
+    )
 }
 
 // HELPERS
 // ================================================================================================
 
+/// The core trace fragment contexts recorded during execution.
+enum CoreTraceFragments {
+    /// Fragment contexts which were kept in memory during execution.
+    InMemory(Vec<CoreTraceFragmentContext>),
+    /// Fragment contexts which were spilled to a fragment store during execution, along with the
+    /// memory reads which were taken out of them before spilling.
+    Stored {
+        store: Box<dyn CoreTraceFragmentStore>,
+        memory_reads: Vec<MemoryReadsReplay>,
+    },
+}
+
+impl CoreTraceFragments {
+    fn len(&self) -> usize {
+        match self {
+            Self::InMemory(contexts) => contexts.len(),
+            Self::Stored { store, .. } => store.len(),
+        }
+    }
+}
+
+/// Reads the fragment context at `index` back from the fragment store, and restores its
+/// `memory_reads`.
+fn load_fragment(
+    store: &dyn CoreTraceFragmentStore,
+    index: usize,
+    memory_reads: MemoryReadsReplay,
+) -> Result<CoreTraceFragmentContext, ExecutionError> {
+    let bytes = store.read(index).map_err(ExecutionError::TraceFragmentStoreError)?;
+    let mut context = CoreTraceFragmentContext::read_from_bytes(&bytes)
+        .map_err(|err| ExecutionError::TraceFragmentStoreError(Box::new(err)))?;
+    context.replay.memory_reads = memory_reads;
+    Ok(context)
+}
+
 /// Pad a logical row count to a valid trace length: next power of two, clamped to `MIN_TRACE_LEN`.
 fn pad_to_trace_length(logical_len: usize) -> usize {
     logical_len.next_power_of_two().max(MIN_TRACE_LEN)
@@ -232,22 +323,28 @@ fn pad_to_trace_length(logical_len: usize) -> usize {
 
 /// Generates row-major core trace in parallel from the provided trace fragment contexts.
 fn generate_core_trace_row_major(
-    core_trace_contexts: Vec<CoreTraceFragmentContext>,
+    core_trace_fragments: CoreTraceFragments,
     kernel: KernelDescriptor,
     fragment_size: usize,
     mast_forest_store: &[Arc<SparseMastForest>],
     max_stack_depth: usize,
+    scheduling: FragmentScheduling,
 ) -> Result<Vec<Felt>, ExecutionError> {
-    let num_fragments = core_trace_contexts.len();
+    let num_fragments = core_trace_fragments.len();
     let total_allocated_rows = num_fragments * fragment_size;
 
     let mut core_trace_data = Felt::zero_vec(total_allocated_rows * CORE_STORAGE_WIDTH);
 
-    // Save the first stack top for initialization
-    let first_stack_top = if let Some(first_context) = core_trace_contexts.first() {
-        first_context.state.stack.stack_top.to_vec()
-    } else {
-        vec![ZERO; MIN_STACK_DEPTH]
+    // Stored fragment contexts are loaded lazily, so that only the fragments currently being
+    // processed are held in memory.
+    let fragments = match core_trace_fragments {
+        CoreTraceFragments::InMemory(contexts) => Either::Left(contexts.into_par_iter().map(Ok)),
+        CoreTraceFragments::Stored { store, memory_reads } => Either::Right(
+            memory_reads
+                .into_par_iter()
+                .enumerate()
+                .map(move |(index, memory_reads)| load_fragment(&*store, index, memory_reads)),
+        ),
     };
 
     let writers: Vec<RowMajorTraceWriter<'_, Felt>> = core_trace_data
@@ -257,11 +354,20 @@ fn generate_core_trace_row_major(
         })
         .collect();
 
-    // Build the core trace fragments in parallel
-    let fragment_results: Result<Vec<_>, ExecutionError> = core_trace_contexts
-        .into_par_iter()
+    // Build the core trace fragments in parallel, unless they must be generated one at a time; a
+    // single rayon task walks over all fragments in order.
+    let min_fragments_per_task = match scheduling {
+        FragmentScheduling::Parallel => 1,
+        FragmentScheduling::Sequential => num_fragments,
+    };
+    let fragment_results: Result<Vec<_>, ExecutionError> = fragments
         .zip(writers.into_par_iter())
-        .map(|(trace_state, writer)| {
+        .enumerate()
+        .with_min_len(min_fragments_per_task)
+        .map(|(index, (trace_state, writer))| {
+            let trace_state = trace_state?;
+            // The stack top of the first fragment is needed to initialize the first row
+            let first_stack_top = (index == 0).then(|| trace_state.state.stack.stack_top.to_vec());
             let (mut processor, mut tracer, mut continuation_stack, mut current_forest) =
                 split_trace_fragment_context(
                     trace_state,
@@ -278,16 +384,18 @@ fn generate_core_trace_row_major(
                 &mut tracer,
             )?;
 
-            tracer.into_final_state()
+            Ok((first_stack_top, tracer.into_final_state()?))
         })
         .collect();
     let fragment_results = fragment_results?;
 
+    let mut first_stack_top = None;
     let mut stack_rows = Vec::new();
     let mut system_rows = Vec::new();
     let mut total_core_trace_rows = 0;
 
-    for final_state in fragment_results {
+    for (stack_top, final_state) in fragment_results {
+        first_stack_top = first_stack_top.or(stack_top);
         stack_rows.push(final_state.last_stack_cols);
         system_rows.push(final_state.last_system_cols);
         total_core_trace_rows += final_state.num_rows_written;
@@ -299,7 +407,7 @@ fn generate_core_trace_row_major(
         fragment_size,
         &stack_rows,
         &system_rows,
-        &first_stack_top,
+        &first_stack_top.unwrap_or_else(|| vec![ZERO; MIN_STACK_DEPTH]),
     );
 
     // Run batch inversion on stack's H0 helper column, processing each fragment in parallel.
@@ -459,7 +567,7 @@ fn initialize_range_checker(
 /// execution; this pass only needs the trace-recording side effects.
 fn initialize_chiplets(
     kernel: KernelDescriptor,
-    core_trace_contexts: &[CoreTraceFragmentContext],
+    memory_reads: &[&MemoryReadsReplay],
     memory_writes: MemoryWritesReplay,
     bitwise: BitwiseReplay,
     kernel_replay: KernelReplay,
@@ -546,16 +654,14 @@ fn initialize_chiplets(
                 .map(|(word, addr, ctx, clk)| MemoryAccess::WriteWord(*addr, *word, *ctx, *clk)),
         );
         let elements_read: Box<dyn Iterator<Item = MemoryAccess>> =
-            Box::new(core_trace_contexts.iter().flat_map(|ctx| {
-                ctx.replay
-                    .memory_reads
+            Box::new(memory_reads.iter().flat_map(|reads| {
+                reads
                     .iter_read_elements()
                     .map(|(_, addr, ctx, clk)| MemoryAccess::ReadElement(addr, ctx, clk))
             }));
         let words_read: Box<dyn Iterator<Item = MemoryAccess>> =
-            Box::new(core_trace_contexts.iter().flat_map(|ctx| {
-                ctx.replay
-                    .memory_reads
+            Box::new(memory_reads.iter().flat_map(|reads| {
+                reads
                     .iter_read_words()
                     .map(|(_, addr, ctx, clk)| MemoryAccess::ReadWord(addr, ctx, clk))
             }));
@@ -285,9 +285,10 @@ impl SystemInterface for ReplayProcessor {
         Ok(())
     }
 
-    fn save_call_state(&mut self) {
+    fn save_call_state(&mut self) -> Result<(), ExecutionError> {
         // no-op for the replay processor: the system call state was already recorded by the
         // tracer into `execution_context_replay` during the original execution.
+        Ok(())
     }
 
     fn restore_call_state(&mut self) -> Result<(), OperationError> {
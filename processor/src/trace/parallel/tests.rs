@@ -450,6 +450,96 @@ fn test_trace_generation_at_fragment_boundaries(
     insta::assert_compact_debug_snapshot!(testname, DeterministicTrace(&trace_from_fragments));
 }
 
+/// Verifies that a trace built from fragment contexts spilled to a fragment store is identical to
+/// one built from fragment contexts kept in memory.
+#[test]
+fn test_trace_generation_from_fragment_store() {
+    const FRAGMENT_SIZE: usize = 4;
+
+    let program = dyn_program();
+    let new_processor = || {
+        FastProcessor::new_with_options(
+            StackInputs::new(external_lib_proc_hash_for_stack()).unwrap(),
+            AdviceInputs::default(),
+            ExecutionOptions::default()
+                .with_core_trace_fragment_size(FRAGMENT_SIZE)
+                .unwrap(),
+        )
+        .expect("processor advice inputs should fit advice map limits")
+    };
+    let new_host = || {
+        let mut host = DefaultHost::default();
+        host.load_library(create_simple_library()).unwrap();
+        host
+    };
+
+    let trace_in_memory = {
+        let trace_inputs =
+            new_processor().execute_trace_inputs_sync(&program, &mut new_host()).unwrap();
+        build_trace(trace_inputs).unwrap()
+    };
+
+    let path =
+        std::env::temp_dir().join(format!("miden-trace-fragments-{}.bin", std::process::id()));
+    let trace_from_store = {
+        let store = crate::trace::FileFragmentStore::create(&path).unwrap();
+        let trace_inputs = new_processor()
+            .execute_trace_inputs_with_fragment_store_sync(
+                &program,
+                &mut new_host(),
+                Box::new(store),
+            )
+            .unwrap();
+        let context = trace_inputs.trace_generation_context();
+        assert!(context.core_trace_contexts.is_empty());
+        assert!(context.core_trace_fragment_store.as_ref().unwrap().len() > 1);
+
+        build_trace(trace_inputs).unwrap()
+    };
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(trace_from_store.stack_outputs(), trace_in_memory.stack_outputs());
+    assert_eq!(trace_from_store.trace_len_summary(), trace_in_memory.trace_len_summary());
+    assert_eq!(
+        format!("{:?}", DeterministicTrace(&trace_from_store)),
+        format!("{:?}", DeterministicTrace(&trace_in_memory)),
+    );
+}
+
+/// Verifies that generating the core trace fragments sequentially yields the same trace as
+/// generating them in parallel.
+#[test]
+fn test_sequential_fragment_scheduling_matches_parallel() {
+    const FRAGMENT_SIZE: usize = 4;
+
+    let program = loop_program();
+    let new_trace_inputs = || {
+        FastProcessor::new_with_options(
+            StackInputs::new(&[ONE, ONE, ONE, ZERO, SENTINEL_VALUE]).unwrap(),
+            AdviceInputs::default(),
+            ExecutionOptions::default()
+                .with_core_trace_fragment_size(FRAGMENT_SIZE)
+                .unwrap(),
+        )
+        .expect("processor advice inputs should fit advice map limits")
+        .execute_trace_inputs_sync(&program, &mut DefaultHost::default())
+        .unwrap()
+    };
+
+    let trace_inputs = new_trace_inputs();
+    assert!(trace_inputs.trace_generation_context().core_trace_contexts.len() > 1);
+    let parallel_trace =
+        build_trace_with_scheduling(trace_inputs, FragmentScheduling::Parallel).unwrap();
+    let sequential_trace =
+        build_trace_with_scheduling(new_trace_inputs(), FragmentScheduling::Sequential).unwrap();
+
+    assert_eq!(sequential_trace.stack_outputs(), parallel_trace.stack_outputs());
+    assert_eq!(
+        format!("{:?}", DeterministicTrace(&sequential_trace)),
+        format!("{:?}", DeterministicTrace(&parallel_trace)),
+    );
+}
+
 #[test]
 fn test_nested_loop_end_flags_stable_across_fragmentation() {
     // Small fragment size is chosen so that the fragment boundaries land on the outer loop replay:
@@ -1,15 +1,20 @@
-use alloc::vec::Vec;
+use alloc::{sync::Arc, vec, vec::Vec};
 
 use miden_core::{
-    mast::{BasicBlockNodeBuilder, MastForest},
+    events::EventName,
+    mast::{BasicBlockNodeBuilder, CallNodeBuilder, JoinNodeBuilder, MastForest},
     operations::Operation,
     program::Program,
 };
 use miden_utils_testing::rand::rand_array;
 
-use super::{ExecutionTrace, Felt};
+use super::{AdviceEvent, ExecutionTrace, Felt};
 use crate::{
-    AdviceInputs, DefaultHost, ExecutionOptions, FastProcessor, StackInputs, trace::build_trace,
+    AdviceInputs, DefaultHost, ExecutionOptions, FastProcessor, ProcessorState, RowIndex,
+    StackInputs,
+    advice::AdviceMutation,
+    event::{EventError, EventHandler},
+    trace::build_trace,
 };
 
 mod chiplets;
@@ -106,3 +111,289 @@ pub fn build_trace_from_ops_with_inputs(
     let trace_inputs = processor.execute_trace_inputs_sync(&program, &mut host).unwrap();
     build_trace(trace_inputs).unwrap()
 }
+
+// OP LOG TESTS
+// ================================================================================================
+
+#[test]
+fn op_log_records_executed_basic_block_operations() {
+    let ops = vec![
+        Operation::Push(Felt::from_u32(1)),
+        Operation::Push(Felt::from_u32(2)),
+        Operation::Add,
+        Operation::Drop,
+    ];
+    let mut mast_forest = MastForest::new();
+    let basic_block_id = BasicBlockNodeBuilder::new(ops).add_to_forest(&mut mast_forest).unwrap();
+    mast_forest.make_root(basic_block_id);
+    let program = Program::new(mast_forest.into(), basic_block_id);
+
+    let mut host = DefaultHost::default();
+    let processor = FastProcessor::new_with_options(
+        StackInputs::default(),
+        AdviceInputs::default(),
+        ExecutionOptions::default().with_op_log(),
+    )
+    .unwrap();
+    let trace_inputs = processor.execute_trace_inputs_sync(&program, &mut host).unwrap();
+    let trace = build_trace(trace_inputs).unwrap();
+
+    let op_log = trace.op_log();
+    // the program is executed as SPAN <ops> END HALT; only the ops inside the span are logged
+    assert_eq!(op_log.len(), trace.trace_len_summary().core_trace_len() - 3);
+    assert_eq!(op_log[0], (RowIndex::from(1_u32), Operation::Push(Felt::from_u32(1))));
+
+    let main = trace.main_trace();
+    for (clk, op) in op_log {
+        assert_eq!(main.get_op_code(*clk), Felt::from_u8(op.op_code()));
+    }
+}
+
+#[test]
+fn op_histogram_counts_executed_operations_by_mnemonic() {
+    let ops = [Operation::U32add, Operation::U32sub, Operation::U32mul, Operation::Swap]
+        .into_iter()
+        .cycle()
+        .take(200)
+        .collect::<Vec<_>>();
+    let mut mast_forest = MastForest::new();
+    let basic_block_id = BasicBlockNodeBuilder::new(ops).add_to_forest(&mut mast_forest).unwrap();
+    mast_forest.make_root(basic_block_id);
+    let program = Program::new(mast_forest.into(), basic_block_id);
+
+    let mut host = DefaultHost::default();
+    let processor = FastProcessor::new_with_options(
+        StackInputs::new(&[Felt::from_u32(1), Felt::from_u32(2)]).unwrap(),
+        AdviceInputs::default(),
+        ExecutionOptions::default().with_op_log(),
+    )
+    .unwrap();
+    let trace_inputs = processor.execute_trace_inputs_sync(&program, &mut host).unwrap();
+    let trace = build_trace(trace_inputs).unwrap();
+
+    let histogram = trace.op_histogram();
+    assert_eq!(histogram.values().sum::<usize>(), trace.op_log().len());
+    for mnemonic in ["u32add", "u32sub", "u32mul", "swap"] {
+        assert_eq!(histogram[mnemonic], 50, "unexpected count for {mnemonic}");
+    }
+
+    let u32_ops: usize = histogram
+        .iter()
+        .filter(|(mnemonic, _)| mnemonic.starts_with("u32"))
+        .map(|(_, count)| count)
+        .sum();
+    assert!(u32_ops * 2 > trace.op_log().len(), "u32 ops should dominate: {histogram:?}");
+}
+
+#[test]
+fn op_log_is_empty_by_default() {
+    let trace = build_trace_from_ops(vec![Operation::Add], &[1, 2]);
+    assert!(trace.op_log().is_empty());
+}
+
+// CONSTRAINT COVERAGE TESTS
+// ================================================================================================
+
+#[test]
+fn constraint_coverage_grows_with_the_trace_corpus() {
+    let mut coverage = miden_air::ConstraintCoverage::new();
+    build_trace_from_ops(vec![Operation::Add], &[1, 2]).record_constraint_coverage(&mut coverage);
+    let never_active = coverage.never_active();
+    assert!(!never_active.is_empty(), "a single ADD cannot exercise every constraint");
+
+    let ops = vec![Operation::U32add, Operation::U32mul, Operation::Swap, Operation::Mul];
+    build_trace_from_ops(ops, &[1, 2, 3]).record_constraint_coverage(&mut coverage);
+    let still_never_active = coverage.never_active();
+    assert_eq!(coverage.num_traces(), 2);
+    assert!(still_never_active.iter().all(|id| never_active.contains(id)));
+    assert!(still_never_active.len() < never_active.len(), "{coverage}");
+}
+
+struct PushAdviceHandler;
+
+impl EventHandler for PushAdviceHandler {
+    fn on_event(&self, _process: &ProcessorState) -> Result<Vec<AdviceMutation>, EventError> {
+        Ok(vec![AdviceMutation::extend_stack([Felt::from_u32(7), Felt::from_u32(8)])])
+    }
+}
+
+#[test]
+fn advice_timeline_records_handler_pushes_and_program_pops_in_order() {
+    const PUSH_EVENT: EventName = EventName::new("test::advice_timeline::push");
+
+    let ops = vec![
+        Operation::Push(PUSH_EVENT.to_event_id().as_felt()),
+        Operation::Emit,
+        Operation::Drop,
+        Operation::AdvPop,
+        Operation::AdvPop,
+        Operation::Drop,
+        Operation::Drop,
+    ];
+    let mut mast_forest = MastForest::new();
+    let basic_block_id = BasicBlockNodeBuilder::new(ops).add_to_forest(&mut mast_forest).unwrap();
+    mast_forest.make_root(basic_block_id);
+    let program = Program::new(mast_forest.into(), basic_block_id);
+
+    let mut host = DefaultHost::default();
+    host.register_handler(PUSH_EVENT, Arc::new(PushAdviceHandler)).unwrap();
+    let processor = FastProcessor::new_with_options(
+        StackInputs::default(),
+        AdviceInputs::default(),
+        ExecutionOptions::default().with_advice_timeline(),
+    )
+    .unwrap();
+    let trace_inputs = processor.execute_trace_inputs_sync(&program, &mut host).unwrap();
+    let trace = build_trace(trace_inputs).unwrap();
+
+    let timeline = trace.advice_timeline();
+    assert_eq!(timeline.len(), 3, "unexpected timeline: {timeline:?}");
+    let AdviceEvent::Push { clk: push_clk, values } = &timeline[0] else {
+        panic!("expected a push event first, got {:?}", timeline[0]);
+    };
+    assert_eq!(values, &[Felt::from_u32(7), Felt::from_u32(8)]);
+    let AdviceEvent::Pop { clk: first_pop_clk, values } = &timeline[1] else {
+        panic!("expected a pop event, got {:?}", timeline[1]);
+    };
+    assert_eq!(values, &[Felt::from_u32(7)]);
+    let AdviceEvent::Pop { clk: second_pop_clk, values } = &timeline[2] else {
+        panic!("expected a pop event, got {:?}", timeline[2]);
+    };
+    assert_eq!(values, &[Felt::from_u32(8)]);
+    assert!(push_clk < first_pop_clk && first_pop_clk < second_pop_clk);
+}
+
+#[test]
+fn advice_timeline_is_empty_by_default() {
+    let trace = build_trace_from_ops(vec![Operation::Add], &[1, 2]);
+    assert!(trace.advice_timeline().is_empty());
+}
+
+// DEPTH PROFILE TESTS
+// ================================================================================================
+
+#[test]
+fn depth_profile_reaches_peak_nesting_of_nested_calls() {
+    // Equivalent to:
+    // proc bar  pad drop  end
+    // proc foo  pad drop  call.bar  end
+    // begin  call.foo  end
+    let program = {
+        let mut mast_forest = MastForest::new();
+        let bar = BasicBlockNodeBuilder::new(vec![Operation::Pad, Operation::Drop])
+            .add_to_forest(&mut mast_forest)
+            .unwrap();
+        let call_bar = CallNodeBuilder::new(bar).add_to_forest(&mut mast_forest).unwrap();
+        let foo_body = BasicBlockNodeBuilder::new(vec![Operation::Pad, Operation::Drop])
+            .add_to_forest(&mut mast_forest)
+            .unwrap();
+        let foo = JoinNodeBuilder::new([foo_body, call_bar])
+            .add_to_forest(&mut mast_forest)
+            .unwrap();
+        let call_foo = CallNodeBuilder::new(foo).add_to_forest(&mut mast_forest).unwrap();
+        mast_forest.make_root(call_foo);
+        Program::new(mast_forest.into(), call_foo)
+    };
+
+    let mut host = DefaultHost::default();
+    let processor = FastProcessor::new_with_options(
+        StackInputs::default(),
+        AdviceInputs::default(),
+        ExecutionOptions::default().with_depth_profile(),
+    )
+    .unwrap();
+    let trace_inputs = processor.execute_trace_inputs_sync(&program, &mut host).unwrap();
+    let trace = build_trace(trace_inputs).unwrap();
+
+    // CALL(foo) -> JOIN -> CALL(bar) -> SPAN
+    let profile = trace.depth_profile();
+    assert_eq!(profile.iter().map(|&(_, depth)| depth).max(), Some(4));
+    assert_eq!(profile.first().map(|&(_, depth)| depth), Some(1));
+    assert_eq!(profile.last().map(|&(_, depth)| depth), Some(0));
+    assert!(profile.windows(2).all(|pair| pair[0].0 < pair[1].0));
+}
+
+#[test]
+fn depth_profile_is_empty_by_default() {
+    let trace = build_trace_from_ops(vec![Operation::Add], &[1, 2]);
+    assert!(trace.depth_profile().is_empty());
+}
+
+// BEHAVIOR FINGERPRINT TESTS
+// ================================================================================================
+
+/// Executes a program which emits an event, stores `stored` in memory, and leaves `output` on top
+/// of the stack, and returns the behavior fingerprint of the resulting trace.
+fn behavior_fingerprint_of(stored: u32, output: u32) -> miden_core::Word {
+    const PUSH_EVENT: EventName = EventName::new("test::behavior_fingerprint::push");
+
+    let ops = vec![
+        Operation::Push(PUSH_EVENT.to_event_id().as_felt()),
+        Operation::Emit,
+        Operation::Drop,
+        Operation::AdvPop,
+        Operation::AdvPop,
+        Operation::Drop,
+        Operation::Drop,
+        Operation::Push(Felt::from_u32(stored)),
+        Operation::Push(Felt::from_u32(4)),
+        Operation::MStore,
+        Operation::Drop,
+        Operation::Push(Felt::from_u32(output)),
+        Operation::Swap,
+        Operation::Drop,
+    ];
+    let mut mast_forest = MastForest::new();
+    let basic_block_id = BasicBlockNodeBuilder::new(ops).add_to_forest(&mut mast_forest).unwrap();
+    mast_forest.make_root(basic_block_id);
+    let program = Program::new(mast_forest.into(), basic_block_id);
+
+    let mut host = DefaultHost::default();
+    host.register_handler(PUSH_EVENT, Arc::new(PushAdviceHandler)).unwrap();
+    let processor = FastProcessor::new(StackInputs::default());
+    let trace_inputs = processor.execute_trace_inputs_sync(&program, &mut host).unwrap();
+    build_trace(trace_inputs).unwrap().behavior_fingerprint()
+}
+
+#[test]
+fn behavior_fingerprint_is_stable_and_tracks_observable_behavior() {
+    let fingerprint = behavior_fingerprint_of(3, 5);
+    assert_eq!(behavior_fingerprint_of(3, 5), fingerprint);
+
+    // different stack outputs
+    assert_ne!(behavior_fingerprint_of(3, 6), fingerprint);
+    // different final memory contents
+    assert_ne!(behavior_fingerprint_of(2, 5), fingerprint);
+}
+
+#[test]
+fn min_cycles_excludes_padding_of_straight_line_program() {
+    // an operation with an immediate value cannot be the last one in an operation group, so this
+    // forces the group to be padded with a NOOP
+    let mut first_ops = vec![Operation::Swap; 8];
+    first_ops.extend([Operation::Push(Felt::from_u32(5)), Operation::Add]);
+    let first_ops = first_ops.repeat(6);
+    let second_ops = vec![Operation::Mul, Operation::Pad, Operation::Incr, Operation::Drop];
+
+    let mut mast_forest = MastForest::new();
+    let first = BasicBlockNodeBuilder::new(first_ops).add_to_forest(&mut mast_forest).unwrap();
+    let second = BasicBlockNodeBuilder::new(second_ops).add_to_forest(&mut mast_forest).unwrap();
+    let join = JoinNodeBuilder::new([first, second]).add_to_forest(&mut mast_forest).unwrap();
+    mast_forest.make_root(join);
+    let num_padding_ops: usize = [first, second]
+        .into_iter()
+        .map(|id| {
+            let basic_block = mast_forest[id].unwrap_basic_block();
+            basic_block.operations().count() - basic_block.raw_operations().count()
+        })
+        .sum();
+    assert!(num_padding_ops > 0);
+    let program = Program::new(mast_forest.into(), join);
+
+    let min_cycles = program.min_cycles().expect("program should be straight-line");
+    let trace = build_trace_from_program(&program, &[1, 2]);
+
+    // the core trace also contains the final HALT row
+    let num_cycles = trace.trace_len_summary().core_trace_len() - 1;
+    assert_eq!(num_cycles, min_cycles + num_padding_ops);
+}
@@ -1,4 +1,4 @@
-use alloc::{collections::VecDeque, string::ToString};
+use alloc::{collections::VecDeque, format, string::ToString};
 
 use miden_air::trace::{
     RowIndex,
@@ -8,13 +8,14 @@ use miden_air::trace::{
 use crate::{
     ContextId, ExecutionError, Felt, MIN_STACK_DEPTH, MemoryError, ONE, Word, ZERO,
     advice::AdviceError,
-    continuation_stack::ContinuationStack,
+    continuation_stack::{ContinuationStack, read_continuation, write_continuation},
     crypto::merkle::MerklePath,
     errors::OperationError,
     mast::{MastForestId, MastNodeId},
     processor::{
         AdviceProviderInterface, HasherInterface, MemoryInterface, Processor, SystemInterface,
     },
+    serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     trace::chiplets::CircuitEvaluation,
 };
 
@@ -598,7 +599,12 @@ impl MemoryWritesReplay {
 }
 
 impl MemoryInterface for MemoryReadsReplay {
-    fn read_element(&mut self, _ctx: ContextId, addr: Felt) -> Result<Felt, MemoryError> {
+    fn read_element(
+        &mut self,
+        _ctx: ContextId,
+        addr: Felt,
+        _clk: RowIndex,
+    ) -> Result<Felt, MemoryError> {
         self.replay_read_element(addr)
     }
 
@@ -615,6 +621,7 @@ impl MemoryInterface for MemoryReadsReplay {
         &mut self,
         _ctx: ContextId,
         _addr: Felt,
+        _clk: RowIndex,
         _element: Felt,
     ) -> Result<(), MemoryError> {
         Ok(())
@@ -1181,3 +1188,203 @@ impl StackOverflowReplay {
             .ok_or(OperationError::Internal("no overflow address operations recorded"))
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for CoreTraceFragmentContext {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let CoreTraceState { system, decoder, stack } = &self.state;
+        target.write_u32(system.clk.into());
+        target.write_u32(system.ctx.into());
+        system.fn_hash.write_into(target);
+        system.deferred_root.write_into(target);
+        decoder.current_addr.write_into(target);
+        decoder.parent_addr.write_into(target);
+        stack.stack_top.write_into(target);
+        target.write_usize(stack.stack_depth);
+        stack.last_overflow_addr.write_into(target);
+
+        let ExecutionReplay {
+            block_stack,
+            execution_context,
+            stack_overflow,
+            memory_reads,
+            advice,
+            hasher,
+            block_address,
+            mast_forest_resolution,
+        } = &self.replay;
+        write_deque(target, &block_stack.node_start_parent_addr, |target, addr| {
+            addr.write_into(target)
+        });
+        write_deque(target, &block_stack.node_end, |target, node_end| {
+            node_end.ended_node_addr.write_into(target);
+            node_end.prev_addr.write_into(target);
+            node_end.prev_parent_addr.write_into(target);
+        });
+        write_deque(target, &execution_context.execution_contexts, |target, ctx_info| {
+            target.write_u32(ctx_info.parent_ctx.into());
+            ctx_info.parent_fn_hash.write_into(target);
+        });
+        write_deque(target, &stack_overflow.overflow_values, |target, value| {
+            value.write_into(target)
+        });
+        write_deque(target, &stack_overflow.restore_context_info, |target, info| {
+            info.write_into(target)
+        });
+        write_deque(target, &memory_reads.elements_read, |target, (element, addr, ctx, clk)| {
+            (element, addr, u32::from(*ctx), u32::from(*clk)).write_into(target)
+        });
+        write_deque(target, &memory_reads.words_read, |target, (word, addr, ctx, clk)| {
+            (word, addr, u32::from(*ctx), u32::from(*clk)).write_into(target)
+        });
+        write_deque(target, &advice.stack_pops, |target, value| value.write_into(target));
+        write_deque(target, &advice.stack_word_pops, |target, word| word.write_into(target));
+        write_deque(target, &advice.stack_dword_pops, |target, words| words.write_into(target));
+        write_deque(target, &hasher.permutation_operations, |target, operation| {
+            operation.write_into(target)
+        });
+        write_deque(target, &hasher.build_merkle_root_operations, |target, operation| {
+            operation.write_into(target)
+        });
+        write_deque(target, &hasher.mrupdate_operations, |target, operation| {
+            operation.write_into(target)
+        });
+        write_deque(target, &block_address.block_addresses, |target, addr| addr.write_into(target));
+        write_deque(
+            target,
+            &mast_forest_resolution.mast_forest_resolutions,
+            |target, (node_id, forest_id)| {
+                node_id.write_into(target);
+                target.write_u32((*forest_id).into());
+            },
+        );
+
+        let continuations = self.continuation.iter();
+        target.write_usize(continuations.len());
+        for continuation in continuations {
+            write_continuation(continuation, target, |forest_id, target| {
+                target.write_u32((*forest_id).into())
+            });
+        }
+        target.write_u32(self.initial_mast_forest_id.into());
+    }
+}
+
+impl Deserializable for CoreTraceFragmentContext {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let system = SystemState {
+            clk: RowIndex::from(source.read_u32()?),
+            ctx: ContextId::from(source.read_u32()?),
+            fn_hash: Word::read_from(source)?,
+            deferred_root: Word::read_from(source)?,
+        };
+        let decoder = DecoderState {
+            current_addr: Felt::read_from(source)?,
+            parent_addr: Felt::read_from(source)?,
+        };
+        let stack_top = <[Felt; MIN_STACK_DEPTH]>::read_from(source)?;
+        let stack_depth = source.read_usize()?;
+        if stack_depth < MIN_STACK_DEPTH {
+            return Err(DeserializationError::InvalidValue(format!(
+                "stack depth {stack_depth} is smaller than the minimum of {MIN_STACK_DEPTH}"
+            )));
+        }
+        let stack = StackState::new(stack_top, stack_depth, Felt::read_from(source)?);
+
+        let replay = ExecutionReplay {
+            block_stack: BlockStackReplay {
+                node_start_parent_addr: read_deque(source, Felt::read_from)?,
+                node_end: read_deque(source, |source| {
+                    Ok(NodeEndData {
+                        ended_node_addr: Felt::read_from(source)?,
+                        prev_addr: Felt::read_from(source)?,
+                        prev_parent_addr: Felt::read_from(source)?,
+                    })
+                })?,
+            },
+            execution_context: ExecutionContextReplay {
+                execution_contexts: read_deque(source, |source| {
+                    Ok(ExecutionContextSystemInfo {
+                        parent_ctx: ContextId::from(source.read_u32()?),
+                        parent_fn_hash: Word::read_from(source)?,
+                    })
+                })?,
+            },
+            stack_overflow: StackOverflowReplay {
+                overflow_values: read_deque(source, <(Felt, Felt)>::read_from)?,
+                restore_context_info: read_deque(source, <(usize, Felt)>::read_from)?,
+            },
+            memory_reads: MemoryReadsReplay {
+                elements_read: read_deque(source, |source| {
+                    let (element, addr, ctx, clk) = <(Felt, Felt, u32, u32)>::read_from(source)?;
+                    Ok((element, addr, ContextId::from(ctx), RowIndex::from(clk)))
+                })?,
+                words_read: read_deque(source, |source| {
+                    let (word, addr, ctx, clk) = <(Word, Felt, u32, u32)>::read_from(source)?;
+                    Ok((word, addr, ContextId::from(ctx), RowIndex::from(clk)))
+                })?,
+            },
+            advice: AdviceReplay {
+                stack_pops: read_deque(source, Felt::read_from)?,
+                stack_word_pops: read_deque(source, Word::read_from)?,
+                stack_dword_pops: read_deque(source, <[Word; 2]>::read_from)?,
+            },
+            hasher: HasherResponseReplay {
+                permutation_operations: read_deque(source, <(Felt, [Felt; 12])>::read_from)?,
+                build_merkle_root_operations: read_deque(source, <(Felt, Word)>::read_from)?,
+                mrupdate_operations: read_deque(source, <(Felt, Word, Word)>::read_from)?,
+            },
+            block_address: BlockAddressReplay {
+                block_addresses: read_deque(source, Felt::read_from)?,
+            },
+            mast_forest_resolution: MastForestResolutionReplay {
+                mast_forest_resolutions: read_deque(source, |source| {
+                    Ok((MastNodeId::read_from(source)?, MastForestId::from(source.read_u32()?)))
+                })?,
+            },
+        };
+
+        let num_continuations = source.read_usize()?;
+        let mut continuation = ContinuationStack::default();
+        for _ in 0..num_continuations {
+            continuation.push_continuation(read_continuation(source, |source| {
+                Ok(MastForestId::from(source.read_u32()?))
+            })?);
+        }
+        let initial_mast_forest_id = MastForestId::from(source.read_u32()?);
+
+        Ok(Self {
+            state: CoreTraceState { system, decoder, stack },
+            replay,
+            continuation,
+            initial_mast_forest_id,
+        })
+    }
+}
+
+/// Writes the length of `deque` followed by each of its items, encoded with `write_item`.
+fn write_deque<W: ByteWriter, T>(
+    target: &mut W,
+    deque: &VecDeque<T>,
+    mut write_item: impl FnMut(&mut W, &T),
+) {
+    target.write_usize(deque.len());
+    for item in deque {
+        write_item(target, item);
+    }
+}
+
+/// Reads a deque written by [`write_deque`], decoding each item with `read_item`.
+fn read_deque<R: ByteReader, T>(
+    source: &mut R,
+    mut read_item: impl FnMut(&mut R) -> Result<T, DeserializationError>,
+) -> Result<VecDeque<T>, DeserializationError> {
+    let len = source.read_usize()?;
+    let mut deque = VecDeque::new();
+    for _ in 0..len {
+        deque.push_back(read_item(source)?);
+    }
+    Ok(deque)
+}
@@ -0,0 +1,54 @@
+use miden_assembly::Assembler;
+use miden_processor::{
+    DefaultHost, ExecutionOptions, Felt, StackInputs, advice::AdviceInputs,
+    consistency::find_divergence,
+};
+
+#[test]
+fn find_divergence_reports_none_for_consistent_execution() {
+    let source = "
+        proc square
+            dup mul
+        end
+
+        proc sum_squares
+            push.0 swap
+            dup neq.0
+            while.true
+                dup exec.square movup.2 add swap
+                sub.1 dup neq.0
+            end
+            drop
+        end
+
+        begin
+            push.100 mem_store.4
+            call.sum_squares
+            procref.square mem_storew_le.8 dropw push.8 dynexec
+            eq.8281 if.true push.1 else push.2 end drop
+        end
+    ";
+    let program = Assembler::default()
+        .assemble_program("program", source)
+        .expect("program should compile")
+        .unwrap_program();
+
+    // Use small fragments so that the trace is built from several replayed fragments.
+    for fragment_size in [5, 64, ExecutionOptions::DEFAULT_CORE_TRACE_FRAGMENT_SIZE] {
+        let options = ExecutionOptions::default()
+            .with_core_trace_fragment_size(fragment_size)
+            .unwrap();
+        let divergence = find_divergence(
+            &program,
+            StackInputs::new(&[Felt::from_u32(6)]).unwrap(),
+            AdviceInputs::default(),
+            &mut DefaultHost::default(),
+            options,
+        )
+        .unwrap();
+
+        if let Some(divergence) = divergence {
+            panic!("{divergence}");
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use miden_assembly::Assembler;
+use miden_processor::{
+    DefaultHost, ExecutionOptions, Felt, Program, StackInputs, advice::AdviceInputs,
+};
+
+fn program(source: &str) -> Program {
+    Assembler::default()
+        .assemble_program("program", source)
+        .expect("program should compile")
+        .unwrap_program()
+}
+
+#[test]
+fn execute_batch_returns_results_in_input_order() {
+    let add = program("begin push.2 add end");
+    let mul = program("begin push.3 mul end");
+    let fail = program("begin push.1 assertz end");
+
+    let programs: Vec<_> = (0..16_u32)
+        .map(|i| {
+            let program = match i % 3 {
+                0 => add.clone(),
+                1 => mul.clone(),
+                _ => fail.clone(),
+            };
+            (
+                program,
+                StackInputs::new(&[Felt::from_u32(i)]).unwrap(),
+                AdviceInputs::default(),
+            )
+        })
+        .collect();
+
+    let results = miden_processor::execute_batch(
+        &programs,
+        |_| DefaultHost::default(),
+        ExecutionOptions::default(),
+    );
+
+    assert_eq!(results.len(), programs.len());
+    for (i, result) in (0..16_u32).zip(results) {
+        match i % 3 {
+            0 => assert_eq!(result.unwrap().stack.get_element(0), Some(Felt::from_u32(i + 2))),
+            1 => assert_eq!(result.unwrap().stack.get_element(0), Some(Felt::from_u32(i * 3))),
+            _ => assert!(result.is_err()),
+        }
+    }
+}
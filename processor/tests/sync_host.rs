@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use miden_assembly::Assembler;
+use miden_debug_types::{Location, SourceFile, SourceSpan};
+use miden_processor::{
+    BaseHost, ExecutionOptions, FastProcessor, Felt, LoadedMastForest, ProcessorState, StackInputs,
+    SyncHost, Word,
+    advice::{AdviceInputs, AdviceMutation},
+    event::EventError,
+};
+
+/// A purely synchronous host which pushes a value onto the advice stack on every event.
+struct CountingSyncHost {
+    event_calls: u32,
+}
+
+impl BaseHost for CountingSyncHost {
+    fn get_label_and_source_file(
+        &self,
+        _location: &Location,
+    ) -> (SourceSpan, Option<Arc<SourceFile>>) {
+        (SourceSpan::UNKNOWN, None)
+    }
+}
+
+impl SyncHost for CountingSyncHost {
+    fn get_mast_forest(&self, _node_digest: &Word) -> Option<LoadedMastForest> {
+        None
+    }
+
+    fn on_event(
+        &mut self,
+        _process: &ProcessorState<'_>,
+    ) -> Result<Vec<AdviceMutation>, EventError> {
+        self.event_calls += 1;
+        Ok(vec![AdviceMutation::extend_stack([Felt::from_u32(self.event_calls)])])
+    }
+}
+
+fn event_program() -> miden_processor::Program {
+    Assembler::default()
+        .assemble_program(
+            "program",
+            r#"
+            begin
+                emit.event("test::sync") adv_push
+                emit.event("test::sync") adv_push
+                add
+                swap drop
+            end
+            "#,
+        )
+        .expect("program should compile")
+        .unwrap_program()
+}
+
+/// Executes a program against a synchronous host from a plain (non-async) test, without any
+/// async runtime in scope.
+#[test]
+fn execute_sync_runs_without_async_runtime() {
+    let program = event_program();
+    let stack_inputs = StackInputs::new(&[Felt::from_u32(7)]).unwrap();
+
+    let mut host = CountingSyncHost { event_calls: 0 };
+    let output = miden_processor::execute_sync(
+        &program,
+        stack_inputs,
+        AdviceInputs::default(),
+        &mut host,
+        ExecutionOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(host.event_calls, 2);
+    assert_eq!(output.stack.get_element(0), Some(Felt::from_u32(3)));
+
+    let mut host = CountingSyncHost { event_calls: 0 };
+    let output = FastProcessor::new(stack_inputs).execute_sync(&program, &mut host).unwrap();
+    assert_eq!(host.event_calls, 2);
+    assert_eq!(output.stack.get_element(0), Some(Felt::from_u32(3)));
+}
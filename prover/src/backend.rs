@@ -0,0 +1,71 @@
+use alloc::string::ToString;
+
+use miden_air::{MidenMultiAir, ProverStatement};
+use miden_core::{Felt, field::QuadFelt};
+use miden_crypto::stark::{ProverInstance, StarkConfig, proof::StarkOutput};
+use miden_processor::ExecutionError;
+
+// PROVER BACKEND
+// ================================================================================================
+
+/// A backend executing the compute-heavy stages of Miden VM STARK proof generation.
+///
+/// Given the per-AIR main traces of a Miden VM statement, a backend is responsible for the
+/// low-degree extension of the traces, the Merkle tree hashing of the committed extensions, and
+/// the evaluation of the quotient polynomial, producing a proof which verifies under `config`.
+/// The prover takes care of everything around it: trace generation, the choice of the STARK
+/// configuration from [`ProvingOptions`](crate::ProvingOptions), seeding the challenger, and
+/// serializing the resulting proof.
+///
+/// [`CpuBackend`] runs all stages on the CPU and is used by the `prove*()` functions; it is the
+/// only backend provided by this crate. Other backends can be implemented outside of this crate and
+/// passed to [`prove_from_trace_sync_with_backend`](crate::prove_from_trace_sync_with_backend).
+/// Such backends must produce proofs which are valid under `config`, as the verifier is unaware of
+/// the backend which generated a proof.
+pub trait ProverBackend {
+    /// Returns the name of this backend, used to label proof generation in traces.
+    fn name(&self) -> &str;
+
+    /// Proves `statement` under `config`, using `challenger` as the Fiat-Shamir transcript.
+    ///
+    /// `challenger` has already observed the protocol parameters of the Miden VM.
+    fn prove<SC>(
+        &self,
+        config: &SC,
+        statement: &ProverStatement<Felt, QuadFelt, MidenMultiAir>,
+        challenger: SC::Challenger,
+    ) -> Result<StarkOutput<Felt, QuadFelt, SC>, ExecutionError>
+    where
+        SC: StarkConfig<Felt, QuadFelt>;
+}
+
+// CPU BACKEND
+// ================================================================================================
+
+/// A [`ProverBackend`] running all stages of proof generation on the CPU.
+///
+/// With the `concurrent` feature enabled, the stages are parallelized across the threads of the
+/// global rayon thread pool.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CpuBackend;
+
+impl ProverBackend for CpuBackend {
+    fn name(&self) -> &str {
+        "cpu"
+    }
+
+    fn prove<SC>(
+        &self,
+        config: &SC,
+        statement: &ProverStatement<Felt, QuadFelt, MidenMultiAir>,
+        challenger: SC::Challenger,
+    ) -> Result<StarkOutput<Felt, QuadFelt, SC>, ExecutionError>
+    where
+        SC: StarkConfig<Felt, QuadFelt>,
+    {
+        ProverInstance::new(config, statement, None)
+            .map_err(|e| ExecutionError::ProvingError(e.to_string()))?
+            .prove(challenger)
+            .map_err(|e| ExecutionError::ProvingError(e.to_string()))
+    }
+}
@@ -11,27 +11,33 @@ use ::serde::Serialize;
 use miden_air::{MidenMultiAir, ProverStatement, Statement};
 use miden_core::{Felt, field::QuadFelt, utils::RowMajorMatrix};
 use miden_crypto::stark::{
-    ProverInstance, StarkConfig,
+    StarkConfig,
     lmcs::Lmcs,
     proof::{StarkOutput, StarkProofData},
 };
 use miden_processor::{
     FastProcessor, Program,
-    trace::{ExecutionTrace, build_trace},
+    trace::{ExecutionTrace, build_trace_with_scheduling},
 };
 use serde_wincode::SerdeCompat;
 use tracing::instrument;
 
+mod backend;
 mod proving_options;
 
 // EXPORTS
 // ================================================================================================
+#[cfg(feature = "custom-backend")]
+pub use backend::{CpuBackend, ProverBackend};
+#[cfg(not(feature = "custom-backend"))]
+use backend::{CpuBackend, ProverBackend};
 pub use miden_air::{DeserializationError, MidenAir, PublicInputs, config};
 pub use miden_core::proof::{DeferredProof, ExecutionProof, HashFunction, StarkProof};
 pub use miden_processor::{
-    ExecutionError, ExecutionOptions, ExecutionOutput, FutureMaybeSend, Host, InputError,
-    ProgramInfo, StackInputs, StackOutputs, SyncHost, TraceBuildInputs, TraceGenerationContext,
-    Word, advice::AdviceInputs, crypto, field, serde, utils,
+    ExecutionError, ExecutionOptions, ExecutionOptionsError, ExecutionOutput, FutureMaybeSend,
+    Host, InputError, ProgramInfo, StackInputs, StackOutputs, SyncHost, TraceBuildInputs,
+    TraceGenerationContext, Word, advice::AdviceInputs, crypto, field, serde,
+    trace::FragmentScheduling, utils,
 };
 pub use proving_options::ProvingOptions;
 
@@ -79,6 +85,7 @@ pub async fn prove(
     proving_options: ProvingOptions,
 ) -> Result<(StackOutputs, ExecutionProof), ExecutionError> {
     // execute the program to create an execution trace using FastProcessor
+    let execution_options = proving_options.apply_to(execution_options);
     let processor = FastProcessor::new_with_options(stack_inputs, advice_inputs, execution_options)
         .map_err(ExecutionError::advice_error_no_context)?;
 
@@ -102,6 +109,7 @@ pub async fn prove_partial(
     execution_options: ExecutionOptions,
     proving_options: ProvingOptions,
 ) -> Result<(StackOutputs, ExecutionProof), ExecutionError> {
+    let execution_options = proving_options.apply_to(execution_options);
     let processor = FastProcessor::new_with_options(stack_inputs, advice_inputs, execution_options)
         .map_err(ExecutionError::advice_error_no_context)?;
 
@@ -122,6 +130,7 @@ pub fn prove_sync(
     execution_options: ExecutionOptions,
     proving_options: ProvingOptions,
 ) -> Result<(StackOutputs, ExecutionProof), ExecutionError> {
+    let execution_options = proving_options.apply_to(execution_options);
     let processor = FastProcessor::new_with_options(stack_inputs, advice_inputs, execution_options)
         .map_err(ExecutionError::advice_error_no_context)?;
 
@@ -142,6 +151,7 @@ pub fn prove_partial_sync(
     execution_options: ExecutionOptions,
     proving_options: ProvingOptions,
 ) -> Result<(StackOutputs, ExecutionProof), ExecutionError> {
+    let execution_options = proving_options.apply_to(execution_options);
     let processor = FastProcessor::new_with_options(stack_inputs, advice_inputs, execution_options)
         .map_err(ExecutionError::advice_error_no_context)?;
 
@@ -156,17 +166,39 @@ pub fn prove_partial_sync(
 ///
 /// This is useful when program execution has already happened elsewhere and only trace building
 /// plus proof generation remain. The execution settings are already reflected in the supplied
-/// `TraceBuildInputs`, so only proof-generation options remain in this API.
+/// `TraceBuildInputs`, so only proof-generation options remain in this API; in particular, the
+/// core trace fragment size set in the [`ProvingOptions`] is ignored.
 #[instrument("prove_trace_sync", skip_all)]
 pub fn prove_from_trace_sync(
     inputs: TraceProvingInputs,
+) -> Result<(StackOutputs, ExecutionProof), ExecutionError> {
+    prove_trace_inputs(inputs, &CpuBackend)
+}
+
+/// Builds an execution trace from pre-executed trace inputs and proves it synchronously, running
+/// the compute-heavy stages of STARK proof generation on `backend`.
+///
+/// This behaves like [`prove_from_trace_sync`], which uses the [`CpuBackend`]. The deferred proof
+/// of the execution's precompile claims is always generated on the CPU.
+#[cfg(feature = "custom-backend")]
+#[instrument("prove_trace_sync_with_backend", skip_all)]
+pub fn prove_from_trace_sync_with_backend(
+    inputs: TraceProvingInputs,
+    backend: &impl ProverBackend,
+) -> Result<(StackOutputs, ExecutionProof), ExecutionError> {
+    prove_trace_inputs(inputs, backend)
+}
+
+fn prove_trace_inputs(
+    inputs: TraceProvingInputs,
+    backend: &impl ProverBackend,
 ) -> Result<(StackOutputs, ExecutionProof), ExecutionError> {
     let (trace_inputs, options) = inputs.into_parts();
     let trace = {
         let _span = tracing::info_span!("build_miden_vm_trace").entered();
-        build_trace(trace_inputs)?
+        build_execution_trace(trace_inputs, &options)?
     };
-    prove_final_execution_trace(trace, options)
+    prove_final_execution_trace(trace, options, backend)
 }
 
 /// Builds an execution trace from pre-executed trace inputs and proves it synchronously, preserving
@@ -180,14 +212,35 @@ pub fn prove_partial_from_trace_sync(
     let (trace_inputs, options) = inputs.into_parts();
     let trace = {
         let _span = tracing::info_span!("build_miden_vm_trace").entered();
-        build_trace(trace_inputs)?
+        build_execution_trace(trace_inputs, &options)?
     };
-    prove_partial_execution_trace(trace, options)
+    prove_partial_execution_trace(trace, options, &CpuBackend)
+}
+
+/// Builds the execution trace from `trace_inputs` using the trace generation parameters of
+/// `options`.
+fn build_execution_trace(
+    trace_inputs: TraceBuildInputs,
+    options: &ProvingOptions,
+) -> Result<ExecutionTrace, ExecutionError> {
+    let scheduling = options.fragment_scheduling();
+    if options.num_threads() == 0 {
+        return build_trace_with_scheduling(trace_inputs, scheduling);
+    }
+
+    let thread_pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(options.num_threads())
+        .build()
+        .map_err(|_| {
+            ExecutionError::Internal("failed to build the trace generation thread pool")
+        })?;
+    thread_pool.install(|| build_trace_with_scheduling(trace_inputs, scheduling))
 }
 
 fn prove_final_execution_trace(
     trace: ExecutionTrace,
     options: ProvingOptions,
+    backend: &impl ProverBackend,
 ) -> Result<(StackOutputs, ExecutionProof), ExecutionError> {
     let hash_fn = options.hash_fn();
     let deferred_proof = {
@@ -196,12 +249,13 @@ fn prove_final_execution_trace(
             .map_err(|err| ExecutionError::ProvingError(err.to_string()))?
     };
 
-    prove_miden_vm_execution_trace(trace, options, deferred_proof)
+    prove_miden_vm_execution_trace(trace, options, deferred_proof, backend)
 }
 
 fn prove_partial_execution_trace(
     trace: ExecutionTrace,
     options: ProvingOptions,
+    backend: &impl ProverBackend,
 ) -> Result<(StackOutputs, ExecutionProof), ExecutionError> {
     let deferred_proof = {
         let _precompile_vm_span = tracing::info_span!("precompile_vm").entered();
@@ -213,7 +267,7 @@ fn prove_partial_execution_trace(
         DeferredProof::Wire(wire)
     };
 
-    prove_miden_vm_execution_trace(trace, options, deferred_proof)
+    prove_miden_vm_execution_trace(trace, options, deferred_proof, backend)
 }
 
 #[instrument("miden_vm", skip_all)]
@@ -221,6 +275,7 @@ fn prove_miden_vm_execution_trace(
     trace: ExecutionTrace,
     options: ProvingOptions,
     deferred_proof: DeferredProof,
+    backend: &impl ProverBackend,
 ) -> Result<(StackOutputs, ExecutionProof), ExecutionError> {
     let trace_len_summary = trace.trace_len_summary();
     tracing::event!(
@@ -243,9 +298,22 @@ fn prove_miden_vm_execution_trace(
 
     let params = config::pcs_params();
     let proof_bytes = match hash_fn {
+        HashFunction::Blake3_192 => {
+            let config = config::blake3_192_config(params, config::RELATION_DIGEST);
+            prove_stark_with_backend(
+                backend,
+                &config,
+                core_matrix,
+                chiplets_matrix,
+                poseidon2_matrix,
+                &public_values,
+                &aux_inputs,
+            )
+        },
         HashFunction::Blake3_256 => {
             let config = config::blake3_256_config(params, config::RELATION_DIGEST);
-            prove_stark(
+            prove_stark_with_backend(
+                backend,
                 &config,
                 core_matrix,
                 chiplets_matrix,
@@ -256,7 +324,8 @@ fn prove_miden_vm_execution_trace(
         },
         HashFunction::Keccak => {
             let config = config::keccak_config(params, config::RELATION_DIGEST);
-            prove_stark(
+            prove_stark_with_backend(
+                backend,
                 &config,
                 core_matrix,
                 chiplets_matrix,
@@ -267,7 +336,8 @@ fn prove_miden_vm_execution_trace(
         },
         HashFunction::Rpo256 => {
             let config = config::rpo_config(params, config::RELATION_DIGEST);
-            prove_stark(
+            prove_stark_with_backend(
+                backend,
                 &config,
                 core_matrix,
                 chiplets_matrix,
@@ -278,7 +348,8 @@ fn prove_miden_vm_execution_trace(
         },
         HashFunction::Poseidon2 => {
             let config = config::poseidon2_config(params, config::RELATION_DIGEST);
-            prove_stark(
+            prove_stark_with_backend(
+                backend,
                 &config,
                 core_matrix,
                 chiplets_matrix,
@@ -289,7 +360,8 @@ fn prove_miden_vm_execution_trace(
         },
         HashFunction::Rpx256 => {
             let config = config::rpx_config(params, config::RELATION_DIGEST);
-            prove_stark(
+            prove_stark_with_backend(
+                backend,
                 &config,
                 core_matrix,
                 chiplets_matrix,
@@ -313,7 +385,6 @@ fn prove_miden_vm_execution_trace(
 /// Pre-seeds the challenger with the protocol parameters, the AIR public values, and the
 /// statement `aux_inputs` (program hash, final deferred root, and the concatenated kernel-procedure
 /// digests). Then delegates to the lifted multi-AIR prover.
-#[instrument("prove_stark", skip_all)]
 pub fn prove_stark<SC>(
     config: &SC,
     core_trace: RowMajorMatrix<Felt>,
@@ -322,6 +393,31 @@ pub fn prove_stark<SC>(
     public_values: &[Felt],
     aux_inputs: &[Felt],
 ) -> Result<Vec<u8>, ExecutionError>
+where
+    SC: StarkConfig<Felt, QuadFelt>,
+    <SC::Lmcs as Lmcs>::Commitment: Serialize,
+{
+    prove_stark_with_backend(
+        &CpuBackend,
+        config,
+        core_trace,
+        chiplets_trace,
+        poseidon2_trace,
+        public_values,
+        aux_inputs,
+    )
+}
+
+#[instrument("prove_stark", skip_all, fields(backend = backend.name()))]
+fn prove_stark_with_backend<SC>(
+    backend: &impl ProverBackend,
+    config: &SC,
+    core_trace: RowMajorMatrix<Felt>,
+    chiplets_trace: RowMajorMatrix<Felt>,
+    poseidon2_trace: RowMajorMatrix<Felt>,
+    public_values: &[Felt],
+    aux_inputs: &[Felt],
+) -> Result<Vec<u8>, ExecutionError>
 where
     SC: StarkConfig<Felt, QuadFelt>,
     <SC::Lmcs as Lmcs>::Commitment: Serialize,
@@ -339,10 +435,7 @@ where
             .map_err(|e| ExecutionError::ProvingError(e.to_string()))?;
 
     let output: StarkOutput<Felt, QuadFelt, SC> =
-        ProverInstance::new(config, &prover_statement, None)
-            .map_err(|e| ExecutionError::ProvingError(e.to_string()))?
-            .prove(challenger)
-            .map_err(|e| ExecutionError::ProvingError(e.to_string()))?;
+        backend.prove(config, &prover_statement, challenger)?;
 
     let proof_encoding_config = wincode::config::Configuration::default();
     let proof_bytes =
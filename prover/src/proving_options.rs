@@ -1,16 +1,20 @@
 use miden_core::proof::HashFunction;
+use miden_processor::{ExecutionOptions, ExecutionOptionsError, trace::FragmentScheduling};
 
 // PROVING OPTIONS
 // ================================================================================================
 
 /// A set of parameters specifying how Miden VM execution proofs are to be generated.
 ///
-/// This struct stores the proof-generation hash function only. The actual STARK proving parameters
-/// (FRI config, security level, etc.) are determined by the hash function and hardcoded in the
-/// prover's config module.
+/// This struct stores the proof-generation hash function together with the parameters of trace
+/// generation. The actual STARK proving parameters (FRI config, security level, etc.) are
+/// determined by the hash function and hardcoded in the prover's config module.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ProvingOptions {
     hash_fn: HashFunction,
+    core_trace_fragment_size: Option<usize>,
+    num_threads: usize,
+    fragment_scheduling: FragmentScheduling,
 }
 
 impl ProvingOptions {
@@ -22,7 +26,12 @@ impl ProvingOptions {
     /// The STARK proving parameters (security level, FRI config, etc.) are determined
     /// by the hash function and hardcoded in the prover's config module.
     pub fn new(hash_fn: HashFunction) -> Self {
-        Self { hash_fn }
+        Self {
+            hash_fn,
+            core_trace_fragment_size: None,
+            num_threads: 0,
+            fragment_scheduling: FragmentScheduling::default(),
+        }
     }
 
     /// Creates a new instance of [ProvingOptions] targeting 96-bit security level.
@@ -33,10 +42,77 @@ impl ProvingOptions {
         Self::new(hash_fn)
     }
 
+    /// Sets the fragment size for core trace generation, overriding the one specified in the
+    /// [ExecutionOptions] passed to the `prove*()` functions.
+    ///
+    /// This has no effect when proving from pre-executed trace inputs, since their fragments were
+    /// laid out during execution.
+    ///
+    /// Returns an error if the size is zero.
+    pub fn with_core_trace_fragment_size(
+        mut self,
+        size: usize,
+    ) -> Result<Self, ExecutionOptionsError> {
+        if size == 0 {
+            return Err(ExecutionOptionsError::CoreTraceFragmentSizeTooSmall);
+        }
+        self.core_trace_fragment_size = Some(size);
+        Ok(self)
+    }
+
+    /// Sets the number of threads used for trace generation.
+    ///
+    /// When set to a non-zero value, the trace is generated on a dedicated thread pool of that
+    /// size; otherwise, the global rayon thread pool is used. STARK proof generation is not
+    /// affected by this setting.
+    pub fn with_num_threads(mut self, num_threads: usize) -> Self {
+        self.num_threads = num_threads;
+        self
+    }
+
+    /// Sets the strategy used to schedule the generation of core trace fragments.
+    pub fn with_fragment_scheduling(mut self, scheduling: FragmentScheduling) -> Self {
+        self.fragment_scheduling = scheduling;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
     /// Returns the hash function to be used in STARK proof generation.
     pub const fn hash_fn(&self) -> HashFunction {
         self.hash_fn
     }
+
+    /// Returns the fragment size for core trace generation, if it overrides the one specified in
+    /// the execution options.
+    pub const fn core_trace_fragment_size(&self) -> Option<usize> {
+        self.core_trace_fragment_size
+    }
+
+    /// Returns the number of threads used for trace generation, or zero if the global rayon
+    /// thread pool is used.
+    pub const fn num_threads(&self) -> usize {
+        self.num_threads
+    }
+
+    /// Returns the strategy used to schedule the generation of core trace fragments.
+    pub const fn fragment_scheduling(&self) -> FragmentScheduling {
+        self.fragment_scheduling
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies the trace generation parameters of these options to `execution_options`.
+    pub(crate) fn apply_to(&self, execution_options: ExecutionOptions) -> ExecutionOptions {
+        match self.core_trace_fragment_size {
+            Some(size) => execution_options
+                .with_core_trace_fragment_size(size)
+                .expect("fragment size was validated when it was set"),
+            None => execution_options,
+        }
+    }
 }
 
 impl Default for ProvingOptions {
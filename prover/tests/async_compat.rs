@@ -79,7 +79,7 @@ async fn prove_async_matches_prove() {
         stack_inputs,
         advice_inputs.clone(),
         &mut sync_host,
-        execution_options,
+        execution_options.clone(),
         options.clone(),
     )
     .unwrap();
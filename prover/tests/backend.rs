@@ -0,0 +1,77 @@
+#![cfg(feature = "custom-backend")]
+
+use core::cell::Cell;
+
+use miden_air::{MidenMultiAir, ProverStatement};
+use miden_assembly::Assembler;
+use miden_crypto::stark::{StarkConfig, proof::StarkOutput};
+use miden_processor::{DefaultHost, ExecutionError, ExecutionOptions, FastProcessor, Felt};
+use miden_prover::{
+    AdviceInputs, CpuBackend, ProverBackend, ProvingOptions, StackInputs, TraceProvingInputs,
+    field::QuadFelt, prove_from_trace_sync, prove_from_trace_sync_with_backend,
+};
+
+/// Delegates to the CPU backend while counting the statements it proves.
+#[derive(Default)]
+struct CountingBackend {
+    num_proofs: Cell<usize>,
+}
+
+impl ProverBackend for CountingBackend {
+    fn name(&self) -> &str {
+        "counting"
+    }
+
+    fn prove<SC>(
+        &self,
+        config: &SC,
+        statement: &ProverStatement<Felt, QuadFelt, MidenMultiAir>,
+        challenger: SC::Challenger,
+    ) -> Result<StarkOutput<Felt, QuadFelt, SC>, ExecutionError>
+    where
+        SC: StarkConfig<Felt, QuadFelt>,
+    {
+        self.num_proofs.set(self.num_proofs.get() + 1);
+        CpuBackend.prove(config, statement, challenger)
+    }
+}
+
+fn trace_proving_inputs() -> TraceProvingInputs {
+    let program = Assembler::default()
+        .assemble_program(
+            "program",
+            r#"
+            begin
+                repeat.64
+                    swap dup.1 add
+                end
+            end
+            "#,
+        )
+        .expect("program should compile")
+        .unwrap_program();
+    let stack_inputs = StackInputs::new(&[Felt::new_unchecked(0), Felt::new_unchecked(1)]).unwrap();
+    let processor = FastProcessor::new_with_options(
+        stack_inputs,
+        AdviceInputs::default(),
+        ExecutionOptions::default(),
+    )
+    .unwrap();
+    let trace_inputs = processor
+        .execute_trace_inputs_sync(&program, &mut DefaultHost::default())
+        .unwrap();
+    TraceProvingInputs::new(trace_inputs, ProvingOptions::default())
+}
+
+#[test]
+fn custom_backend_generates_the_same_proof_as_the_cpu_backend() {
+    let (expected_outputs, expected_proof) = prove_from_trace_sync(trace_proving_inputs()).unwrap();
+
+    let backend = CountingBackend::default();
+    let (outputs, proof) =
+        prove_from_trace_sync_with_backend(trace_proving_inputs(), &backend).unwrap();
+
+    assert_eq!(backend.num_proofs.get(), 1);
+    assert_eq!(outputs, expected_outputs);
+    assert_eq!(proof.miden_proof(), expected_proof.miden_proof());
+}
@@ -0,0 +1,60 @@
+use miden_assembly::Assembler;
+use miden_processor::{DefaultHost, ExecutionOptions, Felt};
+use miden_prover::{
+    AdviceInputs, ExecutionOptionsError, FragmentScheduling, ProvingOptions, StackInputs,
+    prove_sync,
+};
+
+#[test]
+fn trace_generation_options_do_not_change_outputs() {
+    let program = Assembler::default()
+        .assemble_program(
+            "program",
+            r#"
+            begin
+                repeat.64
+                    swap dup.1 add
+                end
+            end
+            "#,
+        )
+        .expect("program should compile")
+        .unwrap_program();
+    let stack_inputs = StackInputs::new(&[Felt::new_unchecked(0), Felt::new_unchecked(1)]).unwrap();
+
+    let (expected_outputs, _) = prove_sync(
+        &program,
+        stack_inputs,
+        AdviceInputs::default(),
+        &mut DefaultHost::default(),
+        ExecutionOptions::default(),
+        ProvingOptions::default(),
+    )
+    .unwrap();
+
+    let options = ProvingOptions::default()
+        .with_core_trace_fragment_size(16)
+        .unwrap()
+        .with_num_threads(2)
+        .with_fragment_scheduling(FragmentScheduling::Sequential);
+    let (outputs, proof) = prove_sync(
+        &program,
+        stack_inputs,
+        AdviceInputs::default(),
+        &mut DefaultHost::default(),
+        ExecutionOptions::default(),
+        options,
+    )
+    .unwrap();
+
+    assert_eq!(outputs, expected_outputs);
+    assert!(!proof.miden_proof().bytes().is_empty());
+}
+
+#[test]
+fn zero_fragment_size_is_rejected() {
+    assert!(matches!(
+        ProvingOptions::default().with_core_trace_fragment_size(0),
+        Err(ExecutionOptionsError::CoreTraceFragmentSizeTooSmall)
+    ));
+}
@@ -0,0 +1,16 @@
+//! Fuzz target for Miden Assembly program assembly.
+//!
+//! This target fuzzes `miden_assembly::assemble_checked`, which parses, analyzes and assembles
+//! MASM source as an executable program, and must report malformed input as diagnostics.
+//!
+//! Run with: cargo +nightly fuzz run masm_assemble --fuzz-dir tools/miden-core-fuzz
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(source) = core::str::from_utf8(data) {
+        let _ = miden_assembly::assemble_checked(source);
+    }
+});
@@ -14,7 +14,11 @@ use miden_core::{
     field::QuadFelt,
 };
 use miden_crypto::stark::{
-    StarkConfig, VerifierInstance, lmcs::Lmcs, proof::StarkProofData, verifier::VerifierError,
+    StarkConfig, VerifierInstance,
+    lmcs::Lmcs,
+    pcs::{DeepError, FriError, PcsError},
+    proof::StarkProofData,
+    verifier::VerifierError,
 };
 use serde::de::DeserializeOwned;
 use serde_wincode::SerdeCompat;
@@ -102,6 +106,26 @@ impl Verifier {
         stack_outputs: StackOutputs,
         proof: ExecutionProof,
     ) -> Result<u32, VerificationError> {
+        self.verify_returning_digest(program_info, stack_inputs, stack_outputs, proof)
+            .map(|(security_level, _)| security_level)
+    }
+
+    /// Verifies a final proof like [`Self::verify`], and additionally returns the final deferred
+    /// root the Miden VM proof was verified against.
+    ///
+    /// The root commits to all precompile requests made by the program, and is [`TRUE_DIGEST`] if
+    /// the program made no such requests. This allows callers to bind the verified precompile
+    /// transcript into a higher-level commitment without hydrating the deferred state.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`Self::verify`].
+    pub fn verify_returning_digest(
+        &self,
+        program_info: ProgramInfo,
+        stack_inputs: StackInputs,
+        stack_outputs: StackOutputs,
+        proof: ExecutionProof,
+    ) -> Result<(u32, Word), VerificationError> {
         let miden_security_level = proof.security_level();
         let (final_deferred_root, precompile_security_level) =
             resolve_final_deferred_root(proof.deferred_proof())?;
@@ -114,9 +138,10 @@ impl Verifier {
             proof.miden_proof(),
         )?;
 
-        Ok(precompile_security_level
+        let security_level = precompile_security_level
             .map(|level| miden_security_level.min(level))
-            .unwrap_or(miden_security_level))
+            .unwrap_or(miden_security_level);
+        Ok((security_level, final_deferred_root))
     }
 
     /// Verifies a partial proof and returns its Miden VM security level and hydrated deferred
@@ -191,6 +216,38 @@ pub fn verify(
     Verifier::default().verify(program_info, stack_inputs, stack_outputs, proof)
 }
 
+/// Verifies the proof like [`Verifier::verify`], but reports which check of the STARK verifier
+/// failed instead of an opaque error.
+///
+/// This is a debugging aid for prover development: the returned [`VerifierFailure`] identifies
+/// the failing stage of the protocol and, for query-phase failures, the index of the failing
+/// query. The deferred proof is resolved exactly as in [`Verifier::verify`]; failures there are
+/// reported as [`VerifierFailure::Deferred`].
+///
+/// # Errors
+/// Returns a [`VerifierFailure`] describing the first check which rejected the proof.
+pub fn verify_stark_diagnostic(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: &ExecutionProof,
+) -> Result<(), VerifierFailure> {
+    let (final_deferred_root, _) = resolve_final_deferred_root(proof.deferred_proof())
+        .map_err(|err| VerifierFailure::Deferred(Box::new(err)))?;
+
+    verify_stark(
+        program_info,
+        stack_inputs,
+        stack_outputs,
+        final_deferred_root,
+        proof.miden_proof(),
+    )
+    .map_err(|err| match err {
+        VerificationError::StarkVerificationError(_, err) => VerifierFailure::from(*err),
+        err => VerifierFailure::Deferred(Box::new(err)),
+    })
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -245,6 +302,10 @@ fn verify_stark(
     let proof_bytes = stark_proof.bytes();
     let params = config::pcs_params();
     match hash_fn {
+        HashFunction::Blake3_192 => {
+            let config = config::blake3_192_config(params, config::RELATION_DIGEST);
+            verify_stark_proof(&config, &public_values, &aux_inputs, proof_bytes)
+        },
         HashFunction::Blake3_256 => {
             let config = config::blake3_256_config(params, config::RELATION_DIGEST);
             verify_stark_proof(&config, &public_values, &aux_inputs, proof_bytes)
@@ -301,6 +362,102 @@ pub enum StarkVerificationError {
     Verifier(#[from] VerifierError),
 }
 
+/// The check of the STARK verifier which rejected a proof, as reported by
+/// [`verify_stark_diagnostic`].
+///
+/// Query indices refer to positions in the LDE domain, i.e. the leaf index opened by the failing
+/// query, rather than to the order in which queries were sampled.
+#[derive(Debug, thiserror::Error)]
+pub enum VerifierFailure {
+    /// The proof bytes could not be decoded into a proof for the selected hash function.
+    #[error("proof could not be decoded: {0}")]
+    Encoding(#[source] StarkVerificationError),
+    /// The proof does not match the shape expected by the Miden VM statement (e.g. trace heights
+    /// or number of AIR instances).
+    #[error("proof shape does not match the statement: {0}")]
+    Shape(#[source] VerifierError),
+    /// The proof transcript was malformed: it ran out of data, had trailing data, or carried an
+    /// invalid proof-of-work witness.
+    #[error("proof transcript is malformed: {0}")]
+    Transcript(#[source] VerifierError),
+    /// A Merkle opening did not authenticate against its commitment.
+    #[error("opening does not match its commitment: {0}")]
+    Commitment(#[source] VerifierError),
+    /// A DEEP query opened trace values which are inconsistent with the claimed evaluations.
+    #[error("DEEP query at index {index} failed: {source}")]
+    DeepQuery {
+        index: usize,
+        #[source]
+        source: VerifierError,
+    },
+    /// An FRI query failed the folding consistency check at the given round, or did not match the
+    /// final polynomial (in which case `round` is `None`).
+    #[error("FRI query at index {index} failed: {source}")]
+    FriQuery {
+        index: usize,
+        round: Option<usize>,
+        #[source]
+        source: VerifierError,
+    },
+    /// The out-of-domain quotient identity does not hold.
+    ///
+    /// The verifier checks all constraints at once through their random linear combination, so a
+    /// failure cannot be attributed to an individual constraint.
+    #[error("out-of-domain constraint check failed")]
+    OodConstraint,
+    /// A boundary assertion over the public inputs evaluated to a non-zero value.
+    #[error("boundary assertion {assertion} is non-zero")]
+    BoundaryAssertion { assertion: usize },
+    /// The deferred proof attached to the execution proof was rejected.
+    #[error("deferred proof was rejected: {0}")]
+    Deferred(#[source] Box<VerificationError>),
+}
+
+impl From<StarkVerificationError> for VerifierFailure {
+    fn from(err: StarkVerificationError) -> Self {
+        let err = match err {
+            StarkVerificationError::Verifier(err) => err,
+            err => return Self::Encoding(err),
+        };
+
+        match &err {
+            VerifierError::Instance(_) | VerifierError::Shape(_) | VerifierError::Domain(_) => {
+                Self::Shape(err)
+            },
+            VerifierError::Transcript(_) | VerifierError::Reduction(_) => Self::Transcript(err),
+            VerifierError::ConstraintMismatch => Self::OodConstraint,
+            &VerifierError::ExternalAssertionFailed { assertion } => {
+                Self::BoundaryAssertion { assertion }
+            },
+            VerifierError::Pcs(pcs) => match pcs {
+                PcsError::NoCommitments => Self::Shape(err),
+                PcsError::TranscriptError(_)
+                | PcsError::DeepError(DeepError::TranscriptError(_))
+                | PcsError::FriError(FriError::TranscriptError(_)) => Self::Transcript(err),
+                PcsError::DeepError(DeepError::LmcsError { .. })
+                | PcsError::FriError(FriError::LmcsError { .. }) => Self::Commitment(err),
+                &PcsError::DeepError(
+                    DeepError::InvalidOpening { tree_index, .. }
+                    | DeepError::EvalPointOnDomain { tree_index },
+                ) => Self::DeepQuery { index: tree_index, source: err },
+                &PcsError::FriError(
+                    FriError::InvalidOpening { tree_index, round }
+                    | FriError::EvaluationMismatch { tree_index, round, .. },
+                ) => Self::FriQuery {
+                    index: tree_index,
+                    round: Some(round),
+                    source: err,
+                },
+                &PcsError::FriError(FriError::FinalPolyMismatch { tree_index }) => Self::FriQuery {
+                    index: tree_index,
+                    round: None,
+                    source: err,
+                },
+            },
+        }
+    }
+}
+
 /// Verifies a multi-AIR STARK proof for the Miden VM statement.
 ///
 /// Pre-seeds the challenger with protocol parameters, AIR public values, and statement